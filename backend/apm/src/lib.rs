@@ -1,12 +1,26 @@
 use std::collections::HashMap;
 use std::env;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
-use anyhow::Result;
+use anyhow::{anyhow, bail, Context, Result};
 use opentelemetry::global;
-use opentelemetry::metrics::Meter;
-use opentelemetry::trace::{Span, Tracer};
+use opentelemetry::metrics::{Counter, Histogram, Meter, ObservableGauge};
+use opentelemetry::trace::{Span, TraceContextExt};
 use opentelemetry::KeyValue;
 use tracing::{info, warn};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+pub mod middleware;
+pub mod prometheus;
+pub mod slow_query;
+
+pub use middleware::ApmMiddleware;
+pub use slow_query::SlowQueryLayer;
+
+use prometheus::{PrometheusRegistry, BYTES_BUCKETS, SECONDS_BUCKETS};
 
 /// APM configuration
 #[derive(Debug, Clone)]
@@ -17,9 +31,23 @@ pub struct ApmConfig {
     pub enabled: bool,
     pub platform: ApmPlatform,
     pub sample_rate: f64,
+    /// How `sample_rate` is applied when choosing the trace sampler
+    pub sampling_strategy: ApmSamplingStrategy,
     pub otlp_endpoint: Option<String>,
+    /// Extra gRPC metadata sent with every export, e.g. vendor API keys
+    pub otlp_headers: HashMap<String, String>,
+    /// Export over plaintext even when the endpoint is `https`
+    pub otlp_insecure: bool,
     pub new_relic_license_key: Option<String>,
     pub datadog_api_key: Option<String>,
+    /// Errors per second above which span error recording is sampled
+    pub error_sampling_threshold: u64,
+    /// Fraction of errors recorded on spans once the threshold is exceeded
+    pub error_span_sample_rate: f64,
+    /// Mirror metrics into a registry served on `GET /metrics`
+    pub prometheus_enabled: bool,
+    /// Database queries taking longer than this are logged and counted
+    pub slow_query_threshold: Duration,
 }
 
 #[derive(Debug, Clone)]
@@ -29,6 +57,22 @@ pub enum ApmPlatform {
     Datadog,
 }
 
+/// Console log layer installed next to the OTLP exporter. The caller picks
+/// its format, so the APM path logs the same way as the application does.
+pub type ConsoleLayer =
+    Box<dyn tracing_subscriber::Layer<tracing_subscriber::Registry> + Send + Sync>;
+
+/// Trace sampling strategy, selected with `OTEL_SAMPLER`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApmSamplingStrategy {
+    /// Sample `sample_rate` of traces by trace id, ignoring the parent
+    Ratio,
+    /// Follow the parent's decision; root spans use the `sample_rate` ratio
+    ParentBased,
+    AlwaysOn,
+    AlwaysOff,
+}
+
 impl Default for ApmConfig {
     fn default() -> Self {
         Self {
@@ -42,20 +86,134 @@ impl Default for ApmConfig {
                 .parse()
                 .unwrap_or(true),
             platform: env::var("APM_PLATFORM")
-                .unwrap_or_else(|_| "opentelemetry".to_string())
-                .parse()
+                .map(ApmPlatform::from)
                 .unwrap_or(ApmPlatform::OpenTelemetry),
             sample_rate: env::var("OTEL_TRACE_SAMPLE_RATE")
                 .unwrap_or_else(|_| "1.0".to_string())
                 .parse()
                 .unwrap_or(1.0),
+            sampling_strategy: env::var("OTEL_SAMPLER")
+                .map(ApmSamplingStrategy::from)
+                .unwrap_or(ApmSamplingStrategy::Ratio),
             otlp_endpoint: env::var("OTEL_EXPORTER_OTLP_ENDPOINT").ok(),
+            // Malformed headers are rejected by `from_env`; `default` can't fail
+            otlp_headers: env::var("OTEL_EXPORTER_OTLP_HEADERS")
+                .ok()
+                .and_then(|raw| parse_otlp_headers(&raw).ok())
+                .unwrap_or_default(),
+            otlp_insecure: env::var("OTEL_EXPORTER_OTLP_INSECURE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(false),
             new_relic_license_key: env::var("NEW_RELIC_LICENSE_KEY").ok(),
             datadog_api_key: env::var("DD_API_KEY").ok(),
+            error_sampling_threshold: env::var("APM_ERROR_SAMPLING_THRESHOLD")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(100),
+            error_span_sample_rate: env::var("APM_ERROR_SPAN_SAMPLE_RATE")
+                .ok()
+                .and_then(|v| v.parse::<f64>().ok())
+                .map(|rate| rate.clamp(0.0, 1.0))
+                .unwrap_or(1.0),
+            prometheus_enabled: env::var("PROMETHEUS_ENABLED")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(false),
+            slow_query_threshold: env::var("SLOW_QUERY_MS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .map(Duration::from_millis)
+                .unwrap_or(Duration::from_millis(100)),
         }
     }
 }
 
+impl ApmConfig {
+    /// Configuration from the environment, failing on a malformed
+    /// `OTEL_EXPORTER_OTLP_HEADERS` instead of dropping it
+    pub fn from_env() -> Result<Self> {
+        let otlp_headers = match env::var("OTEL_EXPORTER_OTLP_HEADERS") {
+            Ok(raw) => parse_otlp_headers(&raw).context("Invalid OTEL_EXPORTER_OTLP_HEADERS")?,
+            Err(_) => HashMap::new(),
+        };
+
+        Ok(Self {
+            otlp_headers,
+            ..Self::default()
+        })
+    }
+
+    /// OTLP endpoint to export to, downgraded to plaintext when
+    /// `otlp_insecure` is set
+    fn resolved_otlp_endpoint(&self) -> String {
+        let endpoint = self
+            .otlp_endpoint
+            .clone()
+            .unwrap_or_else(|| "http://localhost:4317".to_string());
+        match endpoint.strip_prefix("https://") {
+            Some(rest) if self.otlp_insecure => format!("http://{}", rest),
+            _ => endpoint,
+        }
+    }
+}
+
+/// Parse an OTLP header list in the `OTEL_EXPORTER_OTLP_HEADERS` format:
+/// comma-separated `key=value` pairs with percent-encoded values
+pub fn parse_otlp_headers(raw: &str) -> Result<HashMap<String, String>> {
+    let mut headers = HashMap::new();
+    for pair in raw.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let Some((key, value)) = pair.split_once('=') else {
+            bail!("header '{}' is not of the form key=value", pair);
+        };
+        let key = key.trim();
+        if key.is_empty() {
+            bail!("header '{}' has an empty name", pair);
+        }
+        let value = percent_decode(value.trim())
+            .with_context(|| format!("header '{}' has an invalid value", key))?;
+        headers.insert(key.to_string(), value);
+    }
+    Ok(headers)
+}
+
+fn percent_decode(value: &str) -> Result<String> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = bytes
+                .get(i + 1..i + 3)
+                .and_then(|h| std::str::from_utf8(h).ok())
+                .and_then(|h| u8::from_str_radix(h, 16).ok())
+                .ok_or_else(|| anyhow!("bad percent-encoding at byte {}", i))?;
+            decoded.push(hex);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    Ok(String::from_utf8(decoded)?)
+}
+
+/// Convert configured OTLP headers to gRPC metadata, rejecting names and
+/// values gRPC can't carry
+fn otlp_metadata(headers: &HashMap<String, String>) -> Result<tonic::metadata::MetadataMap> {
+    use tonic::metadata::{MetadataKey, MetadataMap, MetadataValue};
+
+    let mut metadata = MetadataMap::with_capacity(headers.len());
+    for (key, value) in headers {
+        let name = MetadataKey::from_bytes(key.as_bytes())
+            .map_err(|_| anyhow!("invalid OTLP header name '{}'", key))?;
+        let value = MetadataValue::try_from(value.as_str())
+            .map_err(|_| anyhow!("invalid value for OTLP header '{}'", key))?;
+        metadata.insert(name, value);
+    }
+    Ok(metadata)
+}
+
 impl From<String> for ApmPlatform {
     fn from(s: String) -> Self {
         match s.to_lowercase().as_str() {
@@ -66,89 +224,277 @@ impl From<String> for ApmPlatform {
     }
 }
 
+impl From<String> for ApmSamplingStrategy {
+    fn from(s: String) -> Self {
+        match s.to_lowercase().as_str() {
+            "parent_based" | "parentbased" | "parentbased_traceidratio" => {
+                ApmSamplingStrategy::ParentBased
+            }
+            "always_on" | "alwayson" => ApmSamplingStrategy::AlwaysOn,
+            "always_off" | "alwaysoff" => ApmSamplingStrategy::AlwaysOff,
+            _ => ApmSamplingStrategy::Ratio,
+        }
+    }
+}
+
+impl ApmSamplingStrategy {
+    /// Build the OpenTelemetry sampler for this strategy
+    pub fn sampler(&self, sample_rate: f64) -> opentelemetry_sdk::trace::Sampler {
+        use opentelemetry_sdk::trace::Sampler;
+
+        match self {
+            ApmSamplingStrategy::Ratio => Sampler::TraceIdRatioBased(sample_rate),
+            ApmSamplingStrategy::ParentBased => {
+                Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(sample_rate)))
+            }
+            ApmSamplingStrategy::AlwaysOn => Sampler::AlwaysOn,
+            ApmSamplingStrategy::AlwaysOff => Sampler::AlwaysOff,
+        }
+    }
+}
+
+/// Broad classification of recorded errors, used as the `error.category` label
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    Database,
+    Rpc,
+    Validation,
+    Auth,
+    Network,
+    Internal,
+}
+
+impl ErrorCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCategory::Database => "database",
+            ErrorCategory::Rpc => "rpc",
+            ErrorCategory::Validation => "validation",
+            ErrorCategory::Auth => "auth",
+            ErrorCategory::Network => "network",
+            ErrorCategory::Internal => "internal",
+        }
+    }
+
+    /// Best-effort classification from an error message
+    pub fn infer(message: &str) -> Self {
+        let lowered = message.to_ascii_lowercase();
+        if lowered.contains("database")
+            || lowered.contains("sqlx")
+            || lowered.contains("sql")
+            || lowered.contains("pool timed out")
+        {
+            ErrorCategory::Database
+        } else if lowered.contains("rpc")
+            || lowered.contains("horizon")
+            || lowered.contains("circuit breaker")
+        {
+            ErrorCategory::Rpc
+        } else if lowered.contains("unauthorized")
+            || lowered.contains("forbidden")
+            || lowered.contains("token")
+        {
+            ErrorCategory::Auth
+        } else if lowered.contains("invalid") || lowered.contains("validation") {
+            ErrorCategory::Validation
+        } else if lowered.contains("connection")
+            || lowered.contains("timeout")
+            || lowered.contains("timed out")
+            || lowered.contains("dns")
+        {
+            ErrorCategory::Network
+        } else {
+            ErrorCategory::Internal
+        }
+    }
+}
+
+impl fmt::Display for ErrorCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Errors that know which [`ErrorCategory`] they belong to
+pub trait CategorizedError {
+    fn category(&self) -> ErrorCategory;
+}
+
+impl CategorizedError for anyhow::Error {
+    /// Walks the error chain and classifies by the first recognisable cause
+    fn category(&self) -> ErrorCategory {
+        self.chain()
+            .map(|cause| ErrorCategory::infer(&cause.to_string()))
+            .find(|category| *category != ErrorCategory::Internal)
+            .unwrap_or(ErrorCategory::Internal)
+    }
+}
+
 /// APM Manager for handling observability
 pub struct ApmManager {
     config: ApmConfig,
     meter: Meter,
     metrics: ApmMetrics,
+    prometheus: Option<Arc<PrometheusRegistry>>,
+    error_sampler: ErrorSampler,
+    slow_queries: AtomicU64,
+}
+
+/// Decides whether an error is recorded on its span.
+///
+/// Below `threshold` errors per second every error is recorded; above it only
+/// every Nth error is, where N is derived from the configured sample rate.
+struct ErrorSampler {
+    threshold: u64,
+    sample_every: u64,
+    window: Mutex<(Instant, u64)>,
+}
+
+impl ErrorSampler {
+    fn new(threshold: u64, sample_rate: f64) -> Self {
+        let sample_every = if sample_rate <= 0.0 {
+            u64::MAX
+        } else {
+            (1.0 / sample_rate).round().max(1.0) as u64
+        };
+
+        Self {
+            threshold,
+            sample_every,
+            window: Mutex::new((Instant::now(), 0)),
+        }
+    }
+
+    fn should_record(&self) -> bool {
+        let Ok(mut window) = self.window.lock() else {
+            return true;
+        };
+
+        if window.0.elapsed() >= Duration::from_secs(1) {
+            *window = (Instant::now(), 0);
+        }
+        window.1 += 1;
+
+        let seen = window.1;
+        if seen <= self.threshold {
+            return true;
+        }
+
+        (seen - self.threshold).is_multiple_of(self.sample_every)
+    }
+}
+
+/// Bounded label set for the `error_total` counter.
+///
+/// Error messages frequently embed ids and timestamps, so they are kept out of
+/// metric labels and only appear on spans and logs.
+pub fn error_metric_labels(category: ErrorCategory) -> [KeyValue; 2] {
+    [
+        KeyValue::new("error.category", category.as_str()),
+        KeyValue::new("error.type", std::any::type_name::<anyhow::Error>()),
+    ]
+}
+
+/// Label set shared by the database query metrics
+fn db_query_attributes(operation: &str, table: &str) -> [KeyValue; 2] {
+    [
+        KeyValue::new("db.operation", operation.to_string()),
+        KeyValue::new("db.table", table.to_string()),
+    ]
 }
 
 /// Application metrics
 pub struct ApmMetrics {
     // HTTP metrics
-    pub http_requests_total: Counter<u64>,
-    pub http_request_duration: Histogram<f64>,
-    pub http_request_size: Histogram<u64>,
-    pub http_response_size: Histogram<u64>,
+    pub http_requests_total: ApmCounter,
+    pub http_request_duration: ApmHistogram,
+    pub http_request_size: ApmHistogram,
+    pub http_response_size: ApmHistogram,
 
     // Database metrics
-    pub db_connections_active: Gauge<u64>,
-    pub db_query_duration: Histogram<f64>,
-    pub db_queries_total: Counter<u64>,
+    pub db_connections_active: ApmGauge,
+    pub db_query_duration: ApmHistogram,
+    pub db_queries_total: ApmCounter,
+    pub slow_query_total: ApmCounter,
 
     // Business metrics
-    pub stellar_requests_total: Counter<u64>,
-    pub active_users: Gauge<u64>,
-    pub data_ingestion_rate: Counter<u64>,
+    pub stellar_requests_total: ApmCounter,
+    pub active_users: ApmGauge,
+    pub data_ingestion_rate: ApmCounter,
 
     // Error metrics
-    pub error_total: Counter<u64>,
-    pub panic_total: Counter<u64>,
+    pub error_total: ApmCounter,
+    pub panic_total: ApmCounter,
 }
 
 impl ApmManager {
+    /// Create the manager, logging to the console as JSON when APM is enabled
     pub fn new(config: ApmConfig) -> Result<Self> {
-        if !config.enabled {
-            return Ok(Self {
-                config,
-                meter: global::meter("stellar-insights"),
-                metrics: ApmMetrics::empty(),
-            });
+        use tracing_subscriber::Layer;
+
+        Self::with_console_layer(config, tracing_subscriber::fmt::layer().json().boxed())
+    }
+
+    /// Create the manager; when APM is enabled, `console` is installed as the
+    /// console log layer alongside the OTLP exporter
+    pub fn with_console_layer(config: ApmConfig, console: ConsoleLayer) -> Result<Self> {
+        if config.enabled {
+            // Initialize OpenTelemetry
+            Self::init_tracing(&config, console)?;
+            info!("APM initialized with platform: {:?}", config.platform);
         }
 
-        // Initialize OpenTelemetry
-        Self::init_tracing(&config)?;
+        // Prometheus works without an OTLP pipeline, so it is set up either way
+        let prometheus = config
+            .prometheus_enabled
+            .then(|| Arc::new(PrometheusRegistry::new()));
 
         let meter = global::meter("stellar-insights");
-        let metrics = ApmMetrics::new(&meter);
-
-        info!("APM initialized with platform: {:?}", config.platform);
+        let metrics = ApmMetrics::new(config.enabled.then_some(&meter), prometheus.clone());
 
         Ok(Self {
+            error_sampler: ErrorSampler::new(
+                config.error_sampling_threshold,
+                config.error_span_sample_rate,
+            ),
             config,
             meter,
             metrics,
+            prometheus,
+            slow_queries: AtomicU64::new(0),
         })
     }
 
-    fn init_tracing(config: &ApmConfig) -> Result<()> {
+    fn init_tracing(config: &ApmConfig, console: ConsoleLayer) -> Result<()> {
         match config.platform {
-            ApmPlatform::OpenTelemetry => Self::init_opentelemetry(config),
-            ApmPlatform::NewRelic => Self::init_new_relic(config),
-            ApmPlatform::Datadog => Self::init_datadog(config),
+            ApmPlatform::OpenTelemetry => Self::init_opentelemetry(config, console),
+            ApmPlatform::NewRelic => Self::init_new_relic(config, console),
+            ApmPlatform::Datadog => Self::init_datadog(config, console),
         }
     }
 
-    fn init_opentelemetry(config: &ApmConfig) -> Result<()> {
+    fn init_opentelemetry(config: &ApmConfig, console: ConsoleLayer) -> Result<()> {
         use opentelemetry_otlp::WithExportConfig;
-        use opentelemetry_sdk::trace::{self, RandomIdGenerator, Sampler};
+        use opentelemetry_sdk::trace::{self, RandomIdGenerator};
         use opentelemetry_sdk::Resource;
         use tracing_subscriber::layer::SubscriberExt;
         use tracing_subscriber::util::SubscriberInitExt;
 
-        let exporter = opentelemetry_otlp::new_exporter().tonic().with_endpoint(
-            config
-                .otlp_endpoint
-                .clone()
-                .unwrap_or_else(|| "http://localhost:4317".to_string()),
-        );
+        let endpoint = config.resolved_otlp_endpoint();
+        let mut exporter = opentelemetry_otlp::new_exporter()
+            .tonic()
+            .with_endpoint(endpoint.clone())
+            .with_metadata(otlp_metadata(&config.otlp_headers)?);
+        if endpoint.starts_with("https://") {
+            exporter = exporter.with_tls_config(tonic::transport::ClientTlsConfig::new());
+        }
 
         let tracer = opentelemetry_otlp::new_pipeline()
             .tracing()
             .with_exporter(exporter)
             .with_trace_config(
                 trace::config()
-                    .with_sampler(Sampler::TraceIdRatio(config.sample_rate))
+                    .with_sampler(config.sampling_strategy.sampler(config.sample_rate))
                     .with_id_generator(RandomIdGenerator::default())
                     .with_resource(Resource::new(vec![
                         KeyValue::new("service.name", config.service_name.clone()),
@@ -161,18 +507,18 @@ impl ApmManager {
         let telemetry = tracing_opentelemetry::layer().with_tracer(tracer);
 
         tracing_subscriber::registry()
+            .with(console)
             .with(telemetry)
             .with(
                 tracing_subscriber::EnvFilter::try_from_default_env()
                     .unwrap_or_else(|_| "stellar_insights=info,tower_http=debug".into()),
             )
-            .with(tracing_subscriber::fmt::layer().json())
             .init();
 
         Ok(())
     }
 
-    fn init_new_relic(config: &ApmConfig) -> Result<()> {
+    fn init_new_relic(config: &ApmConfig, console: ConsoleLayer) -> Result<()> {
         // New Relic integration via OTLP endpoint
         if let (Some(license_key), Some(endpoint)) =
             (&config.new_relic_license_key, &config.otlp_endpoint)
@@ -187,16 +533,16 @@ impl ApmManager {
             env::set_var("NEW_RELIC_OTLP_ENDPOINT", &nr_endpoint);
 
             // Initialize with OpenTelemetry exporter pointing to New Relic
-            Self::init_opentelemetry(config)?;
+            Self::init_opentelemetry(config, console)?;
         } else {
             warn!("New Relic configuration incomplete, falling back to OpenTelemetry");
-            Self::init_opentelemetry(config)?;
+            Self::init_opentelemetry(config, console)?;
         }
 
         Ok(())
     }
 
-    fn init_datadog(config: &ApmConfig) -> Result<()> {
+    fn init_datadog(config: &ApmConfig, console: ConsoleLayer) -> Result<()> {
         // Datadog integration via OTLP endpoint
         if let (Some(api_key), Some(endpoint)) = (&config.datadog_api_key, &config.otlp_endpoint) {
             info!("Initializing Datadog APM");
@@ -209,10 +555,10 @@ impl ApmManager {
             env::set_var("DD_OTLP_ENDPOINT", &dd_endpoint);
 
             // Initialize with OpenTelemetry exporter pointing to Datadog
-            Self::init_opentelemetry(config)?;
+            Self::init_opentelemetry(config, console)?;
         } else {
             warn!("Datadog configuration incomplete, falling back to OpenTelemetry");
-            Self::init_opentelemetry(config)?;
+            Self::init_opentelemetry(config, console)?;
         }
 
         Ok(())
@@ -223,16 +569,21 @@ impl ApmManager {
         &self.metrics
     }
 
+    /// Registry backing `GET /metrics`, when `PROMETHEUS_ENABLED` is set
+    pub fn prometheus(&self) -> Option<&Arc<PrometheusRegistry>> {
+        self.prometheus.as_ref()
+    }
+
     /// Create a custom span with attributes
-    pub fn create_span(&self, name: &str, attributes: Vec<(String, String)>) -> Span {
+    pub fn create_span(&self, name: &str, attributes: Vec<(String, String)>) -> global::BoxedSpan {
         use opentelemetry::trace::Tracer;
 
         let tracer = global::tracer("stellar-insights");
-        let mut span = tracer.start(name);
+        let mut span = tracer.start(name.to_string());
 
         // Add attributes
         for (key, value) in attributes {
-            span = span.with_attributes(vec![KeyValue::new(key, value)]);
+            span.set_attribute(KeyValue::new(key, value));
         }
 
         span
@@ -240,7 +591,7 @@ impl ApmManager {
 
     /// Record a custom metric
     pub fn record_custom_metric(&self, name: &str, value: f64, attributes: Vec<(String, String)>) {
-        let counter = self.meter.u64_counter(name).init();
+        let counter = self.meter.u64_counter(name.to_string()).init();
         let attrs: Vec<KeyValue> = attributes
             .into_iter()
             .map(|(k, v)| KeyValue::new(k, v))
@@ -250,22 +601,90 @@ impl ApmManager {
     }
 
     /// Record an error with context
+    ///
+    /// The error category is inferred from the error chain; use
+    /// [`ApmManager::record_categorized_error`] when the caller already knows it.
     pub fn record_error(&self, error: &anyhow::Error, context: HashMap<String, String>) {
+        self.record_categorized_error(error.category(), error, context);
+    }
+
+    /// Record an error under an explicit category
+    ///
+    /// `error_total` is always incremented with bounded labels (category and
+    /// type). Span attributes and the structured error log, which carry the
+    /// message and the active trace/span ids, are sampled once the error rate
+    /// exceeds `error_sampling_threshold` per second.
+    pub fn record_categorized_error(
+        &self,
+        category: ErrorCategory,
+        error: &anyhow::Error,
+        context: HashMap<String, String>,
+    ) {
+        self.metrics
+            .error_total
+            .add(1, &error_metric_labels(category));
+
+        if !self.error_sampler.should_record() {
+            return;
+        }
+
         let current_span = tracing::Span::current();
         current_span.record("error.message", error.to_string());
         current_span.record("error.type", std::any::type_name::<anyhow::Error>());
+        current_span.record("error.category", category.as_str());
+
+        let (trace_id, span_id) =
+            current_trace_ids(&current_span).unwrap_or_else(|| (String::new(), String::new()));
+
+        tracing::error!(
+            trace_id = %trace_id,
+            span_id = %span_id,
+            error.category = %category,
+            error.message = %error,
+            context = ?context,
+            "APM recorded error"
+        );
 
         for (key, value) in context {
-            current_span.record(&key, value);
+            current_span.record(key.as_str(), value);
         }
+    }
 
-        self.metrics.error_total.add(
-            1,
-            &[
-                KeyValue::new("error.type", std::any::type_name::<anyhow::Error>()),
-                KeyValue::new("error.message", error.to_string()),
-            ],
-        );
+    /// Record a database query's duration under its operation and table
+    ///
+    /// Queries slower than `slow_query_threshold` are also logged with their
+    /// operation, table and duration and counted in `slow_query_total`.
+    pub fn record_db_query(&self, operation: &str, table: &str, duration: Duration) {
+        let attributes = db_query_attributes(operation, table);
+        self.metrics.db_queries_total.add(1, &attributes);
+        self.metrics
+            .db_query_duration
+            .record(duration.as_secs_f64(), &attributes);
+
+        if duration > self.config.slow_query_threshold {
+            warn!(
+                operation = operation,
+                table = table,
+                duration_ms = duration.as_millis() as u64,
+                threshold_ms = self.config.slow_query_threshold.as_millis() as u64,
+                "Slow database query"
+            );
+            self.record_slow_db_query(operation, table);
+        }
+    }
+
+    /// Count a query already found to be slow, e.g. one reported by sqlx's
+    /// slow statement log, in `slow_query_total`
+    pub fn record_slow_db_query(&self, operation: &str, table: &str) {
+        self.metrics
+            .slow_query_total
+            .add(1, &db_query_attributes(operation, table));
+        self.slow_queries.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Number of queries over the slow query threshold since startup
+    pub fn slow_query_count(&self) -> u64 {
+        self.slow_queries.load(Ordering::Relaxed)
     }
 
     /// Shutdown APM gracefully
@@ -278,82 +697,152 @@ impl ApmManager {
     }
 }
 
+/// Extract the OpenTelemetry trace and span ids for a tracing span.
+///
+/// Returns `None` when the span has no valid OpenTelemetry context attached
+/// (for example when no span is active or APM is disabled).
+pub fn current_trace_ids(span: &tracing::Span) -> Option<(String, String)> {
+    let context = span.context();
+    let otel_span = context.span();
+    let span_context = otel_span.span_context();
+
+    if !span_context.is_valid() {
+        return None;
+    }
+
+    Some((
+        span_context.trace_id().to_string(),
+        span_context.span_id().to_string(),
+    ))
+}
+
 impl ApmMetrics {
-    fn new(meter: &Meter) -> Self {
+    /// Instruments record to `meter` when APM is enabled and to `prometheus`
+    /// when the scrape endpoint is enabled; with neither they are no-ops
+    fn new(meter: Option<&Meter>, prometheus: Option<Arc<PrometheusRegistry>>) -> Self {
+        let counter = |name: &'static str| ApmCounter {
+            name,
+            otel: meter.map(|m| m.u64_counter(name).init()),
+            prometheus: prometheus.clone(),
+        };
+        let histogram = |name: &'static str, bounds: &'static [f64]| ApmHistogram {
+            name,
+            bounds,
+            otel: meter.map(|m| m.f64_histogram(name).init()),
+            prometheus: prometheus.clone(),
+        };
+        let gauge = |name: &'static str| {
+            let values = GaugeValues::default();
+            let observed = Arc::clone(&values);
+            ApmGauge {
+                name,
+                values,
+                _otel: meter.map(|m| {
+                    m.u64_observable_gauge(name)
+                        .with_callback(move |observer| {
+                            if let Ok(values) = observed.lock() {
+                                for (attributes, value) in values.iter() {
+                                    observer.observe(*value, attributes);
+                                }
+                            }
+                        })
+                        .init()
+                }),
+                prometheus: prometheus.clone(),
+            }
+        };
+
         Self {
             // HTTP metrics
-            http_requests_total: meter.u64_counter("http_requests_total").init(),
-            http_request_duration: meter.f64_histogram("http_request_duration_seconds").init(),
-            http_request_size: meter.u64_histogram("http_request_size_bytes").init(),
-            http_response_size: meter.u64_histogram("http_response_size_bytes").init(),
+            http_requests_total: counter("http_requests_total"),
+            http_request_duration: histogram("http_request_duration_seconds", SECONDS_BUCKETS),
+            http_request_size: histogram("http_request_size_bytes", BYTES_BUCKETS),
+            http_response_size: histogram("http_response_size_bytes", BYTES_BUCKETS),
 
             // Database metrics
-            db_connections_active: meter.u64_gauge("db_connections_active").init(),
-            db_query_duration: meter.f64_histogram("db_query_duration_seconds").init(),
-            db_queries_total: meter.u64_counter("db_queries_total").init(),
+            db_connections_active: gauge("db_connections_active"),
+            db_query_duration: histogram("db_query_duration_seconds", SECONDS_BUCKETS),
+            db_queries_total: counter("db_queries_total"),
+            slow_query_total: counter("slow_query_total"),
 
             // Business metrics
-            stellar_requests_total: meter.u64_counter("stellar_requests_total").init(),
-            active_users: meter.u64_gauge("active_users").init(),
-            data_ingestion_rate: meter.u64_counter("data_ingestion_rate").init(),
+            stellar_requests_total: counter("stellar_requests_total"),
+            active_users: gauge("active_users"),
+            data_ingestion_rate: counter("data_ingestion_rate"),
 
             // Error metrics
-            error_total: meter.u64_counter("error_total").init(),
-            panic_total: meter.u64_counter("panic_total").init(),
+            error_total: counter("error_total"),
+            panic_total: counter("panic_total"),
         }
     }
+}
 
-    fn empty() -> Self {
-        // Create no-op metrics for when APM is disabled
-        Self {
-            http_requests_total: NoOpCounter::new(),
-            http_request_duration: NoOpHistogram::new(),
-            http_request_size: NoOpHistogram::new(),
-            http_response_size: NoOpHistogram::new(),
-            db_connections_active: NoOpGauge::new(),
-            db_query_duration: NoOpHistogram::new(),
-            db_queries_total: NoOpCounter::new(),
-            stellar_requests_total: NoOpCounter::new(),
-            active_users: NoOpGauge::new(),
-            data_ingestion_rate: NoOpCounter::new(),
-            error_total: NoOpCounter::new(),
-            panic_total: NoOpCounter::new(),
-        }
-    }
+/// Counter that records to OpenTelemetry and the Prometheus registry
+pub struct ApmCounter {
+    name: &'static str,
+    otel: Option<Counter<u64>>,
+    prometheus: Option<Arc<PrometheusRegistry>>,
 }
 
-// No-op metric implementations for when APM is disabled
-struct NoOpCounter;
-struct NoOpHistogram;
-struct NoOpGauge;
+/// Histogram that records to OpenTelemetry and the Prometheus registry
+pub struct ApmHistogram {
+    name: &'static str,
+    bounds: &'static [f64],
+    otel: Option<Histogram<f64>>,
+    prometheus: Option<Arc<PrometheusRegistry>>,
+}
 
-impl NoOpCounter {
-    fn new() -> Self {
-        Self
-    }
+/// Latest gauge value of each attribute set
+type GaugeValues = Arc<Mutex<Vec<(Vec<KeyValue>, u64)>>>;
 
-    fn add(&self, _value: u64, _attributes: &[KeyValue]) {
-        // No-op
-    }
+/// Gauge that records to OpenTelemetry and the Prometheus registry
+///
+/// OpenTelemetry 0.21 only has observable gauges, so the latest value of each
+/// attribute set is kept and reported when the meter collects.
+pub struct ApmGauge {
+    name: &'static str,
+    values: GaugeValues,
+    /// Held so the collection callback stays registered
+    _otel: Option<ObservableGauge<u64>>,
+    prometheus: Option<Arc<PrometheusRegistry>>,
 }
 
-impl NoOpHistogram {
-    fn new() -> Self {
-        Self
-    }
-
-    fn record(&self, _value: f64, _attributes: &[KeyValue]) {
-        // No-op
+impl ApmCounter {
+    pub fn add(&self, value: u64, attributes: &[KeyValue]) {
+        if let Some(counter) = &self.otel {
+            counter.add(value, attributes);
+        }
+        if let Some(registry) = &self.prometheus {
+            registry.add_counter(self.name, value, attributes);
+        }
     }
 }
 
-impl NoOpGauge {
-    fn new() -> Self {
-        Self
+impl ApmHistogram {
+    pub fn record(&self, value: f64, attributes: &[KeyValue]) {
+        if let Some(histogram) = &self.otel {
+            histogram.record(value, attributes);
+        }
+        if let Some(registry) = &self.prometheus {
+            registry.observe_histogram(self.name, self.bounds, value, attributes);
+        }
     }
+}
 
-    fn record(&self, _value: u64, _attributes: &[KeyValue]) {
-        // No-op
+impl ApmGauge {
+    pub fn record(&self, value: u64, attributes: &[KeyValue]) {
+        if let Ok(mut values) = self.values.lock() {
+            match values
+                .iter_mut()
+                .find(|(attrs, _)| attrs.as_slice() == attributes)
+            {
+                Some((_, current)) => *current = value,
+                None => values.push((attributes.to_vec(), value)),
+            }
+        }
+        if let Some(registry) = &self.prometheus {
+            registry.set_gauge(self.name, value, attributes);
+        }
     }
 }
 
@@ -408,4 +897,254 @@ mod tests {
             ApmPlatform::OpenTelemetry
         ));
     }
+
+    #[test]
+    fn test_sampling_strategy_builds_matching_sampler() {
+        use opentelemetry_sdk::trace::Sampler;
+
+        assert_eq!(
+            ApmSamplingStrategy::from("parentbased_traceidratio".to_string()),
+            ApmSamplingStrategy::ParentBased
+        );
+        assert_eq!(
+            ApmSamplingStrategy::from("unknown".to_string()),
+            ApmSamplingStrategy::Ratio
+        );
+
+        assert!(matches!(
+            ApmSamplingStrategy::Ratio.sampler(0.25),
+            Sampler::TraceIdRatioBased(rate) if rate == 0.25
+        ));
+        match ApmSamplingStrategy::ParentBased.sampler(0.5) {
+            // The root sampler is a boxed `ShouldSample`, so compare its debug form
+            Sampler::ParentBased(root) => assert_eq!(
+                format!("{:?}", root),
+                format!("{:?}", Sampler::TraceIdRatioBased(0.5))
+            ),
+            other => panic!("expected parent-based sampler, got {:?}", other),
+        }
+        assert!(matches!(
+            ApmSamplingStrategy::from("always_on".to_string()).sampler(0.1),
+            Sampler::AlwaysOn
+        ));
+        assert!(matches!(
+            ApmSamplingStrategy::from("always_off".to_string()).sampler(0.1),
+            Sampler::AlwaysOff
+        ));
+    }
+
+    #[test]
+    fn test_parse_otlp_headers() {
+        let headers =
+            parse_otlp_headers("api-key=abc123, x-team = core ,authorization=Bearer%20tok%3D,")
+                .unwrap();
+
+        assert_eq!(headers.len(), 3);
+        assert_eq!(headers["api-key"], "abc123");
+        assert_eq!(headers["x-team"], "core");
+        assert_eq!(headers["authorization"], "Bearer tok=");
+        assert!(parse_otlp_headers("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_otlp_headers_rejects_malformed_input() {
+        assert!(parse_otlp_headers("api-key").is_err());
+        assert!(parse_otlp_headers("=value").is_err());
+        assert!(parse_otlp_headers("api-key=bad%zz").is_err());
+
+        let headers = HashMap::from([("bad header".to_string(), "v".to_string())]);
+        assert!(otlp_metadata(&headers).is_err());
+    }
+
+    #[test]
+    fn test_insecure_downgrades_https_endpoint() {
+        let mut config = ApmConfig {
+            otlp_endpoint: Some("https://otlp.example.com:4317".to_string()),
+            otlp_insecure: false,
+            ..ApmConfig::default()
+        };
+        assert_eq!(
+            config.resolved_otlp_endpoint(),
+            "https://otlp.example.com:4317"
+        );
+
+        config.otlp_insecure = true;
+        assert_eq!(
+            config.resolved_otlp_endpoint(),
+            "http://otlp.example.com:4317"
+        );
+    }
+
+    #[derive(Clone, Default)]
+    struct CapturedLogs(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl std::io::Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for CapturedLogs {
+        type Writer = Self;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    fn disabled_config() -> ApmConfig {
+        ApmConfig {
+            enabled: false,
+            ..ApmConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_record_error_logs_active_trace_id() {
+        use opentelemetry::trace::TracerProvider as _;
+        use opentelemetry_sdk::trace::TracerProvider;
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let provider = TracerProvider::builder().build();
+        let tracer = provider.tracer("apm-test");
+        let logs = CapturedLogs::default();
+
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(tracer))
+            .with(
+                tracing_subscriber::fmt::layer()
+                    .json()
+                    .with_writer(logs.clone()),
+            );
+
+        let apm = ApmManager::new(disabled_config()).unwrap();
+
+        let expected_trace_id = tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("failing_operation");
+            let _enter = span.enter();
+
+            apm.record_error(&anyhow::anyhow!("boom"), HashMap::new());
+
+            current_trace_ids(&span).map(|(trace_id, _)| trace_id)
+        });
+
+        let expected_trace_id = expected_trace_id.expect("span should carry a trace id");
+        let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+
+        assert!(output.contains("APM recorded error"));
+        assert!(output.contains(&format!("\"trace_id\":\"{}\"", expected_trace_id)));
+    }
+
+    #[test]
+    fn test_current_trace_ids_without_active_span() {
+        assert!(current_trace_ids(&tracing::Span::none()).is_none());
+    }
+
+    #[test]
+    fn test_error_category_inference() {
+        let db_error =
+            anyhow::anyhow!("sqlx error: no rows returned").context("Failed to load anchor");
+        let rpc_error = anyhow::anyhow!("Horizon returned 503");
+        let other_error = anyhow::anyhow!("something odd happened");
+
+        assert_eq!(db_error.category(), ErrorCategory::Database);
+        assert_eq!(rpc_error.category(), ErrorCategory::Rpc);
+        assert_eq!(other_error.category(), ErrorCategory::Internal);
+    }
+
+    #[test]
+    fn test_record_error_counts_by_category() {
+        let apm = ApmManager::new(ApmConfig {
+            prometheus_enabled: true,
+            ..disabled_config()
+        })
+        .unwrap();
+
+        apm.record_categorized_error(
+            ErrorCategory::Database,
+            &anyhow::anyhow!("connection pool exhausted"),
+            HashMap::new(),
+        );
+        apm.record_error(&anyhow::anyhow!("RPC getLedgers failed"), HashMap::new());
+        apm.record_error(&anyhow::anyhow!("Horizon timeout"), HashMap::new());
+
+        let exported = apm.prometheus().unwrap().render();
+        let error_type = std::any::type_name::<anyhow::Error>();
+        assert!(
+            exported.contains(&format!(
+                r#"error_total{{error_category="database",error_type="{}"}} 1"#,
+                error_type
+            )),
+            "{}",
+            exported
+        );
+        assert!(exported.contains(&format!(
+            r#"error_total{{error_category="rpc",error_type="{}"}} 2"#,
+            error_type
+        )));
+        assert!(!exported.contains(r#"error_category="validation""#));
+    }
+
+    #[test]
+    fn test_error_metric_labels_exclude_message() {
+        let messages = [
+            "anchor 4f1c2a not found at 2026-01-01T00:00:00Z",
+            "anchor 9b7e11 not found at 2026-02-02T12:34:56Z",
+        ];
+
+        let label_sets: Vec<Vec<String>> = messages
+            .iter()
+            .map(|message| {
+                let error = anyhow::anyhow!("database: {}", message);
+                error_metric_labels(error.category())
+                    .iter()
+                    .map(|kv| format!("{}={}", kv.key.as_str(), kv.value))
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(label_sets[0], label_sets[1]);
+        for label in &label_sets[0] {
+            assert!(!label.starts_with("error.message"));
+            assert!(messages.iter().all(|m| !label.contains(m)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_slow_query_increments_counter() {
+        let apm = ApmManager::new(ApmConfig {
+            slow_query_threshold: Duration::from_millis(20),
+            ..disabled_config()
+        })
+        .unwrap();
+
+        apm.record_db_query("SELECT", "anchors", Duration::from_millis(1));
+        assert_eq!(apm.slow_query_count(), 0);
+
+        // An artificially slow operation
+        let start = Instant::now();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        apm.record_db_query("SELECT", "corridor_metrics_hourly", start.elapsed());
+        assert_eq!(apm.slow_query_count(), 1);
+
+        apm.record_slow_db_query("UPDATE", "anchors");
+        assert_eq!(apm.slow_query_count(), 2);
+    }
+
+    #[test]
+    fn test_error_sampler_thins_out_above_threshold() {
+        let sampler = ErrorSampler::new(2, 0.25);
+        let recorded = (0..10).filter(|_| sampler.should_record()).count();
+
+        // First two always recorded, then one in every four of the remaining eight
+        assert_eq!(recorded, 4);
+
+        let unsampled = ErrorSampler::new(0, 1.0);
+        assert!((0..10).all(|_| unsampled.should_record()));
+    }
 }
@@ -2,17 +2,17 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use axum::{
-    extract::{Request, State},
-    http::{HeaderMap, StatusCode},
+    extract::{MatchedPath, Request, State},
+    http::HeaderMap,
     middleware::Next,
     response::Response,
 };
 use opentelemetry::global;
-use opentelemetry::trace::{Span, SpanKind, Tracer};
+use opentelemetry::trace::{Span, SpanKind, TraceContextExt, Tracer};
 use opentelemetry::{Context, KeyValue};
 use tracing::{error, info, warn};
 
-use crate::apm::ApmManager;
+use crate::ApmManager;
 
 /// APM middleware for Axum
 pub struct ApmMiddleware {
@@ -24,19 +24,31 @@ impl ApmMiddleware {
         Self { apm }
     }
 
+    /// APM manager the middleware records to
+    pub fn apm(&self) -> &Arc<ApmManager> {
+        &self.apm
+    }
+
     /// Middleware function for HTTP request tracking
     pub async fn track_http_request(
         State(apm): State<Arc<ApmManager>>,
         request: Request,
         next: Next,
     ) -> Response {
-        if !apm.config.enabled {
+        if !apm.config.enabled && apm.prometheus().is_none() {
             return next.run(request).await;
         }
 
         let start_time = Instant::now();
         let method = request.method().to_string();
         let uri = request.uri().to_string();
+        // Metrics are labelled by route template, not the raw URL, so path
+        // parameters and query strings don't create a series per request
+        let route = request
+            .extensions()
+            .get::<MatchedPath>()
+            .map(|path| path.as_str().to_string())
+            .unwrap_or_else(|| "unmatched".to_string());
         let user_agent = request
             .headers()
             .get("user-agent")
@@ -68,7 +80,7 @@ impl ApmMiddleware {
                         bytes as f64,
                         &[
                             KeyValue::new("http.method", method.clone()),
-                            KeyValue::new("http.url", uri.clone()),
+                            KeyValue::new("http.route", route.clone()),
                         ],
                     );
                 }
@@ -91,7 +103,7 @@ impl ApmMiddleware {
             &[
                 KeyValue::new("http.method", method.clone()),
                 KeyValue::new("http.status_code", status_code_value.to_string()),
-                KeyValue::new("http.url", uri.clone()),
+                KeyValue::new("http.route", route.clone()),
             ],
         );
 
@@ -100,7 +112,7 @@ impl ApmMiddleware {
             &[
                 KeyValue::new("http.method", method.clone()),
                 KeyValue::new("http.status_code", status_code_value.to_string()),
-                KeyValue::new("http.url", uri.clone()),
+                KeyValue::new("http.route", route.clone()),
             ],
         );
 
@@ -113,7 +125,7 @@ impl ApmMiddleware {
                         &[
                             KeyValue::new("http.method", method.clone()),
                             KeyValue::new("http.status_code", status_code_value.to_string()),
-                            KeyValue::new("http.url", uri.clone()),
+                            KeyValue::new("http.route", route.clone()),
                         ],
                     );
                 }
@@ -196,31 +208,18 @@ impl ApmMiddleware {
             .with_attributes(vec![KeyValue::new("db.operation", operation.to_string())]);
 
         if let Some(table_name) = table {
-            span_builder = span_builder.with_attributes(vec![KeyValue::new("db.table", table_name.to_string())]);
+            span_builder = span_builder
+                .with_attributes(vec![KeyValue::new("db.table", table_name.to_string())]);
         }
 
         let span = span_builder.start(&tracer);
-        let _cx = Context::current_with_span(span);
+        let cx = Context::current_with_span(span);
 
         let result = f.await;
         let duration = start_time.elapsed();
 
-        // Record metrics
-        apm.metrics().db_queries_total.add(
-            1,
-            &[
-                KeyValue::new("db.operation", operation.to_string()),
-                KeyValue::new("db.table", table.unwrap_or("unknown").to_string()),
-            ],
-        );
-
-        apm.metrics().db_query_duration.record(
-            duration.as_secs_f64(),
-            &[
-                KeyValue::new("db.operation", operation.to_string()),
-                KeyValue::new("db.table", table.unwrap_or("unknown").to_string()),
-            ],
-        );
+        // Record metrics, flagging the query if it was slow
+        apm.record_db_query(operation, table.unwrap_or("unknown"), duration);
 
         match &result {
             Ok(_) => {
@@ -239,7 +238,8 @@ impl ApmMiddleware {
                     error = %e,
                     "Database operation failed"
                 );
-                span.set_status(opentelemetry::trace::Status::error(e.to_string()));
+                cx.span()
+                    .set_status(opentelemetry::trace::Status::error(e.to_string()));
                 apm.record_error(
                     e,
                     std::collections::HashMap::from([
@@ -280,7 +280,7 @@ impl ApmMiddleware {
             ])
             .start(&tracer);
 
-        let _cx = Context::current_with_span(span);
+        let cx = Context::current_with_span(span);
 
         let result = f.await;
         let duration = start_time.elapsed();
@@ -311,7 +311,8 @@ impl ApmMiddleware {
                     error = %e,
                     "Stellar RPC operation failed"
                 );
-                span.set_status(opentelemetry::trace::Status::error(e.to_string()));
+                cx.span()
+                    .set_status(opentelemetry::trace::Status::error(e.to_string()));
                 apm.record_error(
                     e,
                     std::collections::HashMap::from([
@@ -351,7 +352,7 @@ impl ApmMiddleware {
             ])
             .start(&tracer);
 
-        let _cx = Context::current_with_span(span);
+        let cx = Context::current_with_span(span);
 
         let result = f.await;
         let duration = start_time.elapsed();
@@ -373,7 +374,8 @@ impl ApmMiddleware {
                     error = %e,
                     "Background job failed"
                 );
-                span.set_status(opentelemetry::trace::Status::error(e.to_string()));
+                cx.span()
+                    .set_status(opentelemetry::trace::Status::error(e.to_string()));
                 apm.record_error(
                     e,
                     std::collections::HashMap::from([
@@ -468,28 +470,45 @@ macro_rules! track_background_job {
 mod tests {
     use super::*;
     use axum::{body::Body, http::Method, Router};
+    use tower::ServiceExt;
 
     #[tokio::test]
-    async fn test_http_request_tracking() {
-        let config = crate::ApmConfig::default();
+    async fn test_http_request_tracking_labels_by_route() {
+        let config = crate::ApmConfig {
+            enabled: false,
+            prometheus_enabled: true,
+            ..crate::ApmConfig::default()
+        };
         let apm = Arc::new(crate::ApmManager::new(config).unwrap());
 
         let app = Router::new()
+            .route(
+                "/anchors/:id",
+                axum::routing::get(|| async { "Hello, World!" }),
+            )
             .layer(axum::middleware::from_fn_with_state(
                 apm.clone(),
                 crate::middleware::ApmMiddleware::track_http_request,
-            ))
-            .route("/test", axum::routing::get(|| async { "Hello, World!" }));
-
-        // Test request
-        let request = Request::builder()
-            .method(Method::GET)
-            .uri("/test")
-            .header("user-agent", "test-agent")
-            .body(Body::empty())
-            .unwrap();
-
-        // Note: This test may fail if APM is not properly initialized
-        // In production, ensure OTEL_ENABLED=false for testing without APM infrastructure
+            ));
+
+        for id in ["1", "2?verbose=true"] {
+            let request = Request::builder()
+                .method(Method::GET)
+                .uri(format!("/anchors/{}", id))
+                .header("user-agent", "test-agent")
+                .body(Body::empty())
+                .unwrap();
+            app.clone().oneshot(request).await.unwrap();
+        }
+
+        let body = apm.prometheus().unwrap().render();
+        assert!(
+            body.contains(
+                r#"http_requests_total{http_method="GET",http_route="/anchors/:id",http_status_code="200"} 2"#
+            ),
+            "{}",
+            body
+        );
+        assert!(!body.contains("/anchors/1"));
     }
 }
@@ -0,0 +1,336 @@
+//! Prometheus scrape support for APM metrics
+//!
+//! OpenTelemetry instruments do not expose their current values, so every
+//! recording made through [`crate::ApmMetrics`] is mirrored into a
+//! [`PrometheusRegistry`] which renders the text exposition format on
+//! `GET /metrics`.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex};
+
+use axum::{
+    extract::State,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use opentelemetry::KeyValue;
+
+use crate::ApmManager;
+
+/// Content type of the Prometheus text exposition format
+pub const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Bucket bounds for histograms measured in seconds
+pub const SECONDS_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Bucket bounds for histograms measured in bytes
+pub const BYTES_BUCKETS: &[f64] = &[
+    100.0,
+    1_000.0,
+    10_000.0,
+    100_000.0,
+    1_000_000.0,
+    10_000_000.0,
+];
+
+/// Sanitized `(name, value)` label pairs, sorted so equal sets compare equal
+type LabelSet = Vec<(String, String)>;
+
+#[derive(Debug, Clone)]
+struct HistogramState {
+    bounds: &'static [f64],
+    /// Non-cumulative count per bound; rendered cumulatively
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl HistogramState {
+    fn new(bounds: &'static [f64]) -> Self {
+        Self {
+            bounds,
+            counts: vec![0; bounds.len()],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    fn observe(&mut self, value: f64) {
+        if let Some(index) = self.bounds.iter().position(|bound| value <= *bound) {
+            self.counts[index] += 1;
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+}
+
+/// Current values of every APM instrument, keyed by metric name then labels
+#[derive(Debug, Default)]
+pub struct PrometheusRegistry {
+    counters: Mutex<BTreeMap<&'static str, BTreeMap<LabelSet, u64>>>,
+    gauges: Mutex<BTreeMap<&'static str, BTreeMap<LabelSet, u64>>>,
+    histograms: Mutex<BTreeMap<&'static str, BTreeMap<LabelSet, HistogramState>>>,
+}
+
+impl PrometheusRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_counter(&self, name: &'static str, value: u64, attributes: &[KeyValue]) {
+        if let Ok(mut counters) = self.counters.lock() {
+            *counters
+                .entry(name)
+                .or_default()
+                .entry(label_set(attributes))
+                .or_insert(0) += value;
+        }
+    }
+
+    pub fn set_gauge(&self, name: &'static str, value: u64, attributes: &[KeyValue]) {
+        if let Ok(mut gauges) = self.gauges.lock() {
+            gauges
+                .entry(name)
+                .or_default()
+                .insert(label_set(attributes), value);
+        }
+    }
+
+    pub fn observe_histogram(
+        &self,
+        name: &'static str,
+        bounds: &'static [f64],
+        value: f64,
+        attributes: &[KeyValue],
+    ) {
+        if let Ok(mut histograms) = self.histograms.lock() {
+            histograms
+                .entry(name)
+                .or_default()
+                .entry(label_set(attributes))
+                .or_insert_with(|| HistogramState::new(bounds))
+                .observe(value);
+        }
+    }
+
+    /// Render every recorded series in the text exposition format
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        if let Ok(counters) = self.counters.lock() {
+            for (name, series) in counters.iter() {
+                let _ = writeln!(out, "# TYPE {} counter", name);
+                for (labels, value) in series {
+                    let _ = writeln!(out, "{}{} {}", name, format_labels(labels, None), value);
+                }
+            }
+        }
+
+        if let Ok(gauges) = self.gauges.lock() {
+            for (name, series) in gauges.iter() {
+                let _ = writeln!(out, "# TYPE {} gauge", name);
+                for (labels, value) in series {
+                    let _ = writeln!(out, "{}{} {}", name, format_labels(labels, None), value);
+                }
+            }
+        }
+
+        if let Ok(histograms) = self.histograms.lock() {
+            for (name, series) in histograms.iter() {
+                let _ = writeln!(out, "# TYPE {} histogram", name);
+                for (labels, state) in series {
+                    let mut cumulative = 0;
+                    for (bound, count) in state.bounds.iter().zip(&state.counts) {
+                        cumulative += count;
+                        let le = bound.to_string();
+                        let _ = writeln!(
+                            out,
+                            "{}_bucket{} {}",
+                            name,
+                            format_labels(labels, Some(&le)),
+                            cumulative
+                        );
+                    }
+                    let _ = writeln!(
+                        out,
+                        "{}_bucket{} {}",
+                        name,
+                        format_labels(labels, Some("+Inf")),
+                        state.count
+                    );
+                    let _ = writeln!(
+                        out,
+                        "{}_sum{} {}",
+                        name,
+                        format_labels(labels, None),
+                        state.sum
+                    );
+                    let _ = writeln!(
+                        out,
+                        "{}_count{} {}",
+                        name,
+                        format_labels(labels, None),
+                        state.count
+                    );
+                }
+            }
+        }
+
+        out
+    }
+}
+
+/// Prometheus label names only allow `[a-zA-Z0-9_]`, so OpenTelemetry keys
+/// such as `http.method` become `http_method`
+fn sanitize_label_name(key: &str) -> String {
+    key.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn label_set(attributes: &[KeyValue]) -> LabelSet {
+    let mut labels: LabelSet = attributes
+        .iter()
+        .map(|kv| (sanitize_label_name(kv.key.as_str()), kv.value.to_string()))
+        .collect();
+    labels.sort();
+    labels
+}
+
+fn format_labels(labels: &LabelSet, le: Option<&str>) -> String {
+    let mut parts: Vec<String> = labels
+        .iter()
+        .map(|(name, value)| format!("{}=\"{}\"", name, escape_label_value(value)))
+        .collect();
+    if let Some(le) = le {
+        parts.push(format!("le=\"{}\"", le));
+    }
+    if parts.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", parts.join(","))
+    }
+}
+
+/// GET /metrics - APM metrics in Prometheus text format
+///
+/// Responds 404 when `PROMETHEUS_ENABLED` is off.
+pub async fn metrics_handler(State(apm): State<Arc<ApmManager>>) -> Response {
+    match apm.prometheus() {
+        Some(registry) => (
+            StatusCode::OK,
+            [(header::CONTENT_TYPE, CONTENT_TYPE)],
+            registry.render(),
+        )
+            .into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// Router serving the scrape endpoint, mergeable into any application router
+pub fn routes<S>(apm: Arc<ApmManager>) -> Router<S> {
+    Router::new()
+        .route("/metrics", get(metrics_handler))
+        .with_state(apm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ApmConfig;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    fn prometheus_apm() -> Arc<ApmManager> {
+        Arc::new(
+            ApmManager::new(ApmConfig {
+                enabled: false,
+                prometheus_enabled: true,
+                ..ApmConfig::default()
+            })
+            .unwrap(),
+        )
+    }
+
+    async fn scrape(app: Router) -> (StatusCode, String) {
+        let response = app
+            .oneshot(Request::get("/metrics").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_metrics_endpoint_reports_recorded_requests() {
+        let apm = prometheus_apm();
+        let labels = [
+            KeyValue::new("http.method", "GET"),
+            KeyValue::new("http.status_code", "200"),
+        ];
+        apm.metrics().http_requests_total.add(1, &labels);
+        apm.metrics().http_request_duration.record(0.042, &labels);
+
+        let (status, body) = scrape(routes(apm)).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("# TYPE http_requests_total counter"));
+        assert!(
+            body.contains(r#"http_requests_total{http_method="GET",http_status_code="200"} 1"#),
+            "{}",
+            body
+        );
+        assert!(body.contains(
+            r#"http_request_duration_seconds_bucket{http_method="GET",http_status_code="200",le="0.05"} 1"#
+        ));
+        assert!(body.contains(
+            r#"http_request_duration_seconds_count{http_method="GET",http_status_code="200"} 1"#
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_metrics_endpoint_is_not_found_when_disabled() {
+        let apm = Arc::new(
+            ApmManager::new(ApmConfig {
+                enabled: false,
+                prometheus_enabled: false,
+                ..ApmConfig::default()
+            })
+            .unwrap(),
+        );
+
+        let (status, _) = scrape(routes(apm)).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_histogram_buckets_are_cumulative() {
+        let registry = PrometheusRegistry::new();
+        for value in [50.0, 500.0, 5_000.0, 50_000_000.0] {
+            registry.observe_histogram("http_response_size_bytes", BYTES_BUCKETS, value, &[]);
+        }
+
+        let body = registry.render();
+        assert!(body.contains("http_response_size_bytes_bucket{le=\"100\"} 1"));
+        assert!(body.contains("http_response_size_bytes_bucket{le=\"1000\"} 2"));
+        assert!(body.contains("http_response_size_bytes_bucket{le=\"10000000\"} 3"));
+        assert!(body.contains("http_response_size_bytes_bucket{le=\"+Inf\"} 4"));
+        assert!(body.contains("http_response_size_bytes_count 4"));
+    }
+}
@@ -0,0 +1,169 @@
+//! Slow query counting for sqlx
+//!
+//! sqlx logs statements slower than the threshold given to
+//! `log_slow_statements` on the `sqlx::query` target, tagging the event with a
+//! `slow_threshold` field. [`SlowQueryLayer`] watches for those events and
+//! counts each one in `slow_query_total`, so every query on the pool is covered
+//! without wrapping the individual `Database` methods.
+
+use std::fmt;
+use std::sync::Arc;
+
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::Layer;
+
+use crate::ApmManager;
+
+/// Target sqlx logs executed statements under
+pub const SQLX_QUERY_TARGET: &str = "sqlx::query";
+
+/// Tracing layer counting sqlx's slow statement events in the APM metrics
+pub struct SlowQueryLayer {
+    apm: Arc<ApmManager>,
+}
+
+impl SlowQueryLayer {
+    pub fn new(apm: Arc<ApmManager>) -> Self {
+        Self { apm }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for SlowQueryLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if event.metadata().target() != SQLX_QUERY_TARGET {
+            return;
+        }
+
+        let mut statement = StatementVisitor::default();
+        event.record(&mut statement);
+        if !statement.slow {
+            return;
+        }
+
+        // sqlx leaves `db.statement` empty when the summary is the whole query
+        let sql = if statement.sql.trim().is_empty() {
+            &statement.summary
+        } else {
+            &statement.sql
+        };
+        let (operation, table) = statement_operation_and_table(sql);
+        self.apm.record_slow_db_query(&operation, &table);
+    }
+}
+
+/// Fields of a sqlx statement event
+#[derive(Default)]
+struct StatementVisitor {
+    summary: String,
+    sql: String,
+    slow: bool,
+}
+
+impl Visit for StatementVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "summary" => self.summary = value.to_string(),
+            "db.statement" => self.sql = value.to_string(),
+            _ => {}
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        match field.name() {
+            "slow_threshold" => self.slow = true,
+            "summary" => self.summary = format!("{:?}", value),
+            "db.statement" => self.sql = format!("{:?}", value),
+            _ => {}
+        }
+    }
+}
+
+/// Operation keyword and table a SQL statement works on, `unknown` for
+/// whichever can't be told from the text
+fn statement_operation_and_table(sql: &str) -> (String, String) {
+    let words: Vec<&str> = sql.split_whitespace().collect();
+    let operation = words
+        .first()
+        .map_or_else(|| "unknown".to_string(), |word| word.to_ascii_uppercase());
+
+    // The table follows FROM, INTO or UPDATE
+    let table = words
+        .windows(2)
+        .find(|pair| {
+            ["FROM", "INTO", "UPDATE"]
+                .iter()
+                .any(|keyword| pair[0].eq_ignore_ascii_case(keyword))
+        })
+        .map(|pair| {
+            pair[1]
+                .trim_matches(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .to_string()
+        })
+        .filter(|table| !table.is_empty())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    (operation, table)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ApmConfig;
+    use std::time::Duration;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn test_statement_operation_and_table() {
+        assert_eq!(
+            statement_operation_and_table("select * from anchors where id = ?"),
+            ("SELECT".to_string(), "anchors".to_string())
+        );
+        assert_eq!(
+            statement_operation_and_table("INSERT INTO \"payments\" (id) VALUES (?)"),
+            ("INSERT".to_string(), "payments".to_string())
+        );
+        assert_eq!(
+            statement_operation_and_table("PRAGMA foreign_keys = ON"),
+            ("PRAGMA".to_string(), "unknown".to_string())
+        );
+    }
+
+    #[test]
+    fn test_only_slow_sqlx_statements_are_counted() {
+        let apm = Arc::new(
+            ApmManager::new(ApmConfig {
+                enabled: false,
+                ..ApmConfig::default()
+            })
+            .unwrap(),
+        );
+        let subscriber = tracing_subscriber::registry().with(SlowQueryLayer::new(Arc::clone(&apm)));
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::debug!(
+                target: "sqlx::query",
+                summary = "SELECT * FROM anchors",
+                db.statement = "",
+                elapsed_secs = 0.001,
+                "SELECT * FROM anchors"
+            );
+            tracing::warn!(
+                target: "sqlx::query",
+                summary = "SELECT * FROM corridor_metrics_hourly …",
+                db.statement = "SELECT * FROM corridor_metrics_hourly WHERE hour_bucket >= ?",
+                elapsed_secs = 2.0,
+                slow_threshold = ?Duration::from_millis(100),
+                "slow statement: execution time exceeded alert threshold"
+            );
+            tracing::warn!(
+                target: "stellar_insights_backend",
+                slow_threshold = ?Duration::from_millis(100),
+                "not a sqlx statement"
+            );
+        });
+
+        assert_eq!(apm.slow_query_count(), 1);
+    }
+}
@@ -0,0 +1,65 @@
+-- no-transaction
+-- Allow the 'provisional' verification status.
+-- SQLite cannot alter a CHECK constraint, so the table is rebuilt. Foreign keys
+-- are switched off first so dropping the old table does not cascade into
+-- asset_verification_reports and asset_verification_history.
+PRAGMA foreign_keys = OFF;
+
+BEGIN;
+
+CREATE TABLE verified_assets_new (
+    id TEXT PRIMARY KEY,
+    asset_code TEXT NOT NULL,
+    asset_issuer TEXT NOT NULL,
+    verification_status TEXT NOT NULL CHECK (verification_status IN ('verified', 'provisional', 'unverified', 'suspicious')),
+    reputation_score REAL NOT NULL DEFAULT 0.0,
+    
+    -- Verification sources
+    stellar_expert_verified BOOLEAN DEFAULT FALSE,
+    stellar_toml_verified BOOLEAN DEFAULT FALSE,
+    anchor_registry_verified BOOLEAN DEFAULT FALSE,
+    
+    -- Metrics
+    trustline_count INTEGER DEFAULT 0,
+    transaction_count INTEGER DEFAULT 0,
+    total_volume_usd REAL DEFAULT 0.0,
+    
+    -- TOML data
+    toml_home_domain TEXT,
+    toml_name TEXT,
+    toml_description TEXT,
+    toml_org_name TEXT,
+    toml_org_url TEXT,
+    toml_logo_url TEXT,
+    
+    -- Community reports
+    suspicious_reports_count INTEGER DEFAULT 0,
+    last_suspicious_report_at TIMESTAMP,
+    
+    -- Verification metadata
+    last_verified_at TIMESTAMP,
+    verification_notes TEXT,
+    
+    -- Timestamps
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    
+    -- Unique constraint on asset_code and issuer pair
+    UNIQUE(asset_code, asset_issuer)
+);
+
+INSERT INTO verified_assets_new SELECT * FROM verified_assets;
+
+DROP TABLE verified_assets;
+
+ALTER TABLE verified_assets_new RENAME TO verified_assets;
+
+CREATE INDEX IF NOT EXISTS idx_verified_assets_status ON verified_assets(verification_status);
+CREATE INDEX IF NOT EXISTS idx_verified_assets_reputation ON verified_assets(reputation_score DESC);
+CREATE INDEX IF NOT EXISTS idx_verified_assets_asset_code ON verified_assets(asset_code);
+CREATE INDEX IF NOT EXISTS idx_verified_assets_issuer ON verified_assets(asset_issuer);
+CREATE INDEX IF NOT EXISTS idx_verified_assets_updated ON verified_assets(updated_at DESC);
+
+COMMIT;
+
+PRAGMA foreign_keys = ON;
@@ -0,0 +1,7 @@
+-- Client-supplied key so retried create requests return the original
+-- pending transaction instead of inserting a duplicate.
+-- SQLite treats NULLs as distinct, so rows without a key are unaffected.
+ALTER TABLE pending_transactions ADD COLUMN idempotency_key TEXT;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_pending_transactions_idempotency_key
+    ON pending_transactions(idempotency_key);
@@ -0,0 +1,3 @@
+-- Network hash recorded on submission, used to poll RPC getTransaction
+-- until the transaction lands in a ledger or fails.
+ALTER TABLE pending_transactions ADD COLUMN transaction_hash TEXT;
@@ -0,0 +1,3 @@
+-- Flags hourly corridor aggregates whose volume_usd left out payments in an
+-- asset the price feed had no USD price for.
+ALTER TABLE corridor_metrics_hourly ADD COLUMN has_missing_prices BOOLEAN NOT NULL DEFAULT 0;
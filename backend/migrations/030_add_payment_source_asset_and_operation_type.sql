@@ -0,0 +1,7 @@
+-- Source asset and operation type of stored payments, so path payments keep
+-- their corridor when read back. Rows stored before this have NULLs and are
+-- treated as plain payments.
+ALTER TABLE payments ADD COLUMN operation_type TEXT;
+ALTER TABLE payments ADD COLUMN source_asset_type TEXT;
+ALTER TABLE payments ADD COLUMN source_asset_code TEXT;
+ALTER TABLE payments ADD COLUMN source_asset_issuer TEXT;
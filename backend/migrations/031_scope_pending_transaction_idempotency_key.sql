@@ -0,0 +1,7 @@
+-- Idempotency keys are chosen by clients, so two accounts may pick the same
+-- one. Scope uniqueness to the source account so one account's retry never
+-- returns another account's transaction.
+DROP INDEX IF EXISTS idx_pending_transactions_idempotency_key;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_pending_transactions_source_idempotency_key
+    ON pending_transactions(source_account, idempotency_key);
@@ -0,0 +1,4 @@
+-- Keeps the reliability score as computed alongside the stored one, which
+-- may have been smoothed against the anchor's previous score. Rows recorded
+-- before this migration have no raw score.
+ALTER TABLE anchor_metrics_history ADD COLUMN raw_reliability_score REAL;
@@ -1,8 +1,26 @@
 use anyhow::Result;
+use axum::{
+    body::{to_bytes, Body, HttpBody},
+    extract::{MatchedPath, RawPathParams, Request, State},
+    http::Method,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
 use chrono::Utc;
+use serde_json::json;
 use sqlx::SqlitePool;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::warn;
 use uuid::Uuid;
 
+use crate::auth_middleware::AuthUser;
+use crate::database::Database;
+use crate::error::ApiError;
+
+/// Largest response the audit middleware buffers to find a created entity's id
+const MAX_AUDITED_RESPONSE_BYTES: usize = 1024 * 1024;
+
 #[derive(Debug, Clone)]
 pub struct AdminAuditLogEntry {
     pub id: String,
@@ -17,11 +35,38 @@ pub struct AdminAuditLogEntry {
 
 pub struct AdminAuditLogger {
     pool: SqlitePool,
+    /// Held while chaining an entry so two appends can't share a previous hash
+    chain_lock: Mutex<()>,
 }
 
 impl AdminAuditLogger {
     pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            chain_lock: Mutex::new(()),
+        }
+    }
+
+    /// Record an admin action chained to the most recent entry
+    pub async fn append_action(
+        &self,
+        action: &str,
+        resource: &str,
+        user_id: &str,
+        status: &str,
+        details: serde_json::Value,
+    ) -> Result<()> {
+        let _chain = self.chain_lock.lock().await;
+        let prev_hash = self.last_hash().await?;
+        self.log_action(
+            action,
+            resource,
+            user_id,
+            status,
+            details,
+            prev_hash.as_deref(),
+        )
+        .await
     }
 
     /// Record an admin action with tamper-proof hash chaining
@@ -74,4 +119,102 @@ impl AdminAuditLogger {
 
         Ok(())
     }
+
+    /// Hash of the most recent entry, used to chain the next one
+    pub async fn last_hash(&self) -> Result<Option<String>> {
+        let hash = sqlx::query_scalar::<_, String>(
+            "SELECT hash FROM admin_audit_log ORDER BY timestamp DESC LIMIT 1",
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(hash)
+    }
+}
+
+/// Audit every POST/PUT on the routes it wraps.
+///
+/// Must be layered inside `auth_middleware` so the `AuthUser` extension is
+/// already attached. The target entity is the `:id` path parameter when the
+/// route has one, otherwise the `id` field of a successful JSON response (the
+/// newly created entity), read from responses of at most 1 MiB. Audit failures
+/// are logged and never fail the request.
+pub async fn admin_audit_middleware(
+    State(db): State<Arc<Database>>,
+    path_params: RawPathParams,
+    req: Request,
+    next: Next,
+) -> Response {
+    let method = req.method().clone();
+    if method != Method::POST && method != Method::PUT {
+        return next.run(req).await;
+    }
+
+    let Some(user) = req.extensions().get::<AuthUser>().cloned() else {
+        return next.run(req).await;
+    };
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|p| p.as_str().to_string())
+        .unwrap_or_else(|| req.uri().path().to_string());
+    let path_id = path_params
+        .iter()
+        .find(|(name, _)| *name == "id")
+        .map(|(_, value)| value.to_string());
+
+    let response = next.run(req).await;
+    let status = response.status();
+
+    let buffered = response
+        .body()
+        .size_hint()
+        .upper()
+        .is_some_and(|size| size <= MAX_AUDITED_RESPONSE_BYTES as u64);
+    let (response, target_id) = match path_id {
+        Some(id) => (response, Some(id)),
+        None if status.is_success() && buffered => {
+            let (parts, body) = response.into_parts();
+            match to_bytes(body, MAX_AUDITED_RESPONSE_BYTES).await {
+                Ok(bytes) => {
+                    let created_id = serde_json::from_slice::<serde_json::Value>(&bytes)
+                        .ok()
+                        .and_then(|v| v.get("id").and_then(|id| id.as_str()).map(String::from));
+                    (Response::from_parts(parts, Body::from(bytes)), created_id)
+                }
+                // The action has still happened, so it is audited below
+                Err(e) => {
+                    warn!("Failed to buffer response for audit log: {}", e);
+                    let error =
+                        ApiError::internal("RESPONSE_READ_FAILED", "Failed to read response");
+                    (error.into_response(), None)
+                }
+            }
+        }
+        None => (response, None),
+    };
+
+    let outcome = if status.is_success() {
+        "success"
+    } else {
+        "failure"
+    };
+    let details = json!({
+        "method": method.as_str(),
+        "route": route,
+        "target_id": target_id,
+        "http_status": status.as_u16(),
+    });
+
+    if let Err(e) = db
+        .admin_audit_logger
+        .append_action(method.as_str(), &route, &user.user_id, outcome, details)
+        .await
+    {
+        warn!(
+            "Failed to write admin audit log for {} {}: {}",
+            method, route, e
+        );
+    }
+
+    response
 }
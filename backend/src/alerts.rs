@@ -7,6 +7,7 @@ pub enum AlertType {
     SuccessRateDrop,
     LatencyIncrease,
     LiquidityDecrease,
+    HealthScoreDegradation,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -82,6 +83,11 @@ impl AlertManager {
         }
     }
 
+    /// Send an alert raised elsewhere to every subscriber
+    pub fn dispatch(&self, alert: Alert) {
+        let _ = self.tx.send(alert);
+    }
+
     pub fn subscribe(&self) -> broadcast::Receiver<Alert> {
         self.tx.subscribe()
     }
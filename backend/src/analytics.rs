@@ -74,6 +74,48 @@ pub fn compute_anchor_metrics(
     }
 }
 
+/// How a newly computed reliability score is combined with the anchor's
+/// previous one before it is stored
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ReliabilitySmoothing {
+    /// Store each computed score as is
+    #[default]
+    Raw,
+    /// Exponential moving average, where `alpha` in (0, 1] is the weight of
+    /// the new score
+    Ema { alpha: f64 },
+}
+
+impl ReliabilitySmoothing {
+    /// Load from ANCHOR_RELIABILITY_EMA_ALPHA; unset, or outside (0, 1],
+    /// keeps raw scores
+    pub fn from_env() -> Self {
+        let alpha = std::env::var("ANCHOR_RELIABILITY_EMA_ALPHA")
+            .ok()
+            .and_then(|s| s.trim().parse::<f64>().ok());
+        match alpha {
+            Some(alpha) if alpha > 0.0 && alpha <= 1.0 => Self::Ema { alpha },
+            Some(alpha) => {
+                tracing::warn!(
+                    "ANCHOR_RELIABILITY_EMA_ALPHA must be in (0, 1], got {}; using raw scores",
+                    alpha
+                );
+                Self::Raw
+            }
+            None => Self::Raw,
+        }
+    }
+
+    /// Blend `score` with `previous`, the anchor's last stored score when it
+    /// has been measured before
+    pub fn apply(self, previous: Option<f64>, score: f64) -> f64 {
+        match (self, previous) {
+            (Self::Ema { alpha }, Some(previous)) => alpha * score + (1.0 - alpha) * previous,
+            _ => score,
+        }
+    }
+}
+
 /// Calculate settlement time score (0-100)
 /// Lower settlement time = higher score
 fn calculate_settlement_time_score(avg_settlement_time_ms: Option<i32>) -> f64 {
@@ -238,6 +280,33 @@ mod tests {
         assert_eq!(metrics.status, AnchorStatus::Red);
     }
 
+    #[test]
+    fn test_ema_smoothing_reduces_variance() {
+        fn variance(series: &[f64]) -> f64 {
+            let mean = series.iter().sum::<f64>() / series.len() as f64;
+            series.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / series.len() as f64
+        }
+
+        // Reliability of a low-volume anchor flapping between good and bad runs
+        let raw = [95.0, 20.0, 90.0, 35.0, 100.0, 15.0, 85.0, 30.0, 98.0, 25.0];
+        let smoothing = ReliabilitySmoothing::Ema { alpha: 0.3 };
+        let mut previous = None;
+        let smoothed: Vec<f64> = raw
+            .iter()
+            .map(|&score| {
+                let stored = smoothing.apply(previous, score);
+                previous = Some(stored);
+                stored
+            })
+            .collect();
+
+        assert_eq!(smoothed[0], raw[0]);
+        assert!(variance(&smoothed) < variance(&raw));
+
+        let raw_only = ReliabilitySmoothing::Raw;
+        assert_eq!(raw_only.apply(Some(95.0), 20.0), 20.0);
+    }
+
     #[test]
     fn test_settlement_time_score_fast() {
         let score = calculate_settlement_time_score(Some(500));
@@ -0,0 +1,225 @@
+use axum::{
+    extract::{Path, State},
+    routing::get,
+    Json, Router,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+use crate::cache::{keys, CacheManager};
+use crate::cache_middleware::CacheAware;
+use crate::error::{ApiError, ApiResult};
+use crate::models::corridor::Corridor;
+use crate::muxed::is_account_id;
+use crate::rpc::{Payment, StellarRpcClient, Trade};
+use crate::services::price_feed::PriceFeedClient;
+
+/// Account activity changes often, so summaries are only cached briefly
+pub const ACCOUNT_SUMMARY_TTL_SECS: usize = 60;
+
+/// Most recent payments and trades considered for a summary
+const ACTIVITY_LIMIT: u32 = 200;
+
+#[derive(Clone)]
+pub struct AccountSummaryState {
+    pub rpc_client: Arc<StellarRpcClient>,
+    pub cache: Arc<CacheManager>,
+    pub price_feed: Arc<PriceFeedClient>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AccountSummary {
+    pub account_id: String,
+    /// USD value of payments sent by the account; unpriced assets count as zero
+    pub sent_volume_usd: f64,
+    /// USD value of payments received by the account; unpriced assets count as zero
+    pub received_volume_usd: f64,
+    pub payment_count: usize,
+    pub trade_count: usize,
+    /// Distinct corridor keys across the account's payments and trades
+    pub corridors: Vec<String>,
+    pub first_activity: Option<DateTime<Utc>>,
+    pub last_activity: Option<DateTime<Utc>>,
+}
+
+pub fn routes(state: AccountSummaryState) -> Router {
+    Router::new()
+        .route("/api/accounts/:id/summary", get(get_account_summary))
+        .with_state(state)
+}
+
+/// Summarize an account's recent payment and trade activity
+///
+/// **DATA SOURCE: RPC**
+/// - Recent payments and trades for the account, priced via the price feed
+#[utoipa::path(
+    get,
+    path = "/api/accounts/{id}/summary",
+    params(("id" = String, Path, description = "Stellar account ID")),
+    responses(
+        (status = 200, description = "Account activity summary", body = AccountSummary),
+        (status = 400, description = "Invalid account ID"),
+        (status = 502, description = "RPC unavailable")
+    ),
+    tag = "Accounts"
+)]
+pub async fn get_account_summary(
+    State(state): State<AccountSummaryState>,
+    Path(account_id): Path<String>,
+) -> ApiResult<Json<AccountSummary>> {
+    if !is_account_id(&account_id) {
+        let details = HashMap::from([("account_id".to_string(), serde_json::json!(account_id))]);
+        return Err(ApiError::bad_request_with_details(
+            "INVALID_ACCOUNT",
+            "account_id must be a Stellar account ID (G...)",
+            details,
+        ));
+    }
+
+    let summary = <()>::get_or_fetch(
+        &state.cache,
+        &keys::account_summary(&account_id),
+        ACCOUNT_SUMMARY_TTL_SECS,
+        async {
+            let (payments, trades) = tokio::try_join!(
+                state
+                    .rpc_client
+                    .fetch_account_payments(&account_id, ACTIVITY_LIMIT),
+                state
+                    .rpc_client
+                    .fetch_account_trades(&account_id, ACTIVITY_LIMIT, None),
+            )?;
+            let prices = state
+                .price_feed
+                .get_prices(&distinct_payment_assets(&payments))
+                .await;
+            Ok(summarize_account(&account_id, &payments, &trades, &prices))
+        },
+    )
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch activity for account {}: {}", account_id, e);
+        ApiError::upstream("RPC_ERROR", "Failed to fetch account activity")
+    })?;
+
+    Ok(Json(summary))
+}
+
+/// `(code, issuer)` of an asset, with lumens as `("XLM", "native")`
+fn asset_parts(asset_type: &str, code: Option<&str>, issuer: Option<&str>) -> (String, String) {
+    match (asset_type, code, issuer) {
+        ("native", _, _) | (_, None, _) | (_, _, None) => ("XLM".to_string(), "native".to_string()),
+        (_, Some(code), Some(issuer)) => (code.to_string(), issuer.to_string()),
+    }
+}
+
+/// Asset a payment delivered
+fn payment_asset(payment: &Payment) -> (String, String) {
+    asset_parts(
+        &payment.asset_type,
+        payment.get_asset_code().as_deref(),
+        payment.get_asset_issuer().as_deref(),
+    )
+}
+
+/// Corridor a payment moved value along; non-path payments stay in one asset
+fn payment_corridor(payment: &Payment) -> String {
+    let destination = payment_asset(payment);
+    let source = match &payment.source_asset_type {
+        Some(asset_type) => asset_parts(
+            asset_type,
+            payment.source_asset_code.as_deref(),
+            payment.source_asset_issuer.as_deref(),
+        ),
+        None => destination.clone(),
+    };
+    Corridor::new(source.0, source.1, destination.0, destination.1).to_string_key()
+}
+
+fn trade_corridor(trade: &Trade) -> String {
+    let base = asset_parts(
+        &trade.base_asset_type,
+        trade.base_asset_code.as_deref(),
+        trade.base_asset_issuer.as_deref(),
+    );
+    let counter = asset_parts(
+        &trade.counter_asset_type,
+        trade.counter_asset_code.as_deref(),
+        trade.counter_asset_issuer.as_deref(),
+    );
+    Corridor::new(base.0, base.1, counter.0, counter.1).to_string_key()
+}
+
+/// Price feed keys (`CODE:ISSUER`) of the assets the payments delivered
+pub fn distinct_payment_assets(payments: &[Payment]) -> Vec<String> {
+    payments
+        .iter()
+        .map(|payment| {
+            let (code, issuer) = payment_asset(payment);
+            format!("{}:{}", code, issuer)
+        })
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+fn parse_timestamp(timestamp: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// Fold an account's payments and trades into a summary, converting payment
+/// amounts with `prices` keyed by `CODE:ISSUER`
+pub fn summarize_account(
+    account_id: &str,
+    payments: &[Payment],
+    trades: &[Trade],
+    prices: &HashMap<String, f64>,
+) -> AccountSummary {
+    let mut sent_volume_usd = 0.0;
+    let mut received_volume_usd = 0.0;
+    let mut corridors = BTreeSet::new();
+    let mut timestamps = Vec::with_capacity(payments.len() + trades.len());
+
+    for payment in payments {
+        let (code, issuer) = payment_asset(payment);
+        let volume_usd = match (
+            payment.get_amount().parse::<f64>(),
+            prices.get(&format!("{}:{}", code, issuer)),
+        ) {
+            (Ok(amount), Some(price)) => amount * price,
+            _ => 0.0,
+        };
+
+        let sent =
+            payment.source_account == account_id || payment.from.as_deref() == Some(account_id);
+        if sent {
+            sent_volume_usd += volume_usd;
+        } else if payment.get_destination().as_deref() == Some(account_id) {
+            received_volume_usd += volume_usd;
+        }
+
+        corridors.insert(payment_corridor(payment));
+        timestamps.extend(parse_timestamp(&payment.created_at));
+    }
+
+    for trade in trades {
+        corridors.insert(trade_corridor(trade));
+        timestamps.extend(parse_timestamp(&trade.ledger_close_time));
+    }
+
+    AccountSummary {
+        account_id: account_id.to_string(),
+        sent_volume_usd,
+        received_volume_usd,
+        payment_count: payments.len(),
+        trade_count: trades.len(),
+        corridors: corridors.into_iter().collect(),
+        first_activity: timestamps.iter().min().copied(),
+        last_activity: timestamps.iter().max().copied(),
+    }
+}
@@ -0,0 +1,83 @@
+use axum::{extract::State, routing::post, Json, Router};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::cache_invalidation::{tag_pattern, CacheInvalidationService};
+use crate::error::{ApiError, ApiJson, ApiResult};
+
+/// Key prefixes an operator may purge. Other keys sharing the Redis instance
+/// (webhook nonces, rate-limit counters, last-good fallbacks) are off limits.
+const PURGEABLE_PREFIXES: &[&str] = &["anchor:", "corridor:", "dashboard:", "metrics:"];
+
+/// Either a cache family (`corridor`, `anchor`, `dashboard`, `metrics`) or a
+/// key pattern; exactly one must be given
+#[derive(Debug, Deserialize)]
+pub struct InvalidateCacheRequest {
+    pub tag: Option<String>,
+    /// A specific key or Redis glob such as `corridor:detail:*`, within one of
+    /// the purgeable cache families
+    pub pattern: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct InvalidateCacheResponse {
+    pub pattern: String,
+    pub removed: usize,
+}
+
+impl InvalidateCacheRequest {
+    fn resolve_pattern(&self) -> ApiResult<String> {
+        match (self.tag.as_deref(), self.pattern.as_deref()) {
+            (Some(tag), None) => tag_pattern(tag).ok_or_else(|| {
+                ApiError::bad_request(
+                    "UNKNOWN_CACHE_TAG",
+                    format!(
+                        "Unknown cache tag '{}'; expected anchor, corridor, dashboard or metrics",
+                        tag
+                    ),
+                )
+            }),
+            (None, Some(pattern)) => {
+                let pattern = pattern.trim();
+                if !PURGEABLE_PREFIXES
+                    .iter()
+                    .any(|prefix| pattern.starts_with(prefix))
+                {
+                    return Err(ApiError::bad_request(
+                        "INVALID_CACHE_PATTERN",
+                        format!(
+                            "Pattern must start with one of: {}",
+                            PURGEABLE_PREFIXES.join(", ")
+                        ),
+                    ));
+                }
+                Ok(pattern.to_string())
+            }
+            _ => Err(ApiError::bad_request(
+                "INVALID_CACHE_TARGET",
+                "Provide exactly one of 'tag' or 'pattern'",
+            )),
+        }
+    }
+}
+
+/// Handler for POST /api/admin/cache/invalidate - purge caches after a data fix
+pub async fn invalidate_cache(
+    State(invalidation): State<Arc<CacheInvalidationService>>,
+    ApiJson(req): ApiJson<InvalidateCacheRequest>,
+) -> ApiResult<Json<InvalidateCacheResponse>> {
+    let pattern = req.resolve_pattern()?;
+    let removed = invalidation.invalidate_matching(&pattern).await?;
+    tracing::warn!(
+        "Operator cache invalidation removed {} keys matching {}",
+        removed,
+        pattern
+    );
+    Ok(Json(InvalidateCacheResponse { pattern, removed }))
+}
+
+pub fn routes(invalidation: Arc<CacheInvalidationService>) -> Router {
+    Router::new()
+        .route("/api/admin/cache/invalidate", post(invalidate_cache))
+        .with_state(invalidation)
+}
@@ -0,0 +1,17 @@
+use axum::{extract::State, routing::get, Json, Router};
+use std::sync::Arc;
+
+use crate::cache::CacheManager;
+use crate::env_config::AppConfig;
+
+/// Handler for GET /api/admin/config - effective runtime configuration with
+/// secrets redacted
+pub async fn get_effective_config(State(cache): State<Arc<CacheManager>>) -> Json<AppConfig> {
+    Json(AppConfig::resolve(&cache.config))
+}
+
+pub fn routes(cache: Arc<CacheManager>) -> Router {
+    Router::new()
+        .route("/api/admin/config", get(get_effective_config))
+        .with_state(cache)
+}
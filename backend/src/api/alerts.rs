@@ -11,7 +11,7 @@ use uuid::Uuid;
 use crate::{
     auth::Claims,
     database::Database,
-    error::{ApiError, ApiResult},
+    error::{ApiError, ApiJson, ApiResult},
     models::alerts::{CreateAlertRuleRequest, SnoozeAlertRequest, UpdateAlertRuleRequest},
     state::AppState,
 };
@@ -37,7 +37,7 @@ async fn list_rules(State(state): State<AppState>, claims: Claims) -> ApiResult<
 async fn create_rule(
     State(state): State<AppState>,
     claims: Claims,
-    Json(payload): Json<CreateAlertRuleRequest>,
+    ApiJson(payload): ApiJson<CreateAlertRuleRequest>,
 ) -> ApiResult<impl IntoResponse> {
     let rule = state.db.create_alert_rule(&claims.sub, payload).await?;
     Ok((StatusCode::CREATED, Json(rule)))
@@ -47,7 +47,7 @@ async fn update_rule(
     State(state): State<AppState>,
     claims: Claims,
     Path(id): Path<String>,
-    Json(payload): Json<UpdateAlertRuleRequest>,
+    ApiJson(payload): ApiJson<UpdateAlertRuleRequest>,
 ) -> Result<impl IntoResponse> {
     let rule = state
         .db
@@ -98,7 +98,7 @@ async fn snooze_rule_from_history(
     State(state): State<AppState>,
     claims: Claims,
     Path(id): Path<String>,
-    Json(payload): Json<SnoozeAlertRequest>,
+    ApiJson(payload): ApiJson<SnoozeAlertRequest>,
 ) -> Result<impl IntoResponse> {
     // Id passed here is the rule's ID since we are snoozing the rule
     let rule = state
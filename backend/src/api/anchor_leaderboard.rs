@@ -0,0 +1,122 @@
+use axum::{
+    extract::{Query, State},
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::api::asset_leaderboard::window_duration;
+use crate::api::limits::check_limit;
+use crate::api::precision::Rounded;
+use crate::database::{AnchorReliabilityAverage, Database};
+use crate::error::{ApiError, ApiResult};
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AnchorLeaderboardQuery {
+    /// Averaging window: 24h, 7d or 30d (default: 7d)
+    #[serde(default = "default_period")]
+    #[param(example = "7d")]
+    pub period: String,
+    /// Maximum number of anchors to return (default: 10, max: 100)
+    #[serde(default = "default_limit")]
+    #[param(example = 10)]
+    pub limit: i64,
+}
+
+fn default_period() -> String {
+    "7d".to_string()
+}
+
+fn default_limit() -> i64 {
+    10
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AnchorLeaderboardEntry {
+    pub rank: usize,
+    pub anchor_id: String,
+    pub name: String,
+    /// Mean reliability score of the anchor's history within the period
+    pub avg_reliability_score: f64,
+    /// Number of history records averaged
+    pub samples: i64,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AnchorLeaderboardResponse {
+    pub period: String,
+    pub anchors: Vec<AnchorLeaderboardEntry>,
+}
+
+const MAX_LIMIT: i64 = 100;
+
+pub fn routes(db: Arc<Database>) -> Router {
+    Router::new()
+        .route("/api/anchors/leaderboard", get(get_anchor_leaderboard))
+        .with_state(db)
+}
+
+/// Rank anchors by average reliability over a recent period
+///
+/// **DATA SOURCE: DATABASE**
+/// - Anchor metrics history; anchors without history in the period are omitted
+#[utoipa::path(
+    get,
+    path = "/api/anchors/leaderboard",
+    params(AnchorLeaderboardQuery),
+    responses(
+        (status = 200, description = "Anchor reliability leaderboard", body = AnchorLeaderboardResponse),
+        (status = 400, description = "Unsupported period or limit"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Anchors"
+)]
+pub async fn get_anchor_leaderboard(
+    State(db): State<Arc<Database>>,
+    Query(params): Query<AnchorLeaderboardQuery>,
+) -> ApiResult<Json<Rounded<AnchorLeaderboardResponse>>> {
+    let period = window_duration(&params.period).ok_or_else(|| {
+        ApiError::bad_request(
+            "INVALID_PERIOD",
+            format!(
+                "Unsupported period '{}': expected 24h, 7d or 30d",
+                params.period
+            ),
+        )
+    })?;
+    let limit = check_limit(params.limit, MAX_LIMIT)?;
+
+    let averages = db
+        .anchor_reliability_leaderboard(chrono::Utc::now() - period, limit)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to load anchor reliability history: {}", e);
+            ApiError::internal(
+                "DATABASE_ERROR",
+                "Failed to load anchor reliability history",
+            )
+        })?;
+
+    Ok(Json(Rounded(AnchorLeaderboardResponse {
+        period: params.period,
+        anchors: rank_anchors(averages),
+    })))
+}
+
+/// Number the averages, which the query returns best first
+fn rank_anchors(averages: Vec<AnchorReliabilityAverage>) -> Vec<AnchorLeaderboardEntry> {
+    averages
+        .into_iter()
+        .enumerate()
+        .map(|(i, average)| AnchorLeaderboardEntry {
+            rank: i + 1,
+            anchor_id: average.anchor_id,
+            name: average.name,
+            avg_reliability_score: average.avg_reliability_score,
+            samples: average.samples,
+        })
+        .collect()
+}
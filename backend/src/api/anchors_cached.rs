@@ -8,10 +8,13 @@ use serde::{Deserialize, Serialize};
 use std::sync::{Arc, Mutex, OnceLock};
 use utoipa::{IntoParams, ToSchema};
 
+use crate::api::limits::validate_limit;
+use crate::api::precision::Rounded;
 use crate::cache::{keys, CacheManager};
-use crate::cache_middleware::CacheAware;
+use crate::cache_middleware::{CacheAware, CacheBypass};
 use crate::database::Database;
 use crate::error::ApiResult;
+use crate::models::AnchorStatus;
 use crate::rpc::{
     circuit_breaker::{CircuitBreaker, CircuitBreakerConfig},
     error::{with_retry, RetryConfig, RpcError},
@@ -36,7 +39,7 @@ fn default_limit() -> i64 {
     50
 }
 
-fn rpc_circuit_breaker() -> Arc<CircuitBreaker> {
+pub(crate) fn rpc_circuit_breaker() -> Arc<CircuitBreaker> {
     static CIRCUIT_BREAKER: OnceLock<Arc<CircuitBreaker>> = OnceLock::new();
     CIRCUIT_BREAKER
         .get_or_init(|| {
@@ -117,122 +120,128 @@ pub async fn get_anchors(
         Arc<PriceFeedClient>,
     )>,
     Query(params): Query<ListAnchorsQuery>,
+    bypass: CacheBypass,
     headers: HeaderMap,
 ) -> ApiResult<Response> {
+    validate_limit(params.limit)?;
     let cache_key = keys::anchor_list(params.limit, params.offset);
 
-    let response = <()>::get_or_fetch(&cache, &cache_key, cache.config.get_ttl("anchor"), async {
-        // Get anchor metadata from database (names, accounts, etc.)
-        let anchors = db.list_anchors(params.limit, params.offset).await?;
+    let response = <()>::get_or_fetch_with_bypass(
+        &cache,
+        &cache_key,
+        cache.config.get_ttl("anchor"),
+        bypass,
+        async {
+            // Get anchor metadata from database (names, accounts, etc.)
+            let anchors = db.list_anchors(params.limit, params.offset).await?;
 
-        if anchors.is_empty() {
-            return Ok(AnchorsResponse {
-                anchors: vec![],
-                total: 0,
-            });
-        }
+            if anchors.is_empty() {
+                return Ok(AnchorsResponse {
+                    anchors: vec![],
+                    total: 0,
+                });
+            }
 
-        // OPTIMIZATION: Batch fetch all assets for these anchors (1 query instead of N)
-        let anchor_ids: Vec<uuid::Uuid> = anchors
-            .iter()
-            .map(|a| uuid::Uuid::parse_str(&a.id).unwrap_or_else(|_| uuid::Uuid::nil()))
-            .collect();
+            // OPTIMIZATION: Batch fetch all assets for these anchors (1 query instead of N)
+            let anchor_ids: Vec<uuid::Uuid> = anchors
+                .iter()
+                .map(|a| uuid::Uuid::parse_str(&a.id).unwrap_or_else(|_| uuid::Uuid::nil()))
+                .collect();
 
-        let asset_map = db
-            .get_assets_by_anchors(&anchor_ids)
-            .await
-            .unwrap_or_default();
+            let asset_map = db
+                .get_assets_by_anchors(&anchor_ids)
+                .await
+                .unwrap_or_default();
 
-        let circuit_breaker = rpc_circuit_breaker();
-        let mut anchor_responses = Vec::new();
+            let circuit_breaker = rpc_circuit_breaker();
+            let mut anchor_responses = Vec::new();
 
-        // Process anchors with pre-fetched data
-        for anchor in anchors {
-            let anchor_id = uuid::Uuid::parse_str(&anchor.id).unwrap_or_else(|_| uuid::Uuid::nil());
+            // Process anchors with pre-fetched data
+            for anchor in anchors {
+                let anchor_id =
+                    uuid::Uuid::parse_str(&anchor.id).unwrap_or_else(|_| uuid::Uuid::nil());
 
-            // Get pre-fetched assets (no additional query needed)
-            let assets = asset_map.get(&anchor.id).cloned().unwrap_or_default();
+                // Get pre-fetched assets (no additional query needed)
+                let assets = asset_map.get(&anchor.id).cloned().unwrap_or_default();
 
-            // **RPC DATA**: Fetch real-time payment data for this anchor with pagination
-            let payments = match rpc_client
-                .fetch_all_account_payments(&anchor.stellar_account, Some(500))
-                .await
-            {
-                Ok(p) => p,
-                Err(e) => {
-                    tracing::warn!(
-                        "Failed to fetch payments for anchor {}: {}",
-                        anchor.stellar_account,
-                        e
-                    );
-                    vec![]
-                }
-            };
+                // **RPC DATA**: Fetch real-time payment data for this anchor with pagination
+                let payments = match rpc_client
+                    .fetch_all_account_payments(&anchor.stellar_account, Some(500))
+                    .await
+                {
+                    Ok(p) => p,
+                    Err(e) => {
+                        tracing::warn!(
+                            "Failed to fetch payments for anchor {}: {}",
+                            anchor.stellar_account,
+                            e
+                        );
+                        vec![]
+                    }
+                };
 
-            // Calculate metrics from RPC payment data
-            let (total_transactions, successful_transactions, failed_transactions) =
-                if !payments.is_empty() {
-                    let total = payments.len() as i64;
-                    // In Stellar, if a payment appears in the ledger, it was successful
-                    // Failed payments don't appear in the payment stream
-                    let successful = total;
-                    let failed = 0;
-                    (total, successful, failed)
+                // Calculate metrics from RPC payment data
+                let (total_transactions, successful_transactions, failed_transactions) =
+                    if !payments.is_empty() {
+                        let total = payments.len() as i64;
+                        // In Stellar, if a payment appears in the ledger, it was successful
+                        // Failed payments don't appear in the payment stream
+                        let successful = total;
+                        let failed = 0;
+                        (total, successful, failed)
+                    } else {
+                        (
+                            anchor.total_transactions,
+                            anchor.successful_transactions,
+                            anchor.failed_transactions,
+                        )
+                    };
+
+                let failure_rate = if total_transactions > 0 {
+                    (failed_transactions as f64 / total_transactions as f64) * 100.0
                 } else {
-                    (
-                        anchor.total_transactions,
-                        anchor.successful_transactions,
-                        anchor.failed_transactions,
-                    )
+                    0.0
                 };
 
-            let failure_rate = if total_transactions > 0 {
-                (failed_transactions as f64 / total_transactions as f64) * 100.0
-            } else {
-                0.0
-            };
-
-            let reliability_score = if total_transactions > 0 {
-                (successful_transactions as f64 / total_transactions as f64) * 100.0
-            } else {
-                anchor.reliability_score
-            };
+                let reliability_score = if total_transactions > 0 {
+                    (successful_transactions as f64 / total_transactions as f64) * 100.0
+                } else {
+                    anchor.reliability_score
+                };
 
-            let status = if reliability_score >= 99.0 {
-                "green".to_string()
-            } else if reliability_score >= 95.0 {
-                "yellow".to_string()
-            } else {
-                "red".to_string()
-            };
+                let status = AnchorStatus::from_reliability_score(reliability_score)
+                    .as_str()
+                    .to_string();
 
-            let anchor_response = AnchorMetricsResponse {
-                id: anchor.id.to_string(),
-                name: anchor.name,
-                stellar_account: anchor.stellar_account,
-                reliability_score,
-                asset_coverage: assets.len(),
-                failure_rate,
-                total_transactions,
-                successful_transactions,
-                failed_transactions,
-                status,
-            };
+                let anchor_response = AnchorMetricsResponse {
+                    id: anchor.id.to_string(),
+                    name: anchor.name,
+                    stellar_account: anchor.stellar_account,
+                    reliability_score,
+                    asset_coverage: assets.len(),
+                    failure_rate,
+                    total_transactions,
+                    successful_transactions,
+                    failed_transactions,
+                    status,
+                };
 
-            anchor_responses.push(anchor_response);
-        }
+                anchor_responses.push(anchor_response);
+            }
 
-        let total = anchor_responses.len();
+            let total = anchor_responses.len();
 
-        Ok(AnchorsResponse {
-            anchors: anchor_responses,
-            total,
-        })
-    })
+            Ok(AnchorsResponse {
+                anchors: anchor_responses,
+                total,
+            })
+        },
+    )
     .await?;
 
     let ttl = cache.config.get_ttl("anchor");
-    let response = crate::http_cache::cached_json_response(&headers, &cache_key, &response, ttl)?;
+    let response =
+        crate::http_cache::cached_json_response(&headers, &cache_key, &Rounded(&response), ttl)?;
     Ok(response)
 }
 
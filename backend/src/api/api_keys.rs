@@ -9,6 +9,7 @@ use serde_json::json;
 use std::sync::Arc;
 
 use crate::database::Database;
+use crate::error::ApiJson;
 use crate::models::api_key::CreateApiKeyRequest;
 
 fn extract_wallet_address(headers: &HeaderMap) -> Result<String, ApiKeyError> {
@@ -23,7 +24,7 @@ fn extract_wallet_address(headers: &HeaderMap) -> Result<String, ApiKeyError> {
 pub async fn create_api_key(
     State(db): State<Arc<Database>>,
     headers: HeaderMap,
-    Json(req): Json<CreateApiKeyRequest>,
+    ApiJson(req): ApiJson<CreateApiKeyRequest>,
 ) -> Result<Response, ApiKeyError> {
     let wallet_address = extract_wallet_address(&headers)?;
 
@@ -0,0 +1,379 @@
+use axum::{
+    extract::{Query, State},
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::api::limits::check_limit;
+use crate::api::precision::Rounded;
+use crate::database::Database;
+use crate::error::{ApiError, ApiResult};
+use crate::models::TrustlineStat;
+use crate::services::aggregation::HourlyCorridorMetrics;
+use crate::services::trustline_analyzer::TrustlineAnalyzer;
+
+#[derive(Clone)]
+pub struct AssetLeaderboardState {
+    pub db: Arc<Database>,
+    pub trustline_analyzer: Arc<TrustlineAnalyzer>,
+}
+
+/// Metric assets are ranked by
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum LeaderboardBy {
+    /// USD volume of corridors the asset appears in
+    #[default]
+    Volume,
+    /// Trustlines from the latest trustline sync
+    Trustlines,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LeaderboardQuery {
+    /// Ranking metric: volume or trustlines (default: volume)
+    #[serde(default)]
+    pub by: LeaderboardBy,
+    /// Volume window: 24h, 7d or 30d (default: 24h); ignored for trustlines
+    #[serde(default = "default_window")]
+    #[param(example = "24h")]
+    pub window: String,
+    /// Maximum number of assets to return (default: 20, max: 100)
+    #[serde(default = "default_limit")]
+    #[param(example = 20)]
+    pub limit: i64,
+}
+
+fn default_window() -> String {
+    "24h".to_string()
+}
+
+fn default_limit() -> i64 {
+    20
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AssetLeaderboardEntry {
+    pub rank: usize,
+    #[schema(example = "USDC")]
+    pub asset_code: String,
+    pub asset_issuer: String,
+    /// Total USD volume across corridors with this asset as source or destination
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume_usd: Option<f64>,
+    /// Number of corridors contributing to `volume_usd`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub corridor_count: Option<usize>,
+    /// Some contributing volume could not be priced, so `volume_usd` is
+    /// incomplete
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_missing_prices: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_trustlines: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AssetLeaderboardResponse {
+    pub by: LeaderboardBy,
+    /// Volume window, omitted for trustline rankings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window: Option<String>,
+    pub assets: Vec<AssetLeaderboardEntry>,
+}
+
+const MAX_LIMIT: i64 = 100;
+
+pub fn routes(state: AssetLeaderboardState) -> Router {
+    Router::new()
+        .route("/api/assets/leaderboard", get(get_asset_leaderboard))
+        .with_state(state)
+}
+
+/// Rank assets by corridor volume or trustline count
+///
+/// **DATA SOURCE: DATABASE**
+/// - Hourly corridor aggregates, already in USD (`by=volume`)
+/// - Trustline stats from trustline ingestion (`by=trustlines`)
+#[utoipa::path(
+    get,
+    path = "/api/assets/leaderboard",
+    params(LeaderboardQuery),
+    responses(
+        (status = 200, description = "Asset leaderboard", body = AssetLeaderboardResponse),
+        (status = 400, description = "Unsupported window"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Assets"
+)]
+pub async fn get_asset_leaderboard(
+    State(state): State<AssetLeaderboardState>,
+    Query(params): Query<LeaderboardQuery>,
+) -> ApiResult<Json<Rounded<AssetLeaderboardResponse>>> {
+    let limit = check_limit(params.limit, MAX_LIMIT)? as usize;
+
+    let response = match params.by {
+        LeaderboardBy::Volume => {
+            let window = window_duration(&params.window).ok_or_else(|| {
+                ApiError::bad_request(
+                    "INVALID_WINDOW",
+                    format!(
+                        "Unsupported window '{}': expected 24h, 7d or 30d",
+                        params.window
+                    ),
+                )
+            })?;
+            let end = chrono::Utc::now();
+            let metrics = state
+                .db
+                .fetch_hourly_metrics_by_timerange(end - window, end)
+                .await
+                .map_err(|e| {
+                    tracing::error!("Failed to load corridor aggregates: {}", e);
+                    ApiError::internal("DATABASE_ERROR", "Failed to load corridor aggregates")
+                })?;
+
+            AssetLeaderboardResponse {
+                by: LeaderboardBy::Volume,
+                window: Some(params.window),
+                assets: rank_by_volume(&metrics, limit),
+            }
+        }
+        LeaderboardBy::Trustlines => {
+            let stats = state
+                .trustline_analyzer
+                .get_trustline_rankings(limit as i64)
+                .await
+                .map_err(|e| {
+                    tracing::error!("Failed to load trustline rankings: {}", e);
+                    ApiError::internal("DATABASE_ERROR", "Failed to load trustline rankings")
+                })?;
+
+            AssetLeaderboardResponse {
+                by: LeaderboardBy::Trustlines,
+                window: None,
+                assets: rank_by_trustlines(stats, limit),
+            }
+        }
+    };
+
+    Ok(Json(Rounded(response)))
+}
+
+/// Length of a `24h`, `7d` or `30d` window
+pub(crate) fn window_duration(window: &str) -> Option<chrono::Duration> {
+    match window {
+        "24h" => Some(chrono::Duration::hours(24)),
+        "7d" => Some(chrono::Duration::days(7)),
+        "30d" => Some(chrono::Duration::days(30)),
+        _ => None,
+    }
+}
+
+/// Price feed key (`CODE:ISSUER`, `XLM:native` for lumens)
+fn asset_key(code: &str, issuer: &str) -> String {
+    if issuer.is_empty() || issuer == "native" {
+        "XLM:native".to_string()
+    } else {
+        format!("{}:{}", code, issuer)
+    }
+}
+
+/// Credit each corridor's USD volume to both of its assets and rank.
+///
+/// Aggregates are converted to USD when they are computed; buckets flagged
+/// with missing prices carry the unpriced amounts and mark the asset's total
+/// as incomplete.
+fn rank_by_volume(metrics: &[HourlyCorridorMetrics], limit: usize) -> Vec<AssetLeaderboardEntry> {
+    // asset key -> (code, issuer, volume, corridors, missing prices)
+    let mut totals: HashMap<String, (String, String, f64, Vec<&str>, bool)> = HashMap::new();
+
+    for metric in metrics {
+        let mut sides = vec![(&metric.asset_a_code, &metric.asset_a_issuer)];
+        if asset_key(&metric.asset_a_code, &metric.asset_a_issuer)
+            != asset_key(&metric.asset_b_code, &metric.asset_b_issuer)
+        {
+            sides.push((&metric.asset_b_code, &metric.asset_b_issuer));
+        }
+
+        for (code, issuer) in sides {
+            let entry = totals
+                .entry(asset_key(code, issuer))
+                .or_insert_with(|| (code.clone(), issuer.clone(), 0.0, Vec::new(), false));
+            entry.2 += metric.volume_usd;
+            entry.4 |= metric.has_missing_prices;
+            if !entry.3.contains(&metric.corridor_key.as_str()) {
+                entry.3.push(&metric.corridor_key);
+            }
+        }
+    }
+
+    let mut ranked: Vec<_> = totals.into_values().collect();
+    ranked.sort_by(|a, b| b.2.total_cmp(&a.2).then_with(|| a.0.cmp(&b.0)));
+
+    ranked
+        .into_iter()
+        .take(limit)
+        .enumerate()
+        .map(
+            |(i, (asset_code, asset_issuer, volume_usd, corridors, has_missing_prices))| {
+                AssetLeaderboardEntry {
+                    rank: i + 1,
+                    asset_code,
+                    asset_issuer,
+                    volume_usd: Some(volume_usd),
+                    corridor_count: Some(corridors.len()),
+                    has_missing_prices: Some(has_missing_prices),
+                    total_trustlines: None,
+                }
+            },
+        )
+        .collect()
+}
+
+fn rank_by_trustlines(mut stats: Vec<TrustlineStat>, limit: usize) -> Vec<AssetLeaderboardEntry> {
+    stats.sort_by(|a, b| {
+        b.total_trustlines
+            .cmp(&a.total_trustlines)
+            .then_with(|| a.asset_code.cmp(&b.asset_code))
+    });
+
+    stats
+        .into_iter()
+        .take(limit)
+        .enumerate()
+        .map(|(i, stat)| AssetLeaderboardEntry {
+            rank: i + 1,
+            asset_code: stat.asset_code,
+            asset_issuer: stat.asset_issuer,
+            volume_usd: None,
+            corridor_count: None,
+            has_missing_prices: None,
+            total_trustlines: Some(stat.total_trustlines),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    const USDC_ISSUER: &str = "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+    const EURC_ISSUER: &str = "GDHU6WRG4IEQXM5NZ4BMPKOXHW76MZM4Y2IEMFDVXBSDP6SJY4ITNPP2";
+
+    fn corridor(a: (&str, &str), b: (&str, &str), volume: f64) -> HourlyCorridorMetrics {
+        HourlyCorridorMetrics {
+            id: uuid::Uuid::new_v4().to_string(),
+            corridor_key: format!("{}:{}->{}:{}", a.0, a.1, b.0, b.1),
+            asset_a_code: a.0.to_string(),
+            asset_a_issuer: a.1.to_string(),
+            asset_b_code: b.0.to_string(),
+            asset_b_issuer: b.1.to_string(),
+            hour_bucket: Utc::now(),
+            total_transactions: 10,
+            successful_transactions: 10,
+            failed_transactions: 0,
+            success_rate: 100.0,
+            volume_usd: volume,
+            avg_slippage_bps: 0.0,
+            avg_settlement_latency_ms: None,
+            liquidity_depth_usd: 0.0,
+            has_missing_prices: false,
+        }
+    }
+
+    #[test]
+    fn test_rank_assets_by_volume() {
+        let xlm = ("XLM", "native");
+        let usdc = ("USDC", USDC_ISSUER);
+        let eurc = ("EURC", EURC_ISSUER);
+        let mut unpriced = corridor(eurc, eurc, 500.0);
+        unpriced.has_missing_prices = true;
+        let metrics = vec![
+            // $1,000 for XLM and USDC
+            corridor(xlm, usdc, 1_000.0),
+            // $5,000 for USDC and EURC, over two hourly buckets
+            corridor(usdc, eurc, 2_000.0),
+            corridor(usdc, eurc, 3_000.0),
+            // Same-asset corridor counts once
+            unpriced,
+        ];
+
+        let ranked = rank_by_volume(&metrics, 10);
+
+        let summary: Vec<_> = ranked
+            .iter()
+            .map(|e| {
+                (
+                    e.rank,
+                    e.asset_code.as_str(),
+                    e.volume_usd.unwrap(),
+                    e.corridor_count.unwrap(),
+                    e.has_missing_prices.unwrap(),
+                )
+            })
+            .collect();
+        // Aggregated volumes are already in USD and are not priced again
+        assert_eq!(
+            summary,
+            vec![
+                (1, "USDC", 6_000.0, 2, false),
+                (2, "EURC", 5_500.0, 2, true),
+                (3, "XLM", 1_000.0, 1, false),
+            ]
+        );
+        assert!(ranked.iter().all(|e| e.total_trustlines.is_none()));
+    }
+
+    #[test]
+    fn test_rank_by_volume_respects_limit() {
+        let metrics = vec![corridor(("XLM", "native"), ("USDC", USDC_ISSUER), 100.0)];
+
+        let ranked = rank_by_volume(&metrics, 1);
+
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].rank, 1);
+    }
+
+    #[test]
+    fn test_rank_assets_by_trustlines() {
+        let stat = |code: &str, trustlines: i64| TrustlineStat {
+            asset_code: code.to_string(),
+            asset_issuer: USDC_ISSUER.to_string(),
+            total_trustlines: trustlines,
+            authorized_trustlines: trustlines,
+            unauthorized_trustlines: 0,
+            total_supply: 0.0,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        let ranked = rank_by_trustlines(
+            vec![
+                stat("AQUA", 1_200),
+                stat("USDC", 45_000),
+                stat("yXLM", 8_000),
+            ],
+            2,
+        );
+
+        let summary: Vec<_> = ranked
+            .iter()
+            .map(|e| (e.rank, e.asset_code.as_str(), e.total_trustlines.unwrap()))
+            .collect();
+        assert_eq!(summary, vec![(1, "USDC", 45_000), (2, "yXLM", 8_000)]);
+        assert!(ranked.iter().all(|e| e.volume_usd.is_none()));
+    }
+
+    #[test]
+    fn test_window_parsing() {
+        assert_eq!(window_duration("24h"), Some(chrono::Duration::hours(24)));
+        assert_eq!(window_duration("30d"), Some(chrono::Duration::days(30)));
+        assert_eq!(window_duration("1y"), None);
+    }
+}
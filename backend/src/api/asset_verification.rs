@@ -1,28 +1,81 @@
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
-    response::IntoResponse,
+    response::{IntoResponse, Response},
     routing::{get, post},
     Json, Router,
 };
+use futures::future::join_all;
 use serde_json::json;
 use sqlx::SqlitePool;
+use std::collections::HashMap;
 use std::sync::Arc;
+use tokio::sync::Semaphore;
 use uuid::Uuid;
 
+use crate::error::{ApiError, ApiJson};
 use crate::models::asset_verification::{
+    BatchVerifyAssetItem, BatchVerifyAssetResult, BatchVerifyAssetsResponse,
     ListVerifiedAssetsQuery, ReportAssetRequest, VerifiedAssetResponse,
 };
+use crate::rate_limit::RateLimiter;
 use crate::services::asset_verifier::AssetVerifier;
 
-/// Create asset verification routes
-pub fn routes(pool: SqlitePool) -> Router {
+/// On-demand revalidations allowed per asset within `REVALIDATION_WINDOW_SECS`
+pub const REVALIDATIONS_PER_WINDOW: u32 = 1;
+pub const REVALIDATION_WINDOW_SECS: u32 = 3600;
+/// Most assets accepted by one batch verification request
+pub const MAX_BATCH_VERIFY_ASSETS: usize = 25;
+/// Verifications a batch runs at once, keeping the external sources within
+/// their rate limits
+pub const BATCH_VERIFY_CONCURRENCY: usize = 4;
+
+#[derive(Clone)]
+pub struct RevalidationState {
+    pub pool: SqlitePool,
+    pub rate_limiter: Arc<RateLimiter>,
+}
+
+#[derive(Clone)]
+pub struct BatchVerifyState {
+    pub verifier: Arc<AssetVerifier>,
+    /// Shared by all batch requests, so together they run at most
+    /// `BATCH_VERIFY_CONCURRENCY` verifications
+    pub permits: Arc<Semaphore>,
+}
+
+/// Create asset verification routes; batches verify through `verifier`
+pub fn routes(
+    pool: SqlitePool,
+    rate_limiter: Arc<RateLimiter>,
+    verifier: Arc<AssetVerifier>,
+) -> Router {
+    let revalidation = Router::new()
+        .route("/:code/:issuer/revalidate", post(revalidate_asset))
+        .with_state(RevalidationState {
+            pool: pool.clone(),
+            rate_limiter,
+        });
+    let batch = Router::new()
+        .route("/verify-batch", post(verify_assets_batch))
+        .with_state(BatchVerifyState {
+            verifier,
+            permits: Arc::new(Semaphore::new(BATCH_VERIFY_CONCURRENCY)),
+        });
+
     Router::new()
         .route("/verify/:code/:issuer", get(verify_asset))
         .route("/:code/:issuer/verification", get(get_verification))
         .route("/verified", get(list_verified_assets))
         .route("/report", post(report_suspicious_asset))
         .with_state(Arc::new(pool))
+        .merge(revalidation)
+        .merge(batch)
+}
+
+/// Rate limiter key for on-demand revalidation of one asset
+pub fn revalidation_rate_limit_key(code: &str, issuer: &str) -> String {
+    format!("asset_revalidation:{}:{}", code, issuer)
 }
 
 /// Verify an asset and return its verification status
@@ -64,7 +117,9 @@ async fn verify_asset(
             )
         })?;
 
-    match verifier.verify_asset(&code, &issuer).await {
+    // Serve the stored result; re-checking a known asset goes through the
+    // throttled revalidate endpoint
+    match verifier.get_or_revalidate(&code, &issuer).await {
         Ok(asset) => {
             let response: VerifiedAssetResponse = asset.into();
             Ok((StatusCode::OK, Json(response)))
@@ -75,13 +130,85 @@ async fn verify_asset(
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(json!({
                     "error": "Verification failed",
-                    "message": format!("Failed to verify asset: {}", e)
+                    "message": "Failed to verify asset"
                 })),
             ))
         }
     }
 }
 
+/// Verify several assets in one request, returning a result or error per asset
+/// in request order
+/// POST /api/assets/verify-batch
+///
+/// Stored results are served as-is. Unknown assets are verified concurrently,
+/// at most `BATCH_VERIFY_CONCURRENCY` at a time across all requests, and each
+/// external lookup keeps the verifier's retry backoff.
+async fn verify_assets_batch(
+    State(state): State<BatchVerifyState>,
+    ApiJson(assets): ApiJson<Vec<BatchVerifyAssetItem>>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    if assets.is_empty() || assets.len() > MAX_BATCH_VERIFY_ASSETS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(json!({
+                "error": "Invalid batch size",
+                "message": format!("A batch must contain 1-{} assets", MAX_BATCH_VERIFY_ASSETS)
+            })),
+        ));
+    }
+
+    let (verifier, permits) = (state.verifier.as_ref(), state.permits.as_ref());
+
+    let results = join_all(assets.into_iter().map(|asset| async move {
+        let (result, error) = match verify_batch_item(verifier, permits, &asset).await {
+            Ok(response) => (Some(response), None),
+            Err(message) => (None, Some(message)),
+        };
+        BatchVerifyAssetResult {
+            code: asset.code,
+            issuer: asset.issuer,
+            result,
+            error,
+        }
+    }))
+    .await;
+
+    Ok((StatusCode::OK, Json(BatchVerifyAssetsResponse { results })))
+}
+
+/// Validate and verify one asset of a batch once a permit is free
+async fn verify_batch_item(
+    verifier: &AssetVerifier,
+    permits: &Semaphore,
+    asset: &BatchVerifyAssetItem,
+) -> Result<VerifiedAssetResponse, String> {
+    if asset.code.is_empty() || asset.code.len() > 12 {
+        return Err("Asset code must be 1-12 characters".to_string());
+    }
+    if !is_valid_stellar_public_key(&asset.issuer) {
+        return Err("Issuer must be a valid Stellar public key".to_string());
+    }
+
+    let _permit = permits
+        .acquire()
+        .await
+        .map_err(|_| "Verification cancelled".to_string())?;
+    verifier
+        .get_or_revalidate(&asset.code, &asset.issuer)
+        .await
+        .map(VerifiedAssetResponse::from)
+        .map_err(|e| {
+            tracing::error!(
+                "Asset verification failed for {}:{}: {}",
+                asset.code,
+                asset.issuer,
+                e
+            );
+            "Failed to verify asset".to_string()
+        })
+}
+
 /// Get verification details for an asset
 /// GET /api/assets/:code/:issuer/verification
 async fn get_verification(
@@ -216,7 +343,7 @@ async fn list_verified_assets(
 /// POST /api/assets/report
 async fn report_suspicious_asset(
     State(pool): State<Arc<SqlitePool>>,
-    Json(request): Json<ReportAssetRequest>,
+    ApiJson(request): ApiJson<ReportAssetRequest>,
 ) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
     // Input validation
     if request.asset_code.is_empty() || request.asset_code.len() > 12 {
@@ -333,6 +460,94 @@ async fn report_suspicious_asset(
     }
 }
 
+/// Force re-verification of an asset, e.g. after its stellar.toml changed
+/// POST /api/assets/:code/:issuer/revalidate
+async fn revalidate_asset(
+    State(state): State<RevalidationState>,
+    Path((code, issuer)): Path<(String, String)>,
+) -> Result<impl IntoResponse, Response> {
+    // Input validation
+    if code.is_empty() || code.len() > 12 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(json!({
+                "error": "Invalid asset code",
+                "message": "Asset code must be 1-12 characters"
+            })),
+        )
+            .into_response());
+    }
+
+    if !is_valid_stellar_public_key(&issuer) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(json!({
+                "error": "Invalid issuer",
+                "message": "Issuer must be a valid Stellar public key"
+            })),
+        )
+            .into_response());
+    }
+
+    // Failed attempts count too, so the external sources cannot be hammered
+    // through this endpoint
+    let (allowed, info) = state
+        .rate_limiter
+        .check_keyed_limit(
+            &revalidation_rate_limit_key(&code, &issuer),
+            REVALIDATIONS_PER_WINDOW,
+            REVALIDATION_WINDOW_SECS,
+        )
+        .await;
+    if !allowed {
+        let next_allowed_at =
+            chrono::Utc::now() + chrono::Duration::seconds(i64::from(info.reset_after));
+        let details = HashMap::from([
+            ("retry_after".to_string(), json!(info.reset_after)),
+            (
+                "next_allowed_at".to_string(),
+                json!(next_allowed_at.to_rfc3339()),
+            ),
+        ]);
+        return Err(ApiError::rate_limited(
+            "REVALIDATION_RATE_LIMITED",
+            "This asset was revalidated recently",
+        )
+        .with_details(details)
+        .into_response());
+    }
+
+    let verifier = AssetVerifier::new(state.pool.clone()).map_err(|e| {
+        tracing::error!("Failed to create asset verifier: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({
+                "error": "Internal server error",
+                "message": "Failed to initialize verification service"
+            })),
+        )
+            .into_response()
+    })?;
+
+    match verifier.revalidate(&code, &issuer).await {
+        Ok(asset) => {
+            let response: VerifiedAssetResponse = asset.into();
+            Ok((StatusCode::OK, Json(response)))
+        }
+        Err(e) => {
+            tracing::error!("Asset revalidation failed: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({
+                    "error": "Revalidation failed",
+                    "message": format!("Failed to revalidate asset: {}", e)
+                })),
+            )
+                .into_response())
+        }
+    }
+}
+
 /// Validate Stellar public key format
 fn is_valid_stellar_public_key(key: &str) -> bool {
     key.len() == 56 && key.starts_with('G')
@@ -359,6 +574,51 @@ mod tests {
         )); // Secret key
     }
 
+    #[tokio::test]
+    async fn test_revalidate_is_throttled_per_asset() {
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::util::ServiceExt;
+
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        let rate_limiter = Arc::new(RateLimiter::new().await.unwrap());
+        // Unique issuer so a shared Redis from an earlier run cannot interfere
+        let suffix = Uuid::new_v4().simple().to_string().to_uppercase();
+        let issuer = format!("G{:A<55}", suffix);
+
+        // Use up this asset's slot for the window
+        let key = revalidation_rate_limit_key("USDC", &issuer);
+        let (allowed, _) = rate_limiter
+            .check_keyed_limit(&key, REVALIDATIONS_PER_WINDOW, REVALIDATION_WINDOW_SECS)
+            .await;
+        assert!(allowed);
+
+        let response = routes(pool, rate_limiter)
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/USDC/{}/revalidate", issuer))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["error"]["category"], "rate_limited");
+        let details = &json["error"]["details"];
+        let retry_after = details["retry_after"].as_u64().unwrap();
+        assert!(retry_after > 0 && retry_after <= u64::from(REVALIDATION_WINDOW_SECS));
+        assert!(
+            chrono::DateTime::parse_from_rfc3339(details["next_allowed_at"].as_str().unwrap())
+                .is_ok()
+        );
+    }
+
     #[test]
     fn test_is_valid_url() {
         assert!(is_valid_url("https://example.com"));
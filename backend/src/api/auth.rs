@@ -9,12 +9,12 @@ use serde_json::json;
 use std::sync::Arc;
 
 use crate::auth::{AuthService, LoginRequest, LogoutRequest, RefreshTokenRequest};
-use crate::error::ApiError;
+use crate::error::{ApiError, ApiJson};
 
 /// POST /api/auth/login - User login
 pub async fn login(
     State(auth_service): State<Arc<AuthService>>,
-    Json(request): Json<LoginRequest>,
+    ApiJson(request): ApiJson<LoginRequest>,
 ) -> Result<Response, ApiError> {
     let response = auth_service.login(request).await.map_err(|_| {
         ApiError::unauthorized("INVALID_CREDENTIALS", "Invalid username or password")
@@ -26,7 +26,7 @@ pub async fn login(
 /// POST /api/auth/refresh - Refresh access token
 pub async fn refresh(
     State(auth_service): State<Arc<AuthService>>,
-    Json(request): Json<RefreshTokenRequest>,
+    ApiJson(request): ApiJson<RefreshTokenRequest>,
 ) -> Result<Response, ApiError> {
     let response = auth_service
         .refresh(request)
@@ -39,7 +39,7 @@ pub async fn refresh(
 /// POST /api/auth/logout - Logout user
 pub async fn logout(
     State(auth_service): State<Arc<AuthService>>,
-    Json(request): Json<LogoutRequest>,
+    ApiJson(request): ApiJson<LogoutRequest>,
 ) -> Result<Response, ApiError> {
     auth_service
         .logout(request)
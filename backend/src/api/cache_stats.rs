@@ -8,7 +8,7 @@ use axum::{
 use serde::Serialize;
 use std::sync::Arc;
 
-use crate::cache::{CacheManager, CacheStats};
+use crate::cache::{CacheManager, CacheStats, RedisInfo};
 
 #[derive(Serialize)]
 pub struct CacheStatsResponse {
@@ -16,7 +16,27 @@ pub struct CacheStatsResponse {
     pub misses: u64,
     pub invalidations: u64,
     pub hit_rate_percent: f64,
+    /// Hits over total lookups, between 0 and 1
+    pub hit_ratio: f64,
     pub total_requests: u64,
+    /// Entries held by the in-memory fallback
+    pub memory_entries: usize,
+    /// Entries the in-memory fallback evicted because it was full
+    pub memory_evictions: u64,
+    /// Present while Redis is connected
+    pub redis: Option<RedisInfo>,
+}
+
+impl CacheStatsResponse {
+    /// Counters plus the current state of whichever backend is serving
+    pub async fn collect(cache: &CacheManager) -> Self {
+        Self {
+            memory_entries: cache.memory_entries(),
+            memory_evictions: cache.memory_evictions(),
+            redis: cache.redis_info().await,
+            ..Self::from(cache.get_stats())
+        }
+    }
 }
 
 impl From<CacheStats> for CacheStatsResponse {
@@ -27,7 +47,11 @@ impl From<CacheStats> for CacheStatsResponse {
             misses: stats.misses,
             invalidations: stats.invalidations,
             hit_rate_percent: stats.hit_rate(),
+            hit_ratio: stats.hit_ratio(),
             total_requests,
+            memory_entries: 0,
+            memory_evictions: 0,
+            redis: None,
         }
     }
 }
@@ -37,8 +61,7 @@ pub async fn get_cache_stats(
     State(cache): State<Arc<CacheManager>>,
     headers: HeaderMap,
 ) -> Response {
-    let stats = cache.get_stats();
-    let response = CacheStatsResponse::from(stats);
+    let response = CacheStatsResponse::collect(&cache).await;
 
     match crate::http_cache::cached_json_response(&headers, "cache:stats", &response, 30) {
         Ok(resp) => resp,
@@ -83,6 +106,7 @@ mod tests {
         assert_eq!(response.misses, 20);
         assert_eq!(response.invalidations, 5);
         assert_eq!(response.hit_rate_percent, 80.0);
+        assert_eq!(response.hit_ratio, 0.8);
         assert_eq!(response.total_requests, 100);
     }
 
@@ -96,6 +120,7 @@ mod tests {
 
         let response = CacheStatsResponse::from(stats);
         assert_eq!(response.hit_rate_percent, 0.0);
+        assert_eq!(response.hit_ratio, 0.0);
         assert_eq!(response.total_requests, 0);
     }
 }
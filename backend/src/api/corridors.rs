@@ -6,6 +6,8 @@ use chrono::{Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::api::limits::validate_limit;
+use crate::api::precision::Rounded;
 use crate::error::{ApiError, ApiResult};
 use crate::models::corridor::{Corridor, CorridorMetrics};
 use crate::models::SortBy;
@@ -123,7 +125,8 @@ fn get_liquidity_trend(volume_usd: f64) -> String {
 pub async fn list_corridors(
     State(app_state): State<AppState>,
     Query(params): Query<ListCorridorsQuery>,
-) -> ApiResult<Json<Vec<CorridorResponse>>> {
+) -> ApiResult<Json<Rounded<Vec<CorridorResponse>>>> {
+    validate_limit(params.limit)?;
     let today = Utc::now().date_naive();
 
     // Determine date range based on time_period
@@ -257,14 +260,14 @@ pub async fn list_corridors(
         })
         .collect();
 
-    Ok(Json(corridors))
+    Ok(Json(Rounded(corridors)))
 }
 
 /// GET /api/corridors/:corridor_key - Get detailed corridor information
 pub async fn get_corridor_detail(
     State(app_state): State<AppState>,
     Path(corridor_key): Path<String>,
-) -> ApiResult<Json<CorridorDetailResponse>> {
+) -> ApiResult<Json<Rounded<CorridorDetailResponse>>> {
     let parts: Vec<&str> = corridor_key.split("->").collect();
     if parts.len() != 2 {
         return Err(ApiError::bad_request(
@@ -434,13 +437,13 @@ pub async fn get_corridor_detail(
         })
         .collect();
 
-    Ok(Json(CorridorDetailResponse {
+    Ok(Json(Rounded(CorridorDetailResponse {
         corridor: corridor_response,
         historical_success_rate,
         latency_distribution,
         liquidity_trends,
         related_corridors: Some(related_corridors),
-    }))
+    })))
 }
 
 #[cfg(test)]
@@ -1,23 +1,28 @@
 use axum::{
-    extract::{Path, Query, State},
-    http::HeaderMap,
+    extract::{Extension, Path, Query, State},
+    http::{header, HeaderMap, HeaderValue},
     response::Response,
     Json,
 };
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, Mutex, OnceLock};
 use utoipa::{IntoParams, ToSchema};
 
+use crate::api::asset_leaderboard::window_duration;
+use crate::api::limits::validate_limit;
+use crate::api::precision::Rounded;
 use crate::cache::{keys, CacheManager};
-use crate::cache_middleware::CacheAware;
+use crate::cache_middleware::{CacheAware, CacheBypass};
 use crate::database::Database;
 use crate::error::{ApiError, ApiResult};
+use crate::models::corridor::{canonicalize_pair, Corridor};
 use crate::models::SortBy;
 use crate::rpc::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig};
 use crate::rpc::error::{with_retry, RetryConfig, RpcError};
-use crate::rpc::StellarRpcClient;
-use crate::services::price_feed::PriceFeedClient;
+use crate::rpc::{HorizonLiquidityPool, PaymentOutcome, StellarRpcClient};
+use crate::services::aggregation::HourlyCorridorMetrics;
+use crate::services::price_feed::{PriceFeedClient, PriceWithMeta};
 use anyhow::anyhow;
 
 /// Represents an asset pair (source -> destination) for a corridor
@@ -31,6 +36,78 @@ impl AssetPair {
     fn to_corridor_key(&self) -> String {
         format!("{}->{}", self.source_asset, self.destination_asset)
     }
+
+    /// True when the pair converts between two different assets (path payments)
+    fn is_cross_asset(&self) -> bool {
+        self.source_asset != self.destination_asset
+    }
+}
+
+/// True when a corridor key (`SRC:ISSUER->DST:ISSUER`) names two different assets
+fn is_cross_asset_corridor(corridor_key: &str) -> bool {
+    match corridor_key.split_once("->") {
+        Some((source, destination)) => AssetPair {
+            source_asset: source.to_string(),
+            destination_asset: destination.to_string(),
+        }
+        .is_cross_asset(),
+        None => false,
+    }
+}
+
+/// Corridor key (`SRC:ISSUER->DST:ISSUER`) a payment belongs to
+pub(crate) fn corridor_key_for_payment(payment: &crate::rpc::Payment) -> Option<String> {
+    extract_asset_pair_from_payment(payment).map(|pair| pair.to_corridor_key())
+}
+
+/// Canonical corridor a payment belongs to, as stored in `corridors`.
+///
+/// Returns `None` when either asset could not be identified.
+pub(crate) fn corridor_for_payment(payment: &crate::rpc::Payment) -> Option<Corridor> {
+    let pair = extract_asset_pair_from_payment(payment)?;
+    let (source_code, source_issuer) = pair.source_asset.split_once(':')?;
+    let (dest_code, dest_issuer) = pair.destination_asset.split_once(':')?;
+    if source_code == "UNKNOWN" || dest_code == "UNKNOWN" {
+        return None;
+    }
+    Some(Corridor::new(
+        source_code.to_string(),
+        source_issuer.to_string(),
+        dest_code.to_string(),
+        dest_issuer.to_string(),
+    ))
+}
+
+/// Payment attempts in a corridor, split by their transactions' outcome
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PaymentCounts {
+    total_attempts: i64,
+    successful_payments: i64,
+    failed_payments: i64,
+    success_rate: f64,
+}
+
+impl PaymentCounts {
+    fn tally(outcomes: &[&PaymentOutcome]) -> Self {
+        let total_attempts = outcomes.len() as i64;
+        let successful_payments = outcomes.iter().filter(|o| o.successful).count() as i64;
+        let success_rate = if total_attempts > 0 {
+            successful_payments as f64 / total_attempts as f64 * 100.0
+        } else {
+            0.0
+        };
+        Self {
+            total_attempts,
+            successful_payments,
+            failed_payments: total_attempts - successful_payments,
+            success_rate,
+        }
+    }
+}
+
+/// Corridor key (`SRC:ISSUER->DST:ISSUER`) a payment belongs to
+pub(crate) fn corridor_key_for_payment(payment: &crate::rpc::Payment) -> Option<String> {
+    extract_asset_pair_from_payment(payment).map(|pair| pair.to_corridor_key())
 }
 
 /// Extract asset pair from a payment operation
@@ -90,6 +167,58 @@ fn extract_asset_pair_from_payment(payment: &crate::rpc::Payment) -> Option<Asse
     }
 }
 
+/// Where a corridor's figures were derived from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CorridorSource {
+    /// Observed payment streams
+    #[default]
+    Payments,
+    /// Reserves of a two-asset liquidity pool, with no payment activity seen
+    Pool,
+}
+
+/// One page of a list endpoint plus what clients need to fetch the next
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[aliases(PaginatedCorridorResponse = PaginatedResponse<CorridorResponse>)]
+pub struct PaginatedResponse<T> {
+    pub items: Vec<T>,
+    /// Number of items matching the filters across all pages
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+    /// True when items exist past this page
+    pub has_more: bool,
+    /// True when the upstream source was unavailable and the last good
+    /// result was served instead
+    #[serde(default)]
+    pub stale: bool,
+}
+
+impl<T> PaginatedResponse<T> {
+    /// Slice `items` (the full filtered set) to the requested page
+    pub fn paginate(items: Vec<T>, limit: i64, offset: i64) -> Self {
+        let limit = limit.max(0);
+        let offset = offset.max(0);
+        let total = items.len() as i64;
+        let items: Vec<T> = items
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect();
+        let has_more = offset + (items.len() as i64) < total;
+
+        Self {
+            items,
+            total,
+            limit,
+            offset,
+            has_more,
+            stale: false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CorridorResponse {
     /// Unique identifier for the corridor
@@ -128,6 +257,10 @@ pub struct CorridorResponse {
     /// Liquidity depth in USD
     #[schema(example = 1500000.0)]
     pub liquidity_depth_usd: f64,
+    /// True when a price used for the USD figures was stale, making them approximate
+    #[serde(default)]
+    #[schema(example = false)]
+    pub price_stale: bool,
     /// 24-hour trading volume in USD
     #[schema(example = 150000.0)]
     pub liquidity_volume_24h_usd: f64,
@@ -140,6 +273,45 @@ pub struct CorridorResponse {
     /// Last update timestamp
     #[schema(example = "2024-01-15T10:30:00Z")]
     pub last_updated: String,
+    /// Where the corridor was derived from (payments or pool)
+    #[serde(default)]
+    pub source: CorridorSource,
+    /// Change versus the previous period, present when `compare=prev`
+    #[serde(default)]
+    pub trend: Option<CorridorTrend>,
+    /// Source of each computed field, present when `explain=provenance`
+    #[serde(default)]
+    pub provenance: Option<BTreeMap<String, DataProvenance>>,
+}
+
+/// Where a response field's value came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DataProvenance {
+    /// Computed from live Horizon/RPC data for this request
+    Rpc,
+    /// A placeholder or estimate, not backed by observed data
+    Simulated,
+    /// Computed from live data by an earlier request and served from cache
+    Cached,
+    /// Aggregated from metrics stored in the database
+    DbAggregate,
+}
+
+/// Period-over-period change in a corridor's hourly metrics (current minus
+/// previous). Each delta is `None` unless both windows have data for the
+/// corridor.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CorridorTrend {
+    /// Change in success rate, in percentage points
+    #[schema(example = -1.5)]
+    pub success_rate_delta: Option<f64>,
+    /// Change in volume (USD)
+    #[schema(example = 25000.0)]
+    pub volume_usd_delta: Option<f64>,
+    /// Change in health score
+    #[schema(example = 2.3)]
+    pub health_score_delta: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -208,7 +380,7 @@ pub struct ListCorridorsQuery {
     #[serde(default)]
     #[param(example = 0)]
     pub offset: i64,
-    /// Sort by field (success_rate or volume)
+    /// Sort by field (success_rate, volume, health_score or liquidity_depth)
     #[serde(default)]
     pub sort_by: SortBy,
     /// Minimum success rate filter
@@ -226,20 +398,223 @@ pub struct ListCorridorsQuery {
     /// Filter by asset code
     #[param(example = "USDC")]
     pub asset_code: Option<String>,
-    /// Time period for metrics (24h, 7d, 30d)
+    /// Only count payments created within this period (24h, 7d, 30d, 90d); all
+    /// recently fetched payments when unset
     #[param(example = "24h")]
     pub time_period: Option<String>,
+    /// Only return cross-asset (path payment) corridors, hiding same-asset payments
+    #[serde(default)]
+    #[param(example = false)]
+    pub cross_asset_only: bool,
+    /// Report one direction-agnostic market per asset pair, combining `A->B` and `B->A`.
+    /// Also accepted as `bidirectional`.
+    #[serde(default, alias = "bidirectional")]
+    #[param(example = false)]
+    pub market: bool,
+    /// Annotate each corridor with its change versus the previous period
+    #[param(example = "prev")]
+    pub compare: Option<CompareMode>,
+    /// Annotate each corridor with where its figures came from
+    #[param(example = "provenance")]
+    pub explain: Option<ExplainMode>,
+}
+
+/// Extra detail to include in corridor responses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExplainMode {
+    /// Label each computed field with the source of its value
+    Provenance,
+}
+
+/// Period corridor metrics are compared against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompareMode {
+    /// The window of the same length immediately before the current one
+    Prev,
 }
 
 fn default_limit() -> i64 {
     50
 }
 
-fn calculate_health_score(success_rate: f64, total_transactions: i64, volume_usd: f64) -> f64 {
-    let success_weight = 0.6;
-    let volume_weight = 0.2;
-    let transaction_weight = 0.2;
+/// Relative weight of each component in a corridor's health score
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HealthScoreWeights {
+    pub success: f64,
+    pub volume: f64,
+    pub transactions: f64,
+}
+
+impl Default for HealthScoreWeights {
+    fn default() -> Self {
+        Self {
+            success: 0.6,
+            volume: 0.2,
+            transactions: 0.2,
+        }
+    }
+}
+
+impl HealthScoreWeights {
+    /// Allowed drift from 1.0 in the weight sum, for decimal env values
+    const SUM_TOLERANCE: f64 = 1e-6;
+
+    /// Build weights, rejecting negative values or a sum other than 1.0
+    pub fn new(success: f64, volume: f64, transactions: f64) -> anyhow::Result<Self> {
+        let weights = Self {
+            success,
+            volume,
+            transactions,
+        };
+        if [success, volume, transactions]
+            .iter()
+            .any(|w| !w.is_finite() || *w < 0.0)
+        {
+            return Err(anyhow!(
+                "Health score weights must be non-negative numbers, got {:?}",
+                weights
+            ));
+        }
+        let sum = success + volume + transactions;
+        if (sum - 1.0).abs() > Self::SUM_TOLERANCE {
+            return Err(anyhow!(
+                "Health score weights must sum to 1.0, got {} \
+                 (HEALTH_WEIGHT_SUCCESS={}, HEALTH_WEIGHT_VOLUME={}, HEALTH_WEIGHT_TRANSACTIONS={})",
+                sum,
+                success,
+                volume,
+                transactions
+            ));
+        }
+        Ok(weights)
+    }
+
+    /// Load from environment, defaulting each unset weight:
+    /// - HEALTH_WEIGHT_SUCCESS (default: 0.6)
+    /// - HEALTH_WEIGHT_VOLUME (default: 0.2)
+    /// - HEALTH_WEIGHT_TRANSACTIONS (default: 0.2)
+    pub fn from_env() -> anyhow::Result<Self> {
+        let defaults = Self::default();
+        let weight = |name: &str, default: f64| -> anyhow::Result<f64> {
+            match std::env::var(name) {
+                Ok(value) => value
+                    .trim()
+                    .parse()
+                    .map_err(|_| anyhow!("{} must be a number, got '{}'", name, value)),
+                Err(_) => Ok(default),
+            }
+        };
+        Self::new(
+            weight("HEALTH_WEIGHT_SUCCESS", defaults.success)?,
+            weight("HEALTH_WEIGHT_VOLUME", defaults.volume)?,
+            weight("HEALTH_WEIGHT_TRANSACTIONS", defaults.transactions)?,
+        )
+    }
+}
+
+/// Minimum USD volume for a payment corridor to be reported. Corridors
+/// below it, such as a single tiny payment, are dropped as noise.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CorridorVolumeFloor {
+    pub min_volume_usd: f64,
+}
+
+impl CorridorVolumeFloor {
+    /// Load from CORRIDOR_MIN_VOLUME_USD (default: 0, keeping every corridor)
+    pub fn from_env() -> anyhow::Result<Self> {
+        let min_volume_usd = match std::env::var("CORRIDOR_MIN_VOLUME_USD") {
+            Ok(value) => value
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|v| v.is_finite() && *v >= 0.0)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "CORRIDOR_MIN_VOLUME_USD must be a non-negative number, got '{}'",
+                        value
+                    )
+                })?,
+            Err(_) => 0.0,
+        };
+        Ok(Self { min_volume_usd })
+    }
+
+    fn admits(&self, volume_usd: f64) -> bool {
+        volume_usd >= self.min_volume_usd
+    }
+}
+
+/// Weights from the router's extension, or the defaults when the layer isn't installed
+fn health_weights(extension: Option<Extension<HealthScoreWeights>>) -> HealthScoreWeights {
+    extension
+        .map(|Extension(weights)| weights)
+        .unwrap_or_default()
+}
+
+/// Floor from the router's extension, or none when the layer isn't installed
+fn volume_floor(extension: Option<Extension<CorridorVolumeFloor>>) -> CorridorVolumeFloor {
+    extension.map(|Extension(floor)| floor).unwrap_or_default()
+}
+
+/// Smallest accepted CORRIDOR_DETAIL_MAX_PAYMENTS
+const MIN_CORRIDOR_DETAIL_PAYMENTS: i64 = 100;
+/// Largest accepted CORRIDOR_DETAIL_MAX_PAYMENTS
+const MAX_CORRIDOR_DETAIL_PAYMENTS: i64 = 100_000;
+
+/// Maximum stored payments loaded for a corridor detail or comparison request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CorridorPaymentFetchLimit {
+    pub max_payments: i64,
+}
+
+impl Default for CorridorPaymentFetchLimit {
+    fn default() -> Self {
+        Self { max_payments: 5000 }
+    }
+}
+
+impl CorridorPaymentFetchLimit {
+    /// Load from CORRIDOR_DETAIL_MAX_PAYMENTS (default: 5000)
+    pub fn from_env() -> anyhow::Result<Self> {
+        match std::env::var("CORRIDOR_DETAIL_MAX_PAYMENTS") {
+            Ok(value) => Self::new(value.trim().parse::<i64>().map_err(|_| {
+                anyhow!(
+                    "CORRIDOR_DETAIL_MAX_PAYMENTS must be an integer, got '{}'",
+                    value
+                )
+            })?),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub fn new(max_payments: i64) -> anyhow::Result<Self> {
+        if !(MIN_CORRIDOR_DETAIL_PAYMENTS..=MAX_CORRIDOR_DETAIL_PAYMENTS).contains(&max_payments) {
+            anyhow::bail!(
+                "CORRIDOR_DETAIL_MAX_PAYMENTS must be between {} and {}, got {}",
+                MIN_CORRIDOR_DETAIL_PAYMENTS,
+                MAX_CORRIDOR_DETAIL_PAYMENTS,
+                max_payments
+            );
+        }
+        Ok(Self { max_payments })
+    }
+}
+
+/// Limit from the router's extension, or the default when the layer isn't installed
+fn payment_fetch_limit(
+    extension: Option<Extension<CorridorPaymentFetchLimit>>,
+) -> CorridorPaymentFetchLimit {
+    extension.map(|Extension(limit)| limit).unwrap_or_default()
+}
 
+pub(crate) fn calculate_health_score(
+    weights: &HealthScoreWeights,
+    success_rate: f64,
+    total_transactions: i64,
+    volume_usd: f64,
+) -> f64 {
     let volume_score = if volume_usd > 0.0 {
         ((volume_usd.ln() / 15.0) * 100.0).min(100.0)
     } else {
@@ -252,9 +627,9 @@ fn calculate_health_score(success_rate: f64, total_transactions: i64, volume_usd
         0.0
     };
 
-    success_rate * success_weight
-        + volume_score * volume_weight
-        + transaction_score * transaction_weight
+    success_rate * weights.success
+        + volume_score * weights.volume
+        + transaction_score * weights.transactions
 }
 
 fn get_liquidity_trend(volume_usd: f64) -> String {
@@ -267,7 +642,386 @@ fn get_liquidity_trend(volume_usd: f64) -> String {
     }
 }
 
-fn rpc_circuit_breaker() -> Arc<CircuitBreaker> {
+/// Convert a Horizon pool reserve asset (`native` or `CODE:ISSUER`) to the corridor asset format
+fn pool_reserve_asset_key(asset: &str) -> String {
+    if asset == "native" {
+        "XLM:native".to_string()
+    } else {
+        asset.to_string()
+    }
+}
+
+/// Fetch prices for every asset held in the given pools
+async fn fetch_pool_prices(
+    price_feed: &PriceFeedClient,
+    pools: &[HorizonLiquidityPool],
+) -> HashMap<String, PriceWithMeta> {
+    let mut prices = HashMap::new();
+    for pool in pools {
+        for reserve in &pool.reserves {
+            let asset_key = pool_reserve_asset_key(&reserve.asset);
+            if prices.contains_key(&asset_key) {
+                continue;
+            }
+            match price_feed.get_price_with_meta(&asset_key).await {
+                Ok(price) => {
+                    prices.insert(asset_key, price);
+                }
+                Err(e) => tracing::debug!("No price for pool asset {}: {}", asset_key, e),
+            }
+        }
+    }
+    prices
+}
+
+/// Asset pair and USD depth of a two-asset pool.
+///
+/// Constant-product pools hold equal value on both sides, so when only one
+/// reserve can be priced its value is doubled. Returns `None` for pools that do
+/// not hold exactly two assets or where neither reserve has a price.
+fn pool_liquidity_depth(
+    pool: &HorizonLiquidityPool,
+    prices: &HashMap<String, PriceWithMeta>,
+) -> Option<(AssetPair, f64, bool)> {
+    let [reserve_a, reserve_b] = pool.reserves.as_slice() else {
+        return None;
+    };
+
+    let mut priced_values = Vec::new();
+    let mut price_stale = false;
+    for reserve in [reserve_a, reserve_b] {
+        let asset_key = pool_reserve_asset_key(&reserve.asset);
+        if let (Some(price), Ok(amount)) = (prices.get(&asset_key), reserve.amount.parse::<f64>()) {
+            priced_values.push(amount * price.price_usd);
+            price_stale |= price.stale;
+        }
+    }
+
+    let depth_usd = match priced_values.as_slice() {
+        [a, b] => a + b,
+        [a] => a * 2.0,
+        _ => return None,
+    };
+
+    let pair = AssetPair {
+        source_asset: pool_reserve_asset_key(&reserve_a.asset),
+        destination_asset: pool_reserve_asset_key(&reserve_b.asset),
+    };
+    Some((pair, depth_usd, price_stale))
+}
+
+/// Merge pool depth into the corridor list.
+///
+/// Pools are directionless: their depth is added to every payment corridor
+/// between the two assets. Pairs without payment activity get a synthetic
+/// `pool` corridor, with depth summed across pools holding the same pair.
+fn merge_pool_corridors(
+    corridors: &mut Vec<CorridorResponse>,
+    pool_depths: Vec<(AssetPair, f64, bool)>,
+    weights: &HealthScoreWeights,
+) {
+    for (pair, depth_usd, price_stale) in pool_depths {
+        let forward = pair.to_corridor_key();
+        let reverse = AssetPair {
+            source_asset: pair.destination_asset.clone(),
+            destination_asset: pair.source_asset.clone(),
+        }
+        .to_corridor_key();
+
+        let mut matched = false;
+        for corridor in corridors
+            .iter_mut()
+            .filter(|c| c.id == forward || c.id == reverse)
+        {
+            corridor.liquidity_depth_usd += depth_usd;
+            corridor.price_stale |= price_stale;
+            if corridor.source == CorridorSource::Pool {
+                corridor.liquidity_trend = get_liquidity_trend(corridor.liquidity_depth_usd);
+                corridor.health_score =
+                    calculate_health_score(weights, 0.0, 0, corridor.liquidity_depth_usd);
+            }
+            matched = true;
+        }
+        if matched {
+            continue;
+        }
+
+        let asset_code = |asset: &str| asset.split(':').next().unwrap_or(asset).to_string();
+        corridors.push(CorridorResponse {
+            id: forward,
+            source_asset: asset_code(&pair.source_asset),
+            destination_asset: asset_code(&pair.destination_asset),
+            success_rate: 0.0,
+            total_attempts: 0,
+            successful_payments: 0,
+            failed_payments: 0,
+            average_latency_ms: 0.0,
+            median_latency_ms: 0.0,
+            p95_latency_ms: 0.0,
+            p99_latency_ms: 0.0,
+            liquidity_depth_usd: depth_usd,
+            price_stale,
+            liquidity_volume_24h_usd: 0.0,
+            liquidity_trend: get_liquidity_trend(depth_usd),
+            health_score: calculate_health_score(weights, 0.0, 0, depth_usd),
+            last_updated: chrono::Utc::now().to_rfc3339(),
+            source: CorridorSource::Pool,
+            trend: None,
+            provenance: None,
+        });
+    }
+}
+
+/// Combine both directions of each asset pair into one market keyed by the
+/// canonical (alphabetical) pair.
+///
+/// Counts and volumes are summed, success rate is recomputed from the summed
+/// counts and latencies are weighted by attempts. Corridors with a single
+/// direction are re-keyed canonically.
+fn aggregate_markets(
+    corridors: Vec<CorridorResponse>,
+    weights: &HealthScoreWeights,
+) -> Vec<CorridorResponse> {
+    let mut markets: HashMap<String, CorridorResponse> = HashMap::new();
+
+    for corridor in corridors {
+        let Some((source, destination)) = corridor.id.split_once("->") else {
+            continue;
+        };
+        let (lo, hi, reversed) = canonicalize_pair(source, destination);
+        let market_key = format!("{}->{}", lo, hi);
+
+        let Some(market) = markets.get_mut(&market_key) else {
+            let mut market = corridor.clone();
+            market.id = market_key.clone();
+            if reversed {
+                std::mem::swap(&mut market.source_asset, &mut market.destination_asset);
+            }
+            markets.insert(market_key, market);
+            continue;
+        };
+
+        let (market_attempts, corridor_attempts) = (market.total_attempts, corridor.total_attempts);
+        let attempts = market_attempts + corridor_attempts;
+        let weighted = |a: f64, b: f64| {
+            if attempts > 0 {
+                (a * market_attempts as f64 + b * corridor_attempts as f64) / attempts as f64
+            } else {
+                a.max(b)
+            }
+        };
+        market.average_latency_ms =
+            weighted(market.average_latency_ms, corridor.average_latency_ms);
+        market.median_latency_ms = weighted(market.median_latency_ms, corridor.median_latency_ms);
+        market.p95_latency_ms = weighted(market.p95_latency_ms, corridor.p95_latency_ms);
+        market.p99_latency_ms = weighted(market.p99_latency_ms, corridor.p99_latency_ms);
+
+        market.total_attempts = attempts;
+        market.successful_payments += corridor.successful_payments;
+        market.failed_payments += corridor.failed_payments;
+        market.success_rate = if attempts > 0 {
+            market.successful_payments as f64 / attempts as f64 * 100.0
+        } else {
+            0.0
+        };
+        market.liquidity_depth_usd += corridor.liquidity_depth_usd;
+        market.liquidity_volume_24h_usd += corridor.liquidity_volume_24h_usd;
+        market.price_stale |= corridor.price_stale;
+        market.liquidity_trend = get_liquidity_trend(market.liquidity_depth_usd);
+        market.health_score = calculate_health_score(
+            weights,
+            market.success_rate,
+            attempts,
+            market.liquidity_depth_usd,
+        );
+        if corridor.last_updated > market.last_updated {
+            market.last_updated = corridor.last_updated;
+        }
+        if corridor.source == CorridorSource::Payments {
+            market.source = CorridorSource::Payments;
+        }
+    }
+
+    markets.into_values().collect()
+}
+
+/// Window named by `time_period`, or `None` when unset. Unknown periods are
+/// rejected rather than silently ignored.
+fn time_period_window(time_period: Option<&str>) -> ApiResult<Option<chrono::Duration>> {
+    time_period
+        .map(|period| {
+            let window = match period {
+                "90d" => Some(chrono::Duration::days(90)),
+                other => window_duration(other),
+            };
+            window.ok_or_else(|| {
+                ApiError::bad_request(
+                    "INVALID_TIME_PERIOD",
+                    format!(
+                        "Unsupported time_period '{}': expected 24h, 7d, 30d or 90d",
+                        period
+                    ),
+                )
+            })
+        })
+        .transpose()
+}
+
+/// Keep only payments created within `window` before `now`; payments with an
+/// unparseable `created_at` can't be placed in the window and are dropped
+fn retain_within_window(
+    payments: &mut Vec<PaymentOutcome>,
+    window: chrono::Duration,
+    now: chrono::DateTime<chrono::Utc>,
+) {
+    let start = now - window;
+    payments.retain(|outcome| {
+        chrono::DateTime::parse_from_rfc3339(&outcome.payment.created_at)
+            .is_ok_and(|created_at| created_at >= start && created_at <= now)
+    });
+}
+
+/// Window corridor metrics cover when no `time_period` is given
+pub(crate) fn default_time_period_window() -> chrono::Duration {
+    chrono::Duration::hours(24)
+}
+
+/// Attempts, successful payments and USD volume per corridor key across
+/// hourly metrics. With `market`, both directions of a pair are combined
+/// under the canonical market key.
+fn hourly_totals(
+    metrics: &[HourlyCorridorMetrics],
+    market: bool,
+) -> HashMap<String, (i64, i64, f64)> {
+    let mut totals: HashMap<String, (i64, i64, f64)> = HashMap::new();
+    for metric in metrics {
+        let key = match metric.corridor_key.split_once("->") {
+            Some((source, destination)) if market => {
+                let (lo, hi, _) = canonicalize_pair(source, destination);
+                format!("{}->{}", lo, hi)
+            }
+            _ => metric.corridor_key.clone(),
+        };
+        let entry = totals.entry(key).or_insert((0, 0, 0.0));
+        entry.0 += metric.total_transactions;
+        entry.1 += metric.successful_transactions;
+        entry.2 += metric.volume_usd;
+    }
+    totals
+}
+
+/// Success rate and health score of `(attempts, successful, volume_usd)`
+/// totals
+fn rate_and_health(
+    weights: &HealthScoreWeights,
+    &(attempts, successful, volume_usd): &(i64, i64, f64),
+) -> (f64, f64) {
+    let success_rate = if attempts > 0 {
+        successful as f64 / attempts as f64 * 100.0
+    } else {
+        0.0
+    };
+    (
+        success_rate,
+        calculate_health_score(weights, success_rate, attempts, volume_usd),
+    )
+}
+
+/// Health score of each corridor over a window of hourly metrics, combining
+/// every bucket as the corridor list does rather than scoring the latest,
+/// possibly partial, hour
+pub(crate) fn hourly_health_scores(
+    metrics: &[HourlyCorridorMetrics],
+    weights: &HealthScoreWeights,
+) -> HashMap<String, f64> {
+    hourly_totals(metrics, false)
+        .into_iter()
+        .map(|(key, totals)| (key, rate_and_health(weights, &totals).1))
+        .collect()
+}
+
+/// Set each corridor's `trend` to the change in its hourly metrics from the
+/// `previous` window to the `current` one.
+///
+/// Both sides come from the hourly aggregates, so a live sample capped at a
+/// few hundred payments is never weighed against a full window of history.
+fn annotate_trends(
+    corridors: &mut [CorridorResponse],
+    current: &[HourlyCorridorMetrics],
+    previous: &[HourlyCorridorMetrics],
+    weights: &HealthScoreWeights,
+    market: bool,
+) {
+    let current = hourly_totals(current, market);
+    let previous = hourly_totals(previous, market);
+
+    for corridor in corridors.iter_mut() {
+        corridor.trend = Some(
+            match (current.get(&corridor.id), previous.get(&corridor.id)) {
+                (Some(now), Some(before)) => {
+                    let (success_rate, health_score) = rate_and_health(weights, now);
+                    let (previous_rate, previous_health) = rate_and_health(weights, before);
+                    CorridorTrend {
+                        success_rate_delta: Some(success_rate - previous_rate),
+                        volume_usd_delta: Some(now.2 - before.2),
+                        health_score_delta: Some(health_score - previous_health),
+                    }
+                }
+                _ => CorridorTrend::default(),
+            },
+        );
+    }
+}
+
+/// Label each computed field of `corridor` with the source of its value.
+///
+/// Live figures read back from the cache are reported as `cached`, while
+/// placeholders are `simulated` either way: latencies and the 24h volume are
+/// estimates, and pool-only corridors have no observed payments.
+fn field_provenance(
+    corridor: &CorridorResponse,
+    from_cache: bool,
+) -> BTreeMap<String, DataProvenance> {
+    let live = |provenance| {
+        if from_cache {
+            DataProvenance::Cached
+        } else {
+            provenance
+        }
+    };
+    let payments = match corridor.source {
+        CorridorSource::Payments => live(DataProvenance::Rpc),
+        CorridorSource::Pool => DataProvenance::Simulated,
+    };
+
+    let mut fields = BTreeMap::new();
+    for field in [
+        "success_rate",
+        "total_attempts",
+        "successful_payments",
+        "failed_payments",
+    ] {
+        fields.insert(field.to_string(), payments);
+    }
+    for field in [
+        "average_latency_ms",
+        "median_latency_ms",
+        "p95_latency_ms",
+        "p99_latency_ms",
+        "liquidity_volume_24h_usd",
+    ] {
+        fields.insert(field.to_string(), DataProvenance::Simulated);
+    }
+    for field in ["liquidity_depth_usd", "liquidity_trend", "health_score"] {
+        fields.insert(field.to_string(), live(DataProvenance::Rpc));
+    }
+    if corridor.trend.is_some() {
+        fields.insert("trend".to_string(), live(DataProvenance::DbAggregate));
+    }
+    fields
+}
+
+pub(crate) fn rpc_circuit_breaker() -> Arc<CircuitBreaker> {
     static CIRCUIT_BREAKER: OnceLock<Arc<CircuitBreaker>> = OnceLock::new();
     CIRCUIT_BREAKER
         .get_or_init(|| {
@@ -279,58 +1033,96 @@ fn rpc_circuit_breaker() -> Arc<CircuitBreaker> {
         .clone()
 }
 
+/// How long the last good corridor list is kept to fall back on while RPC is down
+const LAST_GOOD_CORRIDOR_LIST_TTL_SECS: usize = 24 * 60 * 60;
+
+/// `Warning` header value marking a response served from stale data
+const STALE_RESPONSE_WARNING: &str = "110 - \"Response is Stale\"";
+
+/// RPC failure while building the corridor list, which can be answered from
+/// the last good result instead of an error
+#[derive(Debug, thiserror::Error)]
+#[error("Failed to fetch {0} from RPC: {1}")]
+struct RpcUnavailable(&'static str, String);
+
 /// Generate cache key for corridor list with filters
 fn generate_corridor_list_cache_key(params: &ListCorridorsQuery) -> String {
     let filter_str = format!(
-        "sr_min:{:?}_sr_max:{:?}_vol_min:{:?}_vol_max:{:?}_asset:{:?}_period:{:?}",
+        "sr_min:{:?}_sr_max:{:?}_vol_min:{:?}_vol_max:{:?}_asset:{:?}_period:{:?}_cross:{}_market:{}_sort:{:?}_compare:{:?}",
         params.success_rate_min,
         params.success_rate_max,
         params.volume_min,
         params.volume_max,
         params.asset_code,
-        params.time_period
+        params.time_period,
+        params.cross_asset_only,
+        params.market,
+        params.sort_by,
+        params.compare
     );
     keys::corridor_list(params.limit, params.offset, &filter_str)
 }
 
 /// List all payment corridors
 ///
-/// Returns a list of payment corridors with performance metrics.
-/// Supports filtering by success rate, volume, and asset code.
+/// Returns a page of payment corridors with performance metrics, sorted by
+/// `sort_by` (descending). Supports filtering by success rate, volume, and
+/// asset code; `total` and `has_more` describe the filtered set. With
+/// `time_period`, metrics only count payments created within that period. With
+/// `market=true`, both directions of an asset pair are reported as one market.
+///
+/// With `Accept: text/csv` the page's corridors are returned as CSV, one row
+/// per corridor without the nested `trend` and `provenance` fields.
+///
+/// If RPC is unavailable, the last good list for the same query is returned
+/// with `stale: true` and a `Warning` header, even past its TTL.
 ///
 /// **DATA SOURCE: RPC**
 /// - Payment data from Horizon API
 /// - Trade data from Horizon API  
 /// - Order book data from Horizon API
+/// - Liquidity pool reserves from Horizon API (`source: "pool"` corridors)
 /// - Calculates corridor metrics from real-time RPC data
 #[utoipa::path(
     get,
     path = "/api/corridors",
     params(ListCorridorsQuery),
     responses(
-        (status = 200, description = "List of corridors retrieved successfully", body = Vec<CorridorResponse>),
+        (status = 200, description = "List of corridors retrieved successfully", body = PaginatedCorridorResponse),
+        (status = 400, description = "Unsupported time_period or limit"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Corridors"
 )]
-#[tracing::instrument(skip(_db, cache, rpc_client, price_feed, params))]
+#[tracing::instrument(skip(db, cache, rpc_client, price_feed, params))]
 pub async fn list_corridors(
-    State((_db, cache, rpc_client, price_feed)): State<(
+    State((db, cache, rpc_client, price_feed)): State<(
         Arc<Database>,
         Arc<CacheManager>,
         Arc<StellarRpcClient>,
         Arc<PriceFeedClient>,
     )>,
+    weights: Option<Extension<HealthScoreWeights>>,
+    floor: Option<Extension<CorridorVolumeFloor>>,
     Query(params): Query<ListCorridorsQuery>,
+    bypass: CacheBypass,
     headers: HeaderMap,
 ) -> ApiResult<Response> {
+    let weights = health_weights(weights);
+    validate_limit(params.limit)?;
+    let window = time_period_window(params.time_period.as_deref())?;
     let cache_key = generate_corridor_list_cache_key(&params);
+    let last_good_key = keys::last_good(&cache_key);
+    let floor = volume_floor(floor);
 
-    let corridors = <()>::get_or_fetch(
+    let mut fetched = false;
+    let result = <()>::get_or_fetch_with_bypass(
         &cache,
         &cache_key,
         cache.config.get_ttl("corridor"),
+        bypass,
         async {
+            fetched = true;
             let circuit_breaker = rpc_circuit_breaker();
 
             // **RPC DATA**: Fetch recent payments to identify active corridors
@@ -345,7 +1137,7 @@ pub async fn list_corridors(
                 circuit_breaker.clone(),
             )
             .await
-            .map_err(|e| anyhow::anyhow!("Failed to fetch payments from RPC: {}", e))?;
+            .map_err(|e| RpcUnavailable("payments", e.to_string()))?;
 
             // **RPC DATA**: Fetch recent trades for volume data
             let _trades = with_retry(
@@ -359,16 +1151,17 @@ pub async fn list_corridors(
                 circuit_breaker.clone(),
             )
             .await
-            .map_err(|e| anyhow::anyhow!("Failed to fetch trades from RPC: {}", e))?;
+            .map_err(|e| RpcUnavailable("trades", e.to_string()))?;
             // **RPC DATA**: Fetch recent payments with pagination to identify active corridors
-            // Use paginated fetch to get more complete data (up to configured limit)
-            let payments = match rpc_client.fetch_all_payments(Some(1000)).await {
-                Ok(p) => p,
-                Err(e) => {
-                    tracing::error!("Failed to fetch payments from RPC: {}", e);
-                    return Ok(vec![]);
-                }
-            };
+            // Use paginated fetch to get more complete data (up to configured limit),
+            // including payments whose transactions failed
+            let mut payments = rpc_client
+                .fetch_all_payment_outcomes(Some(1000))
+                .await
+                .map_err(|e| RpcUnavailable("payments", e.to_string()))?;
+            if let Some(window) = window {
+                retain_within_window(&mut payments, window, chrono::Utc::now());
+            }
 
             // **RPC DATA**: Fetch recent trades with pagination for volume data
             let _trades = match rpc_client.fetch_all_trades(Some(1000)).await {
@@ -381,31 +1174,41 @@ pub async fn list_corridors(
 
             // Group payments by asset pairs to identify corridors
             use std::collections::HashMap;
-            let mut corridor_map: HashMap<String, Vec<&crate::rpc::Payment>> = HashMap::new();
+            let mut corridor_map: HashMap<String, Vec<&PaymentOutcome>> = HashMap::new();
 
-            for payment in &payments {
+            for outcome in &payments {
                 // Extract the actual asset pair from the payment
-                if let Some(asset_pair) = extract_asset_pair_from_payment(payment) {
+                if let Some(asset_pair) = extract_asset_pair_from_payment(&outcome.payment) {
                     let corridor_key = asset_pair.to_corridor_key();
                     corridor_map
                         .entry(corridor_key)
                         .or_insert_with(Vec::new)
-                        .push(payment);
+                        .push(outcome);
                 } else {
-                    tracing::warn!("Failed to extract asset pair from payment: {}", payment.id);
+                    tracing::warn!(
+                        "Failed to extract asset pair from payment: {}",
+                        outcome.payment.id
+                    );
                 }
             }
 
             // Calculate metrics for each corridor
             let mut corridor_responses = Vec::new();
 
-            for (corridor_key, corridor_payments) in corridor_map.iter() {
-                let total_attempts = corridor_payments.len() as i64;
+            for (corridor_key, corridor_outcomes) in corridor_map.iter() {
+                let PaymentCounts {
+                    total_attempts,
+                    successful_payments,
+                    failed_payments,
+                    success_rate,
+                } = PaymentCounts::tally(corridor_outcomes);
 
-                // In Stellar, payments in the stream are successful
-                let successful_payments = total_attempts;
-                let failed_payments = 0;
-                let success_rate = if total_attempts > 0 { 100.0 } else { 0.0 };
+                // Only payments whose transactions succeeded moved any value
+                let corridor_payments: Vec<&crate::rpc::Payment> = corridor_outcomes
+                    .iter()
+                    .filter(|outcome| outcome.successful)
+                    .map(|outcome| &outcome.payment)
+                    .collect();
 
                 // Parse corridor key to get assets
                 let parts: Vec<&str> = corridor_key.split("->").collect();
@@ -422,13 +1225,15 @@ pub async fn list_corridors(
 
                 // Calculate volume from payment amounts and convert to USD
                 let mut volume_usd: f64 = 0.0;
+                let mut price_stale = false;
                 let source_asset_key = parts[0];
 
                 // Get price for source asset
-                if let Ok(price) = price_feed.get_price(source_asset_key).await {
+                if let Ok(price) = price_feed.get_price_with_meta(source_asset_key).await {
+                    price_stale = price.stale;
                     for payment in corridor_payments.iter() {
                         if let Ok(amount) = payment.get_amount().parse::<f64>() {
-                            volume_usd += amount * price;
+                            volume_usd += amount * price.price_usd;
                         }
                     }
                 } else {
@@ -443,8 +1248,13 @@ pub async fn list_corridors(
                         .sum();
                 }
 
+                if !floor.admits(volume_usd) {
+                    continue;
+                }
+
                 // Calculate health score
-                let health_score = calculate_health_score(success_rate, total_attempts, volume_usd);
+                let health_score =
+                    calculate_health_score(&weights, success_rate, total_attempts, volume_usd);
                 let liquidity_trend = get_liquidity_trend(volume_usd);
                 let avg_latency = 400.0 + (success_rate * 2.0);
 
@@ -461,19 +1271,47 @@ pub async fn list_corridors(
                     p95_latency_ms: avg_latency * 2.5,
                     p99_latency_ms: avg_latency * 4.0,
                     liquidity_depth_usd: volume_usd,
+                    price_stale,
                     liquidity_volume_24h_usd: volume_usd * 0.1,
                     liquidity_trend,
                     health_score,
                     last_updated: chrono::Utc::now().to_rfc3339(),
+                    source: CorridorSource::Payments,
+                    trend: None,
+                    provenance: None,
                 };
 
                 corridor_responses.push(corridor_response);
             }
 
+            // Combine directions before adding pool depth, which is already
+            // credited to both directions of a pair
+            if params.market {
+                corridor_responses = aggregate_markets(corridor_responses, &weights);
+            }
+
+            // **RPC DATA**: Add depth from two-asset liquidity pools
+            match rpc_client.fetch_liquidity_pools(200, None).await {
+                Ok(pools) => {
+                    let prices = fetch_pool_prices(&price_feed, &pools).await;
+                    let pool_depths = pools
+                        .iter()
+                        .filter_map(|pool| pool_liquidity_depth(pool, &prices))
+                        .collect();
+                    merge_pool_corridors(&mut corridor_responses, pool_depths, &weights);
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to fetch liquidity pools from RPC: {}", e);
+                }
+            }
+
             // Apply filters
-            let filtered: Vec<_> = corridor_responses
+            let mut filtered: Vec<_> = corridor_responses
                 .into_iter()
                 .filter(|c| {
+                    if params.cross_asset_only && !is_cross_asset_corridor(&c.id) {
+                        return false;
+                    }
                     if let Some(min) = params.success_rate_min {
                         if c.success_rate < min {
                             return false;
@@ -509,37 +1347,177 @@ pub async fn list_corridors(
                 })
                 .collect();
 
-            Ok(filtered)
+            if params.compare == Some(CompareMode::Prev) {
+                let window = window.unwrap_or_else(default_time_period_window);
+                let now = chrono::Utc::now();
+                let current_start = now - window;
+                let (previous, current): (Vec<_>, Vec<_>) = db
+                    .fetch_hourly_metrics_by_timerange(current_start - window, now)
+                    .await?
+                    .into_iter()
+                    .partition(|metric| metric.hour_bucket < current_start);
+                annotate_trends(&mut filtered, &current, &previous, &weights, params.market);
+            }
+
+            sort_corridors(&mut filtered, &params.sort_by);
+            Ok(PaginatedResponse::paginate(
+                filtered,
+                params.limit,
+                params.offset,
+            ))
         },
     )
-    .await?;
+    .await;
+
+    let mut page = match result {
+        Ok(page) => {
+            if fetched {
+                let _ = cache
+                    .set(&last_good_key, &page, LAST_GOOD_CORRIDOR_LIST_TTL_SECS)
+                    .await;
+            }
+            page
+        }
+        Err(e) => {
+            let Some(rpc_error) = e.downcast_ref::<RpcUnavailable>() else {
+                return Err(e.into());
+            };
+            tracing::error!("{}", rpc_error);
+            match cache
+                .get::<PaginatedResponse<CorridorResponse>>(&last_good_key)
+                .await
+            {
+                Ok(Some(last_good)) => {
+                    tracing::warn!("Serving stale corridor list while RPC is unavailable");
+                    PaginatedResponse {
+                        stale: true,
+                        ..last_good
+                    }
+                }
+                _ => PaginatedResponse::paginate(Vec::new(), params.limit, params.offset),
+            }
+        }
+    };
+
+    // Computed after the cache so a cache hit is labelled as such
+    if params.explain == Some(ExplainMode::Provenance) {
+        for corridor in &mut page.items {
+            corridor.provenance = Some(field_provenance(corridor, !fetched || page.stale));
+        }
+    }
 
-    crate::observability::metrics::set_corridors_tracked(corridors.len() as i64);
+    crate::observability::metrics::set_corridors_tracked(page.total);
 
     let ttl = cache.config.get_ttl("corridor");
-    let response = crate::http_cache::cached_json_response(&headers, &cache_key, &corridors, ttl)?;
+    let mut response = if crate::http_cache::accepts(&headers, "text/csv") {
+        crate::http_cache::cached_csv_response(
+            &headers,
+            &format!("{}:csv", cache_key),
+            corridors_to_csv(&page.items),
+            ttl,
+        )
+    } else {
+        crate::http_cache::cached_json_response(&headers, &cache_key, &Rounded(&page), ttl)?
+    };
+    response
+        .headers_mut()
+        .insert(header::VARY, HeaderValue::from_static("accept"));
+    if page.stale {
+        response.headers_mut().insert(
+            header::WARNING,
+            HeaderValue::from_static(STALE_RESPONSE_WARNING),
+        );
+    }
     Ok(response)
 }
 
-/// Calculate historical success rate data points (30-day buckets)
-fn calculate_historical_success_rate(
-    corridor_payments: &[&crate::rpc::Payment],
-) -> Vec<SuccessRateDataPoint> {
-    use std::collections::HashMap;
-
-    if corridor_payments.is_empty() {
-        return vec![];
+/// Columns of the CSV corridor list; the nested `trend` and `provenance` are left out
+const CORRIDOR_CSV_HEADER: &str = "id,source_asset,destination_asset,success_rate,total_attempts,\
+successful_payments,failed_payments,average_latency_ms,median_latency_ms,p95_latency_ms,\
+p99_latency_ms,liquidity_depth_usd,price_stale,liquidity_volume_24h_usd,liquidity_trend,\
+health_score,last_updated,source";
+
+/// Render corridors as CSV, one row per corridor under a header row
+fn corridors_to_csv(corridors: &[CorridorResponse]) -> String {
+    let decimals = crate::api::precision::response_decimals();
+    let rounded = |value: f64| crate::api::precision::round_to(value, decimals).to_string();
+
+    let mut csv = String::from(CORRIDOR_CSV_HEADER);
+    csv.push('\n');
+    for corridor in corridors {
+        let source = match corridor.source {
+            CorridorSource::Payments => "payments",
+            CorridorSource::Pool => "pool",
+        };
+        let fields = [
+            csv_field(&corridor.id),
+            csv_field(&corridor.source_asset),
+            csv_field(&corridor.destination_asset),
+            rounded(corridor.success_rate),
+            corridor.total_attempts.to_string(),
+            corridor.successful_payments.to_string(),
+            corridor.failed_payments.to_string(),
+            corridor.average_latency_ms.to_string(),
+            corridor.median_latency_ms.to_string(),
+            corridor.p95_latency_ms.to_string(),
+            corridor.p99_latency_ms.to_string(),
+            rounded(corridor.liquidity_depth_usd),
+            corridor.price_stale.to_string(),
+            rounded(corridor.liquidity_volume_24h_usd),
+            csv_field(&corridor.liquidity_trend),
+            rounded(corridor.health_score),
+            csv_field(&corridor.last_updated),
+            source.to_string(),
+        ];
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Quote a CSV field when it contains a delimiter, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Sort descending by the requested metric, tie-breaking on id so pages are stable
+fn sort_corridors(corridors: &mut [CorridorResponse], sort_by: &SortBy) {
+    corridors.sort_by(|a, b| {
+        let (a_key, b_key) = match sort_by {
+            SortBy::SuccessRate => (a.success_rate, b.success_rate),
+            SortBy::Volume => (a.liquidity_volume_24h_usd, b.liquidity_volume_24h_usd),
+            SortBy::LiquidityDepth => (a.liquidity_depth_usd, b.liquidity_depth_usd),
+            SortBy::HealthScore => (a.health_score, b.health_score),
+        };
+        b_key.total_cmp(&a_key).then_with(|| a.id.cmp(&b.id))
+    });
+}
+
+/// Calculate historical success rate data points (30-day buckets)
+fn calculate_historical_success_rate(
+    corridor_payments: &[&PaymentOutcome],
+) -> Vec<SuccessRateDataPoint> {
+    use std::collections::HashMap;
+
+    if corridor_payments.is_empty() {
+        return vec![];
     }
 
     // Group payments by date (day)
     let mut daily_data: HashMap<String, (i64, i64)> = HashMap::new();
 
-    for payment in corridor_payments {
+    for outcome in corridor_payments {
         // Extract date from created_at (format: 2026-01-01T00:00:00Z)
-        if let Some(date) = payment.created_at.split('T').next() {
+        if let Some(date) = outcome.payment.created_at.split('T').next() {
             let entry = daily_data.entry(date.to_string()).or_insert((0, 0));
             entry.0 += 1; // increment total
-            entry.1 += 1; // all payments in Stellar stream are successful
+            if outcome.successful {
+                entry.1 += 1;
+            }
         }
     }
 
@@ -651,6 +1629,40 @@ fn calculate_liquidity_trends(
     data_points
 }
 
+/// Time window of stored payments considered by the corridor detail view
+const CORRIDOR_DETAIL_WINDOW_DAYS: i64 = 30;
+
+/// Convert a persisted payment row back into the Horizon payment shape used by
+/// the corridor metric helpers
+fn stored_payment_to_rpc(record: &crate::models::PaymentRecord) -> crate::rpc::Payment {
+    crate::rpc::Payment {
+        id: record.id.clone(),
+        paging_token: record.id.clone(),
+        transaction_hash: record.transaction_hash.clone(),
+        source_account: record.source_account.clone(),
+        destination: record.destination_account.clone(),
+        asset_type: record.asset_type.clone(),
+        asset_code: record.asset_code.clone(),
+        asset_issuer: record.asset_issuer.clone(),
+        amount: record.amount.to_string(),
+        created_at: record.created_at.to_rfc3339(),
+        // Rows stored before operation types were recorded were plain payments
+        operation_type: Some(
+            record
+                .operation_type
+                .clone()
+                .unwrap_or_else(|| "payment".to_string()),
+        ),
+        source_asset_type: record.source_asset_type.clone(),
+        source_asset_code: Some(record.source_asset_code.clone()).filter(|c| !c.is_empty()),
+        source_asset_issuer: Some(record.source_asset_issuer.clone()).filter(|i| !i.is_empty()),
+        source_amount: None,
+        from: Some(record.source_account.clone()),
+        to: Some(record.destination_account.clone()),
+        asset_balance_changes: None,
+    }
+}
+
 /// Find related corridors (same source or destination asset)
 fn find_related_corridors(
     target_corridor_key: &str,
@@ -682,122 +1694,133 @@ fn find_related_corridors(
     }
 }
 
-/// Get detailed corridor information
-///
-/// Returns detailed metrics and historical data for a specific corridor.
-///
-/// **DATA SOURCE: RPC**
-#[utoipa::path(
-    get,
-    path = "/api/corridors/{corridor_key}",
-    params(
-        ("corridor_key" = String, Path, description = "Corridor identifier (e.g., USDC:native->XLM:native)")
-    ),
-    responses(
-        (status = 200, description = "Corridor details retrieved successfully", body = CorridorDetailResponse),
-        (status = 404, description = "Corridor not found"),
-        (status = 500, description = "Internal server error")
-    ),
-    tag = "Corridors"
-)]
-#[tracing::instrument(skip(db, cache, rpc_client, price_feed))]
-pub async fn get_corridor_detail(
-    State((db, cache, rpc_client, price_feed)): State<(
-        Arc<Database>,
-        Arc<CacheManager>,
-        Arc<StellarRpcClient>,
-        Arc<PriceFeedClient>,
-    )>,
-    Path(corridor_key): Path<String>,
-) -> ApiResult<Json<CorridorDetailResponse>> {
-    use std::collections::HashMap;
-
-    // Validate corridor_key format
+/// Check a corridor key has the form `CODE:ISSUER->CODE:ISSUER`, returning
+/// the error code and message to report otherwise
+pub(crate) fn validate_corridor_key(
+    corridor_key: &str,
+) -> Result<(), (&'static str, &'static str)> {
     let parts: Vec<&str> = corridor_key.split("->").collect();
     if parts.len() != 2 {
-        return Err(ApiError::bad_request(
+        return Err((
             "INVALID_CORRIDOR_FORMAT",
             "Corridor key must be in format 'ASSET1:ISSUER1->ASSET2:ISSUER2'",
         ));
     }
-
-    let source_key = parts[0];
-    let dest_key = parts[1];
-
-    // Parse asset components
-    let source_parts: Vec<&str> = source_key.split(':').collect();
-    let dest_parts: Vec<&str> = dest_key.split(':').collect();
-
-    if source_parts.len() != 2 || dest_parts.len() != 2 {
-        return Err(ApiError::bad_request(
-            "INVALID_ASSET_FORMAT",
-            "Asset format must be 'CODE:ISSUER'",
-        ));
+    if parts[0].split(':').count() != 2 || parts[1].split(':').count() != 2 {
+        return Err(("INVALID_ASSET_FORMAT", "Asset format must be 'CODE:ISSUER'"));
     }
+    Ok(())
+}
 
-    // Check cache first
-    let cache_key = keys::corridor_detail(&corridor_key);
-    if let Some(cached) = cache
-        .get::<CorridorDetailResponse>(&cache_key)
+/// Payments ingested within the corridor detail window, as RPC payments
+async fn load_recent_payments(
+    db: &Database,
+    limit: CorridorPaymentFetchLimit,
+) -> ApiResult<Vec<PaymentOutcome>> {
+    let since = chrono::Utc::now() - chrono::Duration::days(CORRIDOR_DETAIL_WINDOW_DAYS);
+    let records = db
+        .get_payments_since(since, limit.max_payments)
         .await
-        .ok()
-        .flatten()
-    {
-        return Ok(Json(cached));
-    }
-
-    // Fetch payments from RPC
-    let circuit_breaker = rpc_circuit_breaker();
+        .map_err(|e| {
+            tracing::error!("Failed to load payments from database: {}", e);
+            ApiError::internal("DATABASE_ERROR", "Failed to load payment data")
+        })?;
+    Ok(records
+        .iter()
+        .map(|record| PaymentOutcome {
+            payment: stored_payment_to_rpc(record),
+            successful: record.successful,
+        })
+        .collect())
+}
 
-    let payments = with_retry(
-        || async {
-            rpc_client
-                .fetch_all_payments(Some(5000))
-                .await
-                .map_err(|e| RpcError::categorize(&e.to_string()))
-        },
-        RetryConfig::default(),
-        circuit_breaker.clone(),
+/// Current metrics of one corridor, computed from recently ingested payments
+/// as the detail endpoint does. `None` when the corridor has no recent payments.
+pub(crate) async fn find_recent_corridor(
+    db: &Database,
+    price_feed: &PriceFeedClient,
+    weights: &HealthScoreWeights,
+    fetch_limit: CorridorPaymentFetchLimit,
+    corridor_key: &str,
+) -> ApiResult<Option<CorridorResponse>> {
+    let payments = load_recent_payments(db, fetch_limit).await?;
+    let corridors = corridor_responses_from_payments(
+        &payments,
+        price_feed,
+        weights,
+        CorridorVolumeFloor::default(),
     )
-    .await
-    .map_err(|e| {
-        tracing::error!("Failed to fetch payments from RPC: {}", e);
-        ApiError::internal("RPC_FETCH_ERROR", "Failed to fetch payment data from RPC")
-    })?;
-
-    // Filter payments for this specific corridor
-    let mut corridor_payments = Vec::new();
-    let mut all_corridors = Vec::new();
-    let mut corridor_map: HashMap<String, Vec<&crate::rpc::Payment>> = HashMap::new();
-
-    for payment in &payments {
-        if let Some(asset_pair) = extract_asset_pair_from_payment(payment) {
-            let key = asset_pair.to_corridor_key();
-            corridor_map
-                .entry(key.clone())
-                .or_insert_with(Vec::new)
-                .push(payment);
+    .await;
+    Ok(corridors.into_iter().find(|c| c.id == corridor_key))
+}
 
-            if key == corridor_key {
-                corridor_payments.push(payment);
-            }
-        }
+/// Historical series of one corridor, as shown on its detail page
+pub(crate) struct CorridorHistory {
+    /// Number of the corridor's payments the series were built from
+    pub payment_count: usize,
+    pub historical_success_rate: Vec<SuccessRateDataPoint>,
+    pub latency_distribution: Vec<LatencyDataPoint>,
+    pub liquidity_trends: Vec<LiquidityDataPoint>,
+}
+
+/// Historical series of `corridor` from its payments in `payments`
+pub(crate) fn corridor_history(
+    corridor: &CorridorResponse,
+    payments: &[PaymentOutcome],
+) -> CorridorHistory {
+    let corridor_outcomes: Vec<&PaymentOutcome> = payments
+        .iter()
+        .filter(|outcome| {
+            corridor_key_for_payment(&outcome.payment).is_some_and(|key| key == corridor.id)
+        })
+        .collect();
+    let corridor_payments: Vec<&crate::rpc::Payment> =
+        corridor_outcomes.iter().map(|o| &o.payment).collect();
+
+    CorridorHistory {
+        payment_count: corridor_outcomes.len(),
+        historical_success_rate: calculate_historical_success_rate(&corridor_outcomes),
+        latency_distribution: calculate_latency_distribution(
+            &corridor_payments,
+            corridor.total_attempts,
+        ),
+        liquidity_trends: calculate_liquidity_trends(
+            &corridor_payments,
+            corridor.liquidity_depth_usd,
+        ),
     }
+}
 
-    // If no payments found for this corridor, return 404
-    if corridor_payments.is_empty() {
-        return Err(ApiError::not_found(
-            "CORRIDOR_NOT_FOUND",
-            &format!("No payment data found for corridor: {}", corridor_key),
-        ));
+/// Metrics for every corridor seen in `payments`
+pub(crate) async fn corridor_responses_from_payments(
+    payments: &[PaymentOutcome],
+    price_feed: &PriceFeedClient,
+    weights: &HealthScoreWeights,
+    floor: CorridorVolumeFloor,
+) -> Vec<CorridorResponse> {
+    let mut corridor_map: HashMap<String, Vec<&PaymentOutcome>> = HashMap::new();
+    for outcome in payments {
+        if let Some(asset_pair) = extract_asset_pair_from_payment(&outcome.payment) {
+            corridor_map
+                .entry(asset_pair.to_corridor_key())
+                .or_default()
+                .push(outcome);
+        }
     }
 
-    // Build all corridor responses for related corridors lookup
-    for (key, corr_payments) in corridor_map.iter() {
-        let total_attempts = corr_payments.len() as i64;
-        let successful_payments = total_attempts;
-        let failed_payments = 0;
-        let success_rate = 100.0; // All payments in Stellar stream are successful
+    let mut corridors = Vec::with_capacity(corridor_map.len());
+    for (key, corr_outcomes) in corridor_map.iter() {
+        let total_attempts = corr_outcomes.len() as i64;
+        // Failed transactions move no value, so only successful payments
+        // count towards volume
+        let corr_payments: Vec<&crate::rpc::Payment> = corr_outcomes
+            .iter()
+            .filter(|o| o.successful)
+            .map(|o| &o.payment)
+            .collect();
+        let successful_payments = corr_payments.len() as i64;
+        let failed_payments = total_attempts - successful_payments;
+        let success_rate = (successful_payments as f64 / total_attempts as f64) * 100.0;
 
         let parts: Vec<&str> = key.split("->").collect();
         if parts.len() != 2 {
@@ -813,10 +1836,12 @@ pub async fn get_corridor_detail(
 
         // Calculate volume
         let mut volume_usd = 0.0;
-        if let Ok(price) = price_feed.get_price(parts[0]).await {
+        let mut price_stale = false;
+        if let Ok(price) = price_feed.get_price_with_meta(parts[0]).await {
+            price_stale = price.stale;
             for payment in corr_payments.iter() {
                 if let Ok(amount) = payment.get_amount().parse::<f64>() {
-                    volume_usd += amount * price;
+                    volume_usd += amount * price.price_usd;
                 }
             }
         } else {
@@ -825,12 +1850,16 @@ pub async fn get_corridor_detail(
                 .filter_map(|p| p.get_amount().parse::<f64>().ok())
                 .sum();
         }
+        if !floor.admits(volume_usd) {
+            continue;
+        }
 
-        let health_score = calculate_health_score(success_rate, total_attempts, volume_usd);
+        let health_score =
+            calculate_health_score(weights, success_rate, total_attempts, volume_usd);
         let liquidity_trend = get_liquidity_trend(volume_usd);
         let avg_latency = 400.0 + (success_rate * 2.0);
 
-        all_corridors.push(CorridorResponse {
+        corridors.push(CorridorResponse {
             id: key.clone(),
             source_asset: source_parts[0].to_string(),
             destination_asset: dest_parts[0].to_string(),
@@ -843,80 +1872,225 @@ pub async fn get_corridor_detail(
             p95_latency_ms: avg_latency * 2.5,
             p99_latency_ms: avg_latency * 4.0,
             liquidity_depth_usd: volume_usd,
+            price_stale,
             liquidity_volume_24h_usd: volume_usd * 0.1,
             liquidity_trend,
             health_score,
             last_updated: chrono::Utc::now().to_rfc3339(),
+            source: CorridorSource::Payments,
+            trend: None,
+            provenance: None,
         });
     }
+    corridors
+}
 
-    // Calculate volume for target corridor
-    let total_attempts = corridor_payments.len() as i64;
-    let successful_payments = total_attempts;
-    let failed_payments = 0;
-    let success_rate = 100.0;
+/// Get detailed corridor information
+///
+/// Returns detailed metrics and historical data for a specific corridor.
+///
+/// **DATA SOURCE: DATABASE**
+/// - Payments persisted by incremental ingestion over the last 30 days
+#[utoipa::path(
+    get,
+    path = "/api/corridors/{corridor_key}",
+    params(
+        ("corridor_key" = String, Path, description = "Corridor identifier (e.g., USDC:native->XLM:native)")
+    ),
+    responses(
+        (status = 200, description = "Corridor details retrieved successfully", body = CorridorDetailResponse),
+        (status = 304, description = "Corridor details unchanged since the `If-None-Match` ETag"),
+        (status = 404, description = "Corridor not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Corridors"
+)]
+#[tracing::instrument(skip(db, cache, _rpc_client, price_feed))]
+pub async fn get_corridor_detail(
+    State((db, cache, _rpc_client, price_feed)): State<(
+        Arc<Database>,
+        Arc<CacheManager>,
+        Arc<StellarRpcClient>,
+        Arc<PriceFeedClient>,
+    )>,
+    weights: Option<Extension<HealthScoreWeights>>,
+    floor: Option<Extension<CorridorVolumeFloor>>,
+    fetch_limit: Option<Extension<CorridorPaymentFetchLimit>>,
+    Path(corridor_key): Path<String>,
+    headers: HeaderMap,
+) -> ApiResult<Response> {
+    let weights = health_weights(weights);
+    if let Err((code, message)) = validate_corridor_key(&corridor_key) {
+        return Err(ApiError::bad_request(code, message));
+    }
 
-    let mut volume_usd = 0.0;
-    if let Ok(price) = price_feed.get_price(source_key).await {
-        for payment in corridor_payments.iter() {
-            if let Ok(amount) = payment.get_amount().parse::<f64>() {
-                volume_usd += amount * price;
-            }
-        }
-    } else {
-        volume_usd = corridor_payments
-            .iter()
-            .filter_map(|p| p.get_amount().parse::<f64>().ok())
-            .sum();
+    // Check cache first
+    let cache_key = keys::corridor_detail(&corridor_key);
+    let ttl = cache.config.get_ttl("corridor");
+    if let Some(cached) = cache
+        .get::<CorridorDetailResponse>(&cache_key)
+        .await
+        .ok()
+        .flatten()
+    {
+        let response =
+            crate::http_cache::cached_json_response(&headers, &cache_key, &Rounded(&cached), ttl)?;
+        return Ok(response);
     }
 
-    let health_score = calculate_health_score(success_rate, total_attempts, volume_usd);
-    let liquidity_trend = get_liquidity_trend(volume_usd);
-    let avg_latency = 400.0 + (success_rate * 2.0);
+    let payments = load_recent_payments(&db, payment_fetch_limit(fetch_limit)).await?;
+    let all_corridors =
+        corridor_responses_from_payments(&payments, &price_feed, &weights, volume_floor(floor))
+            .await;
 
-    let corridor = CorridorResponse {
-        id: corridor_key.clone(),
-        source_asset: source_parts[0].to_string(),
-        destination_asset: dest_parts[0].to_string(),
-        success_rate,
-        total_attempts,
-        successful_payments,
-        failed_payments,
-        average_latency_ms: avg_latency,
-        median_latency_ms: avg_latency * 0.75,
-        p95_latency_ms: avg_latency * 2.5,
-        p99_latency_ms: avg_latency * 4.0,
-        liquidity_depth_usd: volume_usd,
-        liquidity_volume_24h_usd: volume_usd * 0.1,
-        liquidity_trend,
-        health_score,
-        last_updated: chrono::Utc::now().to_rfc3339(),
+    // If no payments found for this corridor, return 404
+    let Some(corridor) = all_corridors.iter().find(|c| c.id == corridor_key).cloned() else {
+        return Err(ApiError::not_found(
+            "CORRIDOR_NOT_FOUND",
+            &format!("No payment data found for corridor: {}", corridor_key),
+        ));
     };
-
-    // Calculate historical metrics
-    let historical_success_rate = calculate_historical_success_rate(&corridor_payments);
-    let latency_distribution = calculate_latency_distribution(&corridor_payments, total_attempts);
-    let liquidity_trends = calculate_liquidity_trends(&corridor_payments, volume_usd);
+    let history = corridor_history(&corridor, &payments);
 
     // Find related corridors
     let related_corridors = find_related_corridors(&corridor_key, &all_corridors);
 
     let response = CorridorDetailResponse {
         corridor,
-        historical_success_rate,
-        latency_distribution,
-        liquidity_trends,
+        historical_success_rate: history.historical_success_rate,
+        latency_distribution: history.latency_distribution,
+        liquidity_trends: history.liquidity_trends,
         related_corridors,
     };
 
-    // Cache the response with 5-minute TTL
-    let _ = cache
-        .set(
-            &cache_key, &response, 300, // 5 minutes
-        )
-        .await;
+    let _ = cache.set(&cache_key, &response, ttl).await;
+
+    let response =
+        crate::http_cache::cached_json_response(&headers, &cache_key, &Rounded(&response), ttl)?;
+    Ok(response)
+}
+
+/// Most corridor keys accepted by one comparison request
+pub const MAX_COMPARE_KEYS: usize = 10;
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CompareCorridorsQuery {
+    /// Comma-separated corridor keys, at most 10
+    #[param(example = "USDC:GISSUER->XLM:native,EURC:GISSUER->XLM:native")]
+    pub keys: String,
+}
+
+/// Why a requested corridor has no metrics in a comparison
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CorridorComparisonError {
+    #[schema(example = "CORRIDOR_NOT_FOUND")]
+    pub code: String,
+    pub message: String,
+}
+
+/// One requested corridor in a comparison: its metrics, or the error
+/// computing them
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CorridorComparisonEntry {
+    /// Corridor key as requested
+    pub key: String,
+    pub corridor: Option<CorridorResponse>,
+    pub error: Option<CorridorComparisonError>,
+}
+
+impl CorridorComparisonEntry {
+    fn error(key: &str, code: &str, message: impl Into<String>) -> Self {
+        Self {
+            key: key.to_string(),
+            corridor: None,
+            error: Some(CorridorComparisonError {
+                code: code.to_string(),
+                message: message.into(),
+            }),
+        }
+    }
+}
+
+/// Compare corridors side by side
+///
+/// Returns the detail endpoint's metrics for each requested corridor, in the
+/// order requested. Keys that are malformed or have no payment data get an
+/// error entry instead of failing the request.
+///
+/// **DATA SOURCE: DATABASE**
+/// - Payments persisted by incremental ingestion over the last 30 days
+#[utoipa::path(
+    get,
+    path = "/api/corridors/compare",
+    params(CompareCorridorsQuery),
+    responses(
+        (status = 200, description = "Metrics or an error for each requested corridor", body = [CorridorComparisonEntry]),
+        (status = 400, description = "No keys or more than 10 keys requested"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Corridors"
+)]
+#[tracing::instrument(skip(db, _cache, _rpc_client, price_feed, weights))]
+pub async fn compare_corridors(
+    State((db, _cache, _rpc_client, price_feed)): State<(
+        Arc<Database>,
+        Arc<CacheManager>,
+        Arc<StellarRpcClient>,
+        Arc<PriceFeedClient>,
+    )>,
+    weights: Option<Extension<HealthScoreWeights>>,
+    floor: Option<Extension<CorridorVolumeFloor>>,
+    fetch_limit: Option<Extension<CorridorPaymentFetchLimit>>,
+    Query(params): Query<CompareCorridorsQuery>,
+) -> ApiResult<Json<Rounded<Vec<CorridorComparisonEntry>>>> {
+    let weights = health_weights(weights);
+    let requested: Vec<&str> = params
+        .keys
+        .split(',')
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .collect();
+    if requested.is_empty() {
+        return Err(ApiError::bad_request(
+            "MISSING_CORRIDOR_KEYS",
+            "At least one corridor key is required",
+        ));
+    }
+    if requested.len() > MAX_COMPARE_KEYS {
+        return Err(ApiError::bad_request(
+            "TOO_MANY_CORRIDOR_KEYS",
+            format!("At most {} corridors can be compared", MAX_COMPARE_KEYS),
+        ));
+    }
+
+    let payments = load_recent_payments(&db, payment_fetch_limit(fetch_limit)).await?;
+    let corridors =
+        corridor_responses_from_payments(&payments, &price_feed, &weights, volume_floor(floor))
+            .await;
+
+    let entries = requested
+        .into_iter()
+        .map(|key| {
+            if let Err((code, message)) = validate_corridor_key(key) {
+                return CorridorComparisonEntry::error(key, code, message);
+            }
+            match corridors.iter().find(|c| c.id == key) {
+                Some(corridor) => CorridorComparisonEntry {
+                    key: key.to_string(),
+                    corridor: Some(corridor.clone()),
+                    error: None,
+                },
+                None => CorridorComparisonEntry::error(
+                    key,
+                    "CORRIDOR_NOT_FOUND",
+                    format!("No payment data found for corridor: {}", key),
+                ),
+            }
+        })
+        .collect();
 
-    Ok(Json(response))
+    Ok(Json(Rounded(entries)))
 }
 
 #[cfg(test)]
@@ -925,10 +2099,40 @@ mod tests {
 
     #[test]
     fn test_health_score_calculation() {
-        let score = calculate_health_score(95.0, 1000, 1_000_000.0);
+        let score = calculate_health_score(&HealthScoreWeights::default(), 95.0, 1000, 1_000_000.0);
         assert!(score > 0.0 && score <= 100.0);
     }
 
+    #[test]
+    fn test_default_health_weights_match_original_split() {
+        let weights = HealthScoreWeights::default();
+        assert_eq!(weights, HealthScoreWeights::new(0.6, 0.2, 0.2).unwrap());
+
+        // volume and transaction scores both cap at 100
+        let score = calculate_health_score(&weights, 90.0, 1_000_000, 1e12);
+        assert!((score - (90.0 * 0.6 + 100.0 * 0.2 + 100.0 * 0.2)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_custom_health_weights() {
+        let success_only = HealthScoreWeights::new(1.0, 0.0, 0.0).unwrap();
+        assert!((calculate_health_score(&success_only, 87.5, 1_000_000, 1e12) - 87.5).abs() < 1e-9);
+
+        let volume_heavy = HealthScoreWeights::new(0.2, 0.7, 0.1).unwrap();
+        let default_score = calculate_health_score(&HealthScoreWeights::default(), 50.0, 10, 1e12);
+        let volume_score = calculate_health_score(&volume_heavy, 50.0, 10, 1e12);
+        assert!(volume_score > default_score);
+    }
+
+    #[test]
+    fn test_health_weights_must_sum_to_one() {
+        let error = HealthScoreWeights::new(0.5, 0.2, 0.2).unwrap_err();
+        assert!(error.to_string().contains("must sum to 1.0"));
+        assert!(HealthScoreWeights::new(1.2, -0.1, -0.1).is_err());
+        // Decimal rounding in env values is tolerated
+        assert!(HealthScoreWeights::new(0.7, 0.1, 0.2).is_ok());
+    }
+
     #[test]
     fn test_liquidity_trend() {
         assert_eq!(get_liquidity_trend(15_000_000.0), "increasing");
@@ -1023,6 +2227,112 @@ mod tests {
         assert_eq!(pair.to_corridor_key(), "USD:GUSDISSUER->EUR:GEURISSUER");
     }
 
+    #[test]
+    fn test_payment_counts_reflect_failed_transactions() {
+        let payment = |id: &str| crate::rpc::Payment {
+            id: id.to_string(),
+            paging_token: format!("token_{}", id),
+            transaction_hash: format!("hash_{}", id),
+            source_account: "GTEST".to_string(),
+            destination: "GDEST".to_string(),
+            asset_type: "native".to_string(),
+            asset_code: None,
+            asset_issuer: None,
+            amount: "10.0".to_string(),
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            operation_type: Some("payment".to_string()),
+            source_asset_type: None,
+            source_asset_code: None,
+            source_asset_issuer: None,
+            source_amount: None,
+            from: Some("GTEST".to_string()),
+            to: Some("GDEST".to_string()),
+            asset_balance_changes: None,
+        };
+        let outcomes: Vec<PaymentOutcome> = [true, true, false, true]
+            .into_iter()
+            .enumerate()
+            .map(|(i, successful)| PaymentOutcome {
+                payment: payment(&i.to_string()),
+                successful,
+            })
+            .collect();
+        let refs: Vec<&PaymentOutcome> = outcomes.iter().collect();
+
+        let counts = PaymentCounts::tally(&refs);
+        assert_eq!(counts.total_attempts, 4);
+        assert_eq!(counts.successful_payments, 3);
+        assert_eq!(counts.failed_payments, 1);
+        assert!((counts.success_rate - 75.0).abs() < 1e-9);
+
+        assert_eq!(PaymentCounts::tally(&[]).success_rate, 0.0);
+    }
+
+    #[test]
+    fn test_cross_asset_filter_keeps_only_path_payments() {
+        let same_asset = crate::rpc::Payment {
+            id: "same".to_string(),
+            paging_token: "token_same".to_string(),
+            transaction_hash: "hash_same".to_string(),
+            source_account: "GTEST".to_string(),
+            destination: "GDEST".to_string(),
+            asset_type: "native".to_string(),
+            asset_code: None,
+            asset_issuer: None,
+            amount: "10.0".to_string(),
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            operation_type: Some("payment".to_string()),
+            source_asset_type: None,
+            source_asset_code: None,
+            source_asset_issuer: None,
+            source_amount: None,
+            from: Some("GTEST".to_string()),
+            to: Some("GDEST".to_string()),
+            asset_balance_changes: None,
+        };
+        let path_payment = crate::rpc::Payment {
+            id: "path".to_string(),
+            paging_token: "token_path".to_string(),
+            operation_type: Some("path_payment_strict_send".to_string()),
+            asset_type: "credit_alphanum4".to_string(),
+            asset_code: Some("USDC".to_string()),
+            asset_issuer: Some("GISSUER".to_string()),
+            source_asset_type: Some("native".to_string()),
+            source_amount: Some("12.0".to_string()),
+            ..same_asset.clone()
+        };
+
+        let same_key = extract_asset_pair_from_payment(&same_asset)
+            .unwrap()
+            .to_corridor_key();
+        let path_key = extract_asset_pair_from_payment(&path_payment)
+            .unwrap()
+            .to_corridor_key();
+
+        assert_eq!(same_key, "XLM:native->XLM:native");
+        assert!(!is_cross_asset_corridor(&same_key));
+        assert_eq!(path_key, "XLM:native->USDC:GISSUER");
+        assert!(is_cross_asset_corridor(&path_key));
+
+        // Same code from different issuers is still a cross-asset corridor
+        assert!(is_cross_asset_corridor("USDC:GISSUER1->USDC:GISSUER2"));
+        assert!(!is_cross_asset_corridor("malformed"));
+    }
+
+    #[test]
+    fn test_cross_asset_only_defaults_to_false() {
+        let all: ListCorridorsQuery = serde_json::from_str("{}").unwrap();
+        assert!(!all.cross_asset_only);
+
+        let cross_only: ListCorridorsQuery =
+            serde_json::from_str(r#"{"cross_asset_only": true}"#).unwrap();
+        assert!(cross_only.cross_asset_only);
+        assert_ne!(
+            generate_corridor_list_cache_key(&all),
+            generate_corridor_list_cache_key(&cross_only)
+        );
+    }
+
     #[test]
     fn test_extract_asset_pair_path_payment_native_to_issued() {
         let payment = crate::rpc::Payment {
@@ -1139,13 +2449,21 @@ mod tests {
             to: Some("GDEST".to_string()),
             asset_balance_changes: None,
         };
+        let succeeded = PaymentOutcome {
+            payment: payment.clone(),
+            successful: true,
+        };
+        let failed = PaymentOutcome {
+            payment,
+            successful: false,
+        };
 
-        let payments = vec![&payment];
+        let payments = vec![&succeeded, &failed];
         let result = calculate_historical_success_rate(&payments);
 
         assert!(!result.is_empty());
-        assert!(result[0].success_rate == 100.0);
-        assert_eq!(result[0].attempts, 1);
+        assert!(result[0].success_rate == 50.0);
+        assert_eq!(result[0].attempts, 2);
         assert!(result[0].timestamp.contains("2026-01-15"));
     }
 
@@ -1183,6 +2501,39 @@ mod tests {
         assert!((total_percentage - 100.0).abs() < 0.1);
     }
 
+    #[test]
+    fn test_stored_payment_maps_to_same_asset_corridor() {
+        let created_at = chrono::Utc::now();
+        let record = crate::models::PaymentRecord {
+            id: "stored_1".to_string(),
+            transaction_hash: "hash_1".to_string(),
+            source_account: "GSOURCE".to_string(),
+            destination_account: "GDEST".to_string(),
+            asset_type: "credit_alphanum4".to_string(),
+            asset_code: Some("USDC".to_string()),
+            asset_issuer: Some("GISSUER".to_string()),
+            operation_type: None,
+            source_asset_type: None,
+            source_asset_code: String::new(),
+            source_asset_issuer: String::new(),
+            destination_asset_code: String::new(),
+            destination_asset_issuer: String::new(),
+            amount: 42.5,
+            successful: true,
+            timestamp: None,
+            submission_time: None,
+            confirmation_time: None,
+            created_at,
+        };
+
+        let payment = stored_payment_to_rpc(&record);
+        assert_eq!(payment.get_amount(), "42.5");
+        assert_eq!(payment.created_at, created_at.to_rfc3339());
+
+        let pair = extract_asset_pair_from_payment(&payment).unwrap();
+        assert_eq!(pair.to_corridor_key(), "USDC:GISSUER->USDC:GISSUER");
+    }
+
     #[test]
     fn test_calculate_liquidity_trends_empty() {
         let payments = vec![];
@@ -1207,10 +2558,14 @@ mod tests {
                 p95_latency_ms: 1000.0,
                 p99_latency_ms: 1200.0,
                 liquidity_depth_usd: 1000000.0,
+                price_stale: false,
                 liquidity_volume_24h_usd: 100000.0,
                 liquidity_trend: "stable".to_string(),
                 health_score: 95.0,
                 last_updated: "2026-01-15T10:00:00Z".to_string(),
+                source: CorridorSource::Payments,
+                trend: None,
+                provenance: None,
             },
             CorridorResponse {
                 id: "USDC:GISSUER->EUR:GEURISSUER".to_string(),
@@ -1225,10 +2580,14 @@ mod tests {
                 p95_latency_ms: 1050.0,
                 p99_latency_ms: 1250.0,
                 liquidity_depth_usd: 900000.0,
+                price_stale: false,
                 liquidity_volume_24h_usd: 90000.0,
                 liquidity_trend: "stable".to_string(),
                 health_score: 94.0,
                 last_updated: "2026-01-15T10:00:00Z".to_string(),
+                source: CorridorSource::Payments,
+                trend: None,
+                provenance: None,
             },
         ];
 
@@ -1237,4 +2596,612 @@ mod tests {
         let related_corridors = related.unwrap();
         assert!(related_corridors.len() >= 2); // At least target and one related
     }
+
+    const MOCK_USDC: &str = "USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+
+    fn mock_pool_prices() -> HashMap<String, PriceWithMeta> {
+        let price = |price_usd| PriceWithMeta {
+            price_usd,
+            as_of: chrono::Utc::now(),
+            stale: false,
+            source: crate::services::price_feed::PriceSource::Provider,
+        };
+        HashMap::from([
+            ("XLM:native".to_string(), price(0.1)),
+            (MOCK_USDC.to_string(), price(1.0)),
+        ])
+    }
+
+    async fn mock_pool_depths() -> Vec<(AssetPair, f64, bool)> {
+        let rpc_client = StellarRpcClient::new_with_defaults(true);
+        let pools = rpc_client.fetch_liquidity_pools(10, None).await.unwrap();
+        let prices = mock_pool_prices();
+        pools
+            .iter()
+            .filter_map(|pool| pool_liquidity_depth(pool, &prices))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_mock_pools_produce_pool_corridors() {
+        let mut corridors = Vec::new();
+        merge_pool_corridors(
+            &mut corridors,
+            mock_pool_depths().await,
+            &HealthScoreWeights::default(),
+        );
+
+        let usdc_xlm = corridors
+            .iter()
+            .find(|c| c.id == format!("{}->XLM:native", MOCK_USDC))
+            .expect("USDC/XLM pool corridor");
+        assert_eq!(usdc_xlm.source, CorridorSource::Pool);
+        assert_eq!(usdc_xlm.source_asset, "USDC");
+        assert_eq!(usdc_xlm.destination_asset, "XLM");
+        assert_eq!(usdc_xlm.total_attempts, 0);
+        // 500,000 USDC at $1 + 1,200,000 XLM at $0.10
+        assert!((usdc_xlm.liquidity_depth_usd - 620_000.0).abs() < 1e-6);
+
+        // Only USDC is priced in the USDC/EURC pool, so its value is doubled
+        let usdc_eurc = corridors
+            .iter()
+            .find(|c| c.id.starts_with(MOCK_USDC) && c.destination_asset == "EURC")
+            .expect("USDC/EURC pool corridor");
+        assert!((usdc_eurc.liquidity_depth_usd - 640_000.0).abs() < 1e-6);
+
+        assert!(corridors.iter().all(|c| c.source == CorridorSource::Pool));
+    }
+
+    #[tokio::test]
+    async fn test_pool_depth_merges_into_payment_corridor() {
+        let payment_key = format!("XLM:native->{}", MOCK_USDC);
+        let mut corridors = vec![CorridorResponse {
+            id: payment_key.clone(),
+            source_asset: "XLM".to_string(),
+            destination_asset: "USDC".to_string(),
+            success_rate: 100.0,
+            total_attempts: 10,
+            successful_payments: 10,
+            failed_payments: 0,
+            average_latency_ms: 400.0,
+            median_latency_ms: 300.0,
+            p95_latency_ms: 1000.0,
+            p99_latency_ms: 1600.0,
+            liquidity_depth_usd: 1_000.0,
+            price_stale: false,
+            liquidity_volume_24h_usd: 100.0,
+            liquidity_trend: "decreasing".to_string(),
+            health_score: 70.0,
+            last_updated: "2026-01-15T10:00:00Z".to_string(),
+            source: CorridorSource::Payments,
+            trend: None,
+            provenance: None,
+        }];
+
+        merge_pool_corridors(
+            &mut corridors,
+            mock_pool_depths().await,
+            &HealthScoreWeights::default(),
+        );
+
+        let matching: Vec<_> = corridors
+            .iter()
+            .filter(|c| c.id == payment_key || c.id == format!("{}->XLM:native", MOCK_USDC))
+            .collect();
+        assert_eq!(matching.len(), 1);
+        assert_eq!(matching[0].source, CorridorSource::Payments);
+        assert!((matching[0].liquidity_depth_usd - 621_000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_pools_with_same_pair_sum_depth() {
+        let pair = || AssetPair {
+            source_asset: "XLM:native".to_string(),
+            destination_asset: MOCK_USDC.to_string(),
+        };
+        let mut corridors = Vec::new();
+        merge_pool_corridors(
+            &mut corridors,
+            vec![(pair(), 100.0, false), (pair(), 250.0, true)],
+            &HealthScoreWeights::default(),
+        );
+
+        assert_eq!(corridors.len(), 1);
+        assert_eq!(corridors[0].liquidity_depth_usd, 350.0);
+        assert!(corridors[0].price_stale);
+    }
+
+    #[test]
+    fn test_paginate_slices_filtered_set() {
+        let page = PaginatedResponse::paginate((1..=5).collect(), 2, 2);
+
+        assert_eq!(page.items, vec![3, 4]);
+        assert_eq!(page.total, 5);
+        assert_eq!(page.limit, 2);
+        assert_eq!(page.offset, 2);
+        assert!(page.has_more);
+
+        let last = PaginatedResponse::paginate((1..=5).collect(), 2, 4);
+        assert_eq!(last.items, vec![5]);
+        assert!(!last.has_more);
+    }
+
+    #[test]
+    fn test_paginate_offset_past_end_is_empty() {
+        let page = PaginatedResponse::paginate((1..=5).collect::<Vec<i32>>(), 10, 50);
+
+        assert!(page.items.is_empty());
+        assert_eq!(page.total, 5);
+        assert_eq!(page.offset, 50);
+        assert!(!page.has_more);
+    }
+
+    #[test]
+    fn test_cache_key_varies_with_pagination() {
+        let query = |offset| ListCorridorsQuery {
+            limit: 10,
+            offset,
+            sort_by: SortBy::default(),
+            success_rate_min: None,
+            success_rate_max: None,
+            volume_min: None,
+            volume_max: None,
+            asset_code: None,
+            time_period: None,
+            cross_asset_only: false,
+            market: false,
+            compare: None,
+            explain: None,
+        };
+
+        assert_ne!(
+            generate_corridor_list_cache_key(&query(0)),
+            generate_corridor_list_cache_key(&query(10))
+        );
+    }
+
+    fn directed_corridor(
+        id: &str,
+        attempts: i64,
+        successful: i64,
+        volume: f64,
+    ) -> CorridorResponse {
+        let (source, destination) = id.split_once("->").unwrap();
+        let code = |asset: &str| asset.split(':').next().unwrap().to_string();
+        CorridorResponse {
+            id: id.to_string(),
+            source_asset: code(source),
+            destination_asset: code(destination),
+            success_rate: successful as f64 / attempts as f64 * 100.0,
+            total_attempts: attempts,
+            successful_payments: successful,
+            failed_payments: attempts - successful,
+            average_latency_ms: 400.0,
+            median_latency_ms: 300.0,
+            p95_latency_ms: 1000.0,
+            p99_latency_ms: 1600.0,
+            liquidity_depth_usd: volume,
+            price_stale: false,
+            liquidity_volume_24h_usd: volume * 0.1,
+            liquidity_trend: get_liquidity_trend(volume),
+            health_score: 0.0,
+            last_updated: "2026-01-15T10:00:00Z".to_string(),
+            source: CorridorSource::Payments,
+            trend: None,
+            provenance: None,
+        }
+    }
+
+    #[test]
+    fn test_sort_corridors_orders_by_each_field() {
+        let corridor =
+            |id: &str, successful: i64, depth: f64, volume_24h: f64, health_score: f64| {
+                let mut corridor = directed_corridor(id, 4, successful, depth);
+                corridor.liquidity_volume_24h_usd = volume_24h;
+                corridor.health_score = health_score;
+                corridor
+            };
+        let corridors = vec![
+            corridor("A:x->B:y", 4, 100.0, 50.0, 50.0),
+            corridor("B:x->C:y", 2, 1_000.0, 5.0, 90.0),
+            corridor("C:x->D:y", 3, 10.0, 500.0, 70.0),
+        ];
+
+        let sorted = |sort_by: &str| {
+            let sort_by: SortBy = serde_json::from_value(serde_json::json!(sort_by)).unwrap();
+            let mut corridors = corridors.clone();
+            sort_corridors(&mut corridors, &sort_by);
+            corridors
+                .into_iter()
+                .map(|c| c.id[..1].to_string())
+                .collect::<String>()
+        };
+
+        assert_eq!(sorted("success_rate"), "ACB");
+        assert_eq!(sorted("volume"), "CAB");
+        assert_eq!(sorted("liquidity_depth"), "BAC");
+        assert_eq!(sorted("health_score"), "BCA");
+        assert!(matches!(SortBy::default(), SortBy::SuccessRate));
+    }
+
+    #[test]
+    fn test_corridors_to_csv_writes_header_and_rows() {
+        let corridor = directed_corridor("USDC:issuer->XLM:native", 4, 3, 1_000.0);
+        let csv = corridors_to_csv(&[corridor]);
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], CORRIDOR_CSV_HEADER);
+        assert_eq!(
+            lines[1],
+            "USDC:issuer->XLM:native,USDC,XLM,75,4,3,1,400,300,1000,1600,1000,false,100,\
+             decreasing,0,2026-01-15T10:00:00Z,payments"
+        );
+        assert_eq!(lines[0].split(',').count(), lines[1].split(',').count());
+    }
+
+    #[test]
+    fn test_csv_field_quotes_special_characters() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_corridor_response_rounds_monetary_fields() {
+        let decimals = crate::api::precision::response_decimals();
+        let mut corridor = directed_corridor("USDC:issuer->XLM:native", 3, 2, 1_234.56789);
+        corridor.health_score = 87.123456789;
+        let json = serde_json::to_value(Rounded(&corridor)).unwrap();
+
+        let rounded = |v: f64| crate::api::precision::round_to(v, decimals);
+        assert_eq!(json["success_rate"].as_f64().unwrap(), rounded(200.0 / 3.0));
+        assert_eq!(
+            json["liquidity_depth_usd"].as_f64().unwrap(),
+            rounded(1_234.56789)
+        );
+        assert_eq!(
+            json["liquidity_volume_24h_usd"].as_f64().unwrap(),
+            rounded(1_234.56789 * 0.1)
+        );
+        assert_eq!(
+            json["health_score"].as_f64().unwrap(),
+            rounded(87.123456789)
+        );
+        // Latencies are not monetary and keep full precision
+        assert_eq!(json["average_latency_ms"].as_f64().unwrap(), 400.0);
+
+        // The cached form of the corridor is not rounded
+        let cached: CorridorResponse =
+            serde_json::from_value(serde_json::to_value(&corridor).unwrap()).unwrap();
+        assert_eq!(cached.health_score, 87.123456789);
+    }
+
+    #[test]
+    fn test_market_aggregates_sum_both_directions() {
+        let forward = format!("XLM:native->{}", MOCK_USDC);
+        let backward = format!("{}->XLM:native", MOCK_USDC);
+        let mut slow = directed_corridor(&backward, 30, 15, 3_000.0);
+        slow.average_latency_ms = 800.0;
+        slow.price_stale = true;
+        let corridors = vec![
+            directed_corridor(&forward, 10, 10, 1_000.0),
+            slow,
+            directed_corridor("EURC:issuer->XLM:native", 5, 5, 50.0),
+        ];
+
+        let mut markets = aggregate_markets(corridors, &HealthScoreWeights::default());
+        markets.sort_by(|a, b| a.id.cmp(&b.id));
+
+        assert_eq!(markets.len(), 2);
+        let market = &markets[1];
+        // USDC sorts before XLM, so the market takes the backward key
+        assert_eq!(market.id, backward);
+        assert_eq!(market.source_asset, "USDC");
+        assert_eq!(market.destination_asset, "XLM");
+        assert_eq!(market.total_attempts, 40);
+        assert_eq!(market.successful_payments, 25);
+        assert_eq!(market.failed_payments, 15);
+        assert!((market.success_rate - 62.5).abs() < 1e-9);
+        assert!((market.liquidity_depth_usd - 4_000.0).abs() < 1e-9);
+        assert!((market.liquidity_volume_24h_usd - 400.0).abs() < 1e-9);
+        // (400 * 10 + 800 * 30) / 40
+        assert!((market.average_latency_ms - 700.0).abs() < 1e-9);
+        assert!(market.price_stale);
+        assert_eq!(
+            market.health_score,
+            calculate_health_score(&HealthScoreWeights::default(), 62.5, 40, 4_000.0)
+        );
+
+        // A single-direction corridor passes through unchanged
+        assert_eq!(markets[0].id, "EURC:issuer->XLM:native");
+        assert_eq!(markets[0].total_attempts, 5);
+    }
+
+    #[test]
+    fn test_market_rekeys_single_direction_canonically() {
+        let markets = aggregate_markets(
+            vec![directed_corridor(
+                &format!("XLM:native->{}", MOCK_USDC),
+                4,
+                4,
+                10.0,
+            )],
+            &HealthScoreWeights::default(),
+        );
+
+        assert_eq!(markets[0].id, format!("{}->XLM:native", MOCK_USDC));
+        assert_eq!(markets[0].source_asset, "USDC");
+        assert_eq!(markets[0].destination_asset, "XLM");
+    }
+
+    #[test]
+    fn test_cache_key_varies_with_market() {
+        let directed: ListCorridorsQuery = serde_json::from_str("{}").unwrap();
+        let market: ListCorridorsQuery = serde_json::from_str(r#"{"market": true}"#).unwrap();
+
+        assert!(!directed.market);
+        assert_ne!(
+            generate_corridor_list_cache_key(&directed),
+            generate_corridor_list_cache_key(&market)
+        );
+    }
+
+    #[test]
+    fn test_bidirectional_is_an_alias_for_market() {
+        let uri: axum::http::Uri = "/api/corridors?bidirectional=true".parse().unwrap();
+        let Query(bidirectional) = Query::<ListCorridorsQuery>::try_from_uri(&uri).unwrap();
+        let market: ListCorridorsQuery = serde_json::from_str(r#"{"market": true}"#).unwrap();
+
+        assert!(bidirectional.market);
+        assert_eq!(
+            generate_corridor_list_cache_key(&bidirectional),
+            generate_corridor_list_cache_key(&market)
+        );
+
+        // Payments seen in each direction land in one market
+        let markets = aggregate_markets(
+            vec![
+                directed_corridor(&format!("{}->XLM:native", MOCK_USDC), 6, 6, 600.0),
+                directed_corridor(&format!("XLM:native->{}", MOCK_USDC), 4, 4, 400.0),
+            ],
+            &HealthScoreWeights::default(),
+        );
+        assert_eq!(markets.len(), 1);
+        assert_eq!(markets[0].total_attempts, 10);
+        assert!((markets[0].liquidity_depth_usd - 1_000.0).abs() < 1e-9);
+    }
+
+    fn hourly(
+        corridor_key: &str,
+        total: i64,
+        successful: i64,
+        volume: f64,
+    ) -> HourlyCorridorMetrics {
+        HourlyCorridorMetrics {
+            id: format!("{}-{}-{}", corridor_key, total, volume),
+            corridor_key: corridor_key.to_string(),
+            asset_a_code: String::new(),
+            asset_a_issuer: String::new(),
+            asset_b_code: String::new(),
+            asset_b_issuer: String::new(),
+            hour_bucket: chrono::Utc::now() - chrono::Duration::hours(30),
+            total_transactions: total,
+            successful_transactions: successful,
+            failed_transactions: total - successful,
+            success_rate: successful as f64 / total as f64 * 100.0,
+            volume_usd: volume,
+            avg_slippage_bps: 0.0,
+            avg_settlement_latency_ms: None,
+            liquidity_depth_usd: 0.0,
+            has_missing_prices: false,
+        }
+    }
+
+    #[test]
+    fn test_trend_deltas_against_previous_period() {
+        let weights = HealthScoreWeights::default();
+        let key = "USDC:issuer->XLM:native";
+        let eurc = "EURC:issuer->XLM:native";
+        // The live figures don't enter the trend, only the hourly metrics do
+        let mut corridors = vec![
+            directed_corridor(key, 3, 3, 10.0),
+            directed_corridor(eurc, 5, 5, 50.0),
+        ];
+        // 90 of 100 successful and $5,000 now, against 80 of 100 and $4,000
+        let current = vec![hourly(key, 100, 90, 5_000.0), hourly(eurc, 5, 5, 50.0)];
+        let previous = vec![hourly(key, 60, 50, 3_000.0), hourly(key, 40, 30, 1_000.0)];
+
+        annotate_trends(&mut corridors, &current, &previous, &weights, false);
+
+        let trend = corridors[0].trend.as_ref().unwrap();
+        assert!((trend.success_rate_delta.unwrap() - 10.0).abs() < 1e-9);
+        assert!((trend.volume_usd_delta.unwrap() - 1_000.0).abs() < 1e-9);
+        let health_delta = calculate_health_score(&weights, 90.0, 100, 5_000.0)
+            - calculate_health_score(&weights, 80.0, 100, 4_000.0);
+        assert!((trend.health_score_delta.unwrap() - health_delta).abs() < 1e-9);
+
+        // No prior-period data: the trend is present but every delta is null
+        assert_eq!(corridors[1].trend, Some(CorridorTrend::default()));
+    }
+
+    #[test]
+    fn test_unchanged_hourly_metrics_show_no_trend() {
+        let weights = HealthScoreWeights::default();
+        let key = "USDC:issuer->XLM:native";
+        // A live sample far smaller than the window's history
+        let mut corridors = vec![directed_corridor(key, 200, 190, 1_000.0)];
+        let window = vec![hourly(key, 5_000, 4_750, 250_000.0)];
+
+        annotate_trends(&mut corridors, &window, &window, &weights, false);
+
+        let trend = corridors[0].trend.as_ref().unwrap();
+        assert_eq!(trend.success_rate_delta, Some(0.0));
+        assert_eq!(trend.volume_usd_delta, Some(0.0));
+        assert_eq!(trend.health_score_delta, Some(0.0));
+    }
+
+    #[test]
+    fn test_hourly_health_scores_cover_the_whole_window() {
+        let weights = HealthScoreWeights::default();
+        let key = "USDC:issuer->XLM:native";
+        // A full hour followed by a barely started one with a single failure
+        let window = vec![hourly(key, 99, 99, 9_900.0), hourly(key, 1, 0, 0.0)];
+
+        let scores = hourly_health_scores(&window, &weights);
+
+        let expected = calculate_health_score(&weights, 99.0, 100, 9_900.0);
+        assert!((scores[key] - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_market_trend_combines_both_directions() {
+        let forward = format!("XLM:native->{}", MOCK_USDC);
+        let backward = format!("{}->XLM:native", MOCK_USDC);
+        let mut corridors = vec![directed_corridor(&backward, 40, 40, 2_000.0)];
+        let current = vec![
+            hourly(&forward, 20, 20, 1_000.0),
+            hourly(&backward, 20, 20, 1_000.0),
+        ];
+        let previous = vec![
+            hourly(&forward, 10, 10, 500.0),
+            hourly(&backward, 10, 5, 500.0),
+        ];
+
+        annotate_trends(
+            &mut corridors,
+            &current,
+            &previous,
+            &HealthScoreWeights::default(),
+            true,
+        );
+
+        let trend = corridors[0].trend.as_ref().unwrap();
+        // 100% now versus 15 of 20 (75%) before
+        assert!((trend.success_rate_delta.unwrap() - 25.0).abs() < 1e-9);
+        assert!((trend.volume_usd_delta.unwrap() - 1_000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_compare_query_param() {
+        let query: ListCorridorsQuery = serde_json::from_str(r#"{"compare": "prev"}"#).unwrap();
+        assert_eq!(query.compare, Some(CompareMode::Prev));
+        assert!(serde_json::from_str::<ListCorridorsQuery>(r#"{"compare": "last"}"#).is_err());
+        assert_ne!(
+            generate_corridor_list_cache_key(&query),
+            generate_corridor_list_cache_key(&ListCorridorsQuery::default())
+        );
+    }
+
+    #[test]
+    fn test_time_period_window_parses_supported_periods() {
+        assert_eq!(time_period_window(None).unwrap(), None);
+        assert_eq!(
+            time_period_window(Some("24h")).unwrap(),
+            Some(chrono::Duration::hours(24))
+        );
+        assert_eq!(
+            time_period_window(Some("7d")).unwrap(),
+            Some(chrono::Duration::days(7))
+        );
+        assert_eq!(
+            time_period_window(Some("30d")).unwrap(),
+            Some(chrono::Duration::days(30))
+        );
+        assert_eq!(
+            time_period_window(Some("90d")).unwrap(),
+            Some(chrono::Duration::days(90))
+        );
+
+        assert!(matches!(
+            time_period_window(Some("1y")),
+            Err(ApiError::BadRequest { .. })
+        ));
+    }
+
+    #[test]
+    fn test_retain_within_window_filters_by_created_at() {
+        let now = chrono::Utc::now();
+        let outcome = |id: &str, created_at: String| PaymentOutcome {
+            payment: crate::rpc::Payment {
+                id: id.to_string(),
+                paging_token: format!("token_{}", id),
+                transaction_hash: format!("hash_{}", id),
+                source_account: "GTEST".to_string(),
+                destination: "GDEST".to_string(),
+                asset_type: "native".to_string(),
+                asset_code: None,
+                asset_issuer: None,
+                amount: "10.0".to_string(),
+                created_at,
+                operation_type: Some("payment".to_string()),
+                source_asset_type: None,
+                source_asset_code: None,
+                source_asset_issuer: None,
+                source_amount: None,
+                from: Some("GTEST".to_string()),
+                to: Some("GDEST".to_string()),
+                asset_balance_changes: None,
+            },
+            successful: true,
+        };
+        let payments = vec![
+            outcome("hour", (now - chrono::Duration::hours(1)).to_rfc3339()),
+            outcome("days", (now - chrono::Duration::days(3)).to_rfc3339()),
+            outcome("weeks", (now - chrono::Duration::days(20)).to_rfc3339()),
+            outcome("months", (now - chrono::Duration::days(60)).to_rfc3339()),
+            outcome("undated", "yesterday".to_string()),
+        ];
+
+        let kept = |period: &str| {
+            let window = time_period_window(Some(period)).unwrap().unwrap();
+            let mut payments = payments.clone();
+            retain_within_window(&mut payments, window, now);
+            payments
+                .into_iter()
+                .map(|outcome| outcome.payment.id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(kept("24h"), vec!["hour"]);
+        assert_eq!(kept("7d"), vec!["hour", "days"]);
+        assert_eq!(kept("30d"), vec!["hour", "days", "weeks"]);
+        assert_eq!(kept("90d"), vec!["hour", "days", "weeks", "months"]);
+    }
+
+    #[test]
+    fn test_provenance_labels_simulated_latency_and_rpc_volume() {
+        let mut corridor = directed_corridor("USDC:issuer->XLM:native", 10, 9, 1_000.0);
+
+        let live = field_provenance(&corridor, false);
+        assert_eq!(live["average_latency_ms"], DataProvenance::Simulated);
+        assert_eq!(live["liquidity_depth_usd"], DataProvenance::Rpc);
+        assert_eq!(live["success_rate"], DataProvenance::Rpc);
+        assert!(!live.contains_key("trend"));
+
+        // From the cache the volume is no longer live, the latency still an estimate
+        corridor.trend = Some(CorridorTrend::default());
+        let cached = field_provenance(&corridor, true);
+        assert_eq!(cached["average_latency_ms"], DataProvenance::Simulated);
+        assert_eq!(cached["liquidity_depth_usd"], DataProvenance::Cached);
+        assert_eq!(cached["trend"], DataProvenance::Cached);
+
+        corridor.source = CorridorSource::Pool;
+        let pool = field_provenance(&corridor, false);
+        assert_eq!(pool["success_rate"], DataProvenance::Simulated);
+        assert_eq!(pool["trend"], DataProvenance::DbAggregate);
+
+        corridor.provenance = Some(pool);
+        let json = serde_json::to_value(&corridor).unwrap();
+        assert_eq!(json["provenance"]["p95_latency_ms"], "simulated");
+        assert_eq!(json["provenance"]["liquidity_depth_usd"], "rpc");
+        assert_eq!(json["provenance"]["trend"], "db_aggregate");
+    }
+
+    #[test]
+    fn test_explain_query_param() {
+        let query: ListCorridorsQuery =
+            serde_json::from_str(r#"{"explain": "provenance"}"#).unwrap();
+        assert_eq!(query.explain, Some(ExplainMode::Provenance));
+        assert!(serde_json::from_str::<ListCorridorsQuery>(r#"{"explain": "all"}"#).is_err());
+    }
 }
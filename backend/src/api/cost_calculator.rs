@@ -10,6 +10,7 @@ use std::collections::BTreeSet;
 use std::sync::Arc;
 use utoipa::ToSchema;
 
+use crate::error::ApiJson;
 use crate::http_cache::cached_json_response;
 use crate::services::price_feed::PriceFeedClient;
 
@@ -154,7 +155,7 @@ impl RouteFees {
 pub async fn estimate_costs(
     State(price_feed): State<Arc<PriceFeedClient>>,
     request_headers: HeaderMap,
-    Json(request): Json<CostCalculationRequest>,
+    ApiJson(request): ApiJson<CostCalculationRequest>,
 ) -> Response {
     let source_currency = normalize_currency(&request.source_currency);
     let destination_currency = normalize_currency(&request.destination_currency);
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 use crate::email::scheduler::DigestScheduler;
-use crate::error::ApiResult;
+use crate::error::{ApiJson, ApiResult};
 
 #[derive(Deserialize)]
 pub struct SendDigestRequest {
@@ -19,7 +19,7 @@ pub struct SendDigestResponse {
 
 pub async fn send_digest_manual(
     State(scheduler): State<Arc<DigestScheduler>>,
-    Json(req): Json<SendDigestRequest>,
+    ApiJson(req): ApiJson<SendDigestRequest>,
 ) -> ApiResult<Json<SendDigestResponse>> {
     // Trigger manual digest send
     match scheduler.send_digest(&req.period).await {
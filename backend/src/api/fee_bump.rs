@@ -3,10 +3,12 @@ use axum::{
     routing::get,
     Json, Router,
 };
+use chrono::{Duration, Utc};
 use serde::Deserialize;
 use std::sync::Arc;
 
-use crate::models::{FeeBumpStats, FeeBumpTransaction};
+use crate::error::{ApiError, ApiResult};
+use crate::models::{FeeBumpAnalytics, FeeBumpStats, FeeBumpTransaction};
 use crate::services::fee_bump_tracker::FeeBumpTrackerService;
 
 #[derive(Deserialize)]
@@ -19,10 +21,38 @@ fn default_limit() -> i64 {
     50
 }
 
+#[derive(Deserialize)]
+pub struct FeeBumpAnalyticsParams {
+    /// Window ending now: 24h, 7d or 30d (default: 24h)
+    #[serde(default = "default_window")]
+    window: String,
+    /// Number of top fee-paying accounts to return (default: 10, max: 100)
+    #[serde(default = "default_top")]
+    top: i64,
+}
+
+fn default_window() -> String {
+    "24h".to_string()
+}
+
+fn default_top() -> i64 {
+    10
+}
+
+fn window_duration(window: &str) -> Option<Duration> {
+    match window {
+        "24h" => Some(Duration::hours(24)),
+        "7d" => Some(Duration::days(7)),
+        "30d" => Some(Duration::days(30)),
+        _ => None,
+    }
+}
+
 pub fn routes(fee_bump_service: Arc<FeeBumpTrackerService>) -> Router {
     Router::new()
         .route("/stats", get(get_fee_bump_stats))
         .route("/recent", get(get_recent_fee_bumps))
+        .route("/analytics", get(get_fee_bump_analytics))
         .with_state(fee_bump_service)
 }
 
@@ -55,3 +85,28 @@ async fn get_recent_fee_bumps(
         .unwrap_or_default();
     Json(transactions)
 }
+
+async fn get_fee_bump_analytics(
+    State(service): State<Arc<FeeBumpTrackerService>>,
+    Query(params): Query<FeeBumpAnalyticsParams>,
+) -> ApiResult<Json<FeeBumpAnalytics>> {
+    let window = window_duration(&params.window).ok_or_else(|| {
+        ApiError::bad_request(
+            "INVALID_WINDOW",
+            format!(
+                "Unsupported window '{}': expected 24h, 7d or 30d",
+                params.window
+            ),
+        )
+    })?;
+
+    let to = Utc::now();
+    let analytics = service
+        .get_fee_bump_analytics(to - window, to, params.top.clamp(1, 100))
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to load fee bump analytics: {}", e);
+            ApiError::internal("DATABASE_ERROR", "Failed to load fee bump analytics")
+        })?;
+    Ok(Json(analytics))
+}
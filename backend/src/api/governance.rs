@@ -10,8 +10,10 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tracing::info;
 
+use crate::api::limits::check_limit;
 use crate::auth::sep10_middleware::{sep10_auth_middleware, Sep10User};
 use crate::auth::sep10_simple::Sep10Service;
+use crate::error::{ApiError, ApiJson};
 use crate::services::governance::{
     AddCommentRequest, CastVoteRequest, CreateProposalRequest, GovernanceService,
 };
@@ -49,6 +51,9 @@ fn default_limit() -> i64 {
     20
 }
 
+/// Largest `limit` accepted by the governance list endpoints
+const MAX_LIMIT: i64 = 100;
+
 #[derive(Debug, Deserialize)]
 pub struct VotesQuery {
     #[serde(default = "default_votes_limit")]
@@ -93,7 +98,7 @@ pub struct HasVotedResponse {
 async fn create_proposal(
     State(service): State<Arc<GovernanceService>>,
     sep10_user: axum::Extension<Sep10User>,
-    Json(request): Json<CreateProposalRequest>,
+    ApiJson(request): ApiJson<CreateProposalRequest>,
 ) -> Result<Response, GovernanceError> {
     info!("Create proposal request from {}", sep10_user.account);
 
@@ -110,7 +115,7 @@ async fn activate_proposal(
     State(service): State<Arc<GovernanceService>>,
     Path(id): Path<String>,
     sep10_user: axum::Extension<Sep10User>,
-    Json(request): Json<ActivateRequest>,
+    ApiJson(request): ApiJson<ActivateRequest>,
 ) -> Result<Response, GovernanceError> {
     info!(
         "Activate proposal {} request from {}",
@@ -130,7 +135,7 @@ async fn list_proposals(
     State(service): State<Arc<GovernanceService>>,
     Query(query): Query<ListProposalsQuery>,
 ) -> Result<Response, GovernanceError> {
-    let limit = query.limit.min(100).max(1);
+    let limit = check_limit(query.limit, MAX_LIMIT)?;
     let offset = query.offset.max(0);
 
     let response = service
@@ -159,7 +164,7 @@ async fn cast_vote(
     State(service): State<Arc<GovernanceService>>,
     Path(id): Path<String>,
     sep10_user: axum::Extension<Sep10User>,
-    Json(request): Json<CastVoteRequest>,
+    ApiJson(request): ApiJson<CastVoteRequest>,
 ) -> Result<Response, GovernanceError> {
     info!(
         "Vote on proposal {} from {}: {}",
@@ -180,7 +185,7 @@ async fn get_votes(
     Path(id): Path<String>,
     Query(query): Query<VotesQuery>,
 ) -> Result<Response, GovernanceError> {
-    let limit = query.limit.min(100).max(1);
+    let limit = check_limit(query.limit, MAX_LIMIT)?;
 
     let response = service
         .get_votes(&id, limit)
@@ -208,7 +213,7 @@ async fn add_comment(
     State(service): State<Arc<GovernanceService>>,
     Path(id): Path<String>,
     sep10_user: axum::Extension<Sep10User>,
-    Json(request): Json<AddCommentRequest>,
+    ApiJson(request): ApiJson<AddCommentRequest>,
 ) -> Result<Response, GovernanceError> {
     info!("Add comment to proposal {} from {}", id, sep10_user.account);
 
@@ -226,7 +231,7 @@ async fn get_comments(
     Path(id): Path<String>,
     Query(query): Query<CommentsQuery>,
 ) -> Result<Response, GovernanceError> {
-    let limit = query.limit.min(100).max(1);
+    let limit = check_limit(query.limit, MAX_LIMIT)?;
 
     let response = service
         .get_comments(&id, limit)
@@ -241,6 +246,13 @@ pub enum GovernanceError {
     BadRequest(String),
     NotFound(String),
     DatabaseError(String),
+    Api(ApiError),
+}
+
+impl From<ApiError> for GovernanceError {
+    fn from(error: ApiError) -> Self {
+        GovernanceError::Api(error)
+    }
 }
 
 impl IntoResponse for GovernanceError {
@@ -249,6 +261,7 @@ impl IntoResponse for GovernanceError {
             GovernanceError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
             GovernanceError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
             GovernanceError::DatabaseError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
+            GovernanceError::Api(error) => return error.into_response(),
         };
 
         let body = Json(ErrorResponse { error: message });
@@ -0,0 +1,299 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::self_check::{run_checks, DependencyProbe, DependencyStatus};
+use crate::shutdown::ShutdownCoordinator;
+use crate::supervisor::{TaskHealth, TaskSupervisor};
+
+/// Per-component bound so a hung dependency cannot stall the readiness probe
+const COMPONENT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Set once startup work (migrations, initial sync) has finished
+#[derive(Clone, Default)]
+pub struct StartupStatus(Arc<AtomicBool>);
+
+impl StartupStatus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn mark_complete(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
+
+#[derive(Clone)]
+pub struct HealthState {
+    pub supervisor: Arc<TaskSupervisor>,
+    pub probes: Arc<Vec<Arc<dyn DependencyProbe>>>,
+    pub startup: StartupStatus,
+    pub shutdown: Arc<ShutdownCoordinator>,
+}
+
+#[derive(Serialize)]
+pub struct ProbeStatusResponse {
+    pub status: &'static str,
+}
+
+#[derive(Serialize)]
+pub struct ReadinessResponse {
+    pub status: &'static str,
+    pub components: Vec<DependencyStatus>,
+    pub tasks: Vec<TaskHealth>,
+}
+
+impl ReadinessResponse {
+    fn new(components: Vec<DependencyStatus>, tasks: Vec<TaskHealth>, ready: bool) -> Self {
+        Self {
+            status: if ready { "ready" } else { "not_ready" },
+            components,
+            tasks,
+        }
+    }
+}
+
+/// Handler for GET /health/live - Liveness probe for Kubernetes
+///
+/// Touches no dependencies; it only fails if the runtime can no longer
+/// schedule the handler.
+pub async fn liveness() -> Json<ProbeStatusResponse> {
+    Json(ProbeStatusResponse { status: "alive" })
+}
+
+/// Handler for GET /health/startup - Startup probe for Kubernetes
+///
+/// Returns 503 until migrations and the initial sync have completed.
+pub async fn startup(State(state): State<HealthState>) -> Response {
+    if state.startup.is_complete() {
+        (
+            StatusCode::OK,
+            Json(ProbeStatusResponse { status: "started" }),
+        )
+            .into_response()
+    } else {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(ProbeStatusResponse { status: "starting" }),
+        )
+            .into_response()
+    }
+}
+
+/// Handler for GET /health/ready - Readiness probe for Kubernetes
+///
+/// Checks Horizon RPC, SQLite, Redis and the cache manager, and reports
+/// supervised background tasks. Returns 503 until startup has completed,
+/// once shutdown has begun draining, and while any critical component is down
+/// or any task is restarting or stopped.
+pub async fn readiness(State(state): State<HealthState>) -> Response {
+    let report = run_checks(&state.probes, COMPONENT_TIMEOUT).await;
+    let draining = state.shutdown.is_draining();
+    let ready = !draining
+        && state.startup.is_complete()
+        && report.critical_up()
+        && state.supervisor.is_ready();
+    let mut response =
+        ReadinessResponse::new(report.dependencies, state.supervisor.task_health(), ready);
+    if draining {
+        response.status = "draining";
+    }
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (status, Json(response)).into_response()
+}
+
+pub fn routes(
+    supervisor: Arc<TaskSupervisor>,
+    probes: Vec<Arc<dyn DependencyProbe>>,
+    startup_status: StartupStatus,
+    shutdown: Arc<ShutdownCoordinator>,
+) -> Router {
+    Router::new()
+        .route("/health/live", get(liveness))
+        .route("/health/startup", get(startup))
+        .route("/health/ready", get(readiness))
+        .with_state(HealthState {
+            supervisor,
+            probes: Arc::new(probes),
+            startup: startup_status,
+            shutdown,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shutdown::{ShutdownConfig, ShutdownCoordinator};
+    use crate::supervisor::SupervisorConfig;
+    use tower::ServiceExt;
+
+    struct StaticProbe {
+        name: &'static str,
+        critical: bool,
+        up: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl DependencyProbe for StaticProbe {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn critical(&self) -> bool {
+            self.critical
+        }
+
+        async fn probe(&self) -> Result<(), String> {
+            if self.up {
+                Ok(())
+            } else {
+                Err(format!("{} unreachable", self.name))
+            }
+        }
+    }
+
+    fn state(components: &[(&'static str, bool, bool)]) -> HealthState {
+        let coordinator = Arc::new(ShutdownCoordinator::new(ShutdownConfig::default()));
+        let probes = components
+            .iter()
+            .map(|&(name, critical, up)| {
+                Arc::new(StaticProbe { name, critical, up }) as Arc<dyn DependencyProbe>
+            })
+            .collect();
+        HealthState {
+            supervisor: Arc::new(TaskSupervisor::new(
+                SupervisorConfig::default(),
+                Arc::clone(&coordinator),
+            )),
+            probes: Arc::new(probes),
+            startup: started(),
+            shutdown: coordinator,
+        }
+    }
+
+    fn started() -> StartupStatus {
+        let startup = StartupStatus::new();
+        startup.mark_complete();
+        startup
+    }
+
+    async fn body_json(response: Response) -> serde_json::Value {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_ready_when_only_non_critical_component_down() {
+        let response = readiness(State(state(&[
+            ("stellar_rpc", true, true),
+            ("database", true, true),
+            ("redis", false, false),
+            ("cache", false, true),
+        ])))
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = body_json(response).await;
+        assert_eq!(body["status"], "ready");
+        let components = body["components"].as_array().unwrap();
+        assert_eq!(components.len(), 4);
+        assert_eq!(components[2]["name"], "redis");
+        assert_eq!(components[2]["up"], false);
+        assert!(components.iter().all(|c| c["latency_ms"].is_u64()));
+    }
+
+    #[tokio::test]
+    async fn test_not_ready_when_critical_component_down() {
+        let response = readiness(State(state(&[
+            ("stellar_rpc", true, true),
+            ("database", true, false),
+        ])))
+        .await;
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let body = body_json(response).await;
+        assert_eq!(body["status"], "not_ready");
+        assert_eq!(body["components"][1]["error"], "database unreachable");
+    }
+
+    #[tokio::test]
+    async fn test_not_ready_while_draining() {
+        let state = state(&[("stellar_rpc", true, true), ("database", true, true)]);
+        let response = readiness(State(state.clone())).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        state.shutdown.begin_draining();
+
+        let response = readiness(State(state)).await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body_json(response).await["status"], "draining");
+    }
+
+    #[tokio::test]
+    async fn test_startup_unavailable_until_init_completes() {
+        let state = HealthState {
+            startup: StartupStatus::new(),
+            ..state(&[])
+        };
+
+        let response = startup(State(state.clone())).await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body_json(response).await["status"], "starting");
+        let response = readiness(State(state.clone())).await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        state.startup.mark_complete();
+
+        let response = startup(State(state.clone())).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body_json(response).await["status"], "started");
+        let response = readiness(State(state)).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_liveness_ignores_dependencies() {
+        let state = state(&[]);
+        let router = routes(
+            state.supervisor,
+            vec![Arc::new(StaticProbe {
+                name: "database",
+                critical: true,
+                up: false,
+            })],
+            StartupStatus::new(),
+            state.shutdown,
+        );
+
+        let response = router
+            .oneshot(
+                axum::http::Request::get("/health/live")
+                    .body(axum::body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body_json(response).await["status"], "alive");
+    }
+}
@@ -0,0 +1,141 @@
+use axum::{extract::State, routing::get, Json, Router};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+use crate::database::Database;
+use crate::error::{ApiError, ApiResult};
+use crate::models::IngestionState;
+use crate::rpc::stellar::toid_ledger;
+use crate::rpc::StellarRpcClient;
+
+/// Approximate ledger close time, used to compare wall-clock age against a
+/// threshold given in ledgers
+const LEDGER_CLOSE_SECS: i64 = 5;
+
+#[derive(Clone)]
+pub struct IngestionStatusState {
+    pub db: Arc<Database>,
+    pub rpc_client: Arc<StellarRpcClient>,
+    /// Lag, in ledgers, beyond which a task is reported as lagging
+    pub lag_threshold_ledgers: u64,
+}
+
+impl IngestionStatusState {
+    pub fn new(db: Arc<Database>, rpc_client: Arc<StellarRpcClient>) -> Self {
+        Self {
+            db,
+            rpc_client,
+            lag_threshold_ledgers: lag_threshold_from_env(),
+        }
+    }
+}
+
+/// Lag threshold from INGESTION_LAG_THRESHOLD_LEDGERS (default: 120, about ten minutes)
+pub fn lag_threshold_from_env() -> u64 {
+    std::env::var("INGESTION_LAG_THRESHOLD_LEDGERS")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(120)
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct IngestionTaskStatus {
+    pub task_name: String,
+    pub last_cursor: String,
+    pub updated_at: DateTime<Utc>,
+    /// Ledger the cursor points into, when it is a Horizon paging token
+    pub cursor_ledger: Option<u64>,
+    /// Ledgers between the network's latest ledger and the cursor
+    pub ledger_lag: Option<u64>,
+    /// Seconds since the cursor last moved
+    pub age_seconds: i64,
+    /// Whether the lag exceeds the configured threshold; judged by wall-clock
+    /// age when the ledger lag is unknown
+    pub lagging: bool,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct IngestionStatusResponse {
+    /// Latest ledger reported by the RPC health check, if it was reachable
+    pub latest_ledger: Option<u64>,
+    pub lag_threshold_ledgers: u64,
+    pub tasks: Vec<IngestionTaskStatus>,
+}
+
+pub fn routes(state: IngestionStatusState) -> Router {
+    Router::new()
+        .route("/api/ingestion/status", get(get_ingestion_status))
+        .with_state(state)
+}
+
+/// Ingestion cursors and how far each task is behind the network
+///
+/// **DATA SOURCE: DATABASE + RPC**
+/// - Cursors from ingestion state; latest ledger from the RPC health check
+#[utoipa::path(
+    get,
+    path = "/api/ingestion/status",
+    responses(
+        (status = 200, description = "Ingestion cursors and lag", body = IngestionStatusResponse),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Ingestion"
+)]
+pub async fn get_ingestion_status(
+    State(state): State<IngestionStatusState>,
+) -> ApiResult<Json<IngestionStatusResponse>> {
+    let states = state.db.list_ingestion_states().await.map_err(|e| {
+        tracing::error!("Failed to load ingestion state: {}", e);
+        ApiError::internal("DATABASE_ERROR", "Failed to load ingestion state")
+    })?;
+
+    // An unreachable RPC leaves lag to be judged by cursor age alone
+    let latest_ledger = match state.rpc_client.check_health().await {
+        Ok(health) => Some(health.latest_ledger),
+        Err(e) => {
+            tracing::warn!("RPC health check failed, reporting cursor age only: {}", e);
+            None
+        }
+    };
+
+    let now = Utc::now();
+    let tasks = states
+        .into_iter()
+        .map(|task| task_status(task, latest_ledger, state.lag_threshold_ledgers, now))
+        .collect();
+
+    Ok(Json(IngestionStatusResponse {
+        latest_ledger,
+        lag_threshold_ledgers: state.lag_threshold_ledgers,
+        tasks,
+    }))
+}
+
+fn task_status(
+    task: IngestionState,
+    latest_ledger: Option<u64>,
+    lag_threshold_ledgers: u64,
+    now: DateTime<Utc>,
+) -> IngestionTaskStatus {
+    let cursor_ledger = toid_ledger(&task.last_cursor).map(u64::from);
+    let ledger_lag = latest_ledger
+        .zip(cursor_ledger)
+        .map(|(latest, cursor)| latest.saturating_sub(cursor));
+    let age_seconds = (now - task.updated_at).num_seconds().max(0);
+    let lagging = match ledger_lag {
+        Some(lag) => lag > lag_threshold_ledgers,
+        None => age_seconds > lag_threshold_ledgers as i64 * LEDGER_CLOSE_SECS,
+    };
+
+    IngestionTaskStatus {
+        task_name: task.task_name,
+        last_cursor: task.last_cursor,
+        updated_at: task.updated_at,
+        cursor_ledger,
+        ledger_lag,
+        age_seconds,
+        lagging,
+    }
+}
@@ -0,0 +1,52 @@
+use axum::{extract::State, routing::post, Json, Router};
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::error::{ApiError, ApiJson, ApiResult};
+use crate::ingestion::{DataIngestionService, LedgerReplaySummary, MAX_REPLAY_LEDGERS};
+use crate::services::fee_bump_tracker::FeeBumpTrackerService;
+
+#[derive(Clone)]
+pub struct LedgerReplayState {
+    pub ingestion: Arc<DataIngestionService>,
+    pub fee_bump_tracker: Arc<FeeBumpTrackerService>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReplayLedgersRequest {
+    pub start: u64,
+    pub end: u64,
+}
+
+/// Handler for POST /api/replay/ledgers - re-ingest an inclusive ledger range
+pub async fn replay_ledgers(
+    State(state): State<LedgerReplayState>,
+    ApiJson(request): ApiJson<ReplayLedgersRequest>,
+) -> ApiResult<Json<LedgerReplaySummary>> {
+    if request.start > request.end {
+        return Err(ApiError::bad_request(
+            "INVALID_LEDGER_RANGE",
+            "start must not be greater than end",
+        ));
+    }
+    if request.end - request.start >= MAX_REPLAY_LEDGERS {
+        return Err(ApiError::bad_request(
+            "LEDGER_RANGE_TOO_LARGE",
+            format!("A replay may cover at most {} ledgers", MAX_REPLAY_LEDGERS),
+        ));
+    }
+
+    let summary = state
+        .ingestion
+        .replay_ledgers(request.start, request.end, &state.fee_bump_tracker)
+        .await
+        .map_err(|e| ApiError::internal("REPLAY_FAILED", format!("{:#}", e)))?;
+
+    Ok(Json(summary))
+}
+
+pub fn routes(state: LedgerReplayState) -> Router {
+    Router::new()
+        .route("/api/replay/ledgers", post(replay_ledgers))
+        .with_state(state)
+}
@@ -0,0 +1,88 @@
+//! Bounds on the `limit` query parameter accepted by list endpoints.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::error::{ApiError, ApiResult};
+
+/// Largest page size accepted when `MAX_PAGE_LIMIT` is unset or invalid
+pub const DEFAULT_MAX_PAGE_LIMIT: i64 = 200;
+
+static MAX_PAGE_LIMIT: OnceLock<i64> = OnceLock::new();
+
+/// Largest `limit` a list endpoint accepts, read once from `MAX_PAGE_LIMIT`
+pub fn max_page_limit() -> i64 {
+    *MAX_PAGE_LIMIT.get_or_init(|| {
+        std::env::var("MAX_PAGE_LIMIT")
+            .ok()
+            .and_then(|v| v.parse::<i64>().ok())
+            .filter(|limit| *limit > 0)
+            .unwrap_or(DEFAULT_MAX_PAGE_LIMIT)
+    })
+}
+
+/// Reject a `limit` below 1 or above `max_limit` with a 400 naming the values
+pub fn check_limit(limit: i64, max_limit: i64) -> ApiResult<i64> {
+    if limit < 1 {
+        let mut details = HashMap::new();
+        details.insert("limit".to_string(), serde_json::json!(limit));
+        return Err(ApiError::bad_request_with_details(
+            "LIMIT_TOO_SMALL",
+            "limit must be at least 1",
+            details,
+        ));
+    }
+    if limit > max_limit {
+        let mut details = HashMap::new();
+        details.insert("limit".to_string(), serde_json::json!(limit));
+        details.insert("max_limit".to_string(), serde_json::json!(max_limit));
+        return Err(ApiError::bad_request_with_details(
+            "LIMIT_TOO_LARGE",
+            format!("limit must not exceed {}", max_limit),
+            details,
+        ));
+    }
+    Ok(limit)
+}
+
+/// `check_limit` against the configured `max_page_limit`
+pub fn validate_limit(limit: i64) -> ApiResult<i64> {
+    check_limit(limit, max_page_limit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_limit_accepts_up_to_max() {
+        assert_eq!(check_limit(50, 200).unwrap(), 50);
+        assert_eq!(check_limit(200, 200).unwrap(), 200);
+    }
+
+    #[test]
+    fn test_check_limit_rejects_oversized() {
+        match check_limit(1_000_000, 200) {
+            Err(ApiError::BadRequest { code, details, .. }) => {
+                assert_eq!(code, "LIMIT_TOO_LARGE");
+                let details = details.unwrap();
+                assert_eq!(details["max_limit"], 200);
+                assert_eq!(details["limit"], 1_000_000);
+            }
+            other => panic!("expected LIMIT_TOO_LARGE, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_check_limit_rejects_zero_and_negative() {
+        for limit in [0, -5] {
+            match check_limit(limit, 200) {
+                Err(ApiError::BadRequest { code, details, .. }) => {
+                    assert_eq!(code, "LIMIT_TOO_SMALL");
+                    assert_eq!(details.unwrap()["limit"], limit);
+                }
+                other => panic!("expected LIMIT_TOO_SMALL, got {:?}", other),
+            }
+        }
+    }
+}
@@ -8,8 +8,9 @@ use axum::{
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+use crate::api::precision::Rounded;
 use crate::cache::{keys, CacheManager};
-use crate::cache_middleware::CacheAware;
+use crate::cache_middleware::{CacheAware, CacheBypass};
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct MetricsOverview {
@@ -23,14 +24,16 @@ pub struct MetricsOverview {
 /// Handler for GET /api/metrics/overview (cached with 1 min TTL)
 pub async fn metrics_overview(
     State(cache): State<Arc<CacheManager>>,
+    bypass: CacheBypass,
     headers: HeaderMap,
 ) -> Response {
     let cache_key = keys::metrics_overview();
 
-    let overview = <()>::get_or_fetch(
+    let overview = <()>::get_or_fetch_with_bypass(
         &cache,
         &cache_key,
         cache.config.get_ttl("dashboard"),
+        bypass,
         async {
             // Placeholder: Replace with real data aggregation logic
             Ok(MetricsOverview {
@@ -52,7 +55,7 @@ pub async fn metrics_overview(
     });
 
     let ttl = cache.config.get_ttl("dashboard");
-    match crate::http_cache::cached_json_response(&headers, &cache_key, &overview, ttl) {
+    match crate::http_cache::cached_json_response(&headers, &cache_key, &Rounded(&overview), ttl) {
         Ok(response) => response,
         Err(e) => (
             axum::http::StatusCode::INTERNAL_SERVER_ERROR,
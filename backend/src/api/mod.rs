@@ -1,10 +1,15 @@
 pub mod account_merges;
+pub mod accounts;
 pub mod achievements;
+pub mod admin_cache;
+pub mod admin_config;
 pub mod alerts;
+pub mod anchor_leaderboard;
 pub mod anchors;
 pub mod anchors_cached;
 pub mod api_keys;
-
+pub mod asset_leaderboard;
+pub mod asset_verification;
 pub mod auth;
 pub mod cache_stats;
 pub mod corridors;
@@ -14,11 +19,16 @@ pub mod cost_calculator;
 pub mod api_analytics;
 pub mod fee_bump;
 pub mod governance;
+pub mod health;
+pub mod ingestion_status;
+pub mod ledger_replay;
+pub mod limits;
 pub mod liquidity_pools;
 pub mod metrics;
 pub mod metrics_cached;
 pub mod network;
 pub mod oauth;
+pub mod precision;
 pub mod prediction;
 pub mod price_feed;
 pub mod replay_handlers;
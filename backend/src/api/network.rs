@@ -1,3 +1,4 @@
+use crate::error::ApiJson;
 use crate::network::{NetworkConfig, StellarNetwork};
 use axum::{
     http::StatusCode,
@@ -76,7 +77,7 @@ pub async fn get_available_networks() -> Json<Vec<NetworkInfo>> {
 
 /// Switch network (Note: This is a placeholder - actual switching would require server restart)
 pub async fn switch_network(
-    Json(request): Json<SwitchNetworkRequest>,
+    ApiJson(request): ApiJson<SwitchNetworkRequest>,
 ) -> Result<Json<SwitchNetworkResponse>, StatusCode> {
     info!("Network switch requested to: {}", request.network);
 
@@ -154,7 +155,7 @@ mod tests {
             network: StellarNetwork::Testnet,
         };
 
-        let result = switch_network(Json(request)).await;
+        let result = switch_network(ApiJson(request)).await;
         assert!(result.is_ok());
 
         let response = result.unwrap().0;
@@ -12,6 +12,7 @@ use sqlx::SqlitePool;
 
 use crate::auth::oauth::{OAuthService, TokenResponse};
 use crate::auth_middleware::AuthUser;
+use crate::error::ApiJson;
 
 /// OAuth Token Request (for /api/oauth/token)
 #[derive(Debug, Deserialize)]
@@ -111,7 +112,7 @@ pub async fn authorize(
 /// POST /api/oauth/token - Exchange authorization code for tokens
 pub async fn token(
     State(db): State<SqlitePool>,
-    Json(request): Json<OAuthTokenRequest>,
+    ApiJson(request): ApiJson<OAuthTokenRequest>,
 ) -> Result<Response, OAuthApiError> {
     let service = OAuthService::new(db.clone());
 
@@ -235,7 +236,7 @@ pub async fn token(
 /// POST /api/oauth/revoke - Revoke an access token
 pub async fn revoke(
     State(db): State<SqlitePool>,
-    Json(request): Json<OAuthRevokeRequest>,
+    ApiJson(request): ApiJson<OAuthRevokeRequest>,
 ) -> Result<Response, OAuthApiError> {
     let service = OAuthService::new(db);
 
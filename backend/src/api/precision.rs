@@ -0,0 +1,169 @@
+//! Presentation rounding for monetary and percentage fields in API responses.
+//!
+//! Applied by wrapping a payload in [`Rounded`] as the HTTP response is
+//! serialized, so in-memory values, cached entries and the snapshot hashing
+//! path keep full precision.
+
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+use std::sync::OnceLock;
+
+/// Decimal places used when `RESPONSE_DECIMAL_PRECISION` is unset or invalid
+pub const DEFAULT_RESPONSE_DECIMALS: u32 = 2;
+/// Upper bound on configurable decimals; beyond this f64 noise shows through
+pub const MAX_RESPONSE_DECIMALS: u32 = 10;
+
+static RESPONSE_DECIMALS: OnceLock<u32> = OnceLock::new();
+
+/// Decimal places for rounded response fields, read once from the environment
+pub fn response_decimals() -> u32 {
+    *RESPONSE_DECIMALS
+        .get_or_init(|| parse_decimals(std::env::var("RESPONSE_DECIMAL_PRECISION").ok().as_deref()))
+}
+
+fn parse_decimals(raw: Option<&str>) -> u32 {
+    match raw.map(str::trim).map(str::parse::<u32>) {
+        Some(Ok(decimals)) if decimals <= MAX_RESPONSE_DECIMALS => decimals,
+        Some(_) => {
+            tracing::warn!(
+                "Invalid RESPONSE_DECIMAL_PRECISION (expected 0-{}), using {}",
+                MAX_RESPONSE_DECIMALS,
+                DEFAULT_RESPONSE_DECIMALS
+            );
+            DEFAULT_RESPONSE_DECIMALS
+        }
+        None => DEFAULT_RESPONSE_DECIMALS,
+    }
+}
+
+/// Round half away from zero to `decimals` places; non-finite values pass through
+pub fn round_to(value: f64, decimals: u32) -> f64 {
+    if !value.is_finite() {
+        return value;
+    }
+    let factor = 10f64.powi(decimals as i32);
+    (value * factor).round() / factor
+}
+
+/// Monetary and percentage fields rounded wherever they appear in a payload
+const ROUNDED_FIELDS: &[&str] = &[
+    "success_rate",
+    "failure_rate",
+    "reliability_score",
+    "avg_reliability_score",
+    "health_score",
+    "percentage",
+    "liquidity_depth_usd",
+    "liquidity_volume_24h_usd",
+    "liquidity_usd",
+    "volume_24h_usd",
+    "volume_usd",
+    "total_volume",
+    "total_volume_usd",
+    "average_transaction_value",
+    "success_rate_delta",
+    "volume_usd_delta",
+    "health_score_delta",
+];
+
+/// Response payload whose monetary and percentage fields serialize rounded
+/// to the configured precision
+#[derive(Debug, Clone)]
+pub struct Rounded<T>(pub T);
+
+impl<T: Serialize> Serialize for Rounded<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut value = serde_json::to_value(&self.0).map_err(serde::ser::Error::custom)?;
+        round_fields(&mut value, response_decimals());
+        value.serialize(serializer)
+    }
+}
+
+/// Round every non-integer number stored under a [`ROUNDED_FIELDS`] key
+fn round_fields(value: &mut Value, decimals: u32) {
+    match value {
+        Value::Object(map) => {
+            for (key, field) in map.iter_mut() {
+                if ROUNDED_FIELDS.contains(&key.as_str()) && field.is_f64() {
+                    let rounded = round_to(field.as_f64().unwrap_or_default(), decimals);
+                    if let Some(number) = serde_json::Number::from_f64(rounded) {
+                        *field = Value::Number(number);
+                    }
+                } else {
+                    round_fields(field, decimals);
+                }
+            }
+        }
+        Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| round_fields(item, decimals)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct Sample {
+        rate: f64,
+        success_rate: f64,
+        count: i64,
+        nested: Vec<Nested>,
+    }
+
+    #[derive(Serialize)]
+    struct Nested {
+        volume_usd: Option<f64>,
+    }
+
+    #[test]
+    fn test_round_to_precision() {
+        assert_eq!(round_to(99.80000000001, 2), 99.8);
+        assert_eq!(round_to(1234.5678, 0), 1235.0);
+        assert_eq!(round_to(1234.5678, 3), 1234.568);
+        assert_eq!(round_to(-0.125, 2), -0.13);
+        assert!(round_to(f64::NAN, 2).is_nan());
+    }
+
+    #[test]
+    fn test_parse_decimals_falls_back_on_invalid_input() {
+        assert_eq!(parse_decimals(None), DEFAULT_RESPONSE_DECIMALS);
+        assert_eq!(parse_decimals(Some("4")), 4);
+        assert_eq!(parse_decimals(Some("abc")), DEFAULT_RESPONSE_DECIMALS);
+        assert_eq!(parse_decimals(Some("42")), DEFAULT_RESPONSE_DECIMALS);
+    }
+
+    #[test]
+    fn test_rounded_payload_rounds_listed_fields_only() {
+        let sample = Sample {
+            rate: 99.80000000001,
+            success_rate: 99.80000000001,
+            count: 7,
+            nested: vec![Nested {
+                volume_usd: Some(1234.56789),
+            }],
+        };
+        let decimals = response_decimals();
+        let json = serde_json::to_value(Rounded(&sample)).unwrap();
+        assert_eq!(
+            json["success_rate"].as_f64().unwrap(),
+            round_to(99.80000000001, decimals)
+        );
+        assert_eq!(
+            json["nested"][0]["volume_usd"].as_f64().unwrap(),
+            round_to(1234.56789, decimals)
+        );
+        assert_eq!(json["rate"].as_f64().unwrap(), 99.80000000001);
+        assert_eq!(json["count"], 7);
+
+        // Serializing the payload itself, as the cache does, keeps full precision
+        let raw = serde_json::to_value(&sample).unwrap();
+        assert_eq!(raw["success_rate"].as_f64().unwrap(), 99.80000000001);
+    }
+}
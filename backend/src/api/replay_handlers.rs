@@ -11,7 +11,8 @@ use std::sync::Arc;
 use tracing::{error, info};
 
 use crate::{
-    error::ApiError,
+    api::limits::validate_limit,
+    error::{ApiError, ApiJson},
     replay::{
         checkpoint::CheckpointManager,
         config::{ReplayConfig, ReplayMode, ReplayRange},
@@ -60,13 +61,18 @@ pub struct ReplayResponse {
 /// Query parameters for listing replays
 #[derive(Debug, Deserialize)]
 pub struct ListReplaysQuery {
-    pub limit: Option<usize>,
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+}
+
+fn default_limit() -> i64 {
+    50
 }
 
 /// Start a new replay
 pub async fn start_replay(
     State(state): State<Arc<AppState>>,
-    Json(req): Json<StartReplayRequest>,
+    ApiJson(req): ApiJson<StartReplayRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     info!("Starting replay with request: {:?}", req);
 
@@ -184,11 +190,12 @@ pub async fn list_replays(
     Query(query): Query<ListReplaysQuery>,
 ) -> Result<impl IntoResponse, ApiError> {
     info!("Listing replay sessions");
+    let limit = validate_limit(query.limit)?;
 
     let replay_storage = ReplayStorage::new(state.db.pool().clone());
 
     let sessions = replay_storage
-        .list_sessions(query.limit)
+        .list_sessions(Some(limit as usize))
         .await
         .map_err(|e| ApiError::internal("INTERNAL_ERROR", e.to_string()))?;
 
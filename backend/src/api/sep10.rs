@@ -9,6 +9,7 @@ use serde_json::json;
 use std::sync::Arc;
 
 use crate::auth::sep10_simple::{ChallengeRequest, Sep10Service, VerificationRequest};
+use crate::error::ApiJson;
 
 /// GET /api/sep10/info - Get SEP-10 server information
 pub async fn get_info(
@@ -27,7 +28,7 @@ pub async fn get_info(
 /// POST /api/sep10/auth - Request SEP-10 challenge transaction
 pub async fn request_challenge(
     State(sep10_service): State<Arc<Sep10Service>>,
-    Json(request): Json<ChallengeRequest>,
+    ApiJson(request): ApiJson<ChallengeRequest>,
 ) -> Result<Response, Sep10ApiError> {
     let response = sep10_service
         .generate_challenge(request)
@@ -40,7 +41,7 @@ pub async fn request_challenge(
 /// POST /api/sep10/verify - Verify signed challenge transaction
 pub async fn verify_challenge(
     State(sep10_service): State<Arc<Sep10Service>>,
-    Json(request): Json<VerificationRequest>,
+    ApiJson(request): ApiJson<VerificationRequest>,
 ) -> Result<Response, Sep10ApiError> {
     let response = sep10_service
         .verify_challenge(request)
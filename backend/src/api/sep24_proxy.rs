@@ -13,6 +13,9 @@ use serde_json::Value;
 use std::sync::Arc;
 use std::time::Duration;
 
+use crate::api::limits::validate_limit;
+use crate::error::{ApiError, ApiJson};
+
 /// Allowed transfer server hosts (env: SEP24_ALLOWED_ORIGINS, comma-separated).
 /// If unset, any origin is allowed (use in dev only).
 fn allowed_origins() -> Vec<String> {
@@ -118,7 +121,7 @@ pub struct DepositInteractiveBody {
 
 pub async fn post_deposit_interactive(
     State(state): State<Sep24State>,
-    Json(body): Json<DepositInteractiveBody>,
+    ApiJson(body): ApiJson<DepositInteractiveBody>,
 ) -> Result<Json<Value>, Sep24Error> {
     if !is_origin_allowed(&body.transfer_server) {
         return Err(Sep24Error::Forbidden(
@@ -189,7 +192,7 @@ pub struct WithdrawInteractiveBody {
 
 pub async fn post_withdraw_interactive(
     State(state): State<Sep24State>,
-    Json(body): Json<WithdrawInteractiveBody>,
+    ApiJson(body): ApiJson<WithdrawInteractiveBody>,
 ) -> Result<Json<Value>, Sep24Error> {
     if !is_origin_allowed(&body.transfer_server) {
         return Err(Sep24Error::Forbidden(
@@ -267,6 +270,7 @@ pub async fn get_transactions(
         url.push_str(&format!("kind={}&", urlencoding::encode(k)));
     }
     if let Some(l) = q.limit {
+        validate_limit(i64::from(l))?;
         url.push_str(&format!("limit={}&", l));
     }
     if let Some(c) = &q.cursor {
@@ -365,11 +369,18 @@ pub enum Sep24Error {
     Forbidden(String),
     Proxy(String),
     Anchor(u16, Value),
+    Api(ApiError),
+}
+
+impl From<ApiError> for Sep24Error {
+    fn from(error: ApiError) -> Self {
+        Sep24Error::Api(error)
+    }
 }
 
 impl IntoResponse for Sep24Error {
     fn into_response(self) -> axum::response::Response {
-        let (status, body) = match &self {
+        let (status, body) = match self {
             Sep24Error::Forbidden(msg) => (
                 StatusCode::FORBIDDEN,
                 serde_json::json!({ "error": "forbidden", "message": msg }),
@@ -379,9 +390,10 @@ impl IntoResponse for Sep24Error {
                 serde_json::json!({ "error": "proxy", "message": msg }),
             ),
             Sep24Error::Anchor(code, data) => {
-                let status = StatusCode::from_u16(*code).unwrap_or(StatusCode::BAD_GATEWAY);
-                (status, data.clone())
+                let status = StatusCode::from_u16(code).unwrap_or(StatusCode::BAD_GATEWAY);
+                (status, data)
             }
+            Sep24Error::Api(error) => return error.into_response(),
         };
         (status, Json(body)).into_response()
     }
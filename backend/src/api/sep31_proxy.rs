@@ -13,6 +13,9 @@ use serde_json::Value;
 use std::sync::Arc;
 use std::time::Duration;
 
+use crate::api::limits::validate_limit;
+use crate::error::{ApiError, ApiJson};
+
 fn allowed_origins() -> Vec<String> {
     std::env::var("SEP31_ALLOWED_ORIGINS")
         .ok()
@@ -97,7 +100,7 @@ pub struct QuoteBody {
 
 pub async fn post_quote(
     State(state): State<Sep31State>,
-    Json(body): Json<QuoteBody>,
+    ApiJson(body): ApiJson<QuoteBody>,
 ) -> Result<Json<Value>, Sep31Error> {
     if !is_origin_allowed(&body.transfer_server) {
         return Err(Sep31Error::Forbidden(
@@ -139,7 +142,7 @@ pub struct CreateTransactionBody {
 
 pub async fn post_transaction(
     State(state): State<Sep31State>,
-    Json(body): Json<CreateTransactionBody>,
+    ApiJson(body): ApiJson<CreateTransactionBody>,
 ) -> Result<Json<Value>, Sep31Error> {
     if !is_origin_allowed(&body.transfer_server) {
         return Err(Sep31Error::Forbidden(
@@ -198,6 +201,7 @@ pub async fn get_transactions(
         url.push_str(&format!("status={}&", urlencoding::encode(s)));
     }
     if let Some(l) = q.limit {
+        validate_limit(i64::from(l))?;
         url.push_str(&format!("limit={}&", l));
     }
     if let Some(c) = &q.cursor {
@@ -328,7 +332,7 @@ pub struct PutCustomerBody {
 
 pub async fn put_customer(
     State(state): State<Sep31State>,
-    Json(body): Json<PutCustomerBody>,
+    ApiJson(body): ApiJson<PutCustomerBody>,
 ) -> Result<Json<Value>, Sep31Error> {
     if !is_origin_allowed(&body.transfer_server) {
         return Err(Sep31Error::Forbidden(
@@ -380,11 +384,18 @@ pub enum Sep31Error {
     Forbidden(String),
     Proxy(String),
     Anchor(u16, Value),
+    Api(ApiError),
+}
+
+impl From<ApiError> for Sep31Error {
+    fn from(error: ApiError) -> Self {
+        Sep31Error::Api(error)
+    }
 }
 
 impl IntoResponse for Sep31Error {
     fn into_response(self) -> axum::response::Response {
-        let (status, body) = match &self {
+        let (status, body) = match self {
             Sep31Error::Forbidden(msg) => (
                 StatusCode::FORBIDDEN,
                 serde_json::json!({ "error": "forbidden", "message": msg }),
@@ -394,9 +405,10 @@ impl IntoResponse for Sep31Error {
                 serde_json::json!({ "error": "proxy", "message": msg }),
             ),
             Sep31Error::Anchor(code, data) => {
-                let status = StatusCode::from_u16(*code).unwrap_or(StatusCode::BAD_GATEWAY);
-                (status, data.clone())
+                let status = StatusCode::from_u16(code).unwrap_or(StatusCode::BAD_GATEWAY);
+                (status, data)
             }
+            Sep31Error::Api(error) => return error.into_response(),
         };
         (status, Json(body)).into_response()
     }
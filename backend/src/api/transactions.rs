@@ -1,6 +1,7 @@
 use axum::{
     extract::{Path, State},
     http::StatusCode,
+    middleware,
     routing::{get, post},
     Json, Router,
 };
@@ -9,8 +10,11 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::{
+    body_limit_middleware::{body_limit_middleware, BodyLimit, DEFAULT_TRANSACTION_BODY_LIMIT},
     database::Database,
+    error::ApiJson,
     models::{PendingTransaction, PendingTransactionWithSignatures, Signature, TransactionResult},
+    rpc::{AccountSigner, StellarRpcClient, TransactionState},
     state::AppState,
 };
 
@@ -20,6 +24,10 @@ pub struct CreateTransactionRequest {
     pub source_account: String,
     pub xdr: String,
     pub required_signatures: i32,
+    /// Retrying with the same key and request returns the original
+    /// transaction; reusing it with a different request is rejected with 422
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -30,21 +38,35 @@ pub struct AddSignatureRequest {
 
 // Routes
 pub fn routes() -> Router<AppState> {
+    // XDR and signatures are small; bound what the mutating routes buffer
+    let body_limit =
+        BodyLimit::from_env("TRANSACTION_MAX_BODY_BYTES", DEFAULT_TRANSACTION_BODY_LIMIT);
+
     Router::new()
         .route("/", post(create_transaction))
-        .route("/:id", get(get_transaction))
         .route("/:id/signatures", post(add_signature))
         .route("/:id/submit", post(submit_transaction))
+        .route("/:id/reconcile", post(reconcile_transaction))
+        .route_layer(middleware::from_fn_with_state(
+            body_limit,
+            body_limit_middleware,
+        ))
+        .route("/:id", get(get_transaction))
 }
 
 // Handlers
 pub async fn create_transaction(
     State(state): State<AppState>,
-    Json(req): Json<CreateTransactionRequest>,
+    ApiJson(req): ApiJson<CreateTransactionRequest>,
 ) -> Result<Json<PendingTransaction>, (StatusCode, String)> {
     let tx = state
         .db
-        .create_pending_transaction(&req.source_account, &req.xdr, req.required_signatures)
+        .create_pending_transaction(
+            &req.source_account,
+            &req.xdr,
+            req.required_signatures,
+            req.idempotency_key.as_deref().filter(|key| !key.is_empty()),
+        )
         .await
         .map_err(|e| {
             tracing::error!("Failed to create transaction: {}", e);
@@ -54,6 +76,14 @@ pub async fn create_transaction(
             )
         })?;
 
+    // A replayed key must carry the request it was first used with
+    if tx.xdr != req.xdr || tx.required_signatures != req.required_signatures {
+        return Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Idempotency key was already used with a different request".to_string(),
+        ));
+    }
+
     Ok(Json(tx))
 }
 
@@ -79,7 +109,7 @@ pub async fn get_transaction(
 pub async fn add_signature(
     State(state): State<AppState>,
     Path(id): Path<String>,
-    Json(req): Json<AddSignatureRequest>,
+    ApiJson(req): ApiJson<AddSignatureRequest>,
 ) -> Result<StatusCode, (StatusCode, String)> {
     // Basic validation
     let tx_opt = state.db.get_pending_transaction(&id).await.map_err(|_| {
@@ -104,6 +134,25 @@ pub async fn add_signature(
         ));
     }
 
+    let signers = state
+        .ingestion
+        .rpc_client()
+        .fetch_account_signers(&tx_with_sigs.transaction.source_account)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch account signers: {}", e);
+            (
+                StatusCode::BAD_GATEWAY,
+                "Failed to fetch account signers".to_string(),
+            )
+        })?;
+    if !is_authorized_signer(&signers, &req.signer) {
+        return Err((
+            StatusCode::FORBIDDEN,
+            "Signer is not authorized for the source account".to_string(),
+        ));
+    }
+
     state
         .db
         .add_transaction_signature(&id, &req.signer, &req.signature)
@@ -116,15 +165,25 @@ pub async fn add_signature(
             )
         })?;
 
-    // Update status if we reached required signatures
-    let current_sigs_count = tx_with_sigs.collected_signatures.len() + 1;
-    if current_sigs_count as i32 >= tx_with_sigs.transaction.required_signatures {
-        state.db.update_transaction_status(&id, "ready").await.ok();
-    }
+    // Flips the status to "ready" once enough signers have signed
+    state.db.check_transaction_ready(&id).await.map_err(|e| {
+        tracing::error!("Failed to check transaction readiness: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Database error".to_string(),
+        )
+    })?;
 
     Ok(StatusCode::CREATED)
 }
 
+/// A signer must be an ed25519 key on the account with a non-zero weight
+pub fn is_authorized_signer(signers: &[AccountSigner], signer: &str) -> bool {
+    signers
+        .iter()
+        .any(|s| s.key == signer && s.weight > 0 && s.signer_type == "ed25519_public_key")
+}
+
 pub async fn submit_transaction(
     State(state): State<AppState>,
     Path(id): Path<String>,
@@ -156,7 +215,7 @@ pub async fn submit_transaction(
     // Update status in DB
     state
         .db
-        .update_transaction_status(&id, "submitted")
+        .record_transaction_submission(&id, &mock_hash)
         .await
         .ok();
 
@@ -165,3 +224,52 @@ pub async fn submit_transaction(
         status: "success".to_string(),
     }))
 }
+
+/// Poll RPC for a submitted transaction and record its outcome
+pub async fn reconcile_transaction(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<PendingTransaction>, (StatusCode, String)> {
+    let tx = reconcile_submitted_transaction(&state.db, state.ingestion.rpc_client(), &id)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to reconcile transaction: {}", e);
+            (
+                StatusCode::BAD_GATEWAY,
+                "Failed to reconcile transaction".to_string(),
+            )
+        })?;
+
+    tx.map(Json)
+        .ok_or((StatusCode::NOT_FOUND, "Transaction not found".to_string()))
+}
+
+/// Look a submitted transaction up with `getTransaction` and move it to
+/// "success" or "failed" once it has landed. Transactions that are not
+/// submitted, or that RPC still reports as pending or unknown, are unchanged.
+pub async fn reconcile_submitted_transaction(
+    db: &Database,
+    rpc_client: &StellarRpcClient,
+    id: &str,
+) -> anyhow::Result<Option<PendingTransaction>> {
+    let Some(tx) = db.get_pending_transaction(id).await? else {
+        return Ok(None);
+    };
+    let mut tx = tx.transaction;
+
+    let hash = match tx.transaction_hash.as_deref() {
+        Some(hash) if tx.status == "submitted" => hash,
+        _ => return Ok(Some(tx)),
+    };
+
+    let outcome = rpc_client.get_transaction(hash).await?;
+    let status = match outcome.status {
+        TransactionState::Success => "success",
+        TransactionState::Failed => "failed",
+        TransactionState::Pending | TransactionState::NotFound => return Ok(Some(tx)),
+    };
+
+    db.update_transaction_status(id, status).await?;
+    tx.status = status.to_string();
+    Ok(Some(tx))
+}
@@ -8,19 +8,21 @@ use axum::{
 use serde::Deserialize;
 use std::sync::Arc;
 
-use crate::models::{TrustlineMetrics, TrustlineSnapshot, TrustlineStat};
-use crate::services::trustline_analyzer::TrustlineAnalyzer;
+use crate::models::{TrustlineAssetDetail, TrustlineMetrics, TrustlineSnapshot, TrustlineStat};
+use crate::services::trustline_analyzer::{trend_window_days, TrustlineAnalyzer};
 
 pub type ApiResult<T> = Result<T, ApiError>;
 
 #[derive(Debug)]
 pub enum ApiError {
+    NotFound(String),
     Internal(String),
 }
 
 impl IntoResponse for ApiError {
     fn into_response(self) -> Response {
         let (status, message) = match self {
+            ApiError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
             ApiError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
         };
         (status, message).into_response()
@@ -53,10 +55,17 @@ fn default_history_limit() -> i64 {
     30 // 30 days
 }
 
+#[derive(Deserialize)]
+pub struct DetailParams {
+    /// Trend window; defaults to `TRUSTLINE_TREND_WINDOW_DAYS`
+    window_days: Option<i64>,
+}
+
 pub fn routes(analyzer: Arc<TrustlineAnalyzer>) -> Router {
     Router::new()
         .route("/stats", get(get_trustline_metrics))
         .route("/rankings", get(get_trustline_rankings))
+        .route("/:asset_code/:asset_issuer", get(get_trustline_detail))
         .route(
             "/:asset_code/:asset_issuer/history",
             get(get_trustline_history),
@@ -99,3 +108,27 @@ async fn get_trustline_history(
         .unwrap_or_default();
     Ok(Json(history))
 }
+
+async fn get_trustline_detail(
+    State(analyzer): State<Arc<TrustlineAnalyzer>>,
+    Path((asset_code, asset_issuer)): Path<(String, String)>,
+    Query(params): Query<DetailParams>,
+) -> ApiResult<Json<TrustlineAssetDetail>> {
+    let window_days = params
+        .window_days
+        .unwrap_or_else(trend_window_days)
+        .clamp(1, 365);
+    let stat = analyzer
+        .get_asset_stat(&asset_code, &asset_issuer)
+        .await?
+        .ok_or_else(|| {
+            ApiError::NotFound(format!(
+                "No trustline stats for {}:{}",
+                asset_code, asset_issuer
+            ))
+        })?;
+    let trend = analyzer
+        .get_trustline_trend(&asset_code, &asset_issuer, window_days)
+        .await?;
+    Ok(Json(TrustlineAssetDetail { stat, trend }))
+}
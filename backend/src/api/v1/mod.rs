@@ -17,7 +17,7 @@ use crate::state::AppState;
 use axum::{
     middleware,
     routing::{get, put},
-    Router,
+    Extension, Router,
 };
 use std::sync::Arc;
 use tower::ServiceBuilder;
@@ -40,6 +40,7 @@ pub fn routes(
     cors: CorsLayer,
     pool: sqlx::SqlitePool,
     cache: Arc<CacheManager>,
+    health_weights: corridors_cached::HealthScoreWeights,
 ) -> Router {
     // 1. Cached routes
     let cached_routes = Router::new()
@@ -49,7 +50,8 @@ pub fn routes(
             "/corridors/:corridor_key",
             get(corridors_cached::get_corridor_detail),
         )
-        .with_state(cached_state);
+        .with_state(cached_state)
+        .layer(Extension(health_weights));
 
     // 2. Public anchor routes
     let public_anchor_routes = Router::new()
@@ -61,6 +63,10 @@ pub fn routes(
         )
         .route("/anchors/:id/assets", get(get_anchor_assets))
         .route("/analytics/muxed", get(get_muxed_analytics))
+        .route(
+            "/analytics/muxed/:base_account",
+            get(get_muxed_account_usage),
+        )
         .with_state(app_state.clone());
 
     // 3. Protected anchor routes
@@ -14,6 +14,7 @@ use tracing::info;
 
 use crate::auth::sep10_middleware::{sep10_auth_middleware, Sep10User};
 use crate::auth::sep10_simple::Sep10Service;
+use crate::error::ApiJson;
 use crate::services::verification_rewards::{VerificationRewardsService, VerifySnapshotRequest};
 
 /// Build verification rewards routes
@@ -67,7 +68,7 @@ pub struct ErrorResponse {
 pub async fn verify_snapshot(
     State(service): State<Arc<VerificationRewardsService>>,
     sep10_user: axum::Extension<Sep10User>,
-    Json(request): Json<VerifySnapshotRequest>,
+    ApiJson(request): ApiJson<VerifySnapshotRequest>,
 ) -> Result<Response, VerificationError> {
     info!(
         "Verification request from user {} for snapshot {}",
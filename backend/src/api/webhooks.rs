@@ -3,20 +3,21 @@ use axum::{
     extract::{Path, State},
     http::StatusCode,
     response::{IntoResponse, Response},
-    routing::{delete, post},
+    routing::{delete, get, post},
     Json, Router,
 };
 use serde_json::json;
 use sqlx::SqlitePool;
 
 use crate::auth_middleware::AuthUser;
+use crate::error::ApiJson;
 use crate::webhooks::{CreateWebhookRequest, WebhookResponse, WebhookService};
 
 /// POST /api/webhooks - Register a new webhook
 pub async fn register_webhook(
     State(db): State<SqlitePool>,
     auth_user: AuthUser,
-    Json(request): Json<CreateWebhookRequest>,
+    ApiJson(request): ApiJson<CreateWebhookRequest>,
 ) -> Result<Response, WebhookApiError> {
     // Validate URL scheme
     if !request.url.starts_with("https://") && !request.url.starts_with("http://") {
@@ -184,6 +185,45 @@ pub async fn test_webhook(
         .into_response())
 }
 
+/// GET /api/webhooks/deliveries/dead - List dead-lettered deliveries
+pub async fn list_dead_deliveries(
+    State(db): State<SqlitePool>,
+    auth_user: AuthUser,
+) -> Result<Response, WebhookApiError> {
+    let service = WebhookService::new(db);
+    let deliveries = service
+        .list_dead_deliveries(&auth_user.user_id)
+        .await
+        .map_err(|e| WebhookApiError::ServerError(e.to_string()))?;
+
+    Ok((StatusCode::OK, Json(json!({"deliveries": deliveries}))).into_response())
+}
+
+/// POST /api/webhooks/deliveries/:id/retry - Re-enqueue a dead-lettered delivery
+pub async fn retry_dead_delivery(
+    State(db): State<SqlitePool>,
+    auth_user: AuthUser,
+    Path(delivery_id): Path<String>,
+) -> Result<Response, WebhookApiError> {
+    let service = WebhookService::new(db);
+    let requeued = service
+        .requeue_dead_delivery(&delivery_id, &auth_user.user_id)
+        .await
+        .map_err(|e| WebhookApiError::ServerError(e.to_string()))?;
+
+    if !requeued {
+        return Err(WebhookApiError::NotFound(
+            "Dead-lettered delivery not found".to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(json!({"message": "Delivery re-enqueued for retry"})),
+    )
+        .into_response())
+}
+
 /// Webhook API Error types
 #[derive(Debug)]
 pub enum WebhookApiError {
@@ -215,5 +255,10 @@ pub fn routes(db: SqlitePool) -> Router {
         .route("/api/webhooks", post(register_webhook).get(list_webhooks))
         .route("/api/webhooks/:id", delete(delete_webhook))
         .route("/api/webhooks/:id/test", post(test_webhook))
+        .route("/api/webhooks/deliveries/dead", get(list_dead_deliveries))
+        .route(
+            "/api/webhooks/deliveries/:id/retry",
+            post(retry_dead_delivery),
+        )
         .with_state(db)
 }
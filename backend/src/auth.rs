@@ -1,5 +1,6 @@
 // pub mod sep10;  // Commented out - uses stellar-xdr types that require stellar-base
 pub mod oauth;
+pub mod refresh_tokens;
 pub mod sep10_middleware;
 pub mod sep10_simple;
 
@@ -7,10 +8,12 @@ use anyhow::{anyhow, Result};
 use chrono::{Duration, Utc};
 use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
 use redis::aio::MultiplexedConnection;
-use redis::AsyncCommands;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use refresh_tokens::{RedisRefreshTokenStore, RefreshTokenStore, Rotation};
 
 // Token expiry constants
 const ACCESS_TOKEN_EXPIRY_HOURS: i64 = 1;
@@ -51,6 +54,8 @@ pub struct RefreshTokenRequest {
 #[derive(Debug, Serialize)]
 pub struct RefreshTokenResponse {
     pub access_token: String,
+    /// Replaces the presented refresh token, which can no longer be used
+    pub refresh_token: String,
     pub expires_in: i64,
 }
 
@@ -68,12 +73,19 @@ pub struct Claims {
     pub exp: i64,           // Expiry timestamp
     pub iat: i64,           // Issued at timestamp
     pub token_type: String, // "access" or "refresh"
+    /// Token ID, set on refresh tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jti: Option<String>,
+    /// Refresh token family the token belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub family: Option<String>,
 }
 
 /// Authentication service
 pub struct AuthService {
     jwt_secret: String,
-    redis_connection: Arc<RwLock<Option<MultiplexedConnection>>>,
+    token_store: Arc<dyn RefreshTokenStore>,
+    refresh_token_ttl: Duration,
 }
 
 impl AuthService {
@@ -87,10 +99,23 @@ impl AuthService {
 
         Self {
             jwt_secret,
-            redis_connection,
+            token_store: Arc::new(RedisRefreshTokenStore::new(redis_connection)),
+            refresh_token_ttl: Duration::days(REFRESH_TOKEN_EXPIRY_DAYS),
         }
     }
 
+    /// Keep refresh token families in `token_store` instead of Redis
+    pub fn with_token_store(mut self, token_store: Arc<dyn RefreshTokenStore>) -> Self {
+        self.token_store = token_store;
+        self
+    }
+
+    /// Override how long a refresh token family lives without being used
+    pub fn with_refresh_token_ttl(mut self, ttl: Duration) -> Self {
+        self.refresh_token_ttl = ttl;
+        self
+    }
+
     /// Authenticate user with credentials
     /// TODO: Implement database-backed user store with bcrypt/argon2 password hashing
     pub fn authenticate(&self, _username: &str, _password: &str) -> Result<User> {
@@ -115,6 +140,8 @@ impl AuthService {
             exp: expiration,
             iat: Utc::now().timestamp(),
             token_type: "access".to_string(),
+            jti: None,
+            family: None,
         };
 
         encode(
@@ -125,10 +152,10 @@ impl AuthService {
         .map_err(|e| anyhow!("Failed to generate access token: {}", e))
     }
 
-    /// Generate refresh token
-    pub fn generate_refresh_token(&self, user: &User) -> Result<String> {
+    /// Generate a refresh token with ID `jti` in refresh token `family`
+    pub fn generate_refresh_token(&self, user: &User, family: &str, jti: &str) -> Result<String> {
         let expiration = Utc::now()
-            .checked_add_signed(Duration::days(REFRESH_TOKEN_EXPIRY_DAYS))
+            .checked_add_signed(self.refresh_token_ttl)
             .ok_or_else(|| anyhow!("Invalid timestamp"))?
             .timestamp();
 
@@ -138,6 +165,8 @@ impl AuthService {
             exp: expiration,
             iat: Utc::now().timestamp(),
             token_type: "refresh".to_string(),
+            jti: Some(jti.to_string()),
+            family: Some(family.to_string()),
         };
 
         encode(
@@ -161,27 +190,29 @@ impl AuthService {
         .map_err(|e| anyhow!("Invalid token: {}", e))
     }
 
-    /// Store refresh token in Redis
-    pub async fn store_refresh_token(&self, token: &str, user_id: &str) -> Result<()> {
-        if let Some(conn) = self.redis_connection.read().await.as_ref() {
-            let mut conn = conn.clone();
-            let key = format!("refresh_token:{}", user_id);
-            let expiry = REFRESH_TOKEN_EXPIRY_DAYS * 24 * 60 * 60; // seconds
+    fn refresh_token_ttl_std(&self) -> std::time::Duration {
+        self.refresh_token_ttl
+            .to_std()
+            .unwrap_or(std::time::Duration::ZERO)
+    }
 
-            conn.set_ex::<_, _, ()>(&key, token, expiry as u64)
-                .await
-                .map_err(|e| anyhow!("Failed to store refresh token: {}", e))?;
+    /// Start a new refresh token family for the user and return its first token
+    pub async fn issue_refresh_token(&self, user: &User) -> Result<String> {
+        let family = Uuid::new_v4().to_string();
+        let jti = Uuid::new_v4().to_string();
+        let token = self.generate_refresh_token(user, &family, &jti)?;
 
-            tracing::debug!("Stored refresh token for user: {}", user_id);
-        } else {
-            tracing::warn!("Redis not available, refresh token not stored");
-        }
+        self.token_store
+            .create_family(&user.id, &family, &jti, self.refresh_token_ttl_std())
+            .await?;
+        tracing::debug!("Started refresh token family for user: {}", user.id);
 
-        Ok(())
+        Ok(token)
     }
 
-    /// Validate refresh token from Redis
-    pub async fn validate_refresh_token(&self, token: &str) -> Result<Claims> {
+    /// Decode a refresh token, returning its claims with the family and
+    /// token ID it must carry
+    fn decode_refresh_token(&self, token: &str) -> Result<(Claims, String, String)> {
         // First validate JWT signature and expiry
         let claims = self.validate_token(token)?;
 
@@ -190,42 +221,45 @@ impl AuthService {
             return Err(anyhow!("Invalid token type"));
         }
 
-        // Check if token exists in Redis (fail closed - SEC-007)
-        if let Some(conn) = self.redis_connection.read().await.as_ref() {
-            let mut conn = conn.clone();
-            let key = format!("refresh_token:{}", claims.sub);
+        match (claims.family.clone(), claims.jti.clone()) {
+            (Some(family), Some(jti)) => Ok((claims, family, jti)),
+            _ => Err(anyhow!("Refresh token not found or invalid")),
+        }
+    }
 
-            let stored_token: Option<String> = conn
-                .get(&key)
-                .await
-                .map_err(|e| anyhow!("Failed to retrieve refresh token: {}", e))?;
+    /// Revoke every session of a user whose rotated-out refresh token was
+    /// presented again
+    async fn handle_reuse(&self, claims: &Claims) -> Result<()> {
+        let revoked = self.token_store.revoke_user(&claims.sub).await?;
+        tracing::warn!(
+            "Refresh token reuse detected for user {}, revoked {} session(s)",
+            claims.sub,
+            revoked
+        );
+        Ok(())
+    }
 
-            if stored_token.as_deref() != Some(token) {
-                return Err(anyhow!("Refresh token not found or invalid"));
+    /// Validate a refresh token against its family without rotating it.
+    /// Presenting a token the family has rotated past revokes all of the
+    /// user's sessions.
+    pub async fn validate_refresh_token(&self, token: &str) -> Result<Claims> {
+        let (claims, family, jti) = self.decode_refresh_token(token)?;
+
+        // Fails closed while the store is unavailable (SEC-007)
+        match self.token_store.current_token(&family).await? {
+            Some(current) if current == jti => Ok(claims),
+            Some(_) => {
+                self.handle_reuse(&claims).await?;
+                Err(anyhow!("Refresh token reuse detected"))
             }
-        } else {
-            tracing::error!(
-                "Redis not available - refusing refresh token validation (fail closed)"
-            );
-            return Err(anyhow!("Token validation service unavailable"));
+            None => Err(anyhow!("Refresh token not found or invalid")),
         }
-
-        Ok(claims)
     }
 
-    /// Invalidate refresh token (logout)
+    /// Invalidate every refresh token family of the user
     pub async fn invalidate_refresh_token(&self, user_id: &str) -> Result<()> {
-        if let Some(conn) = self.redis_connection.read().await.as_ref() {
-            let mut conn = conn.clone();
-            let key = format!("refresh_token:{}", user_id);
-
-            conn.del::<_, ()>(&key)
-                .await
-                .map_err(|e| anyhow!("Failed to invalidate refresh token: {}", e))?;
-
-            tracing::debug!("Invalidated refresh token for user: {}", user_id);
-        }
-
+        self.token_store.revoke_user(user_id).await?;
+        tracing::debug!("Invalidated refresh tokens for user: {}", user_id);
         Ok(())
     }
 
@@ -236,10 +270,7 @@ impl AuthService {
 
         // Generate tokens
         let access_token = self.generate_access_token(&user)?;
-        let refresh_token = self.generate_refresh_token(&user)?;
-
-        // Store refresh token
-        self.store_refresh_token(&refresh_token, &user.id).await?;
+        let refresh_token = self.issue_refresh_token(&user).await?;
 
         Ok(LoginResponse {
             access_token,
@@ -248,10 +279,29 @@ impl AuthService {
         })
     }
 
-    /// Refresh access token
+    /// Refresh access token, rotating the refresh token
     pub async fn refresh(&self, request: RefreshTokenRequest) -> Result<RefreshTokenResponse> {
-        // Validate refresh token
-        let claims = self.validate_refresh_token(&request.refresh_token).await?;
+        let (claims, family, jti) = self.decode_refresh_token(&request.refresh_token)?;
+        let new_jti = Uuid::new_v4().to_string();
+
+        match self
+            .token_store
+            .rotate(
+                &claims.sub,
+                &family,
+                &jti,
+                &new_jti,
+                self.refresh_token_ttl_std(),
+            )
+            .await?
+        {
+            Rotation::Rotated => {}
+            Rotation::Reused => {
+                self.handle_reuse(&claims).await?;
+                return Err(anyhow!("Refresh token reuse detected"));
+            }
+            Rotation::Unknown => return Err(anyhow!("Refresh token expired or revoked")),
+        }
 
         // Create user from claims
         let user = User {
@@ -259,11 +309,12 @@ impl AuthService {
             username: claims.username,
         };
 
-        // Generate new access token
         let access_token = self.generate_access_token(&user)?;
+        let refresh_token = self.generate_refresh_token(&user, &family, &new_jti)?;
 
         Ok(RefreshTokenResponse {
             access_token,
+            refresh_token,
             expires_in: ACCESS_TOKEN_EXPIRY_HOURS * 3600,
         })
     }
@@ -271,10 +322,10 @@ impl AuthService {
     /// Logout flow
     pub async fn logout(&self, request: LogoutRequest) -> Result<()> {
         // Validate and get claims from refresh token
-        let claims = self.validate_token(&request.refresh_token)?;
+        let (claims, family, _) = self.decode_refresh_token(&request.refresh_token)?;
 
-        // Invalidate refresh token
-        self.invalidate_refresh_token(&claims.sub).await?;
+        // End this session only; the user's other logins stay valid
+        self.token_store.revoke_family(&claims.sub, &family).await?;
 
         Ok(())
     }
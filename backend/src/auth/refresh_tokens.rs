@@ -0,0 +1,274 @@
+//! Refresh token families
+//!
+//! Every login starts a family whose refresh tokens replace one another on
+//! each refresh. A family remembers only the token ID issued last, so
+//! presenting any earlier token from it means the token was copied and is
+//! treated as reuse.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use redis::aio::MultiplexedConnection;
+use redis::AsyncCommands;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+/// Outcome of presenting a refresh token for rotation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    /// The token was the family's current one and has been replaced
+    Rotated,
+    /// The family has since rotated past this token
+    Reused,
+    /// The family expired or was revoked
+    Unknown,
+}
+
+/// Storage for the current token ID of each refresh token family
+#[async_trait]
+pub trait RefreshTokenStore: Send + Sync {
+    /// Start a family for `user_id` whose current token is `token_id`
+    async fn create_family(
+        &self,
+        user_id: &str,
+        family_id: &str,
+        token_id: &str,
+        ttl: Duration,
+    ) -> Result<()>;
+
+    /// Current token ID of a live family
+    async fn current_token(&self, family_id: &str) -> Result<Option<String>>;
+
+    /// Replace `token_id` with `new_token_id` if it is still the family's
+    /// current token, extending the family's lifetime to `ttl`
+    async fn rotate(
+        &self,
+        user_id: &str,
+        family_id: &str,
+        token_id: &str,
+        new_token_id: &str,
+        ttl: Duration,
+    ) -> Result<Rotation>;
+
+    async fn revoke_family(&self, user_id: &str, family_id: &str) -> Result<()>;
+
+    /// Revoke every family belonging to the user, returning how many there were
+    async fn revoke_user(&self, user_id: &str) -> Result<usize>;
+}
+
+fn family_key(family_id: &str) -> String {
+    format!("refresh_family:{}", family_id)
+}
+
+fn user_families_key(user_id: &str) -> String {
+    format!("refresh_families:{}", user_id)
+}
+
+/// Compare-and-swap of a family's current token ID, so two refreshes racing
+/// with the same token cannot both succeed
+const ROTATE_SCRIPT: &str = r#"
+local current = redis.call('GET', KEYS[1])
+if not current then
+    return 0
+end
+if current ~= ARGV[1] then
+    return -1
+end
+redis.call('SET', KEYS[1], ARGV[2], 'EX', ARGV[3])
+redis.call('EXPIRE', KEYS[2], ARGV[3])
+return 1
+"#;
+
+/// Families kept in Redis; every operation fails while Redis is unavailable
+pub struct RedisRefreshTokenStore {
+    redis_connection: Arc<RwLock<Option<MultiplexedConnection>>>,
+}
+
+impl RedisRefreshTokenStore {
+    pub fn new(redis_connection: Arc<RwLock<Option<MultiplexedConnection>>>) -> Self {
+        Self { redis_connection }
+    }
+
+    async fn connection(&self) -> Result<MultiplexedConnection> {
+        self.redis_connection.read().await.clone().ok_or_else(|| {
+            tracing::error!("Redis not available - refusing refresh token operation (fail closed)");
+            anyhow!("Token validation service unavailable")
+        })
+    }
+}
+
+#[async_trait]
+impl RefreshTokenStore for RedisRefreshTokenStore {
+    async fn create_family(
+        &self,
+        user_id: &str,
+        family_id: &str,
+        token_id: &str,
+        ttl: Duration,
+    ) -> Result<()> {
+        let mut conn = self.connection().await?;
+        let families = user_families_key(user_id);
+        redis::pipe()
+            .atomic()
+            .set_ex(family_key(family_id), token_id, ttl.as_secs())
+            .sadd(&families, family_id)
+            .expire(&families, ttl.as_secs() as i64)
+            .query_async::<_, ()>(&mut conn)
+            .await
+            .map_err(|e| anyhow!("Failed to store refresh token: {}", e))?;
+        Ok(())
+    }
+
+    async fn current_token(&self, family_id: &str) -> Result<Option<String>> {
+        let mut conn = self.connection().await?;
+        conn.get(family_key(family_id))
+            .await
+            .map_err(|e| anyhow!("Failed to retrieve refresh token: {}", e))
+    }
+
+    async fn rotate(
+        &self,
+        user_id: &str,
+        family_id: &str,
+        token_id: &str,
+        new_token_id: &str,
+        ttl: Duration,
+    ) -> Result<Rotation> {
+        let mut conn = self.connection().await?;
+        let outcome: i64 = redis::Script::new(ROTATE_SCRIPT)
+            .key(family_key(family_id))
+            .key(user_families_key(user_id))
+            .arg(token_id)
+            .arg(new_token_id)
+            .arg(ttl.as_secs())
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e| anyhow!("Failed to rotate refresh token: {}", e))?;
+
+        Ok(match outcome {
+            1 => Rotation::Rotated,
+            -1 => Rotation::Reused,
+            _ => Rotation::Unknown,
+        })
+    }
+
+    async fn revoke_family(&self, user_id: &str, family_id: &str) -> Result<()> {
+        let mut conn = self.connection().await?;
+        redis::pipe()
+            .atomic()
+            .del(family_key(family_id))
+            .srem(user_families_key(user_id), family_id)
+            .query_async::<_, ()>(&mut conn)
+            .await
+            .map_err(|e| anyhow!("Failed to invalidate refresh token: {}", e))?;
+        Ok(())
+    }
+
+    async fn revoke_user(&self, user_id: &str) -> Result<usize> {
+        let mut conn = self.connection().await?;
+        let families_key = user_families_key(user_id);
+        let families: Vec<String> = conn
+            .smembers(&families_key)
+            .await
+            .map_err(|e| anyhow!("Failed to list refresh token families: {}", e))?;
+
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        for family_id in &families {
+            pipe.del(family_key(family_id));
+        }
+        pipe.del(&families_key);
+        pipe.query_async::<_, ()>(&mut conn)
+            .await
+            .map_err(|e| anyhow!("Failed to invalidate refresh tokens: {}", e))?;
+
+        Ok(families.len())
+    }
+}
+
+#[derive(Debug)]
+struct Family {
+    user_id: String,
+    token_id: String,
+    expires_at: Instant,
+}
+
+/// Process-local families, for tests and single-instance development setups
+#[derive(Debug, Default)]
+pub struct MemoryRefreshTokenStore {
+    families: Mutex<HashMap<String, Family>>,
+}
+
+impl MemoryRefreshTokenStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Family>> {
+        let mut families = self.families.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        families.retain(|_, family| family.expires_at > now);
+        families
+    }
+}
+
+#[async_trait]
+impl RefreshTokenStore for MemoryRefreshTokenStore {
+    async fn create_family(
+        &self,
+        user_id: &str,
+        family_id: &str,
+        token_id: &str,
+        ttl: Duration,
+    ) -> Result<()> {
+        self.lock().insert(
+            family_id.to_string(),
+            Family {
+                user_id: user_id.to_string(),
+                token_id: token_id.to_string(),
+                expires_at: Instant::now() + ttl,
+            },
+        );
+        Ok(())
+    }
+
+    async fn current_token(&self, family_id: &str) -> Result<Option<String>> {
+        Ok(self
+            .lock()
+            .get(family_id)
+            .map(|family| family.token_id.clone()))
+    }
+
+    async fn rotate(
+        &self,
+        _user_id: &str,
+        family_id: &str,
+        token_id: &str,
+        new_token_id: &str,
+        ttl: Duration,
+    ) -> Result<Rotation> {
+        let mut families = self.lock();
+        let Some(family) = families.get_mut(family_id) else {
+            return Ok(Rotation::Unknown);
+        };
+        if family.token_id != token_id {
+            return Ok(Rotation::Reused);
+        }
+        family.token_id = new_token_id.to_string();
+        family.expires_at = Instant::now() + ttl;
+        Ok(Rotation::Rotated)
+    }
+
+    async fn revoke_family(&self, _user_id: &str, family_id: &str) -> Result<()> {
+        self.lock().remove(family_id);
+        Ok(())
+    }
+
+    async fn revoke_user(&self, user_id: &str) -> Result<usize> {
+        let mut families = self.lock();
+        let before = families.len();
+        families.retain(|_, family| family.user_id != user_id);
+        Ok(before - families.len())
+    }
+}
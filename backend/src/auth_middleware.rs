@@ -1,10 +1,11 @@
 use axum::{
     extract::{Extension, Request},
-    http::{header, StatusCode},
+    http::{header, HeaderMap, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
 };
 use serde_json::json;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use crate::auth::Claims;
@@ -13,6 +14,31 @@ use crate::auth::Claims;
 #[derive(Clone)]
 pub struct JwtSecret(pub Arc<str>);
 
+/// User ids holding the admin role, shared via extension
+#[derive(Debug, Clone, Default)]
+pub struct AdminUsers(Arc<HashSet<String>>);
+
+impl AdminUsers {
+    pub fn new<I: IntoIterator<Item = String>>(user_ids: I) -> Self {
+        Self(Arc::new(user_ids.into_iter().collect()))
+    }
+
+    /// Load from the comma-separated ADMIN_USER_IDS; empty when unset
+    pub fn from_env() -> Self {
+        let ids = std::env::var("ADMIN_USER_IDS").unwrap_or_default();
+        Self::new(
+            ids.split(',')
+                .map(str::trim)
+                .filter(|id| !id.is_empty())
+                .map(str::to_string),
+        )
+    }
+
+    pub fn is_admin(&self, user: &AuthUser) -> bool {
+        self.0.contains(&user.user_id)
+    }
+}
+
 /// Extract user from authenticated request
 #[derive(Debug, Clone)]
 pub struct AuthUser {
@@ -70,6 +96,21 @@ pub async fn auth_middleware(
     Ok(next.run(req).await)
 }
 
+/// User behind a valid Bearer access token, or `None` for anonymous or
+/// invalid requests. For public routes that only change behaviour for
+/// authenticated callers instead of rejecting everyone else.
+pub fn authenticated_user(headers: &HeaderMap, jwt_secret: &str) -> Option<AuthUser> {
+    let token = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|h| h.to_str().ok())
+        .and_then(|h| h.strip_prefix("Bearer "))?;
+    let claims = validate_access_token(token, jwt_secret).ok()?;
+    Some(AuthUser {
+        user_id: claims.sub,
+        username: claims.username,
+    })
+}
+
 /// Validate access token
 fn validate_access_token(token: &str, secret: &str) -> Result<Claims, AuthError> {
     use jsonwebtoken::{decode, DecodingKey, Validation};
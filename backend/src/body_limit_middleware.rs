@@ -0,0 +1,62 @@
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::error::ApiError;
+
+/// Default cap for transaction-signing bodies; a signed envelope with many
+/// operations is still only a few kilobytes of base64 XDR
+pub const DEFAULT_TRANSACTION_BODY_LIMIT: usize = 64 * 1024;
+
+/// Largest request body, in bytes, accepted by a group of routes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BodyLimit(pub usize);
+
+impl BodyLimit {
+    /// Limit from the environment variable `name`, falling back to `default`
+    /// when it is unset, unparseable or zero
+    pub fn from_env(name: &str, default: usize) -> Self {
+        let limit = std::env::var(name)
+            .ok()
+            .and_then(|s| s.parse::<usize>().ok())
+            .filter(|limit| *limit > 0)
+            .unwrap_or(default);
+        Self(limit)
+    }
+}
+
+/// Reject request bodies larger than the route group's `BodyLimit` with 413
+pub async fn body_limit_middleware(
+    State(BodyLimit(limit)): State<BodyLimit>,
+    req: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    let too_large = || {
+        ApiError::payload_too_large(
+            "PAYLOAD_TOO_LARGE",
+            format!("Request body exceeds the {} byte limit", limit),
+        )
+    };
+
+    let declared = req
+        .headers()
+        .get(axum::http::header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<usize>().ok());
+    if declared.is_some_and(|length| length > limit) {
+        return Err(too_large());
+    }
+
+    // Content-Length can be absent or wrong, so bound the read as well
+    let (parts, body) = req.into_parts();
+    let bytes = axum::body::to_bytes(body, limit)
+        .await
+        .map_err(|_| too_large())?;
+
+    Ok(next
+        .run(Request::from_parts(parts, Body::from(bytes)))
+        .await)
+}
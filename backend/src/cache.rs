@@ -1,9 +1,16 @@
 use redis::aio::MultiplexedConnection;
 use serde::{de::DeserializeOwned, Serialize};
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
 use tokio::sync::RwLock;
 
+use crate::cache_codec::CacheCodec;
+use crate::cache_memory::MemoryCache;
+
+/// Default interval between Redis health checks and reconnection attempts
+pub const DEFAULT_RECONNECT_INTERVAL_SECS: u64 = 30;
+
 /// Cache statistics for monitoring
 #[derive(Debug, Clone)]
 pub struct CacheStats {
@@ -21,6 +28,50 @@ impl CacheStats {
             (self.hits as f64 / total as f64) * 100.0
         }
     }
+
+    /// Fraction of lookups that were hits, between 0 and 1
+    pub fn hit_ratio(&self) -> f64 {
+        self.hit_rate() / 100.0
+    }
+}
+
+/// Key count and memory usage reported by Redis `INFO`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RedisInfo {
+    /// Keys across all databases
+    pub keys: u64,
+    pub used_memory_bytes: u64,
+    /// Keys Redis evicted under its `maxmemory` policy
+    pub evicted_keys: u64,
+}
+
+impl RedisInfo {
+    /// Pick the memory, eviction and keyspace fields out of `INFO` output
+    pub fn parse(info: &str) -> Self {
+        let mut parsed = Self::default();
+        for line in info.lines() {
+            let Some((field, value)) = line.trim().split_once(':') else {
+                continue;
+            };
+            match field {
+                "used_memory" => parsed.used_memory_bytes = value.parse().unwrap_or(0),
+                "evicted_keys" => parsed.evicted_keys = value.parse().unwrap_or(0),
+                // Keyspace lines look like `db0:keys=12,expires=3,avg_ttl=0`
+                db if db
+                    .strip_prefix("db")
+                    .is_some_and(|n| n.parse::<u32>().is_ok()) =>
+                {
+                    parsed.keys += value
+                        .split(',')
+                        .find_map(|pair| pair.strip_prefix("keys="))
+                        .and_then(|keys| keys.parse::<u64>().ok())
+                        .unwrap_or(0);
+                }
+                _ => {}
+            }
+        }
+        parsed
+    }
 }
 
 /// Cache configuration with TTL settings
@@ -29,6 +80,8 @@ pub struct CacheConfig {
     pub corridor_metrics_ttl: usize, // 5 minutes
     pub anchor_data_ttl: usize,      // 10 minutes
     pub dashboard_stats_ttl: usize,  // 1 minute
+    /// Codec for newly written entries; existing entries keep their own
+    pub codec: CacheCodec,
 }
 
 impl CacheConfig {
@@ -48,13 +101,20 @@ impl Default for CacheConfig {
             corridor_metrics_ttl: 300, // 5 minutes
             anchor_data_ttl: 600,      // 10 minutes
             dashboard_stats_ttl: 60,   // 1 minute
+            codec: CacheCodec::Json,
         }
     }
 }
 
 /// Main cache manager
+///
+/// Reads and writes go to Redis while it is reachable and fall back to a
+/// bounded in-memory LRU otherwise. A background task reconnects to Redis and
+/// switches back once it recovers.
 pub struct CacheManager {
     redis_connection: Arc<RwLock<Option<MultiplexedConnection>>>,
+    memory: Arc<MemoryCache>,
+    reconnect_task: tokio::task::JoinHandle<()>,
     pub config: CacheConfig,
     hits: Arc<AtomicU64>,
     misses: Arc<AtomicU64>,
@@ -65,70 +125,112 @@ impl CacheManager {
     pub async fn new(config: CacheConfig) -> anyhow::Result<Self> {
         let redis_url =
             std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+        let reconnect_interval = std::env::var("CACHE_REDIS_RECONNECT_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_RECONNECT_INTERVAL_SECS);
 
-        let connection = if let Ok(client) = redis::Client::open(redis_url.as_str()) {
-            match client.get_multiplexed_tokio_connection().await {
-                Ok(conn) => {
-                    tracing::info!("Connected to Redis for caching");
-                    Some(conn)
-                }
-                Err(e) => {
-                    tracing::warn!("Failed to connect to Redis for caching: {}", e);
-                    None
-                }
+        Ok(Self::with_redis_url(
+            config,
+            &redis_url,
+            MemoryCache::from_env(),
+            Duration::from_secs(reconnect_interval),
+        )
+        .await)
+    }
+
+    /// Connect to `redis_url`, falling back to `memory` until Redis answers
+    pub async fn with_redis_url(
+        config: CacheConfig,
+        redis_url: &str,
+        memory: MemoryCache,
+        reconnect_interval: Duration,
+    ) -> Self {
+        let connection = match connect_redis(redis_url).await {
+            Ok(conn) => {
+                tracing::info!("Connected to Redis for caching");
+                Some(conn)
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to connect to Redis for caching, using in-memory cache: {}",
+                    e
+                );
+                None
             }
-        } else {
-            tracing::warn!("Invalid Redis URL for caching");
-            None
         };
 
-        Ok(Self {
-            redis_connection: Arc::new(RwLock::new(connection)),
+        let redis_connection = Arc::new(RwLock::new(connection));
+        let memory = Arc::new(memory);
+        let reconnect_task = tokio::spawn(watch_redis(
+            redis_url.to_string(),
+            Arc::downgrade(&redis_connection),
+            Arc::downgrade(&memory),
+            reconnect_interval,
+        ));
+
+        Self {
+            redis_connection,
+            memory,
+            reconnect_task,
             config,
             hits: Arc::new(AtomicU64::new(0)),
             misses: Arc::new(AtomicU64::new(0)),
             invalidations: Arc::new(AtomicU64::new(0)),
-        })
+        }
+    }
+
+    /// Whether requests are currently served by Redis rather than memory
+    pub async fn is_redis_connected(&self) -> bool {
+        self.redis_connection.read().await.is_some()
+    }
+
+    fn record_hit(&self, key: &str) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        crate::observability::metrics::record_cache_lookup(true);
+        tracing::debug!("Cache hit for key: {}", key);
+    }
+
+    fn record_miss(&self, key: &str) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        crate::observability::metrics::record_cache_lookup(false);
+        tracing::debug!("Cache miss for key: {}", key);
     }
 
-    /// Get value from cache, returns None if not found or Redis unavailable
+    /// Get value from cache, returns None if not found or on a Redis error
     pub async fn get<T: DeserializeOwned>(&self, key: &str) -> anyhow::Result<Option<T>> {
-        if let Some(conn) = self.redis_connection.read().await.as_ref() {
-            let mut conn = conn.clone();
+        let conn = self.redis_connection.read().await.clone();
+        let value = if let Some(mut conn) = conn {
             match redis::cmd("GET")
                 .arg(key)
-                .query_async::<_, Option<String>>(&mut conn)
+                .query_async::<_, Option<Vec<u8>>>(&mut conn)
                 .await
             {
-                Ok(Some(value)) => {
-                    self.hits.fetch_add(1, Ordering::Relaxed);
-                    crate::observability::metrics::record_cache_lookup(true);
-                    tracing::debug!("Cache hit for key: {}", key);
-                    match serde_json::from_str::<T>(&value) {
-                        Ok(data) => Ok(Some(data)),
-                        Err(e) => {
-                            tracing::warn!("Failed to deserialize cached value for {}: {}", key, e);
-                            Ok(None)
-                        }
-                    }
-                }
-                Ok(None) => {
-                    self.misses.fetch_add(1, Ordering::Relaxed);
-                    crate::observability::metrics::record_cache_lookup(false);
-                    tracing::debug!("Cache miss for key: {}", key);
-                    Ok(None)
-                }
+                Ok(value) => value,
                 Err(e) => {
                     tracing::warn!("Redis GET error for {}: {}", key, e);
-                    self.misses.fetch_add(1, Ordering::Relaxed);
-                    crate::observability::metrics::record_cache_lookup(false);
-                    Ok(None)
+                    None
                 }
             }
         } else {
-            self.misses.fetch_add(1, Ordering::Relaxed);
-            crate::observability::metrics::record_cache_lookup(false);
-            Ok(None)
+            self.memory.get(key)
+        };
+
+        match value {
+            Some(value) => {
+                self.record_hit(key);
+                match CacheCodec::decode::<T>(&value) {
+                    Ok(data) => Ok(Some(data)),
+                    Err(e) => {
+                        tracing::warn!("Failed to deserialize cached value for {}: {}", key, e);
+                        Ok(None)
+                    }
+                }
+            }
+            None => {
+                self.record_miss(key);
+                Ok(None)
+            }
         }
     }
 
@@ -139,35 +241,40 @@ impl CacheManager {
         value: &T,
         ttl_seconds: usize,
     ) -> anyhow::Result<()> {
-        if let Some(conn) = self.redis_connection.read().await.as_ref() {
-            let mut conn = conn.clone();
-            match serde_json::to_string(value) {
-                Ok(serialized) => {
-                    match redis::cmd("SETEX")
-                        .arg(key)
-                        .arg(ttl_seconds)
-                        .arg(&serialized)
-                        .query_async::<_, ()>(&mut conn)
-                        .await
-                    {
-                        Ok(_) => {
-                            tracing::debug!("Cache set for key: {} (TTL: {}s)", key, ttl_seconds);
-                            Ok(())
-                        }
-                        Err(e) => {
-                            tracing::warn!("Redis SETEX error for {}: {}", key, e);
-                            Ok(())
-                        }
-                    }
+        let encoded = match self.config.codec.encode(value) {
+            Ok(encoded) => encoded,
+            Err(e) => {
+                tracing::warn!("Failed to serialize value for cache key {}: {}", key, e);
+                return Ok(());
+            }
+        };
+
+        let conn = self.redis_connection.read().await.clone();
+        if let Some(mut conn) = conn {
+            match redis::cmd("SETEX")
+                .arg(key)
+                .arg(ttl_seconds)
+                .arg(&encoded)
+                .query_async::<_, ()>(&mut conn)
+                .await
+            {
+                Ok(_) => {
+                    tracing::debug!("Cache set for key: {} (TTL: {}s)", key, ttl_seconds);
                 }
                 Err(e) => {
-                    tracing::warn!("Failed to serialize value for cache key {}: {}", key, e);
-                    Ok(())
+                    tracing::warn!("Redis SETEX error for {}: {}", key, e);
                 }
             }
         } else {
-            Ok(())
+            self.memory
+                .set(key, encoded, Duration::from_secs(ttl_seconds as u64));
+            tracing::debug!(
+                "In-memory cache set for key: {} (TTL: {}s)",
+                key,
+                ttl_seconds
+            );
         }
+        Ok(())
     }
 
     /// Delete a cache key
@@ -190,6 +297,9 @@ impl CacheManager {
                 }
             }
         } else {
+            if self.memory.delete(key) {
+                self.invalidations.fetch_add(1, Ordering::Relaxed);
+            }
             Ok(())
         }
     }
@@ -247,7 +357,10 @@ impl CacheManager {
 
             Ok(deleted_count)
         } else {
-            Ok(0)
+            let deleted_count = self.memory.delete_pattern(pattern);
+            self.invalidations
+                .fetch_add(deleted_count as u64, Ordering::Relaxed);
+            Ok(deleted_count)
         }
     }
 
@@ -256,9 +369,14 @@ impl CacheManager {
         self.delete_pattern(pattern).await
     }
 
-    /// Clean up expired entries (Redis handles this automatically, but useful for monitoring)
+    /// Clean up expired entries (Redis auto-expires keys; the in-memory
+    /// fallback only drops them lazily, so purge it here)
     pub async fn cleanup_expired(&self) -> anyhow::Result<()> {
-        tracing::debug!("Cache cleanup triggered (Redis auto-expires keys)");
+        let purged = self.memory.purge_expired();
+        tracing::debug!(
+            "Cache cleanup triggered, purged {} expired in-memory entries",
+            purged
+        );
         Ok(())
     }
 
@@ -278,8 +396,43 @@ impl CacheManager {
         self.invalidations.store(0, Ordering::Relaxed);
     }
 
+    /// Entries held by the in-memory fallback
+    pub fn memory_entries(&self) -> usize {
+        self.memory.len()
+    }
+
+    /// Entries the in-memory fallback evicted because it was full
+    pub fn memory_evictions(&self) -> u64 {
+        self.memory.evictions()
+    }
+
+    /// Key count and memory usage from Redis `INFO`, or None while Redis is
+    /// unavailable
+    pub async fn redis_info(&self) -> Option<RedisInfo> {
+        let mut conn = self.redis_connection.read().await.clone()?;
+        match redis::cmd("INFO").query_async::<_, String>(&mut conn).await {
+            Ok(info) => Some(RedisInfo::parse(&info)),
+            Err(e) => {
+                tracing::warn!("Redis INFO error: {}", e);
+                None
+            }
+        }
+    }
+
+    /// PING Redis; errors when there is no connection or Redis does not answer
+    pub async fn ping(&self) -> anyhow::Result<()> {
+        let conn = self.redis_connection.read().await.clone();
+        let mut conn = conn.ok_or_else(|| anyhow::anyhow!("Redis not connected"))?;
+        redis::cmd("PING")
+            .query_async::<_, String>(&mut conn)
+            .await?;
+        Ok(())
+    }
+
     /// Close Redis connection gracefully
     pub async fn close(&self) -> anyhow::Result<()> {
+        // Stop the watcher first so it doesn't reconnect behind our back
+        self.reconnect_task.abort();
         let mut conn_guard = self.redis_connection.write().await;
         if let Some(mut conn) = conn_guard.take() {
             // Ensure all pending operations are flushed
@@ -293,6 +446,54 @@ impl CacheManager {
     }
 }
 
+async fn connect_redis(redis_url: &str) -> redis::RedisResult<MultiplexedConnection> {
+    redis::Client::open(redis_url)?
+        .get_multiplexed_tokio_connection()
+        .await
+}
+
+/// Periodically PING Redis, dropping the connection when it stops answering
+/// and reconnecting when it comes back. Exits once the cache manager is
+/// dropped.
+async fn watch_redis(
+    redis_url: String,
+    connection: Weak<RwLock<Option<MultiplexedConnection>>>,
+    memory: Weak<MemoryCache>,
+    interval: Duration,
+) {
+    let mut ticker = tokio::time::interval(interval.max(Duration::from_millis(100)));
+    // The first tick completes immediately and `new` has just tried to connect
+    ticker.tick().await;
+
+    loop {
+        ticker.tick().await;
+        let Some(connection) = connection.upgrade() else {
+            return;
+        };
+
+        let current = connection.read().await.clone();
+        match current {
+            Some(mut conn) => {
+                if let Err(e) = redis::cmd("PING").query_async::<_, String>(&mut conn).await {
+                    tracing::warn!("Redis cache unreachable, using in-memory cache: {}", e);
+                    *connection.write().await = None;
+                }
+            }
+            None => {
+                if let Ok(conn) = connect_redis(&redis_url).await {
+                    // Writes go to Redis from now on, so anything left in
+                    // memory would be stale by the next outage
+                    if let Some(memory) = memory.upgrade() {
+                        memory.clear();
+                    }
+                    *connection.write().await = Some(conn);
+                    tracing::info!("Reconnected to Redis, switching back from in-memory cache");
+                }
+            }
+        }
+    }
+}
+
 /// Cache key builders for consistency
 pub mod keys {
     pub fn anchor_list(limit: i64, offset: i64) -> String {
@@ -319,6 +520,16 @@ pub mod keys {
         format!("corridor:detail:{}", corridor_key)
     }
 
+    /// Last good copy of `key`, kept outside the invalidation patterns so it
+    /// survives as a fallback while the upstream source is down
+    pub fn last_good(key: &str) -> String {
+        format!("last_good:{}", key)
+    }
+
+    pub fn account_summary(account_id: &str) -> String {
+        format!("account:summary:{}", account_id)
+    }
+
     pub fn dashboard_stats() -> String {
         "dashboard:stats".to_string()
     }
@@ -355,6 +566,7 @@ mod tests {
             invalidations: 5,
         };
         assert_eq!(stats.hit_rate(), 80.0);
+        assert_eq!(stats.hit_ratio(), 0.8);
     }
 
     #[test]
@@ -367,6 +579,21 @@ mod tests {
         assert_eq!(stats.hit_rate(), 0.0);
     }
 
+    #[test]
+    fn test_redis_info_parse() {
+        let info = "# Memory\r\nused_memory:1048576\r\nused_memory_human:1.00M\r\n\r\n\
+                    # Stats\r\nevicted_keys:7\r\n\r\n\
+                    # Keyspace\r\ndb0:keys=12,expires=3,avg_ttl=0\r\ndb1:keys=4,expires=0,avg_ttl=0\r\n";
+        assert_eq!(
+            RedisInfo::parse(info),
+            RedisInfo {
+                keys: 16,
+                used_memory_bytes: 1_048_576,
+                evicted_keys: 7,
+            }
+        );
+    }
+
     #[test]
     fn test_cache_key_builders() {
         assert_eq!(keys::anchor_list(50, 0), "anchor:list:50:0");
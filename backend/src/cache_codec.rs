@@ -0,0 +1,157 @@
+//! Serialization of cached values
+//!
+//! Every encoded entry starts with a one-byte format tag so entries written
+//! with different codecs (e.g. mid-rollout) are each decoded correctly. Entries
+//! without a tag predate the tag and are plain JSON.
+
+use serde::{de::DeserializeOwned, Serialize};
+use std::str::FromStr;
+
+const JSON_TAG: u8 = 0x01;
+const BINCODE_TAG: u8 = 0x02;
+
+/// Codec used to encode new cache entries
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CacheCodec {
+    /// Human-readable, handy when inspecting Redis
+    #[default]
+    Json,
+    /// Compact binary encoding for large values such as corridor lists
+    Bincode,
+}
+
+impl CacheCodec {
+    /// Codec from `CACHE_CODEC` (`json` or `bincode`), defaulting to JSON
+    pub fn from_env() -> Self {
+        match std::env::var("CACHE_CODEC") {
+            Ok(value) => value.parse().unwrap_or_else(|_| {
+                tracing::warn!("Unknown CACHE_CODEC '{}', using json", value);
+                CacheCodec::Json
+            }),
+            Err(_) => CacheCodec::Json,
+        }
+    }
+
+    fn tag(self) -> u8 {
+        match self {
+            CacheCodec::Json => JSON_TAG,
+            CacheCodec::Bincode => BINCODE_TAG,
+        }
+    }
+
+    /// Serialize `value` prefixed with this codec's format tag
+    pub fn encode<T: Serialize>(self, value: &T) -> anyhow::Result<Vec<u8>> {
+        let mut encoded = vec![self.tag()];
+        match self {
+            CacheCodec::Json => serde_json::to_writer(&mut encoded, value)?,
+            CacheCodec::Bincode => bincode::serialize_into(&mut encoded, value)?,
+        }
+        Ok(encoded)
+    }
+
+    /// Decode an entry with whichever codec its format tag names
+    pub fn decode<T: DeserializeOwned>(bytes: &[u8]) -> anyhow::Result<T> {
+        match bytes.split_first() {
+            Some((&JSON_TAG, payload)) => Ok(serde_json::from_slice(payload)?),
+            Some((&BINCODE_TAG, payload)) => Ok(bincode::deserialize(payload)?),
+            // Untagged entries were written as bare JSON
+            _ => Ok(serde_json::from_slice(bytes)?),
+        }
+    }
+}
+
+impl FromStr for CacheCodec {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "json" => Ok(CacheCodec::Json),
+            "bincode" => Ok(CacheCodec::Bincode),
+            other => anyhow::bail!("unknown cache codec: {}", other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::corridors_cached::CorridorResponse;
+
+    fn corridor() -> CorridorResponse {
+        serde_json::from_value(serde_json::json!({
+            "id": "USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN->XLM:native",
+            "source_asset": "USDC",
+            "destination_asset": "XLM",
+            "success_rate": 99.5,
+            "total_attempts": 5000,
+            "successful_payments": 4975,
+            "failed_payments": 25,
+            "average_latency_ms": 450.5,
+            "median_latency_ms": 380.0,
+            "p95_latency_ms": 850.0,
+            "p99_latency_ms": 1200.0,
+            "liquidity_depth_usd": 1500000.0,
+            "price_stale": false,
+            "liquidity_volume_24h_usd": 150000.0,
+            "liquidity_trend": "stable",
+            "health_score": 95.5,
+            "last_updated": "2024-01-15T10:30:00Z",
+            "source": "payments"
+        }))
+        .unwrap()
+    }
+
+    fn assert_same(decoded: &CorridorResponse, expected: &CorridorResponse) {
+        assert_eq!(
+            serde_json::to_value(decoded).unwrap(),
+            serde_json::to_value(expected).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_corridor_round_trips_through_each_codec() {
+        let corridor = corridor();
+        for codec in [CacheCodec::Json, CacheCodec::Bincode] {
+            let encoded = codec.encode(&vec![corridor.clone()]).unwrap();
+            let decoded: Vec<CorridorResponse> = CacheCodec::decode(&encoded).unwrap();
+            assert_eq!(decoded.len(), 1);
+            assert_same(&decoded[0], &corridor);
+        }
+    }
+
+    #[test]
+    fn test_format_tag_selects_codec() {
+        let corridor = corridor();
+        let json = CacheCodec::Json.encode(&corridor).unwrap();
+        let binary = CacheCodec::Bincode.encode(&corridor).unwrap();
+
+        assert_eq!(json[0], JSON_TAG);
+        assert_eq!(binary[0], BINCODE_TAG);
+        assert!(binary.len() < json.len());
+
+        // Either entry decodes regardless of the codec currently configured
+        assert_same(&CacheCodec::decode(&json).unwrap(), &corridor);
+        assert_same(&CacheCodec::decode(&binary).unwrap(), &corridor);
+
+        // Swapping the tag makes the payload undecodable, so the tag is what's used
+        let mut mislabelled = binary.clone();
+        mislabelled[0] = JSON_TAG;
+        assert!(CacheCodec::decode::<CorridorResponse>(&mislabelled).is_err());
+    }
+
+    #[test]
+    fn test_untagged_entries_decode_as_json() {
+        let legacy = serde_json::to_vec(&corridor()).unwrap();
+        assert_same(&CacheCodec::decode(&legacy).unwrap(), &corridor());
+    }
+
+    #[test]
+    fn test_codec_from_str() {
+        assert_eq!(
+            "bincode".parse::<CacheCodec>().unwrap(),
+            CacheCodec::Bincode
+        );
+        assert_eq!(" JSON ".parse::<CacheCodec>().unwrap(), CacheCodec::Json);
+        assert!("msgpack".parse::<CacheCodec>().is_err());
+    }
+}
@@ -1,6 +1,17 @@
 use crate::cache::{keys, CacheManager};
 use std::sync::Arc;
 
+/// Key pattern for a cache family an operator can purge by name
+pub fn tag_pattern(tag: &str) -> Option<String> {
+    match tag {
+        "anchor" => Some(keys::anchor_pattern()),
+        "corridor" => Some(keys::corridor_pattern()),
+        "dashboard" => Some(keys::dashboard_pattern()),
+        "metrics" => Some(keys::metrics_overview()),
+        _ => None,
+    }
+}
+
 /// Service for managing cache invalidation on data updates
 pub struct CacheInvalidationService {
     cache: Arc<CacheManager>,
@@ -70,6 +81,14 @@ impl CacheInvalidationService {
         self.cache.delete(&keys::metrics_overview()).await
     }
 
+    /// Remove every key matching `pattern`, which may be a specific key or a
+    /// Redis glob. Deletion is SCAN-based so Redis is never blocked.
+    /// Returns the number of keys removed.
+    pub async fn invalidate_matching(&self, pattern: &str) -> anyhow::Result<usize> {
+        tracing::info!("Invalidating caches matching: {}", pattern);
+        self.cache.delete_pattern(pattern).await
+    }
+
     /// Full cache invalidation (use sparingly)
     pub async fn invalidate_all(&self) -> anyhow::Result<()> {
         tracing::warn!("Performing full cache invalidation");
@@ -91,4 +110,11 @@ mod tests {
         assert_eq!(keys::corridor_pattern(), "corridor:*");
         assert_eq!(keys::dashboard_pattern(), "dashboard:*");
     }
+
+    #[test]
+    fn test_tag_pattern() {
+        assert_eq!(tag_pattern("corridor").as_deref(), Some("corridor:*"));
+        assert_eq!(tag_pattern("anchor").as_deref(), Some("anchor:*"));
+        assert!(tag_pattern("everything").is_none());
+    }
 }
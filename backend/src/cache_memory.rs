@@ -0,0 +1,263 @@
+//! In-memory LRU cache used while Redis is unreachable
+//!
+//! Entries carry their own expiry so TTLs behave like `SETEX`, and the store
+//! is bounded: once `max_entries` is reached the least recently used entry is
+//! evicted.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Default number of entries kept when `CACHE_MEMORY_MAX_ENTRIES` is unset
+pub const DEFAULT_MAX_ENTRIES: usize = 10_000;
+
+#[derive(Debug)]
+struct Entry {
+    value: Vec<u8>,
+    expires_at: Instant,
+    /// Position in the recency index
+    tick: u64,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    entries: HashMap<String, Entry>,
+    /// Access tick -> key, oldest first
+    recency: BTreeMap<u64, String>,
+    next_tick: u64,
+}
+
+impl Inner {
+    fn touch(&mut self, key: &str) {
+        let tick = self.next_tick;
+        self.next_tick += 1;
+        if let Some(entry) = self.entries.get_mut(key) {
+            self.recency.remove(&entry.tick);
+            entry.tick = tick;
+            self.recency.insert(tick, key.to_string());
+        }
+    }
+
+    fn remove(&mut self, key: &str) -> bool {
+        match self.entries.remove(key) {
+            Some(entry) => {
+                self.recency.remove(&entry.tick);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn evict_lru(&mut self) {
+        if let Some((_, key)) = self.recency.pop_first() {
+            self.entries.remove(&key);
+        }
+    }
+}
+
+/// Bounded, TTL-aware LRU store of encoded cache values
+#[derive(Debug)]
+pub struct MemoryCache {
+    inner: Mutex<Inner>,
+    max_entries: usize,
+    /// Entries dropped to make room, not counting expiries or deletes
+    evictions: AtomicU64,
+}
+
+impl MemoryCache {
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            inner: Mutex::new(Inner::default()),
+            max_entries: max_entries.max(1),
+            evictions: AtomicU64::new(0),
+        }
+    }
+
+    /// Build with the capacity from `CACHE_MEMORY_MAX_ENTRIES`
+    pub fn from_env() -> Self {
+        let max_entries = std::env::var("CACHE_MEMORY_MAX_ENTRIES")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_MAX_ENTRIES);
+        Self::new(max_entries)
+    }
+
+    pub fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let mut inner = self.lock();
+        let expired = match inner.entries.get(key) {
+            Some(entry) => entry.expires_at <= Instant::now(),
+            None => return None,
+        };
+        if expired {
+            inner.remove(key);
+            return None;
+        }
+        inner.touch(key);
+        inner.entries.get(key).map(|entry| entry.value.clone())
+    }
+
+    pub fn set(&self, key: &str, value: Vec<u8>, ttl: Duration) {
+        let mut inner = self.lock();
+        let expires_at = Instant::now() + ttl;
+
+        if let Some(entry) = inner.entries.get_mut(key) {
+            entry.value = value;
+            entry.expires_at = expires_at;
+            inner.touch(key);
+            return;
+        }
+
+        if inner.entries.len() >= self.max_entries {
+            Self::purge_expired_locked(&mut inner);
+        }
+        while inner.entries.len() >= self.max_entries {
+            inner.evict_lru();
+            self.evictions.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let tick = inner.next_tick;
+        inner.next_tick += 1;
+        inner.recency.insert(tick, key.to_string());
+        inner.entries.insert(
+            key.to_string(),
+            Entry {
+                value,
+                expires_at,
+                tick,
+            },
+        );
+    }
+
+    /// Returns true if the key was present
+    pub fn delete(&self, key: &str) -> bool {
+        self.lock().remove(key)
+    }
+
+    /// Delete every key matching a Redis-style glob (`*` and `?`)
+    pub fn delete_pattern(&self, pattern: &str) -> usize {
+        let mut inner = self.lock();
+        let matching: Vec<String> = inner
+            .entries
+            .keys()
+            .filter(|key| glob_match(pattern, key))
+            .cloned()
+            .collect();
+        for key in &matching {
+            inner.remove(key);
+        }
+        matching.len()
+    }
+
+    /// Drop expired entries, returning how many were removed
+    pub fn purge_expired(&self) -> usize {
+        Self::purge_expired_locked(&mut self.lock())
+    }
+
+    pub fn clear(&self) {
+        *self.lock() = Inner::default();
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of live entries evicted because the store was full
+    pub fn evictions(&self) -> u64 {
+        self.evictions.load(Ordering::Relaxed)
+    }
+
+    fn purge_expired_locked(inner: &mut Inner) -> usize {
+        let now = Instant::now();
+        let expired: Vec<String> = inner
+            .entries
+            .iter()
+            .filter(|(_, entry)| entry.expires_at <= now)
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in &expired {
+            inner.remove(key);
+        }
+        expired.len()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Match `key` against a Redis `MATCH` pattern supporting `*` and `?`
+fn glob_match(pattern: &str, key: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let key: Vec<char> = key.chars().collect();
+    let (mut p, mut k) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while k < key.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == key[k]) {
+            p += 1;
+            k += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, k));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            k = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TTL: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn test_least_recently_used_entry_is_evicted() {
+        let cache = MemoryCache::new(2);
+        cache.set("a", b"1".to_vec(), TTL);
+        cache.set("b", b"2".to_vec(), TTL);
+        assert_eq!(cache.get("a").as_deref(), Some(&b"1"[..]));
+
+        cache.set("c", b"3".to_vec(), TTL);
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.evictions(), 1);
+        assert!(cache.get("b").is_none());
+        assert_eq!(cache.get("a").as_deref(), Some(&b"1"[..]));
+        assert_eq!(cache.get("c").as_deref(), Some(&b"3"[..]));
+    }
+
+    #[test]
+    fn test_expired_entries_are_not_returned() {
+        let cache = MemoryCache::new(10);
+        cache.set("short", b"x".to_vec(), Duration::ZERO);
+        cache.set("long", b"y".to_vec(), TTL);
+
+        assert!(cache.get("short").is_none());
+        assert_eq!(cache.purge_expired(), 0);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_delete_pattern_matches_globs() {
+        let cache = MemoryCache::new(10);
+        cache.set("anchor:list:50:0", b"[]".to_vec(), TTL);
+        cache.set("anchor:detail:1", b"{}".to_vec(), TTL);
+        cache.set("corridor:detail:1", b"{}".to_vec(), TTL);
+
+        assert_eq!(cache.delete_pattern("anchor:*"), 2);
+        assert_eq!(cache.len(), 1);
+        assert!(glob_match("corridor:?etail:*", "corridor:detail:1"));
+        assert!(!glob_match("corridor:*:2", "corridor:detail:1"));
+    }
+}
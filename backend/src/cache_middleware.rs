@@ -1,6 +1,57 @@
+use crate::auth_middleware::{authenticated_user, AdminUsers, JwtSecret};
 use crate::cache::CacheManager;
+use axum::http::{header, request::Parts, HeaderMap};
+use std::convert::Infallible;
 use std::sync::Arc;
 
+/// Whether a request asked to skip cached reads.
+///
+/// Set when the request carries `Cache-Control: no-cache` and a valid Bearer
+/// access token for a user with the admin role; other callers cannot force
+/// recomputation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheBypass(pub bool);
+
+impl CacheBypass {
+    pub fn from_headers(headers: &HeaderMap, jwt_secret: &str, admins: &AdminUsers) -> Self {
+        let no_cache = headers
+            .get(header::CACHE_CONTROL)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| {
+                v.split(',')
+                    .any(|directive| directive.trim().eq_ignore_ascii_case("no-cache"))
+            })
+            .unwrap_or(false);
+        if !no_cache {
+            return Self(false);
+        }
+        Self(authenticated_user(headers, jwt_secret).is_some_and(|user| admins.is_admin(&user)))
+    }
+}
+
+#[axum::async_trait]
+impl<S> axum::extract::FromRequestParts<S> for CacheBypass
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let jwt_secret = match parts.extensions.get::<JwtSecret>() {
+            Some(JwtSecret(secret)) => secret.to_string(),
+            None => match std::env::var("JWT_SECRET") {
+                Ok(secret) => secret,
+                Err(_) => return Ok(Self(false)),
+            },
+        };
+        let admins = match parts.extensions.get::<AdminUsers>() {
+            Some(admins) => admins.clone(),
+            None => AdminUsers::from_env(),
+        };
+        Ok(Self::from_headers(&parts.headers, &jwt_secret, &admins))
+    }
+}
+
 /// Helper trait for cache-aware operations
 pub trait CacheAware {
     fn get_or_fetch<T, F>(
@@ -12,6 +63,19 @@ pub trait CacheAware {
     where
         T: serde::Serialize + serde::de::DeserializeOwned,
         F: std::future::Future<Output = anyhow::Result<T>>;
+
+    /// Like `get_or_fetch`, but skips the cached read when `bypass` is set.
+    /// The fresh result is still written back so later requests see it.
+    fn get_or_fetch_with_bypass<T, F>(
+        cache: &Arc<CacheManager>,
+        key: &str,
+        ttl: usize,
+        bypass: CacheBypass,
+        fetch_fn: F,
+    ) -> impl std::future::Future<Output = anyhow::Result<T>>
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned,
+        F: std::future::Future<Output = anyhow::Result<T>>;
 }
 
 /// Implement for unit type to provide static methods
@@ -22,13 +86,30 @@ impl CacheAware for () {
         ttl: usize,
         fetch_fn: F,
     ) -> impl std::future::Future<Output = anyhow::Result<T>>
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned,
+        F: std::future::Future<Output = anyhow::Result<T>>,
+    {
+        Self::get_or_fetch_with_bypass(cache, key, ttl, CacheBypass(false), fetch_fn)
+    }
+
+    fn get_or_fetch_with_bypass<T, F>(
+        cache: &Arc<CacheManager>,
+        key: &str,
+        ttl: usize,
+        bypass: CacheBypass,
+        fetch_fn: F,
+    ) -> impl std::future::Future<Output = anyhow::Result<T>>
     where
         T: serde::Serialize + serde::de::DeserializeOwned,
         F: std::future::Future<Output = anyhow::Result<T>>,
     {
         async move {
-            // Try to get from cache first
-            if let Ok(Some(cached)) = cache.get::<T>(key).await {
+            if bypass.0 {
+                tracing::debug!("Cache bypass requested for key: {}", key);
+                crate::observability::metrics::record_cache_bypass();
+            } else if let Ok(Some(cached)) = cache.get::<T>(key).await {
+                // Try to get from cache first
                 return Ok(cached);
             }
 
@@ -71,4 +152,91 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), test_data);
     }
+
+    const JWT_SECRET: &str = "test_jwt_secret_key_that_is_long_enough_for_tests_32";
+
+    fn bearer_headers(user_id: &str, cache_control: &str) -> HeaderMap {
+        std::env::set_var("JWT_SECRET", JWT_SECRET);
+        let auth_service = crate::auth::AuthService::new(Arc::new(tokio::sync::RwLock::new(None)));
+        let token = auth_service
+            .generate_access_token(&crate::auth::User {
+                id: user_id.to_string(),
+                username: user_id.to_string(),
+            })
+            .unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert(header::CACHE_CONTROL, cache_control.parse().unwrap());
+        headers.insert(
+            header::AUTHORIZATION,
+            format!("Bearer {}", token).parse().unwrap(),
+        );
+        headers
+    }
+
+    #[test]
+    fn test_cache_bypass_requires_admin_no_cache() {
+        let admins = AdminUsers::new(["admin-1".to_string()]);
+        let bypass = |headers: &HeaderMap| CacheBypass::from_headers(headers, JWT_SECRET, &admins);
+
+        assert_eq!(
+            bypass(&bearer_headers("admin-1", "no-cache")),
+            CacheBypass(true)
+        );
+        assert_eq!(
+            bypass(&bearer_headers("admin-1", "max-age=0, No-Cache")),
+            CacheBypass(true)
+        );
+        assert_eq!(
+            bypass(&bearer_headers("admin-1", "max-age=60")),
+            CacheBypass(false)
+        );
+        // Authenticated but without the admin role
+        assert_eq!(
+            bypass(&bearer_headers("user-1", "no-cache")),
+            CacheBypass(false)
+        );
+
+        let mut anonymous = HeaderMap::new();
+        anonymous.insert(header::CACHE_CONTROL, "no-cache".parse().unwrap());
+        assert_eq!(bypass(&anonymous), CacheBypass(false));
+    }
+
+    #[tokio::test]
+    async fn test_bypass_fetches_upstream_despite_cache_entry() {
+        // Unreachable Redis, so the cache runs on its in-memory fallback
+        let cache = Arc::new(
+            CacheManager::with_redis_url(
+                Default::default(),
+                "redis://127.0.0.1:1",
+                crate::cache_memory::MemoryCache::new(100),
+                std::time::Duration::from_secs(60),
+            )
+            .await,
+        );
+
+        let key = "test:bypass:key";
+        let cached = TestData {
+            value: "cached".to_string(),
+        };
+        cache.set(key, &cached, 60).await.unwrap();
+        let fresh = || async {
+            Ok(TestData {
+                value: "fresh".to_string(),
+            })
+        };
+
+        let non_admin =
+            <()>::get_or_fetch_with_bypass(&cache, key, 60, CacheBypass(false), fresh())
+                .await
+                .unwrap();
+        assert_eq!(non_admin, cached);
+
+        let admin = <()>::get_or_fetch_with_bypass(&cache, key, 60, CacheBypass(true), fresh())
+            .await
+            .unwrap();
+        assert_eq!(admin.value, "fresh");
+
+        // The fresh value was written back for subsequent readers
+        assert_eq!(cache.get::<TestData>(key).await.unwrap(), Some(admin));
+    }
 }
@@ -0,0 +1,185 @@
+use anyhow::{bail, Result};
+use axum::http::{header, HeaderName, HeaderValue, Method};
+use std::time::Duration;
+use tower_http::cors::{Any, CorsLayer};
+
+/// Origins allowed when neither CORS_ALLOWED_ORIGINS nor ALLOWED_ORIGINS is set
+pub const DEFAULT_ORIGINS: &str = "http://localhost:3000,http://localhost:3001";
+
+const ALLOWED_METHODS: [Method; 7] = [
+    Method::GET,
+    Method::POST,
+    Method::PUT,
+    Method::DELETE,
+    Method::OPTIONS,
+    Method::PATCH,
+    Method::HEAD,
+];
+
+/// Request headers listed origins may send with credentials
+const ALLOWED_HEADERS: [HeaderName; 11] = [
+    header::ACCEPT,
+    header::AUTHORIZATION,
+    header::CACHE_CONTROL,
+    header::CONTENT_TYPE,
+    header::IF_NONE_MATCH,
+    HeaderName::from_static("x-api-key"),
+    HeaderName::from_static("x-csrf-token"),
+    HeaderName::from_static("x-request-id"),
+    HeaderName::from_static("x-signature"),
+    HeaderName::from_static("x-timestamp"),
+    HeaderName::from_static("x-wallet-address"),
+];
+
+/// Origins allowed to make cross-origin requests
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CorsOrigins {
+    /// Any origin, without credentials (development only)
+    Any,
+    /// Only these origins, with credentials allowed
+    List(Vec<HeaderValue>),
+}
+
+impl CorsOrigins {
+    /// Origins from CORS_ALLOWED_ORIGINS, or ALLOWED_ORIGINS when that is
+    /// unset. Falls back to the local frontend origins when neither is set.
+    pub fn from_env() -> Result<Self> {
+        match std::env::var("CORS_ALLOWED_ORIGINS").or_else(|_| std::env::var("ALLOWED_ORIGINS")) {
+            Ok(value) => Self::parse(&value),
+            Err(_) => {
+                tracing::info!(
+                    "No CORS origins configured (CORS_ALLOWED_ORIGINS / ALLOWED_ORIGINS); \
+                     allowing {}",
+                    DEFAULT_ORIGINS
+                );
+                Self::parse(DEFAULT_ORIGINS)
+            }
+        }
+    }
+
+    /// Parse a comma-separated origin list; `*` allows any origin. Invalid
+    /// entries are skipped, but a list with no valid origin is an error
+    /// rather than a silent fall back to allow-all (SEC-011).
+    pub fn parse(value: &str) -> Result<Self> {
+        if value.trim() == "*" {
+            tracing::warn!(
+                "CORS configured to allow ALL origins (*). \
+                 This is insecure and should not be used in production."
+            );
+            return Ok(Self::Any);
+        }
+
+        let origins: Vec<HeaderValue> = value
+            .split(',')
+            .map(str::trim)
+            .filter(|origin| !origin.is_empty())
+            .filter_map(|origin| {
+                origin
+                    .parse::<HeaderValue>()
+                    .map_err(|e| tracing::warn!("Skipping invalid CORS origin '{}': {}", origin, e))
+                    .ok()
+            })
+            .collect();
+
+        if origins.is_empty() {
+            bail!(
+                "CORS origins '{}' contain no valid origin; set valid origins or use '*' \
+                 explicitly for development",
+                value
+            );
+        }
+        Ok(Self::List(origins))
+    }
+
+    /// Build the CORS layer. Credentialed requests are only allowed for an
+    /// explicit origin list, since browsers reject them alongside wildcards.
+    pub fn layer(&self) -> CorsLayer {
+        let base = CorsLayer::new()
+            .allow_methods(ALLOWED_METHODS)
+            .max_age(Duration::from_secs(3600));
+
+        match self {
+            Self::Any => base.allow_origin(Any).allow_headers(Any),
+            Self::List(origins) => {
+                tracing::info!("CORS restricted to {} specific origin(s)", origins.len());
+                base.allow_origin(origins.clone())
+                    .allow_headers(ALLOWED_HEADERS)
+                    .allow_credentials(true)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        body::Body,
+        http::{header, Request},
+        routing::get,
+        Router,
+    };
+    use tower::util::ServiceExt;
+
+    #[test]
+    fn test_parse_origin_list() {
+        let origins = CorsOrigins::parse(
+            "https://stellar-insights.com, https://www.stellar-insights.com,,bad\norigin",
+        )
+        .unwrap();
+
+        assert_eq!(
+            origins,
+            CorsOrigins::List(vec![
+                HeaderValue::from_static("https://stellar-insights.com"),
+                HeaderValue::from_static("https://www.stellar-insights.com"),
+            ])
+        );
+        assert_eq!(CorsOrigins::parse(" * ").unwrap(), CorsOrigins::Any);
+        assert!(CorsOrigins::parse(" , ").is_err());
+        assert_eq!(
+            CorsOrigins::parse(DEFAULT_ORIGINS).unwrap(),
+            CorsOrigins::List(vec![
+                HeaderValue::from_static("http://localhost:3000"),
+                HeaderValue::from_static("http://localhost:3001"),
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_listed_origin_gets_credentialed_preflight() {
+        let origins = CorsOrigins::parse("https://stellar-insights.com").unwrap();
+        let app = Router::new()
+            .route("/", get(|| async { "ok" }))
+            .layer(origins.layer());
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method(Method::OPTIONS)
+                    .uri("/")
+                    .header(header::ORIGIN, "https://stellar-insights.com")
+                    .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+                    .header(
+                        header::ACCESS_CONTROL_REQUEST_HEADERS,
+                        "authorization,x-forwarded-for",
+                    )
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let headers = response.headers();
+        assert_eq!(
+            headers[header::ACCESS_CONTROL_ALLOW_ORIGIN],
+            "https://stellar-insights.com"
+        );
+        assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_CREDENTIALS], "true");
+        let allowed_headers = headers[header::ACCESS_CONTROL_ALLOW_HEADERS]
+            .to_str()
+            .unwrap();
+        assert!(allowed_headers.contains("authorization"));
+        assert!(!allowed_headers.contains("x-forwarded-for"));
+    }
+}
@@ -2,19 +2,21 @@ use crate::admin_audit_log::AdminAuditLogger;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode};
-use sqlx::{ConnectOptions, SqlitePool};
+use sqlx::{ConnectOptions, Sqlite, SqlitePool};
 use std::str::FromStr;
 use std::time::Duration;
 use std::time::Instant;
 use uuid::Uuid;
 
-use crate::analytics::compute_anchor_metrics;
+use crate::analytics::{compute_anchor_metrics, ReliabilitySmoothing};
+use crate::db::pagination::{PaginatedQuery, SortDirection};
 use crate::models::api_key::{
     generate_api_key, hash_api_key, ApiKey, ApiKeyInfo, CreateApiKeyRequest, CreateApiKeyResponse,
 };
 use crate::models::{
-    Anchor, AnchorDetailResponse, AnchorMetricsHistory, Asset, CorridorRecord, CreateAnchorRequest,
-    MetricRecord, MuxedAccountAnalytics, MuxedAccountUsage, SnapshotRecord,
+    Anchor, AnchorDetailResponse, AnchorMetricsHistory, AnchorStatus, Asset, CorridorRecord,
+    CreateAnchorRequest, MetricRecord, MuxedAccountAnalytics, MuxedAccountUsage,
+    MuxedBaseAccountUsage, SnapshotRecord,
 };
 
 /// Configuration for database connection pool
@@ -25,6 +27,11 @@ pub struct PoolConfig {
     pub connect_timeout_seconds: u64,
     pub idle_timeout_seconds: u64,
     pub max_lifetime_seconds: u64,
+    /// How long a connection waits on a locked database before failing
+    pub busy_timeout_ms: u64,
+    /// Use write-ahead logging so readers don't block the writer
+    pub wal_mode: bool,
+    pub foreign_keys: bool,
 }
 
 impl Default for PoolConfig {
@@ -35,6 +42,9 @@ impl Default for PoolConfig {
             connect_timeout_seconds: 30,
             idle_timeout_seconds: 600,
             max_lifetime_seconds: 1800,
+            busy_timeout_ms: 5000,
+            wal_mode: true,
+            foreign_keys: true,
         }
     }
 }
@@ -46,7 +56,7 @@ pub struct SqlLogConfig {
     pub level: log::LevelFilter,
     /// In development: log all queries. In production: log only slow queries.
     pub log_all_in_dev: bool,
-    /// Slow query threshold (ms); only used when log_all_in_dev is false.
+    /// Queries slower than this (ms) are logged as warnings in every mode
     pub slow_query_threshold_ms: u64,
 }
 
@@ -64,7 +74,8 @@ impl SqlLogConfig {
     /// Load from environment:
     /// - RUST_ENV or ENVIRONMENT: "development" => log all queries, else log only slow
     /// - DB_LOG_LEVEL: trace | debug | info | warn | error | off (default: debug in dev, info in prod)
-    /// - DB_SLOW_QUERY_MS: threshold in ms for slow query logging in production (default: 100)
+    /// - SLOW_QUERY_MS: threshold in ms for slow query warnings (default: 100;
+    ///   DB_SLOW_QUERY_MS is still read when unset)
     pub fn from_env() -> Self {
         let env_mode = std::env::var("RUST_ENV")
             .or_else(|_| std::env::var("ENVIRONMENT"))
@@ -72,7 +83,8 @@ impl SqlLogConfig {
         let is_dev = env_mode.to_lowercase() == "development" || env_mode.to_lowercase() == "dev";
 
         let level = parse_db_log_level(is_dev);
-        let slow_query_threshold_ms = std::env::var("DB_SLOW_QUERY_MS")
+        let slow_query_threshold_ms = std::env::var("SLOW_QUERY_MS")
+            .or_else(|_| std::env::var("DB_SLOW_QUERY_MS"))
             .ok()
             .and_then(|s| s.parse().ok())
             .unwrap_or(100);
@@ -127,11 +139,26 @@ impl PoolConfig {
                 .ok()
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(1800),
+            busy_timeout_ms: std::env::var("DB_BUSY_TIMEOUT_MS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(5000),
+            wal_mode: std::env::var("DB_WAL_MODE")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(true),
+            foreign_keys: std::env::var("DB_FOREIGN_KEYS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(true),
         }
     }
 
     /// Create a configured SQLite pool with these settings.
-    /// Uses WAL journal mode and configurable SQL query logging (all in dev, slow-only in prod).
+    /// Applies the journal mode, busy timeout and foreign key pragmas on every
+    /// connection, with SQL query logging (all in dev, slow-only in prod).
+    /// Slow queries are logged as warnings on the `sqlx::query` target, where
+    /// the APM's slow query layer counts them.
     pub async fn create_pool(&self, database_url: &str) -> Result<SqlitePool> {
         let sql_log = SqlLogConfig::from_env();
 
@@ -139,7 +166,12 @@ impl PoolConfig {
             .parse()
             .map_err(|e: sqlx::Error| anyhow::anyhow!("Invalid DATABASE_URL: {}", e))?;
 
-        opts = opts.journal_mode(SqliteJournalMode::Wal);
+        if self.wal_mode {
+            opts = opts.journal_mode(SqliteJournalMode::Wal);
+        }
+        opts = opts
+            .busy_timeout(Duration::from_millis(self.busy_timeout_ms))
+            .foreign_keys(self.foreign_keys);
 
         if sql_log.level != log::LevelFilter::Off {
             if sql_log.log_all_in_dev {
@@ -148,15 +180,13 @@ impl PoolConfig {
                     "SQL query logging: all queries at level {:?} (development)",
                     sql_log.level
                 );
-            } else {
-                let threshold = Duration::from_millis(sql_log.slow_query_threshold_ms);
-                opts = opts.log_slow_statements(sql_log.level, threshold);
-                tracing::info!(
-                    "SQL query logging: slow queries only (> {} ms) at level {:?} (production)",
-                    sql_log.slow_query_threshold_ms,
-                    sql_log.level
-                );
             }
+            let threshold = Duration::from_millis(sql_log.slow_query_threshold_ms);
+            opts = opts.log_slow_statements(log::LevelFilter::Warn, threshold);
+            tracing::info!(
+                "SQL query logging: slow queries (> {} ms) as warnings",
+                sql_log.slow_query_threshold_ms
+            );
         }
 
         let pool = sqlx::sqlite::SqlitePoolOptions::new()
@@ -180,8 +210,8 @@ pub struct AnchorRpcUpdate {
     pub failed_transactions: i64,
     pub total_volume_usd: f64,
     pub avg_settlement_time_ms: i32,
+    /// 0-100 reliability score; the stored status is derived from it
     pub reliability_score: f64,
-    pub status: String,
 }
 
 /// Parameters for recording anchor metrics history
@@ -189,7 +219,10 @@ pub struct AnchorMetricsParams {
     pub anchor_id: Uuid,
     pub success_rate: f64,
     pub failure_rate: f64,
+    /// Score stored on the anchor, smoothed when smoothing is configured
     pub reliability_score: f64,
+    /// Score as computed, before smoothing
+    pub raw_reliability_score: f64,
     pub total_transactions: i64,
     pub successful_transactions: i64,
     pub failed_transactions: i64,
@@ -197,6 +230,58 @@ pub struct AnchorMetricsParams {
     pub volume_usd: Option<f64>,
 }
 
+/// An anchor's average reliability over a window of metrics history
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct AnchorReliabilityAverage {
+    pub anchor_id: String,
+    pub name: String,
+    pub avg_reliability_score: f64,
+    /// History rows the average was taken over
+    pub samples: i64,
+}
+
+/// Rows per multi-row metrics history INSERT, keeping the 12 binds per row
+/// under SQLite's historical limit of 999 parameters per statement
+const METRICS_HISTORY_ROWS_PER_INSERT: usize = 83;
+
+/// Insert a metrics history row on the pool or inside a transaction
+async fn insert_anchor_metrics_history<'e, E>(
+    executor: E,
+    params: &AnchorMetricsParams,
+) -> Result<AnchorMetricsHistory>
+where
+    E: sqlx::Executor<'e, Database = Sqlite>,
+{
+    let id = Uuid::new_v4().to_string();
+    let history = sqlx::query_as::<_, AnchorMetricsHistory>(
+        r#"
+        INSERT INTO anchor_metrics_history (
+            id, anchor_id, timestamp, success_rate, failure_rate, reliability_score,
+            total_transactions, successful_transactions, failed_transactions,
+            avg_settlement_time_ms, volume_usd, raw_reliability_score
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+        RETURNING *
+        "#,
+    )
+    .bind(id)
+    .bind(params.anchor_id.to_string())
+    .bind(Utc::now())
+    .bind(params.success_rate)
+    .bind(params.failure_rate)
+    .bind(params.reliability_score)
+    .bind(params.total_transactions)
+    .bind(params.successful_transactions)
+    .bind(params.failed_transactions)
+    .bind(params.avg_settlement_time_ms.unwrap_or(0))
+    .bind(params.volume_usd.unwrap_or(0.0))
+    .bind(params.raw_reliability_score)
+    .fetch_one(executor)
+    .await?;
+
+    Ok(history)
+}
+
 /// Connection pool metrics
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct PoolMetrics {
@@ -207,6 +292,8 @@ pub struct PoolMetrics {
 pub struct Database {
     pool: SqlitePool,
     pub admin_audit_logger: AdminAuditLogger,
+    /// Applied to anchor reliability scores as metrics updates store them
+    reliability_smoothing: ReliabilitySmoothing,
 }
 
 impl Database {
@@ -215,13 +302,46 @@ impl Database {
         Self {
             pool,
             admin_audit_logger,
+            reliability_smoothing: ReliabilitySmoothing::default(),
         }
     }
 
+    pub fn with_reliability_smoothing(mut self, smoothing: ReliabilitySmoothing) -> Self {
+        self.reliability_smoothing = smoothing;
+        self
+    }
+
+    /// Open a pool on `database_url` with the environment's `PoolConfig` and
+    /// reliability smoothing
+    pub async fn connect(database_url: &str) -> Result<Self> {
+        let pool = PoolConfig::from_env().create_pool(database_url).await?;
+        Ok(Self::new(pool).with_reliability_smoothing(ReliabilitySmoothing::from_env()))
+    }
+
     pub fn pool(&self) -> &SqlitePool {
         &self.pool
     }
 
+    /// Close the pool, waiting up to `timeout` for in-flight queries to finish.
+    ///
+    /// Returns `true` if every connection closed in time. New acquires fail as
+    /// soon as this is called, even if the wait times out.
+    pub async fn close(&self, timeout: Duration) -> bool {
+        let start = Instant::now();
+        let completed = tokio::time::timeout(timeout, self.pool.close())
+            .await
+            .is_ok();
+        if completed {
+            tracing::info!("Database pool closed in {:?}", start.elapsed());
+        } else {
+            tracing::warn!(
+                "Database pool did not close within {:?}, proceeding with shutdown",
+                timeout
+            );
+        }
+        completed
+    }
+
     pub fn corridor_aggregates(&self) -> crate::db::aggregates::CorridorAggregates {
         crate::db::aggregates::CorridorAggregates::new(self.pool.clone())
     }
@@ -276,6 +396,37 @@ impl Database {
         Ok(anchor)
     }
 
+    /// Updates the descriptive fields of an existing anchor.
+    ///
+    /// Used when a create request names a `stellar_account` that is already
+    /// registered and duplicate creation is configured to upsert. Metrics
+    /// are left untouched.
+    pub async fn update_anchor_profile(
+        &self,
+        anchor_id: &str,
+        name: &str,
+        home_domain: Option<&str>,
+    ) -> Result<Anchor> {
+        let anchor = sqlx::query_as::<_, Anchor>(
+            r#"
+            UPDATE anchors
+            SET name = $1,
+                home_domain = COALESCE($2, home_domain),
+                updated_at = $3
+            WHERE id = $4
+            RETURNING *
+            "#,
+        )
+        .bind(name)
+        .bind(home_domain)
+        .bind(Utc::now())
+        .bind(anchor_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(anchor)
+    }
+
     /// Retrieves an anchor by its unique identifier.
     ///
     /// # Arguments
@@ -376,17 +527,12 @@ impl Database {
     /// Query is indexed and metrics are recorded. Typical response time <10ms for limit ≤ 100.
     pub async fn list_anchors(&self, limit: i64, offset: i64) -> Result<Vec<Anchor>> {
         let start = Instant::now();
-        let anchors = sqlx::query_as::<_, Anchor>(
-            r#"
-            SELECT * FROM anchors
-            ORDER BY reliability_score DESC, updated_at DESC
-            LIMIT $1 OFFSET $2
-            "#,
-        )
-        .bind(limit)
-        .bind(offset)
-        .fetch_all(&self.pool)
-        .await?;
+        let anchors = PaginatedQuery::<Anchor>::new("anchors")
+            .sort("reliability_score", SortDirection::Desc)
+            .sort("updated_at", SortDirection::Desc)
+            .offset(limit, offset)
+            .fetch_all(&self.pool)
+            .await?;
 
         crate::observability::metrics::observe_db_query(
             "list_anchors",
@@ -433,8 +579,11 @@ impl Database {
     /// # Side Effects
     ///
     /// - Updates anchor's `updated_at` timestamp
-    /// - Records entry in `anchor_metrics_history` table
-    /// - Computes and updates reliability_score and status
+    /// - Records entry in `anchor_metrics_history` table, keeping the raw
+    ///   score alongside the stored one
+    /// - Computes and updates reliability_score, smoothed against the
+    ///   previous score when EMA smoothing is configured, and the status
+    ///   that score maps to
     pub async fn update_anchor_metrics(
         &self,
         anchor_id: Uuid,
@@ -451,6 +600,15 @@ impl Database {
             failed_transactions,
             avg_settlement_time_ms,
         );
+        let mut tx = self.pool.begin().await?;
+        let previous: Option<(f64, i64)> = sqlx::query_as(
+            "SELECT reliability_score, total_transactions FROM anchors WHERE id = $1",
+        )
+        .bind(anchor_id.to_string())
+        .fetch_optional(&mut *tx)
+        .await?;
+        let reliability_score = self.smoothed_reliability(previous, metrics.reliability_score);
+        let status = AnchorStatus::from_reliability_score(reliability_score);
 
         // Update anchor
         let anchor = sqlx::query_as::<_, Anchor>(
@@ -472,31 +630,46 @@ impl Database {
         .bind(successful_transactions)
         .bind(failed_transactions)
         .bind(avg_settlement_time_ms.unwrap_or(0))
-        .bind(metrics.reliability_score)
-        .bind(metrics.status.as_str())
+        .bind(reliability_score)
+        .bind(status.as_str())
         .bind(volume_usd.unwrap_or(0.0))
         .bind(Utc::now())
         .bind(anchor_id.to_string())
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await?;
 
         // Record metrics history
-        self.record_anchor_metrics_history(AnchorMetricsParams {
-            anchor_id,
-            success_rate: metrics.success_rate,
-            failure_rate: metrics.failure_rate,
-            reliability_score: metrics.reliability_score,
-            total_transactions,
-            successful_transactions,
-            failed_transactions,
-            avg_settlement_time_ms,
-            volume_usd,
-        })
+        insert_anchor_metrics_history(
+            &mut *tx,
+            &AnchorMetricsParams {
+                anchor_id,
+                success_rate: metrics.success_rate,
+                failure_rate: metrics.failure_rate,
+                reliability_score,
+                raw_reliability_score: metrics.reliability_score,
+                total_transactions,
+                successful_transactions,
+                failed_transactions,
+                avg_settlement_time_ms,
+                volume_usd,
+            },
+        )
         .await?;
 
+        tx.commit().await?;
         Ok(anchor)
     }
 
+    /// Reliability score to store given a newly computed one and the anchor's
+    /// stored `(reliability_score, total_transactions)`. An anchor with no
+    /// transactions yet has never been measured, so has nothing to smooth against.
+    fn smoothed_reliability(&self, previous: Option<(f64, i64)>, score: f64) -> f64 {
+        let previous = previous
+            .filter(|(_, total_transactions)| *total_transactions > 0)
+            .map(|(score, _)| score);
+        self.reliability_smoothing.apply(previous, score)
+    }
+
     // Asset operations
 
     /// Creates a new asset or updates existing asset's anchor association.
@@ -693,7 +866,7 @@ impl Database {
         .bind(params.total_volume_usd)
         .bind(params.avg_settlement_time_ms)
         .bind(params.reliability_score)
-        .bind(&params.status)
+        .bind(AnchorStatus::from_reliability_score(params.reliability_score).as_str())
         .bind(Utc::now())
         .bind(&params.stellar_account)
         .execute(&self.pool)
@@ -702,38 +875,151 @@ impl Database {
         Ok(())
     }
 
+    /// Apply RPC metrics for many anchors in one transaction, recording a
+    /// metrics history row for each.
+    ///
+    /// An account with no matching anchor (e.g. deleted since it was listed)
+    /// is skipped and logged; the rest of the batch still commits. Any
+    /// database failure rolls back the whole batch. Returns the number of
+    /// anchors updated.
+    pub async fn update_anchors_from_rpc(&self, updates: Vec<AnchorRpcUpdate>) -> Result<usize> {
+        let start = Instant::now();
+        let mut count = 0;
+        let mut tx = self.pool.begin().await?;
+
+        for params in updates {
+            let previous: Option<(f64, i64)> = sqlx::query_as(
+                "SELECT reliability_score, total_transactions FROM anchors WHERE stellar_account = $1",
+            )
+            .bind(&params.stellar_account)
+            .fetch_optional(&mut *tx)
+            .await?;
+            let reliability_score = self.smoothed_reliability(previous, params.reliability_score);
+
+            let anchor_id: Option<(String,)> = sqlx::query_as(
+                r#"
+                UPDATE anchors
+                SET total_transactions = $1,
+                    successful_transactions = $2,
+                    failed_transactions = $3,
+                    total_volume_usd = $4,
+                    avg_settlement_time_ms = $5,
+                    reliability_score = $6,
+                    status = $7,
+                    updated_at = $8
+                WHERE stellar_account = $9
+                RETURNING id
+                "#,
+            )
+            .bind(params.total_transactions)
+            .bind(params.successful_transactions)
+            .bind(params.failed_transactions)
+            .bind(params.total_volume_usd)
+            .bind(params.avg_settlement_time_ms)
+            .bind(reliability_score)
+            .bind(AnchorStatus::from_reliability_score(reliability_score).as_str())
+            .bind(Utc::now())
+            .bind(&params.stellar_account)
+            .fetch_optional(&mut *tx)
+            .await?;
+
+            let Some((anchor_id,)) = anchor_id else {
+                tracing::warn!(
+                    "Skipping metrics update, no anchor found for account {}",
+                    params.stellar_account
+                );
+                continue;
+            };
+            count += 1;
+
+            let metrics = compute_anchor_metrics(
+                params.total_transactions,
+                params.successful_transactions,
+                params.failed_transactions,
+                Some(params.avg_settlement_time_ms),
+            );
+            insert_anchor_metrics_history(
+                &mut *tx,
+                &AnchorMetricsParams {
+                    anchor_id: Uuid::parse_str(&anchor_id)?,
+                    success_rate: metrics.success_rate,
+                    failure_rate: metrics.failure_rate,
+                    reliability_score,
+                    raw_reliability_score: params.reliability_score,
+                    total_transactions: params.total_transactions,
+                    successful_transactions: params.successful_transactions,
+                    failed_transactions: params.failed_transactions,
+                    avg_settlement_time_ms: Some(params.avg_settlement_time_ms),
+                    volume_usd: Some(params.total_volume_usd),
+                },
+            )
+            .await?;
+        }
+
+        tx.commit().await?;
+        crate::observability::metrics::observe_db_query(
+            "update_anchors_from_rpc",
+            "success",
+            start.elapsed().as_secs_f64(),
+        );
+        Ok(count)
+    }
+
     // Metrics history operations
     pub async fn record_anchor_metrics_history(
         &self,
         params: AnchorMetricsParams,
     ) -> Result<AnchorMetricsHistory> {
-        let id = Uuid::new_v4().to_string();
-        let history = sqlx::query_as::<_, AnchorMetricsHistory>(
-            r#"
-            INSERT INTO anchor_metrics_history (
-                id, anchor_id, timestamp, success_rate, failure_rate, reliability_score,
-                total_transactions, successful_transactions, failed_transactions,
-                avg_settlement_time_ms, volume_usd
-            )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
-            RETURNING *
-            "#,
-        )
-        .bind(id)
-        .bind(params.anchor_id.to_string())
-        .bind(Utc::now())
-        .bind(params.success_rate)
-        .bind(params.failure_rate)
-        .bind(params.reliability_score)
-        .bind(params.total_transactions)
-        .bind(params.successful_transactions)
-        .bind(params.failed_transactions)
-        .bind(params.avg_settlement_time_ms.unwrap_or(0))
-        .bind(params.volume_usd.unwrap_or(0.0))
-        .fetch_one(&self.pool)
-        .await?;
+        insert_anchor_metrics_history(&self.pool, &params).await
+    }
 
-        Ok(history)
+    /// Record many metrics history rows at once, using multi-row INSERTs in a
+    /// single transaction. Returns the number of rows inserted.
+    pub async fn record_anchor_metrics_history_batch(
+        &self,
+        batch: Vec<AnchorMetricsParams>,
+    ) -> Result<u64> {
+        if batch.is_empty() {
+            return Ok(0);
+        }
+
+        let start = Instant::now();
+        let timestamp = Utc::now();
+        let mut inserted = 0;
+        let mut tx = self.pool.begin().await?;
+
+        for chunk in batch.chunks(METRICS_HISTORY_ROWS_PER_INSERT) {
+            let mut query = sqlx::QueryBuilder::<Sqlite>::new(
+                "INSERT INTO anchor_metrics_history (
+                    id, anchor_id, timestamp, success_rate, failure_rate, reliability_score,
+                    total_transactions, successful_transactions, failed_transactions,
+                    avg_settlement_time_ms, volume_usd, raw_reliability_score
+                ) ",
+            );
+            query.push_values(chunk, |mut row, params| {
+                row.push_bind(Uuid::new_v4().to_string())
+                    .push_bind(params.anchor_id.to_string())
+                    .push_bind(timestamp)
+                    .push_bind(params.success_rate)
+                    .push_bind(params.failure_rate)
+                    .push_bind(params.reliability_score)
+                    .push_bind(params.total_transactions)
+                    .push_bind(params.successful_transactions)
+                    .push_bind(params.failed_transactions)
+                    .push_bind(params.avg_settlement_time_ms.unwrap_or(0))
+                    .push_bind(params.volume_usd.unwrap_or(0.0))
+                    .push_bind(params.raw_reliability_score);
+            });
+            inserted += query.build().execute(&mut *tx).await?.rows_affected();
+        }
+
+        tx.commit().await?;
+        crate::observability::metrics::observe_db_query(
+            "record_anchor_metrics_history_batch",
+            "success",
+            start.elapsed().as_secs_f64(),
+        );
+        Ok(inserted)
     }
 
     pub async fn get_anchor_metrics_history(
@@ -757,6 +1043,34 @@ impl Database {
         Ok(history)
     }
 
+    /// Anchors ranked by average reliability score over history recorded
+    /// since `since`. Anchors without history in the window are left out.
+    pub async fn anchor_reliability_leaderboard(
+        &self,
+        since: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<AnchorReliabilityAverage>> {
+        let leaderboard = sqlx::query_as::<_, AnchorReliabilityAverage>(
+            r#"
+            SELECT a.id AS anchor_id, a.name,
+                   AVG(h.reliability_score) AS avg_reliability_score,
+                   COUNT(*) AS samples
+            FROM anchor_metrics_history h
+            JOIN anchors a ON a.id = h.anchor_id
+            WHERE h.timestamp >= $1
+            GROUP BY a.id, a.name
+            ORDER BY avg_reliability_score DESC, a.name ASC
+            LIMIT $2
+            "#,
+        )
+        .bind(since)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(leaderboard)
+    }
+
     pub async fn get_anchor_detail(&self, anchor_id: Uuid) -> Result<Option<AnchorDetailResponse>> {
         let anchor = match self.get_anchor_by_id(anchor_id).await? {
             Some(a) => a,
@@ -808,21 +1122,50 @@ impl Database {
         Ok(corridor)
     }
 
+    /// Inserts any corridors not yet in the catalog, leaving existing rows
+    /// untouched. Returns how many were newly registered.
+    pub async fn register_corridors(
+        &self,
+        corridors: &[crate::models::corridor::Corridor],
+    ) -> Result<u64> {
+        let mut tx = self.pool.begin().await?;
+        let mut registered = 0;
+        for corridor in corridors {
+            let result = sqlx::query(
+                r#"
+                INSERT INTO corridors (
+                    id, source_asset_code, source_asset_issuer,
+                    destination_asset_code, destination_asset_issuer
+                )
+                VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (source_asset_code, source_asset_issuer, destination_asset_code, destination_asset_issuer)
+                DO NOTHING
+                "#,
+            )
+            .bind(Uuid::new_v4().to_string())
+            .bind(&corridor.asset_a_code)
+            .bind(&corridor.asset_a_issuer)
+            .bind(&corridor.asset_b_code)
+            .bind(&corridor.asset_b_issuer)
+            .execute(&mut *tx)
+            .await?;
+            registered += result.rows_affected();
+        }
+        tx.commit().await?;
+        Ok(registered)
+    }
+
     pub async fn list_corridors(
         &self,
         limit: i64,
         offset: i64,
     ) -> Result<Vec<crate::models::corridor::Corridor>> {
         let start = Instant::now();
-        let records = sqlx::query_as::<_, CorridorRecord>(
-            r#"
-            SELECT * FROM corridors ORDER BY reliability_score DESC LIMIT $1 OFFSET $2
-            "#,
-        )
-        .bind(limit)
-        .bind(offset)
-        .fetch_all(&self.pool)
-        .await?;
+        let records = PaginatedQuery::<CorridorRecord>::new("corridors")
+            .sort("reliability_score", SortDirection::Desc)
+            .offset(limit, offset)
+            .fetch_all(&self.pool)
+            .await?;
 
         let corridors = records
             .into_iter()
@@ -995,6 +1338,19 @@ impl Database {
         Ok(state.map(|s| s.last_cursor))
     }
 
+    /// Every ingestion task's stored cursor, by task name
+    pub async fn list_ingestion_states(&self) -> Result<Vec<crate::models::IngestionState>> {
+        let states = sqlx::query_as::<_, crate::models::IngestionState>(
+            r#"
+            SELECT * FROM ingestion_state ORDER BY task_name
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(states)
+    }
+
     pub async fn update_ingestion_cursor(&self, task_name: &str, last_cursor: &str) -> Result<()> {
         sqlx::query(
             r#"
@@ -1021,9 +1377,10 @@ impl Database {
                 r#"
                 INSERT INTO payments (
                     id, transaction_hash, source_account, destination_account,
-                    asset_type, asset_code, asset_issuer, amount, created_at
+                    asset_type, asset_code, asset_issuer, amount, created_at,
+                    operation_type, source_asset_type, source_asset_code, source_asset_issuer
                 )
-                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
                 ON CONFLICT (id) DO NOTHING
                 "#,
             )
@@ -1036,6 +1393,10 @@ impl Database {
             .bind(&payment.asset_issuer)
             .bind(payment.amount)
             .bind(payment.created_at)
+            .bind(&payment.operation_type)
+            .bind(&payment.source_asset_type)
+            .bind(&payment.source_asset_code)
+            .bind(&payment.source_asset_issuer)
             .execute(&self.pool)
             .await?;
         }
@@ -1047,6 +1408,42 @@ impl Database {
         Ok(())
     }
 
+    /// Fetch stored payments created at or after `since`, newest first, each
+    /// marked with whether its ingested transaction succeeded.
+    pub async fn get_payments_since(
+        &self,
+        since: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<crate::models::PaymentRecord>> {
+        let start = Instant::now();
+        let payments = sqlx::query_as::<_, crate::models::PaymentRecord>(
+            r#"
+            SELECT p.id, p.transaction_hash, p.source_account, p.destination_account,
+                   p.asset_type, p.asset_code, p.asset_issuer, p.amount, p.created_at,
+                   p.operation_type, p.source_asset_type,
+                   COALESCE(p.source_asset_code, '') AS source_asset_code,
+                   COALESCE(p.source_asset_issuer, '') AS source_asset_issuer,
+                   -- Payments whose transaction was not ingested are taken as successful
+                   COALESCE(t.successful, 1) AS successful
+            FROM payments p
+            LEFT JOIN transactions t ON t.hash = p.transaction_hash
+            WHERE p.created_at >= $1
+            ORDER BY p.created_at DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(since)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        crate::observability::metrics::observe_db_query(
+            "get_payments_since",
+            "success",
+            start.elapsed().as_secs_f64(),
+        );
+        Ok(payments)
+    }
+
     // Aggregation methods
     pub fn aggregation_db(&self) -> crate::db::aggregation::AggregationDb {
         crate::db::aggregation::AggregationDb::new(self.pool.clone())
@@ -1072,6 +1469,16 @@ impl Database {
             .await
     }
 
+    pub async fn delete_hourly_metrics_in_range(
+        &self,
+        start_time: chrono::DateTime<chrono::Utc>,
+        end_time: chrono::DateTime<chrono::Utc>,
+    ) -> Result<u64> {
+        self.aggregation_db()
+            .delete_hourly_metrics_in_range(start_time, end_time)
+            .await
+    }
+
     pub async fn fetch_hourly_metrics_by_timerange(
         &self,
         start_time: chrono::DateTime<chrono::Utc>,
@@ -1224,23 +1631,95 @@ impl Database {
         })
     }
 
+    /// Payment counts for every muxed sub-account of `base_account`.
+    ///
+    /// An M-address embeds its base key in the same base32 positions as the
+    /// G-address (characters 1..52), so candidates are narrowed with a prefix
+    /// match and then confirmed by decoding.
+    pub async fn get_muxed_usage_for_base_account(
+        &self,
+        base_account: &str,
+    ) -> Result<MuxedBaseAccountUsage> {
+        use crate::muxed;
+
+        let prefix = format!("M{}%", base_account.get(1..52).unwrap_or_default());
+
+        #[derive(sqlx::FromRow)]
+        struct AddrCounts {
+            addr: String,
+            as_source: i64,
+            as_destination: i64,
+        }
+
+        let rows: Vec<AddrCounts> = sqlx::query_as(
+            r#"
+            SELECT addr, SUM(is_source) AS as_source, SUM(is_destination) AS as_destination
+            FROM (
+                SELECT source_account AS addr, 1 AS is_source, 0 AS is_destination
+                FROM payments WHERE source_account LIKE ?1
+                UNION ALL
+                SELECT destination_account AS addr, 0 AS is_source, 1 AS is_destination
+                FROM payments WHERE destination_account LIKE ?1
+            )
+            GROUP BY addr
+            "#,
+        )
+        .bind(&prefix)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut sub_accounts: Vec<MuxedAccountUsage> = rows
+            .into_iter()
+            .filter_map(|row| {
+                let (base, muxed_id) = muxed::resolve_account(&row.addr);
+                (base == base_account && muxed_id.is_some()).then(|| MuxedAccountUsage {
+                    account_address: row.addr,
+                    base_account: Some(base),
+                    muxed_id,
+                    payment_count_as_source: row.as_source,
+                    payment_count_as_destination: row.as_destination,
+                    total_payments: row.as_source + row.as_destination,
+                })
+            })
+            .collect();
+        sub_accounts.sort_by(|a, b| {
+            b.total_payments
+                .cmp(&a.total_payments)
+                .then(a.muxed_id.cmp(&b.muxed_id))
+        });
+
+        Ok(MuxedBaseAccountUsage {
+            base_account: base_account.to_string(),
+            sub_account_count: sub_accounts.len() as i64,
+            total_payments: sub_accounts.iter().map(|u| u.total_payments).sum(),
+            sub_accounts,
+        })
+    }
+
     // =========================
     // Transaction Builder Methods
     // =========================
 
+    /// Create a pending transaction.
+    ///
+    /// When `idempotency_key` is given and `source_account` already has a
+    /// transaction with that key, the existing transaction is returned and
+    /// nothing is inserted. Keys are scoped per source account.
     pub async fn create_pending_transaction(
         &self,
         source_account: &str,
         xdr: &str,
         required_signatures: i32,
+        idempotency_key: Option<&str>,
     ) -> Result<crate::models::PendingTransaction> {
         let id = Uuid::new_v4().to_string();
         let status = "pending";
 
-        let tx = sqlx::query_as::<_, crate::models::PendingTransaction>(
+        let inserted = sqlx::query_as::<_, crate::models::PendingTransaction>(
             r#"
-            INSERT INTO pending_transactions (id, source_account, xdr, required_signatures, status)
-            VALUES ($1, $2, $3, $4, $5)
+            INSERT INTO pending_transactions (id, source_account, xdr, required_signatures, status, idempotency_key)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (source_account, idempotency_key) DO NOTHING
             RETURNING *
             "#,
         )
@@ -1249,6 +1728,23 @@ impl Database {
         .bind(xdr)
         .bind(required_signatures)
         .bind(status)
+        .bind(idempotency_key)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        if let Some(tx) = inserted {
+            return Ok(tx);
+        }
+
+        // Only a keyed insert can conflict, so the key is present here
+        let tx = sqlx::query_as::<_, crate::models::PendingTransaction>(
+            r#"
+            SELECT * FROM pending_transactions
+            WHERE source_account = $1 AND idempotency_key = $2
+            "#,
+        )
+        .bind(source_account)
+        .bind(idempotency_key)
         .fetch_one(&self.pool)
         .await?;
 
@@ -1311,6 +1807,69 @@ impl Database {
         Ok(())
     }
 
+    /// Count distinct signers on a pending transaction and flip it to "ready"
+    /// once `required_signatures` is reached. Signers are checked against the
+    /// source account before their signature is stored, so every row counts.
+    pub async fn check_transaction_ready(
+        &self,
+        id: &str,
+    ) -> Result<Option<crate::models::TransactionReadiness>> {
+        let tx = sqlx::query_as::<_, crate::models::PendingTransaction>(
+            r#"
+            SELECT * FROM pending_transactions WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(tx) = tx else {
+            return Ok(None);
+        };
+
+        let distinct_signers: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(DISTINCT signer) FROM transaction_signatures WHERE transaction_id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        let ready = distinct_signers >= i64::from(tx.required_signatures);
+        let status = if ready && tx.status == "pending" {
+            self.update_transaction_status(id, "ready").await?;
+            "ready".to_string()
+        } else {
+            tx.status
+        };
+
+        Ok(Some(crate::models::TransactionReadiness {
+            transaction_id: tx.id,
+            distinct_signers,
+            required_signatures: tx.required_signatures,
+            ready,
+            status,
+        }))
+    }
+
+    /// Mark a transaction as submitted under its network hash
+    pub async fn record_transaction_submission(&self, id: &str, hash: &str) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE pending_transactions
+            SET status = 'submitted', transaction_hash = $1, updated_at = CURRENT_TIMESTAMP
+            WHERE id = $2
+            "#,
+        )
+        .bind(hash)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
     pub async fn update_transaction_status(&self, id: &str, status: &str) -> Result<()> {
         sqlx::query(
             r#"
@@ -31,7 +31,10 @@ impl AggregationDb {
                 asset_code,
                 asset_issuer,
                 amount,
-                created_at
+                created_at,
+                source_asset_type,
+                source_asset_code,
+                source_asset_issuer
             FROM payments
             WHERE created_at >= ? AND created_at <= ?
             ORDER BY created_at ASC
@@ -58,13 +61,27 @@ impl AggregationDb {
                 // In a real system, you'd have a status field
                 let successful = true;
 
+                // Path payments record their own source asset; older rows and
+                // plain payments send the asset they deliver
+                let (source_asset_code, source_asset_issuer) =
+                    match row.source_asset_type.as_deref() {
+                        Some("native") => ("XLM".to_string(), "native".to_string()),
+                        Some(_) if row.source_asset_code.is_some() => (
+                            row.source_asset_code.clone().unwrap_or_default(),
+                            row.source_asset_issuer.clone().unwrap_or_default(),
+                        ),
+                        _ => (
+                            row.asset_code.clone().unwrap_or_else(|| "XLM".to_string()),
+                            row.asset_issuer
+                                .clone()
+                                .unwrap_or_else(|| "native".to_string()),
+                        ),
+                    };
+
                 Some(crate::models::corridor::PaymentRecord {
                     id: uuid::Uuid::parse_str(&row.id).ok()?,
-                    source_asset_code: row.asset_code.clone().unwrap_or_else(|| "XLM".to_string()),
-                    source_asset_issuer: row
-                        .asset_issuer
-                        .clone()
-                        .unwrap_or_else(|| "native".to_string()),
+                    source_asset_code,
+                    source_asset_issuer,
                     destination_asset_code: row.asset_code.unwrap_or_else(|| "XLM".to_string()),
                     destination_asset_issuer: row
                         .asset_issuer
@@ -106,9 +123,10 @@ impl AggregationDb {
                 avg_slippage_bps,
                 avg_settlement_latency_ms,
                 liquidity_depth_usd,
+                has_missing_prices,
                 created_at,
                 updated_at
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             ON CONFLICT(corridor_key, hour_bucket) DO UPDATE SET
                 total_transactions = total_transactions + excluded.total_transactions,
                 successful_transactions = successful_transactions + excluded.successful_transactions,
@@ -122,6 +140,7 @@ impl AggregationDb {
                     excluded.avg_settlement_latency_ms
                 ),
                 liquidity_depth_usd = (liquidity_depth_usd + excluded.liquidity_depth_usd) / 2.0,
+                has_missing_prices = has_missing_prices OR excluded.has_missing_prices,
                 updated_at = ?
             "#,
         )
@@ -140,6 +159,7 @@ impl AggregationDb {
         .bind(metric.avg_slippage_bps)
         .bind(metric.avg_settlement_latency_ms)
         .bind(metric.liquidity_depth_usd)
+        .bind(metric.has_missing_prices)
         .bind(&now)
         .bind(&now)
         .bind(&now)
@@ -150,6 +170,25 @@ impl AggregationDb {
         Ok(())
     }
 
+    /// Delete the hourly metrics of buckets from `start_time` up to, but not
+    /// including, `end_time`, so they can be rebuilt rather than added to
+    pub async fn delete_hourly_metrics_in_range(
+        &self,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<u64> {
+        let result = sqlx::query(
+            "DELETE FROM corridor_metrics_hourly WHERE hour_bucket >= ? AND hour_bucket < ?",
+        )
+        .bind(start_time.to_rfc3339())
+        .bind(end_time.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .context("Failed to delete hourly metrics by timerange")?;
+
+        Ok(result.rows_affected())
+    }
+
     /// Fetch hourly metrics by time range
     pub async fn fetch_hourly_metrics_by_timerange(
         &self,
@@ -173,7 +212,8 @@ impl AggregationDb {
                 volume_usd,
                 avg_slippage_bps,
                 avg_settlement_latency_ms,
-                liquidity_depth_usd
+                liquidity_depth_usd,
+                has_missing_prices
             FROM corridor_metrics_hourly
             WHERE hour_bucket >= ? AND hour_bucket <= ?
             ORDER BY hour_bucket ASC
@@ -208,6 +248,7 @@ impl AggregationDb {
                     avg_slippage_bps: row.avg_slippage_bps,
                     avg_settlement_latency_ms: row.avg_settlement_latency_ms,
                     liquidity_depth_usd: row.liquidity_depth_usd,
+                    has_missing_prices: row.has_missing_prices,
                 })
             })
             .collect();
@@ -348,6 +389,9 @@ struct PaymentRecordRow {
     asset_issuer: Option<String>,
     amount: f64,
     created_at: String,
+    source_asset_type: Option<String>,
+    source_asset_code: Option<String>,
+    source_asset_issuer: Option<String>,
 }
 
 #[derive(sqlx::FromRow)]
@@ -367,4 +411,5 @@ struct HourlyCorridorMetricsRow {
     avg_slippage_bps: f64,
     avg_settlement_latency_ms: Option<i32>,
     liquidity_depth_usd: f64,
+    has_missing_prices: bool,
 }
@@ -0,0 +1,289 @@
+//! Startup migration runner
+//!
+//! Retries transient lock contention (another instance migrating, a busy
+//! SQLite file) with backoff, and turns genuine failures into an error that
+//! names the offending migration.
+
+use std::future::Future;
+use std::time::Duration;
+
+use sqlx::migrate::{MigrateError, Migrator};
+use sqlx::SqlitePool;
+
+/// SQLite result codes for SQLITE_BUSY and SQLITE_LOCKED
+const SQLITE_BUSY_CODES: [&str; 2] = ["5", "6"];
+
+/// Retry policy for running migrations at startup
+#[derive(Debug, Clone)]
+pub struct MigrationRetryConfig {
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for MigrationRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+impl MigrationRetryConfig {
+    /// Load from environment:
+    /// - DB_MIGRATION_MAX_ATTEMPTS (default: 5)
+    /// - DB_MIGRATION_INITIAL_BACKOFF_MS (default: 500)
+    /// - DB_MIGRATION_MAX_BACKOFF_MS (default: 10000)
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            max_attempts: std::env::var("DB_MIGRATION_MAX_ATTEMPTS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(defaults.max_attempts)
+                .max(1),
+            initial_backoff: std::env::var("DB_MIGRATION_INITIAL_BACKOFF_MS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.initial_backoff),
+            max_backoff: std::env::var("DB_MIGRATION_MAX_BACKOFF_MS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.max_backoff),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MigrationError {
+    #[error(
+        "migration {version} ({description}) failed: {source}. \
+         Fix the migration and restart; the database was left at the previous version"
+    )]
+    MigrationFailed {
+        version: i64,
+        description: String,
+        #[source]
+        source: MigrateError,
+    },
+
+    #[error(
+        "migration {version} ({description}) is marked dirty from an earlier partial run. \
+         Repair the schema and its row in _sqlx_migrations before restarting"
+    )]
+    Dirty { version: i64, description: String },
+
+    #[error("database still locked after {attempts} migration attempt(s): {source}")]
+    LockTimeout {
+        attempts: u32,
+        #[source]
+        source: MigrateError,
+    },
+
+    #[error("failed to run database migrations: {0}")]
+    Other(#[source] MigrateError),
+}
+
+/// Run the embedded `./migrations` against the pool, retrying lock contention
+pub async fn run_migrations(
+    pool: &SqlitePool,
+    config: &MigrationRetryConfig,
+) -> Result<(), MigrationError> {
+    let migrator = sqlx::migrate!("./migrations");
+    run_migrator(&migrator, pool, config).await
+}
+
+/// Run an arbitrary migrator, retrying lock contention
+pub async fn run_migrator(
+    migrator: &Migrator,
+    pool: &SqlitePool,
+    config: &MigrationRetryConfig,
+) -> Result<(), MigrationError> {
+    run_with_retry(config, || migrator.run(pool))
+        .await
+        .map_err(|(attempts, e)| describe_failure(migrator, attempts, e))
+}
+
+/// Call `run` until it succeeds, fails with a non-transient error, or
+/// `max_attempts` is reached. On failure returns the attempt count and error.
+async fn run_with_retry<F, Fut>(
+    config: &MigrationRetryConfig,
+    mut run: F,
+) -> Result<(), (u32, MigrateError)>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), MigrateError>>,
+{
+    let mut attempt = 0;
+    let mut backoff = config.initial_backoff;
+
+    loop {
+        attempt += 1;
+        match run().await {
+            Ok(()) => return Ok(()),
+            Err(e) if is_transient(&e) && attempt < config.max_attempts => {
+                tracing::warn!(
+                    "Database busy while running migrations (attempt {}/{}): {}; retrying in {:?}",
+                    attempt,
+                    config.max_attempts,
+                    e,
+                    backoff
+                );
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(config.max_backoff);
+            }
+            Err(e) => return Err((attempt, e)),
+        }
+    }
+}
+
+/// True for lock/busy errors that are worth retrying
+fn is_transient(error: &MigrateError) -> bool {
+    let sqlx_error = match error {
+        MigrateError::Execute(e) | MigrateError::ExecuteMigration(e, _) => e,
+        _ => return false,
+    };
+
+    match sqlx_error {
+        sqlx::Error::PoolTimedOut => true,
+        sqlx::Error::Database(db_error) => {
+            db_error
+                .code()
+                .is_some_and(|code| SQLITE_BUSY_CODES.contains(&code.as_ref()))
+                || is_lock_message(db_error.message())
+        }
+        other => is_lock_message(&other.to_string()),
+    }
+}
+
+fn is_lock_message(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("database is locked")
+        || message.contains("database table is locked")
+        || message.contains("database is busy")
+}
+
+fn describe_failure(migrator: &Migrator, attempts: u32, error: MigrateError) -> MigrationError {
+    let description = |version: i64| {
+        migrator
+            .iter()
+            .find(|m| m.version == version)
+            .map(|m| m.description.to_string())
+            .unwrap_or_else(|| "unknown".to_string())
+    };
+
+    if is_transient(&error) {
+        return MigrationError::LockTimeout {
+            attempts,
+            source: error,
+        };
+    }
+
+    match error {
+        MigrateError::ExecuteMigration(_, version) => MigrationError::MigrationFailed {
+            version,
+            description: description(version),
+            source: error,
+        },
+        MigrateError::Dirty(version) => MigrationError::Dirty {
+            version,
+            description: description(version),
+        },
+        other => MigrationError::Other(other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn fast_config() -> MigrationRetryConfig {
+        MigrationRetryConfig {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(5),
+        }
+    }
+
+    fn busy_error() -> MigrateError {
+        MigrateError::Execute(sqlx::Error::Protocol("database is locked".to_string()))
+    }
+
+    #[tokio::test]
+    async fn test_transient_busy_error_succeeds_on_retry() {
+        let calls = AtomicU32::new(0);
+
+        let result = run_with_retry(&fast_config(), || {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if call == 0 {
+                    Err(busy_error())
+                } else {
+                    Ok(())
+                }
+            }
+        })
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_persistent_lock_gives_up_after_max_attempts() {
+        let calls = AtomicU32::new(0);
+
+        let result = run_with_retry(&fast_config(), || {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async { Err(busy_error()) }
+        })
+        .await;
+
+        let (attempts, error) = result.unwrap_err();
+        assert_eq!(attempts, 3);
+        assert!(is_transient(&error));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_genuine_failure_reports_offending_migration() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("1_create_widgets.sql"),
+            "CREATE TABLE widgets (id INTEGER PRIMARY KEY);",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("2_add_widget_color.sql"),
+            "ALTER TABLE missing_table ADD COLUMN color TEXT;",
+        )
+        .unwrap();
+
+        let migrator = Migrator::new(dir.path()).await.unwrap();
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+
+        let error = run_migrator(&migrator, &pool, &fast_config())
+            .await
+            .unwrap_err();
+
+        match &error {
+            MigrationError::MigrationFailed {
+                version,
+                description,
+                ..
+            } => {
+                assert_eq!(*version, 2);
+                assert_eq!(description, "add widget color");
+            }
+            other => panic!("expected MigrationFailed, got {:?}", other),
+        }
+        let message = error.to_string();
+        assert!(message.contains("migration 2 (add widget color)"));
+        assert!(message.contains("missing_table"));
+    }
+}
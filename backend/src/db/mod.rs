@@ -1,4 +1,6 @@
 pub mod aggregates;
 pub mod aggregation;
 pub mod alerts;
+pub mod migrations;
+pub mod pagination;
 pub mod schema;
@@ -0,0 +1,348 @@
+use anyhow::Result;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{FromRow, SqlitePool};
+use std::marker::PhantomData;
+
+/// A value bound to a `?` placeholder
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryValue {
+    Text(String),
+    Integer(i64),
+    Real(f64),
+}
+
+impl From<&str> for QueryValue {
+    fn from(value: &str) -> Self {
+        QueryValue::Text(value.to_string())
+    }
+}
+
+impl From<String> for QueryValue {
+    fn from(value: String) -> Self {
+        QueryValue::Text(value)
+    }
+}
+
+impl From<i64> for QueryValue {
+    fn from(value: i64) -> Self {
+        QueryValue::Integer(value)
+    }
+}
+
+impl From<f64> for QueryValue {
+    fn from(value: f64) -> Self {
+        QueryValue::Real(value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterOp {
+    Eq,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+impl FilterOp {
+    fn as_sql(&self) -> &'static str {
+        match self {
+            FilterOp::Eq => "=",
+            FilterOp::Gt => ">",
+            FilterOp::Gte => ">=",
+            FilterOp::Lt => "<",
+            FilterOp::Lte => "<=",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    fn as_sql(&self) -> &'static str {
+        match self {
+            SortDirection::Asc => "ASC",
+            SortDirection::Desc => "DESC",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Page {
+    All,
+    Offset {
+        limit: i64,
+        offset: i64,
+    },
+    /// Keyset pagination: rows strictly past `after` in `column`'s sort order
+    Cursor {
+        column: &'static str,
+        after: QueryValue,
+        limit: i64,
+    },
+}
+
+/// Builder for `SELECT * FROM <table>` listings with filters, sorting and
+/// offset or cursor pagination.
+///
+/// Table and column names are `&'static str` so they can only come from code,
+/// never from request input; every value is bound as a `?` parameter.
+#[derive(Debug, Clone)]
+pub struct PaginatedQuery<T> {
+    table: &'static str,
+    filters: Vec<(&'static str, FilterOp, QueryValue)>,
+    sort: Vec<(&'static str, SortDirection)>,
+    page: Page,
+    _row: PhantomData<fn() -> T>,
+}
+
+impl<T> PaginatedQuery<T> {
+    pub fn new(table: &'static str) -> Self {
+        Self {
+            table,
+            filters: Vec::new(),
+            sort: Vec::new(),
+            page: Page::All,
+            _row: PhantomData,
+        }
+    }
+
+    pub fn filter(
+        mut self,
+        column: &'static str,
+        op: FilterOp,
+        value: impl Into<QueryValue>,
+    ) -> Self {
+        self.filters.push((column, op, value.into()));
+        self
+    }
+
+    /// Add a filter only when `value` is `Some`
+    pub fn filter_opt<V: Into<QueryValue>>(
+        self,
+        column: &'static str,
+        op: FilterOp,
+        value: Option<V>,
+    ) -> Self {
+        match value {
+            Some(value) => self.filter(column, op, value),
+            None => self,
+        }
+    }
+
+    pub fn sort(mut self, column: &'static str, direction: SortDirection) -> Self {
+        self.sort.push((column, direction));
+        self
+    }
+
+    pub fn offset(mut self, limit: i64, offset: i64) -> Self {
+        self.page = Page::Offset { limit, offset };
+        self
+    }
+
+    /// Page past `after` in `column`, following that column's sort direction
+    /// (ascending if it is not sorted on)
+    pub fn cursor(
+        mut self,
+        column: &'static str,
+        after: impl Into<QueryValue>,
+        limit: i64,
+    ) -> Self {
+        self.page = Page::Cursor {
+            column,
+            after: after.into(),
+            limit,
+        };
+        self
+    }
+
+    /// The SQL with `?` placeholders, in the same order as `params`
+    pub fn sql(&self) -> String {
+        let mut sql = format!("SELECT * FROM {}", self.table);
+
+        let mut conditions: Vec<String> = self
+            .filters
+            .iter()
+            .map(|(column, op, _)| format!("{} {} ?", column, op.as_sql()))
+            .collect();
+        if let Page::Cursor { column, .. } = &self.page {
+            let op = match self.direction_of(column) {
+                SortDirection::Asc => FilterOp::Gt,
+                SortDirection::Desc => FilterOp::Lt,
+            };
+            conditions.push(format!("{} {} ?", column, op.as_sql()));
+        }
+        if !conditions.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&conditions.join(" AND "));
+        }
+
+        if !self.sort.is_empty() {
+            let order: Vec<String> = self
+                .sort
+                .iter()
+                .map(|(column, direction)| format!("{} {}", column, direction.as_sql()))
+                .collect();
+            sql.push_str(" ORDER BY ");
+            sql.push_str(&order.join(", "));
+        }
+
+        match &self.page {
+            Page::All => {}
+            Page::Offset { .. } => sql.push_str(" LIMIT ? OFFSET ?"),
+            Page::Cursor { .. } => sql.push_str(" LIMIT ?"),
+        }
+        sql
+    }
+
+    /// Bound values in placeholder order
+    pub fn params(&self) -> Vec<QueryValue> {
+        let mut params: Vec<QueryValue> = self
+            .filters
+            .iter()
+            .map(|(_, _, value)| value.clone())
+            .collect();
+        match &self.page {
+            Page::All => {}
+            Page::Offset { limit, offset } => {
+                params.push(QueryValue::Integer(*limit));
+                params.push(QueryValue::Integer(*offset));
+            }
+            Page::Cursor { after, limit, .. } => {
+                params.push(after.clone());
+                params.push(QueryValue::Integer(*limit));
+            }
+        }
+        params
+    }
+
+    fn direction_of(&self, column: &str) -> SortDirection {
+        self.sort
+            .iter()
+            .find(|(sorted, _)| *sorted == column)
+            .map(|(_, direction)| *direction)
+            .unwrap_or(SortDirection::Asc)
+    }
+}
+
+impl<T> PaginatedQuery<T>
+where
+    T: for<'r> FromRow<'r, SqliteRow> + Send + Unpin,
+{
+    pub async fn fetch_all(&self, pool: &SqlitePool) -> Result<Vec<T>> {
+        let sql = self.sql();
+        let mut query = sqlx::query_as::<_, T>(&sql);
+        for param in self.params() {
+            query = match param {
+                QueryValue::Text(value) => query.bind(value),
+                QueryValue::Integer(value) => query.bind(value),
+                QueryValue::Real(value) => query.bind(value),
+            };
+        }
+        Ok(query.fetch_all(pool).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, sqlx::FromRow)]
+    struct Row {
+        name: String,
+        score: f64,
+    }
+
+    #[test]
+    fn test_offset_query_with_filters_and_sort() {
+        let query = PaginatedQuery::<Row>::new("verified_assets")
+            .filter("verification_status", FilterOp::Eq, "verified")
+            .filter_opt("reputation_score", FilterOp::Gte, Some(60.0))
+            .filter_opt::<i64>("trustline_count", FilterOp::Gte, None)
+            .sort("reputation_score", SortDirection::Desc)
+            .sort("updated_at", SortDirection::Desc)
+            .offset(20, 40);
+
+        assert_eq!(
+            query.sql(),
+            "SELECT * FROM verified_assets WHERE verification_status = ? AND reputation_score >= ? \
+             ORDER BY reputation_score DESC, updated_at DESC LIMIT ? OFFSET ?"
+        );
+        assert_eq!(
+            query.params(),
+            vec![
+                QueryValue::Text("verified".to_string()),
+                QueryValue::Real(60.0),
+                QueryValue::Integer(20),
+                QueryValue::Integer(40),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cursor_query_follows_sort_direction() {
+        let desc = PaginatedQuery::<Row>::new("anchors")
+            .sort("reliability_score", SortDirection::Desc)
+            .cursor("reliability_score", 87.5, 10);
+        assert_eq!(
+            desc.sql(),
+            "SELECT * FROM anchors WHERE reliability_score < ? ORDER BY reliability_score DESC LIMIT ?"
+        );
+        assert_eq!(
+            desc.params(),
+            vec![QueryValue::Real(87.5), QueryValue::Integer(10)]
+        );
+
+        let asc = PaginatedQuery::<Row>::new("anchors")
+            .filter("status", FilterOp::Eq, "green")
+            .cursor("id", "abc", 5);
+        assert_eq!(
+            asc.sql(),
+            "SELECT * FROM anchors WHERE status = ? AND id > ? LIMIT ?"
+        );
+    }
+
+    #[test]
+    fn test_unpaginated_query_has_no_limit() {
+        let query = PaginatedQuery::<Row>::new("corridors");
+        assert_eq!(query.sql(), "SELECT * FROM corridors");
+        assert!(query.params().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_binds_params() {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE items (name TEXT NOT NULL, score REAL NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        for (name, score) in [("a", 10.0), ("b", 50.0), ("c", 90.0), ("d'; DROP", 70.0)] {
+            sqlx::query("INSERT INTO items (name, score) VALUES (?, ?)")
+                .bind(name)
+                .bind(score)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        let rows = PaginatedQuery::<Row>::new("items")
+            .filter("score", FilterOp::Gte, 50.0)
+            .sort("score", SortDirection::Desc)
+            .offset(2, 1)
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+
+        let names: Vec<&str> = rows.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["d'; DROP", "b"]);
+        assert!(rows.iter().all(|r| r.score >= 50.0));
+    }
+}
@@ -5,11 +5,34 @@
 //! if critical configuration is missing.
 
 use anyhow::Result;
+use serde::Serialize;
+use std::collections::BTreeMap;
 use std::env;
 
+use crate::cache::CacheConfig;
+use crate::jobs::JobConfig;
+use crate::network::NetworkConfig;
+use crate::rpc::RpcRateLimitConfig;
+
 /// Required environment variables that must be set
 const REQUIRED_VARS: &[&str] = &["DATABASE_URL", "ENCRYPTION_KEY", "JWT_SECRET"];
 
+/// Secret environment variables; only whether they are set is ever reported
+const SECRET_VARS: &[&str] = &[
+    "JWT_SECRET",
+    "ENCRYPTION_KEY",
+    "WEBHOOK_SIGNING_SECRET",
+    "PRICE_FEED_API_KEY",
+    "TELEGRAM_BOT_TOKEN",
+    "SLACK_WEBHOOK_URL",
+    "WS_AUTH_TOKEN",
+    "VAULT_TOKEN",
+    "STELLAR_SOURCE_SECRET_KEY",
+];
+
+/// Placeholder reported in place of a secret value
+pub const REDACTED: &str = "[REDACTED]";
+
 /// Environment variables that should be validated if present
 const VALIDATED_VARS: &[(&str, fn(&str) -> bool)] = &[
     ("SERVER_PORT", validate_port),
@@ -87,6 +110,7 @@ pub fn log_env_config() {
 
     // CORS
     log_var("CORS_ALLOWED_ORIGINS");
+    log_var("ALLOWED_ORIGINS");
 
     // Slack Bot
     if let Ok(slack_url) = env::var("SLACK_WEBHOOK_URL") {
@@ -111,6 +135,134 @@ pub fn log_env_config() {
     }
 }
 
+/// Effective runtime configuration as resolved by the process, with secrets
+/// masked. Served by `GET /api/admin/config`.
+#[derive(Debug, Clone, Serialize)]
+pub struct AppConfig {
+    pub environment: String,
+    pub network: NetworkSettings,
+    pub database_url: Option<String>,
+    pub redis_url: Option<String>,
+    pub cache_ttls: CacheTtls,
+    pub rate_limits: RateLimitSettings,
+    pub tracing: TracingSettings,
+    pub api: ApiSettings,
+    pub feature_flags: BTreeMap<String, bool>,
+    /// Secret name -> `[REDACTED]` when set, `null` when unset
+    pub secrets: BTreeMap<&'static str, Option<&'static str>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkSettings {
+    pub network: String,
+    pub rpc_url: String,
+    pub horizon_url: String,
+    pub network_passphrase: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheTtls {
+    pub corridor_metrics_secs: usize,
+    pub anchor_data_secs: usize,
+    pub dashboard_stats_secs: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RateLimitSettings {
+    pub rpc_requests_per_minute: f64,
+    pub rpc_burst_size: f64,
+    pub rpc_queue_size: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TracingSettings {
+    pub otlp_endpoint: String,
+    pub sampler: String,
+    pub sample_rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiSettings {
+    pub max_page_limit: i64,
+    pub response_decimals: u32,
+}
+
+impl AppConfig {
+    /// Resolve the configuration the same way the services that use it do.
+    /// `cache` is the config the running `CacheManager` was built with.
+    pub fn resolve(cache: &CacheConfig) -> Self {
+        let network = NetworkConfig::from_env();
+        let rpc_rate_limits = RpcRateLimitConfig::from_env();
+
+        let mut feature_flags = BTreeMap::new();
+        feature_flags.insert("rpc_mock_mode".to_string(), env_flag("RPC_MOCK_MODE"));
+        feature_flags.insert("otel_enabled".to_string(), env_flag("OTEL_ENABLED"));
+        feature_flags.insert("logstash_enabled".to_string(), env_flag("LOGSTASH_ENABLED"));
+        for (job, default_interval) in [
+            ("corridor-refresh", 300),
+            ("anchor-refresh", 600),
+            ("price-feed-update", 900),
+            ("cache-cleanup", 3600),
+        ] {
+            let job = JobConfig::from_env(job, default_interval);
+            feature_flags.insert(format!("job_{}", job.name.replace('-', "_")), job.enabled);
+        }
+
+        Self {
+            environment: env::var("ENVIRONMENT").unwrap_or_else(|_| "development".to_string()),
+            network: NetworkSettings {
+                network: network.network.to_string(),
+                rpc_url: network.rpc_url,
+                horizon_url: network.horizon_url,
+                network_passphrase: network.network_passphrase,
+            },
+            database_url: env::var("DATABASE_URL")
+                .ok()
+                .map(|url| sanitize_database_url(&url)),
+            redis_url: env::var("REDIS_URL").ok().map(|url| sanitize_url(&url)),
+            cache_ttls: CacheTtls {
+                corridor_metrics_secs: cache.corridor_metrics_ttl,
+                anchor_data_secs: cache.anchor_data_ttl,
+                dashboard_stats_secs: cache.dashboard_stats_ttl,
+            },
+            rate_limits: RateLimitSettings {
+                rpc_requests_per_minute: rpc_rate_limits.requests_per_minute,
+                rpc_burst_size: rpc_rate_limits.burst_size,
+                rpc_queue_size: rpc_rate_limits.queue_size,
+            },
+            tracing: TracingSettings {
+                otlp_endpoint: env::var("OTEL_EXPORTER_OTLP_ENDPOINT")
+                    .unwrap_or_else(|_| "http://localhost:4317".to_string()),
+                sampler: env::var("OTEL_SAMPLER").unwrap_or_else(|_| "ratio".to_string()),
+                sample_rate: env::var("OTEL_TRACE_SAMPLE_RATE")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(1.0),
+            },
+            api: ApiSettings {
+                max_page_limit: crate::api::limits::max_page_limit(),
+                response_decimals: crate::api::precision::response_decimals(),
+            },
+            feature_flags,
+            secrets: SECRET_VARS
+                .iter()
+                .map(|name| (*name, redact_secret(env::var(name).ok().as_deref())))
+                .collect(),
+        }
+    }
+}
+
+/// Report a secret only as present or absent
+fn redact_secret(value: Option<&str>) -> Option<&'static str> {
+    value.filter(|v| !v.is_empty()).map(|_| REDACTED)
+}
+
+fn env_flag(name: &str) -> bool {
+    env::var(name)
+        .map(|v| v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+}
+
 /// Helper to log a single environment variable
 fn log_var(name: &str) {
     if let Ok(value) = env::var(name) {
@@ -205,6 +357,13 @@ mod tests {
         assert!(!sanitized.contains("pass"));
     }
 
+    #[test]
+    fn test_redact_secret_never_returns_value() {
+        assert_eq!(redact_secret(Some("super-secret")), Some(REDACTED));
+        assert_eq!(redact_secret(Some("")), None);
+        assert_eq!(redact_secret(None), None);
+    }
+
     #[test]
     fn test_validate_port() {
         assert!(validate_port("8080"));
@@ -1,5 +1,6 @@
 use axum::{
-    extract::Request,
+    async_trait,
+    extract::{rejection::JsonRejection, FromRequest, Request},
     http::StatusCode,
     response::{IntoResponse, Response},
     Json,
@@ -13,9 +14,24 @@ pub struct ErrorResponse {
     pub error: ErrorDetail,
 }
 
+/// Stable class of an error, so clients can branch without matching codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCategory {
+    /// The request itself was invalid or conflicts with current state
+    Validation,
+    NotFound,
+    /// A dependency such as Horizon or RPC failed
+    Upstream,
+    RateLimited,
+    Internal,
+    Auth,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ErrorDetail {
     pub code: String,
+    pub category: ErrorCategory,
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<HashMap<String, serde_json::Value>>,
@@ -49,6 +65,26 @@ pub enum ApiError {
         message: String,
         details: Option<HashMap<String, serde_json::Value>>,
     },
+    Conflict {
+        code: String,
+        message: String,
+        details: Option<HashMap<String, serde_json::Value>>,
+    },
+    Upstream {
+        code: String,
+        message: String,
+        details: Option<HashMap<String, serde_json::Value>>,
+    },
+    RateLimited {
+        code: String,
+        message: String,
+        details: Option<HashMap<String, serde_json::Value>>,
+    },
+    PayloadTooLarge {
+        code: String,
+        message: String,
+        details: Option<HashMap<String, serde_json::Value>>,
+    },
 }
 
 impl ApiError {
@@ -115,13 +151,57 @@ impl ApiError {
         }
     }
 
+    /// Create a Conflict error with details
+    pub fn conflict_with_details(
+        code: impl Into<String>,
+        message: impl Into<String>,
+        details: HashMap<String, serde_json::Value>,
+    ) -> Self {
+        Self::Conflict {
+            code: code.into(),
+            message: message.into(),
+            details: Some(details),
+        }
+    }
+
+    /// Create an Upstream error for a failed dependency
+    pub fn upstream(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::Upstream {
+            code: code.into(),
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    /// Create a RateLimited error
+    pub fn rate_limited(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::RateLimited {
+            code: code.into(),
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    /// Create a PayloadTooLarge error for an oversized request body
+    pub fn payload_too_large(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::PayloadTooLarge {
+            code: code.into(),
+            message: message.into(),
+            details: None,
+        }
+    }
+
     /// Add details to any error variant
     pub fn with_details(mut self, details: HashMap<String, serde_json::Value>) -> Self {
         match &mut self {
             Self::NotFound { details: d, .. }
             | Self::BadRequest { details: d, .. }
             | Self::InternalError { details: d, .. }
-            | Self::Unauthorized { details: d, .. } => {
+            | Self::Unauthorized { details: d, .. }
+            | Self::Conflict { details: d, .. }
+            | Self::Upstream { details: d, .. }
+            | Self::RateLimited { details: d, .. }
+            | Self::PayloadTooLarge { details: d, .. } => {
                 *d = Some(details);
             }
         }
@@ -135,6 +215,24 @@ impl ApiError {
             Self::BadRequest { .. } => StatusCode::BAD_REQUEST,
             Self::InternalError { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             Self::Unauthorized { .. } => StatusCode::UNAUTHORIZED,
+            Self::Conflict { .. } => StatusCode::CONFLICT,
+            Self::Upstream { .. } => StatusCode::BAD_GATEWAY,
+            Self::RateLimited { .. } => StatusCode::TOO_MANY_REQUESTS,
+            Self::PayloadTooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+        }
+    }
+
+    /// Get the category reported to clients for this error
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            Self::NotFound { .. } => ErrorCategory::NotFound,
+            Self::BadRequest { .. } | Self::Conflict { .. } | Self::PayloadTooLarge { .. } => {
+                ErrorCategory::Validation
+            }
+            Self::InternalError { .. } => ErrorCategory::Internal,
+            Self::Unauthorized { .. } => ErrorCategory::Auth,
+            Self::Upstream { .. } => ErrorCategory::Upstream,
+            Self::RateLimited { .. } => ErrorCategory::RateLimited,
         }
     }
 
@@ -169,11 +267,32 @@ impl ApiError {
                 message,
                 details,
             } => (code.clone(), message.clone(), details.clone(), None),
+            Self::Conflict {
+                code,
+                message,
+                details,
+            }
+            | Self::Upstream {
+                code,
+                message,
+                details,
+            }
+            | Self::RateLimited {
+                code,
+                message,
+                details,
+            }
+            | Self::PayloadTooLarge {
+                code,
+                message,
+                details,
+            } => (code.clone(), message.clone(), details.clone(), None),
         };
 
         ErrorResponse {
             error: ErrorDetail {
                 code,
+                category: self.category(),
                 message,
                 details,
                 request_id,
@@ -205,6 +324,9 @@ pub fn error_response_with_request_id(error: ApiError, req: &Request) -> Respons
 /// Convert from anyhow::Error
 impl From<anyhow::Error> for ApiError {
     fn from(err: anyhow::Error) -> Self {
+        if let Some(rpc_err) = err.downcast_ref::<crate::rpc::error::RpcError>() {
+            return rpc_err.clone().into();
+        }
         Self::InternalError {
             code: "INTERNAL_ERROR".to_string(),
             message: "An internal error occurred".to_string(),
@@ -214,6 +336,15 @@ impl From<anyhow::Error> for ApiError {
     }
 }
 
+/// Convert a failed Stellar RPC/Horizon call into a 502
+impl From<crate::rpc::error::RpcError> for ApiError {
+    fn from(err: crate::rpc::error::RpcError) -> Self {
+        // The cause is logged rather than returned to the client
+        tracing::warn!("Upstream RPC request failed: {}", err);
+        Self::upstream("RPC_ERROR", "The Stellar network request failed")
+    }
+}
+
 /// Convert from sqlx::Error
 impl From<sqlx::Error> for ApiError {
     fn from(err: sqlx::Error) -> Self {
@@ -241,6 +372,71 @@ impl From<sqlx::Error> for ApiError {
     }
 }
 
+/// Convert a rejected `Json` body into a structured 400
+impl From<JsonRejection> for ApiError {
+    fn from(rejection: JsonRejection) -> Self {
+        let body_text = rejection.body_text();
+        // axum prefixes the serde error with its own summary; keep the serde part
+        let reason = body_text
+            .split_once(": ")
+            .map(|(_, reason)| reason)
+            .unwrap_or(&body_text);
+        let (code, message) = match &rejection {
+            JsonRejection::JsonSyntaxError(_) => {
+                ("MALFORMED_JSON", format!("Malformed JSON body: {}", reason))
+            }
+            JsonRejection::JsonDataError(_) => (
+                "INVALID_JSON_BODY",
+                format!("Invalid JSON body: {}", reason),
+            ),
+            JsonRejection::MissingJsonContentType(_) => (
+                "MISSING_JSON_CONTENT_TYPE",
+                "Expected request with `Content-Type: application/json`".to_string(),
+            ),
+            _ => ("INVALID_REQUEST_BODY", body_text.clone()),
+        };
+
+        let mut details = HashMap::new();
+        if let Some((line, column)) = parse_error_location(&body_text) {
+            details.insert("line".to_string(), serde_json::json!(line));
+            details.insert("column".to_string(), serde_json::json!(column));
+        }
+
+        if details.is_empty() {
+            Self::bad_request(code, message)
+        } else {
+            Self::bad_request_with_details(code, message, details)
+        }
+    }
+}
+
+/// Pull `line N column M` out of a serde_json error message
+fn parse_error_location(text: &str) -> Option<(u64, u64)> {
+    let rest = &text[text.rfind("line ")? + "line ".len()..];
+    let (line, rest) = rest.split_once(" column ")?;
+    let column: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    Some((line.parse().ok()?, column.parse().ok()?))
+}
+
+/// `Json` extractor that rejects with an `ApiError` instead of axum's plain
+/// text response
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ApiJson<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for ApiJson<T>
+where
+    Json<T>: FromRequest<S, Rejection = JsonRejection>,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(req, state).await?;
+        Ok(Self(value))
+    }
+}
+
 pub type ApiResult<T> = Result<T, ApiError>;
 
 #[cfg(test)]
@@ -321,6 +517,24 @@ mod tests {
         assert!(response.error.details.is_some());
     }
 
+    #[test]
+    fn test_parse_error_location() {
+        assert_eq!(
+            parse_error_location(
+                "Failed to parse the request body as JSON: expected value at line 1 column 10"
+            ),
+            Some((1, 10))
+        );
+        assert_eq!(
+            parse_error_location("Failed to deserialize the JSON body into the target type: name: missing field `name` at line 3 column 1"),
+            Some((3, 1))
+        );
+        assert_eq!(
+            parse_error_location("Failed to buffer the request body"),
+            None
+        );
+    }
+
     #[test]
     fn test_from_anyhow_error() {
         let anyhow_err = anyhow::anyhow!("Test error");
@@ -3,15 +3,39 @@ use sqlx::SqlitePool;
 use std::sync::Arc;
 
 use super::types::*;
+use crate::api::corridors_cached::{
+    corridor_history, corridor_responses_from_payments, CorridorVolumeFloor, HealthScoreWeights,
+};
+use crate::rpc::StellarRpcClient;
+use crate::services::price_feed::PriceFeedClient;
+
+/// Most recent payments scanned when building a corridor's history
+const CORRIDOR_HISTORY_MAX_PAYMENTS: u32 = 200;
 
 pub struct QueryRoot {
     pub pool: Arc<SqlitePool>,
+    pub rpc_client: Arc<StellarRpcClient>,
+    pub price_feed: Arc<PriceFeedClient>,
+}
+
+/// Lookback for a history period (24h, 7d, 30d); `None` for all data
+fn period_window(period: &str) -> Result<Option<chrono::Duration>> {
+    match period {
+        "24h" => Ok(Some(chrono::Duration::hours(24))),
+        "7d" => Ok(Some(chrono::Duration::days(7))),
+        "30d" => Ok(Some(chrono::Duration::days(30))),
+        "all" => Ok(None),
+        other => Err(Error::new(format!(
+            "Unsupported period '{}': expected 24h, 7d, 30d or all",
+            other
+        ))),
+    }
 }
 
 #[Object]
 impl QueryRoot {
     /// Get a single anchor by ID
-    async fn anchor(&self, ctx: &Context<'_>, id: String) -> Result<Option<AnchorType>> {
+    async fn anchor(&self, _ctx: &Context<'_>, id: String) -> Result<Option<AnchorType>> {
         let pool = &self.pool;
         
         let anchor = sqlx::query_as!(
@@ -36,7 +60,7 @@ impl QueryRoot {
     /// Get all anchors with optional filtering and pagination
     async fn anchors(
         &self,
-        ctx: &Context<'_>,
+        _ctx: &Context<'_>,
         filter: Option<AnchorFilter>,
         pagination: Option<PaginationInput>,
     ) -> Result<AnchorsConnection> {
@@ -80,7 +104,7 @@ impl QueryRoot {
     }
 
     /// Get a single corridor by ID
-    async fn corridor(&self, ctx: &Context<'_>, id: String) -> Result<Option<CorridorType>> {
+    async fn corridor(&self, _ctx: &Context<'_>, id: String) -> Result<Option<CorridorType>> {
         let pool = &self.pool;
         
         let corridor = sqlx::query_as!(
@@ -105,7 +129,7 @@ impl QueryRoot {
     /// Get all corridors with optional filtering and pagination
     async fn corridors(
         &self,
-        ctx: &Context<'_>,
+        _ctx: &Context<'_>,
         filter: Option<CorridorFilter>,
         pagination: Option<PaginationInput>,
     ) -> Result<CorridorsConnection> {
@@ -152,8 +176,72 @@ impl QueryRoot {
         })
     }
 
+    /// Get success rate, latency and liquidity history for a corridor, built
+    /// from recent payments as the REST corridor detail is
+    async fn corridor_history(
+        &self,
+        _ctx: &Context<'_>,
+        corridor_key: String,
+        period: Option<String>,
+    ) -> Result<CorridorHistoryType> {
+        let period = period.unwrap_or_else(|| "30d".to_string());
+        let since = period_window(&period)?.map(|window| chrono::Utc::now() - window);
+
+        let mut payments = self
+            .rpc_client
+            .fetch_payment_outcomes(CORRIDOR_HISTORY_MAX_PAYMENTS, None)
+            .await
+            .map_err(|e| Error::new(format!("Failed to fetch payments: {}", e)))?;
+        if let Some(since) = since {
+            payments.retain(|outcome| {
+                chrono::DateTime::parse_from_rfc3339(&outcome.payment.created_at)
+                    .is_ok_and(|created| created >= since)
+            });
+        }
+
+        let corridors = corridor_responses_from_payments(
+            &payments,
+            &self.price_feed,
+            &HealthScoreWeights::default(),
+            CorridorVolumeFloor::default(),
+        )
+        .await;
+        let Some(corridor) = corridors.iter().find(|c| c.id == corridor_key) else {
+            return Ok(CorridorHistoryType {
+                corridor_key,
+                period,
+                payment_count: 0,
+                success_rate: vec![],
+                latency: vec![],
+                liquidity: vec![],
+            });
+        };
+        let history = corridor_history(corridor, &payments);
+
+        Ok(CorridorHistoryType {
+            corridor_key,
+            period,
+            payment_count: history.payment_count as i32,
+            success_rate: history
+                .historical_success_rate
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            latency: history
+                .latency_distribution
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            liquidity: history
+                .liquidity_trends
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        })
+    }
+
     /// Get assets for a specific anchor
-    async fn assets_by_anchor(&self, ctx: &Context<'_>, anchor_id: String) -> Result<Vec<AssetType>> {
+    async fn assets_by_anchor(&self, _ctx: &Context<'_>, anchor_id: String) -> Result<Vec<AssetType>> {
         let pool = &self.pool;
         
         let assets = sqlx::query_as!(
@@ -178,7 +266,7 @@ impl QueryRoot {
     /// Get metrics for an entity within a time range
     async fn metrics(
         &self,
-        ctx: &Context<'_>,
+        _ctx: &Context<'_>,
         entity_id: Option<String>,
         entity_type: Option<String>,
         time_range: Option<TimeRangeInput>,
@@ -212,7 +300,7 @@ impl QueryRoot {
     /// Get latest snapshot for an entity
     async fn latest_snapshot(
         &self,
-        ctx: &Context<'_>,
+        _ctx: &Context<'_>,
         entity_id: String,
         entity_type: String,
     ) -> Result<Option<SnapshotType>> {
@@ -241,7 +329,7 @@ impl QueryRoot {
     /// Search across anchors and corridors
     async fn search(
         &self,
-        ctx: &Context<'_>,
+        _ctx: &Context<'_>,
         query: String,
         limit: Option<i32>,
     ) -> Result<SearchResults> {
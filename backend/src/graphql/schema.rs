@@ -3,12 +3,22 @@ use sqlx::SqlitePool;
 use std::sync::Arc;
 
 use super::resolvers::{MutationRoot, QueryRoot};
+use crate::rpc::StellarRpcClient;
+use crate::services::price_feed::PriceFeedClient;
 
 pub type AppSchema = Schema<QueryRoot, MutationRoot, EmptySubscription>;
 
-pub fn build_schema(pool: Arc<SqlitePool>) -> AppSchema {
+pub fn build_schema(
+    pool: Arc<SqlitePool>,
+    rpc_client: Arc<StellarRpcClient>,
+    price_feed: Arc<PriceFeedClient>,
+) -> AppSchema {
     Schema::build(
-        QueryRoot { pool: pool.clone() },
+        QueryRoot {
+            pool: pool.clone(),
+            rpc_client,
+            price_feed,
+        },
         MutationRoot { pool },
         EmptySubscription,
     )
@@ -2,10 +2,99 @@
 mod tests {
     use super::super::*;
 
-    #[test]
-    fn test_graphql_types_compile() {
-        // This test ensures all types compile correctly
-        // The actual functionality will be tested via integration tests
-        assert!(true);
+    #[tokio::test]
+    async fn test_graphql_types_compile() {
+        // Building the schema checks every type registers correctly
+        let sdl = mock_schema().await.sdl();
+        assert!(sdl.contains("corridorHistory"));
+    }
+
+    async fn mock_schema() -> AppSchema {
+        use crate::services::price_feed::{PriceFeedClient, PriceFeedConfig};
+        use std::sync::Arc;
+
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        build_schema(
+            Arc::new(pool),
+            Arc::new(crate::rpc::StellarRpcClient::new_with_defaults(true)),
+            Arc::new(PriceFeedClient::new(
+                PriceFeedConfig::default(),
+                Default::default(),
+            )),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_corridor_history_from_mock_rpc() {
+        let payments = crate::rpc::StellarRpcClient::new_with_defaults(true)
+            .fetch_payment_outcomes(200, None)
+            .await
+            .unwrap();
+        let corridor_key = payments
+            .iter()
+            .find_map(|outcome| {
+                crate::api::corridors_cached::corridor_key_for_payment(&outcome.payment)
+            })
+            .unwrap();
+        let corridor_payments = payments
+            .iter()
+            .filter(|outcome| {
+                crate::api::corridors_cached::corridor_key_for_payment(&outcome.payment).as_ref()
+                    == Some(&corridor_key)
+            })
+            .count();
+
+        let query = format!(
+            r#"{{ corridorHistory(corridorKey: "{}", period: "all") {{
+                corridorKey paymentCount
+                successRate {{ timestamp successRate attempts }}
+                latency {{ latencyBucketMs count percentage }}
+                liquidity {{ timestamp liquidityUsd volume24hUsd }}
+            }} }}"#,
+            corridor_key
+        );
+        let response = mock_schema().await.execute(query.as_str()).await;
+
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        let history = &data["corridorHistory"];
+        assert_eq!(history["corridorKey"], corridor_key.as_str());
+        assert_eq!(history["paymentCount"], corridor_payments);
+
+        let success_rate = history["successRate"].as_array().unwrap();
+        assert!(!success_rate.is_empty());
+        let attempts: i64 = success_rate
+            .iter()
+            .map(|point| point["attempts"].as_i64().unwrap())
+            .sum();
+        assert_eq!(attempts, corridor_payments as i64);
+        assert_eq!(history["latency"].as_array().unwrap().len(), 5);
+        assert!(!history["liquidity"].as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_corridor_history_of_unknown_corridor_is_empty() {
+        let response = mock_schema()
+            .await
+            .execute(r#"{ corridorHistory(corridorKey: "EURC:GB->XLM:native", period: "all") { paymentCount successRate { attempts } } }"#)
+            .await;
+
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["corridorHistory"]["paymentCount"], 0);
+        assert!(data["corridorHistory"]["successRate"]
+            .as_array()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_corridor_history_rejects_unknown_period() {
+        let response = mock_schema()
+            .await
+            .execute(r#"{ corridorHistory(corridorKey: "XLM:native->USDC:GA", period: "1y") { paymentCount } }"#)
+            .await;
+
+        assert!(response.errors[0].message.contains("Unsupported period"));
     }
 }
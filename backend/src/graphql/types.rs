@@ -80,6 +80,87 @@ pub struct CorridorType {
     pub updated_at: DateTime<Utc>,
 }
 
+/// Daily success rate for a corridor
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+#[graphql(name = "SuccessRateDataPoint")]
+pub struct SuccessRateDataPointType {
+    /// Start of the day (RFC 3339)
+    pub timestamp: String,
+    /// Success rate percentage
+    pub success_rate: f64,
+    /// Number of payment attempts
+    pub attempts: i64,
+}
+
+/// Share of corridor payments in a latency bucket
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+#[graphql(name = "LatencyDataPoint")]
+pub struct LatencyDataPointType {
+    /// Upper bound of the bucket in milliseconds
+    pub latency_bucket_ms: i32,
+    /// Number of payments in the bucket
+    pub count: i64,
+    /// Percentage of all payments
+    pub percentage: f64,
+}
+
+/// Daily liquidity for a corridor
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+#[graphql(name = "LiquidityDataPoint")]
+pub struct LiquidityDataPointType {
+    /// Start of the day (RFC 3339)
+    pub timestamp: String,
+    /// Liquidity in USD
+    pub liquidity_usd: f64,
+    /// Volume over the day in USD
+    pub volume_24h_usd: f64,
+}
+
+/// Corridor time series, matching the history in the REST corridor detail
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+#[graphql(name = "CorridorHistory")]
+pub struct CorridorHistoryType {
+    /// Corridor key (SRC:ISSUER->DST:ISSUER)
+    pub corridor_key: String,
+    /// Period covered (24h, 7d, 30d, all)
+    pub period: String,
+    /// Number of payments the series were built from
+    pub payment_count: i32,
+    pub success_rate: Vec<SuccessRateDataPointType>,
+    pub latency: Vec<LatencyDataPointType>,
+    pub liquidity: Vec<LiquidityDataPointType>,
+}
+
+impl From<crate::api::corridors_cached::SuccessRateDataPoint> for SuccessRateDataPointType {
+    fn from(point: crate::api::corridors_cached::SuccessRateDataPoint) -> Self {
+        Self {
+            timestamp: point.timestamp,
+            success_rate: point.success_rate,
+            attempts: point.attempts,
+        }
+    }
+}
+
+impl From<crate::api::corridors_cached::LatencyDataPoint> for LatencyDataPointType {
+    fn from(point: crate::api::corridors_cached::LatencyDataPoint) -> Self {
+        Self {
+            latency_bucket_ms: point.latency_bucket_ms,
+            count: point.count,
+            percentage: point.percentage,
+        }
+    }
+}
+
+impl From<crate::api::corridors_cached::LiquidityDataPoint> for LiquidityDataPointType {
+    fn from(point: crate::api::corridors_cached::LiquidityDataPoint) -> Self {
+        Self {
+            timestamp: point.timestamp,
+            liquidity_usd: point.liquidity_usd,
+            volume_24h_usd: point.volume_24h_usd,
+        }
+    }
+}
+
 /// Metric data point
 #[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
 #[graphql(name = "Metric")]
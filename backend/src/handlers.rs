@@ -1,17 +1,21 @@
 use axum::{
     extract::{Path, Query, State},
+    http::StatusCode,
     response::IntoResponse,
-    Json,
+    Extension, Json,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use uuid::Uuid;
 
+use crate::api::limits::validate_limit;
 use crate::broadcast::{broadcast_anchor_update, broadcast_corridor_update};
-use crate::error::{ApiError, ApiResult};
+use crate::error::{ApiError, ApiJson, ApiResult};
 use crate::models::corridor::Corridor;
 use crate::models::{AnchorDetailResponse, CreateAnchorRequest, CreateCorridorRequest};
 use crate::services::analytics::{compute_corridor_metrics, CorridorTransaction};
+use crate::shutdown::ShutdownCoordinator;
 use crate::state::AppState;
 
 #[derive(Debug, Deserialize)]
@@ -51,10 +55,8 @@ pub async fn list_anchors(
     State(app_state): State<AppState>,
     Query(params): Query<ListAnchorsQuery>,
 ) -> ApiResult<Json<ListAnchorsResponse>> {
-    let anchors = app_state
-        .db
-        .list_anchors(params.limit, params.offset)
-        .await?;
+    let limit = validate_limit(params.limit)?;
+    let anchors = app_state.db.list_anchors(limit, params.offset).await?;
     let total = anchors.len();
 
     Ok(Json(ListAnchorsResponse { anchors, total }))
@@ -131,26 +133,102 @@ pub async fn get_muxed_analytics(
     Ok(Json(analytics))
 }
 
+/// GET /api/analytics/muxed/:base_account - Usage of each muxed sub-account
+/// of a base (G...) account
+pub async fn get_muxed_account_usage(
+    State(app_state): State<AppState>,
+    Path(base_account): Path<String>,
+) -> ApiResult<Json<crate::models::MuxedBaseAccountUsage>> {
+    let base_account = base_account.trim();
+    if !crate::muxed::is_account_id(base_account) {
+        let mut details = HashMap::new();
+        details.insert("base_account".to_string(), serde_json::json!(base_account));
+        return Err(ApiError::bad_request_with_details(
+            "INVALID_ACCOUNT",
+            "base_account must be a Stellar account ID (G...)",
+            details,
+        ));
+    }
+
+    let usage = app_state
+        .db
+        .get_muxed_usage_for_base_account(base_account)
+        .await?;
+    Ok(Json(usage))
+}
+
+/// Whether creating an anchor for an already-registered `stellar_account`
+/// updates that anchor instead of failing with 409 (`ANCHOR_UPSERT_ON_DUPLICATE`)
+fn anchor_upsert_enabled() -> bool {
+    std::env::var("ANCHOR_UPSERT_ON_DUPLICATE")
+        .map(|v| v.eq_ignore_ascii_case("true") || v == "1")
+        .unwrap_or(false)
+}
+
+/// Whether `err` is a database UNIQUE constraint failure
+fn is_unique_violation(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<sqlx::Error>(),
+        Some(sqlx::Error::Database(db_err)) if db_err.is_unique_violation()
+    )
+}
+
 /// POST /api/anchors - Create a new anchor
 pub async fn create_anchor(
     State(app_state): State<AppState>,
-    Json(req): Json<CreateAnchorRequest>,
+    ApiJson(req): ApiJson<CreateAnchorRequest>,
 ) -> ApiResult<Json<crate::models::Anchor>> {
-    if req.name.is_empty() {
-        return Err(ApiError::bad_request(
-            "INVALID_INPUT",
-            "Name cannot be empty",
-        ));
-    }
+    req.validate()?;
 
-    if req.stellar_account.is_empty() {
-        return Err(ApiError::bad_request(
-            "INVALID_INPUT",
-            "Stellar account cannot be empty",
-        ));
+    if let Some(existing) = app_state
+        .db
+        .get_anchor_by_stellar_account(&req.stellar_account)
+        .await?
+    {
+        if !anchor_upsert_enabled() {
+            let mut details = HashMap::new();
+            details.insert(
+                "existing_anchor_id".to_string(),
+                serde_json::json!(existing.id),
+            );
+            details.insert(
+                "stellar_account".to_string(),
+                serde_json::json!(existing.stellar_account),
+            );
+            return Err(ApiError::conflict_with_details(
+                "ANCHOR_ALREADY_EXISTS",
+                "An anchor with this stellar_account already exists",
+                details,
+            ));
+        }
+
+        let anchor = app_state
+            .db
+            .update_anchor_profile(&existing.id, req.name.trim(), req.home_domain.as_deref())
+            .await?;
+        broadcast_anchor_update(&app_state.ws_state, &anchor);
+        return Ok(Json(anchor));
     }
 
-    let anchor = app_state.db.create_anchor(req).await?;
+    let stellar_account = req.stellar_account.clone();
+    let anchor = match app_state.db.create_anchor(req).await {
+        Ok(anchor) => anchor,
+        // A concurrent create for the same account won the race past the
+        // existence check above
+        Err(err) if is_unique_violation(&err) => {
+            let mut details = HashMap::new();
+            details.insert(
+                "stellar_account".to_string(),
+                serde_json::json!(stellar_account),
+            );
+            return Err(ApiError::conflict_with_details(
+                "ANCHOR_ALREADY_EXISTS",
+                "An anchor with this stellar_account already exists",
+                details,
+            ));
+        }
+        Err(err) => return Err(err.into()),
+    };
 
     // Broadcast the new anchor to WebSocket clients
     broadcast_anchor_update(&app_state.ws_state, &anchor);
@@ -171,7 +249,7 @@ pub struct UpdateMetricsRequest {
 pub async fn update_anchor_metrics(
     State(app_state): State<AppState>,
     Path(id): Path<Uuid>,
-    Json(req): Json<UpdateMetricsRequest>,
+    ApiJson(req): ApiJson<UpdateMetricsRequest>,
 ) -> ApiResult<Json<crate::models::Anchor>> {
     // Verify anchor exists
     if app_state.db.get_anchor_by_id(id).await?.is_none() {
@@ -233,7 +311,7 @@ pub struct CreateAssetRequest {
 pub async fn create_anchor_asset(
     State(app_state): State<AppState>,
     Path(id): Path<Uuid>,
-    Json(req): Json<CreateAssetRequest>,
+    ApiJson(req): ApiJson<CreateAssetRequest>,
 ) -> ApiResult<Json<crate::models::Asset>> {
     // Verify anchor exists
     if app_state.db.get_anchor_by_id(id).await?.is_none() {
@@ -255,9 +333,21 @@ pub async fn create_anchor_asset(
 }
 
 /// Health check endpoint
-pub async fn health_check() -> impl IntoResponse {
-    Json(serde_json::json!({
-        "status": "healthy",
+///
+/// Returns 503 once shutdown has begun so load balancers stop routing here
+/// before the server stops accepting connections.
+pub async fn health_check(
+    shutdown: Option<Extension<Arc<ShutdownCoordinator>>>,
+) -> impl IntoResponse {
+    let draining = shutdown.is_some_and(|Extension(shutdown)| shutdown.is_draining());
+    let status = if draining {
+        StatusCode::SERVICE_UNAVAILABLE
+    } else {
+        StatusCode::OK
+    };
+
+    let body = Json(serde_json::json!({
+        "status": if draining { "draining" } else { "healthy" },
         "service": "stellar-insights-backend",
         "version": env!("CARGO_PKG_VERSION"),
         "api": {
@@ -265,7 +355,8 @@ pub async fn health_check() -> impl IntoResponse {
             "supported_versions": ["v1"],
             "status": "active"
         }
-    }))
+    }));
+    (status, body)
 }
 
 /// Database pool metrics endpoint
@@ -279,10 +370,8 @@ pub async fn list_corridors(
     State(app_state): State<AppState>,
     Query(params): Query<ListCorridorsQuery>,
 ) -> ApiResult<Json<ListCorridorsResponse>> {
-    let corridors = app_state
-        .db
-        .list_corridors(params.limit, params.offset)
-        .await?;
+    let limit = validate_limit(params.limit)?;
+    let corridors = app_state.db.list_corridors(limit, params.offset).await?;
     let total = corridors.len();
     Ok(Json(ListCorridorsResponse { corridors, total }))
 }
@@ -290,20 +379,10 @@ pub async fn list_corridors(
 /// POST /api/corridors - Create a new corridor
 pub async fn create_corridor(
     State(app_state): State<AppState>,
-    Json(req): Json<CreateCorridorRequest>,
+    ApiJson(req): ApiJson<CreateCorridorRequest>,
 ) -> ApiResult<Json<Corridor>> {
-    if req.source_asset_code.is_empty() || req.dest_asset_code.is_empty() {
-        return Err(ApiError::bad_request(
-            "INVALID_INPUT",
-            "Asset codes cannot be empty",
-        ));
-    }
-    if req.source_asset_issuer.is_empty() || req.dest_asset_issuer.is_empty() {
-        return Err(ApiError::bad_request(
-            "INVALID_INPUT",
-            "Asset issuers cannot be empty",
-        ));
-    }
+    req.validate()?;
+
     let corridor = app_state.db.create_corridor(req).await?;
 
     // Broadcast the new corridor to WebSocket clients
@@ -328,7 +407,7 @@ pub struct CorridorTransactionDto {
 pub async fn update_corridor_metrics_from_transactions(
     State(app_state): State<AppState>,
     Path(id): Path<Uuid>,
-    Json(req): Json<UpdateCorridorMetricsFromTxns>,
+    ApiJson(req): ApiJson<UpdateCorridorMetricsFromTxns>,
 ) -> ApiResult<Json<Corridor>> {
     if app_state.db.get_corridor_by_id(id).await?.is_none() {
         let mut details = HashMap::new();
@@ -3,17 +3,21 @@ use std::sync::{Mutex, OnceLock};
 
 use axum::{
     body::Body,
+    extract::Request,
     http::{
         header::{
-            CACHE_CONTROL, CONTENT_TYPE, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+            ACCEPT, CACHE_CONTROL, CONTENT_ENCODING, CONTENT_TYPE, ETAG, IF_MODIFIED_SINCE,
+            IF_NONE_MATCH, LAST_MODIFIED,
         },
         HeaderMap, HeaderValue, StatusCode,
     },
+    middleware::Next,
     response::Response,
 };
 use chrono::{DateTime, Utc};
 use serde::Serialize;
 use sha2::{Digest, Sha256};
+use tower_http::compression::{predicate::SizeAbove, CompressionLayer};
 
 #[derive(Clone)]
 struct CacheEntry {
@@ -127,6 +131,39 @@ pub fn cached_json_response<T: Serialize>(
     ttl_seconds: usize,
 ) -> anyhow::Result<Response> {
     let body = serde_json::to_vec(payload)?;
+    Ok(cached_response(
+        request_headers,
+        resource_key,
+        body,
+        "application/json",
+        ttl_seconds,
+    ))
+}
+
+/// CSV counterpart of `cached_json_response`; `resource_key` should differ
+/// from the JSON representation's so their Last-Modified times are tracked apart
+pub fn cached_csv_response(
+    request_headers: &HeaderMap,
+    resource_key: &str,
+    csv: String,
+    ttl_seconds: usize,
+) -> Response {
+    cached_response(
+        request_headers,
+        resource_key,
+        csv.into_bytes(),
+        "text/csv; charset=utf-8",
+        ttl_seconds,
+    )
+}
+
+fn cached_response(
+    request_headers: &HeaderMap,
+    resource_key: &str,
+    body: Vec<u8>,
+    content_type: &'static str,
+    ttl_seconds: usize,
+) -> Response {
     let etag = format!("\"{:x}\"", Sha256::digest(&body));
     let last_modified = resolve_last_modified(resource_key, &etag);
     let cache_control = format!("public, max-age={ttl_seconds}");
@@ -138,15 +175,85 @@ pub fn cached_json_response<T: Serialize>(
         let mut response = Response::new(Body::empty());
         *response.status_mut() = StatusCode::NOT_MODIFIED;
         set_common_headers(response.headers_mut(), &cache_control, &etag, last_modified);
-        return Ok(response);
+        return response;
     }
 
     let mut response = Response::new(Body::from(body));
     response
         .headers_mut()
-        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
     set_common_headers(response.headers_mut(), &cache_control, &etag, last_modified);
-    Ok(response)
+    response
+}
+
+/// Whether the request's Accept header lists `media_type` with a non-zero
+/// quality; `q=0` marks a type as not acceptable
+pub fn accepts(request_headers: &HeaderMap, media_type: &str) -> bool {
+    request_headers
+        .get_all(ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|range| {
+            let mut params = range.split(';');
+            let matches = params
+                .next()
+                .is_some_and(|range| range.trim().eq_ignore_ascii_case(media_type));
+            matches && quality(params) > 0.0
+        })
+}
+
+/// `q` parameter of a media range, defaulting to 1 when absent or malformed
+fn quality<'a>(params: impl Iterator<Item = &'a str>) -> f32 {
+    params
+        .filter_map(|param| param.split_once('='))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("q"))
+        .and_then(|(_, value)| value.trim().parse::<f32>().ok())
+        .unwrap_or(1.0)
+}
+
+/// Compress responses larger than `min_size` bytes with gzip or brotli,
+/// whichever the request's Accept-Encoding prefers
+pub fn compression_layer(min_size: u16) -> CompressionLayer<SizeAbove> {
+    CompressionLayer::new()
+        .gzip(true)
+        .br(true)
+        .compress_when(SizeAbove::new(min_size))
+}
+
+/// Mark the ETag of content-encoded responses as weak.
+///
+/// ETags are hashed from the uncompressed body, so without this the gzip,
+/// brotli and identity representations of a resource would share one strong
+/// ETag. Conditional requests still match because `If-None-Match` ignores the
+/// `W/` prefix. Must be layered outside `compression_layer` so it sees the
+/// `Content-Encoding` the compression layer sets.
+pub async fn weaken_encoded_etag(request: Request, next: Next) -> Response {
+    // A client revalidating a compressed copy gets back the tag it sent
+    let revalidating_weak = request
+        .headers()
+        .get_all(IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .any(|value| value.contains("W/"));
+
+    let mut response = next.run(request).await;
+    let encoded = response.headers().contains_key(CONTENT_ENCODING)
+        || (revalidating_weak && response.status() == StatusCode::NOT_MODIFIED);
+    if !encoded {
+        return response;
+    }
+
+    let weak = response
+        .headers()
+        .get(ETAG)
+        .and_then(|value| value.to_str().ok())
+        .filter(|etag| !etag.starts_with("W/"))
+        .and_then(|etag| HeaderValue::from_str(&format!("W/{}", etag)).ok());
+    if let Some(weak) = weak {
+        response.headers_mut().insert(ETAG, weak);
+    }
+    response
 }
 
 #[cfg(test)]
@@ -174,6 +281,50 @@ mod tests {
         assert_eq!(body, r#"{"value":"a"}"#);
     }
 
+    #[tokio::test]
+    async fn csv_response_has_its_own_content_type_and_etag() {
+        let headers = HeaderMap::new();
+        let json =
+            cached_json_response(&headers, "resource:d", &Payload { value: "d" }, 60).unwrap();
+        let csv = cached_csv_response(&headers, "resource:d:csv", "value\nd\n".to_string(), 60);
+
+        assert_eq!(
+            csv.headers().get(CONTENT_TYPE).unwrap(),
+            "text/csv; charset=utf-8"
+        );
+        assert_ne!(json.headers().get(ETAG), csv.headers().get(ETAG));
+
+        let body = to_bytes(csv.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(body, "value\nd\n");
+    }
+
+    #[test]
+    fn accepts_matches_any_listed_media_type() {
+        let mut headers = HeaderMap::new();
+        assert!(!accepts(&headers, "text/csv"));
+
+        headers.insert(
+            ACCEPT,
+            HeaderValue::from_static("application/json;q=0.5, Text/CSV;q=0.9"),
+        );
+        assert!(accepts(&headers, "text/csv"));
+        assert!(!accepts(&headers, "text/html"));
+    }
+
+    #[test]
+    fn accepts_rejects_media_types_with_zero_quality() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            ACCEPT,
+            HeaderValue::from_static("application/json, text/csv; q=0"),
+        );
+        assert!(!accepts(&headers, "text/csv"));
+        assert!(accepts(&headers, "application/json"));
+
+        headers.insert(ACCEPT, HeaderValue::from_static("text/csv;q=0.000"));
+        assert!(!accepts(&headers, "text/csv"));
+    }
+
     #[tokio::test]
     async fn returns_304_when_if_none_match_matches() {
         let headers = HeaderMap::new();
@@ -1,22 +1,86 @@
 // I'm exporting the ledger ingestion module as required by issue #2
 pub mod ledger;
+pub mod pipeline;
+pub mod stream;
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use serde::Serialize;
-use std::sync::Arc;
+use std::collections::HashSet;
+use std::sync::{Arc, OnceLock};
 use tracing::{info, warn};
 
+use crate::api::corridors_cached::corridor_for_payment;
 use crate::database::Database;
-use crate::rpc::StellarRpcClient;
+use crate::models::PaymentRecord;
+use crate::rpc::error::RpcError;
+use crate::rpc::{Payment, StellarRpcClient};
+use crate::services::aggregation::AggregationService;
+use crate::services::fee_bump_tracker::FeeBumpTrackerService;
+use crate::services::health_alerts::HealthAlertEngine;
+use stream::IngestionMode;
+
+/// Task name under which the payment cursor is stored in `ingestion_state`
+pub const PAYMENT_CURSOR_TASK: &str = "incremental_payments";
+/// Page size used when walking forward from the stored cursor
+const PAYMENT_PAGE_SIZE: u32 = 200;
+/// Upper bound on pages fetched per sync so one run cannot stall the loop
+const MAX_PAYMENT_PAGES_PER_SYNC: usize = 25;
+/// Largest ledger range a single replay may cover
+pub const MAX_REPLAY_LEDGERS: u64 = 1_000;
 
 pub struct DataIngestionService {
     rpc_client: Arc<StellarRpcClient>,
     db: Arc<Database>,
+    /// Fetched payment pages allowed to wait for the database writer
+    write_backlog: usize,
+    /// Whether payments are polled during metrics sync or streamed
+    mode: IngestionMode,
+    /// Health score rules evaluated after each sync, once installed
+    health_alerts: OnceLock<Arc<HealthAlertEngine>>,
+    /// Rebuilds hourly corridor metrics for replayed ledgers, once installed
+    aggregation: OnceLock<Arc<AggregationService>>,
 }
 
 impl DataIngestionService {
     pub fn new(rpc_client: Arc<StellarRpcClient>, db: Arc<Database>) -> Self {
-        Self { rpc_client, db }
+        Self {
+            rpc_client,
+            db,
+            write_backlog: pipeline::write_backlog_from_env(),
+            mode: IngestionMode::from_env(),
+            health_alerts: OnceLock::new(),
+            aggregation: OnceLock::new(),
+        }
+    }
+
+    pub fn with_mode(mut self, mode: IngestionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Evaluate `engine`'s rules after every metrics sync. Only the first
+    /// engine installed is kept.
+    pub fn set_health_alerts(&self, engine: Arc<HealthAlertEngine>) {
+        if self.health_alerts.set(engine).is_err() {
+            warn!("Health alert engine already installed, ignoring");
+        }
+    }
+
+    /// Re-aggregate hourly corridor metrics through `service` after each
+    /// ledger replay. Only the first service installed is kept.
+    pub fn set_aggregation(&self, service: Arc<AggregationService>) {
+        if self.aggregation.set(service).is_err() {
+            warn!("Aggregation service already installed, ignoring");
+        }
+    }
+
+    pub fn mode(&self) -> IngestionMode {
+        self.mode
+    }
+
+    pub fn rpc_client(&self) -> &Arc<StellarRpcClient> {
+        &self.rpc_client
     }
 
     /// Sync all metrics from Stellar network
@@ -25,28 +89,254 @@ impl DataIngestionService {
 
         self.sync_anchor_metrics().await?;
 
+        // In stream mode payments arrive through `run_payment_stream`
+        if self.mode == IngestionMode::Poll {
+            match self.ingest_new_payments().await {
+                Ok(count) => info!("Ingested {} new payments", count),
+                Err(e) => warn!("Incremental payment ingestion failed: {}", e),
+            }
+        }
+
+        self.run_health_alerts().await;
+
         info!("Metrics synchronization completed");
         Ok(())
     }
 
-    /// Fetch and process anchor metrics from RPC
+    async fn run_health_alerts(&self) {
+        if let Some(engine) = self.health_alerts.get() {
+            match engine.run_cycle().await {
+                Ok(0) => {}
+                Ok(count) => info!("Dispatched {} corridor health alerts", count),
+                Err(e) => warn!("Corridor health alert evaluation failed: {}", e),
+            }
+        }
+    }
+
+    /// Fetch anchor metrics from RPC and write them in a single transaction
     pub async fn sync_anchor_metrics(&self) -> Result<()> {
         info!("Syncing anchor metrics from Stellar network");
 
         let anchors = self.db.list_anchors(0, 100).await?;
+        let mut updates = Vec::with_capacity(anchors.len());
 
         for anchor in anchors {
             match self.process_anchor_metrics(&anchor.stellar_account).await {
-                Ok(_) => info!("Updated metrics for anchor: {}", anchor.name),
-                Err(e) => warn!("Failed to update anchor {}: {}", anchor.name, e),
+                Ok(Some(update)) => updates.push(update),
+                Ok(None) => {}
+                Err(e) => warn!("Failed to fetch metrics for anchor {}: {}", anchor.name, e),
             }
         }
 
+        if !updates.is_empty() {
+            let count = self.db.update_anchors_from_rpc(updates).await?;
+            info!("Updated metrics for {} anchors", count);
+        }
+
         Ok(())
     }
 
-    /// Process metrics for a single anchor
-    async fn process_anchor_metrics(&self, account_id: &str) -> Result<()> {
+    /// Fetch only payments newer than the stored cursor, persist them and
+    /// advance the cursor. Returns the number of payments saved.
+    ///
+    /// When no cursor exists yet, or Horizon rejects the stored one as unknown
+    /// or expired, the cursor is re-seeded from the most recent page instead of
+    /// backfilling history.
+    ///
+    /// Pages are fetched ahead of the writer over a bounded backlog, so a slow
+    /// database throttles fetching rather than piling pages up in memory.
+    pub async fn ingest_new_payments(&self) -> Result<usize> {
+        let cursor = match self.db.get_ingestion_cursor(PAYMENT_CURSOR_TASK).await? {
+            Some(cursor) => cursor,
+            None => return self.reset_payment_cursor().await,
+        };
+
+        let (cursor_expired, total) = pipeline::run_bounded(
+            self.write_backlog,
+            |backlog| async move {
+                let mut cursor = cursor;
+                for _ in 0..MAX_PAYMENT_PAGES_PER_SYNC {
+                    let payments = match self
+                        .rpc_client
+                        .fetch_payments_after(&cursor, PAYMENT_PAGE_SIZE)
+                        .await
+                    {
+                        Ok(payments) => payments,
+                        Err(e) if is_expired_cursor_error(&e) => {
+                            warn!(
+                                "Payment cursor {} rejected by Horizon ({}), resetting",
+                                cursor, e
+                            );
+                            return Ok(true);
+                        }
+                        Err(e) => return Err(anyhow::anyhow!("{}", e)),
+                    };
+
+                    let Some(last) = payments.last() else {
+                        break;
+                    };
+                    let next_cursor = last.paging_token.clone();
+                    let page_len = payments.len();
+
+                    if !backlog.send((payments, next_cursor.clone())).await {
+                        break;
+                    }
+                    cursor = next_cursor;
+
+                    if page_len < PAYMENT_PAGE_SIZE as usize {
+                        break;
+                    }
+                }
+                Ok(false)
+            },
+            |(payments, next_cursor): (Vec<Payment>, String)| async move {
+                let saved = self.persist_payments(payments).await?;
+                self.db
+                    .update_ingestion_cursor(PAYMENT_CURSOR_TASK, &next_cursor)
+                    .await
+                    .context("Failed to update payment cursor")?;
+                Ok(saved)
+            },
+        )
+        .await?;
+
+        if cursor_expired {
+            return Ok(total + self.reset_payment_cursor().await?);
+        }
+        Ok(total)
+    }
+
+    /// Re-seed the payment cursor from the latest page of payments
+    async fn reset_payment_cursor(&self) -> Result<usize> {
+        let payments = self
+            .rpc_client
+            .fetch_payments(PAYMENT_PAGE_SIZE, None)
+            .await
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+        // Horizon returns this page newest first
+        let Some(newest) = payments.first() else {
+            return Ok(0);
+        };
+        let cursor = newest.paging_token.clone();
+
+        let saved = self.persist_payments(payments).await?;
+        self.db
+            .update_ingestion_cursor(PAYMENT_CURSOR_TASK, &cursor)
+            .await
+            .context("Failed to update payment cursor")?;
+        info!("Payment cursor reset to {}", cursor);
+
+        Ok(saved)
+    }
+
+    /// Add the corridors seen in `payments` to the persistent catalog.
+    /// Returns how many had not been registered before.
+    pub async fn register_observed_corridors(&self, payments: &[Payment]) -> Result<u64> {
+        let observed: HashSet<_> = payments.iter().filter_map(corridor_for_payment).collect();
+        if observed.is_empty() {
+            return Ok(0);
+        }
+
+        let corridors: Vec<_> = observed.into_iter().collect();
+        let registered = self
+            .db
+            .register_corridors(&corridors)
+            .await
+            .context("Failed to register observed corridors")?;
+        if registered > 0 {
+            info!("Registered {} newly observed corridors", registered);
+        }
+        Ok(registered)
+    }
+
+    async fn persist_payments(&self, payments: Vec<Payment>) -> Result<usize> {
+        // The corridor catalog is best-effort; a failure here must not hold
+        // back the payment cursor
+        if let Err(e) = self.register_observed_corridors(&payments).await {
+            warn!("{:#}", e);
+        }
+
+        let records: Vec<PaymentRecord> =
+            payments.into_iter().filter_map(to_payment_record).collect();
+        let count = records.len();
+
+        self.db
+            .save_payments(records)
+            .await
+            .context("Failed to save payments to database")?;
+
+        Ok(count)
+    }
+
+    /// Re-fetch payments and transactions for ledgers `start..=end` and
+    /// rebuild the hourly corridor metrics for the hours they fall in.
+    ///
+    /// Every write is an idempotent upsert, the replayed hours are rebuilt
+    /// from scratch and the ingestion cursors are left untouched, so a replay
+    /// can run alongside live ingestion and be retried after a partial failure.
+    pub async fn replay_ledgers(
+        &self,
+        start: u64,
+        end: u64,
+        fee_bump_tracker: &FeeBumpTrackerService,
+    ) -> Result<LedgerReplaySummary> {
+        info!("Replaying ledgers {} to {}", start, end);
+
+        let mut summary = LedgerReplaySummary::default();
+        let range = self
+            .rpc_client
+            .fetch_payments_for_ledger_range(start, end)
+            .await;
+        let window = payment_time_window(&range.payments);
+        summary.payments = self.persist_payments(range.payments).await?;
+        if let Some(e) = range.error {
+            let failed = range.last_processed.map_or(start, |sequence| sequence + 1);
+            return Err(anyhow::anyhow!("{}", e))
+                .with_context(|| format!("Failed to fetch payments for ledger {}", failed));
+        }
+
+        let transactions = self
+            .rpc_client
+            .fetch_transactions_for_ledger_range(start, end)
+            .await
+            .map_err(|e| anyhow::anyhow!("{}", e))
+            .with_context(|| {
+                format!(
+                    "Failed to fetch transactions for ledgers {}..={}",
+                    start, end
+                )
+            })?;
+        summary.transactions = transactions.len();
+        summary.fee_bumps = fee_bump_tracker.process_transactions(&transactions).await?;
+        summary.ledgers = end - start + 1;
+
+        match (window, self.aggregation.get()) {
+            (Some((first, last)), Some(aggregation)) => {
+                summary.hourly_metrics = aggregation
+                    .reaggregate_hours(first, last)
+                    .await
+                    .context("Failed to re-aggregate replayed hours")?;
+            }
+            (Some(_), None) => {
+                warn!("No aggregation service installed, replayed hours were not re-aggregated")
+            }
+            (None, _) => {}
+        }
+        self.run_health_alerts().await;
+
+        info!(
+            "Replayed {} ledgers ({} payments, {} transactions, {} hourly metrics)",
+            summary.ledgers, summary.payments, summary.transactions, summary.hourly_metrics
+        );
+        Ok(summary)
+    }
+
+    /// Compute metrics for a single anchor; `None` when it has no payments
+    async fn process_anchor_metrics(
+        &self,
+        account_id: &str,
+    ) -> Result<Option<crate::database::AnchorRpcUpdate>> {
         let payments = self
             .rpc_client
             .fetch_account_payments(account_id, 100)
@@ -54,7 +344,7 @@ impl DataIngestionService {
             .map_err(|e| anyhow::anyhow!("{}", e))?;
 
         if payments.is_empty() {
-            return Ok(());
+            return Ok(None);
         }
 
         let mut successful = 0;
@@ -84,34 +374,21 @@ impl DataIngestionService {
             1000
         };
 
-        let status = if success_rate >= 98.0 {
-            "green"
-        } else if success_rate >= 95.0 {
-            "yellow"
-        } else {
-            "red"
-        };
-
-        self.db
-            .update_anchor_from_rpc(crate::database::AnchorRpcUpdate {
-                stellar_account: account_id.to_string(),
-                total_transactions,
-                successful_transactions: successful as i64,
-                failed_transactions: failed as i64,
-                total_volume_usd: total_volume,
-                avg_settlement_time_ms: avg_settlement_time,
-                reliability_score,
-                status: status.to_string(),
-            })
-            .await?;
-
-        Ok(())
+        Ok(Some(crate::database::AnchorRpcUpdate {
+            stellar_account: account_id.to_string(),
+            total_transactions,
+            successful_transactions: successful as i64,
+            failed_transactions: failed as i64,
+            total_volume_usd: total_volume,
+            avg_settlement_time_ms: avg_settlement_time,
+            reliability_score,
+        }))
     }
 
+    /// 0-100 score: the success rate less a point per failure, up to 20
     fn calculate_reliability_score(&self, success_rate: f64, failed_count: i64) -> f64 {
-        let base_score = success_rate / 100.0;
-        let penalty = (failed_count as f64 * 0.01).min(0.2);
-        (base_score - penalty).clamp(0.0, 1.0)
+        let penalty = (failed_count as f64).min(20.0);
+        (success_rate - penalty).clamp(0.0, 100.0)
     }
 
     /// Get current network health status
@@ -130,6 +407,74 @@ impl DataIngestionService {
     }
 }
 
+/// Horizon answers an unknown or pruned paging token with a 4xx rather than
+/// an empty page; those cursors can never succeed and must be reset.
+fn is_expired_cursor_error(error: &RpcError) -> bool {
+    matches!(error, RpcError::ServerError { status, .. } if matches!(status, 400 | 404 | 410))
+}
+
+/// Earliest and latest creation time among `payments`, skipping timestamps
+/// that don't parse. `None` when no payment has one.
+fn payment_time_window(payments: &[Payment]) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    payments
+        .iter()
+        .filter_map(|payment| DateTime::parse_from_rfc3339(&payment.created_at).ok())
+        .map(|created_at| created_at.with_timezone(&Utc))
+        .fold(None, |window, created_at| match window {
+            None => Some((created_at, created_at)),
+            Some((first, last)) => Some((first.min(created_at), last.max(created_at))),
+        })
+}
+
+/// Normalize a Horizon payment into the stored row, keeping path payments'
+/// source asset and operation type. `None` when amount or time don't parse.
+pub fn to_payment_record(payment: Payment) -> Option<PaymentRecord> {
+    let amount = payment.get_amount().parse::<f64>().ok()?;
+    let created_at = DateTime::parse_from_rfc3339(&payment.created_at)
+        .ok()?
+        .with_timezone(&Utc);
+    let destination = payment.get_destination().unwrap_or_default();
+    let asset_code = payment.get_asset_code();
+    let asset_issuer = payment.get_asset_issuer();
+    // Path payments name their source asset separately; plain payments
+    // send and deliver the same asset
+    let (source_asset_type, source_asset_code, source_asset_issuer) =
+        match payment.source_asset_type {
+            Some(source_type) => (
+                source_type,
+                payment.source_asset_code,
+                payment.source_asset_issuer,
+            ),
+            None => (
+                payment.asset_type.clone(),
+                asset_code.clone(),
+                asset_issuer.clone(),
+            ),
+        };
+
+    Some(PaymentRecord {
+        id: payment.id,
+        transaction_hash: payment.transaction_hash,
+        source_account: payment.source_account,
+        destination_account: destination,
+        asset_type: payment.asset_type,
+        asset_code: asset_code.clone(),
+        asset_issuer: asset_issuer.clone(),
+        operation_type: payment.operation_type,
+        source_asset_type: Some(source_asset_type),
+        source_asset_code: source_asset_code.unwrap_or_default(),
+        source_asset_issuer: source_asset_issuer.unwrap_or_default(),
+        destination_asset_code: asset_code.unwrap_or_default(),
+        destination_asset_issuer: asset_issuer.unwrap_or_default(),
+        amount,
+        successful: true,
+        timestamp: Some(created_at),
+        submission_time: None,
+        confirmation_time: None,
+        created_at,
+    })
+}
+
 #[derive(Debug, Clone)]
 pub struct NetworkHealth {
     pub status: String,
@@ -137,6 +482,16 @@ pub struct NetworkHealth {
     pub ledger_retention: u64,
 }
 
+/// Counts reported by [`DataIngestionService::replay_ledgers`]
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LedgerReplaySummary {
+    pub ledgers: u64,
+    pub payments: usize,
+    pub transactions: usize,
+    pub fee_bumps: u64,
+    pub hourly_metrics: usize,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct IngestionStatus {
     pub last_ingested_ledger: u64,
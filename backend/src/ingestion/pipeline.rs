@@ -0,0 +1,154 @@
+//! Bounded fetch → persist pipeline
+//!
+//! The fetch stage hands batches to the writer over a channel holding at most
+//! `capacity` batches. When SQLite writes fall behind, `send` waits for room,
+//! so fetching slows to the writer's pace instead of buffering without bound.
+
+use std::future::Future;
+
+use anyhow::Result;
+use tokio::sync::mpsc;
+
+use crate::observability::metrics;
+
+/// Default number of fetched batches allowed to wait for the writer
+pub const DEFAULT_WRITE_BACKLOG: usize = 4;
+
+/// Write backlog from INGESTION_WRITE_BACKLOG (batches; default: 4)
+pub fn write_backlog_from_env() -> usize {
+    std::env::var("INGESTION_WRITE_BACKLOG")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .filter(|&n| n > 0)
+        .unwrap_or(DEFAULT_WRITE_BACKLOG)
+}
+
+/// Fetch-side handle to the write backlog
+pub struct BacklogSender<T> {
+    tx: mpsc::Sender<T>,
+}
+
+impl<T> BacklogSender<T> {
+    /// Queue a batch, waiting while the backlog is full.
+    ///
+    /// Returns false once the writer has stopped; the fetcher should stop too.
+    pub async fn send(&self, batch: T) -> bool {
+        let sent = self.tx.send(batch).await.is_ok();
+        metrics::set_ingestion_write_backlog(self.depth() as i64);
+        sent
+    }
+
+    /// Batches fetched but not yet picked up by the writer
+    pub fn depth(&self) -> usize {
+        self.tx.max_capacity() - self.tx.capacity()
+    }
+}
+
+/// Run `produce` and `consume` concurrently over a channel of `capacity`
+/// batches. `consume` returns the number of items it wrote.
+///
+/// A writer error stops the fetcher at its next send and is returned in
+/// preference to the fetcher's result. Returns the fetcher's result and the
+/// total written.
+pub async fn run_bounded<T, R, P, PFut, C, CFut>(
+    capacity: usize,
+    produce: P,
+    mut consume: C,
+) -> Result<(R, usize)>
+where
+    P: FnOnce(BacklogSender<T>) -> PFut,
+    PFut: Future<Output = Result<R>>,
+    C: FnMut(T) -> CFut,
+    CFut: Future<Output = Result<usize>>,
+{
+    let (tx, mut rx) = mpsc::channel(capacity.max(1));
+
+    let producer = produce(BacklogSender { tx });
+    let consumer = async move {
+        let mut written = 0;
+        while let Some(batch) = rx.recv().await {
+            metrics::set_ingestion_write_backlog(rx.len() as i64);
+            written += consume(batch).await?;
+        }
+        Ok::<_, anyhow::Error>(written)
+    };
+
+    let (produced, written) = tokio::join!(producer, consumer);
+    metrics::set_ingestion_write_backlog(0);
+
+    let written = written?;
+    Ok((produced?, written))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_slow_writer_blocks_fetcher() {
+        const CAPACITY: usize = 2;
+        const BATCHES: usize = 20;
+        let fetched = &AtomicUsize::new(0);
+        let written = &AtomicUsize::new(0);
+        let max_ahead = &AtomicUsize::new(0);
+
+        let (_, total) = run_bounded(
+            CAPACITY,
+            |tx| async move {
+                for i in 0..BATCHES {
+                    assert!(tx.depth() <= CAPACITY);
+                    assert!(tx.send(vec![i; 3]).await);
+                    fetched.fetch_add(1, Ordering::SeqCst);
+                }
+                Ok(())
+            },
+            |batch: Vec<usize>| {
+                let ahead = fetched
+                    .load(Ordering::SeqCst)
+                    .saturating_sub(written.load(Ordering::SeqCst));
+                max_ahead.fetch_max(ahead, Ordering::SeqCst);
+                async move {
+                    tokio::time::sleep(Duration::from_millis(5)).await;
+                    written.fetch_add(1, Ordering::SeqCst);
+                    Ok(batch.len())
+                }
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(total, BATCHES * 3);
+        // At most the batch in hand plus a full backlog; never the whole run
+        assert!(max_ahead.load(Ordering::SeqCst) <= CAPACITY + 1);
+    }
+
+    #[tokio::test]
+    async fn test_writer_error_stops_fetcher() {
+        let fetched = &AtomicUsize::new(0);
+
+        let result = run_bounded(
+            1,
+            |tx| async move {
+                for i in 0..100 {
+                    if !tx.send(i).await {
+                        break;
+                    }
+                    fetched.fetch_add(1, Ordering::SeqCst);
+                }
+                Ok(())
+            },
+            |batch: i32| async move {
+                if batch == 3 {
+                    anyhow::bail!("disk I/O error");
+                }
+                Ok(1)
+            },
+        )
+        .await;
+
+        assert!(result.unwrap_err().to_string().contains("disk I/O error"));
+        assert!(fetched.load(Ordering::SeqCst) < 10);
+    }
+}
@@ -0,0 +1,255 @@
+//! Streaming payment ingestion over Horizon server-sent events
+//!
+//! In stream mode the service holds open `/payments?cursor=...` and persists
+//! payments as they arrive instead of polling on the metrics sync interval.
+//! The cursor is advanced after every persisted batch, so a dropped stream
+//! resumes from the last saved payment rather than from `now`.
+
+use anyhow::{anyhow, Context, Result};
+use std::str::FromStr;
+use std::time::Duration;
+use tracing::{info, warn};
+
+use super::{is_expired_cursor_error, DataIngestionService, PAYMENT_CURSOR_TASK};
+use crate::rpc::Payment;
+
+/// Delay before reopening a dropped stream when
+/// `INGESTION_STREAM_RECONNECT_SECS` is unset
+pub const DEFAULT_RECONNECT_DELAY: Duration = Duration::from_secs(5);
+/// Reconnect when a stream has been silent this long, so a half-open
+/// connection doesn't stall ingestion indefinitely
+const STREAM_IDLE_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// How new payments are picked up
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IngestionMode {
+    /// Fetch pages after the stored cursor on each metrics sync
+    #[default]
+    Poll,
+    /// Hold a Horizon event stream open and ingest payments as they arrive
+    Stream,
+}
+
+impl IngestionMode {
+    /// Mode from `INGESTION_MODE` (`poll` or `stream`), defaulting to polling
+    pub fn from_env() -> Self {
+        match std::env::var("INGESTION_MODE") {
+            Ok(value) => value.parse().unwrap_or_else(|_| {
+                warn!("Unknown INGESTION_MODE '{}', using poll", value);
+                IngestionMode::Poll
+            }),
+            Err(_) => IngestionMode::Poll,
+        }
+    }
+}
+
+impl FromStr for IngestionMode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "poll" => Ok(IngestionMode::Poll),
+            "stream" => Ok(IngestionMode::Stream),
+            other => anyhow::bail!("unknown ingestion mode: {}", other),
+        }
+    }
+}
+
+/// Reconnect delay from INGESTION_STREAM_RECONNECT_SECS (default: 5)
+pub fn reconnect_delay_from_env() -> Duration {
+    std::env::var("INGESTION_STREAM_RECONNECT_SECS")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_RECONNECT_DELAY)
+}
+
+/// A dispatched server-sent event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SseEvent {
+    pub id: Option<String>,
+    pub data: String,
+}
+
+/// Incremental `text/event-stream` parser; chunks may split lines and events
+/// at any byte
+#[derive(Debug, Default)]
+pub struct SseParser {
+    buffer: Vec<u8>,
+    id: Option<String>,
+    data: Vec<String>,
+}
+
+impl SseParser {
+    /// Feed the next chunk, returning the events it completes
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<SseEvent> {
+        self.buffer.extend_from_slice(chunk);
+        let mut events = Vec::new();
+
+        while let Some(end) = self.buffer.iter().position(|&b| b == b'\n') {
+            let raw: Vec<u8> = self.buffer.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&raw[..end]);
+            let line = line.strip_suffix('\r').unwrap_or(&line);
+
+            if line.is_empty() {
+                if let Some(event) = self.dispatch() {
+                    events.push(event);
+                }
+                continue;
+            }
+            // Lines starting with a colon are keep-alive comments
+            if line.starts_with(':') {
+                continue;
+            }
+
+            let (field, value) = match line.split_once(':') {
+                Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+                None => (line, ""),
+            };
+            match field {
+                "id" => self.id = Some(value.to_string()),
+                "data" => self.data.push(value.to_string()),
+                _ => {}
+            }
+        }
+
+        events
+    }
+
+    fn dispatch(&mut self) -> Option<SseEvent> {
+        let id = self.id.take();
+        if self.data.is_empty() {
+            return None;
+        }
+        let data = std::mem::take(&mut self.data).join("\n");
+        Some(SseEvent { id, data })
+    }
+}
+
+/// Payment carried by an event. Horizon also sends non-payment events such as
+/// its opening `"hello"`, which are skipped.
+fn event_payment(event: &SseEvent) -> Option<Payment> {
+    match serde_json::from_str(&event.data) {
+        Ok(payment) => Some(payment),
+        Err(e) => {
+            if event.data.starts_with('{') {
+                warn!("Skipping unparseable payment event {:?}: {}", event.id, e);
+            }
+            None
+        }
+    }
+}
+
+impl DataIngestionService {
+    /// Keep the payment stream open, reopening it from the stored cursor
+    /// `reconnect_delay` after each drop. Runs until cancelled.
+    pub async fn run_payment_stream(&self, reconnect_delay: Duration) {
+        loop {
+            match self.stream_payments().await {
+                Ok(count) => info!("Payment stream closed after {} payments", count),
+                Err(e) => warn!("Payment stream dropped: {:#}", e),
+            }
+            tokio::time::sleep(reconnect_delay).await;
+        }
+    }
+
+    /// Stream payments after the stored cursor (or from `now` when there is
+    /// none) until the connection ends, persisting each chunk's payments and
+    /// advancing the cursor. Returns the number of payments saved.
+    pub async fn stream_payments(&self) -> Result<usize> {
+        let cursor = self
+            .db
+            .get_ingestion_cursor(PAYMENT_CURSOR_TASK)
+            .await?
+            .unwrap_or_else(|| "now".to_string());
+
+        let mut response = match self.rpc_client.open_payment_stream(&cursor).await {
+            Ok(response) => response,
+            Err(e) if cursor != "now" && is_expired_cursor_error(&e) => {
+                warn!(
+                    "Payment cursor {} rejected by Horizon ({}), streaming from now",
+                    cursor, e
+                );
+                self.rpc_client
+                    .open_payment_stream("now")
+                    .await
+                    .map_err(|e| anyhow!("{}", e))?
+            }
+            Err(e) => return Err(anyhow!("{}", e)),
+        };
+        info!("Payment stream opened at cursor {}", cursor);
+
+        let mut parser = SseParser::default();
+        let mut total = 0;
+        loop {
+            let chunk = match tokio::time::timeout(STREAM_IDLE_TIMEOUT, response.chunk()).await {
+                Ok(Ok(Some(chunk))) => chunk,
+                Ok(Ok(None)) => break,
+                Ok(Err(e)) => return Err(anyhow!("Payment stream read failed: {}", e)),
+                Err(_) => {
+                    warn!("Payment stream idle for {:?}", STREAM_IDLE_TIMEOUT);
+                    break;
+                }
+            };
+
+            let payments: Vec<Payment> = parser
+                .push(&chunk)
+                .iter()
+                .filter_map(event_payment)
+                .collect();
+            let Some(last) = payments.last() else {
+                continue;
+            };
+            let next_cursor = last.paging_token.clone();
+
+            total += self.persist_payments(payments).await?;
+            self.db
+                .update_ingestion_cursor(PAYMENT_CURSOR_TASK, &next_cursor)
+                .await
+                .context("Failed to update payment cursor")?;
+        }
+
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parser_handles_split_chunks() {
+        let mut parser = SseParser::default();
+
+        assert!(parser.push(b"retry: 1000\nid: 10").is_empty());
+        assert!(parser.push(b"1\r\ndata: {\"a\":").is_empty());
+        let events = parser.push(b"1}\n\n: keep-alive\n\ndata: \"hello\"\n\n");
+
+        assert_eq!(
+            events,
+            vec![
+                SseEvent {
+                    id: Some("101".to_string()),
+                    data: "{\"a\":1}".to_string(),
+                },
+                SseEvent {
+                    id: None,
+                    data: "\"hello\"".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ingestion_mode_from_str() {
+        assert_eq!(
+            "stream".parse::<IngestionMode>().unwrap(),
+            IngestionMode::Stream
+        );
+        assert_eq!(
+            " POLL ".parse::<IngestionMode>().unwrap(),
+            IngestionMode::Poll
+        );
+        assert!("push".parse::<IngestionMode>().is_err());
+    }
+}
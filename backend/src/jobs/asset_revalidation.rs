@@ -139,7 +139,7 @@ impl AssetRevalidationJob {
         );
 
         let verifier = AssetVerifier::new(self.pool.clone())?;
-        verifier.verify_asset(asset_code, asset_issuer).await?;
+        verifier.revalidate(asset_code, asset_issuer).await?;
 
         info!(
             "Successfully revalidated asset: {}-{}",
@@ -159,6 +159,7 @@ impl AssetRevalidationJob {
                 COUNT(*) as total_assets,
                 SUM(CASE WHEN last_verified_at IS NULL OR last_verified_at < ? THEN 1 ELSE 0 END) as needs_revalidation,
                 SUM(CASE WHEN verification_status = 'verified' THEN 1 ELSE 0 END) as verified_count,
+                SUM(CASE WHEN verification_status = 'provisional' THEN 1 ELSE 0 END) as provisional_count,
                 SUM(CASE WHEN verification_status = 'unverified' THEN 1 ELSE 0 END) as unverified_count,
                 SUM(CASE WHEN verification_status = 'suspicious' THEN 1 ELSE 0 END) as suspicious_count
             FROM verified_assets
@@ -172,6 +173,7 @@ impl AssetRevalidationJob {
             total_assets: row.total_assets.unwrap_or(0) as i64,
             needs_revalidation: row.needs_revalidation.unwrap_or(0) as i64,
             verified_count: row.verified_count.unwrap_or(0) as i64,
+            provisional_count: row.provisional_count.unwrap_or(0) as i64,
             unverified_count: row.unverified_count.unwrap_or(0) as i64,
             suspicious_count: row.suspicious_count.unwrap_or(0) as i64,
         })
@@ -184,6 +186,7 @@ pub struct RevalidationStats {
     pub total_assets: i64,
     pub needs_revalidation: i64,
     pub verified_count: i64,
+    pub provisional_count: i64,
     pub unverified_count: i64,
     pub suspicious_count: i64,
 }
@@ -1,5 +1,7 @@
 pub mod asset_revalidation;
+pub mod retention;
 pub mod scheduler;
 
 pub use asset_revalidation::{AssetRevalidationJob, RevalidationConfig, RevalidationStats};
+pub use retention::{RetentionConfig, RetentionJob, RetentionStats};
 pub use scheduler::{JobConfig, JobScheduler};
@@ -0,0 +1,140 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::SqlitePool;
+use tracing::info;
+
+/// Configuration for the data retention purge job
+#[derive(Debug, Clone)]
+pub struct RetentionConfig {
+    /// Whether old rows are purged at all
+    pub enabled: bool,
+    /// Days of `anchor_metrics_history` to keep
+    pub metrics_retention_days: i64,
+    /// Days of `payments` to keep
+    pub payments_retention_days: i64,
+    /// Rows deleted per statement, bounding how long each write lock is held
+    pub batch_size: i64,
+}
+
+impl Default for RetentionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            metrics_retention_days: 90,
+            payments_retention_days: 90,
+            batch_size: 1000,
+        }
+    }
+}
+
+impl RetentionConfig {
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let var = |name: &str| std::env::var(name).ok();
+        Self {
+            enabled: var("RETENTION_ENABLED")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(defaults.enabled),
+            metrics_retention_days: var("METRICS_RETENTION_DAYS")
+                .and_then(|s| s.parse().ok())
+                .filter(|days| *days > 0)
+                .unwrap_or(defaults.metrics_retention_days),
+            payments_retention_days: var("PAYMENTS_RETENTION_DAYS")
+                .and_then(|s| s.parse().ok())
+                .filter(|days| *days > 0)
+                .unwrap_or(defaults.payments_retention_days),
+            batch_size: var("RETENTION_BATCH_SIZE")
+                .and_then(|s| s.parse().ok())
+                .filter(|size| *size > 0)
+                .unwrap_or(defaults.batch_size),
+        }
+    }
+}
+
+/// Rows removed by one retention run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RetentionStats {
+    pub metrics_history_removed: u64,
+    pub payments_removed: u64,
+}
+
+/// Deletes metrics history and payments older than their retention windows
+pub struct RetentionJob {
+    pool: SqlitePool,
+    config: RetentionConfig,
+}
+
+impl RetentionJob {
+    pub fn new(pool: SqlitePool, config: RetentionConfig) -> Self {
+        Self { pool, config }
+    }
+
+    pub fn config(&self) -> &RetentionConfig {
+        &self.config
+    }
+
+    /// Purge rows older than the configured windows
+    pub async fn run(&self) -> Result<RetentionStats> {
+        let now = Utc::now();
+        let stats = RetentionStats {
+            metrics_history_removed: self
+                .purge(
+                    "anchor_metrics_history",
+                    "timestamp",
+                    now - Duration::days(self.config.metrics_retention_days),
+                )
+                .await?,
+            payments_removed: self
+                .purge(
+                    "payments",
+                    "created_at",
+                    now - Duration::days(self.config.payments_retention_days),
+                )
+                .await?,
+        };
+
+        info!(
+            "Retention purge removed {} metrics history rows and {} payments",
+            stats.metrics_history_removed, stats.payments_removed
+        );
+        Ok(stats)
+    }
+
+    /// Delete rows of `table` whose `column` is before `cutoff`, one batch per
+    /// statement so other writers can get the lock in between
+    async fn purge(&self, table: &str, column: &str, cutoff: DateTime<Utc>) -> Result<u64> {
+        let sql = format!(
+            "DELETE FROM {table} WHERE rowid IN \
+             (SELECT rowid FROM {table} WHERE {column} < $1 LIMIT $2)"
+        );
+
+        let mut removed = 0;
+        loop {
+            let deleted = sqlx::query(&sql)
+                .bind(cutoff)
+                .bind(self.config.batch_size)
+                .execute(&self.pool)
+                .await?
+                .rows_affected();
+            removed += deleted;
+            if deleted < self.config.batch_size as u64 {
+                return Ok(removed);
+            }
+            tokio::task::yield_now().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config() {
+        let config = RetentionConfig::default();
+        assert!(config.enabled);
+        assert_eq!(config.metrics_retention_days, 90);
+        assert_eq!(config.payments_retention_days, 90);
+        assert_eq!(config.batch_size, 1000);
+    }
+}
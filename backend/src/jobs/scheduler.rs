@@ -7,8 +7,10 @@ use tracing::{error, info};
 use crate::cache::CacheManager;
 use crate::database::Database;
 use crate::ingestion::DataIngestionService;
+use crate::jobs::retention::{RetentionConfig, RetentionJob};
 use crate::rpc::StellarRpcClient;
 use crate::services::price_feed::PriceFeedClient;
+use crate::services::webhook_dispatcher::WebhookDispatcher;
 
 #[derive(Clone)]
 pub struct JobConfig {
@@ -141,6 +143,30 @@ impl JobScheduler {
             })
         });
 
+        // Webhook retry job: redelivers failed webhooks with backoff
+        let config = JobConfig::from_env("webhook-retry", 30);
+        let dispatcher = Arc::new(WebhookDispatcher::new(db.pool().clone()));
+        scheduler.add_job(config, move || {
+            let dispatcher = Arc::clone(&dispatcher);
+            Box::pin(async move {
+                dispatcher.retry_due_deliveries().await?;
+                Ok(())
+            })
+        });
+
+        // Data retention purge job
+        let retention = RetentionConfig::from_env();
+        let mut config = JobConfig::from_env("retention", 86400);
+        config.enabled &= retention.enabled;
+        let job = Arc::new(RetentionJob::new(db.pool().clone(), retention));
+        scheduler.add_job(config, move || {
+            let job = Arc::clone(&job);
+            Box::pin(async move {
+                job.run().await?;
+                Ok(())
+            })
+        });
+
         scheduler
     }
 
@@ -9,10 +9,14 @@ pub mod monitor;
 
 pub mod auth;
 pub mod auth_middleware;
+pub mod body_limit_middleware;
 pub mod broadcast;
 pub mod cache;
+pub mod cache_codec;
 pub mod cache_invalidation;
+pub mod cache_memory;
 pub mod cache_middleware;
+pub mod cors;
 pub mod crypto;
 pub mod database;
 pub mod db;
@@ -21,11 +25,13 @@ pub mod email;
 pub mod env_config;
 pub mod error;
 // pub mod gdpr;
+pub mod graphql;
 pub mod handlers;
 pub mod http_cache;
 pub mod ingestion;
 pub mod ip_whitelist_middleware;
 pub mod jobs;
+pub mod listen;
 pub mod logging;
 pub mod ml;
 pub mod ml_handlers;
@@ -39,11 +45,13 @@ pub mod openapi;
 pub mod rate_limit;
 pub mod replay;
 pub mod request_id;
+pub mod self_check;
 pub mod services;
 pub mod shutdown;
 pub mod snapshot;
 pub mod snapshot_handlers;
 pub mod state;
+pub mod supervisor;
 pub mod vault;
 pub mod webhooks;
 pub mod websocket;
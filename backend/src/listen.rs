@@ -0,0 +1,473 @@
+//! Listen address selection and serving over TCP, TLS or a Unix domain socket
+//!
+//! `BIND_ADDR` selects the transport: `tcp://host:port` or `unix:///path/to.sock`.
+//! Without it the server binds `SERVER_HOST:SERVER_PORT` over TCP as before.
+//! Setting `TLS_CERT_PATH` and `TLS_KEY_PATH` terminates TLS on the TCP
+//! listener; certificates are reloaded when the files change.
+
+use std::fmt;
+use std::future::Future;
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::os::unix::fs::FileTypeExt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use anyhow::{bail, Context, Result};
+use axum::extract::ConnectInfo;
+use axum::Router;
+use axum_server::tls_rustls::RustlsConfig;
+use hyper::body::Incoming;
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use hyper_util::server::conn::auto::Builder;
+use hyper_util::server::graceful::GracefulShutdown;
+use hyper_util::service::TowerToHyperService;
+use tokio::net::{UnixListener, UnixStream};
+use tower::ServiceExt;
+
+/// How long in-flight connections get to finish after shutdown
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// First and longest waits before retrying a failed Unix socket accept
+/// (e.g. EMFILE), so a persistent error doesn't spin the accept loop
+const ACCEPT_BACKOFF_MIN: Duration = Duration::from_millis(10);
+const ACCEPT_BACKOFF_MAX: Duration = Duration::from_secs(1);
+
+/// Where the HTTP server listens
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BindAddr {
+    Tcp(String),
+    Unix(PathBuf),
+}
+
+impl BindAddr {
+    /// Parse `tcp://host:port` or `unix:///path`
+    pub fn parse(value: &str) -> Result<Self> {
+        if let Some(addr) = value.strip_prefix("tcp://") {
+            if addr.is_empty() {
+                bail!("BIND_ADDR tcp:// requires host:port");
+            }
+            Ok(Self::Tcp(addr.to_string()))
+        } else if let Some(path) = value.strip_prefix("unix://") {
+            if path.is_empty() {
+                bail!("BIND_ADDR unix:// requires a socket path");
+            }
+            Ok(Self::Unix(PathBuf::from(path)))
+        } else {
+            bail!(
+                "Unsupported BIND_ADDR '{}': expected tcp://host:port or unix:///path",
+                value
+            )
+        }
+    }
+
+    /// Load from BIND_ADDR, falling back to SERVER_HOST/SERVER_PORT over TCP
+    pub fn from_env() -> Result<Self> {
+        match std::env::var("BIND_ADDR") {
+            Ok(value) if !value.trim().is_empty() => Self::parse(value.trim()),
+            _ => {
+                let host = std::env::var("SERVER_HOST").unwrap_or_else(|_| "127.0.0.1".to_string());
+                let port = std::env::var("SERVER_PORT").unwrap_or_else(|_| "8080".to_string());
+                Ok(Self::Tcp(format!("{}:{}", host, port)))
+            }
+        }
+    }
+}
+
+impl fmt::Display for BindAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tcp(addr) => write!(f, "tcp://{}", addr),
+            Self::Unix(path) => write!(f, "unix://{}", path.display()),
+        }
+    }
+}
+
+/// Built-in TLS termination settings
+#[derive(Debug, Clone)]
+pub struct TlsConfig {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
+    /// How often the certificate files are checked for changes
+    pub reload_interval: Duration,
+}
+
+impl TlsConfig {
+    /// Load from environment; `None` (plain HTTP) unless both paths are set:
+    /// - TLS_CERT_PATH: PEM certificate chain
+    /// - TLS_KEY_PATH: PEM private key
+    /// - TLS_RELOAD_INTERVAL_SECS (default: 60)
+    pub fn from_env() -> Result<Option<Self>> {
+        let cert = std::env::var("TLS_CERT_PATH")
+            .ok()
+            .filter(|s| !s.is_empty());
+        let key = std::env::var("TLS_KEY_PATH").ok().filter(|s| !s.is_empty());
+        let (cert_path, key_path) = match (cert, key) {
+            (Some(cert), Some(key)) => (PathBuf::from(cert), PathBuf::from(key)),
+            (None, None) => return Ok(None),
+            _ => bail!("TLS_CERT_PATH and TLS_KEY_PATH must be set together"),
+        };
+        let reload_interval = std::env::var("TLS_RELOAD_INTERVAL_SECS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(Duration::from_secs(60));
+
+        Ok(Some(Self {
+            cert_path,
+            key_path,
+            reload_interval,
+        }))
+    }
+
+    fn modified_times(&self) -> Option<(SystemTime, SystemTime)> {
+        let modified = |path: &Path| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+        Some((modified(&self.cert_path)?, modified(&self.key_path)?))
+    }
+}
+
+/// A bound listener, ready to serve
+pub enum Listener {
+    Tcp(tokio::net::TcpListener),
+    Tls(std::net::TcpListener, RustlsConfig, TlsConfig),
+    Unix(UnixListener, PathBuf),
+}
+
+impl Listener {
+    /// Local TCP address, if listening on TCP
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        match self {
+            Self::Tcp(listener) => listener.local_addr().ok(),
+            Self::Tls(listener, _, _) => listener.local_addr().ok(),
+            Self::Unix(_, _) => None,
+        }
+    }
+}
+
+/// Bind the configured address, terminating TLS when `tls` is set
+pub async fn bind(bind: &BindAddr, tls: Option<&TlsConfig>) -> Result<Listener> {
+    match (bind, tls) {
+        (BindAddr::Tcp(addr), None) => {
+            let listener = tokio::net::TcpListener::bind(addr)
+                .await
+                .with_context(|| format!("Failed to bind {}", addr))?;
+            Ok(Listener::Tcp(listener))
+        }
+        (BindAddr::Tcp(addr), Some(tls)) => {
+            // reqwest and axum-server pull in different rustls backends, so
+            // pick one explicitly; an error means one is already installed
+            let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+
+            let rustls_config = RustlsConfig::from_pem_file(&tls.cert_path, &tls.key_path)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to load TLS certificate {} / key {}",
+                        tls.cert_path.display(),
+                        tls.key_path.display()
+                    )
+                })?;
+            let listener = std::net::TcpListener::bind(addr)
+                .with_context(|| format!("Failed to bind {}", addr))?;
+            listener.set_nonblocking(true)?;
+            Ok(Listener::Tls(listener, rustls_config, tls.clone()))
+        }
+        (BindAddr::Unix(_), Some(_)) => {
+            bail!("TLS termination is not supported on Unix sockets; unset TLS_CERT_PATH/TLS_KEY_PATH")
+        }
+        (BindAddr::Unix(path), None) => Ok(Listener::Unix(bind_unix(path)?, path.clone())),
+    }
+}
+
+/// Serve `app` on `listener` until `shutdown` resolves
+pub async fn serve<F>(app: Router, listener: Listener, shutdown: F) -> Result<()>
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    match listener {
+        Listener::Tcp(listener) => {
+            axum::serve(
+                listener,
+                app.into_make_service_with_connect_info::<SocketAddr>(),
+            )
+            .with_graceful_shutdown(shutdown)
+            .await?;
+        }
+        Listener::Tls(listener, rustls_config, tls) => {
+            let handle = axum_server::Handle::new();
+            let shutdown_handle = handle.clone();
+            tokio::spawn(async move {
+                shutdown.await;
+                shutdown_handle.graceful_shutdown(Some(DRAIN_TIMEOUT));
+            });
+            let reloader = tokio::spawn(reload_on_change(rustls_config.clone(), tls));
+
+            let result = axum_server::from_tcp_rustls(listener, rustls_config)
+                .handle(handle)
+                .serve(app.into_make_service_with_connect_info::<SocketAddr>())
+                .await;
+            reloader.abort();
+            result?;
+        }
+        Listener::Unix(listener, path) => {
+            serve_unix(listener, app, shutdown).await;
+            let _ = std::fs::remove_file(path);
+        }
+    }
+    Ok(())
+}
+
+/// Reload the certificate and key whenever either file's mtime changes.
+///
+/// A failed reload (e.g. the key was written before the matching certificate)
+/// keeps serving the previous certificate and is retried on the next change.
+async fn reload_on_change(rustls_config: RustlsConfig, tls: TlsConfig) {
+    let mut last_modified = tls.modified_times();
+    let mut interval = tokio::time::interval(tls.reload_interval);
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+        let modified = tls.modified_times();
+        if modified.is_none() || modified == last_modified {
+            continue;
+        }
+
+        match rustls_config
+            .reload_from_pem_file(&tls.cert_path, &tls.key_path)
+            .await
+        {
+            Ok(()) => {
+                tracing::info!("Reloaded TLS certificate from {}", tls.cert_path.display());
+                last_modified = modified;
+            }
+            Err(e) => tracing::warn!("Failed to reload TLS certificate: {}", e),
+        }
+    }
+}
+
+/// Bind a Unix socket, replacing a stale socket file left by a previous run.
+///
+/// Anything at the path that is not a socket is left alone and fails the bind.
+fn bind_unix(path: &Path) -> Result<UnixListener> {
+    if let Ok(metadata) = std::fs::symlink_metadata(path) {
+        if !metadata.file_type().is_socket() {
+            bail!(
+                "{} exists and is not a socket; refusing to replace it",
+                path.display()
+            );
+        }
+        std::fs::remove_file(path)
+            .with_context(|| format!("Failed to remove stale socket {}", path.display()))?;
+    }
+    UnixListener::bind(path).with_context(|| format!("Failed to bind {}", path.display()))
+}
+
+/// Placeholder peer address for Unix socket clients.
+///
+/// Middleware such as the rate limiter reads `ConnectInfo<SocketAddr>`. Unix
+/// peers have no IP, so the peer's uid is packed into the `0.0.0.0/8` range:
+/// never loopback (so IP whitelists do not treat it as local) and distinct per
+/// local user for rate limiting.
+fn unix_peer_addr(stream: &UnixStream) -> SocketAddr {
+    let uid = stream.peer_cred().map(|cred| cred.uid()).unwrap_or(0);
+    SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::from(uid & 0x00FF_FFFF), 0))
+}
+
+async fn serve_unix<F>(listener: UnixListener, app: Router, shutdown: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    let graceful = GracefulShutdown::new();
+    let mut accept_backoff = ACCEPT_BACKOFF_MIN;
+    tokio::pin!(shutdown);
+
+    loop {
+        let accepted = tokio::select! {
+            accepted = listener.accept() => accepted,
+            _ = &mut shutdown => break,
+        };
+        let stream = match accepted {
+            Ok((stream, _)) => {
+                accept_backoff = ACCEPT_BACKOFF_MIN;
+                stream
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to accept Unix socket connection: {} (retrying in {:?})",
+                    e,
+                    accept_backoff
+                );
+                tokio::select! {
+                    _ = tokio::time::sleep(accept_backoff) => {}
+                    _ = &mut shutdown => break,
+                }
+                accept_backoff = (accept_backoff * 2).min(ACCEPT_BACKOFF_MAX);
+                continue;
+            }
+        };
+
+        let peer = unix_peer_addr(&stream);
+        let service = app
+            .clone()
+            .map_request(move |req: axum::http::Request<Incoming>| {
+                let mut req = req.map(axum::body::Body::new);
+                req.extensions_mut().insert(ConnectInfo(peer));
+                req
+            });
+        let connection = Builder::new(TokioExecutor::new())
+            .serve_connection_with_upgrades(TokioIo::new(stream), TowerToHyperService::new(service))
+            .into_owned();
+        let connection = graceful.watch(connection);
+
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                tracing::debug!("Unix socket connection error: {}", e);
+            }
+        });
+    }
+
+    if tokio::time::timeout(DRAIN_TIMEOUT, graceful.shutdown())
+        .await
+        .is_err()
+    {
+        tracing::warn!("Timed out waiting for Unix socket connections to close");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::routing::get;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[test]
+    fn test_parse_bind_addr() {
+        assert_eq!(
+            BindAddr::parse("tcp://0.0.0.0:8080").unwrap(),
+            BindAddr::Tcp("0.0.0.0:8080".to_string())
+        );
+        assert_eq!(
+            BindAddr::parse("unix:///run/stellar-insights.sock").unwrap(),
+            BindAddr::Unix(PathBuf::from("/run/stellar-insights.sock"))
+        );
+        assert!(BindAddr::parse("unix://").is_err());
+        assert!(BindAddr::parse("http://localhost:8080").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_serves_request_over_unix_socket() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("server.sock");
+        let listener = bind(&BindAddr::Unix(path.clone()), None).await.unwrap();
+
+        let app = Router::new().route(
+            "/peer",
+            get(|ConnectInfo(addr): ConnectInfo<SocketAddr>| async move { addr.ip().to_string() }),
+        );
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+        let server = tokio::spawn(async move {
+            serve(app, listener, async {
+                let _ = shutdown_rx.await;
+            })
+            .await
+        });
+
+        let mut stream = UnixStream::connect(&path).await.unwrap();
+        stream
+            .write_all(b"GET /peer HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"), "{}", response);
+        let peer_ip: Ipv4Addr = response.rsplit("\r\n\r\n").next().unwrap().parse().unwrap();
+        assert!(!peer_ip.is_loopback());
+        assert_eq!(peer_ip.octets()[0], 0);
+
+        shutdown_tx.send(()).unwrap();
+        server.await.unwrap().unwrap();
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_bind_unix_refuses_to_replace_regular_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("server.sock");
+        std::fs::write(&path, "not a socket").unwrap();
+
+        assert!(bind(&BindAddr::Unix(path.clone()), None).await.is_err());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "not a socket");
+
+        // A socket left behind by a previous run is replaced
+        std::fs::remove_file(&path).unwrap();
+        drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+        assert!(bind(&BindAddr::Unix(path.clone()), None).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_serves_https_when_tls_configured() {
+        let dir = tempfile::tempdir().unwrap();
+        let certified = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+        let tls = TlsConfig {
+            cert_path: dir.path().join("cert.pem"),
+            key_path: dir.path().join("key.pem"),
+            reload_interval: Duration::from_secs(60),
+        };
+        std::fs::write(&tls.cert_path, certified.cert.pem()).unwrap();
+        std::fs::write(&tls.key_path, certified.key_pair.serialize_pem()).unwrap();
+
+        let listener = bind(&BindAddr::Tcp("127.0.0.1:0".to_string()), Some(&tls))
+            .await
+            .unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = Router::new().route("/ping", get(|| async { "pong" }));
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+        let server = tokio::spawn(async move {
+            serve(app, listener, async {
+                let _ = shutdown_rx.await;
+            })
+            .await
+        });
+
+        let mut roots = rustls::RootCertStore::empty();
+        roots.add(certified.cert.der().clone()).unwrap();
+        let client_config = rustls::ClientConfig::builder()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        let connector = tokio_rustls::TlsConnector::from(std::sync::Arc::new(client_config));
+        let tcp = tokio::net::TcpStream::connect(addr).await.unwrap();
+        let server_name = rustls::pki_types::ServerName::try_from("localhost").unwrap();
+        let mut stream = connector.connect(server_name, tcp).await.unwrap();
+
+        stream
+            .write_all(b"GET /ping HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = Vec::new();
+        // The server may close without close_notify; the response is already read by then
+        let _ = stream.read_to_end(&mut response).await;
+        let response = String::from_utf8_lossy(&response);
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"), "{}", response);
+        assert!(response.ends_with("pong"));
+
+        shutdown_tx.send(()).unwrap();
+        server.await.unwrap().unwrap();
+    }
+
+    #[test]
+    fn test_tls_config_requires_both_paths() {
+        std::env::set_var("TLS_CERT_PATH", "/etc/tls/cert.pem");
+        std::env::remove_var("TLS_KEY_PATH");
+        assert!(TlsConfig::from_env().is_err());
+
+        std::env::set_var("TLS_KEY_PATH", "/etc/tls/key.pem");
+        let tls = TlsConfig::from_env().unwrap().unwrap();
+        assert_eq!(tls.key_path, PathBuf::from("/etc/tls/key.pem"));
+
+        std::env::remove_var("TLS_CERT_PATH");
+        std::env::remove_var("TLS_KEY_PATH");
+        assert!(TlsConfig::from_env().unwrap().is_none());
+    }
+}
@@ -1,10 +1,9 @@
 use anyhow::{Context, Result};
-use async_graphql::http::{playground_source, GraphiQLSource};
+use async_graphql::http::playground_source;
 use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
 use axum::extract::State;
 use axum::response::{Html, IntoResponse};
 use axum::{
-    http::Method,
     routing::{get, post, put},
     Router,
 };
@@ -12,22 +11,29 @@ use dotenvy::dotenv;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::task::JoinHandle;
-use tower_http::compression::{predicate::SizeAbove, CompressionLayer};
-use tower_http::cors::{Any, CorsLayer};
 use tower_http::trace::TraceLayer;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
+use stellar_insights_backend::admin_audit_log::admin_audit_middleware;
 use stellar_insights_backend::alerts::AlertManager;
+use stellar_insights_backend::analytics::ReliabilitySmoothing;
 use stellar_insights_backend::api::account_merges;
+use stellar_insights_backend::api::admin_cache;
+use stellar_insights_backend::api::admin_config;
 use stellar_insights_backend::api::anchors_cached::get_anchors;
 use stellar_insights_backend::api::api_analytics;
 use stellar_insights_backend::api::api_keys;
 use stellar_insights_backend::api::asset_verification;
 use stellar_insights_backend::api::cache_stats;
-use stellar_insights_backend::api::corridors_cached::{get_corridor_detail, list_corridors};
+use stellar_insights_backend::api::corridors_cached::{
+    compare_corridors, get_corridor_detail, list_corridors, CorridorPaymentFetchLimit,
+    CorridorVolumeFloor, HealthScoreWeights,
+};
 use stellar_insights_backend::api::cost_calculator;
 use stellar_insights_backend::api::fee_bump;
+use stellar_insights_backend::api::health;
+use stellar_insights_backend::api::ledger_replay::{self, LedgerReplayState};
 use stellar_insights_backend::api::liquidity_pools;
 use stellar_insights_backend::api::metrics_cached;
 use stellar_insights_backend::api::oauth;
@@ -36,18 +42,24 @@ use stellar_insights_backend::api::webhooks;
 use stellar_insights_backend::auth::AuthService;
 use stellar_insights_backend::auth_middleware::auth_middleware;
 use stellar_insights_backend::cache::{CacheConfig, CacheManager};
+use stellar_insights_backend::cache_codec::CacheCodec;
 use stellar_insights_backend::cache_invalidation::CacheInvalidationService;
+use stellar_insights_backend::cors::CorsOrigins;
 use stellar_insights_backend::database::Database;
 use stellar_insights_backend::elk_health;
-// use stellar_insights_backend::graphql::{build_schema, AppSchema};
+use stellar_insights_backend::graphql::{build_schema, AppSchema};
 // use stellar_insights_backend::gdpr::{GdprService, handlers as gdpr_handlers};
+use stellar_insights_backend::db::migrations::{run_migrations, MigrationRetryConfig};
 use stellar_insights_backend::handlers::*;
+use stellar_insights_backend::http_cache;
 use stellar_insights_backend::ingestion::ledger::LedgerIngestionService;
+use stellar_insights_backend::ingestion::stream::{reconnect_delay_from_env, IngestionMode};
 use stellar_insights_backend::ingestion::DataIngestionService;
 use stellar_insights_backend::ip_whitelist_middleware::{
     ip_whitelist_middleware, IpWhitelistConfig,
 };
 use stellar_insights_backend::jobs::JobScheduler;
+use stellar_insights_backend::listen::{self, BindAddr, TlsConfig};
 use stellar_insights_backend::monitor::CorridorMonitor;
 use stellar_insights_backend::network::NetworkConfig;
 use stellar_insights_backend::observability::{metrics as obs_metrics, tracing as obs_tracing};
@@ -58,20 +70,31 @@ use stellar_insights_backend::rate_limit::{
 use stellar_insights_backend::request_id::request_id_middleware;
 use stellar_insights_backend::rpc::StellarRpcClient;
 use stellar_insights_backend::rpc_handlers;
+use stellar_insights_backend::self_check::{
+    self, run_startup_self_check, CacheProbe, DatabaseProbe, DependencyProbe, HorizonProbe,
+    RateLimitRedisProbe, RpcProbe, SelfCheckMode,
+};
 use stellar_insights_backend::services::account_merge_detector::AccountMergeDetector;
+use stellar_insights_backend::services::aggregation::{AggregationConfig, AggregationService};
+use stellar_insights_backend::services::asset_verifier::AssetVerifier;
 use stellar_insights_backend::services::fee_bump_tracker::FeeBumpTrackerService;
+use stellar_insights_backend::services::health_alerts::HealthAlertEngine;
 use stellar_insights_backend::services::liquidity_pool_analyzer::LiquidityPoolAnalyzer;
 use stellar_insights_backend::services::price_feed::{
-    default_asset_mapping, PriceFeedClient, PriceFeedConfig,
+    default_asset_mapping, PriceFeedClient, PriceFeedConfig, TradeSource,
 };
 use stellar_insights_backend::services::realtime_broadcaster::RealtimeBroadcaster;
+use stellar_insights_backend::services::snapshot::SnapshotService;
 use stellar_insights_backend::services::trustline_analyzer::TrustlineAnalyzer;
 use stellar_insights_backend::services::webhook_dispatcher::WebhookDispatcher;
 use stellar_insights_backend::shutdown::{
     flush_cache, log_shutdown_summary, shutdown_background_tasks, shutdown_database,
     shutdown_websockets, wait_for_signal, ShutdownConfig, ShutdownCoordinator,
 };
+use stellar_insights_backend::snapshot::SnapshotSigner;
+use stellar_insights_backend::snapshot_handlers::{self, SnapshotAppState};
 use stellar_insights_backend::state::AppState;
+use stellar_insights_backend::supervisor::{SupervisorConfig, TaskSupervisor};
 use stellar_insights_backend::telegram;
 use stellar_insights_backend::vault;
 use stellar_insights_backend::websocket::WsState;
@@ -84,7 +107,25 @@ async fn main() -> Result<()> {
     // Load environment variables
     dotenv().ok();
 
-    // Initialize tracing + optional OpenTelemetry exporter
+    // APM metrics; the OTLP pipeline stays off since tracing is set up below
+    #[cfg(feature = "apm")]
+    let apm = Arc::new(stellar_insights_apm::ApmManager::new(
+        stellar_insights_apm::ApmConfig {
+            enabled: false,
+            ..stellar_insights_apm::ApmConfig::from_env()?
+        },
+    )?);
+
+    // Initialize tracing + optional OpenTelemetry exporter. With APM, sqlx's
+    // slow statement warnings are also counted in slow_query_total.
+    #[cfg(feature = "apm")]
+    obs_tracing::init_tracing_with_layer(
+        "stellar-insights-backend",
+        Some(Box::new(stellar_insights_apm::SlowQueryLayer::new(
+            Arc::clone(&apm),
+        ))),
+    )?;
+    #[cfg(not(feature = "apm"))]
     obs_tracing::init_tracing("stellar-insights-backend")?;
     obs_metrics::init_metrics();
 
@@ -100,13 +141,20 @@ async fn main() -> Result<()> {
     // Initialize shutdown coordinator
     let shutdown_config = ShutdownConfig::from_env();
     tracing::info!(
-        "Shutdown configuration: graceful_timeout={:?}, background_timeout={:?}, db_timeout={:?}",
+        "Shutdown configuration: graceful_timeout={:?}, background_timeout={:?}, db_timeout={:?}, drain_delay={:?}",
         shutdown_config.graceful_timeout,
         shutdown_config.background_task_timeout,
-        shutdown_config.db_close_timeout
+        shutdown_config.db_close_timeout,
+        shutdown_config.drain_delay
     );
     let shutdown_coordinator = Arc::new(ShutdownCoordinator::new(shutdown_config.clone()));
 
+    // Supervisor restarts background tasks that panic or exit unexpectedly
+    let supervisor = Arc::new(TaskSupervisor::new(
+        SupervisorConfig::from_env(),
+        Arc::clone(&shutdown_coordinator),
+    ));
+
     // Database connection
     let database_url = std::env::var("DATABASE_URL")
         .unwrap_or_else(|_| "sqlite:./stellar_insights.db".to_string());
@@ -133,20 +181,36 @@ async fn main() -> Result<()> {
     let pool_config = stellar_insights_backend::database::PoolConfig::from_env();
     tracing::info!(
         "Database pool configuration: max_connections={}, min_connections={}, \
-         connect_timeout={}s, idle_timeout={}s, max_lifetime={}s",
+         connect_timeout={}s, idle_timeout={}s, max_lifetime={}s, busy_timeout={}ms, \
+         wal={}, foreign_keys={}",
         pool_config.max_connections,
         pool_config.min_connections,
         pool_config.connect_timeout_seconds,
         pool_config.idle_timeout_seconds,
-        pool_config.max_lifetime_seconds
+        pool_config.max_lifetime_seconds,
+        pool_config.busy_timeout_ms,
+        pool_config.wal_mode,
+        pool_config.foreign_keys
     );
 
+    let health_weights =
+        HealthScoreWeights::from_env().context("Invalid corridor health score weights")?;
+    let corridor_volume_floor =
+        CorridorVolumeFloor::from_env().context("Invalid corridor volume floor")?;
+    let corridor_payment_fetch_limit =
+        CorridorPaymentFetchLimit::from_env().context("Invalid corridor detail payment limit")?;
+
     let pool = pool_config.create_pool(&database_url).await?;
 
     tracing::info!("Running database migrations...");
-    sqlx::migrate!("./migrations").run(&pool).await?;
+    let migration_config = MigrationRetryConfig::from_env();
+    run_migrations(&pool, &migration_config)
+        .await
+        .context("Database migrations failed")?;
 
-    let db = Arc::new(Database::new(pool.clone()));
+    let db = Arc::new(
+        Database::new(pool.clone()).with_reliability_smoothing(ReliabilitySmoothing::from_env()),
+    );
 
     // Initialize Stellar RPC Client
     let mock_mode = std::env::var("RPC_MOCK_MODE")
@@ -180,10 +244,15 @@ async fn main() -> Result<()> {
     tracing::info!("WebSocket state initialized");
 
     // Initialize Data Ingestion Service
-    let ingestion_service = Arc::new(DataIngestionService::new(
-        Arc::clone(&rpc_client),
-        Arc::clone(&db),
-    ));
+    let mut ingestion_mode = IngestionMode::from_env();
+    if mock_mode && ingestion_mode == IngestionMode::Stream {
+        tracing::warn!("INGESTION_MODE=stream is unavailable in RPC mock mode, polling instead");
+        ingestion_mode = IngestionMode::Poll;
+    }
+    let ingestion_service = Arc::new(
+        DataIngestionService::new(Arc::clone(&rpc_client), Arc::clone(&db))
+            .with_mode(ingestion_mode),
+    );
 
     // Initialize Fee Bump Tracker Service
     let fee_bump_tracker = Arc::new(FeeBumpTrackerService::new(pool.clone()));
@@ -203,7 +272,10 @@ async fn main() -> Result<()> {
     // Initialize Price Feed Client
     let price_feed_config = PriceFeedConfig::from_env();
     let asset_mapping = default_asset_mapping();
-    let price_feed = Arc::new(PriceFeedClient::new(price_feed_config, asset_mapping));
+    let trade_source: Arc<dyn TradeSource> = rpc_client.clone();
+    let price_feed = Arc::new(
+        PriceFeedClient::new(price_feed_config, asset_mapping).with_trade_source(trade_source),
+    );
     tracing::info!("Price feed client initialized");
 
     // Initialize Trustline Analyzer
@@ -221,10 +293,27 @@ async fn main() -> Result<()> {
     ));
 
     // Initialize Redis cache
-    let cache_config = CacheConfig::default();
+    let cache_config = CacheConfig {
+        codec: CacheCodec::from_env(),
+        ..CacheConfig::default()
+    };
     let cache = Arc::new(CacheManager::new(cache_config).await?);
     tracing::info!("Cache manager initialized");
 
+    // Verify dependencies before starting background work
+    let self_check_probes: Vec<Arc<dyn DependencyProbe>> = vec![
+        Arc::new(RpcProbe(Arc::clone(&rpc_client))),
+        Arc::new(HorizonProbe(Arc::clone(&rpc_client))),
+        Arc::new(DatabaseProbe(pool.clone())),
+        Arc::new(CacheProbe(Arc::clone(&cache))),
+    ];
+    run_startup_self_check(
+        SelfCheckMode::from_env(),
+        &self_check_probes,
+        self_check::timeout_from_env(),
+    )
+    .await?;
+
     // Initialize cache invalidation service
     let cache_invalidation = Arc::new(CacheInvalidationService::new(Arc::clone(&cache)));
 
@@ -273,39 +362,60 @@ async fn main() -> Result<()> {
     // Metrics synchronization task
     let ingestion_clone = Arc::clone(&ingestion_service);
     let cache_invalidation_clone = Arc::clone(&cache_invalidation);
-    let shutdown_rx1 = shutdown_coordinator.subscribe();
-    let task = tokio::spawn(async move {
-        let mut interval = tokio::time::interval(std::time::Duration::from_secs(300)); // 5 minutes
-        let mut shutdown_rx = shutdown_rx1;
-        loop {
-            tokio::select! {
-                _ = interval.tick() => {
-                    if let Err(e) = ingestion_clone.sync_all_metrics().await {
-                        tracing::error!("Metrics synchronization failed: {}", e);
-                        obs_metrics::record_background_job("metrics_sync", "error");
-                    } else {
-                        obs_metrics::record_background_job("metrics_sync", "success");
-                        // Invalidate caches after successful sync
-                        if let Err(e) = cache_invalidation_clone.invalidate_anchors().await {
-                            tracing::warn!("Failed to invalidate anchor caches: {}", e);
-                        }
-                        if let Err(e) = cache_invalidation_clone.invalidate_corridors().await {
-                            tracing::warn!("Failed to invalidate corridor caches: {}", e);
-                        }
-                        if let Err(e) = cache_invalidation_clone.invalidate_metrics().await {
-                            tracing::warn!("Failed to invalidate metrics caches: {}", e);
+    let task = supervisor.spawn("metrics_sync", move |mut shutdown_rx| {
+        let ingestion_clone = Arc::clone(&ingestion_clone);
+        let cache_invalidation_clone = Arc::clone(&cache_invalidation_clone);
+        async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(300)); // 5 minutes
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        if let Err(e) = ingestion_clone.sync_all_metrics().await {
+                            tracing::error!("Metrics synchronization failed: {}", e);
+                            obs_metrics::record_background_job("metrics_sync", "error");
+                        } else {
+                            obs_metrics::record_background_job("metrics_sync", "success");
+                            // Invalidate caches after successful sync
+                            if let Err(e) = cache_invalidation_clone.invalidate_anchors().await {
+                                tracing::warn!("Failed to invalidate anchor caches: {}", e);
+                            }
+                            if let Err(e) = cache_invalidation_clone.invalidate_corridors().await {
+                                tracing::warn!("Failed to invalidate corridor caches: {}", e);
+                            }
+                            if let Err(e) = cache_invalidation_clone.invalidate_metrics().await {
+                                tracing::warn!("Failed to invalidate metrics caches: {}", e);
+                            }
                         }
                     }
-                }
-                _ = shutdown_rx.recv() => {
-                    tracing::info!("Metrics synchronization task shutting down");
-                    break;
+                    _ = shutdown_rx.recv() => {
+                        tracing::info!("Metrics synchronization task shutting down");
+                        break;
+                    }
                 }
             }
         }
     });
     background_tasks.push(task);
 
+    // Payment streaming task (INGESTION_MODE=stream)
+    if ingestion_service.mode() == IngestionMode::Stream {
+        let stream_clone = Arc::clone(&ingestion_service);
+        let reconnect_delay = reconnect_delay_from_env();
+        let task = supervisor.spawn("payment_stream", move |mut shutdown_rx| {
+            let stream_clone = Arc::clone(&stream_clone);
+            async move {
+                tracing::info!("Starting payment stream background task");
+                tokio::select! {
+                    _ = stream_clone.run_payment_stream(reconnect_delay) => {}
+                    _ = shutdown_rx.recv() => {
+                        tracing::info!("Payment stream task shutting down");
+                    }
+                }
+            }
+        });
+        background_tasks.push(task);
+    }
+
     // Initialize Auth Service with its own Redis connection
     let redis_url =
         std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
@@ -402,32 +512,33 @@ async fn main() -> Result<()> {
 
     // Ledger ingestion task
     let ledger_ingestion_clone = Arc::clone(&ledger_ingestion_service);
-    let shutdown_rx2 = shutdown_coordinator.subscribe();
-    let task = tokio::spawn(async move {
-        tracing::info!("Starting ledger ingestion background task");
-        let mut shutdown_rx = shutdown_rx2;
-        loop {
-            tokio::select! {
-                result = ledger_ingestion_clone.run_ingestion(5) => {
-                    match result {
-                        Ok(count) => {
-                            obs_metrics::record_background_job("ledger_ingestion", "success");
-                            if count == 0 {
-                                tokio::time::sleep(std::time::Duration::from_secs(5)).await;
-                            } else {
-                                tokio::task::yield_now().await;
+    let task = supervisor.spawn("ledger_ingestion", move |mut shutdown_rx| {
+        let ledger_ingestion_clone = Arc::clone(&ledger_ingestion_clone);
+        async move {
+            tracing::info!("Starting ledger ingestion background task");
+            loop {
+                tokio::select! {
+                    result = ledger_ingestion_clone.run_ingestion(5) => {
+                        match result {
+                            Ok(count) => {
+                                obs_metrics::record_background_job("ledger_ingestion", "success");
+                                if count == 0 {
+                                    tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+                                } else {
+                                    tokio::task::yield_now().await;
+                                }
+                            }
+                            Err(e) => {
+                                tracing::error!("Ledger ingestion failed: {}", e);
+                                obs_metrics::record_background_job("ledger_ingestion", "error");
+                                tokio::time::sleep(std::time::Duration::from_secs(10)).await;
                             }
-                        }
-                        Err(e) => {
-                            tracing::error!("Ledger ingestion failed: {}", e);
-                            obs_metrics::record_background_job("ledger_ingestion", "error");
-                            tokio::time::sleep(std::time::Duration::from_secs(10)).await;
                         }
                     }
-                }
-                _ = shutdown_rx.recv() => {
-                    tracing::info!("Ledger ingestion task shutting down");
-                    break;
+                    _ = shutdown_rx.recv() => {
+                        tracing::info!("Ledger ingestion task shutting down");
+                        break;
+                    }
                 }
             }
         }
@@ -436,31 +547,32 @@ async fn main() -> Result<()> {
 
     // Liquidity pool sync background task
     let lp_analyzer_clone = Arc::clone(&lp_analyzer);
-    let shutdown_rx3 = shutdown_coordinator.subscribe();
-    let task = tokio::spawn(async move {
-        tracing::info!("Starting liquidity pool sync background task");
-        let mut interval = tokio::time::interval(std::time::Duration::from_secs(300)); // 5 minutes
-        let mut shutdown_rx = shutdown_rx3;
-        loop {
-            tokio::select! {
-                _ = interval.tick() => {
-                    if let Err(e) = lp_analyzer_clone.sync_pools().await {
-                        tracing::error!("Liquidity pool sync failed: {}", e);
-                        obs_metrics::record_background_job("liquidity_pool_sync", "error");
-                    } else {
-                        obs_metrics::record_background_job("liquidity_pool_sync", "success");
+    let task = supervisor.spawn("liquidity_pool_sync", move |mut shutdown_rx| {
+        let lp_analyzer_clone = Arc::clone(&lp_analyzer_clone);
+        async move {
+            tracing::info!("Starting liquidity pool sync background task");
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(300)); // 5 minutes
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        if let Err(e) = lp_analyzer_clone.sync_pools().await {
+                            tracing::error!("Liquidity pool sync failed: {}", e);
+                            obs_metrics::record_background_job("liquidity_pool_sync", "error");
+                        } else {
+                            obs_metrics::record_background_job("liquidity_pool_sync", "success");
+                        }
+                        if let Err(e) = lp_analyzer_clone.take_snapshots().await {
+                            tracing::error!("Liquidity pool snapshot failed: {}", e);
+                            obs_metrics::record_background_job("liquidity_pool_snapshot", "error");
+                        } else {
+                            obs_metrics::record_background_job("liquidity_pool_snapshot", "success");
+                        }
                     }
-                    if let Err(e) = lp_analyzer_clone.take_snapshots().await {
-                        tracing::error!("Liquidity pool snapshot failed: {}", e);
-                        obs_metrics::record_background_job("liquidity_pool_snapshot", "error");
-                    } else {
-                        obs_metrics::record_background_job("liquidity_pool_snapshot", "success");
+                    _ = shutdown_rx.recv() => {
+                        tracing::info!("Liquidity pool sync task shutting down");
+                        break;
                     }
                 }
-                _ = shutdown_rx.recv() => {
-                    tracing::info!("Liquidity pool sync task shutting down");
-                    break;
-                }
             }
         }
     });
@@ -468,31 +580,32 @@ async fn main() -> Result<()> {
 
     // Trustline stats sync background task
     let trustline_analyzer_clone = Arc::clone(&trustline_analyzer);
-    let shutdown_rx4 = shutdown_coordinator.subscribe();
-    let task = tokio::spawn(async move {
-        tracing::info!("Starting trustline stats sync background task");
-        let mut interval = tokio::time::interval(std::time::Duration::from_secs(900)); // 15 minutes
-        let mut shutdown_rx = shutdown_rx4;
-        loop {
-            tokio::select! {
-                _ = interval.tick() => {
-                    if let Err(e) = trustline_analyzer_clone.sync_assets().await {
-                        tracing::error!("Trustline sync failed: {}", e);
-                        obs_metrics::record_background_job("trustline_sync", "error");
-                    } else {
-                        obs_metrics::record_background_job("trustline_sync", "success");
+    let task = supervisor.spawn("trustline_sync", move |mut shutdown_rx| {
+        let trustline_analyzer_clone = Arc::clone(&trustline_analyzer_clone);
+        async move {
+            tracing::info!("Starting trustline stats sync background task");
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(900)); // 15 minutes
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        if let Err(e) = trustline_analyzer_clone.sync_assets().await {
+                            tracing::error!("Trustline sync failed: {}", e);
+                            obs_metrics::record_background_job("trustline_sync", "error");
+                        } else {
+                            obs_metrics::record_background_job("trustline_sync", "success");
+                        }
+                        if let Err(e) = trustline_analyzer_clone.take_snapshots().await {
+                            tracing::error!("Trustline snapshot failed: {}", e);
+                            obs_metrics::record_background_job("trustline_snapshot", "error");
+                        } else {
+                            obs_metrics::record_background_job("trustline_snapshot", "success");
+                        }
                     }
-                    if let Err(e) = trustline_analyzer_clone.take_snapshots().await {
-                        tracing::error!("Trustline snapshot failed: {}", e);
-                        obs_metrics::record_background_job("trustline_snapshot", "error");
-                    } else {
-                        obs_metrics::record_background_job("trustline_snapshot", "success");
+                    _ = shutdown_rx.recv() => {
+                        tracing::info!("Trustline stats sync task shutting down");
+                        break;
                     }
                 }
-                _ = shutdown_rx.recv() => {
-                    tracing::info!("Trustline stats sync task shutting down");
-                    break;
-                }
             }
         }
     });
@@ -519,6 +632,13 @@ async fn main() -> Result<()> {
     let alert_manager = Arc::new(alert_manager_raw);
     tracing::info!("Alert manager initialized");
 
+    // Evaluate corridor health score alert rules after each metrics sync
+    ingestion_service.set_health_alerts(Arc::new(HealthAlertEngine::new(
+        Arc::clone(&db),
+        Arc::clone(&alert_manager),
+        health_weights,
+    )));
+
     // Initialize Corridor Monitor
     let corridor_monitor = Arc::new(stellar_insights_backend::monitor::CorridorMonitor::new(
         Arc::clone(&alert_manager),
@@ -593,6 +713,9 @@ async fn main() -> Result<()> {
             Arc::clone(&db),
             Arc::clone(&cache),
             Arc::clone(&rpc_client),
+            Arc::clone(&price_feed),
+            health_weights,
+            corridor_payment_fetch_limit,
             tg_subscriptions,
             &alert_manager,
         );
@@ -606,9 +729,22 @@ async fn main() -> Result<()> {
         tracing::info!("TELEGRAM_BOT_TOKEN not set, Telegram bot disabled");
     }
 
-    // Run initial sync (skip on network errors)
-    tracing::info!("Running initial metrics synchronization...");
-    let _ = ingestion_service.sync_all_metrics().await;
+    // Run initial sync in the background so /health/startup can report it
+    // (continue on network errors; the sync task retries)
+    let startup_status = health::StartupStatus::new();
+    let initial_sync = {
+        let ingestion_service = Arc::clone(&ingestion_service);
+        let startup_status = startup_status.clone();
+        tokio::spawn(async move {
+            tracing::info!("Running initial metrics synchronization...");
+            if let Err(e) = ingestion_service.sync_all_metrics().await {
+                tracing::warn!("Initial metrics synchronization failed: {}", e);
+            }
+            startup_status.mark_complete();
+            tracing::info!("Startup complete");
+        })
+    };
+    background_tasks.push(initial_sync);
 
     // Start background job scheduler
     tracing::info!("Starting background job scheduler...");
@@ -622,6 +758,26 @@ async fn main() -> Result<()> {
     .await;
     tracing::info!("Background job scheduler started");
 
+    // Start hourly corridor aggregation, pricing volumes in USD
+    let aggregation_service = Arc::new(
+        AggregationService::new(Arc::clone(&db), AggregationConfig::default())
+            .with_price_feed(Arc::clone(&price_feed)),
+    );
+    ingestion_service.set_aggregation(Arc::clone(&aggregation_service));
+    let shutdown_rx_aggregation = shutdown_coordinator.subscribe();
+    let task = tokio::spawn(async move {
+        let mut shutdown_rx = shutdown_rx_aggregation;
+        tokio::select! {
+            _ = aggregation_service.start_scheduler() => {
+                tracing::info!("Corridor aggregation task completed");
+            }
+            _ = shutdown_rx.recv() => {
+                tracing::info!("Corridor aggregation task shutting down");
+            }
+        }
+    });
+    background_tasks.push(task);
+
     // Initialize rate limiter with database support for API key validation
     let rate_limiter_result = RateLimiter::new_with_db(Some(pool.clone())).await;
     let rate_limiter = match rate_limiter_result {
@@ -801,65 +957,12 @@ async fn main() -> Result<()> {
     };
 
     // CORS configuration
-    // Read comma-separated allowed origins from env.
+    // Comma-separated origins from CORS_ALLOWED_ORIGINS (or ALLOWED_ORIGINS).
     // Use "*" to allow all origins (development only).
     // Production example: CORS_ALLOWED_ORIGINS=https://stellar-insights.com
-    let cors_allowed_origins = std::env::var("CORS_ALLOWED_ORIGINS")
-        .unwrap_or_else(|_| "http://localhost:3000,http://localhost:3001".to_string());
-
-    tracing::info!(
-        "Configuring CORS with allowed origins: {}",
-        cors_allowed_origins
-    );
-
-    let cors_methods = [
-        Method::GET,
-        Method::POST,
-        Method::PUT,
-        Method::DELETE,
-        Method::OPTIONS,
-        Method::PATCH,
-        Method::HEAD,
-    ];
-
-    let cors = {
-        let base = CorsLayer::new()
-            .allow_methods(cors_methods)
-            .allow_headers(Any)
-            .max_age(Duration::from_secs(3600));
-
-        if cors_allowed_origins.trim() == "*" {
-            tracing::warn!(
-                "CORS configured to allow ALL origins (*). \
-                 This is insecure and should not be used in production."
-            );
-            base.allow_origin(Any)
-        } else {
-            let origins: Vec<axum::http::HeaderValue> = cors_allowed_origins
-                .split(',')
-                .filter_map(|o| {
-                    let trimmed = o.trim();
-                    trimmed
-                        .parse::<axum::http::HeaderValue>()
-                        .map_err(|e| {
-                            tracing::warn!("Skipping invalid CORS origin '{}': {}", trimmed, e);
-                        })
-                        .ok()
-                })
-                .collect();
-
-            if origins.is_empty() {
-                panic!(
-                    "CORS_ALLOWED_ORIGINS contains no valid origins. \
-                     Set valid origins or use '*' explicitly for development. \
-                     Refusing to fall back to allow-all. (SEC-011)"
-                );
-            } else {
-                tracing::info!("CORS restricted to {} specific origin(s)", origins.len());
-                base.allow_origin(origins)
-            }
-        }
-    };
+    let cors = CorsOrigins::from_env()
+        .context("Invalid CORS origins")?
+        .layer();
 
     // Compression configuration
     // Only compress responses larger than 1KB to avoid overhead on small responses
@@ -868,10 +971,7 @@ async fn main() -> Result<()> {
         .and_then(|s| s.parse::<u16>().ok())
         .unwrap_or(1024);
 
-    let compression = CompressionLayer::new()
-        .gzip(true)
-        .br(true)
-        .compress_when(SizeAbove::new(compression_min_size));
+    let compression = http_cache::compression_layer(compression_min_size);
 
     tracing::info!(
         "Compression enabled (gzip, brotli) for responses > {} bytes",
@@ -889,8 +989,12 @@ async fn main() -> Result<()> {
     let cached_routes = Router::new()
         .route("/api/anchors", get(get_anchors))
         .route("/api/corridors", get(list_corridors))
+        .route("/api/corridors/compare", get(compare_corridors))
         .route("/api/corridors/:corridor_key", get(get_corridor_detail))
         .with_state(cached_state.clone())
+        .layer(axum::Extension(health_weights))
+        .layer(axum::Extension(corridor_volume_floor))
+        .layer(axum::Extension(corridor_payment_fetch_limit))
         .layer(ServiceBuilder::new().layer(middleware::from_fn_with_state(
             rate_limiter.clone(),
             rate_limit_middleware,
@@ -899,7 +1003,10 @@ async fn main() -> Result<()> {
 
     // Build non-cached anchor routes with app state
     let anchor_routes = Router::new()
-        .route("/health", get(health_check))
+        .route(
+            "/health",
+            get(health_check).layer(axum::Extension(Arc::clone(&shutdown_coordinator))),
+        )
         .route("/api/anchors/:id", get(get_anchor))
         .route(
             "/api/anchors/account/:stellar_account",
@@ -907,6 +1014,10 @@ async fn main() -> Result<()> {
         )
         .route("/api/anchors/:id/assets", get(get_anchor_assets))
         .route("/api/analytics/muxed", get(get_muxed_analytics))
+        .route(
+            "/api/analytics/muxed/:base_account",
+            get(get_muxed_account_usage),
+        )
         .with_state(app_state.clone())
         .layer(ServiceBuilder::new().layer(middleware::from_fn_with_state(
             rate_limiter.clone(),
@@ -931,6 +1042,10 @@ async fn main() -> Result<()> {
         .layer(
             ServiceBuilder::new()
                 .layer(middleware::from_fn(auth_middleware))
+                .layer(middleware::from_fn_with_state(
+                    Arc::clone(&db),
+                    admin_audit_middleware,
+                ))
                 .layer(middleware::from_fn_with_state(
                     rate_limiter.clone(),
                     rate_limit_middleware,
@@ -971,7 +1086,11 @@ async fn main() -> Result<()> {
         )
         .route("/api/rpc/trades", get(rpc_handlers::get_trades))
         .route("/api/rpc/orderbook", get(rpc_handlers::get_order_book))
-        .with_state(rpc_client)
+        .route(
+            "/api/rpc/circuit-breaker",
+            get(rpc_handlers::get_circuit_breakers),
+        )
+        .with_state(Arc::clone(&rpc_client))
         .layer(ServiceBuilder::new().layer(middleware::from_fn_with_state(
             rate_limiter.clone(),
             rate_limit_middleware,
@@ -1062,30 +1181,109 @@ async fn main() -> Result<()> {
         )))
         .layer(cors.clone());
 
+    // Build asset leaderboard routes
+    let asset_leaderboard_routes = stellar_insights_backend::api::asset_leaderboard::routes(
+        stellar_insights_backend::api::asset_leaderboard::AssetLeaderboardState {
+            db: Arc::clone(&db),
+            trustline_analyzer: Arc::clone(&trustline_analyzer),
+        },
+    )
+    .layer(ServiceBuilder::new().layer(middleware::from_fn_with_state(
+        rate_limiter.clone(),
+        rate_limit_middleware,
+    )))
+    .layer(cors.clone());
+
+    // Build anchor reliability leaderboard routes
+    let anchor_leaderboard_routes =
+        stellar_insights_backend::api::anchor_leaderboard::routes(Arc::clone(&db))
+            .layer(ServiceBuilder::new().layer(middleware::from_fn_with_state(
+                rate_limiter.clone(),
+                rate_limit_middleware,
+            )))
+            .layer(cors.clone());
+
+    // Build ingestion status routes
+    let ingestion_status_routes = stellar_insights_backend::api::ingestion_status::routes(
+        stellar_insights_backend::api::ingestion_status::IngestionStatusState::new(
+            Arc::clone(&db),
+            Arc::clone(&rpc_client),
+        ),
+    )
+    .layer(ServiceBuilder::new().layer(middleware::from_fn_with_state(
+        rate_limiter.clone(),
+        rate_limit_middleware,
+    )))
+    .layer(cors.clone());
+
+    // Build snapshot diff and signed snapshot download routes
+    let mut signed_snapshot_routes = snapshot_handlers::routes(SnapshotAppState {
+        db: Arc::clone(&db),
+        contract_service: None,
+        snapshot_service: Arc::new(SnapshotService::new(Arc::clone(&db), None)),
+    });
+    match SnapshotSigner::from_env().context("Invalid SNAPSHOT_SIGNING_KEY")? {
+        Some(signer) => {
+            tracing::info!("Snapshot signing public key: {}", signer.public_key_hex());
+            signed_snapshot_routes =
+                signed_snapshot_routes.layer(axum::Extension(Arc::new(signer)));
+        }
+        None => tracing::warn!("SNAPSHOT_SIGNING_KEY not set; signed snapshots unavailable"),
+    }
+    let signed_snapshot_routes = signed_snapshot_routes
+        .layer(ServiceBuilder::new().layer(middleware::from_fn_with_state(
+            rate_limiter.clone(),
+            rate_limit_middleware,
+        )))
+        .layer(cors.clone());
+
+    // Build account summary routes
+    let account_routes = stellar_insights_backend::api::accounts::routes(
+        stellar_insights_backend::api::accounts::AccountSummaryState {
+            rpc_client: Arc::clone(&rpc_client),
+            cache: Arc::clone(&cache),
+            price_feed: Arc::clone(&price_feed),
+        },
+    )
+    .layer(ServiceBuilder::new().layer(middleware::from_fn_with_state(
+        rate_limiter.clone(),
+        rate_limit_middleware,
+    )))
+    .layer(cors.clone());
+
     // Build GraphQL schema
-    // let graphql_schema = build_schema(Arc::new(pool.clone()));
-    // tracing::info!("GraphQL schema initialized");
+    let graphql_schema = build_schema(
+        Arc::new(pool.clone()),
+        Arc::clone(&rpc_client),
+        Arc::clone(&price_feed),
+    );
+    tracing::info!("GraphQL schema initialized");
 
     // GraphQL handler
-    // async fn graphql_handler(
-    //     State(schema): State<AppSchema>,
-    //     req: GraphQLRequest,
-    // ) -> GraphQLResponse {
-    //     schema.execute(req.into_inner()).await.into()
-    // }
+    async fn graphql_handler(
+        State(schema): State<AppSchema>,
+        req: GraphQLRequest,
+    ) -> GraphQLResponse {
+        schema.execute(req.into_inner()).await.into()
+    }
 
     // GraphQL Playground handler
-    // async fn graphql_playground() -> impl IntoResponse {
-    //     Html(playground_source(
-    //         async_graphql::http::GraphQLPlaygroundConfig::new("/graphql"),
-    //     ))
-    // }
+    async fn graphql_playground() -> impl IntoResponse {
+        Html(playground_source(
+            async_graphql::http::GraphQLPlaygroundConfig::new("/graphql"),
+        ))
+    }
 
     // Build GraphQL routes
-    // let graphql_routes = Router::new()
-    //     .route("/graphql", post(graphql_handler))
-    //     .route("/graphql/playground", get(graphql_playground))
-    //     .with_state(graphql_schema);
+    let graphql_routes = Router::new()
+        .route("/graphql", post(graphql_handler))
+        .route("/graphql/playground", get(graphql_playground))
+        .with_state(graphql_schema)
+        .layer(ServiceBuilder::new().layer(middleware::from_fn_with_state(
+            rate_limiter.clone(),
+            rate_limit_middleware,
+        )))
+        .layer(cors.clone());
 
     // Build achievements / quests routes
     let achievements_routes = Router::new()
@@ -1115,6 +1313,67 @@ async fn main() -> Result<()> {
         )
         .layer(cors.clone());
 
+    // Build effective config route (ADMIN - IP whitelisted)
+    let admin_config_routes = Router::new()
+        .merge(admin_config::routes(Arc::clone(&cache)))
+        .layer(
+            ServiceBuilder::new()
+                .layer(middleware::from_fn_with_state(
+                    ip_whitelist_config.clone(),
+                    ip_whitelist_middleware,
+                ))
+                .layer(middleware::from_fn_with_state(
+                    rate_limiter.clone(),
+                    rate_limit_middleware,
+                )),
+        )
+        .layer(cors.clone());
+
+    // Build cache invalidation route (ADMIN - IP whitelisted, authenticated, audited)
+    let admin_cache_routes = Router::new()
+        .merge(admin_cache::routes(Arc::clone(&cache_invalidation)))
+        .layer(
+            ServiceBuilder::new()
+                .layer(middleware::from_fn_with_state(
+                    ip_whitelist_config.clone(),
+                    ip_whitelist_middleware,
+                ))
+                .layer(middleware::from_fn(auth_middleware))
+                .layer(middleware::from_fn_with_state(
+                    Arc::clone(&db),
+                    admin_audit_middleware,
+                ))
+                .layer(middleware::from_fn_with_state(
+                    rate_limiter.clone(),
+                    rate_limit_middleware,
+                )),
+        )
+        .layer(cors.clone());
+
+    // Build ledger replay route (ADMIN - IP whitelisted, authenticated, audited)
+    let ledger_replay_routes = Router::new()
+        .merge(ledger_replay::routes(LedgerReplayState {
+            ingestion: Arc::clone(&ingestion_service),
+            fee_bump_tracker: Arc::clone(&fee_bump_tracker),
+        }))
+        .layer(
+            ServiceBuilder::new()
+                .layer(middleware::from_fn_with_state(
+                    ip_whitelist_config.clone(),
+                    ip_whitelist_middleware,
+                ))
+                .layer(middleware::from_fn(auth_middleware))
+                .layer(middleware::from_fn_with_state(
+                    Arc::clone(&db),
+                    admin_audit_middleware,
+                ))
+                .layer(middleware::from_fn_with_state(
+                    rate_limiter.clone(),
+                    rate_limit_middleware,
+                )),
+        )
+        .layer(cors.clone());
+
     // Build cache stats routes (ADMIN - IP whitelisted)
     let cache_routes = Router::new()
         .merge(cache_stats::routes(Arc::clone(&cache)))
@@ -1186,6 +1445,21 @@ async fn main() -> Result<()> {
             rate_limit_middleware,
         )));
 
+    // Build asset verification routes
+    let asset_verification_routes = Router::new()
+        .nest(
+            "/api/assets",
+            asset_verification::routes(
+                pool.clone(),
+                rate_limiter.clone(),
+                Arc::new(AssetVerifier::new(pool.clone())?),
+            ),
+        )
+        .layer(ServiceBuilder::new().layer(middleware::from_fn_with_state(
+            rate_limiter.clone(),
+            rate_limit_middleware,
+        )));
+
     // Build GDPR routes (temporarily disabled)
     /*
     let gdpr_routes = Router::new()
@@ -1237,6 +1511,21 @@ async fn main() -> Result<()> {
     let swagger_routes =
         SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi());
 
+    // Readiness of dependencies and supervised background tasks
+    let readiness_probes: Vec<Arc<dyn DependencyProbe>> = vec![
+        Arc::new(RpcProbe(Arc::clone(&rpc_client))),
+        Arc::new(DatabaseProbe(pool.clone())),
+        Arc::new(RateLimitRedisProbe(Arc::clone(&rate_limiter))),
+        Arc::new(CacheProbe(Arc::clone(&cache))),
+    ];
+    let health_routes = health::routes(
+        Arc::clone(&supervisor),
+        readiness_probes,
+        startup_status,
+        Arc::clone(&shutdown_coordinator),
+    )
+    .layer(cors.clone());
+
     // Build WebSocket routes
     let ws_routes = Router::new()
         .route("/ws", get(stellar_insights_backend::websocket::ws_handler))
@@ -1255,6 +1544,7 @@ async fn main() -> Result<()> {
         .route("/metrics", get(obs_metrics::metrics_handler))
         .route("/api/elk/health", get(elk_health::elk_health_check))
         .route("/api/elk/metrics", get(elk_health::logging_metrics))
+        .merge(health_routes)
         .merge(swagger_routes)
         .merge(auth_routes)
         .merge(oauth_routes)
@@ -1269,13 +1559,21 @@ async fn main() -> Result<()> {
         .merge(price_routes)
         .merge(cost_calculator_routes)
         .merge(trustline_routes)
+        .merge(asset_leaderboard_routes)
+        .merge(anchor_leaderboard_routes)
+        .merge(ingestion_status_routes)
+        .merge(signed_snapshot_routes)
+        .merge(account_routes)
         .merge(achievements_routes)
         .merge(governance_routes)
         .merge(network_routes)
         .merge(api_analytics_routes)
+        .merge(admin_config_routes)
+        .merge(admin_cache_routes)
+        .merge(ledger_replay_routes)
         .merge(cache_routes)
         .merge(metrics_routes)
-        // .merge(graphql_routes) // Add GraphQL routes
+        .merge(graphql_routes)
         .merge(admin_db_routes)
         .merge(verification_routes)
         .merge(asset_verification_routes)
@@ -1290,22 +1588,29 @@ async fn main() -> Result<()> {
         .layer(TraceLayer::new_for_http())
         .layer(middleware::from_fn(obs_metrics::http_metrics_middleware))
         .layer(middleware::from_fn(request_id_middleware))
-        .layer(compression); // Apply compression to all routes
+        .layer(compression) // Apply compression to all routes
+        .layer(middleware::from_fn(http_cache::weaken_encoded_etag));
 
-    // Start server
-    let host = std::env::var("SERVER_HOST").unwrap_or_else(|_| "127.0.0.1".to_string());
-    let port = std::env::var("SERVER_PORT").unwrap_or_else(|_| "8080".to_string());
-    let addr = format!("{}:{}", host, port);
+    // APM Prometheus scrape endpoint (404 unless PROMETHEUS_ENABLED=true)
+    #[cfg(feature = "apm")]
+    let app = app
+        .nest(
+            "/apm",
+            stellar_insights_apm::prometheus::routes(Arc::clone(&apm)),
+        )
+        .layer(middleware::from_fn_with_state(
+            apm,
+            stellar_insights_apm::ApmMiddleware::track_http_request,
+        ));
 
-    tracing::info!("Server starting on {}", addr);
-    tracing::info!(
-        "GraphQL Playground available at http://{}/graphql/playground",
-        addr
-    );
-    let listener = tokio::net::TcpListener::bind(&addr).await?;
+    // Start server (TCP or Unix socket, from BIND_ADDR)
+    let bind_addr = BindAddr::from_env()?;
+    let listener = listen::bind(&bind_addr, TlsConfig::from_env()?.as_ref()).await?;
+
+    tracing::info!("Server starting on {}", bind_addr);
 
     // Clone resources needed for shutdown
-    let pool_for_shutdown = pool.clone();
+    let db_for_shutdown = Arc::clone(&db);
     let cache_for_shutdown = Arc::clone(&cache);
     let ws_state_for_shutdown = Arc::clone(&ws_state);
     let shutdown_coordinator_clone = Arc::clone(&shutdown_coordinator);
@@ -1313,7 +1618,14 @@ async fn main() -> Result<()> {
     // Spawn signal handler task
     tokio::spawn(async move {
         wait_for_signal().await;
-        tracing::info!("Shutdown signal received, initiating graceful shutdown");
+        let drain_delay = shutdown_coordinator_clone.drain_delay();
+        tracing::info!(
+            "Shutdown signal received, draining traffic for {:?} before graceful shutdown",
+            drain_delay
+        );
+        // /health reports 503 while requests are still served
+        shutdown_coordinator_clone.begin_draining();
+        tokio::time::sleep(drain_delay).await;
         shutdown_coordinator_clone.trigger_shutdown();
     });
 
@@ -1325,17 +1637,11 @@ async fn main() -> Result<()> {
     };
 
     // Start server with graceful shutdown
-    let server = axum::serve(
-        listener,
-        app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
-    )
-    .with_graceful_shutdown(shutdown_signal);
-
     tracing::info!("Server is ready to accept connections");
 
     // Run the server
-    if let Err(e) = server.await {
-        tracing::error!("Server error: {}", e);
+    if let Err(e) = listen::serve(app, listener, shutdown_signal).await {
+        tracing::error!("Server error: {:#}", e);
     }
 
     tracing::info!("Server stopped accepting new connections, starting cleanup");
@@ -1351,7 +1657,7 @@ async fn main() -> Result<()> {
     flush_cache(cache_for_shutdown, shutdown_config.db_close_timeout).await;
 
     tracing::info!("Step 4/4: Closing database connections");
-    shutdown_database(pool_for_shutdown, shutdown_config.db_close_timeout).await;
+    shutdown_database(&db_for_shutdown, shutdown_config.db_close_timeout).await;
 
     // Log final shutdown summary
     log_shutdown_summary(shutdown_start);
@@ -7,14 +7,15 @@ use axum::{
 use dotenv::dotenv;
 use sqlx::postgres::PgPoolOptions;
 use tower::ServiceBuilder;
-use tower_http::cors::{Any, CorsLayer};
 
 use stellar_insights_apm::{ApmManager, ApmConfig, ApmMiddleware};
+use backend::cors::CorsOrigins;
 use backend::database::Database;
 use backend::handlers::*;
 use backend::api::anchors::get_anchors;
 use backend::api::corridors::{list_corridors, get_corridor_detail};
 use backend::ingestion::DataIngestionService;
+use backend::observability::tracing::LogFormat;
 use backend::rpc::StellarRpcClient;
 use backend::rpc_handlers;
 
@@ -24,8 +25,11 @@ async fn main() -> anyhow::Result<()> {
     dotenv().ok();
 
     // Initialize APM
-    let apm_config = ApmConfig::default();
-    let apm = Arc::new(ApmManager::new(apm_config)?);
+    let apm_config = ApmConfig::from_env()?;
+    let apm = Arc::new(ApmManager::with_console_layer(
+        apm_config,
+        LogFormat::from_env().layer(),
+    )?);
     
     // Set up graceful shutdown for APM
     let apm_shutdown = apm.clone();
@@ -42,7 +46,7 @@ async fn main() -> anyhow::Result<()> {
             tracing_subscriber::EnvFilter::try_from_default_env()
                 .unwrap_or_else(|_| "stellar_insights=info,tower_http=debug".into()),
         )
-        .with(tracing_subscriber::fmt::layer().json())
+        .with(LogFormat::from_env().layer())
         .init();
 
     tracing::info!("Starting Stellar Insights backend with APM integration");
@@ -88,7 +92,8 @@ async fn main() -> anyhow::Result<()> {
     let app = Router::new()
         // Health check endpoint
         .route("/health", get(health_check))
-        .route("/metrics", get(metrics_handler))
+        // Prometheus scrape endpoint (404 unless PROMETHEUS_ENABLED=true)
+        .merge(stellar_insights_apm::prometheus::routes(apm.clone()))
         
         // API routes
         .route("/api/anchors", get(get_anchors))
@@ -99,12 +104,7 @@ async fn main() -> anyhow::Result<()> {
         .route("/rpc/stellar/*path", post(rpc_handlers::handle_stellar_rpc))
         
         // CORS layer
-        .layer(
-            CorsLayer::new()
-                .allow_origin(Any)
-                .allow_methods(Any)
-                .allow_headers(Any),
-        )
+        .layer(CorsOrigins::from_env()?.layer())
         
         // APM middleware for HTTP request tracking
         .layer(middleware::from_fn_with_state(
@@ -148,13 +148,6 @@ async fn health_check() -> axum::Json<serde_json::Value> {
     }))
 }
 
-/// Metrics handler for Prometheus scraping
-async fn metrics_handler() -> Result<String, axum::http::StatusCode> {
-    // This would typically expose Prometheus metrics
-    // For now, return a simple response
-    Ok("# HELP stellar_insights_requests_total Total number of requests\n# TYPE stellar_insights_requests_total counter\nstellar_insights_requests_total 0\n".to_string())
-}
-
 /// Graceful shutdown signal
 async fn shutdown_signal(apm: Arc<ApmManager>) {
     let ctrl_c = async {
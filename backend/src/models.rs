@@ -1,5 +1,9 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::error::ApiError;
+use crate::muxed::is_account_id;
 
 pub mod alerts;
 pub mod api_key;
@@ -13,6 +17,10 @@ pub enum SortBy {
     SuccessRate,
     #[serde(rename = "volume")]
     Volume,
+    #[serde(rename = "health_score")]
+    HealthScore,
+    #[serde(rename = "liquidity_depth")]
+    LiquidityDepth,
 }
 
 impl Default for SortBy {
@@ -64,6 +72,9 @@ pub struct AnchorMetricsHistory {
     pub avg_settlement_time_ms: Option<i32>,
     pub volume_usd: Option<f64>,
     pub created_at: DateTime<Utc>,
+    /// Score as computed, before any smoothing; `None` for rows recorded
+    /// before it was kept
+    pub raw_reliability_score: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -103,6 +114,18 @@ impl AnchorStatus {
             AnchorStatus::Red
         }
     }
+
+    /// Status of a 0-100 reliability score, so an anchor's stored score and
+    /// status agree
+    pub fn from_reliability_score(score: f64) -> Self {
+        if score >= 99.0 {
+            AnchorStatus::Green
+        } else if score >= 95.0 {
+            AnchorStatus::Yellow
+        } else {
+            AnchorStatus::Red
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -120,6 +143,112 @@ pub struct CreateCorridorRequest {
     pub dest_asset_issuer: String,
 }
 
+/// Longest anchor name accepted on create
+pub const MAX_ANCHOR_NAME_LEN: usize = 128;
+
+/// Stellar asset codes are 1-12 alphanumeric characters
+pub const MAX_ASSET_CODE_LEN: usize = 12;
+
+/// Collects per-field problems so a request reports every bad field at once
+#[derive(Debug, Default)]
+struct FieldErrors(HashMap<String, serde_json::Value>);
+
+impl FieldErrors {
+    fn add(&mut self, field: &str, message: impl Into<String>) {
+        self.0
+            .insert(field.to_string(), serde_json::Value::String(message.into()));
+    }
+
+    fn into_result(self) -> Result<(), ApiError> {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+        let mut fields: Vec<&str> = self.0.keys().map(String::as_str).collect();
+        fields.sort_unstable();
+        let message = format!("Invalid request fields: {}", fields.join(", "));
+        Err(ApiError::bad_request_with_details(
+            "VALIDATION_ERROR",
+            message,
+            self.0,
+        ))
+    }
+}
+
+fn check_asset_code(errors: &mut FieldErrors, field: &str, code: &str) {
+    if code.is_empty() || code.len() > MAX_ASSET_CODE_LEN {
+        errors.add(
+            field,
+            format!("must be 1-{} characters", MAX_ASSET_CODE_LEN),
+        );
+    } else if !code.chars().all(|c| c.is_ascii_alphanumeric()) {
+        errors.add(field, "must contain only letters and digits");
+    }
+}
+
+/// Issuers must be a G-address; native XLM uses the literal "native"
+fn check_asset_issuer(errors: &mut FieldErrors, field: &str, code: &str, issuer: &str) {
+    if issuer == "native" {
+        if code != "XLM" {
+            errors.add(field, "\"native\" is only valid for XLM");
+        }
+    } else if !is_account_id(issuer) {
+        errors.add(field, "must be a Stellar account ID (G...)");
+    }
+}
+
+impl CreateAnchorRequest {
+    /// Check field formats before anything is written
+    pub fn validate(&self) -> Result<(), ApiError> {
+        let mut errors = FieldErrors::default();
+
+        let name = self.name.trim();
+        if name.is_empty() {
+            errors.add("name", "must not be empty");
+        } else if name.chars().count() > MAX_ANCHOR_NAME_LEN {
+            errors.add(
+                "name",
+                format!("must be at most {} characters", MAX_ANCHOR_NAME_LEN),
+            );
+        }
+
+        if !is_account_id(&self.stellar_account) {
+            errors.add("stellar_account", "must be a Stellar account ID (G...)");
+        }
+
+        if let Some(domain) = &self.home_domain {
+            if domain.trim().is_empty() || domain.contains(char::is_whitespace) {
+                errors.add("home_domain", "must be a bare domain name");
+            } else if domain.contains('/') {
+                errors.add("home_domain", "must not include a scheme or path");
+            }
+        }
+
+        errors.into_result()
+    }
+}
+
+impl CreateCorridorRequest {
+    /// Check asset codes and issuers on both legs of the corridor
+    pub fn validate(&self) -> Result<(), ApiError> {
+        let mut errors = FieldErrors::default();
+        check_asset_code(&mut errors, "source_asset_code", &self.source_asset_code);
+        check_asset_code(&mut errors, "dest_asset_code", &self.dest_asset_code);
+        check_asset_issuer(
+            &mut errors,
+            "source_asset_issuer",
+            &self.source_asset_code,
+            &self.source_asset_issuer,
+        );
+        check_asset_issuer(
+            &mut errors,
+            "dest_asset_issuer",
+            &self.dest_asset_code,
+            &self.dest_asset_issuer,
+        );
+        errors.into_result()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnchorDetailResponse {
     pub anchor: Anchor,
@@ -179,6 +308,11 @@ pub struct PaymentRecord {
     pub asset_type: String,
     pub asset_code: Option<String>,
     pub asset_issuer: Option<String>,
+    /// Horizon operation type (`payment`, `path_payment_strict_send`, ...)
+    #[sqlx(default)]
+    pub operation_type: Option<String>,
+    #[sqlx(default)]
+    pub source_asset_type: Option<String>,
     #[sqlx(default)]
     pub source_asset_code: String,
     #[sqlx(default)]
@@ -255,6 +389,28 @@ pub struct FeeBumpStats {
     pub unique_fee_sources: i64,
 }
 
+/// Fee-bump activity of one fee-paying account
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct FeeBumpSourceStats {
+    pub fee_source: String,
+    pub fee_bump_count: i64,
+    pub total_fee_charged: i64,
+}
+
+/// Fee-bump statistics over a time window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeBumpAnalytics {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub fee_bump_count: i64,
+    pub avg_max_fee: f64,
+    pub avg_fee_charged: f64,
+    /// Average headroom between the fee bid and the fee actually charged
+    pub avg_fee_delta: f64,
+    /// Accounts paying for the most fee bumps, busiest first
+    pub top_fee_sources: Vec<FeeBumpSourceStats>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct LiquidityPool {
     pub pool_id: String,
@@ -334,6 +490,16 @@ pub struct MuxedAccountUsage {
     pub total_payments: i64,
 }
 
+/// Muxed sub-account activity attributed to one base (G...) account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MuxedBaseAccountUsage {
+    pub base_account: String,
+    pub sub_account_count: i64,
+    pub total_payments: i64,
+    /// Busiest sub-accounts first
+    pub sub_accounts: Vec<MuxedAccountUsage>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct PendingTransaction {
     pub id: String,
@@ -343,6 +509,9 @@ pub struct PendingTransaction {
     pub status: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub idempotency_key: Option<String>,
+    /// Network hash, set once the transaction has been submitted
+    pub transaction_hash: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
@@ -361,6 +530,16 @@ pub struct PendingTransactionWithSignatures {
     pub collected_signatures: Vec<Signature>,
 }
 
+/// Signature progress of a pending transaction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionReadiness {
+    pub transaction_id: String,
+    pub distinct_signers: i64,
+    pub required_signatures: i32,
+    pub ready: bool,
+    pub status: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionResult {
     pub hash: String,
@@ -391,6 +570,34 @@ pub struct TrustlineSnapshot {
     pub snapshot_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrustlineTrendDirection {
+    Growing,
+    Flat,
+    Shrinking,
+}
+
+/// Authorized trustline change between the oldest snapshot in the window and now
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustlineTrend {
+    pub direction: TrustlineTrendDirection,
+    pub current_authorized: i64,
+    pub baseline_authorized: i64,
+    pub baseline_at: DateTime<Utc>,
+    /// `None` when the baseline had no authorized trustlines
+    pub change_pct: Option<f64>,
+    pub window_days: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustlineAssetDetail {
+    #[serde(flatten)]
+    pub stat: TrustlineStat,
+    /// `None` until a snapshot exists inside the window
+    pub trend: Option<TrustlineTrend>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrustlineMetrics {
     pub total_assets_tracked: i64,
@@ -6,6 +6,8 @@ use sqlx::FromRow;
 #[serde(rename_all = "lowercase")]
 pub enum VerificationStatus {
     Verified,
+    /// Scored between the provisional and verified thresholds
+    Provisional,
     Unverified,
     Suspicious,
 }
@@ -14,6 +16,7 @@ impl VerificationStatus {
     pub fn as_str(&self) -> &str {
         match self {
             VerificationStatus::Verified => "verified",
+            VerificationStatus::Provisional => "provisional",
             VerificationStatus::Unverified => "unverified",
             VerificationStatus::Suspicious => "suspicious",
         }
@@ -22,6 +25,7 @@ impl VerificationStatus {
     pub fn from_str(s: &str) -> Self {
         match s.to_lowercase().as_str() {
             "verified" => VerificationStatus::Verified,
+            "provisional" => VerificationStatus::Provisional,
             "suspicious" => VerificationStatus::Suspicious,
             _ => VerificationStatus::Unverified,
         }
@@ -188,6 +192,29 @@ pub struct VerifyAssetRequest {
     pub asset_issuer: String,
 }
 
+/// One asset in a `POST /api/assets/verify-batch` request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchVerifyAssetItem {
+    pub code: String,
+    pub issuer: String,
+}
+
+/// Outcome for one asset of a batch; exactly one of `result` and `error` is set
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchVerifyAssetResult {
+    pub code: String,
+    pub issuer: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<VerifiedAssetResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchVerifyAssetsResponse {
+    pub results: Vec<BatchVerifyAssetResult>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReportAssetRequest {
     pub asset_code: String,
@@ -216,6 +243,17 @@ pub struct VerificationResult {
     pub trustline_count: i64,
     pub transaction_count: i64,
     pub total_volume_usd: f64,
+    /// Trustline authorization state from Horizon, when it could be fetched
+    pub trustline_authorization: Option<TrustlineAuthorization>,
+}
+
+/// How an asset's trustlines are authorized and whether the issuer can claw back
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrustlineAuthorization {
+    /// Fully authorized trustlines, plus those authorized to maintain liabilities
+    pub authorized: i64,
+    pub unauthorized: i64,
+    pub clawback_enabled: bool,
 }
 
 #[derive(Debug, Clone)]
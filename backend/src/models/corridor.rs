@@ -31,7 +31,8 @@ impl Corridor {
         let asset_a_key = format!("{}:{}", self.asset_a_code, self.asset_a_issuer);
         let asset_b_key = format!("{}:{}", self.asset_b_code, self.asset_b_issuer);
 
-        if asset_a_key > asset_b_key {
+        let (_, _, reversed) = canonicalize_pair(&asset_a_key, &asset_b_key);
+        if reversed {
             std::mem::swap(&mut self.asset_a_code, &mut self.asset_b_code);
             std::mem::swap(&mut self.asset_a_issuer, &mut self.asset_b_issuer);
         }
@@ -45,6 +46,18 @@ impl Corridor {
     }
 }
 
+/// Order two assets (`CODE:ISSUER`) alphabetically so `A->B` and `B->A`
+/// name the same market.
+///
+/// Returns `(lo, hi, reversed)`; `reversed` is true when `a` sorts after `b`.
+pub fn canonicalize_pair<'a>(a: &'a str, b: &'a str) -> (&'a str, &'a str, bool) {
+    if a > b {
+        (b, a, true)
+    } else {
+        (a, b, false)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct CorridorMetrics {
     pub id: String,
@@ -172,6 +185,19 @@ mod tests {
         assert_eq!(corridor1.asset_b_code, "USDC");
     }
 
+    #[test]
+    fn test_canonicalize_pair_is_direction_agnostic() {
+        let usdc = "USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+        let xlm = "XLM:native";
+
+        let forward = canonicalize_pair(usdc, xlm);
+        let backward = canonicalize_pair(xlm, usdc);
+
+        assert_eq!(forward, (usdc, xlm, false));
+        assert_eq!(backward, (usdc, xlm, true));
+        assert_eq!(canonicalize_pair(xlm, xlm), (xlm, xlm, false));
+    }
+
     #[test]
     fn test_corridor_same_asset_order() {
         let corridor = Corridor::new(
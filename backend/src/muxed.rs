@@ -4,12 +4,12 @@
 //! sub-accounts via a 64-bit muxed ID. M-addresses are 69 characters and start with 'M'.
 //! See SEP-0023 and [Stellar Muxed Accounts FAQ](https://stellar.org/blog/developers/muxed-accounts-faq).
 
-use data_encoding::BASE32;
+use data_encoding::BASE32_NOPAD;
 use serde::{Deserialize, Serialize};
 
-/// Stellar strkey version bytes
-const VERSION_ACCOUNT_ID: u8 = 6; // G-address
-const VERSION_MUXED_ACCOUNT: u8 = 12; // M-address
+/// Stellar strkey version bytes (the version occupies the top five bits)
+const VERSION_ACCOUNT_ID: u8 = 6 << 3; // G-address
+const VERSION_MUXED_ACCOUNT: u8 = 12 << 3; // M-address
 
 /// Length of a Stellar M-address (MUXED_ACCOUNT strkey)
 pub const MUXED_ADDRESS_LEN: usize = 69;
@@ -57,6 +57,24 @@ pub fn is_muxed_address(addr: &str) -> bool {
             .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
 }
 
+/// Returns true if the given string is a valid Stellar account ID (G-address):
+/// 56 base32 characters that decode to the ACCOUNT_ID version byte, a 32-byte
+/// key and a matching CRC-16 checksum.
+pub fn is_account_id(addr: &str) -> bool {
+    if !addr.starts_with('G') || addr.len() != G_ADDRESS_LEN {
+        return false;
+    }
+    let Ok(decoded) = BASE32_NOPAD.decode(addr.as_bytes()) else {
+        return false;
+    };
+    // Account ID: version(1) + ed25519 key(32) + checksum(2) = 35 bytes
+    if decoded.len() != 35 || decoded[0] != VERSION_ACCOUNT_ID {
+        return false;
+    }
+    let checksum = u16::from_le_bytes([decoded[33], decoded[34]]);
+    crc16(&decoded[..33]) == checksum
+}
+
 /// Returns true if the given string looks like a Stellar account address (G or M).
 #[inline]
 pub fn is_stellar_account_address(addr: &str) -> bool {
@@ -73,7 +91,7 @@ pub fn parse_muxed_address(addr: &str) -> Option<MuxedAccountInfo> {
         return None;
     }
 
-    let decoded = BASE32.decode(addr.as_bytes()).ok()?;
+    let decoded = BASE32_NOPAD.decode(addr.as_bytes()).ok()?;
     // Muxed: version(1) + account_id(32) + muxed_id(8) + checksum(2) = 43 bytes
     if decoded.len() != 43 {
         return None;
@@ -81,7 +99,7 @@ pub fn parse_muxed_address(addr: &str) -> Option<MuxedAccountInfo> {
     if decoded[0] != VERSION_MUXED_ACCOUNT {
         return None;
     }
-    let checksum = u16::from_be_bytes([decoded[41], decoded[42]]);
+    let checksum = u16::from_le_bytes([decoded[41], decoded[42]]);
     let payload = &decoded[0..41];
     if crc16(payload) != checksum {
         return None;
@@ -94,10 +112,9 @@ pub fn parse_muxed_address(addr: &str) -> Option<MuxedAccountInfo> {
     let mut g_payload = [0u8; 35];
     g_payload[0] = VERSION_ACCOUNT_ID;
     g_payload[1..33].copy_from_slice(account_id);
-    let c = crc16(&g_payload);
-    g_payload[33] = (c >> 8) as u8;
-    g_payload[34] = (c & 0xff) as u8;
-    let base_account = BASE32.encode(&g_payload);
+    let c = crc16(&g_payload[..33]);
+    g_payload[33..].copy_from_slice(&c.to_le_bytes());
+    let base_account = BASE32_NOPAD.encode(&g_payload);
 
     Some(MuxedAccountInfo {
         muxed_address: addr.to_string(),
@@ -106,6 +123,20 @@ pub fn parse_muxed_address(addr: &str) -> Option<MuxedAccountInfo> {
     })
 }
 
+/// Resolve an account to the G-address payments should be attributed to,
+/// plus the muxed sub-account ID when `addr` is an M-address.
+/// Anything that is not a decodable M-address is returned unchanged.
+pub fn resolve_account(addr: &str) -> (String, Option<u64>) {
+    match parse_muxed_address(addr) {
+        Some(MuxedAccountInfo {
+            base_account: Some(base),
+            muxed_id,
+            ..
+        }) => (base, muxed_id),
+        _ => (addr.to_string(), None),
+    }
+}
+
 /// Normalize an account identifier for display or storage.
 /// Accepts both G- and M-addresses and returns them as-is (no conversion).
 #[inline]
@@ -138,6 +169,31 @@ mod tests {
         assert!(is_muxed_address(m));
     }
 
+    #[test]
+    fn test_is_account_id() {
+        assert!(is_account_id(
+            "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ"
+        ));
+        assert!(!is_account_id("GANCHOR"));
+        // Lowercase and base32-invalid digits are rejected
+        assert!(!is_account_id(
+            "ga7qynf7sowq3glr2bgmzehxavirza4kvwltjjfc7mgxua74p7ujvsgz"
+        ));
+        assert!(!is_account_id(
+            "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSG1"
+        ));
+        assert!(!is_account_id(
+            "MAAAAAAAAAAAAAB7BQ2L7E5NBWMXDUCMZSIPOBKRDSBYVLMXGSSKF6YNPIB7Y77ITLVL6"
+        ));
+        // Right alphabet and length, but the checksum does not match
+        assert!(!is_account_id(
+            "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGA"
+        ));
+        assert!(!is_account_id(
+            "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA"
+        ));
+    }
+
     #[test]
     fn test_is_stellar_account_address() {
         assert!(is_stellar_account_address(
@@ -156,14 +212,40 @@ mod tests {
             parse_muxed_address("GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ")
                 .is_none()
         );
-        // Valid M-address format: parse may succeed (if checksum/version match) or None
-        let m = "MAAAAAAAAAAAAAB7BQ2L7E5NBWMXDUCMZSIPOBKRDSBYVLMXGSSKF6YNPIB7Y77ITLVL6";
-        let info = parse_muxed_address(m);
-        if let Some(ref i) = info {
-            assert!(i.muxed_address == m);
-            assert!(i.base_account.as_ref().map_or(true, |g| g.starts_with('G')));
-        }
+        let m = "MBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLEAAAAAAAAAAAAIYR4";
+        let info = parse_muxed_address(m).expect("valid M-address");
+        assert_eq!(info.muxed_address, m);
+        assert_eq!(
+            info.base_account.as_deref(),
+            Some("GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5")
+        );
+        assert_eq!(info.muxed_id, Some(2));
+        // Corrupted checksum
+        assert!(parse_muxed_address(
+            "MBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLEAAAAAAAAAAAAIYR5"
+        )
+        .is_none());
         // Too short M string
         assert!(parse_muxed_address("M").is_none());
     }
+
+    #[test]
+    fn test_resolve_account() {
+        assert_eq!(
+            resolve_account(
+                "MBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLEAAAAAAAAAAAAFJC4"
+            ),
+            (
+                "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5".to_string(),
+                Some(1)
+            )
+        );
+        assert_eq!(
+            resolve_account("GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5"),
+            (
+                "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5".to_string(),
+                None
+            )
+        );
+    }
 }
@@ -28,6 +28,7 @@ struct MetricsState {
     background_jobs_total: Mutex<HashMap<String, u64>>,
     active_connections: AtomicI64,
     corridors_tracked: AtomicI64,
+    ingestion_write_backlog: AtomicI64,
     http_in_flight_requests: AtomicI64,
 }
 
@@ -219,6 +220,15 @@ pub async fn metrics_handler() -> Response {
         metrics.corridors_tracked.load(Ordering::Relaxed)
     ));
 
+    out.push_str(
+        "# HELP ingestion_write_backlog Fetched ingestion batches waiting to be written\n",
+    );
+    out.push_str("# TYPE ingestion_write_backlog gauge\n");
+    out.push_str(&format!(
+        "ingestion_write_backlog {}\n",
+        metrics.ingestion_write_backlog.load(Ordering::Relaxed)
+    ));
+
     out.push_str("# HELP http_in_flight_requests In-flight HTTP requests\n");
     out.push_str("# TYPE http_in_flight_requests gauge\n");
     out.push_str(&format!(
@@ -283,6 +293,14 @@ pub fn record_cache_lookup(hit: bool) {
     );
 }
 
+/// Count a lookup skipped because an authenticated caller sent `Cache-Control: no-cache`
+pub fn record_cache_bypass() {
+    inc_counter(
+        &state().cache_operations_total,
+        make_key(&[("result", "bypass")]),
+    );
+}
+
 pub fn record_error(error_type: &str) {
     inc_counter(
         &state().errors_total,
@@ -313,6 +331,12 @@ pub fn set_corridors_tracked(count: i64) {
     state().corridors_tracked.store(count, Ordering::Relaxed);
 }
 
+pub fn set_ingestion_write_backlog(depth: i64) {
+    state()
+        .ingestion_write_backlog
+        .store(depth, Ordering::Relaxed);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,10 +2,69 @@ use anyhow::Result;
 use opentelemetry::sdk::{trace as sdktrace, Resource};
 use opentelemetry::KeyValue;
 use opentelemetry_otlp::WithExportConfig;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use tracing::Subscriber;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::registry::{LookupSpan, Registry};
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, Layer};
 use std::net::TcpStream;
 use std::io::Write;
 
+/// Console log output format, selected with `LOG_FORMAT`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// One JSON object per event, for log shippers
+    Json,
+    /// Multi-line, human-readable output for local development
+    Pretty,
+    /// Single-line text
+    Compact,
+}
+
+impl LogFormat {
+    /// Parse `json`, `pretty` or `compact`, ignoring case
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "json" => Some(LogFormat::Json),
+            "pretty" => Some(LogFormat::Pretty),
+            "compact" => Some(LogFormat::Compact),
+            _ => None,
+        }
+    }
+
+    /// Format from LOG_FORMAT (default: json)
+    pub fn from_env() -> Self {
+        match std::env::var("LOG_FORMAT") {
+            Ok(value) => Self::parse(&value).unwrap_or_else(|| {
+                eprintln!("Unknown LOG_FORMAT '{}', using json", value);
+                LogFormat::Json
+            }),
+            Err(_) => LogFormat::Json,
+        }
+    }
+
+    /// Console layer writing to stdout in this format
+    pub fn layer<S>(self) -> Box<dyn Layer<S> + Send + Sync>
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        self.layer_with_writer(std::io::stdout)
+    }
+
+    /// Console layer in this format writing to `writer`
+    pub fn layer_with_writer<S, W>(self, writer: W) -> Box<dyn Layer<S> + Send + Sync>
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+        W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+    {
+        let layer = tracing_subscriber::fmt::layer().with_writer(writer);
+        match self {
+            LogFormat::Json => layer.json().boxed(),
+            LogFormat::Pretty => layer.pretty().boxed(),
+            LogFormat::Compact => layer.compact().boxed(),
+        }
+    }
+}
+
 fn init_otel_tracer(service_name: &str) -> Result<sdktrace::Tracer> {
     let endpoint = std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT")
         .unwrap_or_else(|_| "http://localhost:4317".to_string());
@@ -52,66 +111,44 @@ fn init_logstash_writer(service_name: &str) -> Option<tracing_logstash::Layer> {
 }
 
 pub fn init_tracing(service_name: &str) -> Result<()> {
+    init_tracing_with_layer(service_name, None)
+}
+
+/// Like [`init_tracing`], additionally installing `extra_layer`, e.g. the
+/// APM's slow query counter
+pub fn init_tracing_with_layer(
+    service_name: &str,
+    extra_layer: Option<Box<dyn Layer<Registry> + Send + Sync>>,
+) -> Result<()> {
     // Bridge log crate (e.g. sqlx statement logging) to tracing
     let _ = tracing_log::LogTracer::init();
 
     let env_filter = tracing_subscriber::EnvFilter::try_from_default_env()
-        .unwrap_or_else(|_| "backend=info,tower_http=info".into());
-    let log_format = std::env::var("LOG_FORMAT").unwrap_or_else(|_| "json".to_string());
+        .unwrap_or_else(|_| "backend=info,tower_http=info,sqlx::query=warn".into());
+    let log_format = LogFormat::from_env();
     let otel_enabled = std::env::var("OTEL_ENABLED")
         .map(|v| v.eq_ignore_ascii_case("true"))
         .unwrap_or(false);
 
     let logstash_layer = init_logstash_writer(service_name);
-
-    if otel_enabled {
+    let otel_layer = if otel_enabled {
         let tracer = init_otel_tracer(service_name)?;
+        Some(tracing_opentelemetry::layer().with_tracer(tracer))
+    } else {
+        None
+    };
+
+    // The console format is the same whether or not traces are exported
+    tracing_subscriber::registry()
+        .with(extra_layer)
+        .with(env_filter)
+        .with(log_format.layer())
+        .with(otel_layer)
+        .with(logstash_layer)
+        .init();
 
-        if log_format.eq_ignore_ascii_case("json") {
-            let subscriber = tracing_subscriber::registry()
-                .with(env_filter)
-                .with(tracing_subscriber::fmt::layer().json())
-                .with(tracing_opentelemetry::layer().with_tracer(tracer));
-
-            if let Some(logstash) = logstash_layer {
-                subscriber.with(logstash).init();
-            } else {
-                subscriber.init();
-            }
-        } else {
-            let subscriber = tracing_subscriber::registry()
-                .with(env_filter)
-                .with(tracing_subscriber::fmt::layer())
-                .with(tracing_opentelemetry::layer().with_tracer(tracer));
-
-            if let Some(logstash) = logstash_layer {
-                subscriber.with(logstash).init();
-            } else {
-                subscriber.init();
-            }
-        }
-
+    if otel_enabled {
         tracing::info!("OpenTelemetry tracing enabled");
-    } else if log_format.eq_ignore_ascii_case("json") {
-        let subscriber = tracing_subscriber::registry()
-            .with(env_filter)
-            .with(tracing_subscriber::fmt::layer().json());
-
-        if let Some(logstash) = logstash_layer {
-            subscriber.with(logstash).init();
-        } else {
-            subscriber.init();
-        }
-    } else {
-        let subscriber = tracing_subscriber::registry()
-            .with(env_filter)
-            .with(tracing_subscriber::fmt::layer());
-
-        if let Some(logstash) = logstash_layer {
-            subscriber.with(logstash).init();
-        } else {
-            subscriber.init();
-        }
     }
 
     Ok(())
@@ -120,3 +157,68 @@ pub fn init_tracing(service_name: &str) -> Result<()> {
 pub fn shutdown_tracing() {
     opentelemetry::global::shutdown_tracer_provider();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for CapturedLogs {
+        type Writer = Self;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    fn log_one_event(format: LogFormat) -> String {
+        let logs = CapturedLogs::default();
+        let subscriber =
+            tracing_subscriber::registry().with(format.layer_with_writer(logs.clone()));
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(corridor = "USDC->EURC", "metrics synced");
+        });
+        let output = logs.0.lock().unwrap().clone();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_log_format_parse() {
+        assert_eq!(LogFormat::parse("json"), Some(LogFormat::Json));
+        assert_eq!(LogFormat::parse(" Pretty "), Some(LogFormat::Pretty));
+        assert_eq!(LogFormat::parse("COMPACT"), Some(LogFormat::Compact));
+        assert_eq!(LogFormat::parse("text"), None);
+    }
+
+    #[test]
+    fn test_log_format_selects_matching_layer() {
+        let json = log_one_event(LogFormat::Json);
+        let event: serde_json::Value = serde_json::from_str(json.trim()).unwrap();
+        assert_eq!(event["fields"]["message"], "metrics synced");
+        assert_eq!(event["fields"]["corridor"], "USDC->EURC");
+
+        let compact = log_one_event(LogFormat::Compact);
+        assert_eq!(compact.trim_end().lines().count(), 1);
+        assert!(compact.contains("metrics synced"));
+        assert!(serde_json::from_str::<serde_json::Value>(compact.trim()).is_err());
+
+        // Pretty puts the source location on its own line
+        let pretty = log_one_event(LogFormat::Pretty);
+        assert!(pretty.trim_end().lines().count() > 1);
+        assert!(pretty.contains("metrics synced"));
+    }
+}
@@ -23,18 +23,29 @@ use utoipa::OpenApi;
         crate::api::anchors_cached::get_anchors,
         crate::api::corridors_cached::list_corridors,
         crate::api::corridors_cached::get_corridor_detail,
+        crate::api::corridors_cached::compare_corridors,
         crate::api::price_feed::get_price,
         crate::api::price_feed::get_prices,
         crate::api::price_feed::convert_to_usd,
         crate::api::price_feed::get_cache_stats,
         crate::api::cost_calculator::estimate_costs,
+        crate::api::asset_leaderboard::get_asset_leaderboard,
+        crate::api::anchor_leaderboard::get_anchor_leaderboard,
+        crate::api::accounts::get_account_summary,
+        crate::api::ingestion_status::get_ingestion_status,
     ),
     components(
         schemas(
             crate::api::anchors_cached::AnchorsResponse,
             crate::api::anchors_cached::AnchorMetricsResponse,
             crate::api::corridors_cached::CorridorResponse,
+            crate::api::corridors_cached::PaginatedCorridorResponse,
+            crate::api::corridors_cached::CorridorSource,
+            crate::api::corridors_cached::CorridorTrend,
+            crate::api::corridors_cached::DataProvenance,
             crate::api::corridors_cached::CorridorDetailResponse,
+            crate::api::corridors_cached::CorridorComparisonEntry,
+            crate::api::corridors_cached::CorridorComparisonError,
             crate::api::corridors_cached::SuccessRateDataPoint,
             crate::api::corridors_cached::LatencyDataPoint,
             crate::api::corridors_cached::LiquidityDataPoint,
@@ -48,17 +59,28 @@ use utoipa::OpenApi;
             crate::api::cost_calculator::RouteEstimate,
             crate::api::cost_calculator::CostCalculationResponse,
             crate::api::cost_calculator::ErrorResponse,
+            crate::api::asset_leaderboard::LeaderboardBy,
+            crate::api::asset_leaderboard::AssetLeaderboardEntry,
+            crate::api::asset_leaderboard::AssetLeaderboardResponse,
+            crate::api::anchor_leaderboard::AnchorLeaderboardEntry,
+            crate::api::anchor_leaderboard::AnchorLeaderboardResponse,
+            crate::api::accounts::AccountSummary,
+            crate::api::ingestion_status::IngestionTaskStatus,
+            crate::api::ingestion_status::IngestionStatusResponse,
         )
     ),
     tags(
         (name = "Anchors", description = "Anchor management and metrics endpoints"),
         (name = "Corridors", description = "Payment corridor analytics endpoints"),
         (name = "Prices", description = "Real-time asset price feed endpoints"),
+        (name = "Assets", description = "Asset rankings by volume and adoption"),
+        (name = "Accounts", description = "Per-account activity summaries"),
         (name = "Cost Calculator", description = "Cross-border payment cost estimation and route comparison"),
         (name = "RPC", description = "Stellar RPC integration endpoints"),
         (name = "Fee Bumps", description = "Fee bump transaction tracking"),
         (name = "Cache", description = "Cache management and statistics"),
-        (name = "Metrics", description = "System metrics and monitoring")
+        (name = "Metrics", description = "System metrics and monitoring"),
+        (name = "Ingestion", description = "Ingestion progress and lag")
     )
 )]
 pub struct ApiDoc;
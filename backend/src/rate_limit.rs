@@ -1,6 +1,6 @@
 use axum::{
     extract::{ConnectInfo, Request, State},
-    http::{header, StatusCode},
+    http::header,
     middleware::Next,
     response::{IntoResponse, Response},
 };
@@ -10,6 +10,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+use crate::error::ApiError;
 use crate::models::api_key::hash_api_key;
 
 /// Rate limit configuration for an endpoint
@@ -129,6 +130,16 @@ impl RateLimiter {
         })
     }
 
+    /// PING the rate limiter's Redis connection
+    pub async fn ping_redis(&self) -> anyhow::Result<()> {
+        let conn = self.redis_connection.read().await.clone();
+        let mut conn = conn.ok_or_else(|| anyhow::anyhow!("Redis not connected"))?;
+        redis::cmd("PING")
+            .query_async::<_, String>(&mut conn)
+            .await?;
+        Ok(())
+    }
+
     /// Register a rate limit config for an endpoint
     pub async fn register_endpoint(&self, path: String, config: RateLimitConfig) {
         self.endpoint_configs.write().await.insert(path, config);
@@ -265,7 +276,7 @@ impl RateLimiter {
         // Try Redis first
         if let Some(conn) = self.redis_connection.read().await.as_ref() {
             let mut conn = conn.clone();
-            match self.check_redis_limit(&mut conn, &key, limit).await {
+            match self.check_redis_limit(&mut conn, &key, limit, 60).await {
                 Ok((allowed, remaining, reset)) => {
                     return (
                         allowed,
@@ -283,7 +294,7 @@ impl RateLimiter {
         }
 
         // Fall back to memory store
-        let (allowed, remaining, reset) = self.check_memory_limit(&key, limit).await;
+        let (allowed, remaining, reset) = self.check_memory_limit(&key, limit, 60).await;
         (
             allowed,
             RateLimitInfo {
@@ -303,12 +314,49 @@ impl RateLimiter {
             .await
     }
 
+    /// Allow `limit` calls per `window_secs` for an arbitrary key, independent
+    /// of the per-minute endpoint configs
+    pub async fn check_keyed_limit(
+        &self,
+        key: &str,
+        limit: u32,
+        window_secs: u32,
+    ) -> (bool, RateLimitInfo) {
+        let key = format!("ratelimit:{}", key);
+
+        let redis_result = match self.redis_connection.read().await.as_ref() {
+            Some(conn) => {
+                let mut conn = conn.clone();
+                self.check_redis_limit(&mut conn, &key, limit, window_secs)
+                    .await
+                    .ok()
+            }
+            None => None,
+        };
+        let (allowed, remaining, reset) = match redis_result {
+            Some(result) => result,
+            None => self.check_memory_limit(&key, limit, window_secs).await,
+        };
+
+        (
+            allowed,
+            RateLimitInfo {
+                limit,
+                remaining,
+                reset_after: reset,
+                is_whitelisted: false,
+                client_id: None,
+            },
+        )
+    }
+
     /// Check rate limit in Redis
     async fn check_redis_limit(
         &self,
         conn: &mut MultiplexedConnection,
         key: &str,
         limit: u32,
+        window_secs: u32,
     ) -> anyhow::Result<(bool, u32, u32), Box<dyn std::error::Error + Send + Sync>> {
         use redis::AsyncCommands;
 
@@ -316,26 +364,32 @@ impl RateLimiter {
         let ttl: i64 = conn.ttl(key).await.unwrap_or(-1);
 
         if current >= limit {
-            return Ok((false, 0, if ttl > 0 { ttl as u32 } else { 60 }));
+            return Ok((false, 0, if ttl > 0 { ttl as u32 } else { window_secs }));
         }
 
         let new_count = current + 1;
         conn.incr::<_, _, ()>(key, 1).await?;
 
         if current == 0 {
-            conn.expire::<_, ()>(key, 60).await?;
+            conn.expire::<_, ()>(key, window_secs as i64).await?;
         }
 
-        let remaining = if new_count >= limit {
-            0
+        let remaining = limit.saturating_sub(new_count);
+        let reset = if current > 0 && ttl > 0 {
+            ttl as u32
         } else {
-            limit - new_count
+            window_secs
         };
-        Ok((new_count < limit, remaining, 60))
+        Ok((true, remaining, reset))
     }
 
     /// Check rate limit in memory (fallback)
-    async fn check_memory_limit(&self, key: &str, limit: u32) -> (bool, u32, u32) {
+    async fn check_memory_limit(
+        &self,
+        key: &str,
+        limit: u32,
+        window_secs: u32,
+    ) -> (bool, u32, u32) {
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
@@ -343,23 +397,19 @@ impl RateLimiter {
 
         let mut store = self.fallback_memory_store.write().await;
 
-        let (count, expiry) = store.get(key).copied().unwrap_or((0, now + 60));
+        let window = window_secs as i64;
+        let (count, expiry) = store.get(key).copied().unwrap_or((0, now + window));
 
         if now > expiry {
             // Reset counter
-            store.insert(key.to_string(), (1, now + 60));
-            (true, limit - 1, 60)
+            store.insert(key.to_string(), (1, now + window));
+            (true, limit.saturating_sub(1), window_secs)
         } else if count >= limit {
             (false, 0, (expiry - now) as u32)
         } else {
             let new_count = count + 1;
             store.insert(key.to_string(), (new_count, expiry));
-            let remaining = if new_count >= limit {
-                0
-            } else {
-                limit - new_count
-            };
-            (new_count < limit, remaining, (expiry - now) as u32)
+            (true, limit.saturating_sub(new_count), (expiry - now) as u32)
         }
     }
 }
@@ -382,20 +432,23 @@ pub struct RateLimitError {
 
 impl IntoResponse for RateLimitError {
     fn into_response(self) -> Response {
-        let body = serde_json::json!({
-            "error": "Rate limit exceeded",
-            "limit": self.info.limit,
-            "reset_after": self.info.reset_after,
-        });
+        let details = HashMap::from([
+            ("limit".to_string(), serde_json::json!(self.info.limit)),
+            (
+                "reset_after".to_string(),
+                serde_json::json!(self.info.reset_after),
+            ),
+        ]);
+        let error = ApiError::rate_limited("RATE_LIMIT_EXCEEDED", "Rate limit exceeded")
+            .with_details(details);
 
         (
-            StatusCode::TOO_MANY_REQUESTS,
             [
                 ("RateLimit-Limit", self.info.limit.to_string()),
                 ("RateLimit-Remaining", self.info.remaining.to_string()),
                 ("RateLimit-Reset", self.info.reset_after.to_string()),
             ],
-            axum::Json(body),
+            error,
         )
             .into_response()
     }
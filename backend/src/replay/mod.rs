@@ -19,6 +19,7 @@ pub mod engine;
 pub mod event_processor;
 pub mod state_builder;
 pub mod storage;
+pub mod webhook_guard;
 
 pub use checkpoint::{Checkpoint, CheckpointManager};
 pub use config::{ReplayConfig, ReplayMode, ReplayRange};
@@ -26,6 +27,7 @@ pub use engine::ReplayEngine;
 pub use event_processor::{EventProcessor, ProcessingContext, ProcessingResult};
 pub use state_builder::StateBuilder;
 pub use storage::{EventStorage, ReplayStorage};
+pub use webhook_guard::{DeliveryRejection, WebhookReplayGuard};
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
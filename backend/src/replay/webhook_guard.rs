@@ -0,0 +1,219 @@
+//! Webhook Replay Protection
+//!
+//! Verifies signed webhook deliveries: the HMAC must cover the timestamp, nonce
+//! and body, the timestamp must fall inside a configurable window, and each
+//! nonce may only be seen once while it could still pass the window check.
+
+use async_trait::async_trait;
+use redis::aio::MultiplexedConnection;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+use crate::webhooks::WebhookSignature;
+
+/// Default maximum age of a delivery before it is rejected
+pub const DEFAULT_REPLAY_WINDOW_SECS: u64 = 300;
+
+/// Why a webhook delivery was rejected
+#[derive(Debug, thiserror::Error)]
+pub enum DeliveryRejection {
+    #[error("Signature does not match payload")]
+    InvalidSignature,
+
+    #[error("Timestamp {timestamp} is outside the {window_secs}s replay window")]
+    Expired { timestamp: i64, window_secs: u64 },
+
+    #[error("Nonce already used: {0}")]
+    ReplayedNonce(String),
+
+    #[error("Nonce store error: {0}")]
+    StoreError(String),
+}
+
+/// Records nonces so a delivery cannot be accepted twice
+#[async_trait]
+pub trait NonceStore: Send + Sync {
+    /// Remember `nonce` for `ttl`; returns false if it was already present
+    async fn insert_if_absent(&self, nonce: &str, ttl: Duration) -> anyhow::Result<bool>;
+}
+
+/// Redis-backed nonce store using `SET NX EX`
+pub struct RedisNonceStore {
+    connection: Arc<RwLock<Option<MultiplexedConnection>>>,
+}
+
+impl RedisNonceStore {
+    pub fn new(connection: Arc<RwLock<Option<MultiplexedConnection>>>) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait]
+impl NonceStore for RedisNonceStore {
+    async fn insert_if_absent(&self, nonce: &str, ttl: Duration) -> anyhow::Result<bool> {
+        let Some(mut conn) = self.connection.read().await.clone() else {
+            // Without Redis a nonce cannot be checked, so fail closed
+            anyhow::bail!("Redis is not connected");
+        };
+        let key = format!("webhook:nonce:{}", nonce);
+        let stored: Option<String> = redis::cmd("SET")
+            .arg(&key)
+            .arg("1")
+            .arg("NX")
+            .arg("EX")
+            .arg(ttl.as_secs().max(1))
+            .query_async(&mut conn)
+            .await?;
+        Ok(stored.is_some())
+    }
+}
+
+/// Process-local nonce store for tests and single-instance deployments
+#[derive(Default)]
+pub struct InMemoryNonceStore {
+    nonces: Mutex<HashMap<String, Instant>>,
+}
+
+#[async_trait]
+impl NonceStore for InMemoryNonceStore {
+    async fn insert_if_absent(&self, nonce: &str, ttl: Duration) -> anyhow::Result<bool> {
+        let now = Instant::now();
+        let mut nonces = self.nonces.lock().unwrap_or_else(|e| e.into_inner());
+        nonces.retain(|_, expires_at| *expires_at > now);
+        if nonces.contains_key(nonce) {
+            return Ok(false);
+        }
+        nonces.insert(nonce.to_string(), now + ttl);
+        Ok(true)
+    }
+}
+
+/// Verifies incoming webhook deliveries against replay attacks
+pub struct WebhookReplayGuard {
+    secret: String,
+    window: Duration,
+    nonces: Arc<dyn NonceStore>,
+}
+
+impl WebhookReplayGuard {
+    pub fn new(secret: impl Into<String>, window: Duration, nonces: Arc<dyn NonceStore>) -> Self {
+        Self {
+            secret: secret.into(),
+            window,
+            nonces,
+        }
+    }
+
+    /// Build from `WEBHOOK_SIGNING_SECRET` and `WEBHOOK_REPLAY_WINDOW_SECS`
+    pub fn from_env(nonces: Arc<dyn NonceStore>) -> anyhow::Result<Self> {
+        let secret = std::env::var("WEBHOOK_SIGNING_SECRET")
+            .map_err(|_| anyhow::anyhow!("WEBHOOK_SIGNING_SECRET is not set"))?;
+        let window_secs = std::env::var("WEBHOOK_REPLAY_WINDOW_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_REPLAY_WINDOW_SECS);
+        Ok(Self::new(secret, Duration::from_secs(window_secs), nonces))
+    }
+
+    /// Verify a delivery received at `now` (unix seconds).
+    ///
+    /// The signature is checked before the nonce is recorded so forged
+    /// requests cannot burn nonces belonging to genuine deliveries.
+    pub async fn verify(
+        &self,
+        body: &str,
+        timestamp: i64,
+        nonce: &str,
+        signature: &str,
+        now: i64,
+    ) -> Result<(), DeliveryRejection> {
+        if !WebhookSignature::verify_timestamped(body, timestamp, nonce, &self.secret, signature) {
+            return Err(DeliveryRejection::InvalidSignature);
+        }
+
+        let window_secs = self.window.as_secs();
+        if now.abs_diff(timestamp) > window_secs {
+            return Err(DeliveryRejection::Expired {
+                timestamp,
+                window_secs,
+            });
+        }
+
+        // Keep the nonce for twice the window: a timestamp can be up to one
+        // window in the future and still be accepted one window later
+        let inserted = self
+            .nonces
+            .insert_if_absent(nonce, self.window * 2)
+            .await
+            .map_err(|e| DeliveryRejection::StoreError(e.to_string()))?;
+        if !inserted {
+            return Err(DeliveryRejection::ReplayedNonce(nonce.to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "test-signing-secret";
+    const BODY: &str = r#"{"event":"payment.created"}"#;
+
+    fn guard() -> WebhookReplayGuard {
+        WebhookReplayGuard::new(
+            SECRET,
+            Duration::from_secs(300),
+            Arc::new(InMemoryNonceStore::default()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_valid_delivery_is_accepted() {
+        let now = chrono::Utc::now().timestamp();
+        let signature = WebhookSignature::sign_timestamped(BODY, now, "nonce-1", SECRET);
+
+        assert!(guard()
+            .verify(BODY, now, "nonce-1", &signature, now + 5)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_expired_timestamp_is_rejected() {
+        let now = chrono::Utc::now().timestamp();
+        let sent_at = now - 301;
+        let signature = WebhookSignature::sign_timestamped(BODY, sent_at, "nonce-2", SECRET);
+
+        let result = guard()
+            .verify(BODY, sent_at, "nonce-2", &signature, now)
+            .await;
+        assert!(matches!(result, Err(DeliveryRejection::Expired { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_replayed_nonce_is_rejected() {
+        let guard = guard();
+        let now = chrono::Utc::now().timestamp();
+        let signature = WebhookSignature::sign_timestamped(BODY, now, "nonce-3", SECRET);
+
+        guard
+            .verify(BODY, now, "nonce-3", &signature, now)
+            .await
+            .unwrap();
+        let replay = guard.verify(BODY, now, "nonce-3", &signature, now).await;
+        assert!(matches!(replay, Err(DeliveryRejection::ReplayedNonce(_))));
+    }
+
+    #[tokio::test]
+    async fn test_tampered_timestamp_fails_signature() {
+        let now = chrono::Utc::now().timestamp();
+        let signature = WebhookSignature::sign_timestamped(BODY, now - 1000, "nonce-4", SECRET);
+
+        let result = guard().verify(BODY, now, "nonce-4", &signature, now).await;
+        assert!(matches!(result, Err(DeliveryRejection::InvalidSignature)));
+    }
+}
@@ -4,6 +4,7 @@
 //! fail fast. After a timeout, the circuit moves to half-open and allows
 //! a limited number of test requests; success closes the circuit.
 
+use serde::Serialize;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
@@ -32,9 +33,37 @@ impl Default for CircuitBreakerConfig {
 
 #[derive(Debug, Clone)]
 enum CircuitState {
-    Closed { failure_count: u32 },
-    Open { opened_at: Instant },
-    HalfOpen { success_count: u32 },
+    Closed {
+        failure_count: u32,
+    },
+    Open {
+        opened_at: Instant,
+        failure_count: u32,
+    },
+    HalfOpen {
+        success_count: u32,
+    },
+}
+
+/// Externally visible state of a circuit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CircuitBreakerState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+/// Point-in-time view of a circuit breaker
+#[derive(Debug, Clone, Serialize)]
+pub struct CircuitBreakerStatus {
+    pub endpoint: String,
+    pub state: CircuitBreakerState,
+    /// Consecutive failures while closed, or the failures that opened the circuit
+    pub failure_count: u32,
+    /// Successful trial requests while half-open
+    pub success_count: u32,
+    /// Milliseconds until an open circuit lets a trial request through
+    pub next_half_open_in_ms: Option<u64>,
 }
 
 /// Circuit breaker for a single logical endpoint (e.g. Horizon API).
@@ -54,6 +83,50 @@ impl CircuitBreaker {
         }
     }
 
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    pub fn config(&self) -> &CircuitBreakerConfig {
+        &self.config
+    }
+
+    /// Current state, without moving an expired open circuit to half-open
+    pub async fn status(&self) -> CircuitBreakerStatus {
+        let state = self.state.lock().await.clone();
+        let (state, failure_count, success_count, next_half_open_in_ms) = match state {
+            CircuitState::Closed { failure_count } => {
+                (CircuitBreakerState::Closed, failure_count, 0, None)
+            }
+            CircuitState::Open {
+                opened_at,
+                failure_count,
+            } => {
+                let remaining = self
+                    .config
+                    .timeout_duration
+                    .saturating_sub(opened_at.elapsed());
+                (
+                    CircuitBreakerState::Open,
+                    failure_count,
+                    0,
+                    Some(remaining.as_millis() as u64),
+                )
+            }
+            CircuitState::HalfOpen { success_count } => {
+                (CircuitBreakerState::HalfOpen, 0, success_count, None)
+            }
+        };
+
+        CircuitBreakerStatus {
+            endpoint: self.endpoint.clone(),
+            state,
+            failure_count,
+            success_count,
+            next_half_open_in_ms,
+        }
+    }
+
     /// Run an operation through the circuit breaker.
     /// Returns CircuitBreakerOpen if the circuit is open.
     pub async fn call<F, Fut, T>(&self, f: F) -> Result<T, RpcError>
@@ -86,7 +159,7 @@ impl CircuitBreaker {
         let now = Instant::now();
 
         match &*state {
-            CircuitState::Open { opened_at } => {
+            CircuitState::Open { opened_at, .. } => {
                 if now.duration_since(*opened_at) >= self.config.timeout_duration {
                     *state = CircuitState::HalfOpen { success_count: 0 };
                     metrics::set_circuit_breaker_state(&self.endpoint, 2); // half-open
@@ -129,6 +202,7 @@ impl CircuitBreaker {
                     metrics::set_circuit_breaker_state(&self.endpoint, 1); // open
                     CircuitState::Open {
                         opened_at: Instant::now(),
+                        failure_count: failure_count + 1,
                     }
                 } else {
                     CircuitState::Closed {
@@ -140,6 +214,7 @@ impl CircuitBreaker {
                 metrics::set_circuit_breaker_state(&self.endpoint, 1);
                 CircuitState::Open {
                     opened_at: Instant::now(),
+                    failure_count: 1,
                 }
             }
             other => other,
@@ -187,6 +262,31 @@ mod tests {
         assert!(matches!(r, Err(RpcError::CircuitBreakerOpen)));
     }
 
+    #[tokio::test]
+    async fn status_reports_failures_and_time_until_half_open() {
+        let cb = CircuitBreaker::new(test_config(), "test");
+        let status = cb.status().await;
+        assert_eq!(status.state, CircuitBreakerState::Closed);
+        assert_eq!(status.failure_count, 0);
+        assert_eq!(status.next_half_open_in_ms, None);
+
+        for expected in 1..=2 {
+            let _: Result<(), _> = cb
+                .call(|| async {
+                    Err(RpcError::ServerError {
+                        status: 503,
+                        message: "x".into(),
+                    })
+                })
+                .await;
+            assert_eq!(cb.status().await.failure_count, expected);
+        }
+
+        let status = cb.status().await;
+        assert_eq!(status.state, CircuitBreakerState::Open);
+        assert!(status.next_half_open_in_ms.unwrap() <= 1000);
+    }
+
     #[tokio::test]
     async fn non_retryable_error_does_not_increment_failure_count() {
         let config = CircuitBreakerConfig {
@@ -1,8 +1,10 @@
 //! RPC client configuration from environment.
 
+use std::collections::HashMap;
 use std::time::Duration;
 
 use super::circuit_breaker::CircuitBreakerConfig;
+use super::error::RetryConfig;
 
 /// Load circuit breaker and retry config from environment with defaults.
 pub fn circuit_breaker_config_from_env() -> CircuitBreakerConfig {
@@ -51,3 +53,70 @@ pub fn max_backoff_from_env() -> Duration {
         .unwrap_or(5000);
     Duration::from_millis(ms)
 }
+
+/// Retry policy built from RPC_MAX_RETRIES, RPC_INITIAL_BACKOFF_MS and RPC_MAX_BACKOFF_MS.
+pub fn retry_config_from_env() -> RetryConfig {
+    RetryConfig {
+        max_attempts: max_retries_from_env().saturating_add(1),
+        base_delay_ms: initial_backoff_from_env().as_millis() as u64,
+        max_delay_ms: max_backoff_from_env().as_millis() as u64,
+    }
+}
+
+/// Per-operation HTTP timeouts for RPC and Horizon requests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestTimeouts {
+    pub health: Duration,
+    pub payments: Duration,
+    pub trades: Duration,
+    pub ledgers: Duration,
+}
+
+impl Default for RequestTimeouts {
+    fn default() -> Self {
+        let timeout = Duration::from_secs(30);
+        Self {
+            health: timeout,
+            payments: timeout,
+            trades: timeout,
+            ledgers: timeout,
+        }
+    }
+}
+
+fn timeout_from_env(var: &str, default: Duration) -> Duration {
+    std::env::var(var)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(default)
+}
+
+/// Request timeouts from RPC_{HEALTH,PAYMENTS,TRADES,LEDGERS}_TIMEOUT_SECONDS (default 30 each).
+pub fn request_timeouts_from_env() -> RequestTimeouts {
+    let defaults = RequestTimeouts::default();
+    RequestTimeouts {
+        health: timeout_from_env("RPC_HEALTH_TIMEOUT_SECONDS", defaults.health),
+        payments: timeout_from_env("RPC_PAYMENTS_TIMEOUT_SECONDS", defaults.payments),
+        trades: timeout_from_env("RPC_TRADES_TIMEOUT_SECONDS", defaults.trades),
+        ledgers: timeout_from_env("RPC_LEDGERS_TIMEOUT_SECONDS", defaults.ledgers),
+    }
+}
+
+/// Headers sent with every RPC and Horizon request, such as a provider API key,
+/// from RPC_DEFAULT_HEADERS (default: none).
+pub fn default_headers_from_env() -> HashMap<String, String> {
+    std::env::var("RPC_DEFAULT_HEADERS")
+        .map(|value| parse_default_headers(&value))
+        .unwrap_or_default()
+}
+
+/// Parse comma-separated `Name=value` pairs, skipping entries without a name
+pub fn parse_default_headers(value: &str) -> HashMap<String, String> {
+    value
+        .split(',')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .filter(|(name, _)| !name.is_empty())
+        .collect()
+}
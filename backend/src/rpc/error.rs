@@ -48,6 +48,14 @@ impl RpcError {
         )
     }
 
+    /// Server-requested wait before retrying (`Retry-After` on a 429)
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            RpcError::RateLimitError { retry_after } => *retry_after,
+            _ => None,
+        }
+    }
+
     pub fn categorize(err: &str) -> Self {
         let lowered = err.to_ascii_lowercase();
         if lowered.contains("timeout") || lowered.contains("timed out") {
@@ -82,11 +90,15 @@ impl RpcError {
 }
 
 use crate::rpc::circuit_breaker::CircuitBreaker;
+use rand::Rng;
 
 #[derive(Debug, Clone)]
 pub struct RetryConfig {
+    /// Total attempts, including the first one
     pub max_attempts: u32,
+    /// Backoff before the first retry, doubled on each further retry
     pub base_delay_ms: u64,
+    /// Ceiling for any single backoff, including a server's Retry-After
     pub max_delay_ms: u64,
 }
 
@@ -100,6 +112,24 @@ impl Default for RetryConfig {
     }
 }
 
+impl RetryConfig {
+    /// Exponential backoff for the given (1-based) failed attempt, capped at `max_delay_ms`
+    pub fn backoff_ceiling_ms(&self, attempt: u32) -> u64 {
+        self.base_delay_ms
+            .saturating_mul(2u64.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_delay_ms)
+    }
+
+    /// Full-jitter delay: uniformly random in `[0, backoff_ceiling_ms(attempt)]`
+    ///
+    /// Spreads retries out so clients recovering from the same outage do not
+    /// hit Horizon in lockstep.
+    pub fn jittered_delay(&self, attempt: u32) -> Duration {
+        let ceiling = self.backoff_ceiling_ms(attempt);
+        Duration::from_millis(rand::thread_rng().gen_range(0..=ceiling))
+    }
+}
+
 pub async fn with_retry<F, Fut, T>(
     operation: F,
     config: RetryConfig,
@@ -123,14 +153,15 @@ where
                     return Err(e);
                 }
 
-                let delay = std::cmp::min(
-                    config
-                        .base_delay_ms
-                        .saturating_mul(2u64.saturating_pow(attempt.saturating_sub(1))),
-                    config.max_delay_ms,
-                );
-
-                tokio::time::sleep(Duration::from_millis(delay)).await;
+                // Honour the server's Retry-After over our own backoff, but
+                // never wait longer than `max_delay_ms`
+                let delay = match e.retry_after() {
+                    Some(retry_after) => {
+                        retry_after.min(Duration::from_millis(config.max_delay_ms))
+                    }
+                    None => config.jittered_delay(attempt),
+                };
+                tokio::time::sleep(delay).await;
             }
         }
     }
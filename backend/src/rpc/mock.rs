@@ -0,0 +1,340 @@
+//! Configurable, seeded mock data for `StellarRpcClient` in mock mode
+//!
+//! Every record is drawn from an RNG seeded by the config's seed and the
+//! record's index, so a given seed always yields the same series and a longer
+//! series extends a shorter one.
+
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use super::error::RpcError;
+use super::stellar::{Payment, Price, Trade};
+
+/// Separate RNG streams so payments, trades and calls don't share draws
+const PAYMENT_STREAM: u64 = 1;
+const TRADE_STREAM: u64 = 2;
+const CALL_STREAM: u64 = 3;
+
+/// An asset in the mock mix, picked with probability proportional to `weight`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockAsset {
+    pub code: String,
+    /// None for lumens
+    pub issuer: Option<String>,
+    pub weight: u32,
+}
+
+impl MockAsset {
+    pub fn native(weight: u32) -> Self {
+        Self {
+            code: "XLM".to_string(),
+            issuer: None,
+            weight,
+        }
+    }
+
+    pub fn credit(code: &str, issuer: &str, weight: u32) -> Self {
+        Self {
+            code: code.to_string(),
+            issuer: Some(issuer.to_string()),
+            weight,
+        }
+    }
+
+    fn asset_type(&self) -> &'static str {
+        match (&self.issuer, self.code.len()) {
+            (None, _) => "native",
+            (Some(_), 0..=4) => "credit_alphanum4",
+            (Some(_), _) => "credit_alphanum12",
+        }
+    }
+}
+
+/// Delay added to each mock call
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum MockLatency {
+    #[default]
+    None,
+    Fixed(Duration),
+    /// Drawn uniformly from `[min, max]`
+    Uniform {
+        min: Duration,
+        max: Duration,
+    },
+}
+
+/// Shape of the data a mock-mode client returns
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockConfig {
+    pub seed: u64,
+    /// Assets payments deliver and trades are quoted in
+    pub assets: Vec<MockAsset>,
+    /// Fraction of payments that are path payments, between 0 and 1
+    pub path_payment_ratio: f64,
+    pub latency: MockLatency,
+    /// Fraction of mock calls that fail with a network error, between 0 and 1
+    pub failure_rate: f64,
+}
+
+impl Default for MockConfig {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            assets: vec![
+                MockAsset::native(4),
+                MockAsset::credit(
+                    "USDC",
+                    "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN",
+                    3,
+                ),
+                MockAsset::credit(
+                    "EURC",
+                    "GDHU6WRG4IEQXM5NZ4BMPKOXHW76MZM4Y2IEMFDVXBSDP6SJY4ITNPP2",
+                    2,
+                ),
+                MockAsset::credit(
+                    "NGNT",
+                    "GAWODAROMJ33V5YDFY3NPYTHVYQG7MJXVJ2ND3AOGIHYRWINES6ACCPD",
+                    1,
+                ),
+            ],
+            path_payment_ratio: 0.2,
+            latency: MockLatency::None,
+            failure_rate: 0.0,
+        }
+    }
+}
+
+impl MockConfig {
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            ..Self::default()
+        }
+    }
+}
+
+/// Generates records and simulated call outcomes from a `MockConfig`
+#[derive(Debug)]
+pub(crate) struct MockGenerator {
+    config: MockConfig,
+    assets: Option<WeightedIndex<u32>>,
+    calls: AtomicU64,
+}
+
+impl MockGenerator {
+    pub(crate) fn new(config: MockConfig) -> Self {
+        let assets = WeightedIndex::new(config.assets.iter().map(|asset| asset.weight)).ok();
+        Self {
+            config,
+            assets,
+            calls: AtomicU64::new(0),
+        }
+    }
+
+    fn rng(&self, stream: u64, index: u64) -> StdRng {
+        let seed = self
+            .config
+            .seed
+            .wrapping_mul(0x9E37_79B9_7F4A_7C15)
+            .wrapping_add(stream.wrapping_mul(0xBF58_476D_1CE4_E5B9))
+            .wrapping_add(index);
+        StdRng::seed_from_u64(seed)
+    }
+
+    fn pick_asset(&self, rng: &mut StdRng) -> MockAsset {
+        match &self.assets {
+            Some(index) => self.config.assets[index.sample(rng)].clone(),
+            None => MockAsset::native(1),
+        }
+    }
+
+    /// Wait out the configured latency, then fail at the configured rate
+    pub(crate) async fn simulate_call(&self) -> Result<(), RpcError> {
+        let call = self.calls.fetch_add(1, Ordering::Relaxed);
+        let mut rng = self.rng(CALL_STREAM, call);
+
+        let delay = match self.config.latency {
+            MockLatency::None => Duration::ZERO,
+            MockLatency::Fixed(delay) => delay,
+            MockLatency::Uniform { min, max } if max > min => rng.gen_range(min..=max),
+            MockLatency::Uniform { min, .. } => min,
+        };
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+
+        if rng.gen_bool(self.config.failure_rate.clamp(0.0, 1.0)) {
+            return Err(RpcError::NetworkError("simulated mock failure".to_string()));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn payments(&self, limit: u32) -> Vec<Payment> {
+        (0..limit).map(|i| self.payment(i)).collect()
+    }
+
+    fn payment(&self, i: u32) -> Payment {
+        let mut rng = self.rng(PAYMENT_STREAM, i as u64);
+        let destination_asset = self.pick_asset(&mut rng);
+        let is_path_payment = rng.gen_bool(self.config.path_payment_ratio.clamp(0.0, 1.0));
+        let source_asset = if is_path_payment {
+            Some(self.pick_asset(&mut rng))
+        } else {
+            None
+        };
+        let amount: f64 = rng.gen_range(1.0..10_000.0);
+        let source_account = format!(
+            "GXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX{:03}",
+            i % 1000
+        );
+        let destination = format!(
+            "GDYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYY{:03}",
+            i % 1000
+        );
+
+        Payment {
+            id: format!("payment_{}", i),
+            paging_token: format!("paging_{}", i),
+            transaction_hash: format!("txhash_{}", i),
+            source_account: source_account.clone(),
+            destination: destination.clone(),
+            asset_type: destination_asset.asset_type().to_string(),
+            asset_code: destination_asset
+                .issuer
+                .as_ref()
+                .map(|_| destination_asset.code.clone()),
+            asset_issuer: destination_asset.issuer.clone(),
+            amount: format!("{:.7}", amount),
+            created_at: format!("2026-01-22T{:02}:{:02}:00Z", (i / 60) % 24, i % 60),
+            operation_type: Some(if is_path_payment {
+                "path_payment_strict_send".to_string()
+            } else {
+                "payment".to_string()
+            }),
+            source_asset_type: source_asset
+                .as_ref()
+                .map(|asset| asset.asset_type().to_string()),
+            source_asset_code: source_asset
+                .as_ref()
+                .and_then(|asset| asset.issuer.as_ref().map(|_| asset.code.clone())),
+            source_asset_issuer: source_asset.as_ref().and_then(|asset| asset.issuer.clone()),
+            source_amount: source_asset
+                .as_ref()
+                .map(|_| format!("{:.7}", amount * rng.gen_range(0.9..1.1))),
+            from: Some(source_account),
+            to: Some(destination),
+            asset_balance_changes: None,
+        }
+    }
+
+    pub(crate) fn trades(&self, limit: u32) -> Vec<Trade> {
+        (0..limit).map(|i| self.trade(i)).collect()
+    }
+
+    fn trade(&self, i: u32) -> Trade {
+        let mut rng = self.rng(TRADE_STREAM, i as u64);
+        let base = self.pick_asset(&mut rng);
+        let counter = self.pick_asset(&mut rng);
+        let base_amount: f64 = rng.gen_range(1.0..10_000.0);
+        let price_n: i64 = rng.gen_range(1..1_000);
+        let price_d: i64 = rng.gen_range(1..1_000);
+
+        Trade {
+            id: format!("trade_{}", i),
+            ledger_close_time: format!("2026-01-22T{:02}:{:02}:00Z", (i / 60) % 24, i % 60),
+            base_account: format!(
+                "GXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX{:03}",
+                i % 1000
+            ),
+            base_amount: format!("{:.7}", base_amount),
+            base_asset_type: base.asset_type().to_string(),
+            base_asset_code: base.issuer.as_ref().map(|_| base.code.clone()),
+            base_asset_issuer: base.issuer.clone(),
+            counter_account: format!(
+                "GDYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYYY{:03}",
+                i % 1000
+            ),
+            counter_amount: format!("{:.7}", base_amount * price_n as f64 / price_d as f64),
+            counter_asset_type: counter.asset_type().to_string(),
+            counter_asset_code: counter.issuer.as_ref().map(|_| counter.code.clone()),
+            counter_asset_issuer: counter.issuer.clone(),
+            price: Price {
+                n: price_n,
+                d: price_d,
+            },
+            trade_type: "orderbook".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_longer_series_extends_shorter_one() {
+        let generator = MockGenerator::new(MockConfig::with_seed(7));
+        let short: Vec<_> = generator
+            .payments(10)
+            .into_iter()
+            .map(|p| p.amount)
+            .collect();
+        let long: Vec<_> = generator
+            .payments(20)
+            .into_iter()
+            .map(|p| p.amount)
+            .collect();
+        assert_eq!(short[..], long[..10]);
+    }
+
+    #[test]
+    fn test_path_payment_ratio_is_respected() {
+        let generator = MockGenerator::new(MockConfig {
+            path_payment_ratio: 0.3,
+            ..MockConfig::with_seed(42)
+        });
+        let paths = generator
+            .payments(1_000)
+            .iter()
+            .filter(|p| p.source_asset_type.is_some())
+            .count();
+        assert!((250..=350).contains(&paths), "{} path payments", paths);
+
+        let none = MockGenerator::new(MockConfig {
+            path_payment_ratio: 0.0,
+            ..MockConfig::default()
+        });
+        assert!(none
+            .payments(100)
+            .iter()
+            .all(|p| p.operation_type.as_deref() == Some("payment")));
+    }
+
+    #[test]
+    fn test_asset_mix_limits_assets() {
+        let generator = MockGenerator::new(MockConfig {
+            assets: vec![MockAsset::credit("USDC", "GISSUER", 1)],
+            ..MockConfig::default()
+        });
+        assert!(generator
+            .payments(50)
+            .iter()
+            .all(|p| p.asset_code.as_deref() == Some("USDC")));
+    }
+
+    #[tokio::test]
+    async fn test_failure_rate_extremes() {
+        let failing = MockGenerator::new(MockConfig {
+            failure_rate: 1.0,
+            ..MockConfig::default()
+        });
+        assert!(failing.simulate_call().await.is_err());
+
+        let healthy = MockGenerator::new(MockConfig::default());
+        assert!(healthy.simulate_call().await.is_ok());
+    }
+}
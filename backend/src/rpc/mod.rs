@@ -2,13 +2,14 @@ pub mod circuit_breaker;
 pub mod config;
 pub mod error;
 pub mod metrics;
+pub mod mock;
 pub mod rate_limiter;
 pub mod stellar;
 
 pub use rate_limiter::{RpcRateLimitConfig, RpcRateLimitMetrics, RpcRateLimiter};
 pub use stellar::{
-    Asset, FeeBumpTransactionInfo, GetLedgersResult, HealthResponse, HorizonAsset, HorizonEffect,
-    HorizonLiquidityPool, HorizonOperation, HorizonPoolReserve, HorizonTransaction,
-    InnerTransaction, LedgerInfo, OrderBook, OrderBookEntry, Payment, Price, RpcLedger,
-    StellarRpcClient, Trade,
+    AccountSigner, Asset, FeeBumpTransactionInfo, GetLedgersResult, HealthResponse, HorizonAsset,
+    HorizonEffect, HorizonLiquidityPool, HorizonOperation, HorizonPoolReserve, HorizonTransaction,
+    InnerTransaction, LedgerInfo, OrderBook, OrderBookEntry, Payment, PaymentOutcome, Price,
+    RpcLedger, StellarRpcClient, Trade, TransactionState, TransactionStatus,
 };
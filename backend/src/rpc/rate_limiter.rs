@@ -178,7 +178,9 @@ impl RpcRateLimiter {
         }
     }
 
-    pub async fn on_rate_limited(&self, headers: &HeaderMap) {
+    /// Record a 429 and drain the bucket. Returns how long the caller should
+    /// wait before retrying: the `Retry-After` value, or a default when absent.
+    pub async fn on_rate_limited(&self, headers: &HeaderMap) -> Duration {
         self.rate_limited_responses.fetch_add(1, Ordering::Relaxed);
 
         let wait_seconds =
@@ -190,7 +192,7 @@ impl RpcRateLimiter {
             state.last_refill = Instant::now();
         }
 
-        tokio::time::sleep(Duration::from_secs(wait_seconds)).await;
+        Duration::from_secs(wait_seconds)
     }
 
     pub fn metrics(&self) -> RpcRateLimitMetrics {
@@ -300,10 +302,11 @@ mod tests {
     async fn rate_limited_updates_metrics_and_respects_retry_after() {
         let limiter = RpcRateLimiter::new(RpcRateLimitConfig::default());
         let mut headers = HeaderMap::new();
-        headers.insert("retry-after", HeaderValue::from_static("0"));
+        headers.insert("retry-after", HeaderValue::from_static("7"));
 
-        limiter.on_rate_limited(&headers).await;
+        let wait = limiter.on_rate_limited(&headers).await;
 
+        assert_eq!(wait, Duration::from_secs(7));
         let metrics = limiter.metrics();
         assert_eq!(metrics.rate_limited_responses, 1);
     }
@@ -1,23 +1,27 @@
 use crate::network::{NetworkConfig, StellarNetwork};
 use crate::rpc::circuit_breaker::CircuitBreaker;
 use crate::rpc::config::{
-    circuit_breaker_config_from_env, initial_backoff_from_env, max_backoff_from_env,
-    max_retries_from_env,
+    circuit_breaker_config_from_env, default_headers_from_env, request_timeouts_from_env,
+    retry_config_from_env, RequestTimeouts,
 };
 use crate::rpc::error::{with_retry, RetryConfig, RpcError};
 use crate::rpc::metrics;
+use crate::rpc::mock::{MockConfig, MockGenerator};
 use crate::rpc::rate_limiter::{RpcRateLimitConfig, RpcRateLimitMetrics, RpcRateLimiter};
 use anyhow::{anyhow, Context, Result};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use reqwest::Client;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::collections::HashMap;
+use std::future::Future;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
 use tracing::{debug, info, warn};
 
-const MAX_RETRIES: u32 = 3;
-const INITIAL_BACKOFF_MS: u64 = 100;
-const BACKOFF_MULTIPLIER: u64 = 2;
 const MOCK_OLDEST_LEDGER: u64 = 51_565_760;
 const MOCK_LATEST_LEDGER: u64 = 51_565_820;
 
@@ -36,6 +40,19 @@ const DEFAULT_MAX_TOTAL_RECORDS: u32 = 1000;
 const MIN_PAGINATION_DELAY_MS: u64 = 50;
 /// Default delay between pagination requests
 const DEFAULT_PAGINATION_DELAY_MS: u64 = 100;
+/// Default page fetches in flight for `fetch_all_payments`/`fetch_all_trades`
+const DEFAULT_PAGINATION_CONCURRENCY: usize = 1;
+/// Maximum page fetches in flight for paginated fetches (DoS protection)
+const ABSOLUTE_MAX_PAGINATION_CONCURRENCY: usize = 16;
+/// Most ledgers one concurrent paginated fetch walks back from the latest,
+/// about an hour of history, before paging the rest by cursor. Every ledger
+/// costs at least one request, so sparse records must not turn into a
+/// request per ledger (DoS protection)
+const MAX_CONCURRENT_SCAN_LEDGERS: u32 = 720;
+/// Maximum in-flight ledger requests during a ledger-range fetch
+const LEDGER_RANGE_CONCURRENCY: usize = 4;
+/// Records per page when listing a single ledger's payments or transactions
+const LEDGER_PAGE_LIMIT: usize = 200;
 
 /// Stellar RPC Client for interacting with Stellar network via RPC and Horizon API
 // Asset Models (Horizon API)
@@ -81,6 +98,8 @@ pub struct AssetFlags {
 #[derive(Clone)]
 pub struct StellarRpcClient {
     client: Client,
+    /// Headers sent with every request, including the payment stream's
+    default_headers: HeaderMap,
     rpc_url: String,
     horizon_url: String,
     network_config: NetworkConfig,
@@ -93,12 +112,16 @@ pub struct StellarRpcClient {
     max_total_records: u32,
     /// Delay between pagination requests in milliseconds (default: 100)
     pagination_delay_ms: u64,
-    /// Maximum retries for RPC calls
-    max_retries: u32,
-    /// Initial backoff duration
-    initial_backoff: Duration,
-    /// Maximum backoff duration
-    max_backoff: Duration,
+    /// Page fetches in flight for `fetch_all_payments`/`fetch_all_trades` (default: 1)
+    pagination_concurrency: usize,
+    /// Caps concurrent page fetches across every paginated call on this client
+    pagination_permits: Arc<Semaphore>,
+    /// Retry attempts and jittered backoff bounds for RPC and Horizon calls
+    retry_config: RetryConfig,
+    /// Per-operation overrides of the client's 30s request timeout
+    timeouts: RequestTimeouts,
+    /// Seeded payment and trade generator; the fixed mock series when None
+    mock_generator: Option<Arc<MockGenerator>>,
 }
 
 // ============================================================================
@@ -213,6 +236,21 @@ pub struct Payment {
     pub asset_balance_changes: Option<Vec<AssetBalanceChange>>,
 }
 
+/// A payment tagged with whether its parent transaction succeeded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentOutcome {
+    #[serde(flatten)]
+    pub payment: Payment,
+    /// Horizon's `transaction_successful`; payments from failed transactions
+    /// are only listed when requested with `include_failed=true`
+    #[serde(rename = "transaction_successful", default = "transaction_succeeded")]
+    pub successful: bool,
+}
+
+fn transaction_succeeded() -> bool {
+    true
+}
+
 impl Payment {
     /// Returns the destination account, checking the new `asset_balance_changes`
     /// format first, then falling back to the legacy `destination` / `to` fields.
@@ -321,6 +359,20 @@ pub struct InnerTransaction {
     pub signatures: Vec<String>,
 }
 
+/// A key allowed to sign for an account, from Horizon's `signers` list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountSigner {
+    pub key: String,
+    pub weight: i32,
+    #[serde(rename = "type")]
+    pub signer_type: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct HorizonAccountSigners {
+    signers: Vec<AccountSigner>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Trade {
     pub id: String,
@@ -403,6 +455,40 @@ pub struct GetLedgersResult {
     pub cursor: Option<String>,
 }
 
+/// Where a submitted transaction stands, as reported by RPC `getTransaction`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TransactionState {
+    /// Not (or no longer) within the RPC retention window
+    NotFound,
+    /// Accepted but not yet in a closed ledger
+    Pending,
+    Success,
+    Failed,
+}
+
+/// Result of the RPC `getTransaction` method
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionStatus {
+    pub status: TransactionState,
+    /// Ledger the transaction was applied in, once it has been
+    pub ledger: Option<u64>,
+    #[serde(rename = "resultXdr")]
+    pub result_xdr: Option<String>,
+}
+
+/// Payments collected across a ledger range by `fetch_payments_for_ledger_range`.
+///
+/// When `error` is set the fetch stopped early: `payments` holds everything up
+/// to and including `last_processed`, and the caller can resume from the next
+/// sequence.
+#[derive(Debug, Clone)]
+pub struct LedgerRangePayments {
+    pub payments: Vec<Payment>,
+    pub last_processed: Option<u64>,
+    pub error: Option<RpcError>,
+}
+
 // ============================================================================
 // Liquidity Pool Models (Horizon API)
 // ============================================================================
@@ -454,6 +540,15 @@ fn status_to_rpc_error(
     }
 }
 
+/// Map a failed send, keeping timeouts apart from other network errors
+fn map_send_error(e: reqwest::Error) -> RpcError {
+    if e.is_timeout() {
+        RpcError::TimeoutError(e.to_string())
+    } else {
+        RpcError::NetworkError(e.to_string())
+    }
+}
+
 async fn map_response_error(response: reqwest::Response) -> RpcError {
     let status = response.status();
     let retry_after = response
@@ -468,6 +563,79 @@ async fn map_response_error(response: reqwest::Response) -> RpcError {
     status_to_rpc_error(status, body, retry_after)
 }
 
+/// Parse a Horizon request URL, adding the paging cursor as an encoded query
+/// parameter so tokens from callers cannot inject other parameters
+fn horizon_page_url(url: &str, cursor: Option<&str>) -> Result<reqwest::Url, RpcError> {
+    let mut url = reqwest::Url::parse(url).map_err(|e| RpcError::ParseError(e.to_string()))?;
+    if let Some(cursor) = cursor {
+        url.query_pairs_mut().append_pair("cursor", cursor);
+    }
+    Ok(url)
+}
+
+/// Page fetches in flight from RPC_PAGINATION_CONCURRENCY, capped for DoS
+/// protection
+fn pagination_concurrency_from_env() -> usize {
+    std::env::var("RPC_PAGINATION_CONCURRENCY")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .unwrap_or(DEFAULT_PAGINATION_CONCURRENCY)
+        .clamp(1, ABSOLUTE_MAX_PAGINATION_CONCURRENCY)
+}
+
+/// Ledger a Horizon paging token points into. Tokens are TOIDs (optionally
+/// followed by `-index` for trades), which keep the ledger sequence in their
+/// high 32 bits.
+pub(crate) fn toid_ledger(paging_token: &str) -> Option<u32> {
+    let toid = paging_token.split('-').next()?.parse::<i64>().ok()?;
+    u32::try_from(toid >> 32).ok()
+}
+
+/// TOID of the first operation in `ledger`
+fn ledger_start_toid(ledger: u32) -> i64 {
+    i64::from(ledger) << 32
+}
+
+/// Convert configured headers into a header map, skipping and logging any
+/// name or value that isn't valid in an HTTP header
+fn default_header_map(headers: &HashMap<String, String>) -> HeaderMap {
+    let mut map = HeaderMap::new();
+    for (name, value) in headers {
+        match (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(value),
+        ) {
+            (Ok(name), Ok(value)) => {
+                map.insert(name, value);
+            }
+            _ => warn!("Ignoring invalid default RPC header '{}'", name),
+        }
+    }
+    map
+}
+
+/// HTTP client shared by RPC and Horizon calls
+fn http_client(default_headers: &HeaderMap) -> Client {
+    Client::builder()
+        .timeout(Duration::from_secs(30))
+        .default_headers(default_headers.clone())
+        .build()
+        .expect("Failed to build HTTP client")
+}
+
+fn payment_paging_token(payment: &Payment) -> &str {
+    &payment.paging_token
+}
+
+/// Horizon uses a trade's id as its paging token
+fn trade_paging_token(trade: &Trade) -> &str {
+    &trade.id
+}
+
+fn transaction_paging_token(transaction: &HorizonTransaction) -> &str {
+    &transaction.paging_token
+}
+
 // ============================================================================
 // Implementation
 // ============================================================================
@@ -480,10 +648,44 @@ impl StellarRpcClient {
     /// * `horizon_url` - The Horizon API endpoint URL
     /// * `mock_mode` - If true, returns mock data instead of making real API calls
     pub fn new(rpc_url: String, horizon_url: String, mock_mode: bool) -> Self {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(30))
-            .build()
-            .expect("Failed to build HTTP client");
+        Self::new_with_retry_config(rpc_url, horizon_url, mock_mode, retry_config_from_env())
+    }
+
+    /// Create a new Stellar RPC client with an explicit retry policy
+    ///
+    /// `retry_config.max_delay_ms` caps every backoff; the actual delay is
+    /// drawn uniformly from `[0, backoff]` (full jitter).
+    pub fn new_with_retry_config(
+        rpc_url: String,
+        horizon_url: String,
+        mock_mode: bool,
+        retry_config: RetryConfig,
+    ) -> Self {
+        Self::new_with_headers(
+            rpc_url,
+            horizon_url,
+            mock_mode,
+            retry_config,
+            default_headers_from_env(),
+        )
+    }
+
+    /// Create a new Stellar RPC client that sends `default_headers` with
+    /// every request, e.g. an API key or client identifier a Horizon
+    /// provider requires. Invalid headers are logged and skipped.
+    pub fn new_with_headers(
+        rpc_url: String,
+        horizon_url: String,
+        mock_mode: bool,
+        retry_config: RetryConfig,
+        default_headers: HashMap<String, String>,
+    ) -> Self {
+        let default_headers = default_header_map(&default_headers);
+        if !default_headers.is_empty() {
+            let names: Vec<&str> = default_headers.keys().map(|name| name.as_str()).collect();
+            info!("Sending default RPC headers: {}", names.join(", "));
+        }
+        let client = http_client(&default_headers);
         let rate_limiter = RpcRateLimiter::new(RpcRateLimitConfig::from_env());
 
         // Determine network based on URLs
@@ -546,13 +748,16 @@ impl StellarRpcClient {
             );
         }
 
+        let pagination_concurrency = pagination_concurrency_from_env();
+
         info!(
-            "RPC pagination config: max_per_request={}, max_total={}, delay_ms={}",
-            max_records_per_request, max_total_records, pagination_delay_ms
+            "RPC pagination config: max_per_request={}, max_total={}, delay_ms={}, concurrency={}",
+            max_records_per_request, max_total_records, pagination_delay_ms, pagination_concurrency
         );
 
         Self {
             client,
+            default_headers,
             rpc_url,
             horizon_url,
             network_config,
@@ -562,9 +767,11 @@ impl StellarRpcClient {
             max_records_per_request,
             max_total_records,
             pagination_delay_ms,
-            max_retries: max_retries_from_env(),
-            initial_backoff: initial_backoff_from_env(),
-            max_backoff: max_backoff_from_env(),
+            pagination_concurrency,
+            pagination_permits: Arc::new(Semaphore::new(pagination_concurrency)),
+            retry_config,
+            timeouts: request_timeouts_from_env(),
+            mock_generator: None,
         }
     }
 
@@ -572,10 +779,8 @@ impl StellarRpcClient {
     pub fn new_with_network(network: StellarNetwork, mock_mode: bool) -> Self {
         let network_config = NetworkConfig::for_network(network);
 
-        let client = Client::builder()
-            .timeout(Duration::from_secs(30))
-            .build()
-            .expect("Failed to build HTTP client");
+        let default_headers = default_header_map(&default_headers_from_env());
+        let client = http_client(&default_headers);
         let rate_limiter = RpcRateLimiter::new(RpcRateLimitConfig::from_env());
         let cb_config = circuit_breaker_config_from_env();
         let circuit_breaker = Arc::new(CircuitBreaker::new(cb_config, "rpc"));
@@ -599,8 +804,11 @@ impl StellarRpcClient {
             .unwrap_or(DEFAULT_PAGINATION_DELAY_MS)
             .max(MIN_PAGINATION_DELAY_MS);
 
+        let pagination_concurrency = pagination_concurrency_from_env();
+
         Self {
             client,
+            default_headers,
             rpc_url: network_config.rpc_url.clone(),
             horizon_url: network_config.horizon_url.clone(),
             network_config,
@@ -610,9 +818,11 @@ impl StellarRpcClient {
             max_records_per_request,
             max_total_records,
             pagination_delay_ms,
-            max_retries: max_retries_from_env(),
-            initial_backoff: initial_backoff_from_env(),
-            max_backoff: max_backoff_from_env(),
+            pagination_concurrency,
+            pagination_permits: Arc::new(Semaphore::new(pagination_concurrency)),
+            retry_config: retry_config_from_env(),
+            timeouts: request_timeouts_from_env(),
+            mock_generator: None,
         }
     }
 
@@ -646,18 +856,78 @@ impl StellarRpcClient {
         self.rate_limiter.metrics()
     }
 
+    /// Retry policy used for RPC and Horizon calls
+    pub fn retry_config(&self) -> &RetryConfig {
+        &self.retry_config
+    }
+
+    /// Circuit breaker guarding this client's RPC and Horizon calls
+    pub fn circuit_breaker(&self) -> Arc<CircuitBreaker> {
+        Arc::clone(&self.circuit_breaker)
+    }
+
+    /// Timeouts applied to health, payment, trade and ledger requests
+    pub fn request_timeouts(&self) -> &RequestTimeouts {
+        &self.timeouts
+    }
+
+    /// Override the per-operation request timeouts
+    pub fn with_request_timeouts(mut self, timeouts: RequestTimeouts) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    /// Allow up to `concurrency` page fetches in flight for
+    /// `fetch_all_payments`/`fetch_all_trades`, capped at 16
+    pub fn with_pagination_concurrency(mut self, concurrency: usize) -> Self {
+        let concurrency = concurrency.clamp(1, ABSOLUTE_MAX_PAGINATION_CONCURRENCY);
+        self.pagination_concurrency = concurrency;
+        self.pagination_permits = Arc::new(Semaphore::new(concurrency));
+        self
+    }
+
+    /// Generate mock payments and trades from `config` instead of the fixed
+    /// series. Only affects clients in mock mode.
+    pub fn with_mock_config(mut self, config: MockConfig) -> Self {
+        self.mock_generator = Some(Arc::new(MockGenerator::new(config)));
+        self
+    }
+
+    /// Mock payments from the configured generator, or the fixed series
+    fn mock_payment_series(&self, limit: u32) -> Vec<Payment> {
+        match &self.mock_generator {
+            Some(generator) => generator.payments(limit),
+            None => Self::mock_payments(limit),
+        }
+    }
+
+    /// Mock trades from the configured generator, or the fixed series
+    fn mock_trade_series(&self, limit: u32) -> Vec<Trade> {
+        match &self.mock_generator {
+            Some(generator) => generator.trades(limit),
+            None => Self::mock_trades(limit),
+        }
+    }
+
+    /// Apply the configured mock latency and failure rate
+    async fn simulate_mock_call(&self) -> Result<(), RpcError> {
+        match &self.mock_generator {
+            Some(generator) => generator.simulate_call().await,
+            None => Ok(()),
+        }
+    }
+
     async fn execute_with_retry<F, Fut, T>(&self, operation: F) -> Result<T, RpcError>
     where
         F: Fn() -> Fut,
         Fut: std::future::Future<Output = Result<T, RpcError>>,
     {
-        let retry_config = RetryConfig {
-            max_attempts: self.max_retries + 1,
-            base_delay_ms: self.initial_backoff.as_millis() as u64,
-            max_delay_ms: self.max_backoff.as_millis() as u64,
-        };
-
-        with_retry(operation, retry_config, self.circuit_breaker.clone()).await
+        with_retry(
+            operation,
+            self.retry_config.clone(),
+            self.circuit_breaker.clone(),
+        )
+        .await
     }
 
     /// Check the health of the RPC endpoint
@@ -688,10 +958,11 @@ impl StellarRpcClient {
         let response = self
             .client
             .post(&self.rpc_url)
+            .timeout(self.timeouts.health)
             .json(&payload)
             .send()
             .await
-            .map_err(|e| RpcError::NetworkError(e.to_string()))?;
+            .map_err(map_send_error)?;
 
         if !response.status().is_success() {
             return Err(map_response_error(response).await);
@@ -735,9 +1006,10 @@ impl StellarRpcClient {
         let response = self
             .client
             .get(&url)
+            .timeout(self.timeouts.ledgers)
             .send()
             .await
-            .map_err(|e| RpcError::NetworkError(e.to_string()))?;
+            .map_err(map_send_error)?;
         if !response.status().is_success() {
             return Err(map_response_error(response).await);
         }
@@ -807,10 +1079,11 @@ impl StellarRpcClient {
         let response = self
             .client
             .post(&self.rpc_url)
+            .timeout(self.timeouts.ledgers)
             .json(&payload)
             .send()
             .await
-            .map_err(|e| RpcError::NetworkError(e.to_string()))?;
+            .map_err(map_send_error)?;
         if !response.status().is_success() {
             return Err(map_response_error(response).await);
         }
@@ -829,6 +1102,54 @@ impl StellarRpcClient {
             .ok_or_else(|| RpcError::ParseError("No result in getLedgers response".to_string()))
     }
 
+    /// Look up a submitted transaction by hash via RPC `getTransaction`
+    pub async fn get_transaction(&self, hash: &str) -> Result<TransactionStatus, RpcError> {
+        if self.mock_mode {
+            return Ok(Self::mock_transaction_status());
+        }
+
+        let result = self
+            .execute_with_retry(|| self.get_transaction_internal(hash))
+            .await;
+
+        result.map_err(|e| {
+            metrics::record_rpc_error(e.error_type_label(), "stellar");
+            e
+        })
+    }
+
+    async fn get_transaction_internal(&self, hash: &str) -> Result<TransactionStatus, RpcError> {
+        let payload = json!({
+            "jsonrpc": "2.0",
+            "method": "getTransaction",
+            "id": 1,
+            "params": { "hash": hash }
+        });
+        let response = self
+            .client
+            .post(&self.rpc_url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(map_send_error)?;
+        if !response.status().is_success() {
+            return Err(map_response_error(response).await);
+        }
+        let json_response: JsonRpcResponse<TransactionStatus> = response
+            .json()
+            .await
+            .map_err(|e| RpcError::ParseError(e.to_string()))?;
+        if let Some(error) = json_response.error {
+            return Err(RpcError::ServerError {
+                status: 500,
+                message: format!("RPC error: {} (code: {})", error.message, error.code),
+            });
+        }
+        json_response
+            .result
+            .ok_or_else(|| RpcError::ParseError("No result in getTransaction response".to_string()))
+    }
+
     /// Fetch recent payments
     pub async fn fetch_payments(
         &self,
@@ -836,7 +1157,8 @@ impl StellarRpcClient {
         cursor: Option<&str>,
     ) -> Result<Vec<Payment>, RpcError> {
         if self.mock_mode {
-            return Ok(Self::mock_payments(limit));
+            self.simulate_mock_call().await?;
+            return Ok(self.mock_payment_series(limit));
         }
 
         info!("Fetching {} payments from Horizon API", limit);
@@ -856,16 +1178,146 @@ impl StellarRpcClient {
         limit: u32,
         cursor: Option<&str>,
     ) -> Result<Vec<Payment>, RpcError> {
-        let mut url = format!("{}/payments?order=desc&limit={}", self.horizon_url, limit);
-        if let Some(c) = cursor {
-            url.push_str(&format!("&cursor={}", c));
+        let url = horizon_page_url(
+            &format!("{}/payments?order=desc&limit={}", self.horizon_url, limit),
+            cursor,
+        )?;
+        let response = self
+            .client
+            .get(url)
+            .timeout(self.timeouts.payments)
+            .send()
+            .await
+            .map_err(map_send_error)?;
+        if !response.status().is_success() {
+            return Err(map_response_error(response).await);
         }
+        let horizon_response: HorizonResponse<Payment> = response
+            .json()
+            .await
+            .map_err(|e| RpcError::ParseError(e.to_string()))?;
+        Ok(horizon_response
+            .embedded
+            .map(|e| e.records)
+            .unwrap_or_default())
+    }
+
+    /// Fetch recent payments, including those from failed transactions,
+    /// each tagged with its transaction's outcome
+    pub async fn fetch_payment_outcomes(
+        &self,
+        limit: u32,
+        cursor: Option<&str>,
+    ) -> Result<Vec<PaymentOutcome>, RpcError> {
+        if self.mock_mode {
+            self.simulate_mock_call().await?;
+            return Ok(self
+                .mock_payment_series(limit)
+                .into_iter()
+                .map(|payment| PaymentOutcome {
+                    payment,
+                    successful: true,
+                })
+                .collect());
+        }
+
+        let result = self
+            .execute_with_retry(|| self.fetch_payment_outcomes_internal(limit, cursor))
+            .await;
+
+        result.map_err(|e| {
+            metrics::record_rpc_error(e.error_type_label(), "stellar");
+            e
+        })
+    }
+
+    async fn fetch_payment_outcomes_internal(
+        &self,
+        limit: u32,
+        cursor: Option<&str>,
+    ) -> Result<Vec<PaymentOutcome>, RpcError> {
+        let url = horizon_page_url(
+            &format!(
+                "{}/payments?order=desc&limit={}&include_failed=true",
+                self.horizon_url, limit
+            ),
+            cursor,
+        )?;
         let response = self
             .client
-            .get(&url)
+            .get(url)
+            .timeout(self.timeouts.payments)
             .send()
             .await
-            .map_err(|e| RpcError::NetworkError(e.to_string()))?;
+            .map_err(map_send_error)?;
+        if !response.status().is_success() {
+            return Err(map_response_error(response).await);
+        }
+        let horizon_response: HorizonResponse<PaymentOutcome> = response
+            .json()
+            .await
+            .map_err(|e| RpcError::ParseError(e.to_string()))?;
+        Ok(horizon_response
+            .embedded
+            .map(|e| e.records)
+            .unwrap_or_default())
+    }
+
+    /// Fetch payments strictly newer than `cursor`, oldest first.
+    ///
+    /// Used by incremental ingestion: Horizon returns records after the given
+    /// paging token when ordering ascending, so repeated calls with the last
+    /// seen token walk forward without refetching.
+    pub async fn fetch_payments_after(
+        &self,
+        cursor: &str,
+        limit: u32,
+    ) -> Result<Vec<Payment>, RpcError> {
+        if self.mock_mode {
+            self.simulate_mock_call().await?;
+            // The mock series is newest first, so the payments after
+            // `paging_N` are the ones before index N, served oldest first
+            let newer = cursor
+                .strip_prefix("paging_")
+                .and_then(|index| index.parse::<u32>().ok())
+                .unwrap_or(0)
+                .min(ABSOLUTE_MAX_TOTAL_RECORDS);
+            return Ok(self
+                .mock_payment_series(newer)
+                .into_iter()
+                .rev()
+                .take(limit as usize)
+                .collect());
+        }
+
+        debug!("Fetching {} payments after cursor {}", limit, cursor);
+
+        let result = self
+            .execute_with_retry(|| self.fetch_payments_after_internal(cursor, limit))
+            .await;
+
+        result.map_err(|e| {
+            metrics::record_rpc_error(e.error_type_label(), "stellar");
+            e
+        })
+    }
+
+    async fn fetch_payments_after_internal(
+        &self,
+        cursor: &str,
+        limit: u32,
+    ) -> Result<Vec<Payment>, RpcError> {
+        let url = horizon_page_url(
+            &format!("{}/payments?order=asc&limit={}", self.horizon_url, limit),
+            Some(cursor),
+        )?;
+        let response = self
+            .client
+            .get(url)
+            .timeout(self.timeouts.payments)
+            .send()
+            .await
+            .map_err(map_send_error)?;
         if !response.status().is_success() {
             return Err(map_response_error(response).await);
         }
@@ -879,6 +1331,33 @@ impl StellarRpcClient {
             .unwrap_or_default())
     }
 
+    /// Open Horizon's server-sent event stream of payments after `cursor`
+    /// (`now` for new payments only).
+    ///
+    /// The body never completes on its own, so the request goes through a
+    /// client with only a connect timeout rather than the shared client's
+    /// overall request timeout.
+    pub async fn open_payment_stream(&self, cursor: &str) -> Result<reqwest::Response, RpcError> {
+        let url = horizon_page_url(&format!("{}/payments", self.horizon_url), Some(cursor))?;
+        debug!("Opening payment stream at cursor {}", cursor);
+
+        let client = Client::builder()
+            .connect_timeout(Duration::from_secs(10))
+            .default_headers(self.default_headers.clone())
+            .build()
+            .map_err(|e| RpcError::NetworkError(e.to_string()))?;
+        let response = client
+            .get(url)
+            .header(reqwest::header::ACCEPT, "text/event-stream")
+            .send()
+            .await
+            .map_err(map_send_error)?;
+        if !response.status().is_success() {
+            return Err(map_response_error(response).await);
+        }
+        Ok(response)
+    }
+
     /// Fetch recent trades
     pub async fn fetch_trades(
         &self,
@@ -886,7 +1365,8 @@ impl StellarRpcClient {
         cursor: Option<&str>,
     ) -> Result<Vec<Trade>, RpcError> {
         if self.mock_mode {
-            return Ok(Self::mock_trades(limit));
+            self.simulate_mock_call().await?;
+            return Ok(self.mock_trade_series(limit));
         }
 
         let result = self
@@ -904,16 +1384,75 @@ impl StellarRpcClient {
         limit: u32,
         cursor: Option<&str>,
     ) -> Result<Vec<Trade>, RpcError> {
-        let mut url = format!("{}/trades?order=desc&limit={}", self.horizon_url, limit);
-        if let Some(c) = cursor {
-            url.push_str(&format!("&cursor={}", c));
+        let url = horizon_page_url(
+            &format!("{}/trades?order=desc&limit={}", self.horizon_url, limit),
+            cursor,
+        )?;
+        let response = self
+            .client
+            .get(url)
+            .timeout(self.timeouts.trades)
+            .send()
+            .await
+            .map_err(map_send_error)?;
+        if !response.status().is_success() {
+            return Err(map_response_error(response).await);
+        }
+        let horizon_response: HorizonResponse<Trade> = response
+            .json()
+            .await
+            .map_err(|e| RpcError::ParseError(e.to_string()))?;
+        Ok(horizon_response
+            .embedded
+            .map(|e| e.records)
+            .unwrap_or_default())
+    }
+
+    /// Fetch recent trades between two assets, newest first
+    pub async fn fetch_trades_for_pair(
+        &self,
+        base_asset: &Asset,
+        counter_asset: &Asset,
+        limit: u32,
+    ) -> Result<Vec<Trade>, RpcError> {
+        if self.mock_mode {
+            self.simulate_mock_call().await?;
+            return Ok(self.mock_trade_series(limit));
         }
+
+        let result = self
+            .execute_with_retry(|| {
+                self.fetch_trades_for_pair_internal(base_asset, counter_asset, limit)
+            })
+            .await;
+
+        result.map_err(|e| {
+            metrics::record_rpc_error(e.error_type_label(), "stellar");
+            e
+        })
+    }
+
+    async fn fetch_trades_for_pair_internal(
+        &self,
+        base_asset: &Asset,
+        counter_asset: &Asset,
+        limit: u32,
+    ) -> Result<Vec<Trade>, RpcError> {
+        let base_params = Self::asset_to_query_params("base", base_asset)
+            .map_err(|e| RpcError::ParseError(e.to_string()))?;
+        let counter_params = Self::asset_to_query_params("counter", counter_asset)
+            .map_err(|e| RpcError::ParseError(e.to_string()))?;
+        let url = format!(
+            "{}/trades?{}&{}&order=desc&limit={}",
+            self.horizon_url, base_params, counter_params, limit
+        );
         let response = self
             .client
             .get(&url)
+            .timeout(self.timeouts.trades)
             .send()
             .await
-            .map_err(|e| RpcError::NetworkError(e.to_string()))?;
+            .map_err(map_send_error)?;
         if !response.status().is_success() {
             return Err(map_response_error(response).await);
         }
@@ -964,12 +1503,7 @@ impl StellarRpcClient {
             "{}/order_book?{}&{}&limit={}",
             self.horizon_url, selling_params, buying_params, limit
         );
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| RpcError::NetworkError(e.to_string()))?;
+        let response = self.client.get(&url).send().await.map_err(map_send_error)?;
         if !response.status().is_success() {
             return Err(map_response_error(response).await);
         }
@@ -981,7 +1515,8 @@ impl StellarRpcClient {
 
     pub async fn fetch_payments_for_ledger(&self, sequence: u64) -> Result<Vec<Payment>, RpcError> {
         if self.mock_mode {
-            return Ok(Self::mock_payments(5));
+            self.simulate_mock_call().await?;
+            return Ok(self.mock_payments_for_ledger(sequence));
         }
 
         let result = self
@@ -994,31 +1529,103 @@ impl StellarRpcClient {
         })
     }
 
+    /// Fetch payments for every ledger in `start..=end`, in sequence order.
+    ///
+    /// Up to `LEDGER_RANGE_CONCURRENCY` ledgers are requested at once, each with
+    /// the usual retry policy. The first ledger that still fails stops the
+    /// fetch; results for the ledgers before it are returned alongside the
+    /// error so a backfill can resume from `last_processed + 1`.
+    pub async fn fetch_payments_for_ledger_range(
+        &self,
+        start: u64,
+        end: u64,
+    ) -> LedgerRangePayments {
+        let mut range = LedgerRangePayments {
+            payments: Vec::new(),
+            last_processed: None,
+            error: None,
+        };
+
+        let mut results =
+            stream::iter(start..=end)
+                .map(|sequence| async move {
+                    (sequence, self.fetch_payments_for_ledger(sequence).await)
+                })
+                .buffered(LEDGER_RANGE_CONCURRENCY);
+
+        while let Some((sequence, result)) = results.next().await {
+            match result {
+                Ok(payments) => {
+                    range.payments.extend(payments);
+                    range.last_processed = Some(sequence);
+                }
+                Err(e) => {
+                    warn!(
+                        "Stopping ledger range fetch at ledger {} ({}..={}): {}",
+                        sequence, start, end, e
+                    );
+                    range.error = Some(e);
+                    break;
+                }
+            }
+        }
+
+        range
+    }
+
     async fn fetch_payments_for_ledger_internal(
         &self,
         sequence: u64,
     ) -> Result<Vec<Payment>, RpcError> {
         let url = format!(
-            "{}/ledgers/{}/payments?limit=200",
-            self.horizon_url, sequence
+            "{}/ledgers/{}/payments?limit={}",
+            self.horizon_url, sequence, LEDGER_PAGE_LIMIT
         );
-        let response = self
-            .client
-            .get(&url)
-            .send()
+        self.fetch_ledger_listing(&url, self.timeouts.payments, payment_paging_token)
             .await
-            .map_err(|e| RpcError::NetworkError(e.to_string()))?;
-        if !response.status().is_success() {
-            return Err(map_response_error(response).await);
+    }
+
+    /// Every record of a single-ledger Horizon listing, following paging
+    /// tokens until a short page shows the ledger is exhausted
+    async fn fetch_ledger_listing<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        timeout: Duration,
+        paging_token: fn(&T) -> &str,
+    ) -> Result<Vec<T>, RpcError> {
+        let mut records = Vec::new();
+        let mut cursor = None;
+
+        loop {
+            let page_url = horizon_page_url(url, cursor.as_deref())?;
+            let response = self
+                .client
+                .get(page_url)
+                .timeout(timeout)
+                .send()
+                .await
+                .map_err(map_send_error)?;
+            if !response.status().is_success() {
+                return Err(map_response_error(response).await);
+            }
+            let horizon_response: HorizonResponse<T> = response
+                .json()
+                .await
+                .map_err(|e| RpcError::ParseError(e.to_string()))?;
+            let page = horizon_response
+                .embedded
+                .map(|e| e.records)
+                .unwrap_or_default();
+
+            let full_page = page.len() >= LEDGER_PAGE_LIMIT;
+            cursor = page.last().map(|record| paging_token(record).to_string());
+            records.extend(page);
+            if !full_page {
+                break;
+            }
         }
-        let horizon_response: HorizonResponse<Payment> = response
-            .json()
-            .await
-            .map_err(|e| RpcError::ParseError(e.to_string()))?;
-        Ok(horizon_response
-            .embedded
-            .map(|e| e.records)
-            .unwrap_or_default())
+
+        Ok(records)
     }
 
     /// Fetch transactions for a specific ledger
@@ -1040,31 +1647,32 @@ impl StellarRpcClient {
         })
     }
 
+    /// Fetch transactions for every ledger in `start..=end`, in sequence
+    /// order, with up to `LEDGER_RANGE_CONCURRENCY` ledgers requested at once.
+    /// Fails on the first ledger that still errors after retries.
+    pub async fn fetch_transactions_for_ledger_range(
+        &self,
+        start: u64,
+        end: u64,
+    ) -> Result<Vec<HorizonTransaction>, RpcError> {
+        let ledgers: Vec<Vec<HorizonTransaction>> = stream::iter(start..=end)
+            .map(|sequence| self.fetch_transactions_for_ledger(sequence))
+            .buffered(LEDGER_RANGE_CONCURRENCY)
+            .try_collect()
+            .await?;
+        Ok(ledgers.into_iter().flatten().collect())
+    }
+
     async fn fetch_transactions_for_ledger_internal(
         &self,
         sequence: u64,
     ) -> Result<Vec<HorizonTransaction>, RpcError> {
         let url = format!(
-            "{}/ledgers/{}/transactions?limit=200&include_failed=true",
-            self.horizon_url, sequence
+            "{}/ledgers/{}/transactions?limit={}&include_failed=true",
+            self.horizon_url, sequence, LEDGER_PAGE_LIMIT
         );
-        let response = self
-            .client
-            .get(&url)
-            .send()
+        self.fetch_ledger_listing(&url, self.timeouts.ledgers, transaction_paging_token)
             .await
-            .map_err(|e| RpcError::NetworkError(e.to_string()))?;
-        if !response.status().is_success() {
-            return Err(map_response_error(response).await);
-        }
-        let horizon_response: HorizonResponse<HorizonTransaction> = response
-            .json()
-            .await
-            .map_err(|e| RpcError::ParseError(e.to_string()))?;
-        Ok(horizon_response
-            .embedded
-            .map(|e| e.records)
-            .unwrap_or_default())
     }
 
     /// Fetch operations for a specific ledger
@@ -1097,9 +1705,10 @@ impl StellarRpcClient {
         let response = self
             .client
             .get(&url)
+            .timeout(self.timeouts.ledgers)
             .send()
             .await
-            .map_err(|e| RpcError::NetworkError(e.to_string()))?;
+            .map_err(map_send_error)?;
         if !response.status().is_success() {
             return Err(map_response_error(response).await);
         }
@@ -1143,9 +1752,10 @@ impl StellarRpcClient {
         let response = self
             .client
             .get(&url)
+            .timeout(self.timeouts.ledgers)
             .send()
             .await
-            .map_err(|e| RpcError::NetworkError(e.to_string()))?;
+            .map_err(map_send_error)?;
         if !response.status().is_success() {
             return Err(map_response_error(response).await);
         }
@@ -1166,7 +1776,8 @@ impl StellarRpcClient {
         limit: u32,
     ) -> Result<Vec<Payment>, RpcError> {
         if self.mock_mode {
-            return Ok(Self::mock_payments(limit));
+            self.simulate_mock_call().await?;
+            return Ok(self.mock_account_payments(account_id, limit));
         }
 
         let result = self
@@ -1191,9 +1802,10 @@ impl StellarRpcClient {
         let response = self
             .client
             .get(&url)
+            .timeout(self.timeouts.payments)
             .send()
             .await
-            .map_err(|e| RpcError::NetworkError(e.to_string()))?;
+            .map_err(map_send_error)?;
         if !response.status().is_success() {
             return Err(map_response_error(response).await);
         }
@@ -1207,6 +1819,96 @@ impl StellarRpcClient {
             .unwrap_or_default())
     }
 
+    /// Fetch trades where a specific account was on either side
+    pub async fn fetch_account_trades(
+        &self,
+        account_id: &str,
+        limit: u32,
+        cursor: Option<&str>,
+    ) -> Result<Vec<Trade>, RpcError> {
+        if self.mock_mode {
+            self.simulate_mock_call().await?;
+            return Ok(self.mock_account_trades(account_id, limit));
+        }
+
+        let result = self
+            .execute_with_retry(|| self.fetch_account_trades_internal(account_id, limit, cursor))
+            .await;
+
+        result.map_err(|e| {
+            metrics::record_rpc_error(e.error_type_label(), "stellar");
+            e
+        })
+    }
+
+    async fn fetch_account_trades_internal(
+        &self,
+        account_id: &str,
+        limit: u32,
+        cursor: Option<&str>,
+    ) -> Result<Vec<Trade>, RpcError> {
+        let url = horizon_page_url(
+            &format!(
+                "{}/accounts/{}/trades?order=desc&limit={}",
+                self.horizon_url, account_id, limit
+            ),
+            cursor,
+        )?;
+        let response = self
+            .client
+            .get(url)
+            .timeout(self.timeouts.trades)
+            .send()
+            .await
+            .map_err(map_send_error)?;
+        if !response.status().is_success() {
+            return Err(map_response_error(response).await);
+        }
+        let horizon_response: HorizonResponse<Trade> = response
+            .json()
+            .await
+            .map_err(|e| RpcError::ParseError(e.to_string()))?;
+        Ok(horizon_response
+            .embedded
+            .map(|e| e.records)
+            .unwrap_or_default())
+    }
+
+    /// Fetch the signers configured on an account
+    pub async fn fetch_account_signers(
+        &self,
+        account_id: &str,
+    ) -> Result<Vec<AccountSigner>, RpcError> {
+        if self.mock_mode {
+            return Ok(Self::mock_account_signers(account_id));
+        }
+
+        let result = self
+            .execute_with_retry(|| self.fetch_account_signers_internal(account_id))
+            .await;
+
+        result.map_err(|e| {
+            metrics::record_rpc_error(e.error_type_label(), "stellar");
+            e
+        })
+    }
+
+    async fn fetch_account_signers_internal(
+        &self,
+        account_id: &str,
+    ) -> Result<Vec<AccountSigner>, RpcError> {
+        let url = format!("{}/accounts/{}", self.horizon_url, account_id);
+        let response = self.client.get(&url).send().await.map_err(map_send_error)?;
+        if !response.status().is_success() {
+            return Err(map_response_error(response).await);
+        }
+        let account: HorizonAccountSigners = response
+            .json()
+            .await
+            .map_err(|e| RpcError::ParseError(e.to_string()))?;
+        Ok(account.signers)
+    }
+
     // ============================================================================
     // Paginated Fetch Methods
     // ============================================================================
@@ -1223,12 +1925,40 @@ impl StellarRpcClient {
             let limit = max_records
                 .unwrap_or(self.max_total_records)
                 .min(ABSOLUTE_MAX_TOTAL_RECORDS);
-            return Ok(Self::mock_payments(limit));
+            if self.pagination_concurrency > 1 {
+                return self
+                    .fetch_mock_pages_concurrently(limit, |n| self.mock_payment_series(n))
+                    .await;
+            }
+            self.simulate_mock_call().await?;
+            return Ok(self.mock_payment_series(limit));
         }
 
         let max_records = max_records
             .unwrap_or(self.max_total_records)
             .min(ABSOLUTE_MAX_TOTAL_RECORDS);
+        if self.pagination_concurrency > 1 {
+            let latest = self
+                .fetch_payments(1, None)
+                .await
+                .context("Failed to fetch latest payment")?;
+            let Some(latest_ledger) = latest.first().and_then(|p| toid_ledger(&p.paging_token))
+            else {
+                return Ok(Vec::new());
+            };
+            return self
+                .fetch_ledgers_concurrently(
+                    max_records,
+                    latest_ledger,
+                    |cursor| async move {
+                        self.fetch_payments(self.max_records_per_request, Some(&cursor))
+                            .await
+                    },
+                    payment_paging_token,
+                    "",
+                )
+                .await;
+        }
         let mut all_payments = Vec::new();
         let mut cursor: Option<String> = None;
         let mut fetched = 0;
@@ -1283,6 +2013,48 @@ impl StellarRpcClient {
         Ok(all_payments)
     }
 
+    /// Fetch payments with their transaction outcomes, paginating up to
+    /// `max_records` (max_total_records if None)
+    pub async fn fetch_all_payment_outcomes(
+        &self,
+        max_records: Option<u32>,
+    ) -> Result<Vec<PaymentOutcome>> {
+        let max_records = max_records
+            .unwrap_or(self.max_total_records)
+            .min(ABSOLUTE_MAX_TOTAL_RECORDS);
+        if self.mock_mode {
+            return Ok(self.fetch_payment_outcomes(max_records, None).await?);
+        }
+
+        let mut all_outcomes = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        while (all_outcomes.len() as u32) < max_records {
+            let limit = std::cmp::min(
+                self.max_records_per_request,
+                max_records - all_outcomes.len() as u32,
+            );
+            let outcomes = self
+                .fetch_payment_outcomes(limit, cursor.as_deref())
+                .await
+                .context("Failed to fetch payments page")?;
+
+            let Some(last) = outcomes.last() else {
+                break;
+            };
+            cursor = Some(last.payment.paging_token.clone());
+            let page_len = outcomes.len() as u32;
+            all_outcomes.extend(outcomes);
+
+            if page_len < limit {
+                break;
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(self.pagination_delay_ms)).await;
+        }
+
+        Ok(all_outcomes)
+    }
+
     /// Fetch all trades with automatic pagination up to max_total_records
     ///
     /// # Arguments
@@ -1295,12 +2067,40 @@ impl StellarRpcClient {
             let limit = max_records
                 .unwrap_or(self.max_total_records)
                 .min(ABSOLUTE_MAX_TOTAL_RECORDS);
-            return Ok(Self::mock_trades(limit));
+            if self.pagination_concurrency > 1 {
+                return self
+                    .fetch_mock_pages_concurrently(limit, |n| self.mock_trade_series(n))
+                    .await;
+            }
+            self.simulate_mock_call().await?;
+            return Ok(self.mock_trade_series(limit));
         }
 
         let max_records = max_records
             .unwrap_or(self.max_total_records)
             .min(ABSOLUTE_MAX_TOTAL_RECORDS);
+        if self.pagination_concurrency > 1 {
+            let latest = self
+                .fetch_trades(1, None)
+                .await
+                .context("Failed to fetch latest trade")?;
+            let Some(latest_ledger) = latest.first().and_then(|t| toid_ledger(&t.id)) else {
+                return Ok(Vec::new());
+            };
+            return self
+                .fetch_ledgers_concurrently(
+                    max_records,
+                    latest_ledger,
+                    |cursor| async move {
+                        self.fetch_trades(self.max_records_per_request, Some(&cursor))
+                            .await
+                    },
+                    trade_paging_token,
+                    // Trade cursors pair the operation TOID with the trade's index
+                    "-0",
+                )
+                .await;
+        }
         let mut all_trades = Vec::new();
         let mut cursor: Option<String> = None;
         let mut fetched = 0;
@@ -1357,6 +2157,179 @@ impl StellarRpcClient {
         Ok(all_trades)
     }
 
+    /// Fetch pages `0, 1, 2, ...` with up to `pagination_concurrency` in
+    /// flight, keeping them in page order. Stops once `max_records` are
+    /// collected, after `max_records` pages, or when `fetch_page` returns
+    /// `None` for a page past the end.
+    async fn fetch_pages_concurrently<T, F, Fut>(
+        &self,
+        max_records: u32,
+        fetch_page: F,
+    ) -> Result<Vec<T>>
+    where
+        F: Fn(u32) -> Fut,
+        Fut: Future<Output = Result<Option<Vec<T>>>>,
+    {
+        let permits = &self.pagination_permits;
+        let mut pages = stream::iter(0..max_records)
+            .map(|page| {
+                let fetch = fetch_page(page);
+                async move {
+                    let _permit = permits.acquire().await?;
+                    fetch.await
+                }
+            })
+            .buffered(self.pagination_concurrency);
+
+        let mut records = Vec::new();
+        while let Some(page) = pages.next().await {
+            let Some(page) = page? else {
+                break;
+            };
+            records.extend(page);
+            if records.len() >= max_records as usize {
+                break;
+            }
+        }
+        records.truncate(max_records as usize);
+        Ok(records)
+    }
+
+    /// Horizon cursors chain one page to the next, so concurrent pagination
+    /// splits the history by ledger instead: page `n` holds every record in
+    /// ledger `latest_ledger - n`, newest first. At most
+    /// `MAX_CONCURRENT_SCAN_LEDGERS` ledgers are walked concurrently; when
+    /// those hold fewer than `max_records`, the older history is paged by
+    /// cursor from the oldest scanned ledger, as the sequential fetch does.
+    async fn fetch_ledgers_concurrently<T, F, Fut>(
+        &self,
+        max_records: u32,
+        latest_ledger: u32,
+        fetch_page: F,
+        paging_token: fn(&T) -> &str,
+        cursor_suffix: &str,
+    ) -> Result<Vec<T>>
+    where
+        F: Fn(String) -> Fut,
+        Fut: Future<Output = Result<Vec<T>, RpcError>>,
+    {
+        info!(
+            "Starting concurrent paginated fetch from ledger {} (max: {}, concurrency: {})",
+            latest_ledger, max_records, self.pagination_concurrency
+        );
+        let scan_ledgers = MAX_CONCURRENT_SCAN_LEDGERS
+            .min(max_records)
+            .min(latest_ledger);
+        let mut records = self
+            .fetch_pages_concurrently(max_records, |page| {
+                let ledger = (page < scan_ledgers).then(|| latest_ledger - page);
+                let fetch = ledger.map(|ledger| {
+                    self.fetch_ledger_records(ledger, &fetch_page, paging_token, cursor_suffix)
+                });
+                async move {
+                    match fetch {
+                        Some(fetch) => fetch.await.map(Some),
+                        None => Ok(None),
+                    }
+                }
+            })
+            .await?;
+
+        // Everything older than the scanned ledgers sorts below the start of
+        // the oldest one
+        let oldest_scanned = latest_ledger + 1 - scan_ledgers;
+        let mut cursor = (oldest_scanned > 1)
+            .then(|| format!("{}{}", ledger_start_toid(oldest_scanned), cursor_suffix));
+        while let Some(page_cursor) = cursor.take() {
+            if records.len() >= max_records as usize {
+                break;
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(self.pagination_delay_ms)).await;
+            let page = fetch_page(page_cursor)
+                .await
+                .context("Failed to fetch page past the scanned ledgers")?;
+            let full_page = page.len() as u32 >= self.max_records_per_request;
+            if full_page {
+                cursor = page.last().map(|last| paging_token(last).to_string());
+            }
+            records.extend(page);
+        }
+        records.truncate(max_records as usize);
+
+        info!(
+            "Completed concurrent pagination: fetched {} total records",
+            records.len()
+        );
+        Ok(records)
+    }
+
+    /// Every record in `ledger`, newest first, paging backwards from the
+    /// start of the next ledger until records from an older ledger appear
+    async fn fetch_ledger_records<T, F, Fut>(
+        &self,
+        ledger: u32,
+        fetch_page: F,
+        paging_token: fn(&T) -> &str,
+        cursor_suffix: &str,
+    ) -> Result<Vec<T>>
+    where
+        F: Fn(String) -> Fut,
+        Fut: Future<Output = Result<Vec<T>, RpcError>>,
+    {
+        let mut cursor = format!("{}{}", ledger_start_toid(ledger + 1), cursor_suffix);
+        let mut records = Vec::new();
+
+        loop {
+            let page = fetch_page(cursor)
+                .await
+                .with_context(|| format!("Failed to fetch page for ledger {}", ledger))?;
+            let full_page = page.len() as u32 >= self.max_records_per_request;
+            let Some(last) = page.last() else {
+                break;
+            };
+            cursor = paging_token(last).to_string();
+
+            let mut reached_older_ledger = false;
+            for record in page {
+                if toid_ledger(paging_token(&record)) == Some(ledger) {
+                    records.push(record);
+                } else {
+                    reached_older_ledger = true;
+                }
+            }
+            if reached_older_ledger || !full_page {
+                break;
+            }
+
+            tokio::time::sleep(tokio::time::Duration::from_millis(self.pagination_delay_ms)).await;
+        }
+
+        Ok(records)
+    }
+
+    /// Serve mock records in `max_records_per_request` pages through the
+    /// concurrent pagination path
+    async fn fetch_mock_pages_concurrently<T>(
+        &self,
+        max_records: u32,
+        mock_records: impl Fn(u32) -> Vec<T>,
+    ) -> Result<Vec<T>> {
+        let per_page = self.max_records_per_request;
+        let mock_records = &mock_records;
+        self.fetch_pages_concurrently(max_records, |page| async move {
+            let start = page * per_page;
+            if start >= max_records {
+                return Ok(None);
+            }
+            self.simulate_mock_call().await?;
+            let end = (start + per_page).min(max_records);
+            Ok(Some(
+                mock_records(end).into_iter().skip(start as usize).collect(),
+            ))
+        })
+        .await
+    }
+
     /// Fetch all payments for a specific account with automatic pagination
     ///
     /// # Arguments
@@ -1374,7 +2347,8 @@ impl StellarRpcClient {
             let limit = max_records
                 .unwrap_or(self.max_total_records)
                 .min(ABSOLUTE_MAX_TOTAL_RECORDS);
-            return Ok(Self::mock_payments(limit));
+            self.simulate_mock_call().await?;
+            return Ok(self.mock_payment_series(limit));
         }
 
         let max_records = max_records
@@ -1392,17 +2366,22 @@ impl StellarRpcClient {
         while fetched < max_records {
             let limit = std::cmp::min(self.max_records_per_request, max_records - fetched);
 
-            let mut url = format!(
-                "{}/accounts/{}/payments?order=desc&limit={}",
-                self.horizon_url, account_id, limit
-            );
-
-            if let Some(ref cursor_val) = cursor {
-                url.push_str(&format!("&cursor={}", cursor_val));
-            }
+            let url = horizon_page_url(
+                &format!(
+                    "{}/accounts/{}/payments?order=desc&limit={}",
+                    self.horizon_url, account_id, limit
+                ),
+                cursor.as_deref(),
+            )?;
 
             let response = self
-                .retry_request(|| async { self.client.get(&url).send().await })
+                .retry_request(|| async {
+                    self.client
+                        .get(url.clone())
+                        .timeout(self.timeouts.payments)
+                        .send()
+                        .await
+                })
                 .await
                 .context("Failed to fetch account payments page")?;
 
@@ -1482,17 +2461,16 @@ impl StellarRpcClient {
         }
     }
 
-    /// Retry a request with exponential backoff
+    /// Retry a request with jittered exponential backoff
+    ///
+    /// A 429 waits for its `Retry-After` instead of the computed backoff;
+    /// other 4xx responses are returned without retrying.
     async fn retry_request<F, Fut>(&self, request_fn: F) -> Result<reqwest::Response>
     where
         F: Fn() -> Fut,
         Fut: std::future::Future<Output = Result<reqwest::Response, reqwest::Error>>,
     {
-        let retry_config = RetryConfig {
-            max_attempts: MAX_RETRIES + 1,
-            base_delay_ms: INITIAL_BACKOFF_MS,
-            max_delay_ms: INITIAL_BACKOFF_MS * BACKOFF_MULTIPLIER.pow(MAX_RETRIES),
-        };
+        let retry_config = self.retry_config.clone();
 
         with_retry(
             || async {
@@ -1518,9 +2496,12 @@ impl StellarRpcClient {
                     return Ok(response);
                 }
 
-                if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
-                    self.rate_limiter.on_rate_limited(&headers).await;
-                }
+                // Retry-After (or the limiter's default) replaces the computed backoff
+                let rate_limit_wait = if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                    Some(self.rate_limiter.on_rate_limited(&headers).await)
+                } else {
+                    None
+                };
 
                 let error_text = response
                     .text()
@@ -1532,13 +2513,10 @@ impl StellarRpcClient {
                 );
 
                 let msg = format!("HTTP {}: {}", status, error_text);
-                if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
-                    let retry_after = headers
-                        .get("Retry-After")
-                        .and_then(|v| v.to_str().ok())
-                        .and_then(|s| s.parse::<u64>().ok())
-                        .map(Duration::from_secs);
-                    Err(RpcError::RateLimitError { retry_after })
+                if let Some(retry_after) = rate_limit_wait {
+                    Err(RpcError::RateLimitError {
+                        retry_after: Some(retry_after),
+                    })
                 } else if status == reqwest::StatusCode::REQUEST_TIMEOUT
                     || status == reqwest::StatusCode::GATEWAY_TIMEOUT
                 {
@@ -1546,6 +2524,7 @@ impl StellarRpcClient {
                 } else if status.as_u16() >= 500 {
                     Err(RpcError::NetworkError(msg))
                 } else {
+                    // Other 4xx (400, 404, ...) will not succeed on retry: fail fast
                     Err(RpcError::ServerError {
                         status: status.as_u16(),
                         message: msg,
@@ -1621,6 +2600,14 @@ impl StellarRpcClient {
         }
     }
 
+    fn mock_transaction_status() -> TransactionStatus {
+        TransactionStatus {
+            status: TransactionState::Success,
+            ledger: Some(MOCK_LATEST_LEDGER),
+            result_xdr: Some("AAAAAAAAAGQAAAAAAAAAAQAAAAAAAAABAAAAAAAAAAA=".to_string()),
+        }
+    }
+
     fn mock_payments(limit: u32) -> Vec<Payment> {
         (0..limit)
             .map(|i| {
@@ -1749,6 +2736,51 @@ impl StellarRpcClient {
             .collect()
     }
 
+    /// Deterministic mock payments for a ledger, with ids unique to `sequence`
+    fn mock_payments_for_ledger(&self, sequence: u64) -> Vec<Payment> {
+        self.mock_payment_series(5)
+            .into_iter()
+            .enumerate()
+            .map(|(i, mut payment)| {
+                payment.id = format!("payment_{}_{}", sequence, i);
+                payment.paging_token = format!("paging_{}_{}", sequence, i);
+                payment.transaction_hash = format!("txhash_{}_{}", sequence, i);
+                payment
+            })
+            .collect()
+    }
+
+    /// Mock payments involving `account_id`: it sends the even-indexed ones
+    /// and receives the odd-indexed ones
+    fn mock_account_payments(&self, account_id: &str, limit: u32) -> Vec<Payment> {
+        self.mock_payment_series(limit)
+            .into_iter()
+            .enumerate()
+            .map(|(i, mut payment)| {
+                if i % 2 == 0 {
+                    payment.source_account = account_id.to_string();
+                    payment.from = Some(account_id.to_string());
+                    for change in payment.asset_balance_changes.iter_mut().flatten() {
+                        change.from = Some(account_id.to_string());
+                    }
+                } else {
+                    payment.destination = account_id.to_string();
+                    payment.to = Some(account_id.to_string());
+                }
+                payment
+            })
+            .collect()
+    }
+
+    /// A single-signature account: only the master key, at weight 1
+    fn mock_account_signers(account_id: &str) -> Vec<AccountSigner> {
+        vec![AccountSigner {
+            key: account_id.to_string(),
+            weight: 1,
+            signer_type: "ed25519_public_key".to_string(),
+        }]
+    }
+
     fn mock_trades(limit: u32) -> Vec<Trade> {
         (0..limit)
             .map(|i| Trade {
@@ -1778,6 +2810,23 @@ impl StellarRpcClient {
             .collect()
     }
 
+    /// Mock trades with `account_id` alternating between the base and
+    /// counter side
+    fn mock_account_trades(&self, account_id: &str, limit: u32) -> Vec<Trade> {
+        self.mock_trade_series(limit)
+            .into_iter()
+            .enumerate()
+            .map(|(i, mut trade)| {
+                if i % 2 == 0 {
+                    trade.base_account = account_id.to_string();
+                } else {
+                    trade.counter_account = account_id.to_string();
+                }
+                trade
+            })
+            .collect()
+    }
+
     fn mock_order_book(selling_asset: &Asset, buying_asset: &Asset) -> OrderBook {
         let bids = vec![
             OrderBookEntry {
@@ -1973,19 +3022,14 @@ impl StellarRpcClient {
         limit: u32,
         cursor: Option<&str>,
     ) -> Result<Vec<HorizonLiquidityPool>, RpcError> {
-        let mut url = format!(
-            "{}/liquidity_pools?order=desc&limit={}",
-            self.horizon_url, limit
-        );
-        if let Some(c) = cursor {
-            url.push_str(&format!("&cursor={}", c));
-        }
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| RpcError::NetworkError(e.to_string()))?;
+        let url = horizon_page_url(
+            &format!(
+                "{}/liquidity_pools?order=desc&limit={}",
+                self.horizon_url, limit
+            ),
+            cursor,
+        )?;
+        let response = self.client.get(url).send().await.map_err(map_send_error)?;
         if !response.status().is_success() {
             return Err(map_response_error(response).await);
         }
@@ -2026,12 +3070,7 @@ impl StellarRpcClient {
         pool_id: &str,
     ) -> Result<HorizonLiquidityPool, RpcError> {
         let url = format!("{}/liquidity_pools/{}", self.horizon_url, pool_id);
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| RpcError::NetworkError(e.to_string()))?;
+        let response = self.client.get(&url).send().await.map_err(map_send_error)?;
         if !response.status().is_success() {
             return Err(map_response_error(response).await);
         }
@@ -2048,7 +3087,8 @@ impl StellarRpcClient {
         limit: u32,
     ) -> Result<Vec<Trade>, RpcError> {
         if self.mock_mode {
-            return Ok(Self::mock_trades(limit));
+            self.simulate_mock_call().await?;
+            return Ok(self.mock_trade_series(limit));
         }
 
         let result = self
@@ -2073,9 +3113,10 @@ impl StellarRpcClient {
         let response = self
             .client
             .get(&url)
+            .timeout(self.timeouts.trades)
             .send()
             .await
-            .map_err(|e| RpcError::NetworkError(e.to_string()))?;
+            .map_err(map_send_error)?;
         if !response.status().is_success() {
             return Err(map_response_error(response).await);
         }
@@ -2120,12 +3161,7 @@ impl StellarRpcClient {
         } else {
             url.push_str("&order=desc");
         }
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| RpcError::NetworkError(e.to_string()))?;
+        let response = self.client.get(&url).send().await.map_err(map_send_error)?;
         if !response.status().is_success() {
             return Err(map_response_error(response).await);
         }
@@ -2295,6 +3331,513 @@ impl StellarRpcClient {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_retry_backoff_never_exceeds_ceiling() {
+        let client = StellarRpcClient::new_with_retry_config(
+            "http://localhost:8000".to_string(),
+            "http://localhost:8001".to_string(),
+            true,
+            RetryConfig {
+                max_attempts: 10,
+                base_delay_ms: 100,
+                max_delay_ms: 750,
+            },
+        );
+        let config = client.retry_config();
+
+        assert_eq!(config.backoff_ceiling_ms(1), 100);
+        assert_eq!(config.backoff_ceiling_ms(3), 400);
+        assert_eq!(config.backoff_ceiling_ms(4), 750);
+        assert_eq!(config.backoff_ceiling_ms(64), 750);
+
+        for attempt in 1..=config.max_attempts + 20 {
+            for _ in 0..50 {
+                let delay = config.jittered_delay(attempt);
+                assert!(delay <= Duration::from_millis(config.max_delay_ms));
+                assert!(delay <= Duration::from_millis(config.backoff_ceiling_ms(attempt)));
+            }
+        }
+    }
+
+    /// Serve `/accounts/:id/payments` locally, answering from `responses` in
+    /// order (repeating the last one) and counting hits
+    async fn spawn_horizon_stub(
+        responses: Vec<(u16, Option<&'static str>)>,
+    ) -> (String, Arc<std::sync::atomic::AtomicUsize>) {
+        use axum::http::{HeaderMap, StatusCode};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let hits = Arc::new(AtomicUsize::new(0));
+        let hits_clone = Arc::clone(&hits);
+        let app = axum::Router::new().route(
+            "/accounts/:id/payments",
+            axum::routing::get(move || {
+                let hits = Arc::clone(&hits_clone);
+                let responses = responses.clone();
+                async move {
+                    let hit = hits.fetch_add(1, Ordering::SeqCst);
+                    let (status, retry_after) = responses[hit.min(responses.len() - 1)];
+                    let mut headers = HeaderMap::new();
+                    if let Some(value) = retry_after {
+                        headers.insert("Retry-After", value.parse().unwrap());
+                    }
+                    (
+                        StatusCode::from_u16(status).unwrap(),
+                        headers,
+                        r#"{"_embedded":{"records":[]}}"#,
+                    )
+                }
+            }),
+        );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        (format!("http://{}", addr), hits)
+    }
+
+    fn client_without_backoff(horizon_url: String) -> StellarRpcClient {
+        StellarRpcClient::new_with_retry_config(
+            "http://127.0.0.1:1".to_string(),
+            horizon_url,
+            false,
+            RetryConfig {
+                max_attempts: 3,
+                base_delay_ms: 0,
+                // Zero base delay keeps backoff at zero; this only caps Retry-After
+                max_delay_ms: 1_000,
+            },
+        )
+    }
+
+    #[test]
+    fn test_get_transaction_response_parses() {
+        let body = r#"{
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {
+                "status": "SUCCESS",
+                "latestLedger": 51565830,
+                "ledger": 51565821,
+                "createdAt": "1734032457",
+                "envelopeXdr": "AAAAAgAAAAA=",
+                "resultXdr": "AAAAAAAAAGQAAAAAAAAAAQAAAAAAAAABAAAAAAAAAAA="
+            }
+        }"#;
+        let response: JsonRpcResponse<TransactionStatus> = serde_json::from_str(body).unwrap();
+        let status = response.result.unwrap();
+        assert_eq!(status.status, TransactionState::Success);
+        assert_eq!(status.ledger, Some(51565821));
+        assert!(status.result_xdr.is_some());
+
+        // Unknown hashes carry no ledger or result
+        let body =
+            r#"{"jsonrpc":"2.0","id":1,"result":{"status":"NOT_FOUND","latestLedger":51565830}}"#;
+        let response: JsonRpcResponse<TransactionStatus> = serde_json::from_str(body).unwrap();
+        let status = response.result.unwrap();
+        assert_eq!(status.status, TransactionState::NotFound);
+        assert_eq!(status.ledger, None);
+        assert_eq!(status.result_xdr, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_mock_reports_success() {
+        let client = StellarRpcClient::new_with_defaults(true);
+        let status = client.get_transaction("abc123").await.unwrap();
+        assert_eq!(status.status, TransactionState::Success);
+        assert_eq!(status.ledger, Some(MOCK_LATEST_LEDGER));
+    }
+
+    #[tokio::test]
+    async fn test_retry_request_waits_for_retry_after_on_429() {
+        let (horizon_url, hits) = spawn_horizon_stub(vec![(429, Some("1")), (200, None)]).await;
+        let client = client_without_backoff(horizon_url);
+
+        let start = Instant::now();
+        let payments = client
+            .fetch_all_account_payments("GACCOUNT", Some(10))
+            .await
+            .unwrap();
+
+        assert!(payments.is_empty());
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 2);
+        // Backoff is zero, so the only wait is the server's Retry-After
+        assert!(start.elapsed() >= Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_retry_request_caps_retry_after_at_max_delay() {
+        let (horizon_url, hits) = spawn_horizon_stub(vec![(429, Some("3600")), (200, None)]).await;
+        let client = client_without_backoff(horizon_url);
+
+        let start = Instant::now();
+        let payments = tokio::time::timeout(
+            Duration::from_secs(10),
+            client.fetch_all_account_payments("GACCOUNT", Some(10)),
+        )
+        .await
+        .expect("Retry-After should be capped at max_delay_ms")
+        .unwrap();
+
+        assert!(payments.is_empty());
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert!(start.elapsed() >= Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_retry_request_fails_fast_on_404() {
+        let (horizon_url, hits) = spawn_horizon_stub(vec![(404, None)]).await;
+        let client = client_without_backoff(horizon_url);
+
+        let result = client
+            .fetch_all_account_payments("GACCOUNT", Some(10))
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    /// Serve every request locally after `delay`
+    async fn spawn_slow_stub(delay: Duration) -> String {
+        let app = axum::Router::new().fallback(move || async move {
+            tokio::time::sleep(delay).await;
+            r#"{"jsonrpc":"2.0","id":1,"result":{"status":"healthy"}}"#
+        });
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_payment_outcomes_include_failed_transactions() {
+        let app = axum::Router::new().route(
+            "/payments",
+            axum::routing::get(
+                |query: axum::extract::Query<std::collections::HashMap<String, String>>| async move {
+                    assert_eq!(
+                        query.get("include_failed").map(String::as_str),
+                        Some("true")
+                    );
+                    let record = |id: u32, successful: bool| {
+                        json!({
+                            "id": id.to_string(),
+                            "paging_token": id.to_string(),
+                            "transaction_hash": format!("tx{}", id),
+                            "transaction_successful": successful,
+                            "source_account": "GSOURCE",
+                            "to": "GDEST",
+                            "asset_type": "native",
+                            "amount": "5.0000000",
+                            "created_at": "2026-01-22T00:00:00Z",
+                            "type": "payment"
+                        })
+                    };
+                    axum::Json(json!({
+                        "_embedded": {
+                            "records": [record(1, true), record(2, false), record(3, true)]
+                        }
+                    }))
+                },
+            ),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        let client = client_without_backoff(format!("http://{}", addr));
+        let outcomes = client.fetch_all_payment_outcomes(Some(10)).await.unwrap();
+
+        let successful: Vec<bool> = outcomes.iter().map(|o| o.successful).collect();
+        assert_eq!(successful, vec![true, false, true]);
+        assert_eq!(outcomes[1].payment.transaction_hash, "tx2");
+    }
+
+    #[tokio::test]
+    async fn test_cursor_is_encoded_into_the_query() {
+        let app = axum::Router::new().route(
+            "/trades",
+            axum::routing::get(
+                |query: axum::extract::Query<std::collections::HashMap<String, String>>| async move {
+                    assert_eq!(
+                        query.get("cursor").map(String::as_str),
+                        Some("123-4&order=asc")
+                    );
+                    assert_eq!(query.get("order").map(String::as_str), Some("desc"));
+                    r#"{"_embedded":{"records":[]}}"#
+                },
+            ),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        let client = client_without_backoff(format!("http://{}", addr));
+        let trades = client
+            .fetch_trades(10, Some("123-4&order=asc"))
+            .await
+            .unwrap();
+
+        assert!(trades.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_ledger_payments_follow_cursor_past_the_first_page() {
+        // 250 payments in ledger 7: a full page of 200, then the last 50
+        let app = axum::Router::new().route(
+            "/ledgers/:sequence/payments",
+            axum::routing::get(
+                |axum::extract::Path(sequence): axum::extract::Path<u64>,
+                 query: axum::extract::Query<HashMap<String, String>>| async move {
+                    assert_eq!(sequence, 7);
+                    let start = query
+                        .get("cursor")
+                        .map_or(0, |cursor| cursor.parse::<usize>().unwrap() + 1);
+                    let records: Vec<_> = (start..250.min(start + LEDGER_PAGE_LIMIT))
+                        .map(|i| {
+                            json!({
+                                "id": format!("payment_{}", i),
+                                "paging_token": i.to_string(),
+                                "transaction_hash": "tx1",
+                                "source_account": "GSOURCE",
+                                "to": "GDEST",
+                                "asset_type": "native",
+                                "amount": "5.0000000",
+                                "created_at": "2026-01-22T00:00:00Z",
+                                "type": "payment"
+                            })
+                        })
+                        .collect();
+                    axum::Json(json!({ "_embedded": { "records": records } }))
+                },
+            ),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        let client = client_without_backoff(format!("http://{}", addr));
+        let payments = client.fetch_payments_for_ledger(7).await.unwrap();
+
+        assert_eq!(payments.len(), 250);
+        assert_eq!(payments[249].id, "payment_249");
+    }
+
+    #[tokio::test]
+    async fn test_short_request_timeout_fails_slow_calls() {
+        let url = spawn_slow_stub(Duration::from_secs(5)).await;
+        let client = StellarRpcClient::new_with_retry_config(
+            url.clone(),
+            url,
+            false,
+            RetryConfig {
+                max_attempts: 1,
+                base_delay_ms: 0,
+                max_delay_ms: 0,
+            },
+        )
+        .with_request_timeouts(RequestTimeouts {
+            health: Duration::from_millis(100),
+            ..RequestTimeouts::default()
+        });
+        assert_eq!(client.request_timeouts().payments, Duration::from_secs(30));
+
+        let started = Instant::now();
+        let result = client.check_health().await;
+
+        assert!(
+            matches!(result, Err(RpcError::TimeoutError(_))),
+            "{:?}",
+            result
+        );
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_default_headers_sent_with_requests() {
+        use axum::http::HeaderMap;
+        use std::sync::Mutex;
+
+        let seen: Arc<Mutex<Vec<HeaderMap>>> = Arc::default();
+        let seen_clone = Arc::clone(&seen);
+        let app = axum::Router::new().fallback(move |headers: HeaderMap| {
+            let seen = Arc::clone(&seen_clone);
+            async move {
+                seen.lock().unwrap().push(headers);
+                axum::Json(json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "result": {
+                        "status": "healthy",
+                        "latestLedger": 100,
+                        "oldestLedger": 1,
+                        "ledgerRetentionWindow": 99
+                    }
+                }))
+            }
+        });
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        let url = format!("http://{}", addr);
+        let client = StellarRpcClient::new_with_headers(
+            url.clone(),
+            url,
+            false,
+            RetryConfig {
+                max_attempts: 1,
+                base_delay_ms: 0,
+                max_delay_ms: 0,
+            },
+            HashMap::from([
+                ("X-Api-Key".to_string(), "secret-key".to_string()),
+                ("Bad Header".to_string(), "skipped".to_string()),
+            ]),
+        );
+
+        client.check_health().await.unwrap();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 1);
+        assert_eq!(seen[0]["x-api-key"], "secret-key");
+        assert!(!seen[0].contains_key("bad header"));
+    }
+
+    #[test]
+    fn test_parse_default_headers() {
+        use crate::rpc::config::parse_default_headers;
+
+        let headers =
+            parse_default_headers(" X-Api-Key = abc=123 ,X-Client-Name=insights,,=x,novalue");
+        assert_eq!(
+            headers,
+            HashMap::from([
+                ("X-Api-Key".to_string(), "abc=123".to_string()),
+                ("X-Client-Name".to_string(), "insights".to_string()),
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_account_trades_mock() {
+        let client = StellarRpcClient::new_with_defaults(true);
+        let account = "GACCOUNTXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+
+        let trades = client.fetch_account_trades(account, 7, None).await.unwrap();
+
+        assert_eq!(trades.len(), 7);
+        assert!(trades
+            .iter()
+            .all(|t| t.base_account == account || t.counter_account == account));
+    }
+
+    #[tokio::test]
+    async fn test_mock_config_seed_reproduces_payments() {
+        use crate::rpc::mock::MockConfig;
+
+        let seeded = |seed: u64| {
+            StellarRpcClient::new_with_defaults(true).with_mock_config(MockConfig {
+                path_payment_ratio: 0.3,
+                ..MockConfig::with_seed(seed)
+            })
+        };
+        let fetch = |client: StellarRpcClient| async move {
+            let payments = client.fetch_payments(50, None).await.unwrap();
+            serde_json::to_value(payments).unwrap()
+        };
+
+        let first = fetch(seeded(1234)).await;
+        assert_eq!(first, fetch(seeded(1234)).await);
+        assert_ne!(first, fetch(seeded(4321)).await);
+
+        // Without a config the fixed series is unchanged
+        let default = StellarRpcClient::new_with_defaults(true)
+            .fetch_payments(10, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(default).unwrap(),
+            serde_json::to_value(StellarRpcClient::mock_payments(10)).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_payments_for_ledger_range_mock() {
+        let client = StellarRpcClient::new_with_defaults(true);
+
+        let range = client.fetch_payments_for_ledger_range(100, 103).await;
+
+        assert!(range.error.is_none());
+        assert_eq!(range.last_processed, Some(103));
+        assert_eq!(range.payments.len(), 20);
+        assert_eq!(range.payments[0].id, "payment_100_0");
+        assert_eq!(range.payments[19].id, "payment_103_4");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_payments_for_ledger_range_stops_at_failed_ledger() {
+        use axum::extract::Path;
+        use axum::http::StatusCode;
+
+        let app = axum::Router::new().route(
+            "/ledgers/:sequence/payments",
+            axum::routing::get(|Path(sequence): Path<u64>| async move {
+                if sequence == 12 {
+                    (StatusCode::NOT_FOUND, "{}".to_string())
+                } else {
+                    (
+                        StatusCode::OK,
+                        r#"{"_embedded":{"records":[]}}"#.to_string(),
+                    )
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        let client = client_without_backoff(format!("http://{}", addr));
+
+        let range = client.fetch_payments_for_ledger_range(10, 20).await;
+
+        assert_eq!(range.last_processed, Some(11));
+        assert!(matches!(
+            range.error,
+            Some(RpcError::ServerError { status: 404, .. })
+        ));
+    }
+
+    #[test]
+    fn test_rate_limit_error_exposes_retry_after() {
+        let error = RpcError::RateLimitError {
+            retry_after: Some(Duration::from_secs(3)),
+        };
+        assert_eq!(error.retry_after(), Some(Duration::from_secs(3)));
+        assert!(RpcError::NetworkError("reset".to_string())
+            .retry_after()
+            .is_none());
+        assert!(!RpcError::ServerError {
+            status: 404,
+            message: "not found".to_string(),
+        }
+        .is_transient());
+    }
+
     #[tokio::test]
     async fn test_mock_health_check() {
         let client = StellarRpcClient::new_with_defaults(true);
@@ -2474,6 +4017,147 @@ mod tests {
         assert_eq!(payments.len(), client.max_total_records as usize);
     }
 
+    #[tokio::test]
+    async fn test_concurrent_pagination_keeps_page_order() {
+        let client = StellarRpcClient::new_with_defaults(true).with_pagination_concurrency(4);
+
+        let payments = client.fetch_all_payments(Some(1000)).await.unwrap();
+        assert_eq!(payments.len(), 1000);
+        for (i, payment) in payments.iter().enumerate() {
+            assert_eq!(payment.id, format!("payment_{}", i));
+        }
+
+        let trades = client.fetch_all_trades(Some(450)).await.unwrap();
+        assert_eq!(trades.len(), 450);
+        for (i, trade) in trades.iter().enumerate() {
+            assert_eq!(trade.id, format!("trade_{}", i));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_pages_respect_the_permit_limit() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let client = StellarRpcClient::new_with_defaults(true).with_pagination_concurrency(3);
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+
+        let records = client
+            .fetch_pages_concurrently(20, |page| {
+                let (in_flight, peak) = (&in_flight, &peak);
+                async move {
+                    let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    // Later pages finish first
+                    tokio::time::sleep(Duration::from_millis(20 - page as u64)).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    Ok((page < 10).then(|| vec![page * 2, page * 2 + 1]))
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(records, (0..20).collect::<Vec<u32>>());
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_fetch_pages_past_the_scanned_ledgers() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        const LATEST_LEDGER: u32 = 100_000;
+        // One payment in the latest ledger and one well past the scan window
+        const PAYMENT_LEDGERS: [u32; 2] = [LATEST_LEDGER, LATEST_LEDGER - 5_000];
+        let hits = Arc::new(AtomicUsize::new(0));
+        let app = axum::Router::new().route(
+            "/payments",
+            axum::routing::get({
+                let hits = Arc::clone(&hits);
+                move |query: axum::extract::Query<HashMap<String, String>>| {
+                    let hits = Arc::clone(&hits);
+                    async move {
+                        hits.fetch_add(1, Ordering::SeqCst);
+                        // order=desc: every payment below the cursor, newest first
+                        let below = query
+                            .get("cursor")
+                            .map_or(i64::MAX, |cursor| cursor.parse().unwrap());
+                        let limit: usize = query["limit"].parse().unwrap();
+                        let records: Vec<_> = PAYMENT_LEDGERS
+                            .iter()
+                            .map(|ledger| ledger_start_toid(*ledger) + 1)
+                            .filter(|toid| *toid < below)
+                            .take(limit)
+                            .map(|toid| {
+                                let token = toid.to_string();
+                                json!({
+                                    "id": token,
+                                    "paging_token": token,
+                                    "transaction_hash": "tx1",
+                                    "source_account": "GSOURCE",
+                                    "to": "GDEST",
+                                    "asset_type": "native",
+                                    "amount": "5.0000000",
+                                    "created_at": "2026-01-22T00:00:00Z",
+                                    "type": "payment"
+                                })
+                            })
+                            .collect();
+                        axum::Json(json!({ "_embedded": { "records": records } }))
+                    }
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        let client =
+            client_without_backoff(format!("http://{}", addr)).with_pagination_concurrency(8);
+
+        let payments = client.fetch_all_payments(Some(5000)).await.unwrap();
+
+        let ledgers: Vec<_> = payments
+            .iter()
+            .map(|p| toid_ledger(&p.paging_token).unwrap())
+            .collect();
+        assert_eq!(ledgers, PAYMENT_LEDGERS);
+        // The latest payment, one request per scanned ledger, then a single
+        // cursor page covering everything older
+        assert_eq!(
+            hits.load(Ordering::SeqCst),
+            1 + MAX_CONCURRENT_SCAN_LEDGERS as usize + 1
+        );
+    }
+
+    #[test]
+    fn test_toid_ledger_reads_payment_and_trade_tokens() {
+        let toid = ledger_start_toid(51_565_820) + (3 << 12) + 1;
+        assert_eq!(toid_ledger(&toid.to_string()), Some(51_565_820));
+        assert_eq!(toid_ledger(&format!("{}-2", toid)), Some(51_565_820));
+        assert_eq!(toid_ledger("paging_1"), None);
+    }
+
+    #[tokio::test]
+    async fn test_mock_fetch_payments_after_honours_cursor() {
+        let client = StellarRpcClient::new_with_defaults(true);
+
+        let payments = client.fetch_payments_after("paging_3", 200).await.unwrap();
+        let tokens: Vec<_> = payments.iter().map(|p| p.paging_token.as_str()).collect();
+        assert_eq!(tokens, ["paging_2", "paging_1", "paging_0"]);
+
+        let payments = client.fetch_payments_after("paging_3", 2).await.unwrap();
+        assert_eq!(payments.len(), 2);
+        assert_eq!(payments[1].paging_token, "paging_1");
+
+        // Nothing is newer than the head of the series
+        assert!(client
+            .fetch_payments_after("paging_0", 200)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
     #[tokio::test]
     async fn test_pagination_respects_max_records() {
         let client = StellarRpcClient::new_with_defaults(true);
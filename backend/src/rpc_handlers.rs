@@ -7,7 +7,11 @@ use axum::{
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
-use crate::rpc::{Asset, StellarRpcClient};
+use crate::api::limits::validate_limit;
+use crate::api::{anchors_cached, corridors_cached};
+use crate::error::ApiResult;
+use crate::rpc::circuit_breaker::CircuitBreakerStatus;
+use crate::rpc::{Asset, LedgerInfo, OrderBook, Payment, StellarRpcClient, Trade};
 
 #[derive(Debug, Deserialize)]
 pub struct PaginationQuery {
@@ -37,6 +41,19 @@ pub struct ErrorResponse {
     pub error: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct CircuitBreakerEntry {
+    /// Which caller the breaker guards
+    pub name: String,
+    #[serde(flatten)]
+    pub status: CircuitBreakerStatus,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CircuitBreakersResponse {
+    pub breakers: Vec<CircuitBreakerEntry>,
+}
+
 /// Health check for Stellar RPC
 #[tracing::instrument(skip(client))]
 pub async fn rpc_health_check(
@@ -57,16 +74,8 @@ pub async fn rpc_health_check(
 #[tracing::instrument(skip(client))]
 pub async fn get_latest_ledger(
     State(client): State<Arc<StellarRpcClient>>,
-) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
-    match client.fetch_latest_ledger().await {
-        Ok(ledger) => Ok(Json(ledger)),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("Failed to fetch ledger: {}", e),
-            }),
-        )),
-    }
+) -> ApiResult<Json<LedgerInfo>> {
+    Ok(Json(client.fetch_latest_ledger().await?))
 }
 
 /// Get recent payments
@@ -74,17 +83,10 @@ pub async fn get_latest_ledger(
 pub async fn get_payments(
     State(client): State<Arc<StellarRpcClient>>,
     Query(params): Query<PaginationQuery>,
-) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+) -> ApiResult<Json<Vec<Payment>>> {
+    validate_limit(i64::from(params.limit))?;
     let cursor = params.cursor.as_deref();
-    match client.fetch_payments(params.limit, cursor).await {
-        Ok(payments) => Ok(Json(payments)),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("Failed to fetch payments: {}", e),
-            }),
-        )),
-    }
+    Ok(Json(client.fetch_payments(params.limit, cursor).await?))
 }
 
 /// Get payments for a specific account
@@ -93,19 +95,12 @@ pub async fn get_account_payments(
     State(client): State<Arc<StellarRpcClient>>,
     Path(account_id): Path<String>,
     Query(params): Query<PaginationQuery>,
-) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
-    match client
+) -> ApiResult<Json<Vec<Payment>>> {
+    validate_limit(i64::from(params.limit))?;
+    let payments = client
         .fetch_account_payments(&account_id, params.limit)
-        .await
-    {
-        Ok(payments) => Ok(Json(payments)),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("Failed to fetch account payments: {}", e),
-            }),
-        )),
-    }
+        .await?;
+    Ok(Json(payments))
 }
 
 /// Get recent trades
@@ -113,17 +108,10 @@ pub async fn get_account_payments(
 pub async fn get_trades(
     State(client): State<Arc<StellarRpcClient>>,
     Query(params): Query<PaginationQuery>,
-) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+) -> ApiResult<Json<Vec<Trade>>> {
+    validate_limit(i64::from(params.limit))?;
     let cursor = params.cursor.as_deref();
-    match client.fetch_trades(params.limit, cursor).await {
-        Ok(trades) => Ok(Json(trades)),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("Failed to fetch trades: {}", e),
-            }),
-        )),
-    }
+    Ok(Json(client.fetch_trades(params.limit, cursor).await?))
 }
 
 /// Get order book for a trading pair
@@ -131,7 +119,8 @@ pub async fn get_trades(
 pub async fn get_order_book(
     State(client): State<Arc<StellarRpcClient>>,
     Query(params): Query<OrderBookQuery>,
-) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+) -> ApiResult<Json<OrderBook>> {
+    validate_limit(i64::from(params.limit))?;
     let selling_asset = Asset {
         asset_type: params.selling_asset_type,
         asset_code: params.selling_asset_code,
@@ -144,16 +133,30 @@ pub async fn get_order_book(
         asset_issuer: params.buying_asset_issuer,
     };
 
-    match client
+    let order_book = client
         .fetch_order_book(&selling_asset, &buying_asset, params.limit)
-        .await
-    {
-        Ok(order_book) => Ok(Json(order_book)),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("Failed to fetch order book: {}", e),
-            }),
-        )),
+        .await?;
+    Ok(Json(order_book))
+}
+
+/// State of every endpoint circuit breaker
+#[tracing::instrument(skip(client))]
+pub async fn get_circuit_breakers(
+    State(client): State<Arc<StellarRpcClient>>,
+) -> Json<CircuitBreakersResponse> {
+    let breakers = [
+        ("rpc", client.circuit_breaker()),
+        ("anchors", anchors_cached::rpc_circuit_breaker()),
+        ("corridors", corridors_cached::rpc_circuit_breaker()),
+    ];
+
+    let mut entries = Vec::with_capacity(breakers.len());
+    for (name, breaker) in breakers {
+        entries.push(CircuitBreakerEntry {
+            name: name.to_string(),
+            status: breaker.status().await,
+        });
     }
+
+    Json(CircuitBreakersResponse { breakers: entries })
 }
@@ -0,0 +1,420 @@
+//! Dependency probes and the startup self-check
+//!
+//! Probes the Stellar RPC, Horizon, the database and Redis once at startup and
+//! logs a readiness summary. In `strict` mode the server refuses to start when
+//! a critical dependency is down; the default `warn` mode only logs. The same
+//! probes back the `/health/ready` endpoint.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use sqlx::SqlitePool;
+
+use crate::cache::CacheManager;
+use crate::rate_limit::RateLimiter;
+use crate::rpc::StellarRpcClient;
+
+/// How startup reacts to failed dependency checks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelfCheckMode {
+    /// Skip the self-check
+    Off,
+    /// Log failures and keep starting
+    Warn,
+    /// Refuse to start when a critical dependency is down
+    Strict,
+}
+
+impl SelfCheckMode {
+    /// Load from STARTUP_SELF_CHECK (off, warn, strict; default: warn)
+    pub fn from_env() -> Self {
+        match std::env::var("STARTUP_SELF_CHECK")
+            .unwrap_or_default()
+            .to_lowercase()
+            .as_str()
+        {
+            "off" | "false" | "0" => Self::Off,
+            "strict" => Self::Strict,
+            _ => Self::Warn,
+        }
+    }
+}
+
+/// Per-dependency probe timeout from STARTUP_SELF_CHECK_TIMEOUT_SECS (default: 5)
+pub fn timeout_from_env() -> Duration {
+    let secs = std::env::var("STARTUP_SELF_CHECK_TIMEOUT_SECS")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(5);
+    Duration::from_secs(secs)
+}
+
+/// A single dependency the service talks to
+#[async_trait::async_trait]
+pub trait DependencyProbe: Send + Sync {
+    /// Short name used in logs and reports
+    fn name(&self) -> &'static str;
+
+    /// Whether the service cannot do useful work without this dependency
+    fn critical(&self) -> bool;
+
+    /// Check the dependency, returning a reason on failure
+    async fn probe(&self) -> Result<(), String>;
+}
+
+/// Outcome of probing one dependency
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyStatus {
+    pub name: &'static str,
+    pub critical: bool,
+    pub up: bool,
+    pub latency_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Outcome of probing every dependency
+#[derive(Debug, Clone, Serialize)]
+pub struct SelfCheckReport {
+    pub dependencies: Vec<DependencyStatus>,
+}
+
+impl SelfCheckReport {
+    /// True when every critical dependency is up
+    pub fn critical_up(&self) -> bool {
+        self.dependencies.iter().all(|d| d.up || !d.critical)
+    }
+
+    /// Names of critical dependencies that are down
+    pub fn failed_critical(&self) -> Vec<&'static str> {
+        self.dependencies
+            .iter()
+            .filter(|d| d.critical && !d.up)
+            .map(|d| d.name)
+            .collect()
+    }
+
+    /// Log one line per dependency followed by an overall verdict
+    pub fn log_summary(&self) {
+        for dep in &self.dependencies {
+            match (&dep.error, dep.critical) {
+                (None, _) => tracing::info!("Self-check: {} up ({} ms)", dep.name, dep.latency_ms),
+                (Some(e), true) => tracing::error!(
+                    "Self-check: {} DOWN ({} ms, critical): {}",
+                    dep.name,
+                    dep.latency_ms,
+                    e
+                ),
+                (Some(e), false) => tracing::warn!(
+                    "Self-check: {} down ({} ms, non-critical): {}",
+                    dep.name,
+                    dep.latency_ms,
+                    e
+                ),
+            }
+        }
+
+        let up = self.dependencies.iter().filter(|d| d.up).count();
+        if self.critical_up() {
+            tracing::info!(
+                "Self-check complete: {}/{} dependencies up, ready",
+                up,
+                self.dependencies.len()
+            );
+        } else {
+            tracing::error!(
+                "Self-check complete: {}/{} dependencies up, critical down: {}",
+                up,
+                self.dependencies.len(),
+                self.failed_critical().join(", ")
+            );
+        }
+    }
+}
+
+/// Probe every dependency concurrently, bounding each by `timeout`
+pub async fn run_checks(probes: &[Arc<dyn DependencyProbe>], timeout: Duration) -> SelfCheckReport {
+    let checks = probes.iter().map(|probe| async move {
+        let start = Instant::now();
+        let result = match tokio::time::timeout(timeout, probe.probe()).await {
+            Ok(result) => result,
+            Err(_) => Err(format!("timed out after {:?}", timeout)),
+        };
+        DependencyStatus {
+            name: probe.name(),
+            critical: probe.critical(),
+            up: result.is_ok(),
+            latency_ms: start.elapsed().as_millis() as u64,
+            error: result.err(),
+        }
+    });
+
+    SelfCheckReport {
+        dependencies: futures::future::join_all(checks).await,
+    }
+}
+
+/// Run the startup self-check according to `mode`.
+///
+/// Returns an error only in strict mode with a critical dependency down.
+pub async fn run_startup_self_check(
+    mode: SelfCheckMode,
+    probes: &[Arc<dyn DependencyProbe>],
+    timeout: Duration,
+) -> anyhow::Result<Option<SelfCheckReport>> {
+    if mode == SelfCheckMode::Off {
+        tracing::info!("Startup self-check disabled");
+        return Ok(None);
+    }
+
+    let report = run_checks(probes, timeout).await;
+    report.log_summary();
+
+    if mode == SelfCheckMode::Strict && !report.critical_up() {
+        anyhow::bail!(
+            "Startup self-check failed (STARTUP_SELF_CHECK=strict): critical dependencies down: {}",
+            report.failed_critical().join(", ")
+        );
+    }
+
+    Ok(Some(report))
+}
+
+/// Stellar RPC `getHealth`
+pub struct RpcProbe(pub Arc<StellarRpcClient>);
+
+#[async_trait::async_trait]
+impl DependencyProbe for RpcProbe {
+    fn name(&self) -> &'static str {
+        "stellar_rpc"
+    }
+
+    fn critical(&self) -> bool {
+        true
+    }
+
+    async fn probe(&self) -> Result<(), String> {
+        let health = self.0.check_health().await.map_err(|e| e.to_string())?;
+        if health.status == "healthy" {
+            Ok(())
+        } else {
+            Err(format!("RPC reports status '{}'", health.status))
+        }
+    }
+}
+
+/// Horizon reachability via the latest ledger
+pub struct HorizonProbe(pub Arc<StellarRpcClient>);
+
+#[async_trait::async_trait]
+impl DependencyProbe for HorizonProbe {
+    fn name(&self) -> &'static str {
+        "horizon"
+    }
+
+    fn critical(&self) -> bool {
+        true
+    }
+
+    async fn probe(&self) -> Result<(), String> {
+        self.0
+            .fetch_latest_ledger()
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// SQLite via `SELECT 1`
+pub struct DatabaseProbe(pub SqlitePool);
+
+#[async_trait::async_trait]
+impl DependencyProbe for DatabaseProbe {
+    fn name(&self) -> &'static str {
+        "database"
+    }
+
+    fn critical(&self) -> bool {
+        true
+    }
+
+    async fn probe(&self) -> Result<(), String> {
+        sqlx::query("SELECT 1")
+            .execute(&self.0)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Redis behind the cache manager; the service degrades to uncached without it
+pub struct CacheProbe(pub Arc<CacheManager>);
+
+#[async_trait::async_trait]
+impl DependencyProbe for CacheProbe {
+    fn name(&self) -> &'static str {
+        "cache"
+    }
+
+    fn critical(&self) -> bool {
+        false
+    }
+
+    async fn probe(&self) -> Result<(), String> {
+        self.0.ping().await.map_err(|e| e.to_string())
+    }
+}
+
+/// Redis connection used for rate limiting; limits fall back to memory without it
+pub struct RateLimitRedisProbe(pub Arc<RateLimiter>);
+
+#[async_trait::async_trait]
+impl DependencyProbe for RateLimitRedisProbe {
+    fn name(&self) -> &'static str {
+        "redis"
+    }
+
+    fn critical(&self) -> bool {
+        false
+    }
+
+    async fn probe(&self) -> Result<(), String> {
+        self.0.ping_redis().await.map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockProbe {
+        name: &'static str,
+        critical: bool,
+        result: Result<(), String>,
+        delay: Duration,
+    }
+
+    fn mock(
+        name: &'static str,
+        critical: bool,
+        result: Result<(), &str>,
+    ) -> Arc<dyn DependencyProbe> {
+        Arc::new(MockProbe {
+            name,
+            critical,
+            result: result.map_err(str::to_string),
+            delay: Duration::ZERO,
+        })
+    }
+
+    #[async_trait::async_trait]
+    impl DependencyProbe for MockProbe {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn critical(&self) -> bool {
+            self.critical
+        }
+
+        async fn probe(&self) -> Result<(), String> {
+            tokio::time::sleep(self.delay).await;
+            self.result.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_report_lists_each_dependency_status() {
+        let probes = vec![
+            mock("stellar_rpc", true, Ok(())),
+            mock("horizon", true, Ok(())),
+            mock("database", true, Ok(())),
+            mock("redis", false, Err("connection refused")),
+        ];
+
+        let report = run_checks(&probes, Duration::from_secs(1)).await;
+
+        let names: Vec<_> = report.dependencies.iter().map(|d| d.name).collect();
+        assert_eq!(names, vec!["stellar_rpc", "horizon", "database", "redis"]);
+        assert!(report.dependencies[..3].iter().all(|d| d.up));
+        let redis = &report.dependencies[3];
+        assert!(!redis.up);
+        assert_eq!(redis.error.as_deref(), Some("connection refused"));
+        // Redis is non-critical, so the service is still ready
+        assert!(report.critical_up());
+    }
+
+    #[tokio::test]
+    async fn test_slow_probe_times_out() {
+        let probes: Vec<Arc<dyn DependencyProbe>> = vec![Arc::new(MockProbe {
+            name: "horizon",
+            critical: true,
+            result: Ok(()),
+            delay: Duration::from_secs(5),
+        })];
+
+        let report = run_checks(&probes, Duration::from_millis(20)).await;
+
+        assert!(!report.dependencies[0].up);
+        assert!(report.dependencies[0]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("timed out"));
+        assert_eq!(report.failed_critical(), vec!["horizon"]);
+    }
+
+    #[tokio::test]
+    async fn test_warn_mode_starts_with_critical_dependency_down() {
+        let probes = vec![mock("database", true, Err("unable to open database file"))];
+
+        let report = run_startup_self_check(SelfCheckMode::Warn, &probes, Duration::from_secs(1))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(!report.critical_up());
+    }
+
+    #[tokio::test]
+    async fn test_strict_mode_refuses_to_start_with_critical_dependency_down() {
+        let probes = vec![
+            mock("stellar_rpc", true, Err("connection refused")),
+            mock("redis", false, Err("connection refused")),
+        ];
+
+        let error = run_startup_self_check(SelfCheckMode::Strict, &probes, Duration::from_secs(1))
+            .await
+            .unwrap_err();
+
+        let message = error.to_string();
+        assert!(message.contains("stellar_rpc"));
+        assert!(!message.contains("redis"));
+    }
+
+    #[tokio::test]
+    async fn test_mock_rpc_client_probes_are_up() {
+        let client = Arc::new(StellarRpcClient::new_with_defaults(true));
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let probes: Vec<Arc<dyn DependencyProbe>> = vec![
+            Arc::new(RpcProbe(Arc::clone(&client))),
+            Arc::new(HorizonProbe(client)),
+            Arc::new(DatabaseProbe(pool)),
+        ];
+
+        let report = run_checks(&probes, Duration::from_secs(1)).await;
+
+        assert!(report.dependencies.iter().all(|d| d.up));
+    }
+
+    #[test]
+    fn test_self_check_mode_from_env() {
+        std::env::set_var("STARTUP_SELF_CHECK", "strict");
+        assert_eq!(SelfCheckMode::from_env(), SelfCheckMode::Strict);
+        std::env::set_var("STARTUP_SELF_CHECK", "off");
+        assert_eq!(SelfCheckMode::from_env(), SelfCheckMode::Off);
+        std::env::remove_var("STARTUP_SELF_CHECK");
+        assert_eq!(SelfCheckMode::from_env(), SelfCheckMode::Warn);
+    }
+}
@@ -1,13 +1,15 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, Duration, Timelike, Utc};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::time::{interval, Duration as TokioDuration};
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
 use crate::database::Database;
-use crate::models::corridor::CorridorMetrics;
+use crate::models::corridor::{CorridorMetrics, PaymentRecord};
 use crate::services::analytics::compute_metrics_from_payments;
+use crate::services::price_feed::PriceFeedClient;
 
 const MAX_RETRIES: i32 = 3;
 const RETRY_DELAY_SECS: u64 = 60;
@@ -32,11 +34,23 @@ impl Default for AggregationConfig {
 pub struct AggregationService {
     db: Arc<Database>,
     config: AggregationConfig,
+    price_feed: Option<Arc<PriceFeedClient>>,
 }
 
 impl AggregationService {
     pub fn new(db: Arc<Database>, config: AggregationConfig) -> Self {
-        Self { db, config }
+        Self {
+            db,
+            config,
+            price_feed: None,
+        }
+    }
+
+    /// Convert payment amounts to USD with the given price feed. Without one,
+    /// amounts are taken to already be in USD.
+    pub fn with_price_feed(mut self, price_feed: Arc<PriceFeedClient>) -> Self {
+        self.price_feed = Some(price_feed);
+        self
     }
 
     /// Start the hourly aggregation job scheduler
@@ -102,12 +116,101 @@ impl AggregationService {
         }
     }
 
+    /// Rebuild the hourly metrics of every hour from `start`'s through `end`'s
+    /// out of the stored payments, replacing what was aggregated for those
+    /// hours before. The scheduled job only looks back from now, so this is
+    /// how payments backfilled into past hours reach the metrics. Returns the
+    /// number of hourly metrics stored.
+    pub async fn reaggregate_hours(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<usize> {
+        let job_id = Uuid::new_v4().to_string();
+        self.create_job_record(&job_id, "reaggregate").await?;
+        self.update_job_status(&job_id, "running", None).await?;
+
+        match self.execute_reaggregation(&job_id, start, end).await {
+            Ok(metrics_count) => {
+                info!(
+                    "Re-aggregation completed successfully. Stored {} corridor metrics",
+                    metrics_count
+                );
+                self.update_job_status(&job_id, "completed", None).await?;
+                Ok(metrics_count)
+            }
+            Err(e) => {
+                error!("Re-aggregation failed: {}", e);
+                self.update_job_status(&job_id, "failed", Some(&e.to_string()))
+                    .await?;
+                Err(e)
+            }
+        }
+    }
+
     /// Execute the actual aggregation logic
     async fn execute_aggregation(&self, job_id: &str, now: DateTime<Utc>) -> Result<usize> {
         // Calculate time window for aggregation
         let end_time = now;
         let start_time = end_time - Duration::hours(self.config.lookback_hours);
 
+        let hourly_metrics = self.aggregate_payments(start_time, end_time).await?;
+        if hourly_metrics.is_empty() {
+            return Ok(0);
+        }
+
+        // Store aggregated metrics
+        let stored_count = self.store_hourly_metrics(hourly_metrics).await?;
+
+        // Update last processed hour
+        let last_hour = self.truncate_to_hour(end_time);
+        self.update_last_processed_hour(job_id, last_hour).await?;
+
+        Ok(stored_count)
+    }
+
+    /// Replace the hourly metrics of each hour in turn, so no single fetch
+    /// spans more than an hour of payments
+    async fn execute_reaggregation(
+        &self,
+        job_id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<usize> {
+        let mut stored_count = 0;
+        let mut hour = self.truncate_to_hour(start);
+
+        while hour <= end {
+            let next_hour = hour + Duration::hours(1);
+            // The window ends just short of the next hour, so every payment
+            // aggregated here belongs to `hour`
+            let mut hourly_metrics = self
+                .aggregate_payments(hour, next_hour - Duration::microseconds(1))
+                .await?;
+            for metric in &mut hourly_metrics {
+                metric.hour_bucket = hour;
+            }
+
+            self.db
+                .delete_hourly_metrics_in_range(hour, next_hour)
+                .await
+                .context("Failed to clear hourly metrics for re-aggregation")?;
+            stored_count += self.store_hourly_metrics(hourly_metrics).await?;
+            self.update_last_processed_hour(job_id, hour).await?;
+
+            hour = next_hour;
+        }
+
+        Ok(stored_count)
+    }
+
+    /// Hourly corridor metrics of the payments stored between `start_time`
+    /// and `end_time`
+    async fn aggregate_payments(
+        &self,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Vec<HourlyCorridorMetrics>> {
         info!(
             "Aggregating corridor metrics from {} to {}",
             start_time.to_rfc3339(),
@@ -123,30 +226,49 @@ impl AggregationService {
 
         if payments.is_empty() {
             info!("No payments found in time window");
-            return Ok(0);
+            return Ok(Vec::new());
+        }
+        if payments.len() as i64 >= self.config.batch_size {
+            warn!(
+                "Time window holds more than {} payments; aggregating the first {} only",
+                self.config.batch_size, self.config.batch_size
+            );
         }
 
         info!("Processing {} payments", payments.len());
 
+        // Price every asset in the window with a single lookup
+        let (payments, missing_price_corridors) = match &self.price_feed {
+            Some(price_feed) => {
+                let prices = price_feed
+                    .get_prices(&distinct_payment_assets(&payments))
+                    .await;
+                convert_amounts_to_usd(payments, &prices)
+            }
+            None => (payments, HashSet::new()),
+        };
+        if !missing_price_corridors.is_empty() {
+            warn!(
+                "Missing USD prices for {} corridors; their volumes are incomplete",
+                missing_price_corridors.len()
+            );
+        }
+
         // Compute metrics for each corridor
         let corridor_metrics = compute_metrics_from_payments(&payments);
 
         if corridor_metrics.is_empty() {
             info!("No corridor metrics computed");
-            return Ok(0);
+            return Ok(Vec::new());
         }
 
         // Group metrics by hour bucket
-        let hourly_metrics = self.group_by_hour_bucket(corridor_metrics, start_time);
-
-        // Store aggregated metrics
-        let stored_count = self.store_hourly_metrics(hourly_metrics).await?;
-
-        // Update last processed hour
-        let last_hour = self.truncate_to_hour(end_time);
-        self.update_last_processed_hour(job_id, last_hour).await?;
+        let mut hourly_metrics = self.group_by_hour_bucket(corridor_metrics, start_time);
+        for metric in &mut hourly_metrics {
+            metric.has_missing_prices = missing_price_corridors.contains(&metric.corridor_key);
+        }
 
-        Ok(stored_count)
+        Ok(hourly_metrics)
     }
 
     /// Group metrics by hour bucket
@@ -155,8 +277,6 @@ impl AggregationService {
         metrics: Vec<CorridorMetrics>,
         _start_time: DateTime<Utc>, // Reserved for future time-based filtering
     ) -> Vec<HourlyCorridorMetrics> {
-        use std::collections::HashMap;
-
         let mut hourly_map: HashMap<(String, String), HourlyCorridorMetrics> = HashMap::new();
 
         for metric in metrics {
@@ -202,6 +322,7 @@ impl AggregationService {
                     avg_slippage_bps: 0.0, // TODO: Calculate from order book data
                     avg_settlement_latency_ms: metric.avg_settlement_latency_ms,
                     liquidity_depth_usd: metric.liquidity_depth_usd,
+                    has_missing_prices: false,
                 });
         }
 
@@ -326,8 +447,6 @@ impl AggregationService {
 
     /// Compute volume trends from hourly metrics
     fn compute_volume_trends(&self, metrics: Vec<HourlyCorridorMetrics>) -> Vec<VolumeTrend> {
-        use std::collections::HashMap;
-
         let mut corridor_volumes: HashMap<String, Vec<(DateTime<Utc>, f64)>> = HashMap::new();
 
         for metric in metrics {
@@ -391,10 +510,57 @@ impl Clone for AggregationService {
         Self {
             db: Arc::clone(&self.db),
             config: self.config.clone(),
+            price_feed: self.price_feed.clone(),
         }
     }
 }
 
+/// Price feed key (`CODE:ISSUER`, or `XLM:native`) of the asset a payment's
+/// amount is denominated in
+fn payment_asset_key(payment: &PaymentRecord) -> String {
+    if payment.source_asset_issuer.is_empty() || payment.source_asset_issuer == "native" {
+        "XLM:native".to_string()
+    } else {
+        format!(
+            "{}:{}",
+            payment.source_asset_code, payment.source_asset_issuer
+        )
+    }
+}
+
+/// Distinct assets the payments are denominated in, sorted
+pub fn distinct_payment_assets(payments: &[PaymentRecord]) -> Vec<String> {
+    let mut assets: Vec<String> = payments.iter().map(payment_asset_key).collect();
+    assets.sort();
+    assets.dedup();
+    assets
+}
+
+/// Convert payment amounts to USD in one pass over `prices`.
+///
+/// Payments in an asset without a price contribute no volume. Returns the
+/// converted payments and the keys of corridors that had such payments.
+pub fn convert_amounts_to_usd(
+    payments: Vec<PaymentRecord>,
+    prices: &HashMap<String, f64>,
+) -> (Vec<PaymentRecord>, HashSet<String>) {
+    let mut missing_price_corridors = HashSet::new();
+    let converted = payments
+        .into_iter()
+        .map(|mut payment| {
+            match prices.get(&payment_asset_key(&payment)) {
+                Some(price) => payment.amount *= price,
+                None => {
+                    missing_price_corridors.insert(payment.get_corridor().to_string_key());
+                    payment.amount = 0.0;
+                }
+            }
+            payment
+        })
+        .collect();
+    (converted, missing_price_corridors)
+}
+
 #[derive(Debug, Clone)]
 pub struct HourlyCorridorMetrics {
     pub id: String,
@@ -412,6 +578,9 @@ pub struct HourlyCorridorMetrics {
     pub avg_slippage_bps: f64,
     pub avg_settlement_latency_ms: Option<i32>,
     pub liquidity_depth_usd: f64,
+    /// Whether any payment's asset had no USD price, leaving `volume_usd`
+    /// short
+    pub has_missing_prices: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -471,6 +640,7 @@ mod tests {
                 avg_slippage_bps: 10.0,
                 avg_settlement_latency_ms: Some(500),
                 liquidity_depth_usd: 50000.0,
+                has_missing_prices: false,
             },
             HourlyCorridorMetrics {
                 id: "2".to_string(),
@@ -488,6 +658,7 @@ mod tests {
                 avg_slippage_bps: 12.0,
                 avg_settlement_latency_ms: Some(450),
                 liquidity_depth_usd: 55000.0,
+                has_missing_prices: false,
             },
         ];
 
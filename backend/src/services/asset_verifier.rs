@@ -7,15 +7,86 @@ use std::time::Duration;
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
+use crate::db::pagination::{FilterOp, PaginatedQuery, SortDirection};
 use crate::models::asset_verification::{
-    StellarTomlData, VerificationResult, VerificationStatus, VerifiedAsset,
+    StellarTomlData, TrustlineAuthorization, VerificationResult, VerificationStatus, VerifiedAsset,
 };
+use crate::rpc::stellar::{AssetAccounts, AssetFlags};
 
 const STELLAR_EXPERT_API: &str = "https://api.stellar.expert/explorer/public";
+const HORIZON_API: &str = "https://horizon.stellar.org";
 const REQUEST_TIMEOUT_SECS: u64 = 10;
 const MAX_RETRIES: u32 = 3;
 const RETRY_DELAY_MS: u64 = 500;
 
+/// Default minimum reputation score for `Verified`
+pub const DEFAULT_VERIFIED_MIN_SCORE: f64 = 60.0;
+/// Default minimum reputation score for `Provisional`
+pub const DEFAULT_PROVISIONAL_MIN_SCORE: f64 = 40.0;
+/// Default most points the trustline authorization penalty can take away
+pub const DEFAULT_TRUSTLINE_AUTH_WEIGHT: f64 = 10.0;
+
+/// Load the trustline authorization penalty weight from
+/// `ASSET_TRUSTLINE_AUTH_WEIGHT`, falling back to the default when missing or
+/// outside 0-100
+fn trustline_auth_weight_from_env() -> f64 {
+    std::env::var("ASSET_TRUSTLINE_AUTH_WEIGHT")
+        .ok()
+        .and_then(|v| v.trim().parse::<f64>().ok())
+        .filter(|v| (0.0..=100.0).contains(v))
+        .unwrap_or(DEFAULT_TRUSTLINE_AUTH_WEIGHT)
+}
+
+/// Reputation score cutoffs used by `determine_status`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReputationThresholds {
+    pub verified: f64,
+    pub provisional: f64,
+}
+
+impl Default for ReputationThresholds {
+    fn default() -> Self {
+        Self {
+            verified: DEFAULT_VERIFIED_MIN_SCORE,
+            provisional: DEFAULT_PROVISIONAL_MIN_SCORE,
+        }
+    }
+}
+
+impl ReputationThresholds {
+    /// Load from `ASSET_VERIFIED_MIN_SCORE` and `ASSET_PROVISIONAL_MIN_SCORE`
+    pub fn from_env() -> Self {
+        Self::parse(
+            std::env::var("ASSET_VERIFIED_MIN_SCORE").ok().as_deref(),
+            std::env::var("ASSET_PROVISIONAL_MIN_SCORE").ok().as_deref(),
+        )
+    }
+
+    /// Falls back to the defaults when a value is missing, outside 0-100, or
+    /// the provisional cutoff would sit above the verified one
+    fn parse(verified: Option<&str>, provisional: Option<&str>) -> Self {
+        let score = |value: Option<&str>, default: f64| {
+            value
+                .and_then(|v| v.trim().parse::<f64>().ok())
+                .filter(|v| (0.0..=100.0).contains(v))
+                .unwrap_or(default)
+        };
+        let thresholds = Self {
+            verified: score(verified, DEFAULT_VERIFIED_MIN_SCORE),
+            provisional: score(provisional, DEFAULT_PROVISIONAL_MIN_SCORE),
+        };
+
+        if thresholds.provisional > thresholds.verified {
+            warn!(
+                "ASSET_PROVISIONAL_MIN_SCORE ({}) is above ASSET_VERIFIED_MIN_SCORE ({}), using defaults",
+                thresholds.provisional, thresholds.verified
+            );
+            return Self::default();
+        }
+        thresholds
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct StellarExpertAsset {
     asset: String,
@@ -32,9 +103,47 @@ struct TomlInfo {
     image: Option<String>,
 }
 
+/// The parts of a Horizon `/assets` record the verifier uses
+#[derive(Debug, Deserialize)]
+struct HorizonAssetRecord {
+    num_accounts: i64,
+    accounts: Option<AssetAccounts>,
+    flags: Option<AssetFlags>,
+}
+
+impl HorizonAssetRecord {
+    /// Trustline authorization counts and clawback flag, when Horizon reports them
+    fn trustline_authorization(&self) -> Option<TrustlineAuthorization> {
+        let (Some(accounts), Some(flags)) = (&self.accounts, &self.flags) else {
+            return None;
+        };
+
+        Some(TrustlineAuthorization {
+            authorized: i64::from(accounts.authorized)
+                + i64::from(accounts.authorized_to_maintain_liabilities),
+            unauthorized: i64::from(accounts.unauthorized),
+            clawback_enabled: flags.auth_clawback_enabled,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct HorizonAssetsResponse {
+    _embedded: HorizonAssetsEmbedded,
+}
+
+#[derive(Debug, Deserialize)]
+struct HorizonAssetsEmbedded {
+    records: Vec<HorizonAssetRecord>,
+}
+
 pub struct AssetVerifier {
     http_client: Client,
     pool: SqlitePool,
+    horizon_url: String,
+    stellar_expert_url: String,
+    thresholds: ReputationThresholds,
+    trustline_auth_weight: f64,
 }
 
 impl AssetVerifier {
@@ -45,7 +154,38 @@ impl AssetVerifier {
             .build()
             .context("Failed to create HTTP client")?;
 
-        Ok(Self { http_client, pool })
+        Ok(Self {
+            http_client,
+            pool,
+            horizon_url: HORIZON_API.to_string(),
+            stellar_expert_url: STELLAR_EXPERT_API.to_string(),
+            thresholds: ReputationThresholds::from_env(),
+            trustline_auth_weight: trustline_auth_weight_from_env(),
+        })
+    }
+
+    /// Look up accounts and assets on this Horizon instead of the public one
+    pub fn with_horizon_url(mut self, url: impl Into<String>) -> Self {
+        self.horizon_url = url.into();
+        self
+    }
+
+    /// Query this Stellar Expert API instead of the public one
+    pub fn with_stellar_expert_url(mut self, url: impl Into<String>) -> Self {
+        self.stellar_expert_url = url.into();
+        self
+    }
+
+    /// Override the env-configured reputation thresholds
+    pub fn with_thresholds(mut self, thresholds: ReputationThresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    /// Override the env-configured trustline authorization penalty weight
+    pub fn with_trustline_auth_weight(mut self, weight: f64) -> Self {
+        self.trustline_auth_weight = weight.clamp(0.0, 100.0);
+        self
     }
 
     /// Main verification method that checks all sources
@@ -75,9 +215,22 @@ impl AssetVerifier {
             .await
             .unwrap_or(false);
 
+        // One Horizon lookup serves both the on-chain metrics and authorization
+        let horizon_asset = self
+            .fetch_horizon_asset(asset_code, asset_issuer)
+            .await
+            .unwrap_or_else(|e| {
+                warn!("Failed to fetch Horizon asset record: {}", e);
+                None
+            });
+
         // Get on-chain metrics
-        let (trustline_count, transaction_count, total_volume_usd) =
-            self.get_on_chain_metrics(asset_code, asset_issuer).await;
+        let (trustline_count, transaction_count, total_volume_usd) = self
+            .get_on_chain_metrics(asset_code, asset_issuer, horizon_asset.as_ref())
+            .await;
+        let trustline_authorization = horizon_asset
+            .as_ref()
+            .and_then(HorizonAssetRecord::trustline_authorization);
 
         Ok(VerificationResult {
             stellar_expert_verified,
@@ -87,6 +240,7 @@ impl AssetVerifier {
             trustline_count,
             transaction_count,
             total_volume_usd,
+            trustline_authorization,
         })
     }
 
@@ -94,7 +248,7 @@ impl AssetVerifier {
     async fn check_stellar_expert(&self, asset_code: &str, asset_issuer: &str) -> Result<bool> {
         let url = format!(
             "{}/asset/{}-{}",
-            STELLAR_EXPERT_API, asset_code, asset_issuer
+            self.stellar_expert_url, asset_code, asset_issuer
         );
 
         for attempt in 1..=MAX_RETRIES {
@@ -185,7 +339,7 @@ impl AssetVerifier {
 
     /// Get home domain from Stellar account
     async fn get_home_domain_from_account(&self, account_id: &str) -> Result<Option<String>> {
-        let url = format!("https://horizon.stellar.org/accounts/{}", account_id);
+        let url = format!("{}/accounts/{}", self.horizon_url, account_id);
 
         let response = self.http_client.get(&url).send().await?;
 
@@ -248,17 +402,20 @@ impl AssetVerifier {
         Ok(false)
     }
 
-    /// Get on-chain metrics from database or Horizon
-    async fn get_on_chain_metrics(&self, asset_code: &str, asset_issuer: &str) -> (i64, i64, f64) {
+    /// Get on-chain metrics from database or the asset's Horizon record
+    async fn get_on_chain_metrics(
+        &self,
+        asset_code: &str,
+        asset_issuer: &str,
+        horizon_asset: Option<&HorizonAssetRecord>,
+    ) -> (i64, i64, f64) {
         // Try to get from database first
         if let Ok(Some(metrics)) = self.get_metrics_from_db(asset_code, asset_issuer).await {
             return metrics;
         }
 
-        // Fallback to Horizon API
-        self.get_metrics_from_horizon(asset_code, asset_issuer)
-            .await
-            .unwrap_or((0, 0, 0.0))
+        // Fallback to Horizon's trustline count
+        horizon_asset.map_or((0, 0, 0.0), |asset| (asset.num_accounts, 0, 0.0))
     }
 
     /// Get metrics from database
@@ -285,47 +442,25 @@ impl AssetVerifier {
         Ok(result)
     }
 
-    /// Get metrics from Horizon API
-    async fn get_metrics_from_horizon(
+    /// Look up the asset's record on Horizon; `None` when Horizon doesn't know it
+    async fn fetch_horizon_asset(
         &self,
         asset_code: &str,
         asset_issuer: &str,
-    ) -> Result<(i64, i64, f64)> {
+    ) -> Result<Option<HorizonAssetRecord>> {
         let url = format!(
-            "https://horizon.stellar.org/assets?asset_code={}&asset_issuer={}",
-            asset_code, asset_issuer
+            "{}/assets?asset_code={}&asset_issuer={}",
+            self.horizon_url, asset_code, asset_issuer
         );
 
-        #[derive(Deserialize)]
-        struct AssetRecord {
-            num_accounts: i64,
-            #[serde(default)]
-            num_claimable_balances: i64,
-        }
-
-        #[derive(Deserialize)]
-        struct AssetsResponse {
-            _embedded: Embedded,
-        }
-
-        #[derive(Deserialize)]
-        struct Embedded {
-            records: Vec<AssetRecord>,
-        }
-
         let response = self.http_client.get(&url).send().await?;
 
         if !response.status().is_success() {
-            return Ok((0, 0, 0.0));
+            return Ok(None);
         }
 
-        let assets: AssetsResponse = response.json().await?;
-
-        if let Some(asset) = assets._embedded.records.first() {
-            Ok((asset.num_accounts, 0, 0.0))
-        } else {
-            Ok((0, 0, 0.0))
-        }
+        let assets: HorizonAssetsResponse = response.json().await?;
+        Ok(assets._embedded.records.into_iter().next())
     }
 
     /// Calculate reputation score based on verification results
@@ -369,7 +504,33 @@ impl AssetVerifier {
             score += 2.0;
         }
 
-        score.min(100.0)
+        // Trustline authorization (penalty of up to the configured weight)
+        if let Some(authorization) = &result.trustline_authorization {
+            score -= self.trustline_auth_penalty(authorization, result.stellar_toml_verified);
+        }
+
+        score.clamp(0.0, 100.0)
+    }
+
+    /// Points taken off for trustline authorization risk. Half the weight
+    /// scales with the share of trustlines that are unauthorized; the other
+    /// half applies when the issuer can claw back but the asset isn't listed
+    /// in its stellar.toml, where holders would expect that to be disclosed.
+    fn trustline_auth_penalty(
+        &self,
+        authorization: &TrustlineAuthorization,
+        clawback_disclosed: bool,
+    ) -> f64 {
+        let total = authorization.authorized + authorization.unauthorized;
+        let unauthorized_share = if total > 0 {
+            authorization.unauthorized as f64 / total as f64
+        } else {
+            0.0
+        };
+        let undisclosed_clawback = authorization.clawback_enabled && !clawback_disclosed;
+
+        let risk = (unauthorized_share + if undisclosed_clawback { 1.0 } else { 0.0 }) / 2.0;
+        self.trustline_auth_weight * risk
     }
 
     /// Determine verification status based on reputation score and other factors
@@ -382,8 +543,10 @@ impl AssetVerifier {
             return VerificationStatus::Suspicious;
         }
 
-        if reputation_score >= 60.0 {
+        if reputation_score >= self.thresholds.verified {
             VerificationStatus::Verified
+        } else if reputation_score >= self.thresholds.provisional {
+            VerificationStatus::Provisional
         } else {
             VerificationStatus::Unverified
         }
@@ -536,6 +699,41 @@ impl AssetVerifier {
         Ok(())
     }
 
+    /// Re-run verification for one asset, rescore it and persist the result
+    pub async fn revalidate(&self, asset_code: &str, asset_issuer: &str) -> Result<VerifiedAsset> {
+        let result = self.verify_asset(asset_code, asset_issuer).await?;
+        let suspicious_reports_count = self
+            .get_verified_asset(asset_code, asset_issuer)
+            .await?
+            .map(|asset| asset.suspicious_reports_count)
+            .unwrap_or(0);
+
+        let reputation_score = self.calculate_reputation_score(&result);
+        let status = self.determine_status(reputation_score, suspicious_reports_count);
+
+        self.save_verification_result(asset_code, asset_issuer, &result, reputation_score, status)
+            .await
+    }
+
+    /// Stored verification for an asset, verifying it first when none exists
+    pub async fn get_or_revalidate(
+        &self,
+        asset_code: &str,
+        asset_issuer: &str,
+    ) -> Result<VerifiedAsset> {
+        let stored = self
+            .get_verified_asset(asset_code, asset_issuer)
+            .await
+            .unwrap_or_else(|e| {
+                warn!("Failed to load stored verification: {}", e);
+                None
+            });
+        match stored {
+            Some(asset) => Ok(asset),
+            None => self.revalidate(asset_code, asset_issuer).await,
+        }
+    }
+
     /// Get verified asset from database
     pub async fn get_verified_asset(
         &self,
@@ -559,25 +757,21 @@ impl AssetVerifier {
     /// List verified assets with filters
     pub async fn list_verified_assets(
         &self,
-        status: Option<VerificationStatus>,
+        status: Option<&VerificationStatus>,
         min_reputation: Option<f64>,
         limit: i64,
         offset: i64,
     ) -> Result<Vec<VerifiedAsset>> {
-        let mut query = String::from("SELECT * FROM verified_assets WHERE 1=1");
-
-        if let Some(status) = status {
-            query.push_str(&format!(" AND verification_status = '{}'", status.as_str()));
-        }
-
-        if let Some(min_rep) = min_reputation {
-            query.push_str(&format!(" AND reputation_score >= {}", min_rep));
-        }
-
-        query.push_str(" ORDER BY reputation_score DESC, updated_at DESC");
-        query.push_str(&format!(" LIMIT {} OFFSET {}", limit, offset));
-
-        let assets = sqlx::query_as::<_, VerifiedAsset>(&query)
+        let assets = PaginatedQuery::<VerifiedAsset>::new("verified_assets")
+            .filter_opt(
+                "verification_status",
+                FilterOp::Eq,
+                status.map(|s| s.as_str().to_string()),
+            )
+            .filter_opt("reputation_score", FilterOp::Gte, min_reputation)
+            .sort("reputation_score", SortDirection::Desc)
+            .sort("updated_at", SortDirection::Desc)
+            .offset(limit, offset)
             .fetch_all(&self.pool)
             .await?;
 
@@ -589,8 +783,8 @@ impl AssetVerifier {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_calculate_reputation_score() {
+    #[tokio::test]
+    async fn test_calculate_reputation_score() {
         let pool = SqlitePool::connect(":memory:").await.unwrap();
         let verifier = AssetVerifier::new(pool).unwrap();
 
@@ -602,6 +796,7 @@ mod tests {
             trustline_count: 5000,
             transaction_count: 50000,
             total_volume_usd: 1000000.0,
+            trustline_authorization: None,
         };
 
         let score = verifier.calculate_reputation_score(&result);
@@ -609,22 +804,121 @@ mod tests {
         assert!(score <= 100.0);
     }
 
-    #[test]
-    fn test_determine_status() {
+    #[tokio::test]
+    async fn test_determine_status() {
         let pool = SqlitePool::connect(":memory:").await.unwrap();
-        let verifier = AssetVerifier::new(pool).unwrap();
+        let verifier = AssetVerifier::new(pool)
+            .unwrap()
+            .with_thresholds(ReputationThresholds::default());
 
         assert_eq!(
             verifier.determine_status(80.0, 0),
             VerificationStatus::Verified
         );
+        assert_eq!(
+            verifier.determine_status(60.0, 0),
+            VerificationStatus::Verified
+        );
+        assert_eq!(
+            verifier.determine_status(59.9, 0),
+            VerificationStatus::Provisional
+        );
         assert_eq!(
             verifier.determine_status(40.0, 0),
+            VerificationStatus::Provisional
+        );
+        assert_eq!(
+            verifier.determine_status(39.9, 0),
             VerificationStatus::Unverified
         );
+        // Reports override the score in every tier
         assert_eq!(
             verifier.determine_status(80.0, 3),
             VerificationStatus::Suspicious
         );
+        assert_eq!(
+            verifier.determine_status(50.0, 3),
+            VerificationStatus::Suspicious
+        );
+    }
+
+    #[tokio::test]
+    async fn test_determine_status_uses_configured_thresholds() {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        let verifier = AssetVerifier::new(pool)
+            .unwrap()
+            .with_thresholds(ReputationThresholds {
+                verified: 75.0,
+                provisional: 50.0,
+            });
+
+        assert_eq!(
+            verifier.determine_status(74.0, 0),
+            VerificationStatus::Provisional
+        );
+        assert_eq!(
+            verifier.determine_status(45.0, 0),
+            VerificationStatus::Unverified
+        );
+    }
+
+    #[test]
+    fn test_reputation_thresholds_parse() {
+        assert_eq!(
+            ReputationThresholds::parse(None, None),
+            ReputationThresholds::default()
+        );
+        assert_eq!(
+            ReputationThresholds::parse(Some("70"), Some("30")),
+            ReputationThresholds {
+                verified: 70.0,
+                provisional: 30.0,
+            }
+        );
+        // Out of range values fall back individually
+        assert_eq!(
+            ReputationThresholds::parse(Some("150"), Some("30")).verified,
+            DEFAULT_VERIFIED_MIN_SCORE
+        );
+        // Inverted thresholds fall back to the defaults
+        assert_eq!(
+            ReputationThresholds::parse(Some("30"), Some("70")),
+            ReputationThresholds::default()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_horizon_record_serves_metrics_and_authorization() {
+        // No payments table, so metrics fall back to the Horizon record
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        let verifier = AssetVerifier::new(pool).unwrap();
+        let record: HorizonAssetRecord = serde_json::from_value(serde_json::json!({
+            "num_accounts": 120,
+            "accounts": {
+                "authorized": 100,
+                "authorized_to_maintain_liabilities": 5,
+                "unauthorized": 15
+            },
+            "flags": {
+                "auth_required": true,
+                "auth_revocable": true,
+                "auth_immutable": false,
+                "auth_clawback_enabled": true
+            }
+        }))
+        .unwrap();
+
+        let metrics = verifier
+            .get_on_chain_metrics("USDC", "GISSUER", Some(&record))
+            .await;
+        assert_eq!(metrics, (120, 0, 0.0));
+        assert_eq!(
+            record.trustline_authorization(),
+            Some(TrustlineAuthorization {
+                authorized: 105,
+                unauthorized: 15,
+                clawback_enabled: true,
+            })
+        );
     }
 }
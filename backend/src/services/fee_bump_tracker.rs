@@ -3,7 +3,7 @@ use chrono::{DateTime, Utc};
 use sqlx::{Pool, Sqlite};
 use tracing::{info, warn};
 
-use crate::models::{FeeBumpStats, FeeBumpTransaction};
+use crate::models::{FeeBumpAnalytics, FeeBumpSourceStats, FeeBumpStats, FeeBumpTransaction};
 use crate::rpc::HorizonTransaction; // Changed from StellarRpcClient as we process data structs
 
 pub struct FeeBumpTrackerService {
@@ -144,4 +144,58 @@ impl FeeBumpTrackerService {
             unique_fee_sources: row.4,
         })
     }
+
+    /// Fee-bump statistics for transactions created in `[from, to)`, with the
+    /// `top_sources` busiest fee-paying accounts
+    pub async fn get_fee_bump_analytics(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        top_sources: i64,
+    ) -> Result<FeeBumpAnalytics> {
+        let row: (i64, f64, f64, f64) = sqlx::query_as(
+            r#"
+            SELECT
+                COUNT(*) as fee_bump_count,
+                COALESCE(AVG(max_fee), 0.0) as avg_max_fee,
+                COALESCE(AVG(fee_charged), 0.0) as avg_fee_charged,
+                COALESCE(AVG(max_fee - fee_charged), 0.0) as avg_fee_delta
+            FROM fee_bump_transactions
+            WHERE created_at >= $1 AND created_at < $2
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .fetch_one(&self.pool)
+        .await?;
+
+        let top_fee_sources = sqlx::query_as::<_, FeeBumpSourceStats>(
+            r#"
+            SELECT
+                fee_source,
+                COUNT(*) as fee_bump_count,
+                SUM(fee_charged) as total_fee_charged
+            FROM fee_bump_transactions
+            WHERE created_at >= $1 AND created_at < $2
+            GROUP BY fee_source
+            ORDER BY fee_bump_count DESC, fee_source ASC
+            LIMIT $3
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .bind(top_sources)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(FeeBumpAnalytics {
+            from,
+            to,
+            fee_bump_count: row.0,
+            avg_max_fee: row.1,
+            avg_fee_charged: row.2,
+            avg_fee_delta: row.3,
+            top_fee_sources,
+        })
+    }
 }
@@ -0,0 +1,257 @@
+//! Alert rules on corridor health scores, evaluated after each metrics sync
+//!
+//! Rules live in `alert_rules` with `metric_type = "health_score"` and one of
+//! two conditions:
+//! - `below`: the score is under `threshold`
+//! - `drop_pct`: the score fell by more than `threshold` percent since the
+//!   previous evaluation
+//!
+//! A rule fires once when a corridor starts matching it and stays quiet until
+//! the corridor recovers, so a corridor that stays degraded is not re-alerted
+//! every cycle.
+
+use anyhow::Result;
+use chrono::Utc;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::alerts::{Alert, AlertManager, AlertType};
+use crate::api::corridors_cached::{
+    default_time_period_window, hourly_health_scores, HealthScoreWeights,
+};
+use crate::database::Database;
+use crate::models::alerts::AlertRule;
+
+/// `metric_type` of the rules this engine evaluates
+pub const HEALTH_SCORE_METRIC: &str = "health_score";
+
+/// How a health score rule compares the score against its threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthRuleCondition {
+    /// Score is below the threshold
+    Below,
+    /// Score fell by more than the threshold, in percent, since the last cycle
+    DropPercent,
+}
+
+impl HealthRuleCondition {
+    pub fn parse(condition: &str) -> Option<Self> {
+        match condition {
+            "below" => Some(Self::Below),
+            "drop_pct" => Some(Self::DropPercent),
+            _ => None,
+        }
+    }
+
+    /// Whether `score` matches the rule; a drop needs a previous score to compare against
+    pub fn is_triggered(self, threshold: f64, previous: Option<f64>, score: f64) -> bool {
+        match self {
+            Self::Below => score < threshold,
+            Self::DropPercent => match previous {
+                Some(previous) if previous > 0.0 => {
+                    (previous - score) / previous * 100.0 > threshold
+                }
+                _ => false,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct EngineState {
+    /// Scores seen by the last evaluation, by corridor
+    previous_scores: HashMap<String, f64>,
+    /// (rule id, corridor) pairs that have fired and not yet recovered
+    firing: HashSet<(String, String)>,
+}
+
+pub struct HealthAlertEngine {
+    db: Arc<Database>,
+    alert_manager: Arc<AlertManager>,
+    weights: HealthScoreWeights,
+    state: Mutex<EngineState>,
+}
+
+impl HealthAlertEngine {
+    pub fn new(
+        db: Arc<Database>,
+        alert_manager: Arc<AlertManager>,
+        weights: HealthScoreWeights,
+    ) -> Self {
+        Self {
+            db,
+            alert_manager,
+            weights,
+            state: Mutex::new(EngineState::default()),
+        }
+    }
+
+    /// Score each corridor from its most recent hourly metrics and evaluate
+    /// the rules against them. Returns the number of alerts dispatched.
+    pub async fn run_cycle(&self) -> Result<usize> {
+        let scores = self.latest_health_scores().await?;
+        self.evaluate(&scores).await
+    }
+
+    /// Health score of each corridor with hourly metrics in the corridor
+    /// API's default window, computed as the API computes it
+    pub async fn latest_health_scores(&self) -> Result<HashMap<String, f64>> {
+        let now = Utc::now();
+        let metrics = self
+            .db
+            .fetch_hourly_metrics_by_timerange(now - default_time_period_window(), now)
+            .await?;
+        Ok(hourly_health_scores(&metrics, &self.weights))
+    }
+
+    /// Evaluate the active health score rules against `scores`, dispatching an
+    /// alert for each rule and corridor that newly matches. Returns the number
+    /// of alerts dispatched.
+    pub async fn evaluate(&self, scores: &HashMap<String, f64>) -> Result<usize> {
+        let rules: Vec<AlertRule> = self
+            .db
+            .get_all_active_alert_rules()
+            .await?
+            .into_iter()
+            .filter(|rule| rule.metric_type == HEALTH_SCORE_METRIC)
+            .collect();
+
+        let mut state = self.state.lock().await;
+        let now = Utc::now();
+        let mut firing = HashSet::new();
+        let mut dispatched = 0;
+
+        for rule in &rules {
+            // A snoozed rule keeps its firing state so it doesn't re-alert
+            // as soon as the snooze ends
+            if rule.snoozed_until.is_some_and(|until| now < until) {
+                firing.extend(
+                    state
+                        .firing
+                        .iter()
+                        .filter(|(rule_id, _)| *rule_id == rule.id)
+                        .cloned(),
+                );
+                continue;
+            }
+
+            let Some(condition) = HealthRuleCondition::parse(&rule.condition) else {
+                tracing::warn!(
+                    "Skipping health score rule {} with unknown condition '{}'",
+                    rule.id,
+                    rule.condition
+                );
+                continue;
+            };
+
+            for (corridor_id, &score) in scores {
+                if rule
+                    .corridor_id
+                    .as_ref()
+                    .is_some_and(|id| id != corridor_id)
+                {
+                    continue;
+                }
+
+                let previous = state.previous_scores.get(corridor_id).copied();
+                if !condition.is_triggered(rule.threshold, previous, score) {
+                    continue;
+                }
+
+                let key = (rule.id.clone(), corridor_id.clone());
+                if !state.firing.contains(&key) {
+                    // Left unmarked on failure so the next cycle tries again
+                    if let Err(e) = self
+                        .dispatch(rule, condition, corridor_id, previous, score)
+                        .await
+                    {
+                        tracing::warn!(
+                            "Failed to dispatch health score alert for rule {} on {}: {}",
+                            rule.id,
+                            corridor_id,
+                            e
+                        );
+                        continue;
+                    }
+                    dispatched += 1;
+                }
+                firing.insert(key);
+            }
+        }
+
+        state.firing = firing;
+        state
+            .previous_scores
+            .extend(scores.iter().map(|(id, score)| (id.clone(), *score)));
+        Ok(dispatched)
+    }
+
+    async fn dispatch(
+        &self,
+        rule: &AlertRule,
+        condition: HealthRuleCondition,
+        corridor_id: &str,
+        previous: Option<f64>,
+        score: f64,
+    ) -> Result<()> {
+        let message = match condition {
+            HealthRuleCondition::Below => format!(
+                "Health score {:.1} is below threshold {:.1}",
+                score, rule.threshold
+            ),
+            HealthRuleCondition::DropPercent => format!(
+                "Health score fell from {:.1} to {:.1}, more than {:.1}%",
+                previous.unwrap_or(score),
+                score,
+                rule.threshold
+            ),
+        };
+
+        self.db
+            .insert_alert_history(
+                &rule.id,
+                &rule.user_id,
+                Some(corridor_id.to_string()),
+                &rule.metric_type,
+                score,
+                rule.threshold,
+                &rule.condition,
+                &message,
+            )
+            .await?;
+
+        self.alert_manager.dispatch(Alert {
+            alert_type: AlertType::HealthScoreDegradation,
+            corridor_id: corridor_id.to_string(),
+            message,
+            old_value: previous.unwrap_or(score),
+            new_value: score,
+            timestamp: Utc::now().to_rfc3339(),
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conditions() {
+        assert_eq!(
+            HealthRuleCondition::parse("below"),
+            Some(HealthRuleCondition::Below)
+        );
+        assert_eq!(HealthRuleCondition::parse("above"), None);
+
+        let below = HealthRuleCondition::Below;
+        assert!(below.is_triggered(50.0, None, 49.9));
+        assert!(!below.is_triggered(50.0, None, 50.0));
+
+        let drop = HealthRuleCondition::DropPercent;
+        assert!(!drop.is_triggered(20.0, None, 10.0));
+        assert!(!drop.is_triggered(20.0, Some(80.0), 70.0));
+        assert!(drop.is_triggered(20.0, Some(80.0), 60.0));
+    }
+}
@@ -1,5 +1,4 @@
 use anyhow::{Context, Result};
-use chrono::DateTime;
 use std::sync::Arc;
 use tracing::info;
 
@@ -44,32 +43,7 @@ impl IndexingService {
         // Normalize payments
         let records: Vec<PaymentRecord> = payments
             .into_iter()
-            .filter_map(|p| {
-                let amount = p.amount.parse::<f64>().ok()?;
-                let created_at = DateTime::parse_from_rfc3339(&p.created_at)
-                    .ok()?
-                    .with_timezone(&chrono::Utc);
-
-                Some(PaymentRecord {
-                    id: p.id,
-                    transaction_hash: p.transaction_hash,
-                    source_account: p.source_account,
-                    destination_account: p.destination,
-                    asset_type: p.asset_type.clone(),
-                    asset_code: p.asset_code.clone(),
-                    asset_issuer: p.asset_issuer.clone(),
-                    source_asset_code: p.asset_code.clone().unwrap_or_default(),
-                    source_asset_issuer: p.asset_issuer.clone().unwrap_or_default(),
-                    destination_asset_code: p.asset_code.unwrap_or_default(),
-                    destination_asset_issuer: p.asset_issuer.unwrap_or_default(),
-                    amount,
-                    successful: true,
-                    timestamp: Some(created_at),
-                    submission_time: None,
-                    confirmation_time: None,
-                    created_at,
-                })
-            })
+            .filter_map(crate::ingestion::to_payment_record)
             .collect();
 
         let count = records.len();
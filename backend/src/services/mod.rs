@@ -5,6 +5,7 @@ pub mod asset_verifier;
 pub mod contract;
 pub mod fee_bump_tracker;
 pub mod governance;
+pub mod health_alerts;
 pub mod indexing;
 pub mod liquidity_pool_analyzer;
 pub mod price_feed;
@@ -1,12 +1,22 @@
 use anyhow::{Context, Result};
-use async_lock::RwLock;
+use async_lock::{Mutex, RwLock};
+use chrono::{DateTime, Utc};
 use reqwest::Client;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tracing::{debug, error, info, warn};
 
+use crate::rpc::{Asset, StellarRpcClient, Trade};
+
+/// Stable asset derived prices are quoted against by default (Circle USDC)
+pub const DEFAULT_DERIVED_QUOTE_ASSET: &str =
+    "USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+
+/// Number of recent trades fetched when deriving a price
+const DERIVED_PRICE_TRADE_LIMIT: u32 = 200;
+
 /// Configuration for price feed service
 #[derive(Debug, Clone)]
 pub struct PriceFeedConfig {
@@ -14,10 +24,23 @@ pub struct PriceFeedConfig {
     pub provider: String,
     /// API key (optional for CoinGecko free tier, required for CoinMarketCap)
     pub api_key: Option<String>,
-    /// Cache TTL in seconds (default: 900 = 15 minutes)
+    /// How long a fetched price is reused before asking the provider again
+    /// (default: 900 = 15 minutes)
     pub cache_ttl_seconds: u64,
     /// Request timeout in seconds
     pub request_timeout_seconds: u64,
+    /// Maximum age in seconds before a price is reported as stale (default: 3600)
+    pub max_age_seconds: u64,
+    /// Maximum allowed move, in percent, between consecutive fetched prices.
+    /// Quotes outside the band are rejected and the previous price is kept.
+    pub price_band_pct: Option<f64>,
+    /// Consecutive out-of-band quotes after which the band re-baselines on
+    /// the new price, so a sustained move is eventually accepted (default: 3)
+    pub price_band_rebaseline_after: u32,
+    /// Window of recent trades used to derive a fallback price (default: 3600)
+    pub derived_window_seconds: u64,
+    /// Stable asset, as `CODE:ISSUER`, that derived prices are quoted against
+    pub derived_quote_asset: String,
 }
 
 impl Default for PriceFeedConfig {
@@ -27,6 +50,11 @@ impl Default for PriceFeedConfig {
             api_key: None,
             cache_ttl_seconds: 900, // 15 minutes
             request_timeout_seconds: 10,
+            max_age_seconds: 3600, // 1 hour
+            price_band_pct: None,
+            price_band_rebaseline_after: 3,
+            derived_window_seconds: 3600, // 1 hour
+            derived_quote_asset: DEFAULT_DERIVED_QUOTE_ASSET.to_string(),
         }
     }
 }
@@ -37,7 +65,9 @@ impl PriceFeedConfig {
             provider: std::env::var("PRICE_FEED_PROVIDER")
                 .unwrap_or_else(|_| "coingecko".to_string()),
             api_key: std::env::var("PRICE_FEED_API_KEY").ok(),
-            cache_ttl_seconds: std::env::var("PRICE_FEED_CACHE_TTL_SECONDS")
+            // PRICE_FEED_CACHE_TTL_SECONDS is the older name for the same setting
+            cache_ttl_seconds: std::env::var("PRICE_CACHE_TTL_SECS")
+                .or_else(|_| std::env::var("PRICE_FEED_CACHE_TTL_SECONDS"))
                 .ok()
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(900),
@@ -45,6 +75,25 @@ impl PriceFeedConfig {
                 .ok()
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(10),
+            max_age_seconds: std::env::var("PRICE_MAX_AGE_SECS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(3600),
+            price_band_pct: std::env::var("PRICE_BAND_PCT")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .filter(|pct: &f64| *pct > 0.0),
+            price_band_rebaseline_after: std::env::var("PRICE_BAND_REBASELINE_AFTER")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .filter(|n: &u32| *n > 0)
+                .unwrap_or(3),
+            derived_window_seconds: std::env::var("PRICE_DERIVED_WINDOW_SECS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(3600),
+            derived_quote_asset: std::env::var("PRICE_DERIVED_QUOTE_ASSET")
+                .unwrap_or_else(|_| DEFAULT_DERIVED_QUOTE_ASSET.to_string()),
         }
     }
 }
@@ -54,8 +103,37 @@ impl PriceFeedConfig {
 struct CachedPrice {
     price_usd: f64,
     timestamp: Instant,
+    /// Wall-clock time the price was obtained from the provider
+    as_of: DateTime<Utc>,
+    /// Consecutive quotes rejected as outside the price band
+    out_of_band: u32,
+    source: PriceSource,
 }
 
+/// Where a price came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceSource {
+    /// The external price feed (possibly served from cache)
+    Provider,
+    /// Volume-weighted average of recent DEX trades, used when the feed is down
+    Derived,
+}
+
+/// A price together with its provenance and freshness
+#[derive(Debug, Clone, Serialize)]
+pub struct PriceWithMeta {
+    pub price_usd: f64,
+    /// When the price was obtained from the provider
+    pub as_of: DateTime<Utc>,
+    /// True when the price is older than `PRICE_MAX_AGE_SECS`
+    pub stale: bool,
+    pub source: PriceSource,
+}
+
+/// Source of the current time, injectable for tests
+pub type Clock = Arc<dyn Fn() -> DateTime<Utc> + Send + Sync>;
+
 /// Trait for price feed providers
 #[async_trait::async_trait]
 pub trait PriceFeedProvider: Send + Sync {
@@ -69,6 +147,49 @@ pub trait PriceFeedProvider: Send + Sync {
     fn name(&self) -> &str;
 }
 
+/// Source of recent DEX trades for deriving fallback prices
+#[async_trait::async_trait]
+pub trait TradeSource: Send + Sync {
+    /// Most recent trades between `asset` and `quote` (keyed as in the asset
+    /// mapping), newest first
+    async fn pair_trades(&self, asset: &str, quote: &str, limit: u32) -> Result<Vec<Trade>>;
+}
+
+#[async_trait::async_trait]
+impl TradeSource for StellarRpcClient {
+    async fn pair_trades(&self, asset: &str, quote: &str, limit: u32) -> Result<Vec<Trade>> {
+        Ok(self
+            .fetch_trades_for_pair(&trade_asset(asset)?, &trade_asset(quote)?, limit)
+            .await?)
+    }
+}
+
+/// Horizon asset for a `CODE:ISSUER`, `native` or `XLM:native` key
+fn trade_asset(key: &str) -> Result<Asset> {
+    if key == "native" || key == "XLM:native" {
+        return Ok(Asset {
+            asset_type: "native".to_string(),
+            asset_code: None,
+            asset_issuer: None,
+        });
+    }
+    match key.split_once(':') {
+        Some((code, issuer)) if (1..=12).contains(&code.len()) && !issuer.is_empty() => {
+            let asset_type = if code.len() <= 4 {
+                "credit_alphanum4"
+            } else {
+                "credit_alphanum12"
+            };
+            Ok(Asset {
+                asset_type: asset_type.to_string(),
+                asset_code: Some(code.to_string()),
+                asset_issuer: Some(issuer.to_string()),
+            })
+        }
+        _ => Err(anyhow::anyhow!("Invalid asset key: {}", key)),
+    }
+}
+
 /// CoinGecko provider implementation
 pub struct CoinGeckoProvider {
     client: Client,
@@ -178,8 +299,12 @@ impl PriceFeedProvider for CoinGeckoProvider {
 pub struct PriceFeedClient {
     provider: Arc<dyn PriceFeedProvider>,
     cache: Arc<RwLock<HashMap<String, CachedPrice>>>,
+    /// Per-asset locks so concurrent misses for one asset share a fetch
+    fetch_locks: Arc<std::sync::Mutex<HashMap<String, Arc<Mutex<()>>>>>,
     asset_mapping: Arc<HashMap<String, String>>,
     config: PriceFeedConfig,
+    clock: Clock,
+    trade_source: Option<Arc<dyn TradeSource>>,
 }
 
 impl PriceFeedClient {
@@ -206,59 +331,164 @@ impl PriceFeedClient {
         Self {
             provider,
             cache: Arc::new(RwLock::new(HashMap::new())),
+            fetch_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
             asset_mapping: Arc::new(asset_mapping),
             config,
+            clock: Arc::new(Utc::now),
+            trade_source: None,
         }
     }
 
+    /// Replace the clock used for staleness checks
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Replace the price feed provider
+    pub fn with_provider(mut self, provider: Arc<dyn PriceFeedProvider>) -> Self {
+        self.provider = provider;
+        self
+    }
+
+    /// Derive prices from recent trades when the provider is unavailable
+    pub fn with_trade_source(mut self, trade_source: Arc<dyn TradeSource>) -> Self {
+        self.trade_source = Some(trade_source);
+        self
+    }
+
     /// Get price for a Stellar asset, returns USD value
     pub async fn get_price(&self, stellar_asset: &str) -> Result<f64> {
-        // Check cache first
-        {
-            let cache = self.cache.read().await;
-            if let Some(cached) = cache.get(stellar_asset) {
-                let age = cached.timestamp.elapsed();
-                if age.as_secs() < self.config.cache_ttl_seconds {
-                    debug!("Cache hit for {}: ${}", stellar_asset, cached.price_usd);
-                    return Ok(cached.price_usd);
-                }
-            }
+        self.get_price_with_meta(stellar_asset)
+            .await
+            .map(|meta| meta.price_usd)
+    }
+
+    /// Get price for a Stellar asset along with its `as_of` time and staleness
+    ///
+    /// A price fetched within the cache TTL is reused; concurrent lookups of
+    /// an asset that isn't cached wait for a single provider request.
+    pub async fn get_price_with_meta(&self, stellar_asset: &str) -> Result<PriceWithMeta> {
+        if let Some(cached) = self.fresh_cached(stellar_asset).await {
+            return Ok(cached);
+        }
+
+        let asset_id = self.asset_id(stellar_asset)?;
+        let lock = self.fetch_lock(stellar_asset);
+        let _guard = lock.lock().await;
+
+        // Another lookup may have fetched the price while we waited
+        if let Some(cached) = self.fresh_cached(stellar_asset).await {
+            return Ok(cached);
         }
+        self.fetch_price_with_meta(stellar_asset, asset_id).await
+    }
+
+    /// Fetch a Stellar asset's price from the provider even if a fresh price
+    /// is cached, replacing the cached entry
+    pub async fn refresh_price(&self, stellar_asset: &str) -> Result<PriceWithMeta> {
+        let asset_id = self.asset_id(stellar_asset)?;
+        let lock = self.fetch_lock(stellar_asset);
+        let _guard = lock.lock().await;
+        self.fetch_price_with_meta(stellar_asset, asset_id).await
+    }
 
-        // Map Stellar asset to provider asset ID
-        let asset_id = self
-            .asset_mapping
+    /// Cached price for `stellar_asset` if it is within the cache TTL
+    async fn fresh_cached(&self, stellar_asset: &str) -> Option<PriceWithMeta> {
+        let cache = self.cache.read().await;
+        let cached = cache.get(stellar_asset)?;
+        if cached.timestamp.elapsed().as_secs() >= self.config.cache_ttl_seconds {
+            return None;
+        }
+        debug!("Cache hit for {}: ${}", stellar_asset, cached.price_usd);
+        Some(self.with_meta(cached))
+    }
+
+    /// Provider asset ID for a Stellar asset
+    fn asset_id(&self, stellar_asset: &str) -> Result<&str> {
+        self.asset_mapping
             .get(stellar_asset)
-            .ok_or_else(|| anyhow::anyhow!("No mapping found for asset: {}", stellar_asset))?;
+            .map(String::as_str)
+            .ok_or_else(|| anyhow::anyhow!("No mapping found for asset: {}", stellar_asset))
+    }
 
-        // Fetch from provider
+    fn fetch_lock(&self, stellar_asset: &str) -> Arc<Mutex<()>> {
+        let mut locks = self.fetch_locks.lock().unwrap_or_else(|e| e.into_inner());
+        Arc::clone(
+            locks
+                .entry(stellar_asset.to_string())
+                .or_insert_with(|| Arc::new(Mutex::new(()))),
+        )
+    }
+
+    /// Fetch from the provider and update the cache, falling back to cached
+    /// or derived prices when the provider fails
+    async fn fetch_price_with_meta(
+        &self,
+        stellar_asset: &str,
+        asset_id: &str,
+    ) -> Result<PriceWithMeta> {
         debug!("Fetching price for {} ({})", stellar_asset, asset_id);
         match self.provider.fetch_price(asset_id).await {
             Ok(price) => {
-                // Update cache
                 let mut cache = self.cache.write().await;
-                cache.insert(
-                    stellar_asset.to_string(),
-                    CachedPrice {
-                        price_usd: price,
-                        timestamp: Instant::now(),
-                    },
-                );
+
+                if let Some(previous) = cache.get_mut(stellar_asset) {
+                    if !self.within_price_band(previous.price_usd, price) {
+                        previous.out_of_band += 1;
+                        if previous.out_of_band < self.config.price_band_rebaseline_after {
+                            warn!(
+                                "Rejecting price ${} for {}: outside band of previous ${}",
+                                price, stellar_asset, previous.price_usd
+                            );
+                            return Ok(self.with_meta(previous));
+                        }
+                        warn!(
+                            "Re-baselining {} at ${} after {} consecutive out-of-band quotes",
+                            stellar_asset, price, previous.out_of_band
+                        );
+                    }
+                }
+
+                // Update cache; an accepted quote ends any out-of-band streak
+                let entry = CachedPrice {
+                    price_usd: price,
+                    timestamp: Instant::now(),
+                    as_of: (self.clock)(),
+                    out_of_band: 0,
+                    source: PriceSource::Provider,
+                };
+                let meta = self.with_meta(&entry);
+                cache.insert(stellar_asset.to_string(), entry);
                 info!("Fetched price for {}: ${}", stellar_asset, price);
-                Ok(price)
+                Ok(meta)
             }
             Err(e) => {
                 error!("Failed to fetch price for {}: {}", stellar_asset, e);
 
-                // Try to return stale cache data as fallback
-                let cache = self.cache.read().await;
-                if let Some(cached) = cache.get(stellar_asset) {
+                // Prefer cached provider data while it is still fresh, then a
+                // price derived from trades, then stale cache data
+                let cached = self.cache.read().await.get(stellar_asset).cloned();
+                if let Some(cached) = cached.as_ref().filter(|c| !self.is_stale(c.as_of)) {
+                    warn!(
+                        "Using cached data for {} (age: {:?})",
+                        stellar_asset,
+                        cached.timestamp.elapsed()
+                    );
+                    return Ok(self.with_meta(cached));
+                }
+
+                if let Some(derived) = self.derive_price(stellar_asset).await {
+                    return Ok(derived);
+                }
+
+                if let Some(cached) = cached {
                     warn!(
                         "Using stale cache data for {} (age: {:?})",
                         stellar_asset,
                         cached.timestamp.elapsed()
                     );
-                    return Ok(cached.price_usd);
+                    return Ok(self.with_meta(&cached));
                 }
 
                 Err(e)
@@ -266,6 +496,82 @@ impl PriceFeedClient {
         }
     }
 
+    fn with_meta(&self, cached: &CachedPrice) -> PriceWithMeta {
+        PriceWithMeta {
+            price_usd: cached.price_usd,
+            as_of: cached.as_of,
+            stale: self.is_stale(cached.as_of),
+            source: cached.source,
+        }
+    }
+
+    /// VWAP of `stellar_asset` against the configured quote asset over the
+    /// derived-price window, or None without a trade source or matching trades.
+    ///
+    /// A derived price is cached like a provider quote, so lookups within the
+    /// cache TTL don't re-fetch trades.
+    async fn derive_price(&self, stellar_asset: &str) -> Option<PriceWithMeta> {
+        let trade_source = self.trade_source.as_ref()?;
+        let trades = match trade_source
+            .pair_trades(
+                stellar_asset,
+                &self.config.derived_quote_asset,
+                DERIVED_PRICE_TRADE_LIMIT,
+            )
+            .await
+        {
+            Ok(trades) => trades,
+            Err(e) => {
+                warn!(
+                    "Failed to fetch trades to derive price for {}: {}",
+                    stellar_asset, e
+                );
+                return None;
+            }
+        };
+
+        let now = (self.clock)();
+        let since = now - chrono::Duration::seconds(self.config.derived_window_seconds as i64);
+        let price = volume_weighted_average_price(
+            &trades,
+            stellar_asset,
+            &self.config.derived_quote_asset,
+            since,
+        )?;
+
+        info!(
+            "Derived price for {} from recent trades: ${}",
+            stellar_asset, price
+        );
+        let entry = CachedPrice {
+            price_usd: price,
+            timestamp: Instant::now(),
+            as_of: now,
+            out_of_band: 0,
+            source: PriceSource::Derived,
+        };
+        let meta = self.with_meta(&entry);
+        self.cache
+            .write()
+            .await
+            .insert(stellar_asset.to_string(), entry);
+        Some(meta)
+    }
+
+    fn is_stale(&self, as_of: DateTime<Utc>) -> bool {
+        let age = (self.clock)().signed_duration_since(as_of);
+        age.num_seconds() > self.config.max_age_seconds as i64
+    }
+
+    fn within_price_band(&self, previous: f64, current: f64) -> bool {
+        match self.config.price_band_pct {
+            Some(band_pct) if previous > 0.0 => {
+                ((current - previous).abs() / previous) * 100.0 <= band_pct
+            }
+            _ => true,
+        }
+    }
+
     /// Get prices for multiple Stellar assets
     pub async fn get_prices(&self, stellar_assets: &[String]) -> HashMap<String, f64> {
         let mut result = HashMap::new();
@@ -290,11 +596,12 @@ impl PriceFeedClient {
             return result;
         }
 
-        // Map to provider asset IDs
-        let provider_ids: Vec<String> = to_fetch
+        // Map to provider asset IDs, keeping the Stellar asset each one is for
+        let requested: Vec<(&String, &String)> = to_fetch
             .iter()
-            .filter_map(|asset| self.asset_mapping.get(asset).cloned())
+            .filter_map(|asset| self.asset_mapping.get(asset).map(|id| (asset, id)))
             .collect();
+        let provider_ids: Vec<String> = requested.iter().map(|(_, id)| (*id).clone()).collect();
 
         if provider_ids.is_empty() {
             return result;
@@ -306,13 +613,16 @@ impl PriceFeedClient {
                 let mut cache = self.cache.write().await;
 
                 // Map back to Stellar assets and update cache
-                for (stellar_asset, provider_id) in to_fetch.iter().zip(provider_ids.iter()) {
+                for &(stellar_asset, provider_id) in &requested {
                     if let Some(&price) = prices.get(provider_id) {
                         cache.insert(
                             stellar_asset.clone(),
                             CachedPrice {
                                 price_usd: price,
                                 timestamp: Instant::now(),
+                                as_of: (self.clock)(),
+                                out_of_band: 0,
+                                source: PriceSource::Provider,
                             },
                         );
                         result.insert(stellar_asset.clone(), price);
@@ -369,6 +679,75 @@ impl PriceFeedClient {
     }
 }
 
+/// Volume-weighted average price of `asset` in units of `quote` across trades
+/// that closed at or after `since`.
+///
+/// Trades are matched in either direction, so the result is the total quote
+/// amount exchanged divided by the total asset amount. Assets are keyed as
+/// `CODE:ISSUER`, with `native` or `XLM:native` for lumens.
+pub fn volume_weighted_average_price(
+    trades: &[Trade],
+    asset: &str,
+    quote: &str,
+    since: DateTime<Utc>,
+) -> Option<f64> {
+    let mut asset_volume = 0.0;
+    let mut quote_volume = 0.0;
+
+    for trade in trades {
+        let closed_in_window = DateTime::parse_from_rfc3339(&trade.ledger_close_time)
+            .map(|closed| closed.with_timezone(&Utc) >= since)
+            .unwrap_or(false);
+        if !closed_in_window {
+            continue;
+        }
+
+        let base_matches = |key: &str| {
+            trade_side_is(
+                &trade.base_asset_type,
+                trade.base_asset_code.as_deref(),
+                trade.base_asset_issuer.as_deref(),
+                key,
+            )
+        };
+        let counter_matches = |key: &str| {
+            trade_side_is(
+                &trade.counter_asset_type,
+                trade.counter_asset_code.as_deref(),
+                trade.counter_asset_issuer.as_deref(),
+                key,
+            )
+        };
+
+        let (asset_amount, quote_amount) = if base_matches(asset) && counter_matches(quote) {
+            (&trade.base_amount, &trade.counter_amount)
+        } else if base_matches(quote) && counter_matches(asset) {
+            (&trade.counter_amount, &trade.base_amount)
+        } else {
+            continue;
+        };
+
+        if let (Ok(asset_amount), Ok(quote_amount)) =
+            (asset_amount.parse::<f64>(), quote_amount.parse::<f64>())
+        {
+            asset_volume += asset_amount;
+            quote_volume += quote_amount;
+        }
+    }
+
+    (asset_volume > 0.0).then(|| quote_volume / asset_volume)
+}
+
+fn trade_side_is(asset_type: &str, code: Option<&str>, issuer: Option<&str>, key: &str) -> bool {
+    if asset_type == "native" {
+        return key == "native" || key == "XLM:native";
+    }
+    match key.split_once(':') {
+        Some((key_code, key_issuer)) => code == Some(key_code) && issuer == Some(key_issuer),
+        None => false,
+    }
+}
+
 /// Default asset mapping for common Stellar assets
 pub fn default_asset_mapping() -> HashMap<String, String> {
     let mut mapping = HashMap::new();
@@ -434,6 +813,10 @@ mod tests {
         let config = PriceFeedConfig::from_env();
         assert_eq!(config.provider, "coingecko");
         assert_eq!(config.cache_ttl_seconds, 600);
+
+        std::env::set_var("PRICE_CACHE_TTL_SECS", "120");
+        assert_eq!(PriceFeedConfig::from_env().cache_ttl_seconds, 120);
+        std::env::remove_var("PRICE_CACHE_TTL_SECS");
     }
 
     #[test]
@@ -463,6 +846,9 @@ mod tests {
                 CachedPrice {
                     price_usd: 0.10,
                     timestamp: Instant::now(),
+                    as_of: Utc::now(),
+                    out_of_band: 0,
+                    source: PriceSource::Provider,
                 },
             );
         }
@@ -479,4 +865,324 @@ mod tests {
         assert_eq!(total, 1);
         assert_eq!(fresh, 0);
     }
+
+    #[tokio::test]
+    async fn test_price_staleness_uses_injected_clock() {
+        let config = PriceFeedConfig {
+            max_age_seconds: 60,
+            ..Default::default()
+        };
+        let start = Utc::now();
+        let now = Arc::new(std::sync::Mutex::new(start));
+        let clock_now = Arc::clone(&now);
+        let client = PriceFeedClient::new(config, default_asset_mapping())
+            .with_clock(Arc::new(move || *clock_now.lock().unwrap()));
+
+        {
+            let mut cache = client.cache.write().await;
+            cache.insert(
+                "XLM:native".to_string(),
+                CachedPrice {
+                    price_usd: 0.12,
+                    timestamp: Instant::now(),
+                    as_of: start,
+                    out_of_band: 0,
+                    source: PriceSource::Provider,
+                },
+            );
+        }
+
+        let meta = client.get_price_with_meta("XLM:native").await.unwrap();
+        assert_eq!(meta.price_usd, 0.12);
+        assert_eq!(meta.as_of, start);
+        assert!(!meta.stale);
+
+        *now.lock().unwrap() = start + chrono::Duration::seconds(61);
+
+        let meta = client.get_price_with_meta("XLM:native").await.unwrap();
+        assert_eq!(meta.price_usd, 0.12);
+        assert!(meta.stale);
+    }
+
+    #[test]
+    fn test_price_band() {
+        let config = PriceFeedConfig {
+            price_band_pct: Some(10.0),
+            ..Default::default()
+        };
+        let client = PriceFeedClient::new(config, default_asset_mapping());
+
+        assert!(client.within_price_band(1.0, 1.05));
+        assert!(client.within_price_band(1.0, 0.9));
+        assert!(!client.within_price_band(1.0, 1.5));
+
+        let unbounded = PriceFeedClient::new(PriceFeedConfig::default(), default_asset_mapping());
+        assert!(unbounded.within_price_band(1.0, 100.0));
+    }
+
+    /// Returns whatever price was last set
+    struct SettableProvider(std::sync::Mutex<f64>);
+
+    impl SettableProvider {
+        fn set(&self, price: f64) {
+            *self.0.lock().unwrap() = price;
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl PriceFeedProvider for SettableProvider {
+        async fn fetch_price(&self, _asset_id: &str) -> Result<f64> {
+            Ok(*self.0.lock().unwrap())
+        }
+
+        async fn fetch_prices(&self, _asset_ids: &[String]) -> Result<HashMap<String, f64>> {
+            anyhow::bail!("not used")
+        }
+
+        fn name(&self) -> &str {
+            "Settable"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_price_band_rebaselines_after_sustained_move() {
+        let config = PriceFeedConfig {
+            // Every lookup goes to the provider
+            cache_ttl_seconds: 0,
+            price_band_pct: Some(10.0),
+            price_band_rebaseline_after: 3,
+            ..Default::default()
+        };
+        let provider = Arc::new(SettableProvider(std::sync::Mutex::new(1.0)));
+        let client =
+            PriceFeedClient::new(config, default_asset_mapping()).with_provider(provider.clone());
+        assert_eq!(client.get_price("XLM:native").await.unwrap(), 1.0);
+
+        // A one-off spike is rejected, and an in-band quote ends the streak
+        provider.set(5.0);
+        assert_eq!(client.get_price("XLM:native").await.unwrap(), 1.0);
+        provider.set(1.02);
+        assert_eq!(client.get_price("XLM:native").await.unwrap(), 1.02);
+
+        // A sustained move is rejected until it has been seen three times in a row
+        provider.set(2.0);
+        assert_eq!(client.get_price("XLM:native").await.unwrap(), 1.02);
+        assert_eq!(client.get_price("XLM:native").await.unwrap(), 1.02);
+        assert_eq!(client.get_price("XLM:native").await.unwrap(), 2.0);
+
+        // The band now applies around the new level
+        provider.set(2.1);
+        assert_eq!(client.get_price("XLM:native").await.unwrap(), 2.1);
+    }
+
+    const USDC: &str = DEFAULT_DERIVED_QUOTE_ASSET;
+
+    struct UnavailableProvider;
+
+    #[async_trait::async_trait]
+    impl PriceFeedProvider for UnavailableProvider {
+        async fn fetch_price(&self, _asset_id: &str) -> Result<f64> {
+            anyhow::bail!("provider unavailable")
+        }
+
+        async fn fetch_prices(&self, _asset_ids: &[String]) -> Result<HashMap<String, f64>> {
+            anyhow::bail!("provider unavailable")
+        }
+
+        fn name(&self) -> &str {
+            "Unavailable"
+        }
+    }
+
+    /// Returns a fixed price and counts requests
+    #[derive(Default)]
+    struct CountingProvider {
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    impl CountingProvider {
+        fn calls(&self) -> usize {
+            self.calls.load(std::sync::atomic::Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl PriceFeedProvider for CountingProvider {
+        async fn fetch_price(&self, _asset_id: &str) -> Result<f64> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            // Keep the request in flight long enough for lookups to overlap
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Ok(0.12)
+        }
+
+        async fn fetch_prices(&self, _asset_ids: &[String]) -> Result<HashMap<String, f64>> {
+            anyhow::bail!("not used")
+        }
+
+        fn name(&self) -> &str {
+            "Counting"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rapid_lookups_share_one_upstream_request() {
+        let provider = Arc::new(CountingProvider::default());
+        let client = PriceFeedClient::new(PriceFeedConfig::default(), default_asset_mapping())
+            .with_provider(provider.clone());
+
+        let (first, second) = tokio::join!(
+            client.get_price("XLM:native"),
+            client.get_price("XLM:native")
+        );
+        assert_eq!(first.unwrap(), 0.12);
+        assert_eq!(second.unwrap(), 0.12);
+        assert_eq!(client.get_price("XLM:native").await.unwrap(), 0.12);
+        assert_eq!(provider.calls(), 1);
+
+        // A forced refresh goes upstream despite the fresh cache entry
+        client.refresh_price("XLM:native").await.unwrap();
+        assert_eq!(provider.calls(), 2);
+        client.get_price("XLM:native").await.unwrap();
+        assert_eq!(provider.calls(), 2);
+    }
+
+    /// Serves the trades for the requested pair, like Horizon's `/trades`
+    /// with base and counter assets
+    struct MockTrades {
+        trades: Vec<Trade>,
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    impl MockTrades {
+        fn new(trades: Vec<Trade>) -> Self {
+            Self {
+                trades,
+                calls: std::sync::atomic::AtomicUsize::new(0),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl TradeSource for MockTrades {
+        async fn pair_trades(&self, asset: &str, quote: &str, _limit: u32) -> Result<Vec<Trade>> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(self
+                .trades
+                .iter()
+                .filter(|t| {
+                    let base = |key| {
+                        trade_side_is(
+                            &t.base_asset_type,
+                            t.base_asset_code.as_deref(),
+                            t.base_asset_issuer.as_deref(),
+                            key,
+                        )
+                    };
+                    let counter = |key| {
+                        trade_side_is(
+                            &t.counter_asset_type,
+                            t.counter_asset_code.as_deref(),
+                            t.counter_asset_issuer.as_deref(),
+                            key,
+                        )
+                    };
+                    (base(asset) && counter(quote)) || (base(quote) && counter(asset))
+                })
+                .cloned()
+                .collect())
+        }
+    }
+
+    /// A trade selling `base_amount` of `base` for `counter_amount` of `counter`
+    fn trade(
+        closed: DateTime<Utc>,
+        base: &str,
+        base_amount: &str,
+        counter: &str,
+        counter_amount: &str,
+    ) -> Trade {
+        let side = |key: &str| match key.split_once(':') {
+            Some(("XLM", "native")) | None => ("native".to_string(), None, None),
+            Some((code, issuer)) => (
+                "credit_alphanum4".to_string(),
+                Some(code.to_string()),
+                Some(issuer.to_string()),
+            ),
+        };
+        let (base_asset_type, base_asset_code, base_asset_issuer) = side(base);
+        let (counter_asset_type, counter_asset_code, counter_asset_issuer) = side(counter);
+        Trade {
+            id: format!("{}-{}", closed.timestamp(), base_amount),
+            ledger_close_time: closed.to_rfc3339(),
+            base_account: "GBASE".to_string(),
+            base_amount: base_amount.to_string(),
+            base_asset_type,
+            base_asset_code,
+            base_asset_issuer,
+            counter_account: "GCOUNTER".to_string(),
+            counter_amount: counter_amount.to_string(),
+            counter_asset_type,
+            counter_asset_code,
+            counter_asset_issuer,
+            price: crate::rpc::Price { n: 1, d: 1 },
+            trade_type: "orderbook".to_string(),
+        }
+    }
+
+    fn mock_trades(now: DateTime<Utc>) -> Vec<Trade> {
+        let minutes_ago = |m| now - chrono::Duration::minutes(m);
+        vec![
+            trade(minutes_ago(5), "XLM:native", "100", USDC, "10"),
+            trade(minutes_ago(10), "XLM:native", "300", USDC, "36"),
+            // Quoted the other way round: 22 USDC bought 200 XLM
+            trade(minutes_ago(15), USDC, "22", "XLM:native", "200"),
+            // Outside the one-hour window
+            trade(minutes_ago(90), "XLM:native", "1000", USDC, "500"),
+            // Unrelated pair
+            trade(minutes_ago(5), "XLM:native", "50", "AQUA:GAQUA", "5000"),
+        ]
+    }
+
+    #[test]
+    fn test_volume_weighted_average_price() {
+        let now = Utc::now();
+        let vwap = volume_weighted_average_price(
+            &mock_trades(now),
+            "XLM:native",
+            USDC,
+            now - chrono::Duration::hours(1),
+        )
+        .unwrap();
+
+        // (10 + 36 + 22) USDC / (100 + 300 + 200) XLM
+        assert!((vwap - 68.0 / 600.0).abs() < 1e-12);
+        assert!(
+            volume_weighted_average_price(&mock_trades(now), "EURC:GISSUER", USDC, now).is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_derived_price_when_provider_unavailable() {
+        let now = Utc::now();
+        let trades = Arc::new(MockTrades::new(mock_trades(now)));
+        let client = PriceFeedClient::new(PriceFeedConfig::default(), default_asset_mapping())
+            .with_clock(Arc::new(move || now))
+            .with_provider(Arc::new(UnavailableProvider))
+            .with_trade_source(Arc::clone(&trades) as Arc<dyn TradeSource>);
+
+        let meta = client.get_price_with_meta("XLM:native").await.unwrap();
+        assert_eq!(meta.source, PriceSource::Derived);
+        assert!((meta.price_usd - 68.0 / 600.0).abs() < 1e-12);
+        assert!(!meta.stale);
+
+        // The derived price is cached, so a second lookup fetches no trades
+        let meta = client.get_price_with_meta("XLM:native").await.unwrap();
+        assert_eq!(meta.source, PriceSource::Derived);
+        assert_eq!(trades.calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        let without_trades =
+            PriceFeedClient::new(PriceFeedConfig::default(), default_asset_mapping())
+                .with_provider(Arc::new(UnavailableProvider));
+        assert!(without_trades.get_price("XLM:native").await.is_err());
+    }
 }
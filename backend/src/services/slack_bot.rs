@@ -42,12 +42,14 @@ impl SlackBotService {
             AlertType::SuccessRateDrop => "🔴 Success Rate Drop",
             AlertType::LatencyIncrease => "🟡 Latency Increase",
             AlertType::LiquidityDecrease => "🟠 Liquidity Decrease",
+            AlertType::HealthScoreDegradation => "🔴 Health Score Degradation",
         };
 
         let color = match alert.alert_type {
-            AlertType::SuccessRateDrop => "#E01E5A",   // Red
-            AlertType::LatencyIncrease => "#ECB22E",   // Yellow
-            AlertType::LiquidityDecrease => "#E8912D", // Orange
+            AlertType::SuccessRateDrop => "#E01E5A",        // Red
+            AlertType::LatencyIncrease => "#ECB22E",        // Yellow
+            AlertType::LiquidityDecrease => "#E8912D",      // Orange
+            AlertType::HealthScoreDegradation => "#E01E5A", // Red
         };
 
         let payload = serde_json::json!({
@@ -316,6 +316,28 @@ impl SnapshotService {
         Ok(snapshot_id)
     }
 
+    /// Load the most recently stored snapshot for an epoch
+    pub async fn load_snapshot(&self, epoch: u64) -> Result<Option<AnalyticsSnapshot>> {
+        let data: Option<String> = sqlx::query_scalar(
+            r#"
+            SELECT data FROM snapshots
+            WHERE entity_type = 'analytics_snapshot' AND epoch = ?
+            ORDER BY created_at DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(epoch as i64)
+        .fetch_optional(self.db.pool())
+        .await
+        .context("Failed to fetch snapshot record")?;
+
+        data.map(|json| {
+            serde_json::from_str(&json)
+                .with_context(|| format!("Stored snapshot for epoch {} is malformed", epoch))
+        })
+        .transpose()
+    }
+
     /// Verify that the submission was successful by querying the contract
     /// Verify that a snapshot submission was successful by checking on-chain
     ///
@@ -3,17 +3,43 @@ use sqlx::{Pool, Sqlite};
 use std::sync::Arc;
 use tracing::info;
 
-use crate::models::{TrustlineMetrics, TrustlineSnapshot, TrustlineStat};
+use crate::models::{
+    TrustlineMetrics, TrustlineSnapshot, TrustlineStat, TrustlineTrend, TrustlineTrendDirection,
+};
 use crate::rpc::StellarRpcClient;
 
+/// Default look-back for trustline trends
+pub const DEFAULT_TREND_WINDOW_DAYS: i64 = 30;
+/// Changes smaller than this percentage count as flat
+pub const DEFAULT_TREND_FLAT_THRESHOLD_PCT: f64 = 1.0;
+
+/// Trend window from `TRUSTLINE_TREND_WINDOW_DAYS`
+pub fn trend_window_days() -> i64 {
+    std::env::var("TRUSTLINE_TREND_WINDOW_DAYS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .filter(|days: &i64| *days > 0)
+        .unwrap_or(DEFAULT_TREND_WINDOW_DAYS)
+}
+
 pub struct TrustlineAnalyzer {
     pool: Pool<Sqlite>,
     rpc_client: Arc<StellarRpcClient>,
+    flat_threshold_pct: f64,
 }
 
 impl TrustlineAnalyzer {
     pub fn new(pool: Pool<Sqlite>, rpc_client: Arc<StellarRpcClient>) -> Self {
-        Self { pool, rpc_client }
+        let flat_threshold_pct = std::env::var("TRUSTLINE_TREND_FLAT_PCT")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .filter(|pct: &f64| *pct >= 0.0)
+            .unwrap_or(DEFAULT_TREND_FLAT_THRESHOLD_PCT);
+        Self {
+            pool,
+            rpc_client,
+            flat_threshold_pct,
+        }
     }
 
     // ========================================================================
@@ -140,6 +166,71 @@ impl TrustlineAnalyzer {
         Ok(rankings)
     }
 
+    /// Current trustline stats for one asset
+    pub async fn get_asset_stat(
+        &self,
+        asset_code: &str,
+        asset_issuer: &str,
+    ) -> Result<Option<TrustlineStat>> {
+        let stat = sqlx::query_as::<_, TrustlineStat>(
+            r#"
+            SELECT * FROM trustline_stats
+            WHERE asset_code = ?1 AND asset_issuer = ?2
+            "#,
+        )
+        .bind(asset_code)
+        .bind(asset_issuer)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(stat)
+    }
+
+    /// Compare the current authorized trustline count with the oldest snapshot
+    /// taken in the last `window_days`. Returns `None` when the asset is not
+    /// tracked or has no snapshot in the window.
+    pub async fn get_trustline_trend(
+        &self,
+        asset_code: &str,
+        asset_issuer: &str,
+        window_days: i64,
+    ) -> Result<Option<TrustlineTrend>> {
+        let Some(stat) = self.get_asset_stat(asset_code, asset_issuer).await? else {
+            return Ok(None);
+        };
+
+        let baseline = sqlx::query_as::<_, TrustlineSnapshot>(
+            r#"
+            SELECT * FROM trustline_snapshots
+            WHERE asset_code = ?1 AND asset_issuer = ?2
+              AND snapshot_at >= datetime('now', ?3)
+            ORDER BY snapshot_at ASC
+            LIMIT 1
+            "#,
+        )
+        .bind(asset_code)
+        .bind(asset_issuer)
+        .bind(format!("-{} days", window_days))
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(baseline.map(|baseline| {
+            let (direction, change_pct) = classify_trend(
+                baseline.authorized_trustlines,
+                stat.authorized_trustlines,
+                self.flat_threshold_pct,
+            );
+            TrustlineTrend {
+                direction,
+                current_authorized: stat.authorized_trustlines,
+                baseline_authorized: baseline.authorized_trustlines,
+                baseline_at: baseline.snapshot_at,
+                change_pct,
+                window_days,
+            }
+        }))
+    }
+
     /// Retrieves historical snapshot data for a given asset
     pub async fn get_asset_history(
         &self,
@@ -164,3 +255,30 @@ impl TrustlineAnalyzer {
         Ok(history)
     }
 }
+
+/// Classify the move from `baseline` to `current`, returning the percentage
+/// change when the baseline is non-zero
+fn classify_trend(
+    baseline: i64,
+    current: i64,
+    flat_threshold_pct: f64,
+) -> (TrustlineTrendDirection, Option<f64>) {
+    if baseline == 0 {
+        let direction = if current > 0 {
+            TrustlineTrendDirection::Growing
+        } else {
+            TrustlineTrendDirection::Flat
+        };
+        return (direction, None);
+    }
+
+    let change_pct = (current - baseline) as f64 / baseline as f64 * 100.0;
+    let direction = if change_pct >= flat_threshold_pct {
+        TrustlineTrendDirection::Growing
+    } else if change_pct <= -flat_threshold_pct {
+        TrustlineTrendDirection::Shrinking
+    } else {
+        TrustlineTrendDirection::Flat
+    };
+    (direction, Some(change_pct))
+}
@@ -1,17 +1,23 @@
 /// Webhook Dispatcher Service
 /// Processes webhook events and sends them to registered webhooks with retry logic
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use reqwest::Client;
 use sqlx::SqlitePool;
 use std::time::Duration;
 use uuid::Uuid;
 
+use crate::webhooks::deliveries::{WebhookRetryPolicy, DELIVERY_DEAD};
 use crate::webhooks::{WebhookEventEnvelope, WebhookService, WebhookSignature};
 
 /// Webhook dispatcher - sends events to webhooks asynchronously
 pub struct WebhookDispatcher {
     db: SqlitePool,
     http_client: Client,
+    /// Shared secret for the replay-protected signature (`WEBHOOK_SIGNING_SECRET`)
+    signing_secret: Option<String>,
+    /// Backoff for deliveries that failed their first attempt
+    retry_policy: WebhookRetryPolicy,
 }
 
 impl WebhookDispatcher {
@@ -22,7 +28,22 @@ impl WebhookDispatcher {
             .build()
             .unwrap_or_else(|_| Client::new());
 
-        Self { db, http_client }
+        let signing_secret = std::env::var("WEBHOOK_SIGNING_SECRET")
+            .ok()
+            .filter(|s| !s.is_empty());
+
+        Self {
+            db,
+            http_client,
+            signing_secret,
+            retry_policy: WebhookRetryPolicy::from_env(),
+        }
+    }
+
+    /// Override the retry backoff read from the environment
+    pub fn with_retry_policy(mut self, retry_policy: WebhookRetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
     }
 
     /// Run dispatcher loop - processes pending webhook events
@@ -41,7 +62,7 @@ impl WebhookDispatcher {
     }
 
     /// Process all pending webhook events
-    async fn process_pending_events(&self) -> Result<()> {
+    pub async fn process_pending_events(&self) -> Result<()> {
         let service = WebhookService::new(self.db.clone());
 
         // Fetch pending events (max 10 per run)
@@ -88,43 +109,110 @@ impl WebhookDispatcher {
                     );
                 }
                 Err(e) => {
-                    // Determine retry count from event
-                    let current_retries = self.get_event_retries(&event_id).await.unwrap_or(0);
+                    // Hand the event to the retry queue, which owns it from here
+                    let error = e.to_string();
+                    let delivery = service
+                        .record_failed_delivery(&event_id, &error, &self.retry_policy, Utc::now())
+                        .await?;
+                    let status = if delivery.status == DELIVERY_DEAD {
+                        "failed"
+                    } else {
+                        "retrying"
+                    };
+                    service
+                        .update_event_status(&event_id, status, Some(&error), 1)
+                        .await?;
+
+                    tracing::warn!(
+                        "Webhook delivery failed (retry at {}): webhook_id={}, error={}",
+                        delivery.next_retry_at,
+                        webhook_id,
+                        e
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Retry queued deliveries that are due, using the current time
+    pub async fn retry_due_deliveries(&self) -> Result<usize> {
+        self.retry_due_deliveries_at(Utc::now()).await
+    }
+
+    /// Retry queued deliveries due at `now`, rescheduling failures with
+    /// backoff and dead-lettering those out of attempts. Returns the number
+    /// of deliveries attempted.
+    pub async fn retry_due_deliveries_at(&self, now: DateTime<Utc>) -> Result<usize> {
+        let service = WebhookService::new(self.db.clone());
+        let deliveries = service.due_deliveries(now, 50).await?;
+
+        for delivery in &deliveries {
+            let result = match service.get_webhook(&delivery.webhook_id).await? {
+                Some(webhook) if webhook.is_active => {
+                    self.deliver_webhook(
+                        &webhook.url,
+                        &delivery.payload,
+                        &webhook.secret,
+                        &delivery.event_type,
+                    )
+                    .await
+                }
+                Some(_) => Err(anyhow::anyhow!("webhook_inactive")),
+                None => Err(anyhow::anyhow!("webhook_deleted")),
+            };
+            let attempts = delivery.attempts as i32 + 1;
+
+            match result {
+                Ok(()) => {
+                    service.mark_delivery_delivered(&delivery.id, now).await?;
+                    service
+                        .update_event_status(&delivery.event_id, "delivered", None, attempts)
+                        .await?;
+                    let _ = service.update_last_fired(&delivery.webhook_id).await;
+
+                    tracing::info!(
+                        "Webhook retry delivered: webhook_id={}, event={}, attempts={}",
+                        delivery.webhook_id,
+                        delivery.event_type,
+                        attempts
+                    );
+                }
+                Err(e) => {
+                    let error = e.to_string();
+                    let status = service
+                        .record_delivery_retry_failure(delivery, &error, &self.retry_policy, now)
+                        .await?;
 
-                    if current_retries < 3 {
-                        // Retry later
-                        let _ = service
+                    if status == DELIVERY_DEAD {
+                        service
                             .update_event_status(
-                                &event_id,
-                                "pending",
-                                Some(&e.to_string()),
-                                current_retries + 1,
+                                &delivery.event_id,
+                                "failed",
+                                Some(&error),
+                                attempts,
                             )
-                            .await;
-
-                        tracing::warn!(
-                            "Webhook delivery failed (will retry): webhook_id={}, error={}, retries={}",
-                            webhook_id,
-                            e,
-                            current_retries + 1
+                            .await?;
+                        tracing::error!(
+                            "Webhook delivery dead-lettered: webhook_id={}, error={}, attempts={}",
+                            delivery.webhook_id,
+                            error,
+                            attempts
                         );
                     } else {
-                        // Max retries exceeded
-                        let _ = service
-                            .update_event_status(&event_id, "failed", Some(&e.to_string()), 3)
-                            .await;
-
-                        tracing::error!(
-                            "Webhook delivery failed (max retries): webhook_id={}, error={}",
-                            webhook_id,
-                            e
+                        tracing::warn!(
+                            "Webhook retry failed: webhook_id={}, error={}, attempts={}",
+                            delivery.webhook_id,
+                            error,
+                            attempts
                         );
                     }
                 }
             }
         }
 
-        Ok(())
+        Ok(deliveries.len())
     }
 
     /// Deliver webhook to URL
@@ -156,17 +244,28 @@ impl WebhookDispatcher {
             &signature[..20]
         );
 
-        let response = self
+        let mut request = self
             .http_client
             .post(url)
             .header("X-Zapier-Event", event_type)
             .header("X-Zapier-Signature", signature)
             .header("X-Zapier-Timestamp", timestamp.to_string())
-            .header("X-Zapier-Delivery-ID", delivery_id)
-            .header("Content-Type", "application/json")
-            .body(body)
-            .send()
-            .await?;
+            .header("X-Zapier-Delivery-ID", &delivery_id)
+            .header("Content-Type", "application/json");
+
+        // Replay-protected signature over timestamp + nonce + body, verified by
+        // consumers with `replay::WebhookReplayGuard`
+        if let Some(signing_secret) = &self.signing_secret {
+            let nonce = Uuid::new_v4().to_string();
+            let replay_signature =
+                WebhookSignature::sign_timestamped(&body, timestamp, &nonce, signing_secret);
+            request = request
+                .header("X-Webhook-Timestamp", timestamp.to_string())
+                .header("X-Webhook-Nonce", nonce)
+                .header("X-Webhook-Signature", replay_signature);
+        }
+
+        let response = request.body(body).send().await?;
 
         if response.status().is_success() {
             Ok(())
@@ -178,17 +277,6 @@ impl WebhookDispatcher {
             )
         }
     }
-
-    /// Get current retry count for an event
-    async fn get_event_retries(&self, event_id: &str) -> Result<i32> {
-        let retries: Option<i64> =
-            sqlx::query_scalar("SELECT retries FROM webhook_events WHERE id = ?")
-                .bind(event_id)
-                .fetch_optional(&self.db)
-                .await?;
-
-        Ok(retries.unwrap_or(0) as i32)
-    }
 }
 
 #[cfg(test)]
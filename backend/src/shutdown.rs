@@ -3,11 +3,14 @@
 //! This module provides utilities for handling shutdown signals (SIGTERM, SIGINT)
 //! and coordinating graceful shutdown of server components.
 
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 use tokio::sync::broadcast;
 use tokio::time::timeout;
 use tracing::{info, warn};
 
+use crate::database::Database;
+
 /// Configuration for graceful shutdown behavior
 #[derive(Debug, Clone)]
 pub struct ShutdownConfig {
@@ -17,6 +20,9 @@ pub struct ShutdownConfig {
     pub background_task_timeout: Duration,
     /// Maximum time to wait for database connections to close
     pub db_close_timeout: Duration,
+    /// Time `/health` reports 503 before the server stops accepting
+    /// connections, so load balancers can move traffic elsewhere
+    pub drain_delay: Duration,
 }
 
 impl Default for ShutdownConfig {
@@ -25,6 +31,7 @@ impl Default for ShutdownConfig {
             graceful_timeout: Duration::from_secs(30),
             background_task_timeout: Duration::from_secs(10),
             db_close_timeout: Duration::from_secs(5),
+            drain_delay: Duration::from_secs(5),
         }
     }
 }
@@ -50,10 +57,17 @@ impl ShutdownConfig {
             .map(Duration::from_secs)
             .unwrap_or(Duration::from_secs(5));
 
+        let drain_delay = std::env::var("SHUTDOWN_DRAIN_DELAY")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(Duration::from_secs(5));
+
         Self {
             graceful_timeout,
             background_task_timeout,
             db_close_timeout,
+            drain_delay,
         }
     }
 }
@@ -62,6 +76,7 @@ impl ShutdownConfig {
 pub struct ShutdownCoordinator {
     config: ShutdownConfig,
     shutdown_tx: broadcast::Sender<()>,
+    draining: AtomicBool,
 }
 
 impl ShutdownCoordinator {
@@ -71,6 +86,7 @@ impl ShutdownCoordinator {
         Self {
             config,
             shutdown_tx,
+            draining: AtomicBool::new(false),
         }
     }
 
@@ -79,9 +95,20 @@ impl ShutdownCoordinator {
         self.shutdown_tx.subscribe()
     }
 
+    /// Start failing health checks ahead of shutdown, while still serving requests
+    pub fn begin_draining(&self) {
+        self.draining.store(true, Ordering::Release);
+    }
+
+    /// Whether shutdown has begun and the server should receive no new traffic
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::Acquire)
+    }
+
     /// Trigger shutdown and notify all subscribers
     pub fn trigger_shutdown(&self) {
         info!("Triggering graceful shutdown");
+        self.begin_draining();
         let _ = self.shutdown_tx.send(());
     }
 
@@ -99,6 +126,11 @@ impl ShutdownCoordinator {
     pub fn db_close_timeout(&self) -> Duration {
         self.config.db_close_timeout
     }
+
+    /// Get how long to drain traffic before shutting down
+    pub fn drain_delay(&self) -> Duration {
+        self.config.drain_delay
+    }
 }
 
 /// Wait for shutdown signals (SIGTERM, SIGINT/Ctrl+C)
@@ -166,22 +198,10 @@ pub async fn shutdown_background_tasks(
 
 /// Gracefully close database connection pool
 ///
-/// Attempts to close the database pool within the timeout period.
-pub async fn shutdown_database(pool: sqlx::SqlitePool, timeout_duration: Duration) {
+/// Drains in-flight queries and closes the pool within the timeout period.
+pub async fn shutdown_database(db: &Database, timeout_duration: Duration) {
     info!("Closing database connections");
-
-    let close_future = async {
-        pool.close().await;
-        info!("Database connections closed successfully");
-    };
-
-    match timeout(timeout_duration, close_future).await {
-        Ok(_) => info!("Database closed within timeout"),
-        Err(_) => warn!(
-            "Database did not close within {:?}, proceeding with shutdown",
-            timeout_duration
-        ),
-    }
+    db.close(timeout_duration).await;
 }
 
 /// Flush Redis cache and close connections gracefully
@@ -276,6 +296,7 @@ mod tests {
         assert_eq!(config.graceful_timeout, Duration::from_secs(30));
         assert_eq!(config.background_task_timeout, Duration::from_secs(10));
         assert_eq!(config.db_close_timeout, Duration::from_secs(5));
+        assert_eq!(config.drain_delay, Duration::from_secs(5));
     }
 
     #[test]
@@ -303,6 +324,7 @@ mod tests {
         // Both receivers should get the signal
         assert!(rx1.recv().await.is_ok());
         assert!(rx2.recv().await.is_ok());
+        assert!(coordinator.is_draining());
     }
 
     #[tokio::test]
@@ -329,4 +351,19 @@ mod tests {
         // Should timeout but not panic
         shutdown_background_tasks(vec![task], Duration::from_millis(100)).await;
     }
+
+    #[tokio::test]
+    async fn test_database_close_within_timeout() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let db = Database::new(pool);
+
+        let one: i64 = sqlx::query_scalar("SELECT 1")
+            .fetch_one(db.pool())
+            .await
+            .unwrap();
+        assert_eq!(one, 1);
+
+        assert!(db.close(Duration::from_secs(5)).await);
+        assert!(db.pool().is_closed());
+    }
 }
@@ -0,0 +1,239 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+use super::schema::{AnalyticsSnapshot, SnapshotAnchorMetrics, SnapshotCorridorMetrics};
+
+/// Entities that appeared, disappeared or moved between two snapshots
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EntityDiff<T, D> {
+    pub added: Vec<T>,
+    pub removed: Vec<T>,
+    pub changed: Vec<D>,
+}
+
+/// Change in an anchor present in both snapshots
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AnchorDelta {
+    pub id: Uuid,
+    pub name: String,
+    pub success_rate_from: f64,
+    pub success_rate_to: f64,
+    pub success_rate_change: f64,
+    /// Missing volumes count as zero
+    pub volume_usd_change: f64,
+}
+
+/// Change in a corridor present in both snapshots
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CorridorDelta {
+    pub corridor_key: String,
+    pub success_rate_from: f64,
+    pub success_rate_to: f64,
+    pub success_rate_change: f64,
+    pub volume_usd_change: f64,
+}
+
+/// What changed between the snapshots of two epochs
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SnapshotDiff {
+    pub from_epoch: u64,
+    pub to_epoch: u64,
+    pub anchors: EntityDiff<SnapshotAnchorMetrics, AnchorDelta>,
+    pub corridors: EntityDiff<SnapshotCorridorMetrics, CorridorDelta>,
+}
+
+/// Diff `from` against `to`. Anchors are matched by id and corridors by
+/// corridor key, since corridor metric ids change with every aggregation.
+/// Both snapshots are normalized first, so every list comes out in the same
+/// order as the canonical serialization.
+pub fn diff_snapshots(from: &AnalyticsSnapshot, to: &AnalyticsSnapshot) -> SnapshotDiff {
+    let mut from = from.clone();
+    let mut to = to.clone();
+    from.normalize();
+    to.normalize();
+
+    let anchors = diff_by_key(
+        from.anchor_metrics,
+        to.anchor_metrics,
+        |a| a.id,
+        |before, after| {
+            let volume_usd_change =
+                after.volume_usd.unwrap_or(0.0) - before.volume_usd.unwrap_or(0.0);
+            changed(before.success_rate, after.success_rate, volume_usd_change).then(|| {
+                AnchorDelta {
+                    id: after.id,
+                    name: after.name.clone(),
+                    success_rate_from: before.success_rate,
+                    success_rate_to: after.success_rate,
+                    success_rate_change: after.success_rate - before.success_rate,
+                    volume_usd_change,
+                }
+            })
+        },
+    );
+
+    let corridors = diff_by_key(
+        from.corridor_metrics,
+        to.corridor_metrics,
+        |c| c.corridor_key.clone(),
+        |before, after| {
+            let volume_usd_change = after.volume_usd - before.volume_usd;
+            changed(before.success_rate, after.success_rate, volume_usd_change).then(|| {
+                CorridorDelta {
+                    corridor_key: after.corridor_key.clone(),
+                    success_rate_from: before.success_rate,
+                    success_rate_to: after.success_rate,
+                    success_rate_change: after.success_rate - before.success_rate,
+                    volume_usd_change,
+                }
+            })
+        },
+    );
+
+    SnapshotDiff {
+        from_epoch: from.epoch,
+        to_epoch: to.epoch,
+        anchors,
+        corridors,
+    }
+}
+
+fn changed(success_rate_from: f64, success_rate_to: f64, volume_change: f64) -> bool {
+    success_rate_from != success_rate_to || volume_change != 0.0
+}
+
+/// Split two entity lists into added, removed and changed, ordered by key
+fn diff_by_key<T, K, D>(
+    from: Vec<T>,
+    to: Vec<T>,
+    key: impl Fn(&T) -> K,
+    delta: impl Fn(&T, &T) -> Option<D>,
+) -> EntityDiff<T, D>
+where
+    K: Ord,
+{
+    let mut before: BTreeMap<K, T> = from.into_iter().map(|e| (key(&e), e)).collect();
+    let after: BTreeMap<K, T> = to.into_iter().map(|e| (key(&e), e)).collect();
+
+    let mut added = Vec::new();
+    let mut changed = Vec::new();
+    for (k, entity) in after {
+        match before.remove(&k) {
+            Some(previous) => changed.extend(delta(&previous, &entity)),
+            None => added.push(entity),
+        }
+    }
+
+    EntityDiff {
+        added,
+        removed: before.into_values().collect(),
+        changed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn anchor(id: u128, success_rate: f64, volume_usd: Option<f64>) -> SnapshotAnchorMetrics {
+        SnapshotAnchorMetrics {
+            id: Uuid::from_u128(id),
+            name: format!("Anchor{}", id),
+            stellar_account: format!("GTEST{}", id),
+            success_rate,
+            failure_rate: 1.0 - success_rate,
+            reliability_score: 0.9,
+            total_transactions: 100,
+            successful_transactions: 90,
+            failed_transactions: 10,
+            avg_settlement_time_ms: Some(500),
+            volume_usd,
+            status: "green".to_string(),
+        }
+    }
+
+    fn corridor(key: &str, success_rate: f64, volume_usd: f64) -> SnapshotCorridorMetrics {
+        SnapshotCorridorMetrics {
+            // A fresh id each time, as aggregation produces
+            id: Uuid::new_v4(),
+            corridor_key: key.to_string(),
+            asset_a_code: "USDC".to_string(),
+            asset_a_issuer: "GISSUER".to_string(),
+            asset_b_code: "EURC".to_string(),
+            asset_b_issuer: "GISSUER".to_string(),
+            total_transactions: 100,
+            successful_transactions: 90,
+            failed_transactions: 10,
+            success_rate,
+            volume_usd,
+            avg_settlement_latency_ms: Some(400),
+            liquidity_depth_usd: 1000.0,
+        }
+    }
+
+    #[test]
+    fn test_diff_reports_added_removed_and_changed() {
+        let mut from = AnalyticsSnapshot::new(1, Utc::now());
+        from.add_anchor_metrics(anchor(1, 0.9, Some(100.0)));
+        from.add_anchor_metrics(anchor(2, 0.8, None));
+        from.add_anchor_metrics(anchor(3, 0.7, Some(50.0)));
+        from.add_corridor_metrics(corridor("USDC->EURC", 90.0, 1000.0));
+        from.add_corridor_metrics(corridor("USDC->XLM", 80.0, 500.0));
+
+        let mut to = AnalyticsSnapshot::new(2, Utc::now());
+        to.add_anchor_metrics(anchor(4, 0.95, Some(10.0)));
+        to.add_anchor_metrics(anchor(3, 0.7, Some(50.0)));
+        to.add_anchor_metrics(anchor(1, 0.95, Some(150.0)));
+        to.add_corridor_metrics(corridor("XLM->EURC", 99.0, 10.0));
+        to.add_corridor_metrics(corridor("USDC->EURC", 90.0, 1200.0));
+
+        let diff = diff_snapshots(&from, &to);
+
+        assert_eq!((diff.from_epoch, diff.to_epoch), (1, 2));
+
+        let ids = |anchors: &[SnapshotAnchorMetrics]| -> Vec<Uuid> {
+            anchors.iter().map(|a| a.id).collect()
+        };
+        assert_eq!(ids(&diff.anchors.added), vec![Uuid::from_u128(4)]);
+        assert_eq!(ids(&diff.anchors.removed), vec![Uuid::from_u128(2)]);
+        // Anchor 3 is unchanged and left out
+        assert_eq!(diff.anchors.changed.len(), 1);
+        let delta = &diff.anchors.changed[0];
+        assert_eq!(delta.id, Uuid::from_u128(1));
+        assert!((delta.success_rate_change - 0.05).abs() < 1e-9);
+        assert_eq!(delta.volume_usd_change, 50.0);
+
+        assert_eq!(diff.corridors.added.len(), 1);
+        assert_eq!(diff.corridors.added[0].corridor_key, "XLM->EURC");
+        assert_eq!(diff.corridors.removed.len(), 1);
+        assert_eq!(diff.corridors.removed[0].corridor_key, "USDC->XLM");
+        assert_eq!(
+            diff.corridors.changed,
+            vec![CorridorDelta {
+                corridor_key: "USDC->EURC".to_string(),
+                success_rate_from: 90.0,
+                success_rate_to: 90.0,
+                success_rate_change: 0.0,
+                volume_usd_change: 200.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_diff_of_identical_snapshots_is_empty() {
+        let mut snapshot = AnalyticsSnapshot::new(1, Utc::now());
+        snapshot.add_anchor_metrics(anchor(1, 0.9, Some(100.0)));
+        snapshot.add_corridor_metrics(corridor("USDC->EURC", 90.0, 1000.0));
+
+        let diff = diff_snapshots(&snapshot, &snapshot);
+
+        assert!(diff.anchors.added.is_empty());
+        assert!(diff.anchors.removed.is_empty());
+        assert!(diff.anchors.changed.is_empty());
+        assert!(diff.corridors.added.is_empty());
+        assert!(diff.corridors.removed.is_empty());
+        assert!(diff.corridors.changed.is_empty());
+    }
+}
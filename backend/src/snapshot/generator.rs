@@ -30,13 +30,18 @@ impl SnapshotGenerator {
     /// the original ordering of metrics in memory.
     pub fn generate_hash(snapshot: AnalyticsSnapshot) -> Result<[u8; 32], serde_json::Error> {
         let canonical_json = Self::to_canonical_json(snapshot)?;
+        Ok(Self::hash_canonical_json(&canonical_json))
+    }
+
+    /// SHA-256 of JSON already produced by [`Self::to_canonical_json`]
+    pub fn hash_canonical_json(canonical_json: &str) -> [u8; 32] {
         let mut hasher = Sha256::new();
         hasher.update(canonical_json.as_bytes());
         let result = hasher.finalize();
 
         let mut hash = [0u8; 32];
         hash.copy_from_slice(&result[..]);
-        Ok(hash)
+        hash
     }
 
     /// Generate hex-encoded hash string suitable for display/storage
@@ -1,7 +1,11 @@
+pub mod diff;
 pub mod generator;
 pub mod schema;
+pub mod signing;
 
+pub use diff::{diff_snapshots, AnchorDelta, CorridorDelta, EntityDiff, SnapshotDiff};
 pub use generator::SnapshotGenerator;
 pub use schema::{
     AnalyticsSnapshot, SnapshotAnchorMetrics, SnapshotCorridorMetrics, SCHEMA_VERSION,
 };
+pub use signing::{SignedSnapshot, SnapshotSigner};
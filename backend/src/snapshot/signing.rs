@@ -0,0 +1,70 @@
+use anyhow::{anyhow, Context, Result};
+use ed25519_dalek::{Signer, SigningKey};
+use serde::{Deserialize, Serialize};
+
+use super::generator::SnapshotGenerator;
+use super::schema::AnalyticsSnapshot;
+
+/// Name of the signature scheme reported alongside signed snapshots
+pub const SIGNATURE_ALGORITHM: &str = "ed25519";
+
+/// A snapshot in canonical form with everything needed to verify it offline
+///
+/// `hash` is the hex SHA-256 of `snapshot`, and `signature` is the hex Ed25519
+/// signature over the 32 raw hash bytes made with the key in `public_key`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SignedSnapshot {
+    pub epoch: u64,
+    pub snapshot: String,
+    pub hash: String,
+    pub signature: String,
+    pub public_key: String,
+    pub algorithm: String,
+}
+
+/// Signs snapshot hashes with the server's Ed25519 key
+pub struct SnapshotSigner {
+    key: SigningKey,
+}
+
+impl SnapshotSigner {
+    /// Signer from a hex-encoded 32-byte Ed25519 secret seed
+    pub fn from_seed_hex(seed: &str) -> Result<Self> {
+        let bytes = hex::decode(seed.trim()).context("signing key is not valid hex")?;
+        let seed: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| anyhow!("signing key must be 32 bytes"))?;
+        Ok(Self {
+            key: SigningKey::from_bytes(&seed),
+        })
+    }
+
+    /// Signer from SNAPSHOT_SIGNING_KEY; `None` when it is unset
+    pub fn from_env() -> Result<Option<Self>> {
+        match std::env::var("SNAPSHOT_SIGNING_KEY") {
+            Ok(seed) => Self::from_seed_hex(&seed).map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Hex-encoded public key consumers verify signatures against
+    pub fn public_key_hex(&self) -> String {
+        hex::encode(self.key.verifying_key().to_bytes())
+    }
+
+    pub fn sign(&self, snapshot: AnalyticsSnapshot) -> Result<SignedSnapshot, serde_json::Error> {
+        let epoch = snapshot.epoch;
+        let canonical_json = SnapshotGenerator::to_canonical_json(snapshot)?;
+        let hash = SnapshotGenerator::hash_canonical_json(&canonical_json);
+        let signature = self.key.sign(&hash);
+
+        Ok(SignedSnapshot {
+            epoch,
+            snapshot: canonical_json,
+            hash: hex::encode(hash),
+            signature: hex::encode(signature.to_bytes()),
+            public_key: self.public_key_hex(),
+            algorithm: SIGNATURE_ALGORITHM.to_string(),
+        })
+    }
+}
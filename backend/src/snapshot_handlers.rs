@@ -1,14 +1,22 @@
 //! HTTP handlers for snapshot generation and submission
 
-use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Extension, Json, Router,
+};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tracing::{error, info};
 
 use crate::database::Database;
+use crate::error::ApiJson;
 use crate::services::contract::ContractService;
 use crate::services::snapshot::SnapshotService;
+use crate::snapshot::{diff_snapshots, SignedSnapshot, SnapshotDiff, SnapshotSigner};
 
 /// Response for snapshot generation
 #[derive(Debug, Serialize)]
@@ -39,6 +47,13 @@ pub struct GenerateSnapshotRequest {
     pub submit_to_contract: bool,
 }
 
+/// Epochs to compare; given in either order
+#[derive(Debug, Deserialize)]
+pub struct SnapshotDiffQuery {
+    pub from: u64,
+    pub to: u64,
+}
+
 /// Shared application state for snapshot handlers
 #[derive(Clone)]
 pub struct SnapshotAppState {
@@ -52,7 +67,7 @@ pub struct SnapshotAppState {
 /// POST /api/snapshots/generate
 pub async fn generate_snapshot(
     State(state): State<SnapshotAppState>,
-    Json(request): Json<GenerateSnapshotRequest>,
+    ApiJson(request): ApiJson<GenerateSnapshotRequest>,
 ) -> Result<Json<SnapshotResponse>, SnapshotError> {
     info!(
         "Generating snapshot for epoch {} (submit: {})",
@@ -124,6 +139,78 @@ pub async fn contract_health_check(
     }))
 }
 
+/// Per-anchor and per-corridor changes between two stored snapshots
+///
+/// GET /api/snapshots/diff?from=X&to=Y
+pub async fn snapshot_diff(
+    State(state): State<SnapshotAppState>,
+    Query(query): Query<SnapshotDiffQuery>,
+) -> Result<Json<SnapshotDiff>, SnapshotError> {
+    let (from, to) = if query.from <= query.to {
+        (query.from, query.to)
+    } else {
+        (query.to, query.from)
+    };
+
+    let mut snapshots = Vec::with_capacity(2);
+    for epoch in [from, to] {
+        let snapshot = state
+            .snapshot_service
+            .load_snapshot(epoch)
+            .await
+            .map_err(|e| {
+                error!("Failed to load snapshot for epoch {}: {}", epoch, e);
+                SnapshotError::GenerationError(e.to_string())
+            })?
+            .ok_or_else(|| {
+                SnapshotError::NotFound(format!("No snapshot stored for epoch {}", epoch))
+            })?;
+        snapshots.push(snapshot);
+    }
+
+    Ok(Json(diff_snapshots(&snapshots[0], &snapshots[1])))
+}
+
+/// Stored snapshot in canonical form with its hash, signed by the server key
+///
+/// GET /api/snapshots/:epoch/signed
+pub async fn get_signed_snapshot(
+    State(state): State<SnapshotAppState>,
+    Path(epoch): Path<u64>,
+    signer: Option<Extension<Arc<SnapshotSigner>>>,
+) -> Result<Json<SignedSnapshot>, SnapshotError> {
+    let Some(Extension(signer)) = signer else {
+        return Err(SnapshotError::ConfigError(
+            "Snapshot signing key not configured".to_string(),
+        ));
+    };
+
+    let snapshot = state
+        .snapshot_service
+        .load_snapshot(epoch)
+        .await
+        .map_err(|e| {
+            error!("Failed to load snapshot for epoch {}: {}", epoch, e);
+            SnapshotError::GenerationError(e.to_string())
+        })?
+        .ok_or_else(|| {
+            SnapshotError::NotFound(format!("No snapshot stored for epoch {}", epoch))
+        })?;
+
+    let signed = signer
+        .sign(snapshot)
+        .map_err(|e| SnapshotError::HashingError(e.to_string()))?;
+    Ok(Json(signed))
+}
+
+/// Read-only routes over stored snapshots
+pub fn routes(state: SnapshotAppState) -> Router {
+    Router::new()
+        .route("/api/snapshots/diff", get(snapshot_diff))
+        .route("/api/snapshots/:epoch/signed", get(get_signed_snapshot))
+        .with_state(state)
+}
+
 #[derive(Debug, Serialize)]
 pub struct ContractHealthResponse {
     pub status: &'static str,
@@ -139,6 +226,7 @@ pub enum SnapshotError {
     SubmissionError(String),
     ConnectionError(String),
     ConfigError(String),
+    NotFound(String),
 }
 
 impl IntoResponse for SnapshotError {
@@ -150,6 +238,7 @@ impl IntoResponse for SnapshotError {
             SnapshotError::SubmissionError(msg) => (StatusCode::BAD_GATEWAY, msg),
             SnapshotError::ConnectionError(msg) => (StatusCode::SERVICE_UNAVAILABLE, msg),
             SnapshotError::ConfigError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
+            SnapshotError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
         };
 
         (
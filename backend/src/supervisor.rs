@@ -0,0 +1,341 @@
+//! Supervision for long-running background tasks
+//!
+//! Tasks registered with the [`TaskSupervisor`] are restarted with exponential
+//! backoff when they panic or exit unexpectedly, with the backoff reset once a
+//! run stays up for a while. They stop cleanly when the
+//! [`ShutdownCoordinator`] triggers shutdown. Their state is exposed for the
+//! readiness endpoint.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use tracing::{error, info, warn};
+
+use crate::shutdown::ShutdownCoordinator;
+
+/// Restart policy for supervised tasks
+#[derive(Debug, Clone)]
+pub struct SupervisorConfig {
+    /// Delay before the first restart
+    pub initial_backoff: Duration,
+    /// Upper bound for the restart delay
+    pub max_backoff: Duration,
+    /// A run lasting at least this long resets the restart delay to
+    /// `initial_backoff`
+    pub healthy_run: Duration,
+}
+
+impl Default for SupervisorConfig {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            healthy_run: Duration::from_secs(300),
+        }
+    }
+}
+
+impl SupervisorConfig {
+    /// Load from environment:
+    /// - SUPERVISOR_INITIAL_BACKOFF_MS (default: 1000)
+    /// - SUPERVISOR_MAX_BACKOFF_SECS (default: 60)
+    /// - SUPERVISOR_HEALTHY_RUN_SECS (default: 300)
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            initial_backoff: std::env::var("SUPERVISOR_INITIAL_BACKOFF_MS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.initial_backoff),
+            max_backoff: std::env::var("SUPERVISOR_MAX_BACKOFF_SECS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.max_backoff),
+            healthy_run: std::env::var("SUPERVISOR_HEALTHY_RUN_SECS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.healthy_run),
+        }
+    }
+}
+
+/// Lifecycle state of a supervised task
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskState {
+    Running,
+    Restarting,
+    Stopped,
+}
+
+/// Health snapshot of a single supervised task
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskHealth {
+    pub name: String,
+    pub state: TaskState,
+    pub restarts: u32,
+    pub last_failure: Option<String>,
+    pub last_failure_at: Option<DateTime<Utc>>,
+}
+
+/// Tracks and restarts background tasks
+pub struct TaskSupervisor {
+    config: SupervisorConfig,
+    shutdown: Arc<ShutdownCoordinator>,
+    tasks: Arc<RwLock<HashMap<String, TaskHealth>>>,
+}
+
+impl TaskSupervisor {
+    pub fn new(config: SupervisorConfig, shutdown: Arc<ShutdownCoordinator>) -> Self {
+        Self {
+            config,
+            shutdown,
+            tasks: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Spawn a supervised task.
+    ///
+    /// `factory` is invoked for every (re)start and receives a shutdown receiver
+    /// the task should select on. The returned handle completes once the task
+    /// has stopped for shutdown, so it can be awaited like any other background
+    /// task during graceful shutdown.
+    pub fn spawn<F, Fut>(&self, name: &str, factory: F) -> JoinHandle<()>
+    where
+        F: Fn(broadcast::Receiver<()>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let name = name.to_string();
+        let tasks = Arc::clone(&self.tasks);
+        let shutdown = Arc::clone(&self.shutdown);
+        let config = self.config.clone();
+        let mut shutdown_rx = shutdown.subscribe();
+
+        update(&tasks, &name, |health| health.state = TaskState::Running);
+
+        tokio::spawn(async move {
+            let mut backoff = config.initial_backoff;
+
+            loop {
+                let started = Instant::now();
+                let mut handle = tokio::spawn(factory(shutdown.subscribe()));
+                update(&tasks, &name, |health| health.state = TaskState::Running);
+
+                let outcome = tokio::select! {
+                    result = &mut handle => result,
+                    _ = shutdown_rx.recv() => {
+                        match tokio::time::timeout(shutdown.background_task_timeout(), &mut handle).await {
+                            Ok(_) => info!("Supervised task '{}' stopped", name),
+                            Err(_) => {
+                                warn!("Supervised task '{}' did not stop in time, aborting", name);
+                                handle.abort();
+                            }
+                        }
+                        update(&tasks, &name, |health| health.state = TaskState::Stopped);
+                        return;
+                    }
+                };
+
+                let failure = match outcome {
+                    Ok(()) => "task exited unexpectedly".to_string(),
+                    Err(e) if e.is_panic() => "task panicked".to_string(),
+                    Err(e) => format!("task failed: {}", e),
+                };
+                // Only back off further while the task keeps failing quickly
+                if started.elapsed() >= config.healthy_run {
+                    backoff = config.initial_backoff;
+                }
+                error!(
+                    "Supervised task '{}' {}; restarting in {:?}",
+                    name, failure, backoff
+                );
+                update(&tasks, &name, |health| {
+                    health.state = TaskState::Restarting;
+                    health.restarts += 1;
+                    health.last_failure = Some(failure.clone());
+                    health.last_failure_at = Some(Utc::now());
+                });
+
+                tokio::select! {
+                    _ = tokio::time::sleep(backoff) => {}
+                    _ = shutdown_rx.recv() => {
+                        update(&tasks, &name, |health| health.state = TaskState::Stopped);
+                        return;
+                    }
+                }
+                backoff = (backoff * 2).min(config.max_backoff);
+            }
+        })
+    }
+
+    /// Health of every registered task, sorted by name
+    pub fn task_health(&self) -> Vec<TaskHealth> {
+        let mut tasks: Vec<TaskHealth> = self
+            .tasks
+            .read()
+            .map(|tasks| tasks.values().cloned().collect())
+            .unwrap_or_default();
+        tasks.sort_by(|a, b| a.name.cmp(&b.name));
+        tasks
+    }
+
+    /// True when every registered task is currently running
+    pub fn is_ready(&self) -> bool {
+        self.task_health()
+            .iter()
+            .all(|task| task.state == TaskState::Running)
+    }
+}
+
+fn update(
+    tasks: &RwLock<HashMap<String, TaskHealth>>,
+    name: &str,
+    apply: impl FnOnce(&mut TaskHealth),
+) {
+    if let Ok(mut tasks) = tasks.write() {
+        let health = tasks.entry(name.to_string()).or_insert_with(|| TaskHealth {
+            name: name.to_string(),
+            state: TaskState::Running,
+            restarts: 0,
+            last_failure: None,
+            last_failure_at: None,
+        });
+        apply(health);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shutdown::ShutdownConfig;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn supervisor() -> (TaskSupervisor, Arc<ShutdownCoordinator>) {
+        let coordinator = Arc::new(ShutdownCoordinator::new(ShutdownConfig::default()));
+        let config = SupervisorConfig {
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(50),
+            healthy_run: Duration::from_secs(60),
+        };
+        (
+            TaskSupervisor::new(config, Arc::clone(&coordinator)),
+            coordinator,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_panicking_task_is_restarted() {
+        let (supervisor, coordinator) = supervisor();
+        let starts = Arc::new(AtomicU32::new(0));
+        let starts_clone = Arc::clone(&starts);
+
+        let handle = supervisor.spawn("flaky", move |mut shutdown_rx| {
+            let starts = Arc::clone(&starts_clone);
+            async move {
+                if starts.fetch_add(1, Ordering::SeqCst) == 0 {
+                    panic!("first run fails");
+                }
+                let _ = shutdown_rx.recv().await;
+            }
+        });
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(starts.load(Ordering::SeqCst), 2);
+        let health = supervisor.task_health();
+        assert_eq!(health[0].restarts, 1);
+        assert_eq!(health[0].last_failure.as_deref(), Some("task panicked"));
+        assert_eq!(health[0].state, TaskState::Running);
+        assert!(supervisor.is_ready());
+
+        coordinator.trigger_shutdown();
+        handle.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_backoff_resets_after_a_healthy_run() {
+        let coordinator = Arc::new(ShutdownCoordinator::new(ShutdownConfig::default()));
+        let config = SupervisorConfig {
+            initial_backoff: Duration::from_millis(20),
+            max_backoff: Duration::from_secs(1),
+            healthy_run: Duration::from_millis(100),
+        };
+        let supervisor = TaskSupervisor::new(config, Arc::clone(&coordinator));
+        let starts = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let starts_clone = Arc::clone(&starts);
+
+        let handle = supervisor.spawn("recovering", move |mut shutdown_rx| {
+            let starts = Arc::clone(&starts_clone);
+            async move {
+                let run = {
+                    let mut starts = starts.lock().unwrap();
+                    starts.push(Instant::now());
+                    starts.len()
+                };
+                match run {
+                    // Three quick failures back off 20ms, 40ms, then 80ms
+                    1..=3 => panic!("quick failure"),
+                    // A long run fails after backing off to 160ms
+                    4 => {
+                        tokio::time::sleep(Duration::from_millis(150)).await;
+                        panic!("failure after a healthy run");
+                    }
+                    _ => {
+                        let _ = shutdown_rx.recv().await;
+                    }
+                }
+            }
+        });
+
+        tokio::time::sleep(Duration::from_millis(600)).await;
+        coordinator.trigger_shutdown();
+        handle.await.unwrap();
+
+        let starts = starts.lock().unwrap();
+        assert_eq!(starts.len(), 5);
+        // 150ms run plus the initial 20ms backoff rather than 160ms
+        let restart_gap = starts[4] - starts[3];
+        assert!(
+            restart_gap < Duration::from_millis(250),
+            "restart took {:?}",
+            restart_gap
+        );
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_stops_supervised_task() {
+        let (supervisor, coordinator) = supervisor();
+        let starts = Arc::new(AtomicU32::new(0));
+        let starts_clone = Arc::clone(&starts);
+
+        let handle = supervisor.spawn("loop", move |mut shutdown_rx| {
+            let starts = Arc::clone(&starts_clone);
+            async move {
+                starts.fetch_add(1, Ordering::SeqCst);
+                let _ = shutdown_rx.recv().await;
+            }
+        });
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        coordinator.trigger_shutdown();
+
+        tokio::time::timeout(Duration::from_secs(1), handle)
+            .await
+            .expect("supervisor should stop on shutdown")
+            .unwrap();
+
+        assert_eq!(starts.load(Ordering::SeqCst), 1);
+        assert_eq!(supervisor.task_health()[0].state, TaskState::Stopped);
+        assert!(!supervisor.is_ready());
+    }
+}
@@ -4,10 +4,12 @@ use std::time::Duration;
 use tokio::sync::broadcast;
 
 use crate::alerts::{Alert, AlertManager};
+use crate::api::corridors_cached::{CorridorPaymentFetchLimit, HealthScoreWeights};
 use crate::cache::CacheManager;
 use crate::database::Database;
 use crate::rpc::StellarRpcClient;
-use crate::telegram::client::{BotCommand, TelegramClient};
+use crate::services::price_feed::PriceFeedClient;
+use crate::telegram::client::{BotCommand, TelegramClient, Update};
 use crate::telegram::commands::CommandHandler;
 use crate::telegram::formatter;
 use crate::telegram::subscription::SubscriptionService;
@@ -25,6 +27,9 @@ impl TelegramBot {
         db: Arc<Database>,
         cache: Arc<CacheManager>,
         rpc_client: Arc<StellarRpcClient>,
+        price_feed: Arc<PriceFeedClient>,
+        weights: HealthScoreWeights,
+        fetch_limit: CorridorPaymentFetchLimit,
         subscriptions: Arc<SubscriptionService>,
         alert_manager: &AlertManager,
     ) -> Self {
@@ -33,6 +38,9 @@ impl TelegramBot {
             db,
             cache,
             rpc_client,
+            price_feed,
+            weights,
+            fetch_limit,
             Arc::clone(&subscriptions),
         ));
         let alert_rx = alert_manager.subscribe();
@@ -110,6 +118,26 @@ fn parse_command(text: &str) -> Option<(&str, &str)> {
     Some((command, args))
 }
 
+/// Run the command in an incoming update, returning the chat to reply to and
+/// the reply. `None` when the update carries no command.
+pub async fn reply_to_update(handler: &CommandHandler, update: &Update) -> Option<(i64, String)> {
+    let message = update.message.as_ref()?;
+    let (command, args) = parse_command(message.text.as_deref()?)?;
+    let username = message.from.as_ref().and_then(|u| u.username.as_deref());
+
+    let response = handler
+        .handle_command(
+            command,
+            args,
+            message.chat.id,
+            &message.chat.chat_type,
+            message.chat.title.as_deref(),
+            username,
+        )
+        .await;
+    Some((message.chat.id, response))
+}
+
 async fn polling_loop(
     client: Arc<TelegramClient>,
     handler: Arc<CommandHandler>,
@@ -128,36 +156,13 @@ async fn polling_loop(
                             // Always advance offset
                             offset = Some(update.update_id + 1);
 
-                            if let Some(message) = &update.message {
-                                if let Some(text) = &message.text {
-                                    if let Some((command, args)) = parse_command(text) {
-                                        let chat_id = message.chat.id;
-                                        let chat_type = &message.chat.chat_type;
-                                        let chat_title = message.chat.title.as_deref();
-                                        let username = message
-                                            .from
-                                            .as_ref()
-                                            .and_then(|u| u.username.as_deref());
-
-                                        let response = handler
-                                            .handle_command(
-                                                command,
-                                                args,
-                                                chat_id,
-                                                chat_type,
-                                                chat_title,
-                                                username,
-                                            )
-                                            .await;
-
-                                        if let Err(e) = client.send_message(chat_id, &response).await {
-                                            tracing::error!(
-                                                "Failed to send Telegram message to {}: {}",
-                                                chat_id,
-                                                e
-                                            );
-                                        }
-                                    }
+                            if let Some((chat_id, response)) = reply_to_update(&handler, &update).await {
+                                if let Err(e) = client.send_message(chat_id, &response).await {
+                                    tracing::error!(
+                                        "Failed to send Telegram message to {}: {}",
+                                        chat_id,
+                                        e
+                                    );
                                 }
                             }
                         }
@@ -253,7 +258,7 @@ async fn register_commands(client: &TelegramClient) -> anyhow::Result<()> {
         },
         BotCommand {
             command: "anchors".to_string(),
-            description: "List anchors with reliability".to_string(),
+            description: "Top 5 anchors by reliability".to_string(),
         },
         BotCommand {
             command: "anchor".to_string(),
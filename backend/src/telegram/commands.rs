@@ -1,15 +1,28 @@
 use std::sync::Arc;
 
+use crate::api::corridors_cached::{
+    find_recent_corridor, validate_corridor_key, CorridorPaymentFetchLimit, HealthScoreWeights,
+};
 use crate::cache::CacheManager;
 use crate::database::Database;
 use crate::rpc::StellarRpcClient;
+use crate::services::price_feed::PriceFeedClient;
 use crate::telegram::formatter;
 use crate::telegram::subscription::SubscriptionService;
 
+/// Number of anchors listed by /anchors
+const TOP_ANCHORS: i64 = 5;
+
+const CORRIDOR_USAGE: &str = "Usage: /corridor <SOURCE_CODE:ISSUER->DEST_CODE:ISSUER>\n\
+     Example: /corridor USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN->XLM:native";
+
 pub struct CommandHandler {
     db: Arc<Database>,
     cache: Arc<CacheManager>,
     rpc_client: Arc<StellarRpcClient>,
+    price_feed: Arc<PriceFeedClient>,
+    weights: HealthScoreWeights,
+    fetch_limit: CorridorPaymentFetchLimit,
     subscriptions: Arc<SubscriptionService>,
 }
 
@@ -18,12 +31,18 @@ impl CommandHandler {
         db: Arc<Database>,
         cache: Arc<CacheManager>,
         rpc_client: Arc<StellarRpcClient>,
+        price_feed: Arc<PriceFeedClient>,
+        weights: HealthScoreWeights,
+        fetch_limit: CorridorPaymentFetchLimit,
         subscriptions: Arc<SubscriptionService>,
     ) -> Self {
         Self {
             db,
             cache,
             rpc_client,
+            price_feed,
+            weights,
+            fetch_limit,
             subscriptions,
         }
     }
@@ -119,55 +138,51 @@ impl CommandHandler {
         formatter::format_corridor_list(&corridors)
     }
 
+    /// Summarize a corridor's current metrics, computed as the corridor
+    /// detail endpoint does from recently ingested payments
     async fn handle_corridor_detail(&self, args: &str) -> String {
         let key = args.trim();
         if key.is_empty() {
-            return formatter::escape_markdown(
-                "Usage: /corridor <corridor_key>\nExample: /corridor USDC:GA5Z->XLM:native",
-            );
+            return formatter::escape_markdown(CORRIDOR_USAGE);
+        }
+        if let Err((_, message)) = validate_corridor_key(key) {
+            return formatter::escape_markdown(&format!("{}.\n{}", message, CORRIDOR_USAGE));
         }
 
-        let payments = match self.rpc_client.fetch_payments(200, None).await {
-            Ok(p) => p,
-            Err(e) => {
-                return formatter::escape_markdown(&format!(
-                    "Failed to fetch corridor data: {}",
-                    e
-                ));
+        let corridor = match find_recent_corridor(
+            &self.db,
+            &self.price_feed,
+            &self.weights,
+            self.fetch_limit,
+            key,
+        )
+        .await
+        {
+            Ok(Some(corridor)) => corridor,
+            Ok(None) => {
+                return formatter::escape_markdown(&format!("Corridor '{}' not found.", key));
             }
-        };
-
-        let mut count: i64 = 0;
-        let mut volume: f64 = 0.0;
-
-        for payment in &payments {
-            let corridor_key = format!(
-                "{}:{}->XLM:native",
-                payment.get_asset_code().as_deref().unwrap_or("XLM"),
-                payment.get_asset_issuer().as_deref().unwrap_or("native")
-            );
-            if corridor_key == key {
-                count += 1;
-                volume += payment.get_amount().parse::<f64>().unwrap_or(0.0);
+            Err(e) => {
+                tracing::warn!("Failed to compute corridor {} for Telegram: {:?}", key, e);
+                return formatter::escape_markdown("Failed to fetch corridor data.");
             }
-        }
-
-        if count == 0 {
-            return formatter::escape_markdown(&format!("Corridor '{}' not found.", key));
-        }
-
-        let parts: Vec<&str> = key.split("->").collect();
-        let (src, dst) = if parts.len() == 2 {
-            (parts[0], parts[1])
-        } else {
-            (key, "unknown")
         };
 
-        formatter::format_corridor_detail(key, src, dst, 100.0, count, 400.0, volume, 95.0)
+        formatter::format_corridor_detail(
+            &corridor.id,
+            &corridor.source_asset,
+            &corridor.destination_asset,
+            corridor.success_rate,
+            corridor.total_attempts,
+            corridor.average_latency_ms,
+            corridor.liquidity_depth_usd,
+            corridor.health_score,
+        )
     }
 
+    /// List the most reliable anchors, best first
     async fn handle_anchors(&self) -> String {
-        let anchors = match self.db.list_anchors(50, 0).await {
+        let anchors = match self.db.list_anchors(TOP_ANCHORS, 0).await {
             Ok(a) => a,
             Err(e) => {
                 return formatter::escape_markdown(&format!("Failed to fetch anchors: {}", e));
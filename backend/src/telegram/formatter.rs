@@ -20,12 +20,14 @@ pub fn format_alert(alert: &Alert) -> String {
         AlertType::SuccessRateDrop => "\u{1F534}",   // red circle
         AlertType::LatencyIncrease => "\u{1F7E1}",   // yellow circle
         AlertType::LiquidityDecrease => "\u{1F7E0}", // orange circle
+        AlertType::HealthScoreDegradation => "\u{1F534}", // red circle
     };
 
     let type_label = match alert.alert_type {
         AlertType::SuccessRateDrop => "Success Rate Drop",
         AlertType::LatencyIncrease => "Latency Increase",
         AlertType::LiquidityDecrease => "Liquidity Decrease",
+        AlertType::HealthScoreDegradation => "Health Score Degradation",
     };
 
     let corridor = escape_markdown(&alert.corridor_id);
@@ -196,7 +198,7 @@ pub fn format_help() -> String {
         ("/status", "System health summary"),
         ("/corridors", "Top corridors with metrics"),
         ("/corridor <key>", "Detailed corridor info"),
-        ("/anchors", "List anchors with reliability"),
+        ("/anchors", "Top 5 anchors by reliability"),
         ("/anchor <id>", "Detailed anchor info"),
         ("/subscribe", "Subscribe to alerts"),
         ("/unsubscribe", "Unsubscribe from alerts"),
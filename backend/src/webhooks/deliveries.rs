@@ -0,0 +1,281 @@
+/// Dead-letter queue for webhook deliveries
+/// Tracks deliveries that failed their first attempt, schedules retries with
+/// exponential backoff, and marks them dead once attempts run out
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use super::WebhookService;
+
+pub const DELIVERY_RETRYING: &str = "retrying";
+pub const DELIVERY_DELIVERED: &str = "delivered";
+pub const DELIVERY_DEAD: &str = "dead";
+
+/// Backoff schedule for failed webhook deliveries
+#[derive(Debug, Clone)]
+pub struct WebhookRetryPolicy {
+    /// Attempts, including the first, before a delivery is dead-lettered
+    pub max_attempts: u32,
+    /// Delay after the first failure; doubled for each further failure
+    pub base_delay: Duration,
+    /// Upper bound on the delay between attempts
+    pub max_delay: Duration,
+}
+
+impl Default for WebhookRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            base_delay: Duration::seconds(30),
+            max_delay: Duration::hours(1),
+        }
+    }
+}
+
+impl WebhookRetryPolicy {
+    /// Policy from WEBHOOK_MAX_ATTEMPTS, WEBHOOK_RETRY_BASE_SECS and
+    /// WEBHOOK_RETRY_MAX_SECS, falling back to the defaults
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let secs = |name: &str| {
+            std::env::var(name)
+                .ok()
+                .and_then(|s| s.parse::<i64>().ok())
+                .filter(|secs| *secs > 0)
+                .map(Duration::seconds)
+        };
+        Self {
+            max_attempts: std::env::var("WEBHOOK_MAX_ATTEMPTS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .filter(|attempts| *attempts > 0)
+                .unwrap_or(defaults.max_attempts),
+            base_delay: secs("WEBHOOK_RETRY_BASE_SECS").unwrap_or(defaults.base_delay),
+            max_delay: secs("WEBHOOK_RETRY_MAX_SECS").unwrap_or(defaults.max_delay),
+        }
+    }
+
+    /// Delay before the next attempt once `attempts` have failed, capped at
+    /// `max_delay` even when doubling would overflow
+    pub fn delay_after(&self, attempts: u32) -> Duration {
+        let factor = 2i32.saturating_pow(attempts.saturating_sub(1));
+        self.base_delay
+            .checked_mul(factor)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+
+    /// Whether a delivery with `attempts` failures should be dead-lettered
+    pub fn exhausted(&self, attempts: u32) -> bool {
+        attempts >= self.max_attempts
+    }
+}
+
+/// A webhook delivery awaiting retry or dead-lettered
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct WebhookDelivery {
+    pub id: String,
+    pub event_id: String,
+    pub webhook_id: String,
+    pub event_type: String,
+    pub payload: String,
+    pub status: String,
+    pub attempts: i64,
+    pub next_retry_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WebhookService {
+    /// Queue an event whose first delivery attempt failed, scheduling its
+    /// retry, or dead-letter it straight away if the policy allows a single
+    /// attempt
+    pub async fn record_failed_delivery(
+        &self,
+        event_id: &str,
+        error: &str,
+        policy: &WebhookRetryPolicy,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<WebhookDelivery> {
+        let status = if policy.exhausted(1) {
+            DELIVERY_DEAD
+        } else {
+            DELIVERY_RETRYING
+        };
+
+        let delivery = sqlx::query_as::<_, WebhookDelivery>(
+            "INSERT INTO webhook_deliveries (
+                id, event_id, webhook_id, event_type, payload, status, attempts,
+                next_retry_at, last_error, created_at, updated_at
+             )
+             SELECT ?, id, webhook_id, event_type, payload, ?, 1, ?, ?, ?, ?
+             FROM webhook_events WHERE id = ?
+             RETURNING *",
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(status)
+        .bind(now + policy.delay_after(1))
+        .bind(error)
+        .bind(now)
+        .bind(now)
+        .bind(event_id)
+        .fetch_one(&self.db)
+        .await?;
+
+        Ok(delivery)
+    }
+
+    /// Deliveries whose next retry is due at `now`, oldest first
+    pub async fn due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> anyhow::Result<Vec<WebhookDelivery>> {
+        let deliveries = sqlx::query_as::<_, WebhookDelivery>(
+            "SELECT * FROM webhook_deliveries
+             WHERE status = ? AND next_retry_at <= ?
+             ORDER BY next_retry_at ASC
+             LIMIT ?",
+        )
+        .bind(DELIVERY_RETRYING)
+        .bind(now)
+        .bind(limit as i64)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(deliveries)
+    }
+
+    /// Record a successful retry
+    pub async fn mark_delivery_delivered(
+        &self,
+        delivery_id: &str,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            "UPDATE webhook_deliveries
+             SET status = ?, attempts = attempts + 1, last_error = NULL, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(DELIVERY_DELIVERED)
+        .bind(now)
+        .bind(delivery_id)
+        .execute(&self.db)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Record a failed retry, rescheduling it or dead-lettering it once the
+    /// policy's attempts are used up. Returns the delivery's new status.
+    pub async fn record_delivery_retry_failure(
+        &self,
+        delivery: &WebhookDelivery,
+        error: &str,
+        policy: &WebhookRetryPolicy,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<&'static str> {
+        let attempts = delivery.attempts.max(0) as u32 + 1;
+        let status = if policy.exhausted(attempts) {
+            DELIVERY_DEAD
+        } else {
+            DELIVERY_RETRYING
+        };
+
+        sqlx::query(
+            "UPDATE webhook_deliveries
+             SET status = ?, attempts = ?, next_retry_at = ?, last_error = ?, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(status)
+        .bind(attempts as i64)
+        .bind(now + policy.delay_after(attempts))
+        .bind(error)
+        .bind(now)
+        .bind(&delivery.id)
+        .execute(&self.db)
+        .await?;
+
+        Ok(status)
+    }
+
+    /// Dead-lettered deliveries for the user's webhooks, newest first
+    pub async fn list_dead_deliveries(
+        &self,
+        user_id: &str,
+    ) -> anyhow::Result<Vec<WebhookDelivery>> {
+        let deliveries = sqlx::query_as::<_, WebhookDelivery>(
+            "SELECT d.* FROM webhook_deliveries d
+             JOIN webhooks w ON w.id = d.webhook_id
+             WHERE d.status = ? AND w.user_id = ?
+             ORDER BY d.updated_at DESC",
+        )
+        .bind(DELIVERY_DEAD)
+        .bind(user_id)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(deliveries)
+    }
+
+    /// Put a dead-lettered delivery back on the retry queue with a fresh
+    /// attempt budget. Returns false if the user has no such dead delivery.
+    pub async fn requeue_dead_delivery(
+        &self,
+        delivery_id: &str,
+        user_id: &str,
+    ) -> anyhow::Result<bool> {
+        let now = Utc::now();
+        let result = sqlx::query(
+            "UPDATE webhook_deliveries
+             SET status = ?, attempts = 0, next_retry_at = ?, updated_at = ?
+             WHERE id = ? AND status = ?
+               AND webhook_id IN (SELECT id FROM webhooks WHERE user_id = ?)",
+        )
+        .bind(DELIVERY_RETRYING)
+        .bind(now)
+        .bind(now)
+        .bind(delivery_id)
+        .bind(DELIVERY_DEAD)
+        .bind(user_id)
+        .execute(&self.db)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_up_to_the_cap() {
+        let policy = WebhookRetryPolicy {
+            max_attempts: 10,
+            base_delay: Duration::seconds(30),
+            max_delay: Duration::seconds(200),
+        };
+
+        assert_eq!(policy.delay_after(1), Duration::seconds(30));
+        assert_eq!(policy.delay_after(2), Duration::seconds(60));
+        assert_eq!(policy.delay_after(3), Duration::seconds(120));
+        assert_eq!(policy.delay_after(4), Duration::seconds(200));
+        assert_eq!(policy.delay_after(40), Duration::seconds(200));
+        assert!(!policy.exhausted(9));
+        assert!(policy.exhausted(10));
+    }
+
+    #[test]
+    fn test_backoff_does_not_overflow() {
+        let policy = WebhookRetryPolicy {
+            max_attempts: u32::MAX,
+            base_delay: Duration::days(365 * 100),
+            max_delay: Duration::days(365 * 200),
+        };
+
+        assert_eq!(policy.delay_after(1), Duration::days(365 * 100));
+        assert_eq!(policy.delay_after(100_000), Duration::days(365 * 200));
+        assert_eq!(policy.delay_after(u32::MAX), Duration::days(365 * 200));
+    }
+}
@@ -1,5 +1,6 @@
 /// Webhooks module for Zapier integration
 /// Manages webhook registrations, event definitions, and dispatching
+pub mod deliveries;
 pub mod events;
 
 use hmac::{Hmac, Mac};
@@ -27,6 +28,45 @@ impl WebhookSignature {
         let expected = Self::sign(payload, secret);
         signature == expected
     }
+
+    /// Canonical HMAC input for a timestamped delivery:
+    ///
+    /// `{timestamp}.{nonce length in bytes}.{nonce}.{payload}`
+    ///
+    /// The nonce is length-prefixed so a `.` inside it cannot shift bytes
+    /// between the nonce and the payload and yield the same MAC input.
+    pub fn timestamped_message(payload: &str, timestamp: i64, nonce: &str) -> String {
+        format!("{}.{}.{}.{}", timestamp, nonce.len(), nonce, payload)
+    }
+
+    /// Sign the canonical `timestamped_message` so the timestamp and nonce
+    /// cannot be swapped without invalidating the signature
+    pub fn sign_timestamped(payload: &str, timestamp: i64, nonce: &str, secret: &str) -> String {
+        Self::sign(
+            &Self::timestamped_message(payload, timestamp, nonce),
+            secret,
+        )
+    }
+
+    /// Verify a signature from `sign_timestamped` in constant time
+    pub fn verify_timestamped(
+        payload: &str,
+        timestamp: i64,
+        nonce: &str,
+        secret: &str,
+        signature: &str,
+    ) -> bool {
+        let Some(provided) = signature
+            .strip_prefix("sha256=")
+            .and_then(|hex_sig| hex::decode(hex_sig).ok())
+        else {
+            return false;
+        };
+        let mut mac =
+            HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
+        mac.update(Self::timestamped_message(payload, timestamp, nonce).as_bytes());
+        mac.verify_slice(&provided).is_ok()
+    }
 }
 
 /// Webhook Configuration
@@ -311,6 +351,32 @@ mod tests {
         assert!(WebhookSignature::verify(payload, secret, &signature));
     }
 
+    #[test]
+    fn test_timestamped_signature_binds_nonce_boundary() {
+        let secret = "my-secret";
+        let signature = WebhookSignature::sign_timestamped("c.{}", 1_700_000_000, "a.b", secret);
+
+        assert!(WebhookSignature::verify_timestamped(
+            "c.{}",
+            1_700_000_000,
+            "a.b",
+            secret,
+            &signature
+        ));
+        // Moving the dot-separated part of the nonce into the payload must fail
+        assert!(!WebhookSignature::verify_timestamped(
+            "b.c.{}",
+            1_700_000_000,
+            "a",
+            secret,
+            &signature
+        ));
+        assert_eq!(
+            WebhookSignature::timestamped_message("{}", 5, "n.1"),
+            "5.3.n.1.{}"
+        );
+    }
+
     #[test]
     fn test_event_type_conversion() {
         let event = WebhookEventType::CorridorHealthDegraded;
@@ -0,0 +1,140 @@
+use anyhow::Result;
+use axum::{body::Body, http::Request, http::StatusCode};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::api::accounts::{
+    distinct_payment_assets, routes, AccountSummary, AccountSummaryState,
+};
+use stellar_insights_backend::cache::{CacheConfig, CacheManager};
+use stellar_insights_backend::cache_memory::MemoryCache;
+use stellar_insights_backend::rpc::StellarRpcClient;
+use stellar_insights_backend::services::price_feed::{
+    PriceFeedClient, PriceFeedConfig, PriceFeedProvider,
+};
+
+const ACCOUNT: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+
+/// Prices every asset at $1
+struct UnitPriceProvider;
+
+#[async_trait::async_trait]
+impl PriceFeedProvider for UnitPriceProvider {
+    async fn fetch_price(&self, _asset_id: &str) -> Result<f64> {
+        Ok(1.0)
+    }
+
+    async fn fetch_prices(&self, asset_ids: &[String]) -> Result<HashMap<String, f64>> {
+        Ok(asset_ids.iter().map(|id| (id.clone(), 1.0)).collect())
+    }
+
+    fn name(&self) -> &str {
+        "Unit"
+    }
+}
+
+#[tokio::test]
+async fn test_account_summary_populates_in_mock_mode() {
+    let rpc_client = Arc::new(StellarRpcClient::new_with_defaults(true));
+
+    // Map every asset in the mock activity to a provider id of the same name
+    let payments = rpc_client
+        .fetch_account_payments(ACCOUNT, 200)
+        .await
+        .unwrap();
+    let mapping = distinct_payment_assets(&payments)
+        .into_iter()
+        .map(|asset| (asset.clone(), asset))
+        .collect();
+    let price_feed = Arc::new(
+        PriceFeedClient::new(PriceFeedConfig::default(), mapping)
+            .with_provider(Arc::new(UnitPriceProvider)),
+    );
+    let cache = Arc::new(
+        CacheManager::with_redis_url(
+            CacheConfig::default(),
+            "redis://127.0.0.1:1",
+            MemoryCache::new(100),
+            Duration::from_secs(60),
+        )
+        .await,
+    );
+
+    let app = routes(AccountSummaryState {
+        rpc_client,
+        cache,
+        price_feed,
+    });
+    let response = app
+        .oneshot(
+            Request::get(format!("/api/accounts/{}/summary", ACCOUNT))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let summary: AccountSummary = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(summary.account_id, ACCOUNT);
+    assert_eq!(summary.payment_count, payments.len());
+    assert!(summary.trade_count > 0);
+    assert!(summary.sent_volume_usd > 0.0);
+    assert!(summary.received_volume_usd > 0.0);
+    assert!(!summary.corridors.is_empty());
+    assert!(summary.corridors.contains(
+        &"USDC:GBXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX->XLM:native".to_string()
+    ));
+    let first = summary.first_activity.unwrap();
+    let last = summary.last_activity.unwrap();
+    assert!(first < last);
+}
+
+#[tokio::test]
+async fn test_account_summary_rejects_invalid_account_id() {
+    let cache = Arc::new(
+        CacheManager::with_redis_url(
+            CacheConfig::default(),
+            "redis://127.0.0.1:1",
+            MemoryCache::new(100),
+            Duration::from_secs(60),
+        )
+        .await,
+    );
+    let app = routes(AccountSummaryState {
+        rpc_client: Arc::new(StellarRpcClient::new_with_defaults(true)),
+        cache,
+        price_feed: Arc::new(PriceFeedClient::new(
+            PriceFeedConfig::default(),
+            HashMap::new(),
+        )),
+    });
+
+    // Bad checksum, secret-key prefix and arbitrary text
+    for account_id in [
+        "GCKFBEIYTKP5RDBQMTVVALONAOPBXICILMAFKKKZFQRBZ5GJ2HBL3VCT",
+        "SBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5",
+        "not-an-account",
+    ] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::get(format!("/api/accounts/{}/summary", account_id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", account_id);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["error"]["code"], "INVALID_ACCOUNT");
+    }
+}
@@ -0,0 +1,212 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    middleware, Router,
+};
+use serde_json::{json, Value};
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use stellar_insights_backend::admin_audit_log::admin_audit_middleware;
+use stellar_insights_backend::auth::{AuthService, User};
+use stellar_insights_backend::auth_middleware::{auth_middleware, JwtSecret};
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::handlers::create_anchor;
+use stellar_insights_backend::ingestion::DataIngestionService;
+use stellar_insights_backend::rpc::StellarRpcClient;
+use stellar_insights_backend::state::AppState;
+use stellar_insights_backend::websocket::WsState;
+use tokio::sync::RwLock;
+use tower::util::ServiceExt;
+
+const JWT_SECRET: &str = "test_jwt_secret_key_that_is_long_enough_for_tests_32";
+
+async fn setup_test_pool() -> SqlitePool {
+    // One connection so the handler and the assertions share the in-memory database
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    pool
+}
+
+fn create_protected_router(db: Arc<Database>) -> Router {
+    let ws_state = Arc::new(WsState::new());
+    let rpc_client = Arc::new(StellarRpcClient::new_with_defaults(true));
+    let ingestion = Arc::new(DataIngestionService::new(rpc_client, Arc::clone(&db)));
+    let state = AppState {
+        db: Arc::clone(&db),
+        ws_state,
+        ingestion,
+    };
+    Router::new()
+        .route("/api/anchors", axum::routing::post(create_anchor))
+        .with_state(state)
+        .layer(
+            tower::ServiceBuilder::new()
+                .layer(axum::Extension(JwtSecret(Arc::from(JWT_SECRET))))
+                .layer(middleware::from_fn(auth_middleware))
+                .layer(middleware::from_fn_with_state(db, admin_audit_middleware)),
+        )
+}
+
+fn access_token(user_id: &str) -> String {
+    std::env::set_var("JWT_SECRET", JWT_SECRET);
+    let auth_service = AuthService::new(Arc::new(RwLock::new(None)));
+    auth_service
+        .generate_access_token(&User {
+            id: user_id.to_string(),
+            username: "auditor".to_string(),
+        })
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_create_anchor_writes_audit_entry_for_user() {
+    let pool = setup_test_pool().await;
+    let app = create_protected_router(Arc::new(Database::new(pool.clone())));
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/anchors")
+                .header("content-type", "application/json")
+                .header(
+                    "authorization",
+                    format!("Bearer {}", access_token("user-42")),
+                )
+                .body(Body::from(
+                    json!({
+                        "name": "Audited Anchor",
+                        "stellar_account": "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5"
+                    })
+                    .to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let anchor: Value = serde_json::from_slice(&body).unwrap();
+
+    let (action, resource, user_id, status, details): (String, String, String, String, String) =
+        sqlx::query_as("SELECT action, resource, user_id, status, details FROM admin_audit_log")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    let details: Value = serde_json::from_str(&details).unwrap();
+
+    assert_eq!(action, "POST");
+    assert_eq!(resource, "/api/anchors");
+    assert_eq!(user_id, "user-42");
+    assert_eq!(status, "success");
+    assert_eq!(details["target_id"], anchor["id"]);
+    assert_eq!(details["http_status"], 200);
+}
+
+#[tokio::test]
+async fn test_unauthenticated_request_is_not_audited() {
+    let pool = setup_test_pool().await;
+    let app = create_protected_router(Arc::new(Database::new(pool.clone())));
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/anchors")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({ "name": "Anchor", "stellar_account": "GANCHOR" }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM admin_audit_log")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+}
+
+#[tokio::test]
+async fn test_concurrent_appends_form_a_single_chain() {
+    use stellar_insights_backend::admin_audit_log::AdminAuditLogger;
+
+    // A file database so appends run on separate connections
+    let dir = tempfile::tempdir().unwrap();
+    let pool = SqlitePoolOptions::new()
+        .max_connections(5)
+        .connect(&format!(
+            "sqlite://{}?mode=rwc",
+            dir.path().join("audit.db").display()
+        ))
+        .await
+        .unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    let logger = Arc::new(AdminAuditLogger::new(pool.clone()));
+
+    let appends: Vec<_> = (0..10)
+        .map(|i| {
+            let logger = Arc::clone(&logger);
+            tokio::spawn(async move {
+                logger
+                    .append_action(
+                        "POST",
+                        "/api/anchors",
+                        &format!("user-{}", i),
+                        "success",
+                        json!({}),
+                    )
+                    .await
+                    .unwrap();
+            })
+        })
+        .collect();
+    for append in appends {
+        append.await.unwrap();
+    }
+
+    let rows: Vec<(
+        String,
+        chrono::DateTime<chrono::Utc>,
+        String,
+        String,
+        String,
+        String,
+        String,
+        String,
+    )> = sqlx::query_as(
+        "SELECT id, timestamp, action, resource, user_id, status, details, hash \
+             FROM admin_audit_log ORDER BY timestamp ASC",
+    )
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(rows.len(), 10);
+
+    // Each entry is chained to the one before it
+    let mut prev_hash: Option<String> = None;
+    for (id, timestamp, action, resource, user_id, status, details, hash) in rows {
+        let details: Value = serde_json::from_str(&details).unwrap();
+        let data = format!(
+            "{}|{}|{}|{}|{}|{}|{}",
+            id, timestamp, action, resource, user_id, status, details
+        );
+        let hash_input = match &prev_hash {
+            Some(h) => format!("{}|{}", h, data),
+            None => data,
+        };
+        assert_eq!(hash, format!("{:x}", md5::compute(hash_input)));
+        prev_hash = Some(hash);
+    }
+}
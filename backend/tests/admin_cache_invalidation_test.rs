@@ -0,0 +1,115 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::Duration;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::api::admin_cache;
+use stellar_insights_backend::cache::{keys, CacheConfig, CacheManager};
+use stellar_insights_backend::cache_invalidation::CacheInvalidationService;
+use stellar_insights_backend::cache_memory::MemoryCache;
+
+/// Seeds two corridor keys, two anchor keys and the dashboard key
+async fn setup() -> (Router, Arc<CacheManager>) {
+    let cache = Arc::new(
+        CacheManager::with_redis_url(
+            CacheConfig::default(),
+            "redis://127.0.0.1:1",
+            MemoryCache::new(100),
+            Duration::from_secs(60),
+        )
+        .await,
+    );
+    for key in [
+        keys::corridor_detail("USDC->XLM"),
+        keys::corridor_list(50, 0, "none"),
+        keys::anchor_detail("anchor-1"),
+        keys::anchor_list(50, 0),
+        keys::dashboard_stats(),
+    ] {
+        cache.set(&key, &"cached", 600).await.unwrap();
+    }
+
+    let invalidation = Arc::new(CacheInvalidationService::new(Arc::clone(&cache)));
+    (admin_cache::routes(invalidation), cache)
+}
+
+async fn invalidate(app: Router, body: Value) -> (StatusCode, Value) {
+    let response = app
+        .oneshot(
+            Request::post("/api/admin/cache/invalidate")
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+async fn is_cached(cache: &CacheManager, key: &str) -> bool {
+    cache.get::<String>(key).await.unwrap().is_some()
+}
+
+#[tokio::test]
+async fn test_invalidate_by_tag_removes_the_cache_family() {
+    let (app, cache) = setup().await;
+
+    let (status, body) = invalidate(app, json!({ "tag": "corridor" })).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["pattern"], "corridor:*");
+    assert_eq!(body["removed"], 2);
+    assert!(!is_cached(&cache, &keys::corridor_detail("USDC->XLM")).await);
+    assert!(is_cached(&cache, &keys::anchor_detail("anchor-1")).await);
+    assert!(is_cached(&cache, &keys::dashboard_stats()).await);
+}
+
+#[tokio::test]
+async fn test_invalidate_specific_key() {
+    let (app, cache) = setup().await;
+    let key = keys::anchor_detail("anchor-1");
+
+    let (status, body) = invalidate(app, json!({ "pattern": key })).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["removed"], 1);
+    assert!(!is_cached(&cache, &key).await);
+    assert!(is_cached(&cache, &keys::anchor_list(50, 0)).await);
+}
+
+#[tokio::test]
+async fn test_invalid_targets_are_rejected() {
+    let (app, _cache) = setup().await;
+
+    let (status, body) = invalidate(app.clone(), json!({ "tag": "everything" })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["code"], "UNKNOWN_CACHE_TAG");
+
+    let (status, _) = invalidate(app.clone(), json!({ "pattern": "*" })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = invalidate(app, json!({ "tag": "anchor", "pattern": "anchor:*" })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_patterns_outside_cache_families_are_rejected() {
+    let (app, cache) = setup().await;
+    cache.set("webhook:nonce:abc", &"seen", 600).await.unwrap();
+
+    for pattern in ["webhook:nonce:*", "ratelimit:*", "last_good:*", "anchor*"] {
+        let (status, body) = invalidate(app.clone(), json!({ "pattern": pattern })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{}", pattern);
+        assert_eq!(body["error"]["code"], "INVALID_CACHE_PATTERN");
+    }
+    assert!(is_cached(&cache, "webhook:nonce:abc").await);
+}
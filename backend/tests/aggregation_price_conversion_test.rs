@@ -0,0 +1,125 @@
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::models::PaymentRecord;
+use stellar_insights_backend::services::aggregation::{AggregationConfig, AggregationService};
+use stellar_insights_backend::services::price_feed::{
+    PriceFeedClient, PriceFeedConfig, PriceFeedProvider,
+};
+
+// Not in the price feed mapping, so it never gets a price
+const EURC_ISSUER: &str = "GDHU6WRG4IEQXM5NZ4BMPKOXHW76MZM4Y2IEMFDVXBSDP6SJY4ITNPP2";
+
+/// Prices lumens at $0.10 and counts batch requests
+#[derive(Default)]
+struct FixedPriceProvider {
+    batches: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl PriceFeedProvider for FixedPriceProvider {
+    async fn fetch_price(&self, _asset_id: &str) -> Result<f64> {
+        anyhow::bail!("aggregation should fetch prices in one batch")
+    }
+
+    async fn fetch_prices(&self, asset_ids: &[String]) -> Result<HashMap<String, f64>> {
+        self.batches.fetch_add(1, Ordering::SeqCst);
+        Ok(asset_ids
+            .iter()
+            .filter(|id| *id == "stellar")
+            .map(|id| (id.clone(), 0.1))
+            .collect())
+    }
+
+    fn name(&self) -> &str {
+        "Fixed"
+    }
+}
+
+fn payment(asset_code: Option<&str>, asset_issuer: Option<&str>, amount: f64) -> PaymentRecord {
+    let id = uuid::Uuid::new_v4().to_string();
+    let created_at = Utc::now() - Duration::minutes(5);
+    PaymentRecord {
+        transaction_hash: format!("hash_{}", id),
+        id,
+        source_account: "GSOURCE".to_string(),
+        destination_account: "GDEST".to_string(),
+        asset_type: if asset_code.is_some() {
+            "credit_alphanum4".to_string()
+        } else {
+            "native".to_string()
+        },
+        asset_code: asset_code.map(str::to_string),
+        asset_issuer: asset_issuer.map(str::to_string),
+        operation_type: None,
+        source_asset_type: None,
+        source_asset_code: asset_code.unwrap_or("XLM").to_string(),
+        source_asset_issuer: asset_issuer.unwrap_or("native").to_string(),
+        destination_asset_code: asset_code.unwrap_or("XLM").to_string(),
+        destination_asset_issuer: asset_issuer.unwrap_or("native").to_string(),
+        amount,
+        successful: true,
+        timestamp: Some(created_at),
+        submission_time: None,
+        confirmation_time: None,
+        created_at,
+    }
+}
+
+#[tokio::test]
+async fn test_corridor_without_price_is_flagged() {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    let db = Arc::new(Database::new(pool));
+    db.save_payments(vec![
+        payment(None, None, 100.0),
+        payment(None, None, 50.0),
+        payment(Some("EURC"), Some(EURC_ISSUER), 75.0),
+    ])
+    .await
+    .unwrap();
+
+    let provider = Arc::new(FixedPriceProvider::default());
+    let mapping = HashMap::from([("XLM:native".to_string(), "stellar".to_string())]);
+    let price_feed = Arc::new(
+        PriceFeedClient::new(PriceFeedConfig::default(), mapping).with_provider(provider.clone()),
+    );
+
+    let aggregation = AggregationService::new(
+        Arc::clone(&db),
+        AggregationConfig {
+            interval_hours: 1,
+            lookback_hours: 24 * 365,
+            batch_size: 10_000,
+        },
+    )
+    .with_price_feed(price_feed);
+    aggregation.run_hourly_aggregation().await.unwrap();
+
+    // Both assets are priced with a single request
+    assert_eq!(provider.batches.load(Ordering::SeqCst), 1);
+
+    let metrics = db
+        .fetch_hourly_metrics_by_timerange(Utc::now() - Duration::days(1), Utc::now())
+        .await
+        .unwrap();
+    assert_eq!(metrics.len(), 2);
+
+    let xlm = metrics
+        .iter()
+        .find(|m| m.corridor_key == "XLM:native->XLM:native")
+        .unwrap();
+    assert!(!xlm.has_missing_prices);
+    assert_eq!(xlm.total_transactions, 2);
+    assert!((xlm.volume_usd - 15.0).abs() < 1e-9);
+
+    let eurc = metrics.iter().find(|m| m.asset_a_code == "EURC").unwrap();
+    assert!(eurc.has_missing_prices);
+    assert_eq!(eurc.total_transactions, 1);
+    assert_eq!(eurc.volume_usd, 0.0);
+}
@@ -0,0 +1,138 @@
+use sqlx::sqlite::SqlitePoolOptions;
+use stellar_insights_backend::analytics::ReliabilitySmoothing;
+use stellar_insights_backend::database::{AnchorRpcUpdate, Database};
+use stellar_insights_backend::models::CreateAnchorRequest;
+use uuid::Uuid;
+
+async fn setup_test_db() -> Database {
+    // One connection so the transaction and later reads share the in-memory database
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    Database::new(pool)
+}
+
+async fn create_anchor(db: &Database, name: &str, account: &str) -> Uuid {
+    let anchor = db
+        .create_anchor(CreateAnchorRequest {
+            name: name.to_string(),
+            stellar_account: account.to_string(),
+            home_domain: None,
+        })
+        .await
+        .unwrap();
+    Uuid::parse_str(&anchor.id).unwrap()
+}
+
+fn update(account: &str, total: i64, volume: f64) -> AnchorRpcUpdate {
+    AnchorRpcUpdate {
+        stellar_account: account.to_string(),
+        total_transactions: total,
+        successful_transactions: total,
+        failed_transactions: 0,
+        total_volume_usd: volume,
+        avg_settlement_time_ms: 1000,
+        reliability_score: 100.0,
+    }
+}
+
+#[tokio::test]
+async fn test_update_anchors_from_rpc_updates_all_in_one_batch() {
+    let db = setup_test_db().await;
+    let accounts = ["GANCHORA", "GANCHORB", "GANCHORC"];
+    let mut ids = Vec::new();
+    for (i, account) in accounts.iter().enumerate() {
+        ids.push(create_anchor(&db, &format!("Anchor {}", i), account).await);
+    }
+
+    let updated = db
+        .update_anchors_from_rpc(vec![
+            update(accounts[0], 10, 100.0),
+            update(accounts[1], 20, 200.0),
+            update(accounts[2], 30, 300.0),
+        ])
+        .await
+        .unwrap();
+    assert_eq!(updated, 3);
+
+    for (i, (account, id)) in accounts.iter().zip(&ids).enumerate() {
+        let anchor = db
+            .get_anchor_by_stellar_account(account)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(anchor.total_transactions, 10 * (i as i64 + 1));
+        assert_eq!(anchor.total_volume_usd, 100.0 * (i as f64 + 1.0));
+
+        let history = db.get_anchor_metrics_history(*id, 10).await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].total_transactions, anchor.total_transactions);
+        assert_eq!(history[0].success_rate, 100.0);
+    }
+}
+
+#[tokio::test]
+async fn test_update_anchors_from_rpc_skips_missing_anchors() {
+    let db = setup_test_db().await;
+    let first = create_anchor(&db, "First", "GANCHORA").await;
+    let last = create_anchor(&db, "Last", "GANCHORC").await;
+
+    let updated = db
+        .update_anchors_from_rpc(vec![
+            update("GANCHORA", 10, 100.0),
+            update("GUNKNOWN", 20, 200.0),
+            update("GANCHORC", 30, 300.0),
+        ])
+        .await
+        .unwrap();
+    assert_eq!(updated, 2);
+
+    for (account, id, total) in [("GANCHORA", first, 10), ("GANCHORC", last, 30)] {
+        let anchor = db
+            .get_anchor_by_stellar_account(account)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(anchor.total_transactions, total);
+        assert_eq!(
+            db.get_anchor_metrics_history(id, 10).await.unwrap().len(),
+            1
+        );
+    }
+    assert!(db
+        .get_anchor_by_stellar_account("GUNKNOWN")
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_smoothed_update_keeps_raw_score_and_matching_status() {
+    let db = setup_test_db()
+        .await
+        .with_reliability_smoothing(ReliabilitySmoothing::Ema { alpha: 0.5 });
+    let id = create_anchor(&db, "Anchor", "GANCHORA").await;
+
+    db.update_anchors_from_rpc(vec![update("GANCHORA", 10, 100.0)])
+        .await
+        .unwrap();
+    let mut dip = update("GANCHORA", 10, 100.0);
+    dip.reliability_score = 90.0;
+    db.update_anchors_from_rpc(vec![dip]).await.unwrap();
+
+    let anchor = db
+        .get_anchor_by_stellar_account("GANCHORA")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(anchor.reliability_score, 95.0);
+    // The status follows the stored score; the raw one would be red
+    assert_eq!(anchor.status, "yellow");
+
+    let history = db.get_anchor_metrics_history(id, 1).await.unwrap();
+    assert_eq!(history[0].reliability_score, 95.0);
+    assert_eq!(history[0].raw_reliability_score, Some(90.0));
+}
@@ -0,0 +1,158 @@
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+    routing::post,
+    Router,
+};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::handlers::create_anchor;
+
+mod common;
+
+const ACCOUNT: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+
+/// Both tests toggle ANCHOR_UPSERT_ON_DUPLICATE, so they must not overlap
+static ENV_LOCK: Mutex<()> = Mutex::const_new(());
+
+async fn create_test_app() -> (Router, Arc<Database>) {
+    let state = common::test_app_state().await;
+    let db = Arc::clone(&state.db);
+    let app = Router::new()
+        .route("/api/anchors", post(create_anchor))
+        .with_state(state);
+    (app, db)
+}
+
+async fn post_anchor(app: &Router, body: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/anchors")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+#[tokio::test]
+async fn test_duplicate_anchor_returns_conflict() {
+    let _guard = ENV_LOCK.lock().await;
+    std::env::remove_var("ANCHOR_UPSERT_ON_DUPLICATE");
+    let (app, db) = create_test_app().await;
+
+    let (status, first) = post_anchor(
+        &app,
+        json!({ "name": "First Anchor", "stellar_account": ACCOUNT }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = post_anchor(
+        &app,
+        json!({ "name": "Second Anchor", "stellar_account": ACCOUNT }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["error"]["code"], "ANCHOR_ALREADY_EXISTS");
+    assert_eq!(body["error"]["details"]["existing_anchor_id"], first["id"]);
+
+    let stored = db
+        .get_anchor_by_stellar_account(ACCOUNT)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.name, "First Anchor");
+}
+
+#[tokio::test]
+async fn test_duplicate_anchor_is_upserted_when_enabled() {
+    let _guard = ENV_LOCK.lock().await;
+    std::env::set_var("ANCHOR_UPSERT_ON_DUPLICATE", "true");
+    let (app, db) = create_test_app().await;
+
+    let (status, first) = post_anchor(
+        &app,
+        json!({
+            "name": "First Anchor",
+            "stellar_account": ACCOUNT,
+            "home_domain": "first.example.com"
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, second) = post_anchor(
+        &app,
+        json!({ "name": "Renamed Anchor", "stellar_account": ACCOUNT }),
+    )
+    .await;
+    std::env::remove_var("ANCHOR_UPSERT_ON_DUPLICATE");
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(second["id"], first["id"]);
+    assert_eq!(second["name"], "Renamed Anchor");
+    // Omitted fields keep their stored value
+    assert_eq!(second["home_domain"], "first.example.com");
+
+    let anchors = db.list_anchors(1000, 0).await.unwrap();
+    assert_eq!(
+        anchors
+            .iter()
+            .filter(|a| a.stellar_account == ACCOUNT)
+            .count(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_concurrent_duplicate_creates_never_return_500() {
+    let _guard = ENV_LOCK.lock().await;
+    std::env::remove_var("ANCHOR_UPSERT_ON_DUPLICATE");
+    let (app, db) = create_test_app().await;
+
+    let requests = (0..8).map(|i| {
+        let app = app.clone();
+        async move {
+            post_anchor(
+                &app,
+                json!({ "name": format!("Anchor {}", i), "stellar_account": ACCOUNT }),
+            )
+            .await
+        }
+    });
+    let results = futures::future::join_all(requests).await;
+
+    let created = results
+        .iter()
+        .filter(|(status, _)| *status == StatusCode::OK)
+        .count();
+    assert_eq!(created, 1);
+    for (status, body) in results.iter().filter(|(s, _)| *s != StatusCode::OK) {
+        assert_eq!(*status, StatusCode::CONFLICT);
+        assert_eq!(body["error"]["code"], "ANCHOR_ALREADY_EXISTS");
+    }
+
+    let anchors = db.list_anchors(1000, 0).await.unwrap();
+    assert_eq!(
+        anchors
+            .iter()
+            .filter(|a| a.stellar_account == ACCOUNT)
+            .count(),
+        1
+    );
+}
@@ -0,0 +1,129 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use chrono::{Duration, Utc};
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tower::util::ServiceExt;
+use uuid::Uuid;
+
+use stellar_insights_backend::api::anchor_leaderboard;
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::models::CreateAnchorRequest;
+
+async fn create_anchor(db: &Database, name: &str, account: &str) -> String {
+    db.create_anchor(CreateAnchorRequest {
+        name: name.to_string(),
+        stellar_account: account.to_string(),
+        home_domain: None,
+    })
+    .await
+    .unwrap()
+    .id
+}
+
+async fn record_history(pool: &SqlitePool, anchor_id: &str, days_ago: i64, reliability: f64) {
+    sqlx::query(
+        "INSERT INTO anchor_metrics_history (
+            id, anchor_id, timestamp, success_rate, failure_rate, reliability_score,
+            total_transactions, successful_transactions, failed_transactions
+         )
+         VALUES (?, ?, ?, ?, ?, ?, 100, 100, 0)",
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(anchor_id)
+    .bind(Utc::now() - Duration::days(days_ago))
+    .bind(reliability * 100.0)
+    .bind(100.0 - reliability * 100.0)
+    .bind(reliability)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+async fn leaderboard(db: Arc<Database>, query: &str) -> (StatusCode, Value) {
+    let response = anchor_leaderboard::routes(db)
+        .oneshot(
+            Request::get(format!("/api/anchors/leaderboard?{}", query))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+fn ranking(body: &Value) -> Vec<(u64, String, f64)> {
+    body["anchors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| {
+            (
+                entry["rank"].as_u64().unwrap(),
+                entry["name"].as_str().unwrap().to_string(),
+                (entry["avg_reliability_score"].as_f64().unwrap() * 1000.0).round() / 1000.0,
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_anchors_ranked_by_average_reliability_in_period() {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    let db = Arc::new(Database::new(pool.clone()));
+
+    let steady = create_anchor(&db, "Steady", "GSTEADY").await;
+    let recovered = create_anchor(&db, "Recovered", "GRECOVERED").await;
+    let dormant = create_anchor(&db, "Dormant", "GDORMANT").await;
+
+    record_history(&pool, &steady, 1, 0.9).await;
+    record_history(&pool, &steady, 3, 0.7).await;
+    record_history(&pool, &recovered, 2, 0.95).await;
+    record_history(&pool, &recovered, 10, 0.1).await;
+    record_history(&pool, &dormant, 20, 0.6).await;
+
+    // Only the last week counts, and the dormant anchor has no history in it
+    let (status, body) = leaderboard(Arc::clone(&db), "period=7d").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["period"], "7d");
+    assert_eq!(
+        ranking(&body),
+        vec![
+            (1, "Recovered".to_string(), 0.95),
+            (2, "Steady".to_string(), 0.8),
+        ]
+    );
+
+    // A month includes the older, lower scores
+    let (_, body) = leaderboard(Arc::clone(&db), "period=30d").await;
+    assert_eq!(
+        ranking(&body),
+        vec![
+            (1, "Steady".to_string(), 0.8),
+            (2, "Dormant".to_string(), 0.6),
+            (3, "Recovered".to_string(), 0.525),
+        ]
+    );
+
+    let (_, body) = leaderboard(Arc::clone(&db), "period=30d&limit=1").await;
+    assert_eq!(ranking(&body), vec![(1, "Steady".to_string(), 0.8)]);
+
+    let (status, _) = leaderboard(Arc::clone(&db), "period=1y").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // Out-of-range limits are rejected rather than clamped
+    let (status, body) = leaderboard(Arc::clone(&db), "limit=0").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["code"], "LIMIT_TOO_SMALL");
+    let (status, body) = leaderboard(db, "limit=101").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["code"], "LIMIT_TOO_LARGE");
+}
@@ -0,0 +1,203 @@
+use axum::{
+    body::Body,
+    extract::{Path, Query},
+    http::{header::CONTENT_TYPE, Request, StatusCode},
+    routing::get,
+    Json, Router,
+};
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::api::asset_verification::{routes, MAX_BATCH_VERIFY_ASSETS};
+use stellar_insights_backend::models::asset_verification::{
+    VerificationResult, VerificationStatus,
+};
+use stellar_insights_backend::rate_limit::RateLimiter;
+use stellar_insights_backend::services::asset_verifier::AssetVerifier;
+
+const USDC_ISSUER: &str = "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+const EURC_ISSUER: &str = "GDHU6WRG4IEQXM5NZ4BMPKOXHW76MZM4Y2IEMFDVXBSDP6SJY4ITNPP2";
+
+fn result(trustline_count: i64) -> VerificationResult {
+    VerificationResult {
+        stellar_expert_verified: true,
+        stellar_toml_verified: true,
+        stellar_toml_data: None,
+        anchor_registry_verified: false,
+        trustline_count,
+        transaction_count: 100,
+        total_volume_usd: 10_000.0,
+        trustline_authorization: None,
+    }
+}
+
+/// Stub Horizon and Stellar Expert, counting the Horizon asset lookups
+async fn external_sources() -> (String, Arc<AtomicUsize>) {
+    let asset_lookups = Arc::new(AtomicUsize::new(0));
+    let lookups = Arc::clone(&asset_lookups);
+    let app = Router::new()
+        .route(
+            "/expert/asset/:asset",
+            get(|Path(asset): Path<String>| async move {
+                Json(json!({ "asset": asset, "domain": "example.org" }))
+            }),
+        )
+        // No home domain, so no stellar.toml is fetched
+        .route(
+            "/horizon/accounts/:account",
+            get(|| async { StatusCode::NOT_FOUND }),
+        )
+        .route(
+            "/horizon/assets",
+            get(move |Query(query): Query<HashMap<String, String>>| {
+                let lookups = Arc::clone(&lookups);
+                async move {
+                    lookups.fetch_add(1, Ordering::SeqCst);
+                    let num_accounts = match query["asset_code"].as_str() {
+                        "BRL" => 1200,
+                        _ => 300,
+                    };
+                    Json(json!({ "_embedded": { "records": [{ "num_accounts": num_accounts }] } }))
+                }
+            }),
+        );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    (format!("http://{}", addr), asset_lookups)
+}
+
+async fn setup() -> Router {
+    setup_with_sources().await.0
+}
+
+async fn setup_with_sources() -> (Router, Arc<AtomicUsize>) {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+
+    // Stored results are served without calling out to external sources
+    let verifier = AssetVerifier::new(pool.clone()).unwrap();
+    for (code, issuer, trustlines) in [("USDC", USDC_ISSUER, 5000), ("EURC", EURC_ISSUER, 800)] {
+        verifier
+            .save_verification_result(
+                code,
+                issuer,
+                &result(trustlines),
+                75.0,
+                VerificationStatus::Verified,
+            )
+            .await
+            .unwrap();
+    }
+
+    let (sources_url, asset_lookups) = external_sources().await;
+    let verifier = AssetVerifier::new(pool.clone())
+        .unwrap()
+        .with_horizon_url(format!("{}/horizon", sources_url))
+        .with_stellar_expert_url(format!("{}/expert", sources_url));
+    let rate_limiter = Arc::new(RateLimiter::new().await.unwrap());
+    let app = Router::new().nest(
+        "/api/assets",
+        routes(pool, rate_limiter, Arc::new(verifier)),
+    );
+    (app, asset_lookups)
+}
+
+async fn verify_batch(app: Router, body: Value) -> (StatusCode, Value) {
+    let response = app
+        .oneshot(
+            Request::post("/api/assets/verify-batch")
+                .header(CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn test_batch_returns_result_for_each_asset() {
+    let (status, body) = verify_batch(
+        setup().await,
+        json!([
+            { "code": "USDC", "issuer": USDC_ISSUER },
+            { "code": "EURC", "issuer": EURC_ISSUER },
+            { "code": "BAD", "issuer": "not-a-key" },
+        ]),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    let results = body["results"].as_array().unwrap();
+    assert_eq!(results.len(), 3);
+
+    assert_eq!(results[0]["code"], "USDC");
+    assert_eq!(results[0]["result"]["metrics"]["trustline_count"], 5000);
+    assert_eq!(results[1]["code"], "EURC");
+    assert_eq!(results[1]["result"]["metrics"]["trustline_count"], 800);
+    assert!(results[0].get("error").is_none());
+
+    assert!(results[2].get("result").is_none());
+    assert_eq!(
+        results[2]["error"],
+        "Issuer must be a valid Stellar public key"
+    );
+}
+
+#[tokio::test]
+async fn test_batch_verifies_unknown_assets_against_external_sources() {
+    let (app, asset_lookups) = setup_with_sources().await;
+    let batch = json!([
+        { "code": "BRL", "issuer": USDC_ISSUER },
+        { "code": "ARS", "issuer": EURC_ISSUER },
+    ]);
+
+    let (status, body) = verify_batch(app.clone(), batch.clone()).await;
+    assert_eq!(status, StatusCode::OK);
+    let results = body["results"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    for (result, trustlines) in results.iter().zip([1200, 300]) {
+        assert!(result.get("error").is_none());
+        assert_eq!(result["result"]["metrics"]["trustline_count"], trustlines);
+        assert_eq!(
+            result["result"]["trust_indicators"]["stellar_expert_verified"],
+            true
+        );
+    }
+    assert_eq!(asset_lookups.load(Ordering::SeqCst), 2);
+
+    // Both results were stored, so a repeat is served without new lookups
+    let (status, body) = verify_batch(app, batch).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body["results"][0]["result"]["metrics"]["trustline_count"],
+        1200
+    );
+    assert_eq!(asset_lookups.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_batch_size_is_capped() {
+    let app = setup().await;
+
+    let (status, _) = verify_batch(app.clone(), json!([])).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let oversized: Vec<Value> = (0..=MAX_BATCH_VERIFY_ASSETS)
+        .map(|_| json!({ "code": "USDC", "issuer": USDC_ISSUER }))
+        .collect();
+    let (status, body) = verify_batch(app, Value::Array(oversized)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"], "Invalid batch size");
+}
@@ -2,9 +2,9 @@ use anyhow::Result;
 use chrono::Utc;
 use sqlx::SqlitePool;
 use stellar_insights_backend::models::asset_verification::{
-    ReportAssetRequest, ReportType, VerificationStatus,
+    ReportAssetRequest, ReportType, TrustlineAuthorization, VerificationStatus,
 };
-use stellar_insights_backend::services::asset_verifier::AssetVerifier;
+use stellar_insights_backend::services::asset_verifier::{AssetVerifier, ReputationThresholds};
 use uuid::Uuid;
 
 /// Helper function to create a test database
@@ -18,7 +18,7 @@ async fn create_test_db() -> Result<SqlitePool> {
             id TEXT PRIMARY KEY,
             asset_code TEXT NOT NULL,
             asset_issuer TEXT NOT NULL,
-            verification_status TEXT NOT NULL CHECK (verification_status IN ('verified', 'unverified', 'suspicious')),
+            verification_status TEXT NOT NULL CHECK (verification_status IN ('verified', 'provisional', 'unverified', 'suspicious')),
             reputation_score REAL NOT NULL DEFAULT 0.0,
             stellar_expert_verified BOOLEAN DEFAULT FALSE,
             stellar_toml_verified BOOLEAN DEFAULT FALSE,
@@ -93,6 +93,7 @@ async fn test_reputation_score_calculation() -> Result<()> {
         trustline_count: 15000,
         transaction_count: 150000,
         total_volume_usd: 1000000.0,
+        trustline_authorization: None,
     };
 
     let score = verifier.calculate_reputation_score(&result);
@@ -107,6 +108,7 @@ async fn test_reputation_score_calculation() -> Result<()> {
         trustline_count: 0,
         transaction_count: 0,
         total_volume_usd: 0.0,
+        trustline_authorization: None,
     };
 
     let score = verifier.calculate_reputation_score(&result);
@@ -121,6 +123,7 @@ async fn test_reputation_score_calculation() -> Result<()> {
         trustline_count: 1500,
         transaction_count: 15000,
         total_volume_usd: 100000.0,
+        trustline_authorization: None,
     };
 
     let score = verifier.calculate_reputation_score(&result);
@@ -129,17 +132,81 @@ async fn test_reputation_score_calculation() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_trustline_authorization_penalty() -> Result<()> {
+    let pool = create_test_db().await?;
+    let verifier = AssetVerifier::new(pool)?.with_trustline_auth_weight(10.0);
+
+    let result = |authorization: TrustlineAuthorization| {
+        stellar_insights_backend::services::asset_verifier::VerificationResult {
+            stellar_expert_verified: true,
+            stellar_toml_verified: false,
+            stellar_toml_data: None,
+            anchor_registry_verified: false,
+            trustline_count: 1500,
+            transaction_count: 15000,
+            total_volume_usd: 100000.0,
+            trustline_authorization: Some(authorization),
+        }
+    };
+
+    // Every trustline authorized and no clawback: no penalty
+    let clean = result(TrustlineAuthorization {
+        authorized: 1500,
+        unauthorized: 0,
+        clawback_enabled: false,
+    });
+    assert_eq!(verifier.calculate_reputation_score(&clean), 44.0); // 30 + 7 + 7
+
+    // Undisclosed clawback costs half the weight, and three in four
+    // unauthorized trustlines cost 3/4 of the other half
+    let risky = result(TrustlineAuthorization {
+        authorized: 500,
+        unauthorized: 1500,
+        clawback_enabled: true,
+    });
+    assert_eq!(verifier.calculate_reputation_score(&risky), 35.25); // 44 - 5 - 3.75
+
+    // Listing the asset in stellar.toml discloses the clawback
+    let disclosed = stellar_insights_backend::services::asset_verifier::VerificationResult {
+        stellar_toml_verified: true,
+        ..risky
+    };
+    assert_eq!(verifier.calculate_reputation_score(&disclosed), 70.25); // 74 - 3.75
+
+    // The penalty never drives the score below zero
+    let unverified = stellar_insights_backend::services::asset_verifier::VerificationResult {
+        stellar_expert_verified: false,
+        trustline_count: 0,
+        transaction_count: 0,
+        ..result(TrustlineAuthorization {
+            authorized: 0,
+            unauthorized: 10,
+            clawback_enabled: true,
+        })
+    };
+    assert_eq!(verifier.calculate_reputation_score(&unverified), 0.0);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_status_determination() -> Result<()> {
     let pool = create_test_db().await?;
-    let verifier = AssetVerifier::new(pool)?;
+    let verifier = AssetVerifier::new(pool)?.with_thresholds(ReputationThresholds::default());
 
     // Test case 1: Verified status
     let status = verifier.determine_status(80.0, 0);
     assert_eq!(status, VerificationStatus::Verified);
 
-    // Test case 2: Unverified status (low score)
+    // Test case 2: Provisional status (between the thresholds)
     let status = verifier.determine_status(40.0, 0);
+    assert_eq!(status, VerificationStatus::Provisional);
+    let status = verifier.determine_status(59.9, 0);
+    assert_eq!(status, VerificationStatus::Provisional);
+
+    // Unverified status (below the provisional threshold)
+    let status = verifier.determine_status(39.9, 0);
     assert_eq!(status, VerificationStatus::Unverified);
 
     // Test case 3: Suspicious status (high reports)
@@ -154,6 +221,10 @@ async fn test_status_determination() -> Result<()> {
     let status = verifier.determine_status(80.0, 3);
     assert_eq!(status, VerificationStatus::Suspicious);
 
+    // Reports also override a provisional score
+    let status = verifier.determine_status(50.0, 3);
+    assert_eq!(status, VerificationStatus::Suspicious);
+
     Ok(())
 }
 
@@ -182,6 +253,7 @@ async fn test_save_and_retrieve_verification() -> Result<()> {
         trustline_count: 50000,
         transaction_count: 1000000,
         total_volume_usd: 50000000.0,
+        trustline_authorization: None,
     };
 
     // Save verification result
@@ -230,6 +302,7 @@ async fn test_list_verified_assets() -> Result<()> {
             trustline_count: (i as i64) * 1000,
             transaction_count: (i as i64) * 10000,
             total_volume_usd: (i as f64) * 100000.0,
+            trustline_authorization: None,
         };
 
         verifier
@@ -272,6 +345,7 @@ async fn test_unique_constraint() -> Result<()> {
         trustline_count: 1000,
         transaction_count: 10000,
         total_volume_usd: 100000.0,
+        trustline_authorization: None,
     };
 
     // First save should succeed
@@ -289,6 +363,7 @@ async fn test_unique_constraint() -> Result<()> {
         trustline_count: 2000,
         transaction_count: 20000,
         total_volume_usd: 200000.0,
+        trustline_authorization: None,
     };
 
     let second_save = verifier
@@ -325,6 +400,7 @@ async fn test_concurrent_verification() -> Result<()> {
                         trustline_count: 100,
                         transaction_count: 1000,
                         total_volume_usd: 10000.0,
+                        trustline_authorization: None,
                     };
 
                 verifier
@@ -377,6 +453,7 @@ async fn test_similar_asset_codes() -> Result<()> {
             trustline_count: 1000,
             transaction_count: 10000,
             total_volume_usd: 100000.0,
+            trustline_authorization: None,
         };
 
         verifier
@@ -0,0 +1,111 @@
+use chrono::Duration;
+use std::sync::Arc;
+use stellar_insights_backend::auth::refresh_tokens::{MemoryRefreshTokenStore, RefreshTokenStore};
+use stellar_insights_backend::auth::{AuthService, RefreshTokenRequest, User};
+use tokio::sync::RwLock;
+
+fn auth_service(store: Arc<MemoryRefreshTokenStore>) -> AuthService {
+    if std::env::var("JWT_SECRET").is_err() {
+        std::env::set_var(
+            "JWT_SECRET",
+            "test_jwt_secret_key_that_is_long_enough_for_tests_32",
+        );
+    }
+
+    AuthService::new(Arc::new(RwLock::new(None))).with_token_store(store)
+}
+
+fn user() -> User {
+    User {
+        id: "user-1".to_string(),
+        username: "test-user".to_string(),
+    }
+}
+
+fn request(refresh_token: &str) -> RefreshTokenRequest {
+    RefreshTokenRequest {
+        refresh_token: refresh_token.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_refresh_rotates_the_refresh_token() {
+    let service = auth_service(Arc::new(MemoryRefreshTokenStore::new()));
+    let first = service.issue_refresh_token(&user()).await.unwrap();
+
+    let refreshed = service.refresh(request(&first)).await.unwrap();
+    assert_ne!(refreshed.refresh_token, first);
+    let claims = service.validate_token(&refreshed.access_token).unwrap();
+    assert_eq!(claims.sub, "user-1");
+    assert_eq!(claims.token_type, "access");
+
+    // The replacement belongs to the same family and keeps rotating
+    let rotated = service
+        .validate_refresh_token(&refreshed.refresh_token)
+        .await
+        .unwrap();
+    assert_eq!(
+        rotated.family,
+        service.validate_token(&first).unwrap().family
+    );
+    service
+        .refresh(request(&refreshed.refresh_token))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_reused_refresh_token_revokes_every_session() {
+    let service = auth_service(Arc::new(MemoryRefreshTokenStore::new()));
+    let stolen = service.issue_refresh_token(&user()).await.unwrap();
+    let other_session = service.issue_refresh_token(&user()).await.unwrap();
+
+    let rotated = service.refresh(request(&stolen)).await.unwrap();
+
+    // Presenting the rotated-out token again is reuse
+    assert!(service.refresh(request(&stolen)).await.is_err());
+
+    // Which ends the legitimate holder's session and every other login
+    assert!(service
+        .refresh(request(&rotated.refresh_token))
+        .await
+        .is_err());
+    assert!(service.refresh(request(&other_session)).await.is_err());
+}
+
+#[tokio::test]
+async fn test_expired_refresh_tokens_are_rejected() {
+    let store = Arc::new(MemoryRefreshTokenStore::new());
+    let service = auth_service(Arc::clone(&store));
+    let other_session = service.issue_refresh_token(&user()).await.unwrap();
+
+    // A family left unused past its lifetime is gone from the store, even
+    // while the token itself is within its expiry leeway
+    let short_lived = auth_service(Arc::clone(&store)).with_refresh_token_ttl(Duration::seconds(1));
+    let idle = short_lived.issue_refresh_token(&user()).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(1500)).await;
+    assert!(short_lived.refresh(request(&idle)).await.is_err());
+
+    // A token past its own expiry is rejected without touching the store
+    store
+        .create_family(
+            "user-1",
+            "family-1",
+            "token-1",
+            std::time::Duration::from_secs(60),
+        )
+        .await
+        .unwrap();
+    let expired = auth_service(Arc::clone(&store))
+        .with_refresh_token_ttl(Duration::minutes(-5))
+        .generate_refresh_token(&user(), "family-1", "token-1")
+        .unwrap();
+    assert!(service.refresh(request(&expired)).await.is_err());
+    assert_eq!(
+        store.current_token("family-1").await.unwrap().as_deref(),
+        Some("token-1")
+    );
+
+    // Expiry is not reuse, so the user's other sessions survive
+    service.refresh(request(&other_session)).await.unwrap();
+}
@@ -0,0 +1,91 @@
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+    Extension, Router,
+};
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::Duration;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::api::metrics_cached::{self, MetricsOverview};
+use stellar_insights_backend::auth::{AuthService, User};
+use stellar_insights_backend::auth_middleware::{AdminUsers, JwtSecret};
+use stellar_insights_backend::cache::{keys, CacheConfig, CacheManager};
+use stellar_insights_backend::cache_memory::MemoryCache;
+
+const JWT_SECRET: &str = "test_jwt_secret_key_that_is_long_enough_for_tests_32";
+
+async fn setup() -> Router {
+    // Unreachable Redis, so the cache runs on its in-memory fallback
+    let cache = Arc::new(
+        CacheManager::with_redis_url(
+            CacheConfig::default(),
+            "redis://127.0.0.1:1",
+            MemoryCache::new(100),
+            Duration::from_secs(60),
+        )
+        .await,
+    );
+    let stale = MetricsOverview {
+        total_volume: 1.0,
+        total_transactions: 1,
+        active_users: 1,
+        average_transaction_value: 1.0,
+        corridor_count: 1,
+    };
+    cache
+        .set(&keys::metrics_overview(), &stale, 60)
+        .await
+        .unwrap();
+
+    metrics_cached::routes(cache)
+        .layer(Extension(AdminUsers::new(["admin-1".to_string()])))
+        .layer(Extension(JwtSecret(Arc::from(JWT_SECRET))))
+}
+
+fn token(user_id: &str) -> String {
+    std::env::set_var("JWT_SECRET", JWT_SECRET);
+    AuthService::new(Arc::new(tokio::sync::RwLock::new(None)))
+        .generate_access_token(&User {
+            id: user_id.to_string(),
+            username: user_id.to_string(),
+        })
+        .unwrap()
+}
+
+async fn overview(app: Router, user_id: &str) -> Value {
+    let response = app
+        .oneshot(
+            Request::get("/api/metrics/overview")
+                .header(header::CACHE_CONTROL, "no-cache")
+                .header(header::AUTHORIZATION, format!("Bearer {}", token(user_id)))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn test_non_admin_no_cache_is_served_from_cache() {
+    let app = setup().await;
+    let body = overview(app, "user-1").await;
+    assert_eq!(body["total_transactions"], 1);
+}
+
+#[tokio::test]
+async fn test_admin_no_cache_recomputes_despite_cache_entry() {
+    let app = setup().await;
+    let body = overview(app.clone(), "admin-1").await;
+    assert_ne!(body["total_transactions"], 1);
+
+    // The fresh value was written back, so a later non-admin read sees it
+    let body = overview(app, "user-1").await;
+    assert_ne!(body["total_transactions"], 1);
+}
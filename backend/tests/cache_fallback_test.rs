@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use stellar_insights_backend::cache::{keys, CacheConfig, CacheManager};
+use stellar_insights_backend::cache_memory::MemoryCache;
+
+/// Nothing listens on port 1, so connecting always fails
+const UNREACHABLE_REDIS: &str = "redis://127.0.0.1:1";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct AnchorSummary {
+    id: String,
+    name: String,
+}
+
+async fn memory_only_cache(max_entries: usize) -> CacheManager {
+    CacheManager::with_redis_url(
+        CacheConfig::default(),
+        UNREACHABLE_REDIS,
+        MemoryCache::new(max_entries),
+        Duration::from_secs(60),
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_get_and_set_use_memory_when_redis_is_absent() {
+    let cache = memory_only_cache(100).await;
+    assert!(!cache.is_redis_connected().await);
+
+    let anchor = AnchorSummary {
+        id: "anchor-1".to_string(),
+        name: "Circle".to_string(),
+    };
+    let key = keys::anchor_detail(&anchor.id);
+    cache.set(&key, &anchor, 600).await.unwrap();
+
+    let cached: Option<AnchorSummary> = cache.get(&key).await.unwrap();
+    assert_eq!(cached, Some(anchor));
+}
+
+#[tokio::test]
+async fn test_hit_ratio_is_tracked_for_memory_fallback() {
+    let cache = memory_only_cache(100).await;
+
+    cache
+        .set(&keys::dashboard_stats(), &42u64, 60)
+        .await
+        .unwrap();
+    for _ in 0..3 {
+        let hit: Option<u64> = cache.get(&keys::dashboard_stats()).await.unwrap();
+        assert_eq!(hit, Some(42));
+    }
+    let miss: Option<u64> = cache.get(&keys::metrics_overview()).await.unwrap();
+    assert!(miss.is_none());
+
+    let stats = cache.get_stats();
+    assert_eq!(stats.hits, 3);
+    assert_eq!(stats.misses, 1);
+    assert_eq!(stats.hit_rate(), 75.0);
+}
+
+#[tokio::test]
+async fn test_invalidation_and_eviction_in_memory_fallback() {
+    let cache = memory_only_cache(2).await;
+
+    cache
+        .set(&keys::anchor_detail("1"), &1u64, 600)
+        .await
+        .unwrap();
+    cache
+        .set(&keys::anchor_detail("2"), &2u64, 600)
+        .await
+        .unwrap();
+    cache
+        .set(&keys::corridor_detail("c"), &3u64, 600)
+        .await
+        .unwrap();
+
+    // Capacity is two, so the oldest anchor entry has been evicted
+    let evicted: Option<u64> = cache.get(&keys::anchor_detail("1")).await.unwrap();
+    assert!(evicted.is_none());
+
+    let deleted = cache.delete_pattern(&keys::anchor_pattern()).await.unwrap();
+    assert_eq!(deleted, 1);
+    let corridor: Option<u64> = cache.get(&keys::corridor_detail("c")).await.unwrap();
+    assert_eq!(corridor, Some(3));
+    assert_eq!(cache.get_stats().invalidations, 1);
+}
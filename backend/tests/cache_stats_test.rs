@@ -0,0 +1,51 @@
+use axum::{body::Body, http::Request};
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::Duration;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::api::cache_stats;
+use stellar_insights_backend::cache::{CacheConfig, CacheManager};
+use stellar_insights_backend::cache_memory::MemoryCache;
+
+#[tokio::test]
+async fn test_stats_report_hit_ratio_of_primed_lookups() {
+    // Nothing listens on this port, so the cache runs from memory
+    let cache = Arc::new(
+        CacheManager::with_redis_url(
+            CacheConfig::default(),
+            "redis://127.0.0.1:1",
+            MemoryCache::new(100),
+            Duration::from_secs(60),
+        )
+        .await,
+    );
+
+    assert!(cache.get::<String>("stats:key").await.unwrap().is_none());
+    cache
+        .set("stats:key", &"value".to_string(), 60)
+        .await
+        .unwrap();
+    assert!(cache.get::<String>("stats:key").await.unwrap().is_some());
+
+    let response = cache_stats::routes(cache)
+        .oneshot(
+            Request::get("/api/cache/stats")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let stats: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(stats["hits"], 1);
+    assert_eq!(stats["misses"], 1);
+    assert_eq!(stats["hit_ratio"], 0.5);
+    assert_eq!(stats["memory_entries"], 1);
+    assert_eq!(stats["memory_evictions"], 0);
+    assert!(stats["redis"].is_null());
+}
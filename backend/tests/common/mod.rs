@@ -0,0 +1,26 @@
+//! Fixtures shared by the integration tests; include with `mod common;`.
+
+use sqlx::SqlitePool;
+use std::sync::Arc;
+
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::ingestion::DataIngestionService;
+use stellar_insights_backend::rpc::StellarRpcClient;
+use stellar_insights_backend::state::AppState;
+use stellar_insights_backend::websocket::WsState;
+
+/// `AppState` over a fresh, migrated in-memory database and a mock RPC client
+pub async fn test_app_state() -> AppState {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    let db = Arc::new(Database::new(pool));
+
+    let ws_state = Arc::new(WsState::new());
+    let rpc_client = Arc::new(StellarRpcClient::new_with_defaults(true));
+    let ingestion = Arc::new(DataIngestionService::new(rpc_client, Arc::clone(&db)));
+    AppState {
+        db,
+        ws_state,
+        ingestion,
+    }
+}
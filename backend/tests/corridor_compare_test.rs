@@ -0,0 +1,238 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Extension, Router,
+};
+use chrono::Utc;
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use std::time::Duration;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::api::corridors_cached::{
+    compare_corridors, get_corridor_detail, CorridorVolumeFloor, HealthScoreWeights,
+};
+use stellar_insights_backend::cache::{CacheConfig, CacheManager};
+use stellar_insights_backend::cache_memory::MemoryCache;
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::models::PaymentRecord;
+use stellar_insights_backend::rpc::StellarRpcClient;
+use stellar_insights_backend::services::price_feed::{
+    default_asset_mapping, PriceFeedClient, PriceFeedConfig,
+};
+
+// Issuer outside the price feed mapping, so volumes use raw amounts offline
+const ISSUER: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+
+fn usdc() -> String {
+    format!("USDC:{}->USDC:{}", ISSUER, ISSUER)
+}
+
+fn eurc() -> String {
+    format!("EURC:{}->EURC:{}", ISSUER, ISSUER)
+}
+
+fn payment(id: &str, code: &str, amount: f64) -> PaymentRecord {
+    let now = Utc::now();
+    PaymentRecord {
+        id: id.to_string(),
+        transaction_hash: format!("hash_{}", id),
+        source_account: "GSOURCE".to_string(),
+        destination_account: "GDEST".to_string(),
+        asset_type: "credit_alphanum4".to_string(),
+        asset_code: Some(code.to_string()),
+        asset_issuer: Some(ISSUER.to_string()),
+        operation_type: None,
+        source_asset_type: None,
+        source_asset_code: code.to_string(),
+        source_asset_issuer: ISSUER.to_string(),
+        destination_asset_code: code.to_string(),
+        destination_asset_issuer: ISSUER.to_string(),
+        amount,
+        successful: true,
+        timestamp: Some(now),
+        submission_time: None,
+        confirmation_time: None,
+        created_at: now,
+    }
+}
+
+async fn setup() -> Router {
+    setup_with_floor(CorridorVolumeFloor::default()).await
+}
+
+async fn setup_with_floor(floor: CorridorVolumeFloor) -> Router {
+    router()
+        .await
+        .layer(Extension(HealthScoreWeights::default()))
+        .layer(Extension(floor))
+}
+
+/// Corridor routes without the weight or floor extensions installed
+async fn router() -> Router {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    let db = Arc::new(Database::new(pool));
+    db.save_payments(vec![
+        payment("1", "USDC", 100.0),
+        payment("2", "USDC", 50.0),
+        payment("3", "EURC", 20.0),
+    ])
+    .await
+    .unwrap();
+
+    let cache = Arc::new(
+        CacheManager::with_redis_url(
+            CacheConfig::default(),
+            "redis://127.0.0.1:1",
+            MemoryCache::new(100),
+            Duration::from_secs(60),
+        )
+        .await,
+    );
+    let rpc_client = Arc::new(StellarRpcClient::new_with_defaults(true));
+    let price_feed = Arc::new(PriceFeedClient::new(
+        PriceFeedConfig::default(),
+        default_asset_mapping(),
+    ));
+
+    Router::new()
+        .route(
+            "/api/corridors/compare",
+            axum::routing::get(compare_corridors),
+        )
+        .route(
+            "/api/corridors/:corridor_key",
+            axum::routing::get(get_corridor_detail),
+        )
+        .with_state((db, cache, rpc_client, price_feed))
+}
+
+async fn compare(app: Router, keys: &[&str]) -> (StatusCode, Value) {
+    let keys: String = keys.join(",");
+    let query = encode_keys(&keys);
+    let response = app
+        .oneshot(
+            Request::get(format!("/api/corridors/compare?keys={}", query))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+/// Percent-encode the separators inside corridor keys
+fn encode_keys(keys: &str) -> String {
+    keys.replace(':', "%3A").replace('>', "%3E")
+}
+
+#[tokio::test]
+async fn test_compare_returns_corridors_in_requested_order() {
+    let app = setup().await;
+    let (eurc, usdc) = (eurc(), usdc());
+
+    let (status, body) = compare(app, &[eurc.as_str(), usdc.as_str()]).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let entries = body.as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0]["key"], eurc);
+    assert_eq!(entries[0]["corridor"]["total_attempts"], 1);
+    assert_eq!(entries[1]["key"], usdc);
+    assert_eq!(entries[1]["corridor"]["total_attempts"], 2);
+    assert_eq!(entries[1]["corridor"]["liquidity_depth_usd"], 150.0);
+    assert!(entries[1]["error"].is_null());
+}
+
+#[tokio::test]
+async fn test_compare_reports_invalid_keys_per_entry() {
+    let app = setup().await;
+    let usdc = usdc();
+    let unknown = format!("GBPX:{}->GBPX:{}", ISSUER, ISSUER);
+
+    let (status, body) = compare(
+        app,
+        &[
+            "not-a-corridor",
+            usdc.as_str(),
+            unknown.as_str(),
+            "USDC->XLM",
+        ],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    let entries = body.as_array().unwrap();
+    let codes: Vec<&Value> = entries.iter().map(|e| &e["error"]["code"]).collect();
+    assert_eq!(codes[0], "INVALID_CORRIDOR_FORMAT");
+    assert!(codes[1].is_null());
+    assert_eq!(entries[1]["corridor"]["id"], usdc);
+    assert_eq!(codes[2], "CORRIDOR_NOT_FOUND");
+    assert_eq!(codes[3], "INVALID_ASSET_FORMAT");
+}
+
+#[tokio::test]
+async fn test_compare_caps_the_number_of_keys() {
+    let app = setup().await;
+    let usdc = usdc();
+    let keys = vec![usdc.as_str(); 11];
+
+    let (status, body) = compare(app.clone(), &keys).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["code"], "TOO_MANY_CORRIDOR_KEYS");
+
+    let (status, _) = compare(app, &[]).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_corridors_below_volume_floor_are_dropped() {
+    // EURC has a single 20 unit payment, USDC two totalling 150
+    let app = setup_with_floor(CorridorVolumeFloor {
+        min_volume_usd: 50.0,
+    })
+    .await;
+    let (eurc, usdc) = (eurc(), usdc());
+
+    let (status, body) = compare(app.clone(), &[usdc.as_str(), eurc.as_str()]).await;
+    assert_eq!(status, StatusCode::OK);
+    let entries = body.as_array().unwrap();
+    assert_eq!(entries[0]["corridor"]["total_attempts"], 2);
+    assert_eq!(entries[1]["error"]["code"], "CORRIDOR_NOT_FOUND");
+
+    let response = app
+        .oneshot(
+            Request::get(format!("/api/corridors/{}", encode_keys(&eurc)))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_handlers_default_weights_when_extension_is_missing() {
+    let app = router().await;
+    let usdc = usdc();
+
+    let (status, body) = compare(app.clone(), &[usdc.as_str()]).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body[0]["corridor"]["total_attempts"], 2);
+
+    let response = app
+        .oneshot(
+            Request::get(format!("/api/corridors/{}", encode_keys(&usdc)))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
@@ -0,0 +1,137 @@
+use axum::{
+    body::Body,
+    http::{
+        header::{CACHE_CONTROL, ETAG, IF_NONE_MATCH},
+        Request, StatusCode,
+    },
+    Extension, Router,
+};
+use chrono::Utc;
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use std::time::Duration;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::api::corridors_cached::{get_corridor_detail, HealthScoreWeights};
+use stellar_insights_backend::cache::{CacheConfig, CacheManager};
+use stellar_insights_backend::cache_memory::MemoryCache;
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::models::PaymentRecord;
+use stellar_insights_backend::rpc::StellarRpcClient;
+use stellar_insights_backend::services::price_feed::{
+    default_asset_mapping, PriceFeedClient, PriceFeedConfig,
+};
+
+// Issuer outside the price feed mapping, so volumes use raw amounts offline
+const ISSUER: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+
+fn payment(id: &str, amount: f64) -> PaymentRecord {
+    let now = Utc::now();
+    PaymentRecord {
+        id: id.to_string(),
+        transaction_hash: format!("hash_{}", id),
+        source_account: "GSOURCE".to_string(),
+        destination_account: "GDEST".to_string(),
+        asset_type: "credit_alphanum4".to_string(),
+        asset_code: Some("USDC".to_string()),
+        asset_issuer: Some(ISSUER.to_string()),
+        operation_type: None,
+        source_asset_type: None,
+        source_asset_code: "USDC".to_string(),
+        source_asset_issuer: ISSUER.to_string(),
+        destination_asset_code: "USDC".to_string(),
+        destination_asset_issuer: ISSUER.to_string(),
+        amount,
+        successful: true,
+        timestamp: Some(now),
+        submission_time: None,
+        confirmation_time: None,
+        created_at: now,
+    }
+}
+
+async fn setup() -> Router {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    let db = Arc::new(Database::new(pool));
+    db.save_payments(vec![payment("1", 100.0), payment("2", 50.0)])
+        .await
+        .unwrap();
+
+    let cache = Arc::new(
+        CacheManager::with_redis_url(
+            CacheConfig::default(),
+            "redis://127.0.0.1:1",
+            MemoryCache::new(100),
+            Duration::from_secs(60),
+        )
+        .await,
+    );
+    let rpc_client = Arc::new(StellarRpcClient::new_with_defaults(true));
+    let price_feed = Arc::new(PriceFeedClient::new(
+        PriceFeedConfig::default(),
+        default_asset_mapping(),
+    ));
+
+    Router::new()
+        .route(
+            "/api/corridors/:corridor_key",
+            axum::routing::get(get_corridor_detail),
+        )
+        .with_state((db, cache, rpc_client, price_feed))
+        .layer(Extension(HealthScoreWeights::default()))
+}
+
+fn detail_uri() -> String {
+    format!("/api/corridors/USDC%3A{}-%3EUSDC%3A{}", ISSUER, ISSUER)
+}
+
+#[tokio::test]
+async fn test_detail_returns_304_for_matching_etag() {
+    let app = setup().await;
+
+    let first = app
+        .clone()
+        .oneshot(Request::get(detail_uri()).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(first.status(), StatusCode::OK);
+    assert_eq!(
+        first.headers().get(CACHE_CONTROL).unwrap(),
+        "public, max-age=300"
+    );
+    let etag = first.headers().get(ETAG).unwrap().clone();
+
+    let second = app
+        .oneshot(
+            Request::get(detail_uri())
+                .header(IF_NONE_MATCH, etag.clone())
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(second.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(second.headers().get(ETAG).unwrap(), &etag);
+    let body = axum::body::to_bytes(second.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    assert!(body.is_empty());
+}
+
+#[tokio::test]
+async fn test_detail_ignores_stale_etag() {
+    let app = setup().await;
+
+    let response = app
+        .oneshot(
+            Request::get(detail_uri())
+                .header(IF_NONE_MATCH, "\"stale\"")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get(ETAG).is_some());
+}
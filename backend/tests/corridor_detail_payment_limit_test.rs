@@ -0,0 +1,119 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Extension, Router,
+};
+use chrono::Utc;
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use std::time::Duration;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::api::corridors_cached::{
+    get_corridor_detail, CorridorPaymentFetchLimit, HealthScoreWeights,
+};
+use stellar_insights_backend::cache::{CacheConfig, CacheManager};
+use stellar_insights_backend::cache_memory::MemoryCache;
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::models::PaymentRecord;
+use stellar_insights_backend::rpc::StellarRpcClient;
+use stellar_insights_backend::services::price_feed::{
+    default_asset_mapping, PriceFeedClient, PriceFeedConfig,
+};
+
+// Issuer outside the price feed mapping, so volumes use raw amounts offline
+const ISSUER: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+
+fn payment(id: usize) -> PaymentRecord {
+    let now = Utc::now();
+    PaymentRecord {
+        id: id.to_string(),
+        transaction_hash: format!("hash_{}", id),
+        source_account: "GSOURCE".to_string(),
+        destination_account: "GDEST".to_string(),
+        asset_type: "credit_alphanum4".to_string(),
+        asset_code: Some("USDC".to_string()),
+        asset_issuer: Some(ISSUER.to_string()),
+        operation_type: None,
+        source_asset_type: None,
+        source_asset_code: "USDC".to_string(),
+        source_asset_issuer: ISSUER.to_string(),
+        destination_asset_code: "USDC".to_string(),
+        destination_asset_issuer: ISSUER.to_string(),
+        amount: 10.0,
+        successful: true,
+        timestamp: Some(now),
+        submission_time: None,
+        confirmation_time: None,
+        created_at: now,
+    }
+}
+
+async fn setup(limit: CorridorPaymentFetchLimit) -> Router {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    let db = Arc::new(Database::new(pool));
+    db.save_payments((0..150).map(payment).collect())
+        .await
+        .unwrap();
+
+    let cache = Arc::new(
+        CacheManager::with_redis_url(
+            CacheConfig::default(),
+            "redis://127.0.0.1:1",
+            MemoryCache::new(100),
+            Duration::from_secs(60),
+        )
+        .await,
+    );
+    let rpc_client = Arc::new(StellarRpcClient::new_with_defaults(true));
+    let price_feed = Arc::new(PriceFeedClient::new(
+        PriceFeedConfig::default(),
+        default_asset_mapping(),
+    ));
+
+    Router::new()
+        .route(
+            "/api/corridors/:corridor_key",
+            axum::routing::get(get_corridor_detail),
+        )
+        .with_state((db, cache, rpc_client, price_feed))
+        .layer(Extension(HealthScoreWeights::default()))
+        .layer(Extension(limit))
+}
+
+async fn total_attempts(app: Router) -> i64 {
+    let uri = format!("/api/corridors/USDC%3A{}-%3EUSDC%3A{}", ISSUER, ISSUER);
+    let response = app
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body: Value = serde_json::from_slice(&body).unwrap();
+    body["corridor"]["total_attempts"].as_i64().unwrap()
+}
+
+#[tokio::test]
+async fn test_detail_loads_at_most_the_configured_payments() {
+    let capped = setup(CorridorPaymentFetchLimit::new(100).unwrap()).await;
+    assert_eq!(total_attempts(capped).await, 100);
+
+    let default = setup(CorridorPaymentFetchLimit::default()).await;
+    assert_eq!(total_attempts(default).await, 150);
+}
+
+#[test]
+fn test_payment_fetch_limit_rejects_out_of_range_values() {
+    assert!(CorridorPaymentFetchLimit::new(99).is_err());
+    assert!(CorridorPaymentFetchLimit::new(100_001).is_err());
+    assert_eq!(
+        CorridorPaymentFetchLimit::new(100_000)
+            .unwrap()
+            .max_payments,
+        100_000
+    );
+}
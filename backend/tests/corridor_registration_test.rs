@@ -0,0 +1,103 @@
+use sqlx::SqlitePool;
+use std::sync::Arc;
+
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::ingestion::DataIngestionService;
+use stellar_insights_backend::rpc::{Payment, StellarRpcClient};
+
+const ISSUER: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+
+async fn setup() -> (DataIngestionService, SqlitePool) {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    let db = Arc::new(Database::new(pool.clone()));
+    let rpc_client = Arc::new(StellarRpcClient::new_with_defaults(true));
+    (DataIngestionService::new(rpc_client, db), pool)
+}
+
+fn path_payment(id: &str, source_code: &str, dest_code: &str) -> Payment {
+    Payment {
+        id: id.to_string(),
+        paging_token: id.to_string(),
+        transaction_hash: format!("hash_{}", id),
+        source_account: "GSOURCE".to_string(),
+        destination: "GDEST".to_string(),
+        asset_type: "credit_alphanum4".to_string(),
+        asset_code: Some(dest_code.to_string()),
+        asset_issuer: Some(ISSUER.to_string()),
+        amount: "25.0".to_string(),
+        created_at: "2026-01-01T00:00:00Z".to_string(),
+        operation_type: Some("path_payment_strict_send".to_string()),
+        source_asset_type: Some("credit_alphanum4".to_string()),
+        source_asset_code: Some(source_code.to_string()),
+        source_asset_issuer: Some(ISSUER.to_string()),
+        source_amount: Some("27.0".to_string()),
+        from: Some("GSOURCE".to_string()),
+        to: Some("GDEST".to_string()),
+        asset_balance_changes: None,
+    }
+}
+
+async fn count_corridors(pool: &SqlitePool, code_a: &str, code_b: &str) -> i64 {
+    sqlx::query_scalar(
+        "SELECT COUNT(*) FROM corridors
+         WHERE (source_asset_code = ? AND destination_asset_code = ?)
+            OR (source_asset_code = ? AND destination_asset_code = ?)",
+    )
+    .bind(code_a)
+    .bind(code_b)
+    .bind(code_b)
+    .bind(code_a)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_new_asset_pair_registers_corridor_once() {
+    let (ingestion, pool) = setup().await;
+    assert_eq!(count_corridors(&pool, "EURT", "NGNT").await, 0);
+
+    // Both directions of the pair name the same corridor
+    let first_batch = vec![
+        path_payment("p1", "EURT", "NGNT"),
+        path_payment("p2", "NGNT", "EURT"),
+    ];
+    let registered = ingestion
+        .register_observed_corridors(&first_batch)
+        .await
+        .unwrap();
+    assert_eq!(registered, 1);
+    assert_eq!(count_corridors(&pool, "EURT", "NGNT").await, 1);
+
+    let second_batch = vec![path_payment("p3", "EURT", "NGNT")];
+    let registered = ingestion
+        .register_observed_corridors(&second_batch)
+        .await
+        .unwrap();
+    assert_eq!(registered, 0);
+    assert_eq!(count_corridors(&pool, "EURT", "NGNT").await, 1);
+}
+
+#[tokio::test]
+async fn test_unidentified_assets_are_not_registered() {
+    let (ingestion, pool) = setup().await;
+    let before: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM corridors")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+    let mut payment = path_payment("p1", "EURT", "NGNT");
+    payment.asset_code = None;
+    let registered = ingestion
+        .register_observed_corridors(&[payment])
+        .await
+        .unwrap();
+
+    let after: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM corridors")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(registered, 0);
+    assert_eq!(before, after);
+}
@@ -0,0 +1,112 @@
+use axum::{
+    body::Body,
+    http::{header::WARNING, Request, StatusCode},
+    Extension, Router,
+};
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::api::corridors_cached::{list_corridors, HealthScoreWeights};
+use stellar_insights_backend::cache::{keys, CacheConfig, CacheManager};
+use stellar_insights_backend::cache_memory::MemoryCache;
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::rpc::error::RetryConfig;
+use stellar_insights_backend::rpc::StellarRpcClient;
+use stellar_insights_backend::services::price_feed::{PriceFeedClient, PriceFeedConfig};
+
+async fn new_cache() -> Arc<CacheManager> {
+    Arc::new(
+        CacheManager::with_redis_url(
+            CacheConfig::default(),
+            "redis://127.0.0.1:1",
+            MemoryCache::new(100),
+            Duration::from_secs(60),
+        )
+        .await,
+    )
+}
+
+/// RPC client whose every request is refused
+fn unreachable_rpc() -> StellarRpcClient {
+    StellarRpcClient::new_with_retry_config(
+        "http://127.0.0.1:1".to_string(),
+        "http://127.0.0.1:1".to_string(),
+        false,
+        RetryConfig {
+            max_attempts: 1,
+            base_delay_ms: 0,
+            max_delay_ms: 0,
+        },
+    )
+}
+
+async fn router(cache: Arc<CacheManager>, rpc_client: StellarRpcClient) -> Router {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    let db = Arc::new(Database::new(pool));
+    // No asset mapping, so volumes use raw amounts without price lookups
+    let price_feed = Arc::new(PriceFeedClient::new(
+        PriceFeedConfig::default(),
+        HashMap::new(),
+    ));
+
+    Router::new()
+        .route("/api/corridors", axum::routing::get(list_corridors))
+        .with_state((db, cache, Arc::new(rpc_client), price_feed))
+        .layer(Extension(HealthScoreWeights::default()))
+}
+
+async fn list(app: Router) -> (StatusCode, Option<String>, Value) {
+    let response = app
+        .oneshot(Request::get("/api/corridors").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let warning = response
+        .headers()
+        .get(WARNING)
+        .map(|v| v.to_str().unwrap().to_string());
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, warning, serde_json::from_slice(&body).unwrap())
+}
+
+// One test, since the list handler's circuit breaker is shared across the
+// binary and must not open before the cache is primed
+#[tokio::test]
+async fn test_last_good_list_is_served_while_rpc_is_down() {
+    let cache = new_cache().await;
+
+    let (status, warning, primed) =
+        list(router(cache.clone(), StellarRpcClient::new_with_defaults(true)).await).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(warning.is_none());
+    assert_eq!(primed["stale"], false);
+    assert!(!primed["items"].as_array().unwrap().is_empty());
+
+    // Expire the regular entry so the next request goes to RPC
+    cache
+        .invalidate_pattern(&keys::corridor_pattern())
+        .await
+        .unwrap();
+
+    let (status, warning, stale) = list(router(cache, unreachable_rpc()).await).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(warning.as_deref(), Some("110 - \"Response is Stale\""));
+    assert_eq!(stale["stale"], true);
+    assert_eq!(stale["total"], primed["total"]);
+    assert_eq!(stale["items"][0]["id"], primed["items"][0]["id"]);
+
+    // With nothing cached there is nothing to fall back on
+    let (status, warning, empty) = list(router(new_cache().await, unreachable_rpc()).await).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(warning.is_none());
+    assert_eq!(empty["stale"], false);
+    assert_eq!(empty["total"], 0);
+    assert!(empty["items"].as_array().unwrap().is_empty());
+}
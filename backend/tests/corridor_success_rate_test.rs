@@ -0,0 +1,132 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Extension, Router,
+};
+use chrono::Utc;
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use std::time::Duration;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::api::corridors_cached::{get_corridor_detail, HealthScoreWeights};
+use stellar_insights_backend::cache::{CacheConfig, CacheManager};
+use stellar_insights_backend::cache_memory::MemoryCache;
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::models::PaymentRecord;
+use stellar_insights_backend::rpc::StellarRpcClient;
+use stellar_insights_backend::services::price_feed::{
+    default_asset_mapping, PriceFeedClient, PriceFeedConfig,
+};
+
+// Issuer outside the price feed mapping, so volumes use raw amounts offline
+const ISSUER: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+
+fn payment(id: usize) -> PaymentRecord {
+    let now = Utc::now();
+    PaymentRecord {
+        id: id.to_string(),
+        transaction_hash: format!("hash_{}", id),
+        source_account: "GSOURCE".to_string(),
+        destination_account: "GDEST".to_string(),
+        asset_type: "credit_alphanum4".to_string(),
+        asset_code: Some("USDC".to_string()),
+        asset_issuer: Some(ISSUER.to_string()),
+        operation_type: None,
+        source_asset_type: None,
+        source_asset_code: "USDC".to_string(),
+        source_asset_issuer: ISSUER.to_string(),
+        destination_asset_code: "USDC".to_string(),
+        destination_asset_issuer: ISSUER.to_string(),
+        amount: 10.0,
+        successful: true,
+        timestamp: Some(now),
+        submission_time: None,
+        confirmation_time: None,
+        created_at: now,
+    }
+}
+
+async fn record_transaction(pool: &SqlitePool, hash: &str, successful: bool) {
+    sqlx::query(
+        "INSERT OR IGNORE INTO ledgers (sequence, hash, close_time, transaction_count, operation_count)
+         VALUES (100, 'ledger_hash', '2026-01-01T00:00:00Z', 0, 0)",
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO transactions (hash, ledger_sequence, source_account, fee, operation_count, successful)
+         VALUES (?, 100, 'GSOURCE', 100, 1, ?)",
+    )
+    .bind(hash)
+    .bind(successful)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+async fn setup() -> Router {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    // hash_0 failed, hash_1 succeeded and the others were never ingested
+    record_transaction(&pool, "hash_0", false).await;
+    record_transaction(&pool, "hash_1", true).await;
+    let db = Arc::new(Database::new(pool));
+    db.save_payments((0..4).map(payment).collect())
+        .await
+        .unwrap();
+
+    let cache = Arc::new(
+        CacheManager::with_redis_url(
+            CacheConfig::default(),
+            "redis://127.0.0.1:1",
+            MemoryCache::new(100),
+            Duration::from_secs(60),
+        )
+        .await,
+    );
+    let rpc_client = Arc::new(StellarRpcClient::new_with_defaults(true));
+    let price_feed = Arc::new(PriceFeedClient::new(
+        PriceFeedConfig::default(),
+        default_asset_mapping(),
+    ));
+
+    Router::new()
+        .route(
+            "/api/corridors/:corridor_key",
+            axum::routing::get(get_corridor_detail),
+        )
+        .with_state((db, cache, rpc_client, price_feed))
+        .layer(Extension(HealthScoreWeights::default()))
+}
+
+#[tokio::test]
+async fn test_failed_payment_lowers_corridor_success_rate() {
+    let app = setup().await;
+
+    let uri = format!("/api/corridors/USDC%3A{}-%3EUSDC%3A{}", ISSUER, ISSUER);
+    let response = app
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let detail: Value = serde_json::from_slice(&body).unwrap();
+
+    let corridor = &detail["corridor"];
+    assert_eq!(corridor["total_attempts"], 4);
+    assert_eq!(corridor["successful_payments"], 3);
+    assert_eq!(corridor["failed_payments"], 1);
+    assert_eq!(corridor["success_rate"], 75.0);
+    // The failed payment moved no value
+    assert_eq!(corridor["liquidity_depth_usd"], 30.0);
+
+    let history = detail["historical_success_rate"].as_array().unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0]["success_rate"], 75.0);
+    assert_eq!(history[0]["attempts"], 4);
+}
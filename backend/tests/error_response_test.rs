@@ -1,5 +1,8 @@
+use axum::{http::StatusCode, response::IntoResponse};
 use std::collections::HashMap;
-use stellar_insights_backend::error::{ApiError, ErrorResponse};
+use stellar_insights_backend::error::{ApiError, ErrorCategory, ErrorResponse};
+use stellar_insights_backend::rate_limit::{RateLimitError, RateLimitInfo};
+use stellar_insights_backend::rpc::error::RpcError;
 
 #[test]
 fn test_not_found_error_creation() {
@@ -186,3 +189,123 @@ fn test_stack_trace_in_debug_mode() {
         assert!(response.error.stack_trace.is_none());
     }
 }
+
+#[tokio::test]
+async fn test_constructors_report_category_and_status() {
+    let cases = [
+        (
+            ApiError::bad_request("INVALID_INPUT", "Invalid input"),
+            ErrorCategory::Validation,
+            StatusCode::BAD_REQUEST,
+            "validation",
+        ),
+        (
+            ApiError::conflict_with_details("DUPLICATE", "Already exists", HashMap::new()),
+            ErrorCategory::Validation,
+            StatusCode::CONFLICT,
+            "validation",
+        ),
+        (
+            ApiError::not_found("CORRIDOR_NOT_FOUND", "Corridor not found"),
+            ErrorCategory::NotFound,
+            StatusCode::NOT_FOUND,
+            "not_found",
+        ),
+        (
+            ApiError::upstream("HORIZON_UNAVAILABLE", "Horizon is unavailable"),
+            ErrorCategory::Upstream,
+            StatusCode::BAD_GATEWAY,
+            "upstream",
+        ),
+        (
+            ApiError::rate_limited("RATE_LIMIT_EXCEEDED", "Too many requests"),
+            ErrorCategory::RateLimited,
+            StatusCode::TOO_MANY_REQUESTS,
+            "rate_limited",
+        ),
+        (
+            ApiError::internal("INTERNAL_ERROR", "Something went wrong"),
+            ErrorCategory::Internal,
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "internal",
+        ),
+        (
+            ApiError::unauthorized("INVALID_TOKEN", "Invalid authentication token"),
+            ErrorCategory::Auth,
+            StatusCode::UNAUTHORIZED,
+            "auth",
+        ),
+    ];
+
+    for (error, category, status, serialized) in cases {
+        assert_eq!(error.category(), category);
+
+        let response = error.into_response();
+        assert_eq!(response.status(), status, "{:?}", category);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["error"]["category"], serialized);
+    }
+}
+
+#[test]
+fn test_from_anyhow_error_is_internal() {
+    let api_error: ApiError = anyhow::anyhow!("Test error").into();
+    assert_eq!(api_error.category(), ErrorCategory::Internal);
+}
+
+#[tokio::test]
+async fn test_rpc_errors_are_upstream() {
+    let direct: ApiError = RpcError::ServerError {
+        status: 503,
+        message: "horizon unavailable".to_string(),
+    }
+    .into();
+    let wrapped: ApiError = anyhow::Error::new(RpcError::CircuitBreakerOpen).into();
+
+    for error in [direct, wrapped] {
+        assert_eq!(error.category(), ErrorCategory::Upstream);
+
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["error"]["code"], "RPC_ERROR");
+        assert_eq!(json["error"]["category"], "upstream");
+        // The upstream cause is not leaked to clients
+        assert!(!json["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("horizon"));
+    }
+}
+
+#[tokio::test]
+async fn test_rate_limit_rejection_reports_category() {
+    let response = RateLimitError {
+        info: RateLimitInfo {
+            limit: 10,
+            remaining: 0,
+            reset_after: 42,
+            is_whitelisted: false,
+            client_id: None,
+        },
+    }
+    .into_response();
+
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(response.headers()["RateLimit-Limit"], "10");
+    assert_eq!(response.headers()["RateLimit-Reset"], "42");
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["error"]["code"], "RATE_LIMIT_EXCEEDED");
+    assert_eq!(json["error"]["category"], "rate_limited");
+    assert_eq!(json["error"]["details"]["limit"], 10);
+    assert_eq!(json["error"]["details"]["reset_after"], 42);
+}
@@ -1,7 +1,16 @@
-use chrono::Utc;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use chrono::{DateTime, Duration, Utc};
 use sqlx::SqlitePool;
-use stellar_insights_backend::rpc::{FeeBumpTransactionInfo, HorizonTransaction, InnerTransaction};
+use std::sync::Arc;
+use stellar_insights_backend::api::fee_bump;
+use stellar_insights_backend::rpc::{
+    FeeBumpTransactionInfo, HorizonTransaction, InnerTransaction, StellarRpcClient,
+};
 use stellar_insights_backend::services::fee_bump_tracker::FeeBumpTrackerService;
+use tower::util::ServiceExt;
 
 #[sqlx::test]
 async fn test_fee_bump_tracker_process_transactions(pool: SqlitePool) {
@@ -77,3 +86,106 @@ async fn test_fee_bump_tracker_process_transactions(pool: SqlitePool) {
     assert_eq!(stats.min_fee_charged, 100);
     assert_eq!(stats.unique_fee_sources, 1);
 }
+
+async fn insert_ledger(pool: &SqlitePool, sequence: i64) {
+    sqlx::query("INSERT INTO ledgers (sequence, hash, close_time, transaction_count, operation_count) VALUES ($1, 'ledger_hash', '2026-01-01T00:00:00Z', 0, 0)")
+        .bind(sequence)
+        .execute(pool)
+        .await
+        .expect("Failed to insert mock ledger");
+}
+
+#[sqlx::test]
+async fn test_fee_bump_analytics_from_ledger_transactions(pool: SqlitePool) {
+    let service = FeeBumpTrackerService::new(pool.clone());
+    let rpc_client = StellarRpcClient::new_with_defaults(true);
+    insert_ledger(&pool, 100).await;
+    insert_ledger(&pool, 101).await;
+
+    // Mock ledgers alternate fee bumps: transactions 0, 2 and 4 of 5 are bumped
+    let first = rpc_client.fetch_transactions_for_ledger(100).await.unwrap();
+    assert_eq!(service.process_transactions(&first).await.unwrap(), 3);
+
+    // A second ledger whose fee bumps are paid by another account
+    let second: Vec<HorizonTransaction> = rpc_client
+        .fetch_transactions_for_ledger(101)
+        .await
+        .unwrap()
+        .into_iter()
+        .take(3)
+        .map(|mut tx| {
+            tx.hash = format!("{}_101", tx.hash);
+            tx.fee_account = Some("GYY".to_string());
+            tx.fee_charged = Some("300".to_string());
+            tx
+        })
+        .collect();
+    assert_eq!(service.process_transactions(&second).await.unwrap(), 2);
+
+    let created_at: DateTime<Utc> = first[0].created_at.parse().unwrap();
+    let analytics = service
+        .get_fee_bump_analytics(
+            created_at - Duration::hours(1),
+            created_at + Duration::hours(1),
+            10,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(analytics.fee_bump_count, 5);
+    assert_eq!(analytics.avg_max_fee, 1000.0);
+    assert_eq!(analytics.avg_fee_charged, 180.0);
+    assert_eq!(analytics.avg_fee_delta, 820.0);
+    let sources: Vec<(&str, i64, i64)> = analytics
+        .top_fee_sources
+        .iter()
+        .map(|s| (s.fee_source.as_str(), s.fee_bump_count, s.total_fee_charged))
+        .collect();
+    assert_eq!(sources, vec![("GXX", 3, 300), ("GYY", 2, 600)]);
+
+    // Limited to the busiest account
+    let top_one = service
+        .get_fee_bump_analytics(
+            created_at - Duration::hours(1),
+            created_at + Duration::hours(1),
+            1,
+        )
+        .await
+        .unwrap();
+    assert_eq!(top_one.top_fee_sources.len(), 1);
+
+    // Nothing outside the window
+    let later = service
+        .get_fee_bump_analytics(
+            created_at + Duration::hours(1),
+            created_at + Duration::hours(2),
+            10,
+        )
+        .await
+        .unwrap();
+    assert_eq!(later.fee_bump_count, 0);
+    assert_eq!(later.avg_fee_delta, 0.0);
+    assert!(later.top_fee_sources.is_empty());
+}
+
+#[sqlx::test]
+async fn test_fee_bump_analytics_rejects_unknown_window(pool: SqlitePool) {
+    let app = fee_bump::routes(Arc::new(FeeBumpTrackerService::new(pool)));
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::get("/analytics?window=1y")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = app
+        .oneshot(Request::get("/analytics").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
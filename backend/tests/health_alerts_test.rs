@@ -0,0 +1,116 @@
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::broadcast::error::TryRecvError;
+
+use stellar_insights_backend::alerts::{AlertManager, AlertType};
+use stellar_insights_backend::api::corridors_cached::HealthScoreWeights;
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::models::alerts::CreateAlertRuleRequest;
+use stellar_insights_backend::services::health_alerts::HealthAlertEngine;
+
+const USER_ID: &str = "user-1";
+const CORRIDOR: &str = "USDC:issuer->XLM:native";
+
+fn scores(score: f64) -> HashMap<String, f64> {
+    HashMap::from([(CORRIDOR.to_string(), score)])
+}
+
+#[tokio::test]
+async fn test_health_score_alert_fires_once_while_below_threshold() {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    sqlx::query("INSERT INTO users (id, username) VALUES (?, ?)")
+        .bind(USER_ID)
+        .bind("alice")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let db = Arc::new(Database::new(pool));
+
+    db.create_alert_rule(
+        USER_ID,
+        CreateAlertRuleRequest {
+            corridor_id: Some(CORRIDOR.to_string()),
+            metric_type: "health_score".to_string(),
+            condition: "below".to_string(),
+            threshold: 50.0,
+            notify_email: false,
+            notify_webhook: false,
+            notify_in_app: true,
+        },
+    )
+    .await
+    .unwrap();
+
+    let (alert_manager, mut alerts) = AlertManager::new();
+    let engine = HealthAlertEngine::new(
+        Arc::clone(&db),
+        Arc::new(alert_manager),
+        HealthScoreWeights::default(),
+    );
+
+    assert_eq!(engine.evaluate(&scores(80.0)).await.unwrap(), 0);
+    assert!(matches!(alerts.try_recv(), Err(TryRecvError::Empty)));
+
+    // Dropping below the threshold dispatches one alert
+    assert_eq!(engine.evaluate(&scores(40.0)).await.unwrap(), 1);
+    let alert = alerts.try_recv().unwrap();
+    assert!(matches!(
+        alert.alert_type,
+        AlertType::HealthScoreDegradation
+    ));
+    assert_eq!(alert.corridor_id, CORRIDOR);
+    assert_eq!(alert.new_value, 40.0);
+
+    // Staying below does not fire again
+    assert_eq!(engine.evaluate(&scores(35.0)).await.unwrap(), 0);
+    assert!(matches!(alerts.try_recv(), Err(TryRecvError::Empty)));
+
+    let history = db.get_alert_history_for_user(USER_ID, 10).await.unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].corridor_id.as_deref(), Some(CORRIDOR));
+
+    // Recovering re-arms the rule for the next degradation
+    assert_eq!(engine.evaluate(&scores(70.0)).await.unwrap(), 0);
+    assert_eq!(engine.evaluate(&scores(45.0)).await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_health_score_drop_rule_compares_with_previous_cycle() {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    sqlx::query("INSERT INTO users (id, username) VALUES (?, ?)")
+        .bind(USER_ID)
+        .bind("alice")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let db = Arc::new(Database::new(pool));
+
+    db.create_alert_rule(
+        USER_ID,
+        CreateAlertRuleRequest {
+            corridor_id: None,
+            metric_type: "health_score".to_string(),
+            condition: "drop_pct".to_string(),
+            threshold: 20.0,
+            notify_email: false,
+            notify_webhook: false,
+            notify_in_app: true,
+        },
+    )
+    .await
+    .unwrap();
+
+    let (alert_manager, mut alerts) = AlertManager::new();
+    let engine = HealthAlertEngine::new(db, Arc::new(alert_manager), HealthScoreWeights::default());
+
+    // The first cycle has nothing to compare against
+    assert_eq!(engine.evaluate(&scores(90.0)).await.unwrap(), 0);
+    // A 10% dip stays under the rule's 20%
+    assert_eq!(engine.evaluate(&scores(81.0)).await.unwrap(), 0);
+    // A 25% fall fires
+    assert_eq!(engine.evaluate(&scores(60.75)).await.unwrap(), 1);
+    assert_eq!(alerts.try_recv().unwrap().old_value, 81.0);
+}
@@ -0,0 +1,42 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    routing::get,
+    Extension, Router,
+};
+use serde_json::Value;
+use std::sync::Arc;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::handlers::health_check;
+use stellar_insights_backend::shutdown::{ShutdownConfig, ShutdownCoordinator};
+
+async fn health(app: Router) -> (StatusCode, Value) {
+    let response = app
+        .oneshot(Request::get("/health").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn test_health_returns_503_while_draining() {
+    let coordinator = Arc::new(ShutdownCoordinator::new(ShutdownConfig::default()));
+    let app = Router::new()
+        .route("/health", get(health_check))
+        .layer(Extension(Arc::clone(&coordinator)));
+
+    let (status, body) = health(app.clone()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "healthy");
+
+    coordinator.begin_draining();
+
+    let (status, body) = health(app).await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(body["status"], "draining");
+}
@@ -0,0 +1,98 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use chrono::{Duration, Utc};
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::api::ingestion_status::{self, IngestionStatusState};
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::rpc::StellarRpcClient;
+
+async fn ingestion_status(state: IngestionStatusState) -> Value {
+    let response = ingestion_status::routes(state)
+        .oneshot(
+            Request::get("/api/ingestion/status")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+fn task<'a>(body: &'a Value, name: &str) -> &'a Value {
+    body["tasks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|task| task["task_name"] == name)
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_ingestion_status_reports_cursor_lag() {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    let db = Arc::new(Database::new(pool.clone()));
+    let rpc_client = Arc::new(StellarRpcClient::new_with_defaults(true));
+    let latest_ledger = rpc_client.check_health().await.unwrap().latest_ledger;
+
+    // A paging token ten ledgers behind the network
+    let cursor = ((latest_ledger - 10) << 32).to_string();
+    db.update_ingestion_cursor("incremental_payments", &cursor)
+        .await
+        .unwrap();
+
+    // A cursor that is not a paging token and has not moved in an hour
+    sqlx::query(
+        "INSERT INTO ingestion_state (task_name, last_cursor, updated_at) VALUES (?, ?, ?)",
+    )
+    .bind("legacy_task")
+    .bind("now")
+    .bind(Utc::now() - Duration::hours(1))
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let body = ingestion_status(IngestionStatusState {
+        db: Arc::clone(&db),
+        rpc_client: Arc::clone(&rpc_client),
+        lag_threshold_ledgers: 5,
+    })
+    .await;
+
+    assert_eq!(body["latest_ledger"], latest_ledger);
+    assert_eq!(body["lag_threshold_ledgers"], 5);
+
+    let payments = task(&body, "incremental_payments");
+    assert_eq!(payments["last_cursor"], cursor);
+    assert_eq!(payments["cursor_ledger"], latest_ledger - 10);
+    assert_eq!(payments["ledger_lag"], 10);
+    assert!(payments["updated_at"].is_string());
+    assert!(payments["age_seconds"].as_i64().unwrap() < 60);
+    assert_eq!(payments["lagging"], true);
+
+    // Without a ledger, lag is judged by how long the cursor has been idle
+    let legacy = task(&body, "legacy_task");
+    assert!(legacy["cursor_ledger"].is_null());
+    assert!(legacy["ledger_lag"].is_null());
+    assert!(legacy["age_seconds"].as_i64().unwrap() >= 3600);
+    assert_eq!(legacy["lagging"], true);
+
+    // A generous threshold clears the ledger-based flag
+    let body = ingestion_status(IngestionStatusState {
+        db,
+        rpc_client,
+        lag_threshold_ledgers: 1_000,
+    })
+    .await;
+    assert_eq!(task(&body, "incremental_payments")["lagging"], false);
+}
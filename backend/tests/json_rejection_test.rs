@@ -0,0 +1,111 @@
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+    routing::post,
+    Router,
+};
+use serde_json::Value;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::handlers::{create_anchor, create_corridor};
+
+mod common;
+
+async fn create_test_router() -> Router {
+    let state = common::test_app_state().await;
+    Router::new()
+        .route("/api/anchors", post(create_anchor))
+        .route("/api/corridors", post(create_corridor))
+        .with_state(state)
+}
+
+async fn post_raw(
+    app: &Router,
+    uri: &str,
+    content_type: Option<&str>,
+    body: &str,
+) -> (StatusCode, Value) {
+    let mut request = Request::builder().method("POST").uri(uri);
+    if let Some(content_type) = content_type {
+        request = request.header(header::CONTENT_TYPE, content_type);
+    }
+    let response = app
+        .clone()
+        .oneshot(request.body(Body::from(body.to_string())).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+#[tokio::test]
+async fn test_malformed_json_returns_structured_error() {
+    let app = create_test_router().await;
+
+    let (status, body) = post_raw(
+        &app,
+        "/api/anchors",
+        Some("application/json"),
+        "{\n  \"name\": \"Anchor\",\n  \"stellar_account\": \n}",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["code"], "MALFORMED_JSON");
+    let message = body["error"]["message"].as_str().unwrap();
+    assert!(message.contains("line 4"), "{}", message);
+    assert_eq!(body["error"]["details"]["line"], 4);
+    assert!(body["error"]["details"]["column"].is_u64());
+}
+
+#[tokio::test]
+async fn test_wrong_shape_json_returns_structured_error() {
+    let app = create_test_router().await;
+
+    let (status, body) = post_raw(
+        &app,
+        "/api/corridors",
+        Some("application/json"),
+        r#"{"source_asset_code": 42}"#,
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["code"], "INVALID_JSON_BODY");
+    assert!(body["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("source_asset_code"));
+}
+
+#[tokio::test]
+async fn test_missing_content_type_returns_structured_error() {
+    let app = create_test_router().await;
+
+    let (status, body) = post_raw(&app, "/api/anchors", None, r#"{"name": "Anchor"}"#).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["code"], "MISSING_JSON_CONTENT_TYPE");
+}
+
+#[tokio::test]
+async fn test_module_routes_return_structured_json_errors() {
+    let app = Router::new().nest(
+        "/api/network",
+        stellar_insights_backend::api::network::routes(),
+    );
+
+    let (status, body) = post_raw(
+        &app,
+        "/api/network/switch",
+        Some("application/json"),
+        r#"{"network": "#,
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["code"], "MALFORMED_JSON");
+}
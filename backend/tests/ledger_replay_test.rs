@@ -0,0 +1,104 @@
+use axum::{
+    body::Body,
+    http::{header::CONTENT_TYPE, Request, StatusCode},
+    Router,
+};
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::api::ledger_replay::{routes, LedgerReplayState};
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::ingestion::{DataIngestionService, MAX_REPLAY_LEDGERS};
+use stellar_insights_backend::rpc::StellarRpcClient;
+use stellar_insights_backend::services::aggregation::{AggregationConfig, AggregationService};
+use stellar_insights_backend::services::fee_bump_tracker::FeeBumpTrackerService;
+
+async fn setup() -> (Router, SqlitePool) {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    let db = Arc::new(Database::new(pool.clone()));
+    let rpc_client = Arc::new(StellarRpcClient::new_with_defaults(true));
+
+    let ingestion = Arc::new(DataIngestionService::new(rpc_client, Arc::clone(&db)));
+    ingestion.set_aggregation(Arc::new(AggregationService::new(
+        db,
+        AggregationConfig::default(),
+    )));
+
+    let app = routes(LedgerReplayState {
+        ingestion,
+        fee_bump_tracker: Arc::new(FeeBumpTrackerService::new(pool.clone())),
+    });
+    (app, pool)
+}
+
+async fn replay(app: Router, body: Value) -> (StatusCode, Value) {
+    let response = app
+        .oneshot(
+            Request::post("/api/replay/ledgers")
+                .header(CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+async fn payment_count(pool: &SqlitePool) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM payments")
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+/// Transactions counted in corridor_metrics_hourly for the mock payments' hour
+async fn hourly_transaction_total(pool: &SqlitePool) -> i64 {
+    sqlx::query_scalar(
+        "SELECT COALESCE(SUM(total_transactions), 0) FROM corridor_metrics_hourly
+         WHERE hour_bucket = '2026-01-22T10:00:00+00:00'",
+    )
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_replay_reports_processed_counts() {
+    let (app, pool) = setup().await;
+
+    let (status, body) = replay(app.clone(), json!({ "start": 51565800, "end": 51565802 })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["ledgers"], 3);
+    assert_eq!(body["payments"], 15);
+    assert_eq!(body["transactions"], 15);
+    assert_eq!(payment_count(&pool).await, 15);
+    assert!(body["hourly_metrics"].as_u64().unwrap() > 0);
+    assert_eq!(hourly_transaction_total(&pool).await, 15);
+
+    // Replaying the same range again must not duplicate anything
+    let (status, body) = replay(app, json!({ "start": 51565800, "end": 51565802 })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["payments"], 15);
+    assert_eq!(payment_count(&pool).await, 15);
+    assert_eq!(hourly_transaction_total(&pool).await, 15);
+}
+
+#[tokio::test]
+async fn test_replay_rejects_invalid_ranges() {
+    let (app, _pool) = setup().await;
+
+    let (status, body) = replay(app.clone(), json!({ "start": 10, "end": 9 })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["code"], "INVALID_LEDGER_RANGE");
+
+    let (status, body) = replay(app, json!({ "start": 1, "end": MAX_REPLAY_LEDGERS + 1 })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["code"], "LEDGER_RANGE_TOO_LARGE");
+}
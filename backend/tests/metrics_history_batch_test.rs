@@ -0,0 +1,60 @@
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use stellar_insights_backend::database::{AnchorMetricsParams, Database};
+use stellar_insights_backend::models::CreateAnchorRequest;
+
+fn params(anchor_id: Uuid, i: i64) -> AnchorMetricsParams {
+    AnchorMetricsParams {
+        anchor_id,
+        success_rate: 99.0,
+        failure_rate: 1.0,
+        reliability_score: 90.0,
+        raw_reliability_score: 90.0,
+        total_transactions: i,
+        successful_transactions: i,
+        failed_transactions: 0,
+        avg_settlement_time_ms: Some(1000),
+        volume_usd: Some(i as f64),
+    }
+}
+
+#[tokio::test]
+async fn test_batch_records_every_metrics_history_row() {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    let db = Database::new(pool.clone());
+    let anchor = db
+        .create_anchor(CreateAnchorRequest {
+            name: "Anchor".to_string(),
+            stellar_account: "GANCHOR".to_string(),
+            home_domain: None,
+        })
+        .await
+        .unwrap();
+    let anchor_id = Uuid::parse_str(&anchor.id).unwrap();
+
+    // More rows than fit in one INSERT, so the batch spans several statements
+    let batch = (0..100).map(|i| params(anchor_id, i)).collect();
+    assert_eq!(
+        db.record_anchor_metrics_history_batch(batch).await.unwrap(),
+        100
+    );
+
+    let totals: Vec<i64> = sqlx::query_scalar(
+        "SELECT total_transactions FROM anchor_metrics_history
+         WHERE anchor_id = ? ORDER BY total_transactions",
+    )
+    .bind(&anchor.id)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(totals, (0..100).collect::<Vec<i64>>());
+
+    assert_eq!(
+        db.record_anchor_metrics_history_batch(Vec::new())
+            .await
+            .unwrap(),
+        0
+    );
+}
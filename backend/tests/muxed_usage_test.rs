@@ -0,0 +1,98 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    routing::get,
+    Router,
+};
+use serde_json::Value;
+use sqlx::SqlitePool;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::handlers::get_muxed_account_usage;
+
+mod common;
+
+const BASE: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+/// Sub-accounts 1 and 2 of `BASE`
+const MUXED_1: &str = "MBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLEAAAAAAAAAAAAFJC4";
+const MUXED_2: &str = "MBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLEAAAAAAAAAAAAIYR4";
+/// Sub-account 0 of a different base account
+const OTHER_MUXED: &str = "MA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVAAAAAAAAAAAAAJLK";
+
+async fn setup() -> (Router, SqlitePool) {
+    let state = common::test_app_state().await;
+    let pool = state.db.pool().clone();
+    let app = Router::new()
+        .route(
+            "/api/analytics/muxed/:base_account",
+            get(get_muxed_account_usage),
+        )
+        .with_state(state);
+    (app, pool)
+}
+
+async fn insert_payment(pool: &SqlitePool, id: &str, source: &str, destination: &str) {
+    sqlx::query(
+        "INSERT INTO payments (id, transaction_hash, source_account, destination_account, asset_type, amount, created_at)
+         VALUES (?, ?, ?, ?, 'native', 10.0, CURRENT_TIMESTAMP)",
+    )
+    .bind(id)
+    .bind(format!("hash_{}", id))
+    .bind(source)
+    .bind(destination)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+async fn get_json(app: &Router, uri: &str) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+#[tokio::test]
+async fn test_muxed_destinations_are_attributed_to_base_account() {
+    let (app, pool) = setup().await;
+    insert_payment(&pool, "p1", "GSENDER", MUXED_1).await;
+    insert_payment(&pool, "p2", "GSENDER", MUXED_2).await;
+    insert_payment(&pool, "p3", "GSENDER", MUXED_2).await;
+    insert_payment(&pool, "p4", MUXED_2, "GRECEIVER").await;
+    insert_payment(&pool, "p5", "GSENDER", OTHER_MUXED).await;
+    // Direct payments to the base account are not sub-account usage
+    insert_payment(&pool, "p6", "GSENDER", BASE).await;
+
+    let (status, body) = get_json(&app, &format!("/api/analytics/muxed/{}", BASE)).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["base_account"], BASE);
+    assert_eq!(body["sub_account_count"], 2);
+    assert_eq!(body["total_payments"], 4);
+
+    let sub_accounts = body["sub_accounts"].as_array().unwrap();
+    assert_eq!(sub_accounts[0]["account_address"], MUXED_2);
+    assert_eq!(sub_accounts[0]["muxed_id"], 2);
+    assert_eq!(sub_accounts[0]["base_account"], BASE);
+    assert_eq!(sub_accounts[0]["payment_count_as_destination"], 2);
+    assert_eq!(sub_accounts[0]["payment_count_as_source"], 1);
+    assert_eq!(sub_accounts[1]["account_address"], MUXED_1);
+    assert_eq!(sub_accounts[1]["muxed_id"], 1);
+    assert_eq!(sub_accounts[1]["total_payments"], 1);
+}
+
+#[tokio::test]
+async fn test_muxed_usage_rejects_non_account_id() {
+    let (app, _pool) = setup().await;
+
+    let (status, body) = get_json(&app, &format!("/api/analytics/muxed/{}", MUXED_1)).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["code"], "INVALID_ACCOUNT");
+}
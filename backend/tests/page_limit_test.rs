@@ -0,0 +1,88 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::api::corridors::list_corridors;
+use stellar_insights_backend::api::limits::max_page_limit;
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::handlers::list_anchors;
+use stellar_insights_backend::ingestion::DataIngestionService;
+use stellar_insights_backend::rpc::StellarRpcClient;
+use stellar_insights_backend::state::AppState;
+use stellar_insights_backend::websocket::WsState;
+
+async fn create_test_router() -> Router {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    let db = Arc::new(Database::new(pool));
+
+    let ws_state = Arc::new(WsState::new());
+    let rpc_client = Arc::new(StellarRpcClient::new_with_defaults(true));
+    let ingestion = Arc::new(DataIngestionService::new(rpc_client, Arc::clone(&db)));
+    let state = AppState {
+        db,
+        ws_state,
+        ingestion,
+    };
+    Router::new()
+        .route("/api/anchors", axum::routing::get(list_anchors))
+        .route("/api/corridors", axum::routing::get(list_corridors))
+        .with_state(state)
+}
+
+async fn get(app: &Router, uri: &str) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+#[tokio::test]
+async fn test_oversized_limit_rejected_on_anchors_and_corridors() {
+    let app = create_test_router().await;
+
+    for path in ["/api/anchors", "/api/corridors"] {
+        let (status, body) = get(&app, &format!("{}?limit=1000000", path)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{}", path);
+        assert_eq!(body["error"]["code"], "LIMIT_TOO_LARGE", "{}", path);
+        assert_eq!(body["error"]["details"]["max_limit"], max_page_limit());
+    }
+}
+
+#[tokio::test]
+async fn test_non_positive_limit_rejected_on_anchors_and_corridors() {
+    let app = create_test_router().await;
+
+    for path in ["/api/anchors", "/api/corridors"] {
+        for limit in [0, -1] {
+            let (status, body) = get(&app, &format!("{}?limit={}", path, limit)).await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{}", path);
+            assert_eq!(body["error"]["code"], "LIMIT_TOO_SMALL", "{}", path);
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_limit_at_max_and_default_are_accepted() {
+    let app = create_test_router().await;
+
+    for path in ["/api/anchors", "/api/corridors"] {
+        let (status, _) = get(&app, &format!("{}?limit={}", path, max_page_limit())).await;
+        assert_eq!(status, StatusCode::OK, "{}", path);
+
+        let (status, _) = get(&app, path).await;
+        assert_eq!(status, StatusCode::OK, "{}", path);
+    }
+}
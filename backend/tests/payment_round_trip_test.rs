@@ -0,0 +1,136 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Extension, Router,
+};
+use chrono::{Duration as ChronoDuration, Utc};
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use std::time::Duration;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::api::corridors_cached::{get_corridor_detail, HealthScoreWeights};
+use stellar_insights_backend::cache::{CacheConfig, CacheManager};
+use stellar_insights_backend::cache_memory::MemoryCache;
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::ingestion::to_payment_record;
+use stellar_insights_backend::rpc::{Payment, StellarRpcClient};
+use stellar_insights_backend::services::price_feed::{
+    default_asset_mapping, PriceFeedClient, PriceFeedConfig,
+};
+
+// Issuers outside the price feed mapping, so volumes use raw amounts offline
+const EURT_ISSUER: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+const USDC_ISSUER: &str = "GCKFBEIYTKP5RDBQMTVVALONAOPBXICILMAFKKGBMOPKMZ3NNYHQ5OSG";
+
+fn path_payment() -> Payment {
+    Payment {
+        id: "path_1".to_string(),
+        paging_token: "path_1".to_string(),
+        transaction_hash: "hash_path_1".to_string(),
+        source_account: "GSOURCE".to_string(),
+        destination: "GDEST".to_string(),
+        asset_type: "credit_alphanum4".to_string(),
+        asset_code: Some("USDC".to_string()),
+        asset_issuer: Some(USDC_ISSUER.to_string()),
+        amount: "95.0".to_string(),
+        created_at: (Utc::now() - ChronoDuration::minutes(5)).to_rfc3339(),
+        operation_type: Some("path_payment_strict_send".to_string()),
+        source_asset_type: Some("credit_alphanum4".to_string()),
+        source_asset_code: Some("EURT".to_string()),
+        source_asset_issuer: Some(EURT_ISSUER.to_string()),
+        source_amount: Some("100.0".to_string()),
+        from: Some("GSOURCE".to_string()),
+        to: Some("GDEST".to_string()),
+        asset_balance_changes: None,
+    }
+}
+
+async fn setup() -> (Router, Arc<Database>) {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    let db = Arc::new(Database::new(pool));
+    let record = to_payment_record(path_payment()).unwrap();
+    db.save_payments(vec![record]).await.unwrap();
+
+    let cache = Arc::new(
+        CacheManager::with_redis_url(
+            CacheConfig::default(),
+            "redis://127.0.0.1:1",
+            MemoryCache::new(100),
+            Duration::from_secs(60),
+        )
+        .await,
+    );
+    let rpc_client = Arc::new(StellarRpcClient::new_with_defaults(true));
+    let price_feed = Arc::new(PriceFeedClient::new(
+        PriceFeedConfig::default(),
+        default_asset_mapping(),
+    ));
+
+    let app = Router::new()
+        .route(
+            "/api/corridors/:corridor_key",
+            axum::routing::get(get_corridor_detail),
+        )
+        .with_state((Arc::clone(&db), cache, rpc_client, price_feed))
+        .layer(Extension(HealthScoreWeights::default()));
+    (app, db)
+}
+
+#[tokio::test]
+async fn test_path_payment_keeps_source_asset_when_stored() {
+    let (_app, db) = setup().await;
+
+    let stored = db
+        .get_payments_since(Utc::now() - ChronoDuration::hours(1), 10)
+        .await
+        .unwrap();
+    assert_eq!(stored.len(), 1);
+    let record = &stored[0];
+    assert_eq!(
+        record.operation_type.as_deref(),
+        Some("path_payment_strict_send")
+    );
+    assert_eq!(
+        record.source_asset_type.as_deref(),
+        Some("credit_alphanum4")
+    );
+    assert_eq!(record.source_asset_code, "EURT");
+    assert_eq!(record.source_asset_issuer, EURT_ISSUER);
+    assert_eq!(record.asset_code.as_deref(), Some("USDC"));
+}
+
+#[tokio::test]
+async fn test_stored_path_payment_is_served_under_its_cross_asset_corridor() {
+    let (app, _db) = setup().await;
+
+    let uri = format!(
+        "/api/corridors/EURT%3A{}-%3EUSDC%3A{}",
+        EURT_ISSUER, USDC_ISSUER
+    );
+    let response = app
+        .clone()
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let detail: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(detail["corridor"]["source_asset"], "EURT");
+    assert_eq!(detail["corridor"]["destination_asset"], "USDC");
+
+    // It is no longer mistaken for a same-asset USDC payment
+    let uri = format!(
+        "/api/corridors/USDC%3A{}-%3EUSDC%3A{}",
+        USDC_ISSUER, USDC_ISSUER
+    );
+    let response = app
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
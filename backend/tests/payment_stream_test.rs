@@ -0,0 +1,148 @@
+use axum::{extract::Query, http::header, routing::get, Router};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::ingestion::stream::IngestionMode;
+use stellar_insights_backend::ingestion::{DataIngestionService, PAYMENT_CURSOR_TASK};
+use stellar_insights_backend::rpc::{Payment, StellarRpcClient};
+
+const ISSUER: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+
+fn payment(paging_token: &str) -> Payment {
+    Payment {
+        id: paging_token.to_string(),
+        paging_token: paging_token.to_string(),
+        transaction_hash: format!("hash_{}", paging_token),
+        source_account: "GSOURCE".to_string(),
+        destination: "GDEST".to_string(),
+        asset_type: "credit_alphanum4".to_string(),
+        asset_code: Some("USDC".to_string()),
+        asset_issuer: Some(ISSUER.to_string()),
+        amount: "10.0".to_string(),
+        created_at: "2026-01-01T00:00:00Z".to_string(),
+        operation_type: Some("payment".to_string()),
+        source_asset_type: None,
+        source_asset_code: None,
+        source_asset_issuer: None,
+        source_amount: None,
+        from: Some("GSOURCE".to_string()),
+        to: Some("GDEST".to_string()),
+        asset_balance_changes: None,
+    }
+}
+
+fn event(paging_token: &str) -> String {
+    format!(
+        "id: {}\ndata: {}\n\n",
+        paging_token,
+        serde_json::to_string(&payment(paging_token)).unwrap()
+    )
+}
+
+/// Horizon stub serving a finite event stream per cursor, so each connection
+/// drops once its events are sent. Records the cursor of every connection.
+async fn spawn_stream_stub() -> (String, Arc<Mutex<Vec<String>>>) {
+    let cursors = Arc::new(Mutex::new(Vec::new()));
+    let seen = Arc::clone(&cursors);
+    let app = Router::new().route(
+        "/payments",
+        get(move |Query(query): Query<HashMap<String, String>>| {
+            let seen = Arc::clone(&seen);
+            async move {
+                let cursor = query.get("cursor").cloned().unwrap_or_default();
+                seen.lock().unwrap().push(cursor.clone());
+                let body = match cursor.as_str() {
+                    "now" => format!(
+                        "retry: 1000\ndata: \"hello\"\n\n{}{}",
+                        event("101"),
+                        event("102")
+                    ),
+                    "102" => format!(": keep-alive\n\n{}", event("103")),
+                    _ => String::new(),
+                };
+                ([(header::CONTENT_TYPE, "text/event-stream")], body)
+            }
+        }),
+    );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    (format!("http://{}", addr), cursors)
+}
+
+async fn setup() -> (
+    DataIngestionService,
+    Arc<Database>,
+    SqlitePool,
+    Arc<Mutex<Vec<String>>>,
+) {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    let db = Arc::new(Database::new(pool.clone()));
+    let (horizon_url, cursors) = spawn_stream_stub().await;
+    let rpc_client = Arc::new(StellarRpcClient::new(
+        "http://127.0.0.1:1".to_string(),
+        horizon_url,
+        false,
+    ));
+    let service =
+        DataIngestionService::new(rpc_client, Arc::clone(&db)).with_mode(IngestionMode::Stream);
+    (service, db, pool, cursors)
+}
+
+async fn payment_count(pool: &SqlitePool) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM payments")
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_streamed_payments_are_saved_and_cursor_advances() {
+    let (service, db, pool, cursors) = setup().await;
+
+    assert_eq!(service.stream_payments().await.unwrap(), 2);
+    assert_eq!(
+        db.get_ingestion_cursor(PAYMENT_CURSOR_TASK).await.unwrap(),
+        Some("102".to_string())
+    );
+
+    // The next connection resumes from the last persisted payment
+    assert_eq!(service.stream_payments().await.unwrap(), 1);
+    assert_eq!(
+        db.get_ingestion_cursor(PAYMENT_CURSOR_TASK).await.unwrap(),
+        Some("103".to_string())
+    );
+
+    assert_eq!(*cursors.lock().unwrap(), vec!["now", "102"]);
+    assert_eq!(payment_count(&pool).await, 3);
+}
+
+#[tokio::test]
+async fn test_dropped_stream_reconnects_from_last_cursor() {
+    let (service, _db, pool, cursors) = setup().await;
+    let service = Arc::new(service);
+
+    let runner = Arc::clone(&service);
+    let task =
+        tokio::spawn(async move { runner.run_payment_stream(Duration::from_millis(10)).await });
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while cursors.lock().unwrap().len() < 3 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("stream was not reopened");
+    task.abort();
+
+    let cursors = cursors.lock().unwrap().clone();
+    assert_eq!(&cursors[..3], &["now", "102", "103"]);
+    assert_eq!(payment_count(&pool).await, 3);
+}
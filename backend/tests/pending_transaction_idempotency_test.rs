@@ -0,0 +1,122 @@
+use axum::{
+    body::Body,
+    http::{header::CONTENT_TYPE, Request, StatusCode},
+    Router,
+};
+use serde_json::json;
+use sqlx::SqlitePool;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::api::transactions;
+use stellar_insights_backend::database::Database;
+
+mod common;
+
+const SOURCE: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+const OTHER_SOURCE: &str = "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+const XDR: &str = "AAAAAgAAAABexample";
+
+async fn setup() -> (Database, SqlitePool) {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    (Database::new(pool.clone()), pool)
+}
+
+async fn row_count(pool: &SqlitePool) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM pending_transactions")
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_same_idempotency_key_returns_existing_transaction() {
+    let (db, pool) = setup().await;
+
+    let first = db
+        .create_pending_transaction(SOURCE, XDR, 2, Some("retry-key-1"))
+        .await
+        .unwrap();
+    let retry = db
+        .create_pending_transaction(SOURCE, XDR, 2, Some("retry-key-1"))
+        .await
+        .unwrap();
+
+    assert_eq!(first.id, retry.id);
+    assert_eq!(retry.idempotency_key.as_deref(), Some("retry-key-1"));
+    assert_eq!(row_count(&pool).await, 1);
+}
+
+#[tokio::test]
+async fn test_transactions_without_a_key_are_not_deduplicated() {
+    let (db, pool) = setup().await;
+
+    let first = db
+        .create_pending_transaction(SOURCE, XDR, 1, None)
+        .await
+        .unwrap();
+    let second = db
+        .create_pending_transaction(SOURCE, XDR, 1, None)
+        .await
+        .unwrap();
+    let keyed = db
+        .create_pending_transaction(SOURCE, XDR, 1, Some("other-key"))
+        .await
+        .unwrap();
+
+    assert_ne!(first.id, second.id);
+    assert_ne!(keyed.id, first.id);
+    assert_eq!(row_count(&pool).await, 3);
+}
+
+#[tokio::test]
+async fn test_idempotency_keys_are_scoped_to_the_source_account() {
+    let (db, pool) = setup().await;
+
+    let first = db
+        .create_pending_transaction(SOURCE, XDR, 1, Some("shared-key"))
+        .await
+        .unwrap();
+    let other = db
+        .create_pending_transaction(OTHER_SOURCE, XDR, 1, Some("shared-key"))
+        .await
+        .unwrap();
+
+    assert_ne!(first.id, other.id);
+    assert_eq!(other.source_account, OTHER_SOURCE);
+    assert_eq!(row_count(&pool).await, 2);
+}
+
+async fn create(app: &Router, xdr: &str, required_signatures: i32) -> StatusCode {
+    let body = json!({
+        "source_account": SOURCE,
+        "xdr": xdr,
+        "required_signatures": required_signatures,
+        "idempotency_key": "retry-key-1",
+    });
+    app.clone()
+        .oneshot(
+            Request::post("/api/transactions")
+                .header(CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+        .status()
+}
+
+#[tokio::test]
+async fn test_replayed_key_with_different_payload_is_rejected() {
+    let app = Router::new()
+        .nest("/api/transactions", transactions::routes())
+        .with_state(common::test_app_state().await);
+
+    assert_eq!(create(&app, XDR, 2).await, StatusCode::OK);
+    assert_eq!(create(&app, XDR, 2).await, StatusCode::OK);
+    assert_eq!(
+        create(&app, "AAAAAgAAAABother", 2).await,
+        StatusCode::UNPROCESSABLE_ENTITY
+    );
+    assert_eq!(create(&app, XDR, 3).await, StatusCode::UNPROCESSABLE_ENTITY);
+}
@@ -24,6 +24,7 @@ fn test_pool_config_from_env() {
     env::set_var("DB_POOL_CONNECT_TIMEOUT_SECONDS", "60");
     env::set_var("DB_POOL_IDLE_TIMEOUT_SECONDS", "300");
     env::set_var("DB_POOL_MAX_LIFETIME_SECONDS", "3600");
+    env::set_var("DB_BUSY_TIMEOUT_MS", "2500");
 
     let config = PoolConfig::from_env();
 
@@ -32,6 +33,7 @@ fn test_pool_config_from_env() {
     assert_eq!(config.connect_timeout_seconds, 60);
     assert_eq!(config.idle_timeout_seconds, 300);
     assert_eq!(config.max_lifetime_seconds, 3600);
+    assert_eq!(config.busy_timeout_ms, 2500);
 
     // Clean up
     env::remove_var("DB_POOL_MAX_CONNECTIONS");
@@ -39,6 +41,7 @@ fn test_pool_config_from_env() {
     env::remove_var("DB_POOL_CONNECT_TIMEOUT_SECONDS");
     env::remove_var("DB_POOL_IDLE_TIMEOUT_SECONDS");
     env::remove_var("DB_POOL_MAX_LIFETIME_SECONDS");
+    env::remove_var("DB_BUSY_TIMEOUT_MS");
 }
 
 #[test]
@@ -90,6 +93,7 @@ async fn test_pool_creation() {
         connect_timeout_seconds: 10,
         idle_timeout_seconds: 300,
         max_lifetime_seconds: 900,
+        ..PoolConfig::default()
     };
 
     // Use in-memory SQLite for testing
@@ -116,3 +120,35 @@ async fn test_pool_metrics() {
     assert_eq!(metrics.size, 2);
     assert_eq!(metrics.idle, 2);
 }
+
+#[tokio::test]
+async fn test_connect_applies_pragmas() {
+    use stellar_insights_backend::database::Database;
+
+    // WAL needs a file; in-memory databases always report "memory"
+    let dir = tempfile::tempdir().unwrap();
+    let url = format!("sqlite://{}?mode=rwc", dir.path().join("test.db").display());
+    let db = Database::connect(&url).await.unwrap();
+
+    let journal_mode: String = sqlx::query_scalar("PRAGMA journal_mode")
+        .fetch_one(db.pool())
+        .await
+        .unwrap();
+    assert_eq!(journal_mode, "wal");
+    let foreign_keys: i64 = sqlx::query_scalar("PRAGMA foreign_keys")
+        .fetch_one(db.pool())
+        .await
+        .unwrap();
+    assert_eq!(foreign_keys, 1);
+
+    let config = PoolConfig {
+        busy_timeout_ms: 2500,
+        ..PoolConfig::default()
+    };
+    let pool = config.create_pool(&url).await.unwrap();
+    let busy_timeout: i64 = sqlx::query_scalar("PRAGMA busy_timeout")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(busy_timeout, 2500);
+}
@@ -272,3 +272,22 @@ async fn test_rate_limit_info_includes_client_id() {
     assert!(info.client_id.is_some());
     assert_eq!(info.client_id.unwrap(), "apikey:test_key_123");
 }
+
+#[tokio::test]
+async fn test_keyed_limit_uses_custom_window() {
+    let limiter = RateLimiter::new().await.unwrap();
+    let key = format!("test:keyed:{}", uuid::Uuid::new_v4());
+
+    let (allowed, info) = limiter.check_keyed_limit(&key, 1, 3600).await;
+    assert!(allowed);
+    assert_eq!(info.remaining, 0);
+
+    let (allowed, info) = limiter.check_keyed_limit(&key, 1, 3600).await;
+    assert!(!allowed);
+    assert!(info.reset_after > 60 && info.reset_after <= 3600);
+
+    // Other keys are counted separately
+    let other = format!("test:keyed:{}", uuid::Uuid::new_v4());
+    let (allowed, _) = limiter.check_keyed_limit(&other, 1, 3600).await;
+    assert!(allowed);
+}
@@ -0,0 +1,130 @@
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+    routing::post,
+    Router,
+};
+use serde_json::{json, Value};
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::handlers::{create_anchor, create_corridor};
+
+mod common;
+
+const ISSUER: &str = "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+/// Not used by the seed data, so anchors can be created with it
+const ANCHOR_ACCOUNT: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+
+async fn create_test_router() -> Router {
+    let state = common::test_app_state().await;
+    Router::new()
+        .route("/api/anchors", post(create_anchor))
+        .route("/api/corridors", post(create_corridor))
+        .with_state(state)
+}
+
+async fn post_json(app: &Router, uri: &str, body: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+#[tokio::test]
+async fn test_anchor_with_bad_account_is_rejected() {
+    let app = create_test_router().await;
+
+    let (status, body) = post_json(
+        &app,
+        "/api/anchors",
+        json!({ "name": "Anchor", "stellar_account": "GANCHOR" }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["code"], "VALIDATION_ERROR");
+    assert!(body["error"]["details"]["stellar_account"].is_string());
+    assert!(body["error"]["details"]["name"].is_null());
+}
+
+#[tokio::test]
+async fn test_anchor_with_empty_name_is_rejected() {
+    let app = create_test_router().await;
+
+    let (status, body) = post_json(
+        &app,
+        "/api/anchors",
+        json!({ "name": "   ", "stellar_account": ANCHOR_ACCOUNT }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["code"], "VALIDATION_ERROR");
+    assert!(body["error"]["details"]["name"].is_string());
+    assert!(body["error"]["details"]["stellar_account"].is_null());
+}
+
+#[tokio::test]
+async fn test_corridor_with_oversized_asset_code_is_rejected() {
+    let app = create_test_router().await;
+
+    let (status, body) = post_json(
+        &app,
+        "/api/corridors",
+        json!({
+            "source_asset_code": "USDCOVERTHELIMIT",
+            "source_asset_issuer": ISSUER,
+            "dest_asset_code": "XLM",
+            "dest_asset_issuer": "native"
+        }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["code"], "VALIDATION_ERROR");
+    assert!(body["error"]["details"]["source_asset_code"].is_string());
+    assert!(body["error"]["details"]["dest_asset_issuer"].is_null());
+}
+
+#[tokio::test]
+async fn test_valid_requests_are_accepted() {
+    let app = create_test_router().await;
+
+    let (status, _) = post_json(
+        &app,
+        "/api/anchors",
+        json!({
+            "name": "Anchor",
+            "stellar_account": ANCHOR_ACCOUNT,
+            "home_domain": "anchor.example.com"
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = post_json(
+        &app,
+        "/api/corridors",
+        json!({
+            "source_asset_code": "USDC",
+            "source_asset_issuer": ISSUER,
+            "dest_asset_code": "XLM",
+            "dest_asset_issuer": "native"
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
@@ -0,0 +1,76 @@
+use axum::{
+    body::Body,
+    http::{
+        header::{ACCEPT_ENCODING, CONTENT_ENCODING, ETAG, IF_NONE_MATCH},
+        HeaderMap, Request, StatusCode,
+    },
+    middleware,
+    response::Response,
+    routing::get,
+    Router,
+};
+use serde_json::json;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::http_cache::{
+    cached_json_response, compression_layer, weaken_encoded_etag,
+};
+
+async fn large_corridor_list(headers: HeaderMap) -> Response {
+    let corridors: Vec<_> = (0..200)
+        .map(|i| json!({ "id": format!("USDC:issuer-{}->XLM:native", i), "success_rate": 99.5 }))
+        .collect();
+    cached_json_response(&headers, "compression:corridors", &corridors, 60).unwrap()
+}
+
+fn app() -> Router {
+    Router::new()
+        .route("/api/corridors", get(large_corridor_list))
+        .layer(compression_layer(1024))
+        .layer(middleware::from_fn(weaken_encoded_etag))
+}
+
+async fn get_corridors(headers: &[(&str, &str)]) -> Response {
+    let mut request = Request::get("/api/corridors");
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    app()
+        .oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_large_response_is_gzipped_when_accepted() {
+    let plain = get_corridors(&[]).await;
+    assert!(plain.headers().get(CONTENT_ENCODING).is_none());
+    let plain_etag = plain.headers()[ETAG].to_str().unwrap().to_string();
+    assert!(!plain_etag.starts_with("W/"));
+    let plain_body = axum::body::to_bytes(plain.into_body(), usize::MAX)
+        .await
+        .unwrap();
+
+    let gzipped = get_corridors(&[(ACCEPT_ENCODING.as_str(), "gzip")]).await;
+    assert_eq!(gzipped.status(), StatusCode::OK);
+    assert_eq!(gzipped.headers()[CONTENT_ENCODING], "gzip");
+
+    // The compressed representation must not share the identity one's strong ETag
+    let gzipped_etag = gzipped.headers()[ETAG].to_str().unwrap().to_string();
+    assert_eq!(gzipped_etag, format!("W/{}", plain_etag));
+
+    let gzipped_body = axum::body::to_bytes(gzipped.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    assert_eq!(&gzipped_body[..2], &[0x1f, 0x8b]);
+    assert!(gzipped_body.len() < plain_body.len());
+
+    // Revalidating the compressed copy still matches
+    let revalidated = get_corridors(&[
+        (ACCEPT_ENCODING.as_str(), "gzip"),
+        (IF_NONE_MATCH.as_str(), &gzipped_etag),
+    ])
+    .await;
+    assert_eq!(revalidated.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(revalidated.headers()[ETAG], gzipped_etag.as_str());
+}
@@ -0,0 +1,123 @@
+use chrono::{DateTime, Duration, Utc};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use stellar_insights_backend::database::{AnchorMetricsParams, Database};
+use stellar_insights_backend::jobs::retention::{RetentionConfig, RetentionJob, RetentionStats};
+use stellar_insights_backend::models::{CreateAnchorRequest, PaymentRecord};
+
+const ISSUER: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+
+fn payment(id: &str, created_at: DateTime<Utc>) -> PaymentRecord {
+    PaymentRecord {
+        id: id.to_string(),
+        transaction_hash: format!("hash_{}", id),
+        source_account: "GSOURCE".to_string(),
+        destination_account: "GDEST".to_string(),
+        asset_type: "credit_alphanum4".to_string(),
+        asset_code: Some("USDC".to_string()),
+        asset_issuer: Some(ISSUER.to_string()),
+        operation_type: None,
+        source_asset_type: None,
+        source_asset_code: "USDC".to_string(),
+        source_asset_issuer: ISSUER.to_string(),
+        destination_asset_code: "USDC".to_string(),
+        destination_asset_issuer: ISSUER.to_string(),
+        amount: 10.0,
+        successful: true,
+        timestamp: Some(created_at),
+        submission_time: None,
+        confirmation_time: None,
+        created_at,
+    }
+}
+
+/// Record a metrics history row and backdate it to `timestamp`
+async fn record_history(db: &Database, anchor_id: Uuid, timestamp: DateTime<Utc>) -> String {
+    let history = db
+        .record_anchor_metrics_history(AnchorMetricsParams {
+            anchor_id,
+            success_rate: 99.0,
+            failure_rate: 1.0,
+            reliability_score: 95.0,
+            raw_reliability_score: 95.0,
+            total_transactions: 100,
+            successful_transactions: 99,
+            failed_transactions: 1,
+            avg_settlement_time_ms: Some(1000),
+            volume_usd: Some(1000.0),
+        })
+        .await
+        .unwrap();
+    sqlx::query("UPDATE anchor_metrics_history SET timestamp = $1 WHERE id = $2")
+        .bind(timestamp)
+        .bind(&history.id)
+        .execute(db.pool())
+        .await
+        .unwrap();
+    history.id
+}
+
+async fn ids(pool: &SqlitePool, table: &str) -> Vec<String> {
+    sqlx::query_scalar(&format!("SELECT id FROM {} ORDER BY id", table))
+        .fetch_all(pool)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_only_rows_past_retention_are_purged() {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    let db = Database::new(pool.clone());
+
+    let anchor = db
+        .create_anchor(CreateAnchorRequest {
+            name: "Test Anchor".to_string(),
+            stellar_account: ISSUER.to_string(),
+            home_domain: None,
+        })
+        .await
+        .unwrap();
+    let anchor_id = Uuid::parse_str(&anchor.id).unwrap();
+
+    let now = Utc::now();
+    for _ in 0..5 {
+        record_history(&db, anchor_id, now - Duration::days(400)).await;
+    }
+    let recent_history = record_history(&db, anchor_id, now - Duration::days(1)).await;
+
+    let mut payments: Vec<PaymentRecord> = (0..5)
+        .map(|i| payment(&format!("old_{}", i), now - Duration::days(40)))
+        .collect();
+    payments.push(payment("recent", now - Duration::days(1)));
+    db.save_payments(payments).await.unwrap();
+
+    // A batch size smaller than the backlog exercises the batching loop
+    let job = RetentionJob::new(
+        pool.clone(),
+        RetentionConfig {
+            enabled: true,
+            metrics_retention_days: 90,
+            payments_retention_days: 30,
+            batch_size: 2,
+        },
+    );
+    let stats = job.run().await.unwrap();
+
+    assert_eq!(
+        stats,
+        RetentionStats {
+            metrics_history_removed: 5,
+            payments_removed: 5,
+        }
+    );
+    assert_eq!(
+        ids(&pool, "anchor_metrics_history").await,
+        vec![recent_history]
+    );
+    assert_eq!(ids(&pool, "payments").await, vec!["recent".to_string()]);
+
+    // Nothing left to purge on the next run
+    assert_eq!(job.run().await.unwrap(), RetentionStats::default());
+}
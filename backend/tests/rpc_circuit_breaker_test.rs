@@ -0,0 +1,79 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    routing::get,
+    Router,
+};
+use serde_json::Value;
+use std::sync::Arc;
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::rpc::error::RpcError;
+use stellar_insights_backend::rpc::StellarRpcClient;
+use stellar_insights_backend::rpc_handlers;
+
+async fn circuit_breakers(client: Arc<StellarRpcClient>) -> Value {
+    let response = Router::new()
+        .route(
+            "/api/rpc/circuit-breaker",
+            get(rpc_handlers::get_circuit_breakers),
+        )
+        .with_state(client)
+        .oneshot(
+            Request::get("/api/rpc/circuit-breaker")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+fn breaker<'a>(body: &'a Value, name: &str) -> &'a Value {
+    body["breakers"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|entry| entry["name"] == name)
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_tripped_breaker_is_reported_open() {
+    let client = Arc::new(StellarRpcClient::new_with_defaults(true));
+
+    let body = circuit_breakers(Arc::clone(&client)).await;
+    let rpc = breaker(&body, "rpc");
+    assert_eq!(rpc["state"], "Closed");
+    assert_eq!(rpc["failure_count"], 0);
+    assert!(rpc["next_half_open_in_ms"].is_null());
+
+    let circuit_breaker = client.circuit_breaker();
+    let threshold = circuit_breaker.config().failure_threshold;
+    for _ in 0..threshold {
+        let _: Result<(), _> = circuit_breaker
+            .call(|| async {
+                Err(RpcError::ServerError {
+                    status: 503,
+                    message: "unavailable".into(),
+                })
+            })
+            .await;
+    }
+
+    let body = circuit_breakers(client).await;
+    let rpc = breaker(&body, "rpc");
+    assert_eq!(rpc["endpoint"], "rpc");
+    assert_eq!(rpc["state"], "Open");
+    assert_eq!(rpc["failure_count"], threshold);
+    let retry_in = rpc["next_half_open_in_ms"].as_u64().unwrap();
+    assert!(retry_in <= circuit_breaker.config().timeout_duration.as_millis() as u64);
+
+    // The Horizon breakers are reported alongside it
+    assert!(breaker(&body, "anchors")["state"].is_string());
+    assert!(breaker(&body, "corridors")["state"].is_string());
+}
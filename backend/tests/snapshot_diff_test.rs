@@ -0,0 +1,126 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use chrono::Utc;
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tower::util::ServiceExt;
+use uuid::Uuid;
+
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::services::snapshot::SnapshotService;
+use stellar_insights_backend::snapshot::{AnalyticsSnapshot, SnapshotCorridorMetrics};
+use stellar_insights_backend::snapshot_handlers::{routes, SnapshotAppState};
+
+fn corridor(key: &str, volume_usd: f64) -> SnapshotCorridorMetrics {
+    SnapshotCorridorMetrics {
+        id: Uuid::new_v4(),
+        corridor_key: key.to_string(),
+        asset_a_code: "USDC".to_string(),
+        asset_a_issuer: "GISSUER".to_string(),
+        asset_b_code: "EURC".to_string(),
+        asset_b_issuer: "GISSUER".to_string(),
+        total_transactions: 10,
+        successful_transactions: 9,
+        failed_transactions: 1,
+        success_rate: 90.0,
+        volume_usd,
+        avg_settlement_latency_ms: None,
+        liquidity_depth_usd: 0.0,
+    }
+}
+
+async fn store(pool: &SqlitePool, snapshot: AnalyticsSnapshot) {
+    let epoch = snapshot.epoch as i64;
+    let timestamp = snapshot.timestamp;
+    let data = SnapshotService::serialize_deterministically(snapshot).unwrap();
+    sqlx::query(
+        "INSERT INTO snapshots (id, entity_id, entity_type, data, epoch, timestamp)
+         VALUES (?, 'system', 'analytics_snapshot', ?, ?, ?)",
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(data)
+    .bind(epoch)
+    .bind(timestamp)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+async fn setup() -> Router {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+
+    let mut first = AnalyticsSnapshot::new(1, Utc::now());
+    first.add_corridor_metrics(corridor("USDC->EURC", 100.0));
+    first.add_corridor_metrics(corridor("USDC->XLM", 50.0));
+    store(&pool, first).await;
+
+    let mut second = AnalyticsSnapshot::new(2, Utc::now());
+    second.add_corridor_metrics(corridor("USDC->EURC", 250.0));
+    second.add_corridor_metrics(corridor("XLM->EURC", 10.0));
+    store(&pool, second).await;
+
+    let db = Arc::new(Database::new(pool));
+    let state = SnapshotAppState {
+        snapshot_service: Arc::new(SnapshotService::new(Arc::clone(&db), None)),
+        db,
+        contract_service: None,
+    };
+    routes(state)
+}
+
+async fn get(app: Router, uri: &str) -> (StatusCode, Value) {
+    let response = app
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+#[tokio::test]
+async fn test_diff_between_stored_epochs() {
+    let app = setup().await;
+
+    // Out-of-order epochs are swapped
+    let (status, diff) = get(app, "/api/snapshots/diff?from=2&to=1").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(diff["from_epoch"], 1);
+    assert_eq!(diff["to_epoch"], 2);
+    let corridors = &diff["corridors"];
+    assert_eq!(corridors["added"][0]["corridor_key"], "XLM->EURC");
+    assert_eq!(corridors["removed"][0]["corridor_key"], "USDC->XLM");
+    assert_eq!(corridors["changed"][0]["corridor_key"], "USDC->EURC");
+    assert_eq!(corridors["changed"][0]["volume_usd_change"], 150.0);
+}
+
+#[tokio::test]
+async fn test_diff_with_missing_epoch_is_404() {
+    let app = setup().await;
+
+    let (status, body) = get(app, "/api/snapshots/diff?from=1&to=9").await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"], "No snapshot stored for epoch 9");
+}
+
+#[tokio::test]
+async fn test_diff_route_is_not_shadowed_by_signed_snapshot_route() {
+    let app = setup().await;
+
+    let (status, _) = get(app.clone(), "/api/snapshots/diff?from=1&to=2").await;
+    assert_eq!(status, StatusCode::OK);
+
+    // The signed download is served by the same router
+    let (status, body) = get(app, "/api/snapshots/1/signed").await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(body["error"], "Snapshot signing key not configured");
+}
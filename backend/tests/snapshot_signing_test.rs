@@ -0,0 +1,116 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Extension, Router,
+};
+use chrono::Utc;
+use ed25519_dalek::{Signature, SigningKey, Verifier};
+use sha2::{Digest, Sha256};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tower::util::ServiceExt;
+use uuid::Uuid;
+
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::services::snapshot::SnapshotService;
+use stellar_insights_backend::snapshot::{
+    AnalyticsSnapshot, SignedSnapshot, SnapshotCorridorMetrics, SnapshotSigner,
+};
+use stellar_insights_backend::snapshot_handlers::{get_signed_snapshot, SnapshotAppState};
+
+const SEED: [u8; 32] = [7; 32];
+
+async fn setup() -> Router {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+
+    let mut snapshot = AnalyticsSnapshot::new(42, Utc::now());
+    snapshot.add_corridor_metrics(SnapshotCorridorMetrics {
+        id: Uuid::new_v4(),
+        corridor_key: "USDC->EURC".to_string(),
+        asset_a_code: "USDC".to_string(),
+        asset_a_issuer: "GISSUER".to_string(),
+        asset_b_code: "EURC".to_string(),
+        asset_b_issuer: "GISSUER".to_string(),
+        total_transactions: 10,
+        successful_transactions: 9,
+        failed_transactions: 1,
+        success_rate: 90.0,
+        volume_usd: 1000.0,
+        avg_settlement_latency_ms: Some(1200),
+        liquidity_depth_usd: 5000.0,
+    });
+    let timestamp = snapshot.timestamp;
+    sqlx::query(
+        "INSERT INTO snapshots (id, entity_id, entity_type, data, epoch, timestamp)
+         VALUES (?, 'system', 'analytics_snapshot', ?, 42, ?)",
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(SnapshotService::serialize_deterministically(snapshot).unwrap())
+    .bind(timestamp)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let db = Arc::new(Database::new(pool));
+    let signer = SnapshotSigner::from_seed_hex(&hex::encode(SEED)).unwrap();
+    Router::new()
+        .route(
+            "/api/snapshots/:epoch/signed",
+            axum::routing::get(get_signed_snapshot),
+        )
+        .with_state(SnapshotAppState {
+            snapshot_service: Arc::new(SnapshotService::new(Arc::clone(&db), None)),
+            db,
+            contract_service: None,
+        })
+        .layer(Extension(Arc::new(signer)))
+}
+
+#[tokio::test]
+async fn test_signed_snapshot_verifies_against_configured_key() {
+    let response = setup()
+        .await
+        .oneshot(
+            Request::get("/api/snapshots/42/signed")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let signed: SignedSnapshot = serde_json::from_slice(&body).unwrap();
+
+    let verifying_key = SigningKey::from_bytes(&SEED).verifying_key();
+    assert_eq!(signed.public_key, hex::encode(verifying_key.to_bytes()));
+    assert_eq!(signed.epoch, 42);
+
+    // The hash is recomputable from the canonical JSON alone
+    let hash = Sha256::digest(signed.snapshot.as_bytes());
+    assert_eq!(signed.hash, hex::encode(hash));
+
+    let signature_bytes: [u8; 64] = hex::decode(&signed.signature).unwrap().try_into().unwrap();
+    let signature = Signature::from_bytes(&signature_bytes);
+    assert!(verifying_key.verify(&hash, &signature).is_ok());
+
+    let mut tampered = hash;
+    tampered[0] ^= 1;
+    assert!(verifying_key.verify(&tampered, &signature).is_err());
+}
+
+#[tokio::test]
+async fn test_signed_snapshot_for_unknown_epoch_is_not_found() {
+    let response = setup()
+        .await
+        .oneshot(
+            Request::get("/api/snapshots/7/signed")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
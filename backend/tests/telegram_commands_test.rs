@@ -0,0 +1,188 @@
+use chrono::Utc;
+use serde_json::json;
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use std::time::Duration;
+
+use stellar_insights_backend::api::corridors_cached::{
+    CorridorPaymentFetchLimit, HealthScoreWeights,
+};
+use stellar_insights_backend::cache::{CacheConfig, CacheManager};
+use stellar_insights_backend::cache_memory::MemoryCache;
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::models::{CreateAnchorRequest, PaymentRecord};
+use stellar_insights_backend::rpc::StellarRpcClient;
+use stellar_insights_backend::services::price_feed::{
+    default_asset_mapping, PriceFeedClient, PriceFeedConfig,
+};
+use stellar_insights_backend::telegram::bot::reply_to_update;
+use stellar_insights_backend::telegram::client::Update;
+use stellar_insights_backend::telegram::{formatter, CommandHandler, SubscriptionService};
+
+// Issuer outside the price feed mapping, so volumes use raw amounts offline
+const ISSUER: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+const CHAT_ID: i64 = 4242;
+
+fn payment(id: &str, amount: f64) -> PaymentRecord {
+    let now = Utc::now();
+    PaymentRecord {
+        id: id.to_string(),
+        transaction_hash: format!("hash_{}", id),
+        source_account: "GSOURCE".to_string(),
+        destination_account: "GDEST".to_string(),
+        asset_type: "credit_alphanum4".to_string(),
+        asset_code: Some("USDC".to_string()),
+        asset_issuer: Some(ISSUER.to_string()),
+        operation_type: None,
+        source_asset_type: None,
+        source_asset_code: "USDC".to_string(),
+        source_asset_issuer: ISSUER.to_string(),
+        destination_asset_code: "USDC".to_string(),
+        destination_asset_issuer: ISSUER.to_string(),
+        amount,
+        successful: true,
+        timestamp: Some(now),
+        submission_time: None,
+        confirmation_time: None,
+        created_at: now,
+    }
+}
+
+async fn setup() -> (SqlitePool, Arc<Database>, CommandHandler) {
+    setup_with_fetch_limit(CorridorPaymentFetchLimit::default()).await
+}
+
+async fn setup_with_fetch_limit(
+    fetch_limit: CorridorPaymentFetchLimit,
+) -> (SqlitePool, Arc<Database>, CommandHandler) {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    let db = Arc::new(Database::new(pool.clone()));
+
+    let cache = Arc::new(
+        CacheManager::with_redis_url(
+            CacheConfig::default(),
+            "redis://127.0.0.1:1",
+            MemoryCache::new(100),
+            Duration::from_secs(60),
+        )
+        .await,
+    );
+    let handler = CommandHandler::new(
+        Arc::clone(&db),
+        cache,
+        Arc::new(StellarRpcClient::new_with_defaults(true)),
+        Arc::new(PriceFeedClient::new(
+            PriceFeedConfig::default(),
+            default_asset_mapping(),
+        )),
+        HealthScoreWeights::default(),
+        fetch_limit,
+        Arc::new(SubscriptionService::new(pool.clone())),
+    );
+    (pool, db, handler)
+}
+
+/// An update as Telegram's getUpdates delivers it for a private chat message
+fn incoming(text: &str) -> Update {
+    serde_json::from_value(json!({
+        "update_id": 1001,
+        "message": {
+            "message_id": 7,
+            "date": 1_760_000_000,
+            "text": text,
+            "chat": { "id": CHAT_ID, "type": "private", "username": "alice" },
+            "from": { "id": 99, "is_bot": false, "first_name": "Alice", "username": "alice" }
+        }
+    }))
+    .unwrap()
+}
+
+async fn reply(handler: &CommandHandler, text: &str) -> String {
+    let (chat_id, reply) = reply_to_update(handler, &incoming(text)).await.unwrap();
+    assert_eq!(chat_id, CHAT_ID);
+    reply
+}
+
+#[tokio::test]
+async fn test_corridor_command_replies_with_corridor_summary() {
+    let (_, db, handler) = setup().await;
+    db.save_payments(vec![payment("1", 100.0), payment("2", 50.0)])
+        .await
+        .unwrap();
+    let key = format!("USDC:{}->USDC:{}", ISSUER, ISSUER);
+
+    let reply = reply(&handler, &format!("/corridor@StellarInsightsBot {}", key)).await;
+
+    // Two successful payments worth 150 in total
+    let health =
+        100.0 * 0.6 + (150f64.ln() / 15.0 * 100.0) * 0.2 + (2f64.ln() / 10.0 * 100.0) * 0.2;
+    assert_eq!(
+        reply,
+        formatter::format_corridor_detail(&key, "USDC", "USDC", 100.0, 2, 600.0, 150.0, health)
+    );
+    assert!(reply.contains("Total Attempts: 2"));
+}
+
+#[tokio::test]
+async fn test_corridor_command_loads_at_most_the_configured_payments() {
+    let (_, db, handler) =
+        setup_with_fetch_limit(CorridorPaymentFetchLimit::new(100).unwrap()).await;
+    db.save_payments((0..150).map(|i| payment(&i.to_string(), 1.0)).collect())
+        .await
+        .unwrap();
+    let key = format!("USDC:{}->USDC:{}", ISSUER, ISSUER);
+
+    let reply = reply(&handler, &format!("/corridor {}", key)).await;
+
+    assert!(reply.contains("Total Attempts: 100"), "{}", reply);
+}
+
+#[tokio::test]
+async fn test_malformed_corridor_command_replies_with_usage() {
+    let (_, _, handler) = setup().await;
+
+    for text in ["/corridor", "/corridor USDC", "/corridor USDC->XLM"] {
+        let reply = reply(&handler, text).await;
+        assert!(reply.contains("Usage: /corridor"), "{}: {}", text, reply);
+    }
+
+    // Plain text is not a command and gets no reply
+    assert!(reply_to_update(&handler, &incoming("hello"))
+        .await
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_anchors_command_lists_top_five_by_reliability() {
+    let (pool, db, handler) = setup().await;
+    for (i, reliability) in [70.0, 95.0, 40.0, 88.0, 99.0, 60.0].iter().enumerate() {
+        let anchor = db
+            .create_anchor(CreateAnchorRequest {
+                name: format!("Anchor {}", reliability),
+                stellar_account: format!("GANCHOR{}", i),
+                home_domain: None,
+            })
+            .await
+            .unwrap();
+        sqlx::query("UPDATE anchors SET reliability_score = ? WHERE id = ?")
+            .bind(reliability)
+            .bind(&anchor.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    let reply = reply(&handler, "/anchors").await;
+
+    let names: Vec<String> = [99, 95, 88, 70, 60]
+        .iter()
+        .map(|score| formatter::escape_markdown(&format!("Anchor {}", score)))
+        .collect();
+    let positions: Vec<usize> = names
+        .iter()
+        .map(|name| reply.find(&format!("*{}*", name)).unwrap())
+        .collect();
+    assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(!reply.contains(&formatter::escape_markdown("Anchor 40")));
+}
@@ -0,0 +1,64 @@
+use axum::{
+    body::Body,
+    http::{header::CONTENT_TYPE, Request, StatusCode},
+    Router,
+};
+use serde_json::{json, Value};
+use tower::util::ServiceExt;
+
+use stellar_insights_backend::api::transactions;
+use stellar_insights_backend::body_limit_middleware::DEFAULT_TRANSACTION_BODY_LIMIT;
+
+mod common;
+
+const SOURCE: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+
+async fn create_test_router() -> Router {
+    let state = common::test_app_state().await;
+    Router::new()
+        .nest("/api/transactions", transactions::routes())
+        .with_state(state)
+}
+
+async fn create(app: Router, xdr: String) -> (StatusCode, Value) {
+    let body = json!({
+        "source_account": SOURCE,
+        "xdr": xdr,
+        "required_signatures": 1,
+    });
+    let response = app
+        .oneshot(
+            Request::post("/api/transactions")
+                .header(CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+#[tokio::test]
+async fn test_over_limit_body_is_rejected_with_413() {
+    let app = create_test_router().await;
+
+    let (status, body) = create(app, "A".repeat(DEFAULT_TRANSACTION_BODY_LIMIT + 1)).await;
+
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    assert_eq!(body["error"]["code"], "PAYLOAD_TOO_LARGE");
+    assert_eq!(body["error"]["category"], "validation");
+}
+
+#[tokio::test]
+async fn test_body_within_limit_is_accepted() {
+    let app = create_test_router().await;
+
+    let (status, body) = create(app, "AAAAAgAAAABexample".to_string()).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "pending");
+}
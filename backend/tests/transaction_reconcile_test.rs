@@ -0,0 +1,54 @@
+use sqlx::SqlitePool;
+
+use stellar_insights_backend::api::transactions::reconcile_submitted_transaction;
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::rpc::StellarRpcClient;
+
+const SOURCE: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+
+async fn setup() -> (Database, String) {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    let db = Database::new(pool);
+    let tx = db
+        .create_pending_transaction(SOURCE, "AAAAAgAAAABexample", 1, None)
+        .await
+        .unwrap();
+    (db, tx.id)
+}
+
+#[tokio::test]
+async fn test_submitted_transaction_reconciles_to_success() {
+    let (db, id) = setup().await;
+    let rpc_client = StellarRpcClient::new_with_defaults(true);
+    db.record_transaction_submission(&id, "abc123")
+        .await
+        .unwrap();
+
+    let tx = reconcile_submitted_transaction(&db, &rpc_client, &id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(tx.status, "success");
+    assert_eq!(tx.transaction_hash.as_deref(), Some("abc123"));
+    let stored = db.get_pending_transaction(&id).await.unwrap().unwrap();
+    assert_eq!(stored.transaction.status, "success");
+}
+
+#[tokio::test]
+async fn test_unsubmitted_transaction_is_left_alone() {
+    let (db, id) = setup().await;
+    let rpc_client = StellarRpcClient::new_with_defaults(true);
+
+    let tx = reconcile_submitted_transaction(&db, &rpc_client, &id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(tx.status, "pending");
+
+    assert!(reconcile_submitted_transaction(&db, &rpc_client, "missing")
+        .await
+        .unwrap()
+        .is_none());
+}
@@ -0,0 +1,100 @@
+use sqlx::SqlitePool;
+
+use stellar_insights_backend::api::transactions::is_authorized_signer;
+use stellar_insights_backend::database::Database;
+use stellar_insights_backend::rpc::{AccountSigner, StellarRpcClient};
+
+const SOURCE: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+const SIGNER_A: &str = "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+const SIGNER_B: &str = "GDHU6WRG4IEQXM5NZ4BMPKOXHW76MZM4Y2IEMFDVXBSDP6SJY4ITNPP2";
+
+async fn setup(required_signatures: i32) -> (Database, String) {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    let db = Database::new(pool);
+    let tx = db
+        .create_pending_transaction(SOURCE, "AAAAAgAAAABexample", required_signatures, None)
+        .await
+        .unwrap();
+    (db, tx.id)
+}
+
+#[tokio::test]
+async fn test_under_threshold_stays_pending() {
+    let (db, id) = setup(2).await;
+    db.add_transaction_signature(&id, SIGNER_A, "sig-a")
+        .await
+        .unwrap();
+
+    let readiness = db.check_transaction_ready(&id).await.unwrap().unwrap();
+
+    assert_eq!(readiness.distinct_signers, 1);
+    assert!(!readiness.ready);
+    assert_eq!(readiness.status, "pending");
+}
+
+#[tokio::test]
+async fn test_exactly_threshold_flips_to_ready() {
+    let (db, id) = setup(2).await;
+    db.add_transaction_signature(&id, SIGNER_A, "sig-a")
+        .await
+        .unwrap();
+    db.add_transaction_signature(&id, SIGNER_B, "sig-b")
+        .await
+        .unwrap();
+
+    let readiness = db.check_transaction_ready(&id).await.unwrap().unwrap();
+
+    assert_eq!(readiness.distinct_signers, 2);
+    assert!(readiness.ready);
+    assert_eq!(readiness.status, "ready");
+    let stored = db.get_pending_transaction(&id).await.unwrap().unwrap();
+    assert_eq!(stored.transaction.status, "ready");
+}
+
+#[tokio::test]
+async fn test_duplicate_signer_counts_once() {
+    let (db, id) = setup(2).await;
+    db.add_transaction_signature(&id, SIGNER_A, "sig-a")
+        .await
+        .unwrap();
+    assert!(db
+        .add_transaction_signature(&id, SIGNER_A, "sig-a-again")
+        .await
+        .is_err());
+
+    let readiness = db.check_transaction_ready(&id).await.unwrap().unwrap();
+
+    assert_eq!(readiness.distinct_signers, 1);
+    assert!(!readiness.ready);
+}
+
+#[tokio::test]
+async fn test_unknown_transaction_has_no_readiness() {
+    let (db, _) = setup(1).await;
+    assert!(db
+        .check_transaction_ready("missing")
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_signer_must_belong_to_source_account() {
+    let signer = |key: &str, weight| AccountSigner {
+        key: key.to_string(),
+        weight,
+        signer_type: "ed25519_public_key".to_string(),
+    };
+    let signers = vec![signer(SOURCE, 1), signer(SIGNER_A, 1), signer(SIGNER_B, 0)];
+
+    assert!(is_authorized_signer(&signers, SIGNER_A));
+    // A zero-weight signer has been removed from the account
+    assert!(!is_authorized_signer(&signers, SIGNER_B));
+    assert!(!is_authorized_signer(&signers, "GUNKNOWN"));
+
+    let rpc_client = StellarRpcClient::new_with_defaults(true);
+    let master_only = rpc_client.fetch_account_signers(SOURCE).await.unwrap();
+    assert!(is_authorized_signer(&master_only, SOURCE));
+    assert!(!is_authorized_signer(&master_only, SIGNER_A));
+}
@@ -1,5 +1,6 @@
 use sqlx::SqlitePool;
 use std::sync::Arc;
+use stellar_insights_backend::models::TrustlineTrendDirection;
 use stellar_insights_backend::rpc::StellarRpcClient;
 use stellar_insights_backend::services::trustline_analyzer::TrustlineAnalyzer;
 
@@ -45,3 +46,102 @@ async fn test_trustlines_snapshots(pool: SqlitePool) {
     assert_eq!(history[0].asset_code, asset.asset_code);
     assert_eq!(history[0].total_trustlines, asset.total_trustlines);
 }
+
+async fn insert_stat(pool: &SqlitePool, code: &str, authorized: i64) {
+    sqlx::query(
+        r#"
+        INSERT INTO trustline_stats (
+            asset_code, asset_issuer, total_trustlines, authorized_trustlines, unauthorized_trustlines, total_supply
+        )
+        VALUES (?1, 'GISSUER', ?2, ?2, 0, 0)
+        "#,
+    )
+    .bind(code)
+    .bind(authorized)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+async fn insert_snapshot(pool: &SqlitePool, code: &str, authorized: i64, days_ago: i64) {
+    sqlx::query(
+        r#"
+        INSERT INTO trustline_snapshots (
+            asset_code, asset_issuer, total_trustlines, authorized_trustlines, unauthorized_trustlines, total_supply, snapshot_at
+        )
+        VALUES (?1, 'GISSUER', ?2, ?2, 0, 0, datetime('now', ?3))
+        "#,
+    )
+    .bind(code)
+    .bind(authorized)
+    .bind(format!("-{} days", days_ago))
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+#[sqlx::test]
+async fn test_trustline_trend_classification(pool: SqlitePool) {
+    let rpc_client = Arc::new(StellarRpcClient::new_with_defaults(true));
+    let analyzer = TrustlineAnalyzer::new(pool.clone(), rpc_client);
+
+    // Growing: 1000 -> 1200. The snapshot outside the window is ignored.
+    insert_stat(&pool, "GROW", 1200).await;
+    insert_snapshot(&pool, "GROW", 10, 60).await;
+    insert_snapshot(&pool, "GROW", 1000, 20).await;
+    insert_snapshot(&pool, "GROW", 1100, 5).await;
+
+    // Shrinking: 1000 -> 800
+    insert_stat(&pool, "SHRINK", 800).await;
+    insert_snapshot(&pool, "SHRINK", 1000, 25).await;
+
+    // Flat: 1000 -> 1005 is under the 1% threshold
+    insert_stat(&pool, "FLAT", 1005).await;
+    insert_snapshot(&pool, "FLAT", 1000, 10).await;
+
+    let trend = analyzer
+        .get_trustline_trend("GROW", "GISSUER", 30)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(trend.direction, TrustlineTrendDirection::Growing);
+    assert_eq!(trend.baseline_authorized, 1000);
+    assert_eq!(trend.current_authorized, 1200);
+    assert_eq!(trend.change_pct, Some(20.0));
+    assert_eq!(trend.window_days, 30);
+
+    let trend = analyzer
+        .get_trustline_trend("SHRINK", "GISSUER", 30)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(trend.direction, TrustlineTrendDirection::Shrinking);
+    assert_eq!(trend.change_pct, Some(-20.0));
+
+    let trend = analyzer
+        .get_trustline_trend("FLAT", "GISSUER", 30)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(trend.direction, TrustlineTrendDirection::Flat);
+
+    // A shorter window picks a later baseline
+    let trend = analyzer
+        .get_trustline_trend("GROW", "GISSUER", 7)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(trend.baseline_authorized, 1100);
+
+    // No snapshot inside the window, or untracked asset
+    assert!(analyzer
+        .get_trustline_trend("SHRINK", "GISSUER", 7)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(analyzer
+        .get_trustline_trend("NONE", "GISSUER", 30)
+        .await
+        .unwrap()
+        .is_none());
+}
@@ -0,0 +1,169 @@
+use axum::{http::StatusCode, routing::post, Router};
+use chrono::{Duration, Utc};
+use serde_json::json;
+use sqlx::SqlitePool;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use stellar_insights_backend::services::webhook_dispatcher::WebhookDispatcher;
+use stellar_insights_backend::webhooks::deliveries::{
+    WebhookRetryPolicy, DELIVERY_DEAD, DELIVERY_RETRYING,
+};
+use stellar_insights_backend::webhooks::{CreateWebhookRequest, WebhookService};
+
+const USER_ID: &str = "user-1";
+
+/// Receiver that rejects every delivery, counting the attempts
+async fn spawn_failing_endpoint() -> (String, Arc<AtomicUsize>) {
+    let hits = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&hits);
+    let app = Router::new().route(
+        "/hook",
+        post(move || {
+            let counter = Arc::clone(&counter);
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }),
+    );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    (format!("http://{}/hook", addr), hits)
+}
+
+async fn setup() -> (SqlitePool, WebhookService, String, Arc<AtomicUsize>) {
+    let pool = SqlitePool::connect(":memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    sqlx::query("INSERT INTO users (id, username) VALUES (?, ?)")
+        .bind(USER_ID)
+        .bind("webhook-owner")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let (url, hits) = spawn_failing_endpoint().await;
+    let service = WebhookService::new(pool.clone());
+    let webhook = service
+        .register_webhook(
+            USER_ID,
+            CreateWebhookRequest {
+                url,
+                event_types: vec!["payment.created".to_string()],
+                filters: None,
+            },
+        )
+        .await
+        .unwrap();
+    let event_id = service
+        .create_webhook_event(&webhook.id, "payment.created", json!({"amount": "10"}))
+        .await
+        .unwrap();
+
+    (pool, service, event_id, hits)
+}
+
+async fn event_status(pool: &SqlitePool, event_id: &str) -> String {
+    sqlx::query_scalar("SELECT status FROM webhook_events WHERE id = ?")
+        .bind(event_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_failed_delivery_backs_off_then_dead_letters() {
+    let (pool, service, event_id, hits) = setup().await;
+    let dispatcher = WebhookDispatcher::new(pool.clone()).with_retry_policy(WebhookRetryPolicy {
+        max_attempts: 3,
+        base_delay: Duration::seconds(30),
+        max_delay: Duration::hours(1),
+    });
+
+    // The first failure queues the delivery for retry after the base delay
+    let start = Utc::now();
+    dispatcher.process_pending_events().await.unwrap();
+    assert_eq!(event_status(&pool, &event_id).await, "retrying");
+    let queued = service
+        .due_deliveries(start + Duration::seconds(60), 10)
+        .await
+        .unwrap();
+    assert_eq!(queued.len(), 1);
+    let first = &queued[0];
+    assert_eq!(first.status, DELIVERY_RETRYING);
+    assert_eq!(first.attempts, 1);
+    assert!(first.next_retry_at >= start + Duration::seconds(30));
+    assert!(first.next_retry_at <= Utc::now() + Duration::seconds(30));
+
+    // Nothing is retried before it is due
+    assert_eq!(dispatcher.retry_due_deliveries_at(start).await.unwrap(), 0);
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+    // The second failure doubles the delay
+    let second_at = first.next_retry_at;
+    assert_eq!(
+        dispatcher.retry_due_deliveries_at(second_at).await.unwrap(),
+        1
+    );
+    let retried = service
+        .due_deliveries(second_at + Duration::hours(1), 10)
+        .await
+        .unwrap();
+    assert_eq!(retried[0].attempts, 2);
+    assert_eq!(retried[0].next_retry_at, second_at + Duration::seconds(60));
+
+    // The third failure exhausts the attempts
+    dispatcher
+        .retry_due_deliveries_at(retried[0].next_retry_at)
+        .await
+        .unwrap();
+    assert_eq!(hits.load(Ordering::SeqCst), 3);
+    assert_eq!(event_status(&pool, &event_id).await, "failed");
+    assert!(service
+        .due_deliveries(second_at + Duration::days(1), 10)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let dead = service.list_dead_deliveries(USER_ID).await.unwrap();
+    assert_eq!(dead.len(), 1);
+    assert_eq!(dead[0].status, DELIVERY_DEAD);
+    assert_eq!(dead[0].attempts, 3);
+    assert!(dead[0].last_error.as_deref().unwrap().contains("500"));
+}
+
+#[tokio::test]
+async fn test_dead_delivery_can_be_requeued_by_its_owner() {
+    let (pool, service, _event_id, _hits) = setup().await;
+    let dispatcher = WebhookDispatcher::new(pool.clone()).with_retry_policy(WebhookRetryPolicy {
+        max_attempts: 1,
+        ..WebhookRetryPolicy::default()
+    });
+
+    dispatcher.process_pending_events().await.unwrap();
+    let dead = service.list_dead_deliveries(USER_ID).await.unwrap();
+    assert_eq!(dead.len(), 1);
+
+    assert!(!service
+        .requeue_dead_delivery(&dead[0].id, "someone-else")
+        .await
+        .unwrap());
+    assert!(service
+        .requeue_dead_delivery(&dead[0].id, USER_ID)
+        .await
+        .unwrap());
+
+    assert!(service
+        .list_dead_deliveries(USER_ID)
+        .await
+        .unwrap()
+        .is_empty());
+    let due = service.due_deliveries(Utc::now(), 10).await.unwrap();
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].status, DELIVERY_RETRYING);
+    assert_eq!(due[0].attempts, 0);
+}
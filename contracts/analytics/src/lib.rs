@@ -34,11 +34,14 @@ impl AnalyticsContract {
     ///
     /// # Arguments
     /// * `env` - Contract environment
-    /// * `admin` - Address authorized to submit snapshots
+    /// * `admin` - Address authorized to submit snapshots (must authenticate)
     ///
     /// # Panics
     /// * If contract is already initialized (admin already set)
+    /// * If the admin has not authorized the call
     pub fn initialize(env: Env, admin: Address) {
+        admin.require_auth();
+
         let storage = env.storage().instance();
 
         // Prevent re-initialization if admin is already set
@@ -64,6 +67,8 @@ impl AnalyticsContract {
     /// Submit a new snapshot for a specific epoch.
     /// Stores the snapshot in the historical map and updates latest epoch.
     /// Epochs must be submitted in strictly increasing order (monotonicity).
+    /// Resubmitting the latest epoch with the same hash is a no-op, so a
+    /// retried submission succeeds.
     ///
     /// # Arguments
     /// * `env` - Contract environment
@@ -76,10 +81,12 @@ impl AnalyticsContract {
     /// * If admin is not set (contract not initialized)
     /// * If caller is not the authorized admin
     /// * If epoch is 0 (invalid)
-    /// * If epoch <= latest (monotonicity violated: out-of-order or duplicate)
+    /// * If epoch equals latest but the hash differs from the stored one
+    /// * If epoch < latest (monotonicity violated: out-of-order)
     ///
     /// # Returns
-    /// * Ledger timestamp when snapshot was recorded
+    /// * Ledger timestamp when snapshot was recorded (the original timestamp
+    ///   for an identical resubmission)
     pub fn submit_snapshot(env: Env, epoch: u64, hash: BytesN<32>, caller: Address) -> u64 {
         // Check if contract is paused
         let is_paused: bool = env
@@ -115,15 +122,23 @@ impl AnalyticsContract {
             .get(&DataKey::LatestEpoch)
             .unwrap_or(0);
 
-        if epoch <= latest {
-            if epoch == latest {
-                panic!("Snapshot for epoch {} already exists", epoch);
-            } else {
-                panic!(
-                    "Epoch monotonicity violated: epoch {} must be strictly greater than latest {}",
-                    epoch, latest
-                );
+        if epoch == latest {
+            if let Some(existing) = Self::get_snapshot(env.clone(), epoch) {
+                if existing.hash == hash {
+                    return existing.timestamp;
+                }
             }
+            panic!(
+                "Snapshot for epoch {} already exists with a different hash",
+                epoch
+            );
+        }
+
+        if epoch < latest {
+            panic!(
+                "Epoch monotonicity violated: epoch {} must be strictly greater than latest {}",
+                epoch, latest
+            );
         }
 
         let timestamp = env.ledger().timestamp();
@@ -215,6 +230,33 @@ impl AnalyticsContract {
             .unwrap_or(0)
     }
 
+    /// Get snapshots recorded within a ledger-time range
+    ///
+    /// # Arguments
+    /// * `env` - Contract environment
+    /// * `start_ts` - Earliest ledger timestamp to include
+    /// * `end_ts` - Latest ledger timestamp to include
+    ///
+    /// # Returns
+    /// * Snapshots whose timestamp falls within `[start_ts, end_ts]`, ordered by epoch
+    pub fn get_snapshots_by_time(
+        env: Env,
+        start_ts: u64,
+        end_ts: u64,
+    ) -> soroban_sdk::Vec<SnapshotMetadata> {
+        let snapshots = Self::get_snapshot_history(env.clone());
+        let mut matching = soroban_sdk::Vec::new(&env);
+
+        // Map iteration is ordered by epoch
+        for (_, snapshot) in snapshots.iter() {
+            if snapshot.timestamp >= start_ts && snapshot.timestamp <= end_ts {
+                matching.push_back(snapshot);
+            }
+        }
+
+        matching
+    }
+
     /// Get all epochs that have snapshots (for iteration purposes)
     ///
     /// # Arguments
@@ -233,6 +275,57 @@ impl AnalyticsContract {
         epochs
     }
 
+    /// Remove all but the most recent `keep_last` snapshots from history
+    /// to bound persistent storage. The latest epoch is left untouched.
+    ///
+    /// # Arguments
+    /// * `env` - Contract environment
+    /// * `caller` - Address attempting to prune (must be admin)
+    /// * `keep_last` - Number of most recent epochs to retain (at least 1)
+    ///
+    /// # Panics
+    /// * If contract is not initialized (admin not set)
+    /// * If caller is not the admin
+    /// * If keep_last is 0
+    ///
+    /// # Returns
+    /// * Number of snapshots removed
+    pub fn prune_snapshots(env: Env, caller: Address, keep_last: u32) -> u32 {
+        caller.require_auth();
+
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Contract not initialized: admin not set");
+
+        if caller != admin {
+            panic!("Unauthorized: only the admin can prune snapshots");
+        }
+
+        if keep_last == 0 {
+            panic!("Invalid keep_last: must retain at least one snapshot");
+        }
+
+        let mut snapshots = Self::get_snapshot_history(env.clone());
+        let total = snapshots.len();
+        if total <= keep_last {
+            return 0;
+        }
+
+        // Map keys iterate in ascending order, so the oldest epochs come first
+        let to_remove = total - keep_last;
+        for epoch in snapshots.keys().iter().take(to_remove as usize) {
+            snapshots.remove(epoch);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Snapshots, &snapshots);
+
+        to_remove
+    }
+
     /// Get the current authorized admin address
     ///
     /// # Arguments
@@ -1,7 +1,9 @@
+extern crate std;
+
 use super::*;
 use soroban_sdk::{
-    testutils::{Address as _, Ledger},
-    Address, BytesN, Env,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Ledger},
+    Address, BytesN, Env, IntoVal, Symbol,
 };
 
 fn create_test_hash(env: &Env, value: u8) -> BytesN<32> {
@@ -183,6 +185,40 @@ fn test_historical_data_integrity_after_new_submissions() {
     assert_eq!(client.get_latest_epoch(), epoch3);
 }
 
+#[test]
+fn test_get_snapshots_by_time() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AnalyticsContract);
+    let client = AnalyticsContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    client.initialize(&admin);
+
+    for (epoch, timestamp) in [(1u64, 100u64), (2, 200), (3, 300), (4, 400)] {
+        env.ledger().set_timestamp(timestamp);
+        client.submit_snapshot(&epoch, &create_test_hash(&env, epoch as u8), &admin);
+    }
+
+    // Both bounds are inclusive
+    let window = client.get_snapshots_by_time(&200, &300);
+    assert_eq!(window.len(), 2);
+    assert_eq!(window.get(0).unwrap().epoch, 2);
+    assert_eq!(window.get(0).unwrap().timestamp, 200);
+    assert_eq!(window.get(1).unwrap().epoch, 3);
+    assert_eq!(window.get(1).unwrap().hash, create_test_hash(&env, 3));
+
+    let all = client.get_snapshots_by_time(&0, &u64::MAX);
+    assert_eq!(all.len(), 4);
+    for (i, snapshot) in all.iter().enumerate() {
+        assert_eq!(snapshot.epoch, i as u64 + 1);
+    }
+
+    assert_eq!(client.get_snapshots_by_time(&250, &299).len(), 0);
+    assert_eq!(client.get_snapshots_by_time(&300, &200).len(), 0);
+}
+
 #[test]
 fn test_get_nonexistent_snapshot() {
     let env = Env::default();
@@ -213,6 +249,70 @@ fn test_invalid_epoch_zero() {
     client.submit_snapshot(&0, &hash, &admin);
 }
 
+#[test]
+fn test_identical_resubmission_is_noop() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AnalyticsContract);
+    let client = AnalyticsContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    client.initialize(&admin);
+
+    let epoch = 1u64;
+    let hash = create_test_hash(&env, 1);
+
+    env.ledger().set_timestamp(1000);
+    let original = client.submit_snapshot(&epoch, &hash, &admin);
+
+    // A retry lands in a later ledger but keeps the original record
+    env.ledger().set_timestamp(2000);
+    let retried = client.submit_snapshot(&epoch, &hash, &admin);
+
+    assert_eq!(retried, original);
+    assert_eq!(client.get_snapshot(&epoch).unwrap().timestamp, 1000);
+    assert_eq!(client.get_latest_epoch(), epoch);
+    assert_eq!(client.get_snapshot_history().len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "already exists with a different hash")]
+fn test_conflicting_resubmission_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AnalyticsContract);
+    let client = AnalyticsContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    client.initialize(&admin);
+
+    let epoch = 1u64;
+    client.submit_snapshot(&epoch, &create_test_hash(&env, 1), &admin);
+    client.submit_snapshot(&epoch, &create_test_hash(&env, 2), &admin);
+}
+
+#[test]
+#[should_panic(expected = "Epoch monotonicity violated")]
+fn test_identical_resubmission_of_older_epoch_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AnalyticsContract);
+    let client = AnalyticsContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    client.initialize(&admin);
+
+    let hash_old = create_test_hash(&env, 5);
+    client.submit_snapshot(&5u64, &hash_old, &admin);
+    client.submit_snapshot(&10u64, &create_test_hash(&env, 10), &admin);
+
+    // Only the latest epoch may be retried
+    client.submit_snapshot(&5u64, &hash_old, &admin);
+}
+
 #[test]
 #[should_panic(expected = "already exists")]
 fn test_duplicate_epoch_fails() {
@@ -285,6 +385,79 @@ fn test_bounded_storage_growth_simulation() {
 // Access Control Tests - Tests for Issue #41
 // ============================================================================
 
+#[test]
+fn test_prune_snapshots_keeps_most_recent_epochs() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AnalyticsContract);
+    let client = AnalyticsContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    client.initialize(&admin);
+
+    for epoch in 1..=10u64 {
+        client.submit_snapshot(&epoch, &create_test_hash(&env, epoch as u8), &admin);
+    }
+
+    assert_eq!(client.prune_snapshots(&admin, &3), 7);
+
+    let epochs = client.get_all_epochs();
+    assert_eq!(epochs.len(), 3);
+    assert_eq!(epochs.get(0), Some(8));
+    assert_eq!(epochs.get(1), Some(9));
+    assert_eq!(epochs.get(2), Some(10));
+    assert_eq!(client.get_snapshot(&7), None);
+    assert_eq!(
+        client.get_snapshot(&8).unwrap().hash,
+        create_test_hash(&env, 8)
+    );
+
+    // The latest epoch is untouched, so monotonicity still holds
+    assert_eq!(client.get_latest_epoch(), 10);
+    assert_eq!(client.get_latest_snapshot().unwrap().epoch, 10);
+
+    // Nothing left to prune
+    assert_eq!(client.prune_snapshots(&admin, &3), 0);
+    assert_eq!(client.get_all_epochs().len(), 3);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_prune_snapshots_by_non_admin_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AnalyticsContract);
+    let client = AnalyticsContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    client.initialize(&admin);
+    client.submit_snapshot(&1u64, &create_test_hash(&env, 1), &admin);
+
+    client.prune_snapshots(&other, &1);
+}
+
+#[test]
+#[should_panic(expected = "Epoch monotonicity violated")]
+fn test_pruned_epoch_cannot_be_resubmitted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AnalyticsContract);
+    let client = AnalyticsContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    client.initialize(&admin);
+    for epoch in 1..=3u64 {
+        client.submit_snapshot(&epoch, &create_test_hash(&env, epoch as u8), &admin);
+    }
+    client.prune_snapshots(&admin, &1);
+
+    client.submit_snapshot(&1u64, &create_test_hash(&env, 1), &admin);
+}
+
 #[test]
 #[should_panic(expected = "Unauthorized")]
 fn test_unauthorized_submission_fails() {
@@ -333,6 +506,74 @@ fn test_authorized_submission_succeeds() {
     assert_eq!(snapshot.hash, hash);
 }
 
+#[test]
+fn test_submission_requires_admin_signature() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AnalyticsContract);
+    let client = AnalyticsContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    client.initialize(&admin);
+
+    let hash = create_test_hash(&env, 1);
+    client.submit_snapshot(&1u64, &hash, &admin);
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    contract_id.clone(),
+                    Symbol::new(&env, "submit_snapshot"),
+                    (1u64, hash, admin.clone()).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+
+    // Naming the admin as caller is not enough without its signature
+    env.set_auths(&[]);
+    assert!(client
+        .try_submit_snapshot(&2u64, &create_test_hash(&env, 2), &admin)
+        .is_err());
+    assert_eq!(client.get_latest_epoch(), 1);
+}
+
+#[test]
+fn test_initialize_requires_admin_signature() {
+    let env = Env::default();
+
+    let contract_id = env.register_contract(None, AnalyticsContract);
+    let client = AnalyticsContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    assert!(client.try_initialize(&admin).is_err());
+    assert_eq!(client.get_admin(), None);
+}
+
+#[test]
+fn test_admin_transfer_moves_submission_rights() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AnalyticsContract);
+    let client = AnalyticsContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    client.initialize(&admin);
+    client.set_admin(&admin, &new_admin);
+
+    client.submit_snapshot(&1u64, &create_test_hash(&env, 1), &new_admin);
+    assert!(client
+        .try_submit_snapshot(&2u64, &create_test_hash(&env, 2), &admin)
+        .is_err());
+    assert_eq!(client.get_latest_epoch(), 1);
+}
+
 #[test]
 fn test_get_admin() {
     let env = Env::default();
@@ -64,7 +64,7 @@ export async function GET() {
       avg_settlement_time_ms?: number;
     }
 
-    const corridors: BackendCorridor[] = await corridorsRes.json();
+    const { items: corridors }: { items: BackendCorridor[] } = await corridorsRes.json();
     const paymentsData = paymentsRes.ok ? await paymentsRes.json() : { _embedded: { records: [] } };
 
     // --- Aggregation Logic ---
@@ -30,7 +30,7 @@ export async function GET() {
         }
 
         const anchorsData = await anchorsRes.json();
-        const corridors: Corridor[] = await corridorsRes.json();
+        const { items: corridors }: { items: Corridor[] } = await corridorsRes.json();
         const anchors: Anchor[] = anchorsData.anchors || [];
 
         // 2. Transform into Nodes and Links
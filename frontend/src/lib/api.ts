@@ -214,6 +214,18 @@ export interface AnchorsResponse {
   total: number;
 }
 
+/**
+ * One page of a paginated list endpoint
+ */
+export interface PaginatedResponse<T> {
+  items: T[];
+  total: number;
+  limit: number;
+  offset: number;
+  has_more: boolean;
+  stale?: boolean;
+}
+
 export interface CorridorDetailData {
   corridor: CorridorMetrics;
   historical_success_rate: SuccessRateDataPoint[];
@@ -271,7 +283,8 @@ export async function getCorridors(
   }
   const query = params.toString();
   const url = query ? `/corridors?${query}` : "/corridors";
-  return api.get<CorridorMetrics[]>(url);
+  const page = await api.get<PaginatedResponse<CorridorMetrics>>(url);
+  return page.items;
 }
 
 /**